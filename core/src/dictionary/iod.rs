@@ -0,0 +1,178 @@
+//! This module contains the concept of an information object definition
+//! (IOD), as specified in DICOM PS3.3.
+//!
+//! An IOD is composed of modules, each of which is a collection of attribute
+//! specifications. These specifications state whether the attribute must be
+//! present and whether it may be empty (the attribute's _type_), which values
+//! it may take, and the attributes expected within each item if the attribute
+//! is a sequence.
+
+use crate::header::Tag;
+use std::fmt;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// The type of an attribute in a module, which defines
+/// whether it must be present and whether it may be empty.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AttributeType {
+    /// Required, and must not be empty.
+    Type1,
+    /// Conditionally required, and must not be empty if present.
+    Type1C,
+    /// Required, but may be empty.
+    Type2,
+    /// Conditionally required, and may be empty.
+    Type2C,
+    /// Optional.
+    Type3,
+}
+
+impl AttributeType {
+    /// Whether the attribute must always be present.
+    pub fn is_required(self) -> bool {
+        matches!(self, AttributeType::Type1 | AttributeType::Type2)
+    }
+
+    /// Whether the attribute must have a value when present.
+    pub fn is_non_empty(self) -> bool {
+        matches!(self, AttributeType::Type1 | AttributeType::Type1C)
+    }
+
+    /// Obtain the type as written in the standard (e.g. "1C").
+    pub fn to_str(self) -> &'static str {
+        match self {
+            AttributeType::Type1 => "1",
+            AttributeType::Type1C => "1C",
+            AttributeType::Type2 => "2",
+            AttributeType::Type2C => "2C",
+            AttributeType::Type3 => "3",
+        }
+    }
+}
+
+impl FromStr for AttributeType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(AttributeType::Type1),
+            "1C" => Ok(AttributeType::Type1C),
+            "2" => Ok(AttributeType::Type2),
+            "2C" => Ok(AttributeType::Type2C),
+            "3" => Ok(AttributeType::Type3),
+            _ => Err("invalid attribute type"),
+        }
+    }
+}
+
+impl fmt::Display for AttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Type {}", self.to_str())
+    }
+}
+
+/// The usage of a module in an IOD.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ModuleUsage {
+    /// Mandatory (M)
+    Mandatory,
+    /// Conditional (C)
+    Conditional,
+    /// User option (U)
+    UserOption,
+}
+
+impl FromStr for ModuleUsage {
+    type Err = &'static str;
+
+    /// Parse the module usage from the text in the IOD table,
+    /// which may be followed by the condition of a conditional module.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().chars().next() {
+            Some('M') => Ok(ModuleUsage::Mandatory),
+            Some('C') => Ok(ModuleUsage::Conditional),
+            Some('U') => Ok(ModuleUsage::UserOption),
+            _ => Err("invalid module usage"),
+        }
+    }
+}
+
+/// The specification of an attribute in a module.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AttributeSpec<'a> {
+    /// The attribute tag
+    pub tag: Tag,
+    /// The attribute type
+    pub attribute_type: AttributeType,
+    /// The only values which the attribute may take, if enumerated.
+    pub enumerated_values: &'a [&'a str],
+    /// Known values of the attribute, which may be extended.
+    pub defined_terms: &'a [&'a str],
+    /// The attributes of each item, if the attribute is a sequence.
+    pub items: &'a [AttributeSpec<'a>],
+}
+
+/// The specification of a module, with macros already expanded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ModuleSpec<'a> {
+    /// The module name (e.g. "Patient")
+    pub name: &'a str,
+    /// The attributes of the module
+    pub attributes: &'a [AttributeSpec<'a>],
+}
+
+/// A module referenced by an IOD.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct IodModuleSpec<'a> {
+    /// The module
+    pub module: &'a ModuleSpec<'a>,
+    /// The usage of the module in the IOD
+    pub usage: ModuleUsage,
+}
+
+/// The specification of an information object definition.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct IodSpec<'a> {
+    /// The IOD name, without the "IOD" suffix (e.g. "CT Image")
+    pub name: &'a str,
+    /// The modules of the IOD
+    pub modules: &'a [IodModuleSpec<'a>],
+}
+
+/** Type trait for a dictionary of information object definitions.
+ *
+ * The methods herein have no generic parameters, so as to enable being
+ * used as a trait object.
+ */
+pub trait IodDictionary: Debug {
+    /// Fetch the IOD of the given SOP class UID.
+    fn by_sop_class(&self, uid: &str) -> Option<&IodSpec<'_>>;
+
+    /// Fetch an IOD by its name (e.g. "CT Image").
+    fn by_name(&self, name: &str) -> Option<&IodSpec<'_>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_attribute_type_and_usage() {
+        assert_eq!("1C".parse(), Ok(AttributeType::Type1C));
+        assert_eq!(" 2 ".parse(), Ok(AttributeType::Type2));
+        assert!("4".parse::<AttributeType>().is_err());
+        assert!(AttributeType::Type2.is_required());
+        assert!(!AttributeType::Type2.is_non_empty());
+        assert!(AttributeType::Type1C.is_non_empty());
+        assert!(!AttributeType::Type1C.is_required());
+        assert_eq!(AttributeType::Type2C.to_string(), "Type 2C");
+
+        assert_eq!("M".parse(), Ok(ModuleUsage::Mandatory));
+        assert_eq!(
+            "C - Required if contrast media was used in this image".parse(),
+            Ok(ModuleUsage::Conditional)
+        );
+        assert_eq!("U".parse(), Ok(ModuleUsage::UserOption));
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! The standard data dictionary is available in the `dicom-std-dict` crate.
 
+pub mod iod;
+pub mod owned;
+pub mod private;
 pub mod stub;
+pub mod uid;
 
 use crate::header::{Tag, VR};
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use std::str::FromStr;
 
 /// Specification of a range of tags pertaining to an attribute.
@@ -129,9 +133,27 @@ pub trait DictionaryEntry {
     }
     /// The alias of the attribute, with no spaces, usually in UpperCamelCase.
     fn alias(&self) -> &str;
+    /// The human-readable name of the attribute, as written in the standard
+    /// (e.g. "Patient's Name").
+    fn name(&self) -> &str;
     /// The _typical_ value representation of the attribute.
     /// In some edge cases, an element might not have this VR.
-    fn vr(&self) -> VR;
+    fn vr(&self) -> VR {
+        self.vrs().first().cloned().unwrap_or(VR::UN)
+    }
+    /// All value representations which the attribute may have,
+    /// starting with the typical one (e.g. `[US, SS]`).
+    fn vrs(&self) -> &[VR];
+    /// The value multiplicity of the attribute in textual form,
+    /// as written in the standard (e.g. "1-n").
+    fn vm(&self) -> &str;
+    /// The value multiplicity of the attribute. Returns `None` if it could
+    /// not be interpreted.
+    fn value_multiplicity(&self) -> Option<ValueMultiplicity> {
+        self.vm().parse().ok()
+    }
+    /// Whether the attribute has been retired from the standard.
+    fn is_retired(&self) -> bool;
 }
 
 /// A data type for a dictionary entry with full ownership.
@@ -141,8 +163,14 @@ pub struct DictionaryEntryBuf {
     pub tag: TagRange,
     /// The alias of the attribute, with no spaces, usually InCapitalizedCamelCase
     pub alias: String,
-    /// The _typical_  value representation of the attribute
-    pub vr: VR,
+    /// The human-readable name of the attribute
+    pub name: String,
+    /// The possible value representations of the attribute, typical one first
+    pub vrs: Vec<VR>,
+    /// The value multiplicity of the attribute
+    pub vm: String,
+    /// Whether the attribute is retired
+    pub retired: bool,
 }
 
 impl DictionaryEntry for DictionaryEntryBuf {
@@ -152,8 +180,33 @@ impl DictionaryEntry for DictionaryEntryBuf {
     fn alias(&self) -> &str {
         self.alias.as_str()
     }
-    fn vr(&self) -> VR {
-        self.vr
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+    fn vrs(&self) -> &[VR] {
+        &self.vrs
+    }
+    fn vm(&self) -> &str {
+        self.vm.as_str()
+    }
+    fn is_retired(&self) -> bool {
+        self.retired
+    }
+}
+
+impl<'a, E: ?Sized> From<&'a E> for DictionaryEntryBuf
+where
+    E: DictionaryEntry,
+{
+    fn from(entry: &'a E) -> Self {
+        DictionaryEntryBuf {
+            tag: entry.tag_range(),
+            alias: entry.alias().to_string(),
+            name: entry.name().to_string(),
+            vrs: entry.vrs().to_vec(),
+            vm: entry.vm().to_string(),
+            retired: entry.is_retired(),
+        }
     }
 }
 
@@ -164,8 +217,14 @@ pub struct DictionaryEntryRef<'a> {
     pub tag: TagRange,
     /// The alias of the attribute, with no spaces, usually InCapitalizedCamelCase
     pub alias: &'a str,
-    /// The _typical_  value representation of the attribute
-    pub vr: VR,
+    /// The human-readable name of the attribute
+    pub name: &'a str,
+    /// The possible value representations of the attribute, typical one first
+    pub vrs: &'a [VR],
+    /// The value multiplicity of the attribute
+    pub vm: &'a str,
+    /// Whether the attribute is retired
+    pub retired: bool,
 }
 
 impl<'a> DictionaryEntry for DictionaryEntryRef<'a> {
@@ -175,8 +234,101 @@ impl<'a> DictionaryEntry for DictionaryEntryRef<'a> {
     fn alias(&self) -> &str {
         self.alias
     }
-    fn vr(&self) -> VR {
-        self.vr
+    fn name(&self) -> &str {
+        self.name
+    }
+    fn vrs(&self) -> &[VR] {
+        self.vrs
+    }
+    fn vm(&self) -> &str {
+        self.vm
+    }
+    fn is_retired(&self) -> bool {
+        self.retired
+    }
+}
+
+/// The value multiplicity of an attribute: the number of values which may be
+/// present in a data element of that attribute.
+///
+/// The textual form follows the notation of the standard:
+/// `1`, `1-3`, `1-n`, or `2-2n` (an even number of values).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ValueMultiplicity {
+    /// The minimum number of values.
+    pub min: u32,
+    /// The maximum number of values, `None` if unbounded.
+    pub max: Option<u32>,
+    /// The values come in groups of this size after the minimum
+    /// (e.g. 2 in `2-2n`).
+    pub step: u32,
+}
+
+impl ValueMultiplicity {
+    /// Check whether the given number of values satisfies this multiplicity.
+    pub fn contains(&self, n: u32) -> bool {
+        n >= self.min
+            && self.max.map(|max| n <= max).unwrap_or(true)
+            && (n - self.min).checked_rem(self.step) == Some(0)
+    }
+}
+
+impl FromStr for ValueMultiplicity {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut parts = s.splitn(2, '-');
+        let min = parts
+            .next()
+            .and_then(|p| p.trim().parse().ok())
+            .ok_or("invalid minimum value multiplicity")?;
+        match parts.next().map(str::trim) {
+            None => Ok(ValueMultiplicity {
+                min,
+                max: Some(min),
+                step: 1,
+            }),
+            Some("n") => Ok(ValueMultiplicity {
+                min,
+                max: None,
+                step: 1,
+            }),
+            Some(max) if max.ends_with('n') => {
+                let step = max[..max.len() - 1]
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or("invalid value multiplicity step")?;
+                Ok(ValueMultiplicity {
+                    min,
+                    max: None,
+                    step,
+                })
+            }
+            Some(max) => {
+                let max = max
+                    .parse()
+                    .ok()
+                    .ok_or("invalid maximum value multiplicity")?;
+                Ok(ValueMultiplicity {
+                    min,
+                    max: Some(max),
+                    step: 1,
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValueMultiplicity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.max, self.step) {
+            (Some(max), _) if max == self.min => write!(f, "{}", self.min),
+            (Some(max), _) => write!(f, "{}-{}", self.min, max),
+            (None, 1) => write!(f, "{}-n", self.min),
+            (None, step) => write!(f, "{}-{}n", self.min, step),
+        }
     }
 }
 
@@ -214,7 +366,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::TagRange;
+    use super::{TagRange, ValueMultiplicity};
     use crate::header::Tag;
 
     #[test]
@@ -231,4 +383,31 @@ mod tests {
         let tag: TagRange = "1234,56xx".parse().unwrap();
         assert_eq!(tag, TagRange::Element100(Tag(0x1234, 0x5600)));
     }
+
+    #[test]
+    fn test_parse_value_multiplicity() {
+        let vm: ValueMultiplicity = "1".parse().unwrap();
+        assert_eq!(vm.max, Some(1));
+        assert!(vm.contains(1));
+        assert!(!vm.contains(2));
+
+        let vm: ValueMultiplicity = "1-3".parse().unwrap();
+        assert!(vm.contains(3));
+        assert!(!vm.contains(4));
+
+        let vm: ValueMultiplicity = "1-n".parse().unwrap();
+        assert!(vm.contains(1));
+        assert!(vm.contains(100));
+        assert!(!vm.contains(0));
+        assert_eq!(vm.to_string(), "1-n");
+
+        let vm: ValueMultiplicity = "2-2n".parse().unwrap();
+        assert!(vm.contains(2));
+        assert!(vm.contains(4));
+        assert!(!vm.contains(3));
+        assert_eq!(vm.to_string(), "2-2n");
+
+        assert!("".parse::<ValueMultiplicity>().is_err());
+        assert!("1-x".parse::<ValueMultiplicity>().is_err());
+    }
 }
@@ -0,0 +1,216 @@
+//! This module contains an owned data dictionary,
+//! which can be built and extended at run time.
+
+use super::{DataDictionary, DictionaryEntry, DictionaryEntryBuf, TagRange};
+use crate::header::Tag;
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+use std::sync::Arc;
+
+/// A data dictionary with full ownership of its entries.
+///
+/// Unlike the compiled-in standard dictionary, this one can be populated at
+/// run time, for instance from a dictionary file, so that local or private
+/// attributes can be added without recompiling. Adding an entry with the same
+/// tag as an existing one replaces the latter, which makes it possible to
+/// layer custom entries on top of a copy of another dictionary.
+///
+/// Since DICOM objects keep a copy of their dictionary, consider sharing it
+/// as an `Arc<OwnedDataDictionary>`, which is also a data dictionary.
+#[derive(Debug, Default, Clone)]
+pub struct OwnedDataDictionary {
+    /// mapping: tag → entry
+    by_tag: HashMap<Tag, DictionaryEntryBuf>,
+    /// mapping: name → tag
+    by_name: HashMap<String, Tag>,
+    /// repeating elements of the form (ggxx, eeee). The `xx` portion is zeroed.
+    repeating_ggxx: HashSet<Tag>,
+    /// repeating elements of the form (gggg, eexx). The `xx` portion is zeroed.
+    repeating_eexx: HashSet<Tag>,
+}
+
+impl OwnedDataDictionary {
+    /// Create a new, empty dictionary.
+    pub fn new() -> Self {
+        OwnedDataDictionary::default()
+    }
+
+    /// Add an entry to the dictionary, replacing (and returning) any
+    /// previous entry for the same tag.
+    pub fn add(&mut self, entry: DictionaryEntryBuf) -> Option<DictionaryEntryBuf> {
+        let tag = entry.tag.inner();
+        match entry.tag {
+            TagRange::Group100(tag) => {
+                self.repeating_ggxx.insert(tag);
+            }
+            TagRange::Element100(tag) => {
+                self.repeating_eexx.insert(tag);
+            }
+            TagRange::Single(_) => {}
+        }
+        self.by_name.insert(entry.alias.clone(), tag);
+        let old = self.by_tag.insert(tag, entry);
+        if let Some(old) = &old {
+            // do not let the old alias point to the new entry
+            if old.alias != self.by_tag[&tag].alias {
+                self.by_name.remove(&old.alias);
+            }
+        }
+        old
+    }
+
+    /// Retrieve the number of entries in the dictionary.
+    pub fn len(&self) -> usize {
+        self.by_tag.len()
+    }
+
+    /// Check whether the dictionary has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.by_tag.is_empty()
+    }
+
+    /// Iterate over all entries in the dictionary, in no particular order.
+    pub fn entries(&self) -> impl Iterator<Item = &DictionaryEntryBuf> {
+        self.by_tag.values()
+    }
+
+    fn indexed_tag(&self, tag: Tag) -> Option<&DictionaryEntryBuf> {
+        self.by_tag.get(&tag).or_else(|| {
+            let group_trimmed = Tag(tag.0 & 0xFF00, tag.1);
+            if self.repeating_ggxx.contains(&group_trimmed) {
+                return self.by_tag.get(&group_trimmed);
+            }
+            let elem_trimmed = Tag(tag.0, tag.1 & 0xFF00);
+            if self.repeating_eexx.contains(&elem_trimmed) {
+                return self.by_tag.get(&elem_trimmed);
+            }
+            None
+        })
+    }
+}
+
+impl DataDictionary for OwnedDataDictionary {
+    type Entry = DictionaryEntryBuf;
+
+    fn by_name(&self, name: &str) -> Option<&DictionaryEntryBuf> {
+        self.by_name.get(name).and_then(|tag| self.by_tag.get(tag))
+    }
+
+    fn by_tag(&self, tag: Tag) -> Option<&DictionaryEntryBuf> {
+        self.indexed_tag(tag)
+    }
+}
+
+impl DataDictionary for &OwnedDataDictionary {
+    type Entry = DictionaryEntryBuf;
+
+    fn by_name(&self, name: &str) -> Option<&DictionaryEntryBuf> {
+        (**self).by_name(name)
+    }
+
+    fn by_tag(&self, tag: Tag) -> Option<&DictionaryEntryBuf> {
+        (**self).by_tag(tag)
+    }
+}
+
+impl DataDictionary for Arc<OwnedDataDictionary> {
+    type Entry = DictionaryEntryBuf;
+
+    fn by_name(&self, name: &str) -> Option<&DictionaryEntryBuf> {
+        (**self).by_name(name)
+    }
+
+    fn by_tag(&self, tag: Tag) -> Option<&DictionaryEntryBuf> {
+        (**self).by_tag(tag)
+    }
+}
+
+impl Extend<DictionaryEntryBuf> for OwnedDataDictionary {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = DictionaryEntryBuf>,
+    {
+        for entry in iter {
+            self.add(entry);
+        }
+    }
+}
+
+impl FromIterator<DictionaryEntryBuf> for OwnedDataDictionary {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = DictionaryEntryBuf>,
+    {
+        let mut dict = OwnedDataDictionary::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<'a, E: 'a> FromIterator<&'a E> for OwnedDataDictionary
+where
+    E: DictionaryEntry,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a E>,
+    {
+        iter.into_iter().map(DictionaryEntryBuf::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::VR;
+
+    #[test]
+    fn owned_dictionary_lookup_and_replace() {
+        let mut dict: OwnedDataDictionary = vec![
+            DictionaryEntryBuf {
+                tag: TagRange::Single(Tag(0x0010, 0x0010)),
+                alias: "PatientName".to_string(),
+                name: "Patient's Name".to_string(),
+                vrs: vec![VR::PN],
+                vm: "1".to_string(),
+                retired: false,
+            },
+            DictionaryEntryBuf {
+                tag: TagRange::Group100(Tag(0x6000, 0x3000)),
+                alias: "OverlayData".to_string(),
+                name: "Overlay Data".to_string(),
+                vrs: vec![VR::OB, VR::OW],
+                vm: "1".to_string(),
+                retired: false,
+            },
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(dict.len(), 2);
+        assert_eq!(
+            dict.by_name("PatientName").map(|e| e.tag()),
+            Some(Tag(0x0010, 0x0010))
+        );
+        assert_eq!(
+            dict.by_tag(Tag(0x6002, 0x3000)).map(|e| e.alias()),
+            Some("OverlayData")
+        );
+
+        let old = dict.add(DictionaryEntryBuf {
+            tag: TagRange::Single(Tag(0x0010, 0x0010)),
+            alias: "NameOfPatient".to_string(),
+            name: "Name of Patient".to_string(),
+            vrs: vec![VR::PN],
+            vm: "1".to_string(),
+            retired: false,
+        });
+        assert_eq!(old.map(|e| e.alias), Some("PatientName".to_string()));
+        assert_eq!(dict.len(), 2);
+        assert!(dict.by_name("PatientName").is_none());
+        assert_eq!(
+            dict.by_tag(Tag(0x0010, 0x0010)).map(|e| e.alias()),
+            Some("NameOfPatient")
+        );
+    }
+}
@@ -0,0 +1,228 @@
+//! This module contains the concept of a private data dictionary.
+//!
+//! Private attributes are not identified by a fixed tag. Instead, a private
+//! creator element `(gggg,00xx)` reserves the block of elements
+//! `(gggg,xx00)`-`(gggg,xxFF)` for a given creator string, so that the same
+//! attribute may live in a different block from one data set to the next.
+//! Private dictionary entries are therefore keyed by the triple
+//! _(private creator, group, element offset)_.
+
+use crate::header::{Tag, VR};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+/// Check whether the given tag belongs to a private group.
+pub fn is_private(tag: Tag) -> bool {
+    tag.0 & 1 == 1 && tag.0 > 0x0008 && tag.0 != 0xFFFF
+}
+
+/// Check whether the given tag is a private creator element,
+/// in the range `(gggg,0010)`-`(gggg,00FF)` of a private group.
+pub fn is_private_creator(tag: Tag) -> bool {
+    is_private(tag) && tag.1 >= 0x0010 && tag.1 <= 0x00FF
+}
+
+/// Retrieve the tag of the private creator element which reserves the block
+/// of the given private attribute tag. Returns `None` if the tag is not a
+/// private attribute within a reserved block.
+pub fn private_creator_of(tag: Tag) -> Option<Tag> {
+    if !is_private(tag) {
+        return None;
+    }
+    let block = tag.1 >> 8;
+    if block < 0x10 {
+        return None;
+    }
+    Some(Tag(tag.0, block))
+}
+
+/// Obtain the tag of a private attribute from its group,
+/// the block reserved by its private creator, and its element offset.
+pub fn private_tag(group: u16, block: u8, offset: u8) -> Tag {
+    Tag(group, (u16::from(block) << 8) | u16::from(offset))
+}
+
+/// Normalize a private creator value for comparison,
+/// by removing the trailing padding.
+pub fn trim_creator(creator: &str) -> &str {
+    creator.trim_end_matches(&[' ', '\0'][..])
+}
+
+/// Type trait for a dictionary of private DICOM attributes.
+pub trait PrivateDataDictionary: Debug {
+    /// Fetch an entry by its private creator, group and element offset
+    /// within the reserved block.
+    fn by_creator(&self, creator: &str, group: u16, offset: u8) -> Option<&PrivateDictionaryEntry>;
+
+    /// Fetch an entry by its private creator and its alias.
+    fn by_name(&self, creator: &str, name: &str) -> Option<&PrivateDictionaryEntry>;
+}
+
+impl<T: ?Sized> PrivateDataDictionary for &T
+where
+    T: PrivateDataDictionary,
+{
+    fn by_creator(&self, creator: &str, group: u16, offset: u8) -> Option<&PrivateDictionaryEntry> {
+        (**self).by_creator(creator, group, offset)
+    }
+
+    fn by_name(&self, creator: &str, name: &str) -> Option<&PrivateDictionaryEntry> {
+        (**self).by_name(creator, name)
+    }
+}
+
+/// A private data dictionary entry.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PrivateDictionaryEntry {
+    /// The private creator string that reserves the attribute's block.
+    pub creator: String,
+    /// The private group of the attribute.
+    pub group: u16,
+    /// The element offset within the reserved block.
+    pub offset: u8,
+    /// The alias of the attribute, with no spaces, usually in UpperCamelCase.
+    pub alias: String,
+    /// The _typical_ value representation of the attribute.
+    pub vr: VR,
+}
+
+impl PrivateDictionaryEntry {
+    /// Retrieve the actual tag of this attribute, given the block reserved by
+    /// its private creator in a data set.
+    pub fn tag_in_block(&self, block: u8) -> Tag {
+        private_tag(self.group, block, self.offset)
+    }
+}
+
+/// An owned, mutable dictionary of private attributes.
+#[derive(Debug, Default, Clone)]
+pub struct PrivateDictionary {
+    by_creator: HashMap<(String, u16, u8), PrivateDictionaryEntry>,
+    by_name: HashMap<(String, String), (String, u16, u8)>,
+}
+
+impl PrivateDictionary {
+    /// Create a new, empty private dictionary.
+    pub fn new() -> Self {
+        PrivateDictionary::default()
+    }
+
+    /// Add an entry to the dictionary, replacing (and returning) any previous
+    /// entry for the same creator, group and offset.
+    pub fn add(&mut self, entry: PrivateDictionaryEntry) -> Option<PrivateDictionaryEntry> {
+        let creator = trim_creator(&entry.creator).to_string();
+        let key = (creator.clone(), entry.group, entry.offset);
+        if let Some(old) = self.by_creator.get(&key) {
+            let old_name = (creator.clone(), old.alias.clone());
+            if self.by_name.get(&old_name) == Some(&key) {
+                self.by_name.remove(&old_name);
+            }
+        }
+        self.by_name
+            .insert((creator, entry.alias.clone()), key.clone());
+        self.by_creator.insert(key, entry)
+    }
+
+    /// Retrieve the number of entries in the dictionary.
+    pub fn len(&self) -> usize {
+        self.by_creator.len()
+    }
+
+    /// Check whether the dictionary has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.by_creator.is_empty()
+    }
+}
+
+impl std::iter::FromIterator<PrivateDictionaryEntry> for PrivateDictionary {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = PrivateDictionaryEntry>,
+    {
+        let mut dict = PrivateDictionary::new();
+        for entry in iter {
+            dict.add(entry);
+        }
+        dict
+    }
+}
+
+impl PrivateDataDictionary for PrivateDictionary {
+    fn by_creator(&self, creator: &str, group: u16, offset: u8) -> Option<&PrivateDictionaryEntry> {
+        self.by_creator
+            .get(&(trim_creator(creator).to_string(), group, offset))
+    }
+
+    fn by_name(&self, creator: &str, name: &str) -> Option<&PrivateDictionaryEntry> {
+        self.by_name
+            .get(&(trim_creator(creator).to_string(), name.to_string()))
+            .and_then(|key| self.by_creator.get(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn private_tag_helpers() {
+        assert!(is_private(Tag(0x0029, 0x1010)));
+        assert!(!is_private(Tag(0x0028, 0x0010)));
+        assert!(is_private_creator(Tag(0x0029, 0x0010)));
+        assert!(!is_private_creator(Tag(0x0029, 0x1010)));
+        assert_eq!(
+            private_creator_of(Tag(0x0029, 0x1108)),
+            Some(Tag(0x0029, 0x0011))
+        );
+        assert_eq!(private_creator_of(Tag(0x0029, 0x0010)), None);
+        assert_eq!(private_tag(0x0029, 0x11, 0x08), Tag(0x0029, 0x1108));
+    }
+
+    #[test]
+    fn private_dictionary_lookup() {
+        let dict: PrivateDictionary = vec![PrivateDictionaryEntry {
+            creator: "SIEMENS CSA HEADER".to_string(),
+            group: 0x0029,
+            offset: 0x08,
+            alias: "CSAImageHeaderType".to_string(),
+            vr: VR::CS,
+        }]
+        .into_iter()
+        .collect();
+
+        let entry = dict
+            .by_creator("SIEMENS CSA HEADER ", 0x0029, 0x08)
+            .expect("entry should exist");
+        assert_eq!(entry.vr, VR::CS);
+        assert_eq!(entry.tag_in_block(0x11), Tag(0x0029, 0x1108));
+        assert!(dict
+            .by_creator("SIEMENS CSA HEADER", 0x0029, 0x09)
+            .is_none());
+        assert!(dict.by_creator("ACME", 0x0029, 0x08).is_none());
+        assert_eq!(
+            dict.by_name("SIEMENS CSA HEADER", "CSAImageHeaderType"),
+            Some(entry)
+        );
+    }
+
+    #[test]
+    fn private_dictionary_replace_entry() {
+        let mut dict = PrivateDictionary::new();
+        let entry = PrivateDictionaryEntry {
+            creator: "ACME".to_string(),
+            group: 0x0029,
+            offset: 0x01,
+            alias: "OldName".to_string(),
+            vr: VR::LO,
+        };
+        assert_eq!(dict.add(entry.clone()), None);
+        let renamed = PrivateDictionaryEntry {
+            alias: "NewName".to_string(),
+            ..entry.clone()
+        };
+        assert_eq!(dict.add(renamed.clone()), Some(entry));
+        assert_eq!(dict.len(), 1);
+        assert_eq!(dict.by_name("ACME", "OldName"), None);
+        assert_eq!(dict.by_name("ACME", "NewName"), Some(&renamed));
+    }
+}
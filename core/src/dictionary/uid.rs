@@ -0,0 +1,152 @@
+//! This module contains the concept of a dictionary of unique identifiers,
+//! as registered in PS3.6 Table A-1.
+
+use std::fmt;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// The type of a registered DICOM unique identifier.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum UidType {
+    /// SOP Class
+    SopClass,
+    /// Meta SOP Class
+    MetaSopClass,
+    /// Transfer Syntax
+    TransferSyntax,
+    /// Well-known SOP Instance
+    WellKnownSopInstance,
+    /// Well-known frame of reference
+    WellKnownFrameOfReference,
+    /// DICOM UIDs as a Coding Scheme
+    DicomUidsAsCodingScheme,
+    /// Coding Scheme
+    CodingScheme,
+    /// Application Context Name
+    ApplicationContextName,
+    /// Service Class
+    ServiceClass,
+    /// Application Hosting Model
+    ApplicationHostingModel,
+    /// Mapping Resource
+    MappingResource,
+    /// LDAP OID
+    LdapOid,
+    /// Synchronization Frame of Reference
+    SynchronizationFrameOfReference,
+    /// Context Group Name
+    ContextGroupName,
+}
+
+impl UidType {
+    /// Obtain the description of this UID type, as written in the standard.
+    pub fn to_str(self) -> &'static str {
+        use UidType::*;
+        match self {
+            SopClass => "SOP Class",
+            MetaSopClass => "Meta SOP Class",
+            TransferSyntax => "Transfer Syntax",
+            WellKnownSopInstance => "Well-known SOP Instance",
+            WellKnownFrameOfReference => "Well-known frame of reference",
+            DicomUidsAsCodingScheme => "DICOM UIDs as a Coding Scheme",
+            CodingScheme => "Coding Scheme",
+            ApplicationContextName => "Application Context Name",
+            ServiceClass => "Service Class",
+            ApplicationHostingModel => "Application Hosting Model",
+            MappingResource => "Mapping Resource",
+            LdapOid => "LDAP OID",
+            SynchronizationFrameOfReference => "Synchronization Frame of Reference",
+            ContextGroupName => "Context Group Name",
+        }
+    }
+}
+
+impl FromStr for UidType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use UidType::*;
+        match s.trim().to_ascii_lowercase().as_str() {
+            "sop class" => Ok(SopClass),
+            "meta sop class" => Ok(MetaSopClass),
+            "transfer syntax" => Ok(TransferSyntax),
+            "well-known sop instance" => Ok(WellKnownSopInstance),
+            "well-known frame of reference" => Ok(WellKnownFrameOfReference),
+            "dicom uids as a coding scheme" => Ok(DicomUidsAsCodingScheme),
+            "coding scheme" => Ok(CodingScheme),
+            "application context name" => Ok(ApplicationContextName),
+            "service class" => Ok(ServiceClass),
+            "application hosting model" => Ok(ApplicationHostingModel),
+            "mapping resource" => Ok(MappingResource),
+            "ldap oid" => Ok(LdapOid),
+            "synchronization frame of reference" => Ok(SynchronizationFrameOfReference),
+            "context group name" => Ok(ContextGroupName),
+            _ => Err("unknown UID type"),
+        }
+    }
+}
+
+impl fmt::Display for UidType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+/** Type trait for a dictionary of registered unique identifiers.
+ *
+ * The methods herein have no generic parameters, so as to enable being
+ * used as a trait object.
+ */
+pub trait UidDictionary: Debug {
+    /// Fetch an entry by its UID value (e.g. "1.2.840.10008.1.1").
+    /// Trailing null or space padding is ignored.
+    fn by_uid(&self, uid: &str) -> Option<&UidDictionaryEntryRef<'_>>;
+
+    /// Fetch an entry by its keyword (e.g. "Verification").
+    fn by_keyword(&self, keyword: &str) -> Option<&UidDictionaryEntryRef<'_>>;
+}
+
+/// A registered unique identifier entry.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UidDictionaryEntryRef<'a> {
+    /// The UID value
+    pub uid: &'a str,
+    /// The name of the UID, without the retired marker
+    pub name: &'a str,
+    /// The keyword of the UID, with no spaces, in UpperCamelCase
+    pub keyword: &'a str,
+    /// The type of the UID
+    pub uid_type: UidType,
+    /// Whether the UID has been retired from the standard
+    pub retired: bool,
+}
+
+/// Normalize a UID value for comparison,
+/// by removing the trailing padding.
+pub fn trim_uid(uid: &str) -> &str {
+    uid.trim_end_matches(&['\0', ' '][..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uid_type_round_trip() {
+        for uid_type in &[
+            UidType::SopClass,
+            UidType::MetaSopClass,
+            UidType::TransferSyntax,
+            UidType::WellKnownSopInstance,
+            UidType::LdapOid,
+        ] {
+            assert_eq!(uid_type.to_str().parse::<UidType>(), Ok(*uid_type));
+        }
+        assert_eq!(
+            "Well-known SOP Instance".parse::<UidType>(),
+            Ok(UidType::WellKnownSopInstance)
+        );
+        assert!("Something Else".parse::<UidType>().is_err());
+        assert_eq!(trim_uid("1.2.840.10008.1.2\0"), "1.2.840.10008.1.2");
+    }
+}
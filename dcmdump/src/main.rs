@@ -12,6 +12,7 @@ use dicom::core::dictionary::{DataDictionary, DictionaryEntry};
 use dicom::core::header::Header;
 use dicom::core::value::{PrimitiveValue, Value as DicomValue};
 use dicom::core::VR;
+use dicom::dictionary_std::StandardUidDictionary;
 use dicom::object::mem::{InMemDicomObject, InMemElement};
 use dicom::object::{open_file, DefaultDicomObject, FileMetaTable, StandardDataDictionary};
 
@@ -58,15 +59,23 @@ where
     writeln!(
         to,
         "Media Storage SOP Class UID: {}",
-        meta.media_storage_sop_class_uid
+        StandardUidDictionary::summary(&meta.media_storage_sop_class_uid)
     )?;
     writeln!(
         to,
         "Media Storage SOP Instance UID: {}",
         meta.media_storage_sop_instance_uid
     )?;
-    writeln!(to, "Transfer Syntax: {}", meta.transfer_syntax)?;
-    writeln!(to, "Implementation Class UID: {}", meta.transfer_syntax)?;
+    writeln!(
+        to,
+        "Transfer Syntax: {}",
+        StandardUidDictionary::summary(&meta.transfer_syntax)
+    )?;
+    writeln!(
+        to,
+        "Implementation Class UID: {}",
+        meta.implementation_class_uid
+    )?;
 
     if let Some(v) = meta.implementation_version_name.as_ref() {
         writeln!(to, "Implementation version name: {}", v)?;
@@ -200,10 +209,19 @@ fn value_summary(value: &PrimitiveValue, vr: VR, max_characters: u32) -> Cow<str
         .into(),
         (U8(values), _) => format_value_list(values, max_characters).into(),
         (Tags(values), _) => format_value_list(values, max_characters).into(),
+        (Strs(values), VR::UI) if values.len() == 1 => {
+            StandardUidDictionary::summary(&values[0]).into()
+        }
+        (Strs(values), VR::UI) => format_value_list(
+            values.iter().map(|uid| StandardUidDictionary::summary(uid)),
+            max_characters,
+        )
+        .into(),
         (Strs(values), _) => format_value_list(values, max_characters).into(),
         (Date(values), _) => format_value_list(values, max_characters).into(),
         (Time(values), _) => format_value_list(values, max_characters).into(),
         (DateTime(values), _) => format_value_list(values, max_characters).into(),
+        (Str(value), VR::UI) => StandardUidDictionary::summary(value).into(),
         (Str(value), _) => cut_str(&value.to_string(), max_characters)
             .into_owned()
             .into(),
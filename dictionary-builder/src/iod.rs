@@ -0,0 +1,484 @@
+//! Reading of the information object definitions in PS3.3,
+//! and generation of the respective module and IOD specifications.
+//!
+//! IOD tables (captioned "... IOD Modules") link each module to the section
+//! describing it, where the module attribute table is found. Macros included
+//! by module attribute tables are expanded in place, so that the generated
+//! modules are self contained.
+
+use crate::{DynResult, XmlResult};
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{create_dir_all, File};
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+/// A body cell of a PS3.3 table.
+#[derive(Debug, Default, Clone)]
+struct Cell {
+    /// the text content of the cell
+    text: String,
+    /// the targets of the cross references in the cell
+    links: Vec<String>,
+    /// terms listed under "Enumerated Values"
+    enumerated: Vec<String>,
+    /// terms listed under "Defined Terms"
+    defined: Vec<String>,
+}
+
+/// A table of PS3.3, with its body rows.
+#[derive(Debug, Default, Clone)]
+struct Table {
+    id: String,
+    /// the `xml:id` of the section containing the table
+    section: String,
+    caption: String,
+    rows: Vec<Vec<Cell>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum TermList {
+    None,
+    Enumerated,
+    Defined,
+}
+
+fn attribute_value(e: &BytesStart, key: &[u8]) -> XmlResult<Option<String>> {
+    for attr in e.attributes() {
+        let attr = attr?;
+        if attr.key == key {
+            return Ok(Some(String::from_utf8_lossy(&attr.value).into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+/// Read all tables in the document.
+fn read_tables<R: BufRead>(xml: R) -> XmlResult<Vec<Table>> {
+    let mut reader = Reader::from_reader(xml);
+    reader.expand_empty_elements(true).trim_text(true);
+    let mut buf = Vec::new();
+
+    let mut sections: Vec<String> = Vec::new();
+    let mut tables = Vec::new();
+    let mut table: Option<Table> = None;
+    let mut in_caption = false;
+    let mut in_body = false;
+    let mut row: Vec<Cell> = Vec::new();
+    let mut cell: Option<Cell> = None;
+    let mut term_list = TermList::None;
+    let mut term: Option<String> = None;
+
+    loop {
+        buf.clear();
+        match reader.read_event(&mut buf)? {
+            Event::Start(ref e) => match e.local_name() {
+                b"section" => {
+                    sections.push(attribute_value(e, b"xml:id")?.unwrap_or_default());
+                }
+                b"table" => {
+                    table = Some(Table {
+                        id: attribute_value(e, b"xml:id")?.unwrap_or_default(),
+                        section: sections.last().cloned().unwrap_or_default(),
+                        ..Default::default()
+                    });
+                }
+                b"caption" if table.is_some() => in_caption = true,
+                b"tbody" if table.is_some() => in_body = true,
+                b"tr" if in_body => row.clear(),
+                b"td" if in_body => {
+                    cell = Some(Cell::default());
+                    term_list = TermList::None;
+                }
+                b"xref" => {
+                    if let Some(cell) = cell.as_mut() {
+                        if let Some(link) = attribute_value(e, b"linkend")? {
+                            cell.links.push(link);
+                        }
+                    }
+                }
+                b"term" if cell.is_some() => term = Some(String::new()),
+                _ => {}
+            },
+            Event::End(ref e) => match e.local_name() {
+                b"section" => {
+                    sections.pop();
+                }
+                b"table" => {
+                    tables.extend(table.take());
+                    in_body = false;
+                }
+                b"caption" => in_caption = false,
+                b"tbody" => in_body = false,
+                b"tr" if in_body => {
+                    if let Some(table) = table.as_mut() {
+                        table.rows.push(std::mem::take(&mut row));
+                    }
+                }
+                b"td" => row.extend(cell.take()),
+                b"term" => {
+                    if let (Some(term), Some(cell)) = (term.take(), cell.as_mut()) {
+                        let term = term.trim().to_string();
+                        match term_list {
+                            TermList::Enumerated => cell.enumerated.push(term),
+                            TermList::Defined => cell.defined.push(term),
+                            TermList::None => {}
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(data) => {
+                let text = data.unescape_and_decode(&reader)?.replace('\u{200b}', "");
+                if let Some(term) = term.as_mut() {
+                    term.push_str(&text);
+                }
+                if let Some(cell) = cell.as_mut() {
+                    if text.contains("Enumerated Value") {
+                        term_list = TermList::Enumerated;
+                    } else if text.contains("Defined Term") {
+                        term_list = TermList::Defined;
+                    }
+                    if !cell.text.is_empty() {
+                        cell.text.push(' ');
+                    }
+                    cell.text.push_str(&text);
+                } else if in_caption {
+                    if let Some(table) = table.as_mut() {
+                        if !table.caption.is_empty() {
+                            table.caption.push(' ');
+                        }
+                        table.caption.push_str(&text);
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(tables)
+}
+
+/// An attribute specification read from a module or macro table.
+#[derive(Debug, Clone, PartialEq)]
+struct AttributeNode {
+    tag: (u16, u16),
+    attribute_type: &'static str,
+    enumerated: Vec<String>,
+    defined: Vec<String>,
+    items: Vec<AttributeNode>,
+}
+
+/// Parse a tag in the form `(gggg,eeee)`.
+/// Tags with repeating portions such as `(60xx,3000)` are not accepted.
+fn parse_tag(text: &str) -> Option<(u16, u16)> {
+    let text = text.trim().trim_start_matches('(').trim_end_matches(')');
+    let mut parts = text.split(',');
+    let group = u16::from_str_radix(parts.next()?.trim(), 16).ok()?;
+    let element = u16::from_str_radix(parts.next()?.trim(), 16).ok()?;
+    Some((group, element))
+}
+
+/// Whether the description refers to a specific value of a multi-valued
+/// attribute (e.g. "Value 3 shall have one of the following Defined Terms").
+fn mentions_specific_value(text: &str) -> bool {
+    text.match_indices("Value ").any(|(i, _)| {
+        text[i + 6..]
+            .chars()
+            .next()
+            .map(|c| c.is_ascii_digit())
+            .unwrap_or(false)
+    })
+}
+
+/// Map the attribute type in PS3.3 to its variant in `AttributeType`.
+fn attribute_type_variant(text: &str) -> Option<&'static str> {
+    match text.trim() {
+        "1" => Some("Type1"),
+        "1C" => Some("Type1C"),
+        "2" => Some("Type2"),
+        "2C" => Some("Type2C"),
+        "3" => Some("Type3"),
+        _ => None,
+    }
+}
+
+/// Insert an attribute at the given sequence nesting depth,
+/// ignoring attributes already declared at that level.
+fn insert_node(nodes: &mut Vec<AttributeNode>, depth: usize, node: AttributeNode) {
+    if depth > 0 {
+        if let Some(parent) = nodes.last_mut() {
+            return insert_node(&mut parent.items, depth - 1, node);
+        }
+    }
+    if nodes.iter().all(|n| n.tag != node.tag) {
+        nodes.push(node);
+    }
+}
+
+/// The collection of PS3.3 tables, indexed for expanding module specifications.
+struct Part3 {
+    tables: Vec<Table>,
+    by_id: HashMap<String, usize>,
+}
+
+impl Part3 {
+    fn new(tables: Vec<Table>) -> Self {
+        let by_id = tables
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.id.clone(), i))
+            .collect();
+        Part3 { tables, by_id }
+    }
+
+    /// Find the module attribute table in the given section.
+    fn module_table(&self, section: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|t| t.section == section && t.caption.ends_with("Module Attributes"))
+    }
+
+    /// Read the attributes of a module or macro table,
+    /// expanding any included macros.
+    fn attributes(&self, table: &Table, visiting: &mut Vec<String>) -> Vec<AttributeNode> {
+        visiting.push(table.id.clone());
+        let mut nodes = Vec::new();
+        for row in &table.rows {
+            let first = match row.first() {
+                Some(cell) => cell,
+                None => continue,
+            };
+            let name = first.text.trim_start();
+            let depth = name.chars().take_while(|c| *c == '>').count();
+            let name = name.trim_start_matches('>').trim_start();
+
+            if name.starts_with("Include") {
+                let included = first
+                    .links
+                    .iter()
+                    .filter_map(|id| self.by_id.get(id))
+                    .map(|i| &self.tables[*i])
+                    .find(|t| !visiting.contains(&t.id));
+                if let Some(included) = included {
+                    for node in self.attributes(included, visiting) {
+                        insert_node(&mut nodes, depth, node);
+                    }
+                }
+                continue;
+            }
+
+            if row.len() < 3 {
+                continue;
+            }
+            let (tag, attribute_type) = match (
+                parse_tag(&row[1].text),
+                attribute_type_variant(&row[2].text),
+            ) {
+                (Some(tag), Some(attribute_type)) => (tag, attribute_type),
+                _ => continue,
+            };
+            let (enumerated, defined) = match row.get(3) {
+                // terms which only apply to specific values are not kept
+                Some(d) if !mentions_specific_value(&d.text) => {
+                    (d.enumerated.clone(), d.defined.clone())
+                }
+                _ => (Vec::new(), Vec::new()),
+            };
+            insert_node(
+                &mut nodes,
+                depth,
+                AttributeNode {
+                    tag,
+                    attribute_type,
+                    enumerated,
+                    defined,
+                    items: Vec::new(),
+                },
+            );
+        }
+        visiting.pop();
+        nodes
+    }
+}
+
+/// A module specification read from PS3.3.
+#[derive(Debug, Clone)]
+struct ModuleEntry {
+    name: String,
+    attributes: Vec<AttributeNode>,
+}
+
+/// An IOD specification read from PS3.3,
+/// with its modules as (module section id, usage variant) pairs.
+#[derive(Debug, Clone)]
+struct IodEntry {
+    name: String,
+    modules: Vec<(String, &'static str)>,
+}
+
+fn module_usage_variant(text: &str) -> Option<&'static str> {
+    match text.trim().chars().next() {
+        Some('M') => Some("Mandatory"),
+        Some('C') => Some("Conditional"),
+        Some('U') => Some("UserOption"),
+        _ => None,
+    }
+}
+
+/// Read the IODs and the modules which they use.
+fn read_iods(part3: &Part3) -> (Vec<IodEntry>, BTreeMap<String, ModuleEntry>) {
+    let mut iods = Vec::new();
+    let mut modules = BTreeMap::new();
+
+    for table in &part3.tables {
+        let name = match table.caption.trim().rfind(" IOD Modules") {
+            Some(i) => table.caption[..i].trim().to_string(),
+            None => continue,
+        };
+        let mut iod_modules = Vec::new();
+        for row in &table.rows {
+            // the information entity cell only appears in the first row
+            // of each entity, so look for the module reference instead
+            let i = match row
+                .iter()
+                .position(|c| c.links.iter().any(|l| l.starts_with("sect_")))
+            {
+                Some(i) => i,
+                None => continue,
+            };
+            let section = row[i]
+                .links
+                .iter()
+                .find(|l| l.starts_with("sect_"))
+                .unwrap();
+            let usage = match row.get(i + 1).and_then(|c| module_usage_variant(&c.text)) {
+                Some(usage) => usage,
+                None => continue,
+            };
+            let module_table = match part3.module_table(section) {
+                Some(t) => t,
+                None => continue,
+            };
+            if !modules.contains_key(section) {
+                let name = module_table
+                    .caption
+                    .trim_end_matches("Module Attributes")
+                    .trim()
+                    .to_string();
+                let attributes = part3.attributes(module_table, &mut Vec::new());
+                modules.insert(section.clone(), ModuleEntry { name, attributes });
+            }
+            iod_modules.push((section.clone(), usage));
+        }
+        iods.push(IodEntry {
+            name,
+            modules: iod_modules,
+        });
+    }
+
+    (iods, modules)
+}
+
+/// Build a constant name from a module name (e.g. "Contrast/Bolus").
+fn module_constant_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 7);
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_uppercase());
+        } else if !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    let mut out = out.trim_matches('_').to_string();
+    out.push_str("_MODULE");
+    out
+}
+
+fn write_attributes<W: Write>(f: &mut W, nodes: &[AttributeNode], depth: usize) -> DynResult<()> {
+    let indent = "    ".repeat(depth + 1);
+    for node in nodes {
+        write!(
+            f,
+            "{}A {{ tag: Tag(0x{:04X}, 0x{:04X}), attribute_type: {}, enumerated_values: &{:?}, defined_terms: &{:?}, items: &[",
+            indent, node.tag.0, node.tag.1, node.attribute_type, node.enumerated, node.defined
+        )?;
+        if node.items.is_empty() {
+            f.write_all(b"] },\n")?;
+        } else {
+            f.write_all(b"\n")?;
+            write_attributes(f, &node.items, depth + 1)?;
+            writeln!(f, "{}] }},", indent)?;
+        }
+    }
+    Ok(())
+}
+
+/// Read the IODs of PS3.3 from the given XML document
+/// and write their specifications as Rust code.
+pub fn to_iod_code_file<P: AsRef<Path>, R: BufRead>(dest_path: P, xml: R) -> DynResult<()> {
+    let tables = read_tables(xml).map_err(|e| format!("Failed to read XML: {:?}", e))?;
+    let part3 = Part3::new(tables);
+    let (iods, modules) = read_iods(&part3);
+
+    if let Some(p_dir) = dest_path.as_ref().parent() {
+        create_dir_all(p_dir)?;
+    }
+    let mut f = File::create(&dest_path)?;
+
+    f.write_all(
+        b"//! Automatically generated. Edit at your own risk.\n\n\
+    use dicom_core::dictionary::iod::{\n    \
+        AttributeSpec, AttributeType::*, IodModuleSpec, IodSpec, ModuleSpec, ModuleUsage::*,\n\
+    };\n\
+    use dicom_core::Tag;\n\n\
+    type A = AttributeSpec<'static>;\n\
+    type M = IodModuleSpec<'static>;\n",
+    )?;
+
+    // module constants are named after the module,
+    // with the section id added in case of a clash
+    let mut constant_names: HashMap<&str, String> = HashMap::new();
+    let mut module_list: Vec<_> = modules.iter().collect();
+    module_list.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+    for (section, module) in &module_list {
+        let mut constant = module_constant_name(&module.name);
+        if constant_names.values().any(|c| *c == constant) {
+            constant = format!(
+                "{}_{}",
+                constant,
+                module_constant_name(section).trim_end_matches("_MODULE")
+            );
+        }
+
+        writeln!(f, "\n/// {} Module", module.name)?;
+        writeln!(f, "#[rustfmt::skip]")?;
+        writeln!(
+            f,
+            "pub const {}: ModuleSpec<'static> = ModuleSpec {{ name: {:?}, attributes: &[",
+            constant, module.name
+        )?;
+        write_attributes(&mut f, &module.attributes, 0)?;
+        f.write_all(b"] };\n")?;
+        constant_names.insert(section.as_str(), constant);
+    }
+
+    f.write_all(b"\n#[rustfmt::skip]\npub const IODS: &[IodSpec<'static>] = &[\n")?;
+    for iod in &iods {
+        writeln!(f, "    IodSpec {{ name: {:?}, modules: &[", iod.name)?;
+        for (section, usage) in &iod.modules {
+            writeln!(
+                f,
+                "        M {{ module: &{}, usage: {} }},",
+                constant_names[section.as_str()],
+                usage
+            )?;
+        }
+        f.write_all(b"    ] },\n")?;
+    }
+    f.write_all(b"];\n")?;
+    Ok(())
+}
@@ -6,7 +6,12 @@
 //!
 //! Simply run the application. It will automatically retrieve the dictionary
 //! from the official DICOM website and store the result in "entries.rs".
-//! Future versions will enable different kinds of outputs.
+//! With `-f tags`, a module of tag constants is built from the same table
+//! and stored in "tags.rs".
+//! With `-t uids`, the registry of unique identifiers (PS3.6 Table A-1)
+//! is built instead, and stored in "uids.rs".
+//! With `-t iods`, the information object definitions and their modules
+//! are built from PS3.3, and stored in "iods.rs".
 //!
 //! Please use the `--help` flag for the full usage information.
 
@@ -32,17 +37,22 @@ use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 use std::str::FromStr;
 
+mod iod;
+
 /// url to PS3.6 XML file
 const DEFAULT_LOCATION: &str =
     "http://dicom.nema.org/medical/dicom/current/source/docbook/part06/part06.xml";
 
+/// url to PS3.3 XML file, used for building the IODs
+const DEFAULT_IOD_LOCATION: &str =
+    "http://dicom.nema.org/medical/dicom/current/source/docbook/part03/part03.xml";
+
 fn main() {
     let matches = App::new("DICOM Dictionary Builder")
         .version("0.1.0")
         .arg(
             Arg::with_name("FROM")
-                .default_value(DEFAULT_LOCATION)
-                .help("Where to fetch the dictionary from"),
+                .help("Where to fetch the dictionary from [default: PS3.6, or PS3.3 for IODs]"),
         )
         .arg(
             Arg::with_name("OUTPUT")
@@ -59,7 +69,19 @@ fn main() {
                 .default_value("rs")
                 .takes_value(true)
                 .possible_value("rs")
-                .possible_value("json"),
+                .possible_value("json")
+                .possible_value("tags"),
+        )
+        .arg(
+            Arg::with_name("TABLE")
+                .short("t")
+                .long("table")
+                .help("The table of the standard to build")
+                .default_value("elements")
+                .takes_value(true)
+                .possible_value("elements")
+                .possible_value("uids")
+                .possible_value("iods"),
         )
         .arg(
             Arg::with_name("no-retired")
@@ -69,31 +91,34 @@ fn main() {
         .get_matches();
 
     let format = matches.value_of("FORMAT").unwrap();
-    let ignore_retired = matches.is_present("no-retired");
-
-    let out_file = matches.value_of("OUTPUT").unwrap_or_else(|| match format {
-        "rs" => "entries.rs",
-        "json" => "entries.json",
+    let table = matches.value_of("TABLE").unwrap();
+    let include_retired = !matches.is_present("no-retired");
+
+    let out_file = matches.value_of("OUTPUT").unwrap_or(match (table, format) {
+        ("uids", "rs") => "uids.rs",
+        ("uids", "json") => "uids.json",
+        ("iods", _) => "iods.rs",
+        (_, "rs") => "entries.rs",
+        (_, "json") => "entries.json",
+        (_, "tags") => "tags.rs",
         _ => "entries",
     });
     let dst = Path::new(out_file);
 
     let mut core = Core::new().unwrap();
 
-    let src = matches.value_of("FROM").unwrap();
+    let src = matches.value_of("FROM").unwrap_or(match table {
+        "iods" => DEFAULT_IOD_LOCATION,
+        _ => DEFAULT_LOCATION,
+    });
     if src.starts_with("http:") || src.starts_with("https:") {
         let src = Uri::from_str(src).unwrap();
         println!("Downloading DICOM dictionary ...");
         let req = xml_from_site(src).and_then(|resp| {
             resp.into_body().concat2().and_then(|body: Chunk| {
-                let xml_entries = XmlEntryIterator::new(&*body).map(|item| item.unwrap());
                 println!("Writing to file ...");
-                match format {
-                    "rs" => to_code_file(dst, xml_entries, !ignore_retired),
-                    "json" => to_json_file(dst, xml_entries),
-                    _ => unreachable!(),
-                }
-                .expect("Failed to write file");
+                write_table(&*body, dst, table, format, include_retired)
+                    .expect("Failed to write file");
                 Ok(())
             })
         });
@@ -102,14 +127,52 @@ fn main() {
         // read from File
         let file = File::open(src).unwrap();
         let file = BufReader::new(file);
-        let xml_entries = XmlEntryIterator::new(file).map(|item| item.unwrap());
+        write_table(file, dst, table, format, include_retired).expect("Failed to write file");
+    }
+}
 
-        match format {
-            "rs" => to_code_file(dst, xml_entries, true),
-            "json" => to_json_file(dst, xml_entries),
-            _ => unreachable!(),
+/// Read the requested table from the given XML document
+/// and write it to `dst` in the given format.
+fn write_table<R: BufRead>(
+    xml: R,
+    dst: &Path,
+    table: &str,
+    format: &str,
+    include_retired: bool,
+) -> DynResult<()> {
+    match table {
+        "iods" => match format {
+            "rs" => iod::to_iod_code_file(dst, xml),
+            _ => Err("IODs can only be built as Rust code".into()),
+        },
+        "uids" => {
+            let xml_uids = xml_uids(xml).map(|item| item.unwrap());
+            match format {
+                "rs" => to_uid_code_file(dst, xml_uids, include_retired),
+                "json" => to_uid_json_file(dst, xml_uids),
+                _ => unreachable!(),
+            }
         }
-        .expect("Failed to write file");
+        _ => match format {
+            "rs" => {
+                let xml_entries = xml_entries(xml, &["table_6-1"]).map(|item| item.unwrap());
+                to_code_file(dst, xml_entries, include_retired)
+            }
+            "json" => {
+                let xml_entries = xml_entries(xml, &["table_6-1"]).map(|item| item.unwrap());
+                to_json_file(dst, xml_entries)
+            }
+            "tags" => {
+                // also include file meta and directory structuring elements
+                let mut xml_entries: Vec<_> =
+                    xml_entries(xml, &["table_6-1", "table_7-1", "table_8-1"])
+                        .map(|item| item.unwrap())
+                        .collect();
+                xml_entries.sort_by(|a, b| a.tag.cmp(&b.tag));
+                to_tags_file(dst, xml_entries, include_retired)
+            }
+            _ => unreachable!(),
+        },
     }
 }
 
@@ -132,199 +195,120 @@ struct Entry {
     obs: Option<String>,
 }
 
+/// An iterator over the body rows of DocBook tables,
+/// identified by their `xml:id` attribute.
+/// Each row is yielded as the list of its cells' text content.
+struct XmlTableIterator<R: BufRead> {
+    parser: Reader<R>,
+    buf: Vec<u8>,
+    table_ids: &'static [&'static str],
+    state: XmlReadingState,
+    row: Vec<String>,
+    cell: String,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum XmlReadingState {
     Off,
     InTableHead,
     InTable,
-    InCellTag,
-    InCellName,
-    InCellKeyword,
-    InCellVR,
-    InCellVM,
-    InCellObs,
-    InCellUnknown,
-}
-
-struct XmlEntryIterator<R: BufRead> {
-    parser: Reader<R>,
-    buf: Vec<u8>,
-    depth: u32,
-    tag: Option<String>,
-    name: Option<String>,
-    keyword: Option<String>,
-    vr: Option<String>,
-    vm: Option<String>,
-    obs: Option<String>,
-    state: XmlReadingState,
+    InCell,
+    Done,
 }
 
-impl<R: BufRead> XmlEntryIterator<R> {
-    pub fn new(xml: R) -> XmlEntryIterator<R> {
+impl<R: BufRead> XmlTableIterator<R> {
+    pub fn new(xml: R, table_ids: &'static [&'static str]) -> XmlTableIterator<R> {
         let mut reader = Reader::from_reader(xml);
         reader.expand_empty_elements(true).trim_text(true);
-        XmlEntryIterator {
+        XmlTableIterator {
             parser: reader,
             buf: Vec::new(),
-            depth: 0,
-            tag: None,
-            name: None,
-            keyword: None,
-            vr: None,
-            vm: None,
-            obs: None,
+            table_ids,
             state: XmlReadingState::Off,
+            row: Vec::new(),
+            cell: String::new(),
         }
     }
 }
 
-impl<R: BufRead> Iterator for XmlEntryIterator<R> {
-    type Item = XmlResult<Entry>;
-    fn next(&mut self) -> Option<XmlResult<Entry>> {
+impl<R: BufRead> Iterator for XmlTableIterator<R> {
+    type Item = XmlResult<Vec<String>>;
+    fn next(&mut self) -> Option<XmlResult<Vec<String>>> {
+        if self.state == XmlReadingState::Done {
+            return None;
+        }
         loop {
             self.buf.clear();
             let res = self.parser.read_event(&mut self.buf);
             match res {
                 Ok(Event::Start(ref e)) => {
-                    self.depth += 1;
                     let local_name = e.local_name();
                     match self.state {
-                        XmlReadingState::Off => {
-                            if local_name == b"table" {
-                                // check for attribute xml:id="table_6-1"
-                                match e.attributes().find(|attr| {
-                                    attr.is_err()
-                                        || attr.as_ref().unwrap()
+                        XmlReadingState::Off if local_name == b"table" => {
+                            // check for attribute xml:id="<table_id>"
+                            let table_ids = self.table_ids;
+                            match e.attributes().find(|attr| {
+                                attr.is_err()
+                                    || table_ids.iter().any(|table_id| {
+                                        attr.as_ref().unwrap()
                                             == &Attribute {
                                                 key: b"xml:id",
-                                                value: Cow::Borrowed(b"table_6-1"),
+                                                value: Cow::Borrowed(table_id.as_bytes()),
                                             }
-                                }) {
-                                    Some(Ok(_)) => {
-                                        // entered the table!
-                                        self.state = XmlReadingState::InTableHead;
-                                    }
-                                    Some(Err(err)) => return Some(Err(err)),
-                                    None => {}
+                                    })
+                            }) {
+                                Some(Ok(_)) => {
+                                    // entered the table!
+                                    self.state = XmlReadingState::InTableHead;
                                 }
+                                Some(Err(err)) => return Some(Err(err)),
+                                None => {}
                             }
                         }
-                        XmlReadingState::InTableHead => {
-                            if local_name == b"tbody" {
-                                self.state = XmlReadingState::InTable;
-                            }
+                        XmlReadingState::InTableHead if local_name == b"tbody" => {
+                            self.state = XmlReadingState::InTable;
                         }
-                        XmlReadingState::InTable => {
-                            if local_name == b"para" {
-                                self.state = XmlReadingState::InCellTag;
-                            }
+                        XmlReadingState::InTable if local_name == b"tr" => {
+                            self.row.clear();
                         }
-                        XmlReadingState::InCellTag => {
-                            if local_name == b"para" {
-                                self.state = XmlReadingState::InCellName;
-                            }
-                        }
-                        XmlReadingState::InCellName => {
-                            if local_name == b"para" {
-                                self.state = XmlReadingState::InCellKeyword;
-                            }
-                        }
-                        XmlReadingState::InCellKeyword => {
-                            if local_name == b"para" {
-                                self.state = XmlReadingState::InCellVR;
-                            }
-                        }
-                        XmlReadingState::InCellVR => {
-                            if local_name == b"para" {
-                                self.state = XmlReadingState::InCellVM;
-                            }
-                        }
-                        XmlReadingState::InCellVM => {
-                            if local_name == b"para" {
-                                self.state = XmlReadingState::InCellObs;
-                            }
-                        }
-                        XmlReadingState::InCellObs => {
-                            if local_name == b"para" {
-                                self.state = XmlReadingState::InCellUnknown;
-                            }
+                        XmlReadingState::InTable if local_name == b"td" => {
+                            self.cell.clear();
+                            self.state = XmlReadingState::InCell;
                         }
                         _ => {}
                     }
                 }
                 Ok(Event::End(ref e)) => {
-                    self.depth -= 1;
                     let local_name = e.local_name();
                     match self.state {
-                        XmlReadingState::Off => {
-                            // do nothing
+                        XmlReadingState::InCell if local_name == b"td" => {
+                            self.row.push(self.cell.trim().to_string());
+                            self.state = XmlReadingState::InTable;
                         }
-                        _e => {
-                            if local_name == b"tr" && self.tag.is_some() {
-                                let tag = self.tag.take().unwrap();
-                                let out = Entry {
-                                    tag,
-                                    name: self.name.take(),
-                                    alias: self.keyword.take(),
-                                    vr: self.vr.take(),
-                                    vm: self.vm.take(),
-                                    obs: self.obs.take(),
-                                };
-                                self.state = XmlReadingState::InTable;
-                                return Some(Ok(out));
-                            } else if local_name == b"tbody" {
-                                // the table ended!
-                                break;
-                            }
+                        XmlReadingState::InTable if local_name == b"tr" => {
+                            return Some(Ok(std::mem::take(&mut self.row)));
                         }
+                        XmlReadingState::InTable if local_name == b"tbody" => {
+                            // the table ended, look for the next one
+                            self.state = XmlReadingState::Off;
+                        }
+                        _ => {}
                     }
                 }
-                Ok(Event::Text(data)) => match self.state {
-                    XmlReadingState::InCellTag => {
-                        let data = data
-                            .unescape_and_decode(&self.parser)
-                            .unwrap()
-                            .replace("\u{200b}", "");
-                        self.tag = Some(data);
-                    }
-                    XmlReadingState::InCellName => {
-                        let data = data
-                            .unescape_and_decode(&self.parser)
-                            .unwrap()
-                            .replace("\u{200b}", "");
-                        self.name = Some(data);
-                    }
-                    XmlReadingState::InCellKeyword => {
-                        let data = data
-                            .unescape_and_decode(&self.parser)
-                            .unwrap()
-                            .replace("\u{200b}", "");
-                        self.keyword = Some(data);
-                    }
-                    XmlReadingState::InCellVR => {
-                        let data = data
-                            .unescape_and_decode(&self.parser)
-                            .unwrap()
-                            .replace("\u{200b}", "");
-                        self.vr = Some(data);
-                    }
-                    XmlReadingState::InCellVM => {
-                        let data = data
-                            .unescape_and_decode(&self.parser)
-                            .unwrap()
-                            .replace("\u{200b}", "");
-                        self.vm = Some(data);
-                    }
-                    XmlReadingState::InCellObs => {
-                        let data = data
-                            .unescape_and_decode(&self.parser)
-                            .unwrap()
-                            .replace("\u{200b}", "");
-                        self.obs = Some(data);
+                Ok(Event::Text(data)) => {
+                    if self.state == XmlReadingState::InCell {
+                        let data = match data.unescape_and_decode(&self.parser) {
+                            Ok(data) => data.replace("\u{200b}", ""),
+                            Err(e) => return Some(Err(e)),
+                        };
+                        if !self.cell.is_empty() {
+                            self.cell.push(' ');
+                        }
+                        self.cell.push_str(&data);
                     }
-                    _ => {}
-                },
+                }
                 Ok(Event::Eof { .. }) => {
+                    self.state = XmlReadingState::Done;
                     break;
                 }
                 Ok(_) => {}
@@ -338,6 +322,65 @@ impl<R: BufRead> Iterator for XmlEntryIterator<R> {
     }
 }
 
+/// Convert an empty cell into `None`.
+fn non_empty(cell: Option<String>) -> Option<String> {
+    cell.filter(|c| !c.is_empty())
+}
+
+/// Iterate over the data element entries in the given tables of PS3.6,
+/// such as Table 6-1 (Registry of DICOM Data Elements).
+fn xml_entries<R: BufRead>(
+    xml: R,
+    table_ids: &'static [&'static str],
+) -> impl Iterator<Item = XmlResult<Entry>> {
+    XmlTableIterator::new(xml, table_ids).filter_map(|row| {
+        row.map(|row| {
+            let mut cells = row.into_iter();
+            let tag = non_empty(cells.next())?;
+            Some(Entry {
+                tag,
+                name: non_empty(cells.next()),
+                alias: non_empty(cells.next()),
+                vr: non_empty(cells.next()),
+                vm: non_empty(cells.next()),
+                obs: non_empty(cells.next()),
+            })
+        })
+        .transpose()
+    })
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Clone, Serialize)]
+struct UidEntry {
+    uid: String,
+    name: String,
+    keyword: Option<String>,
+    #[serde(rename = "type")]
+    uid_type: String,
+    part: Option<String>,
+}
+
+/// Iterate over the unique identifier entries in PS3.6 Table A-1.
+fn xml_uids<R: BufRead>(xml: R) -> impl Iterator<Item = XmlResult<UidEntry>> {
+    XmlTableIterator::new(xml, &["table_A-1"]).filter_map(|row| {
+        row.map(|row| {
+            let mut cells = row.into_iter();
+            let uid = non_empty(cells.next())?;
+            let name = non_empty(cells.next())?;
+            let keyword = non_empty(cells.next());
+            let uid_type = non_empty(cells.next())?;
+            Some(UidEntry {
+                uid,
+                name,
+                keyword,
+                uid_type,
+                part: non_empty(cells.next()),
+            })
+        })
+        .transpose()
+    })
+}
+
 fn to_code_file<P: AsRef<Path>, I>(dest_path: P, entries: I, include_retired: bool) -> DynResult<()>
 where
     I: IntoIterator<Item = Entry>,
@@ -364,10 +407,11 @@ where
     for e in entries {
         let Entry {
             tag,
+            name,
             alias,
             vr,
+            vm,
             obs,
-            ..
         } = e;
 
         // sanitize components
@@ -378,12 +422,12 @@ where
             continue;
         };
 
-        if let Some(ref s) = obs {
-            if s == "RET" && !include_retired {
-                // don't include retired attributes
-                continue;
-            }
+        let retired = obs.as_ref().map(|s| s.starts_with("RET")).unwrap_or(false);
+        if retired && !include_retired {
+            // don't include retired attributes
+            continue;
         }
+
         let cap = regex_tag.captures(tag.as_str());
         let tag_txt = if let Some(cap) = cap {
             // single tag
@@ -407,19 +451,22 @@ where
             continue;
         };
 
-        let mut vr = vr.unwrap_or_else(|| "".into());
-        if vr == "See Note" {
-            vr = "UN See Note".to_string();
-        }
-
-        let (vr1, vr2) = vr.split_at(2);
+        let name = name.unwrap_or_else(|| alias.clone());
 
-        let second_vr = if vr2 != "" {
-            format!(" /*{} */", vr2)
+        // the VR column may list alternatives, as in "US or SS or OW",
+        // or refer to a note (items and delimiters), which maps to UN
+        let vr = vr.unwrap_or_else(String::new);
+        let (vrs_txt, vr_note) = if vr.starts_with("See Note") || vr.is_empty() {
+            ("UN".to_string(), format!(" /* {} */", vr))
         } else {
-            vr2.to_string()
+            (
+                vr.split(" or ").collect::<Vec<_>>().join(", "),
+                String::new(),
+            )
         };
 
+        let vm = vm.unwrap_or_else(String::new);
+
         let mut obs = obs.unwrap_or_else(String::new);
         if obs != "" {
             obs = format!(" // {}", obs.as_str());
@@ -427,14 +474,236 @@ where
 
         writeln!(
             f,
-            "    E {{ tag: {}, alias: \"{}\", vr: {}{} }},{}",
-            tag_txt, alias, vr1, second_vr, obs
+            "    E {{ tag: {}, alias: {:?}, name: {:?}, vrs: &[{}]{}, vm: {:?}, retired: {} }},{}",
+            tag_txt, alias, name, vrs_txt, vr_note, vm, retired, obs
         )?;
     }
     f.write_all(b"];\n")?;
     Ok(())
 }
 
+/// Convert an attribute keyword in UpperCamelCase
+/// into the name of a constant in SCREAMING_SNAKE_CASE,
+/// keeping acronyms together (e.g. `SOPInstanceUID` → `SOP_INSTANCE_UID`,
+/// `SourceImageIDs` → `SOURCE_IMAGE_IDS`).
+fn to_constant_name(alias: &str) -> String {
+    // mixed case acronyms which would otherwise be split apart
+    let alias = MIXED_CASE_ACRONYMS
+        .iter()
+        .fold(alias.to_string(), |alias, (acronym, name)| {
+            alias.replace(acronym, name)
+        });
+    let chars: Vec<char> = alias.chars().collect();
+    let mut out = String::with_capacity(alias.len() + 8);
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_ascii_uppercase() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars
+                .get(i + 1)
+                .map(|c| c.is_ascii_lowercase())
+                .unwrap_or(false);
+            // plural acronyms such as in `SourceImageIDs` are kept together
+            let is_plural = chars.get(i + 1) == Some(&'s')
+                && chars
+                    .get(i + 2)
+                    .map(|c| !c.is_ascii_lowercase())
+                    .unwrap_or(true);
+            if prev.is_ascii_lowercase()
+                || (prev.is_ascii_uppercase() && next_is_lower && !is_plural)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+    out
+}
+
+/// Acronyms in keywords which are not entirely in upper case,
+/// and the upper case form to use in constant names.
+const MIXED_CASE_ACRONYMS: &[(&str, &str)] = &[("CTDIvol", "CTDIVOL"), ("DBDt", "DBDT")];
+
+/// The command elements of PS3.7 Annex E,
+/// which are not part of the registry in PS3.6
+/// but are emitted to the tag constants all the same.
+const COMMAND_ELEMENTS: &[(&str, &str, &str)] = &[
+    (
+        "(0000,0000)",
+        "Command Group Length",
+        "COMMAND_GROUP_LENGTH",
+    ),
+    (
+        "(0000,0002)",
+        "Affected SOP Class UID",
+        "AFFECTED_SOP_CLASS_UID",
+    ),
+    (
+        "(0000,0003)",
+        "Requested SOP Class UID",
+        "REQUESTED_SOP_CLASS_UID",
+    ),
+    ("(0000,0100)", "Command Field", "COMMAND_FIELD"),
+    ("(0000,0110)", "Message ID", "MESSAGE_ID"),
+    (
+        "(0000,0120)",
+        "Message ID Being Responded To",
+        "MESSAGE_ID_BEING_RESPONDED_TO",
+    ),
+    ("(0000,0600)", "Move Destination", "MOVE_DESTINATION"),
+    ("(0000,0700)", "Priority", "PRIORITY"),
+    (
+        "(0000,0800)",
+        "Command Data Set Type",
+        "COMMAND_DATA_SET_TYPE",
+    ),
+    ("(0000,0900)", "Status", "STATUS"),
+    ("(0000,0901)", "Offending Element", "OFFENDING_ELEMENT"),
+    ("(0000,0902)", "Error Comment", "ERROR_COMMENT"),
+    ("(0000,0903)", "Error ID", "ERROR_ID"),
+    (
+        "(0000,1000)",
+        "Affected SOP Instance UID",
+        "AFFECTED_SOP_INSTANCE_UID",
+    ),
+    (
+        "(0000,1001)",
+        "Requested SOP Instance UID",
+        "REQUESTED_SOP_INSTANCE_UID",
+    ),
+    ("(0000,1002)", "Event Type ID", "EVENT_TYPE_ID"),
+    (
+        "(0000,1005)",
+        "Attribute Identifier List",
+        "ATTRIBUTE_IDENTIFIER_LIST",
+    ),
+    ("(0000,1008)", "Action Type ID", "ACTION_TYPE_ID"),
+    (
+        "(0000,1020)",
+        "Number of Remaining Sub-operations",
+        "NUMBER_OF_REMAINING_SUBOPERATIONS",
+    ),
+    (
+        "(0000,1021)",
+        "Number of Completed Sub-operations",
+        "NUMBER_OF_COMPLETED_SUBOPERATIONS",
+    ),
+    (
+        "(0000,1022)",
+        "Number of Failed Sub-operations",
+        "NUMBER_OF_FAILED_SUBOPERATIONS",
+    ),
+    (
+        "(0000,1023)",
+        "Number of Warning Sub-operations",
+        "NUMBER_OF_WARNING_SUBOPERATIONS",
+    ),
+    (
+        "(0000,1030)",
+        "Move Originator Application Entity Title",
+        "MOVE_ORIGINATOR_APPLICATION_ENTITY_TITLE",
+    ),
+    (
+        "(0000,1031)",
+        "Move Originator Message ID",
+        "MOVE_ORIGINATOR_MESSAGE_ID",
+    ),
+];
+
+fn to_tags_file<P: AsRef<Path>, I>(dest_path: P, entries: I, include_retired: bool) -> DynResult<()>
+where
+    I: IntoIterator<Item = Entry>,
+{
+    if let Some(p_dir) = dest_path.as_ref().parent() {
+        create_dir_all(p_dir)?;
+    }
+    let mut f = File::create(&dest_path)?;
+
+    f.write_all(
+        b"//! Automatically generated. Edit at your own risk.\n\
+    //!\n\
+    //! Constants for the tags of all attributes in the standard dictionary.\n\
+    //! Attributes in a repeating group `(ggxx,eeee)` or with a repeating\n\
+    //! element `(gggg,eexx)` are declared with the `xx` portion zeroed.\n\
+    //! Use [`repeating_group_base`] or [`repeating_element_base`]\n\
+    //! before comparing a tag against these constants.\n\n\
+    use dicom_core::Tag;\n\n\
+    /// Mask the `xx` portion of a tag in a repeating group `(ggxx,eeee)`,\n\
+    /// such as those of overlays and curves.\n\
+    #[inline]\n\
+    pub const fn repeating_group_base(tag: Tag) -> Tag {\n\
+    \x20   Tag(tag.0 & 0xFF00, tag.1)\n\
+    }\n\n\
+    /// Mask the `xx` portion of a tag with a repeating element `(gggg,eexx)`.\n\
+    #[inline]\n\
+    pub const fn repeating_element_base(tag: Tag) -> Tag {\n\
+    \x20   Tag(tag.0, tag.1 & 0xFF00)\n\
+    }\n\n",
+    )?;
+
+    for (tag, name, const_name) in COMMAND_ELEMENTS {
+        writeln!(
+            f,
+            "/// {} {}\npub const {}: Tag = Tag(0x0000, 0x{});",
+            tag,
+            name,
+            const_name,
+            &tag[6..10]
+        )?;
+    }
+
+    let regex_tag = Regex::new(r"^\(([0-9A-F]{4}),([0-9A-F]{4})\)$")?;
+    let regex_tag_group100 = Regex::new(r"^\(([0-9A-F]{2})xx,([0-9A-F]{4})\)$")?;
+    let regex_tag_element100 = Regex::new(r"^\(([0-9A-F]{4}),([0-9A-F]{2})xx\)$")?;
+
+    let mut names = std::collections::HashSet::new();
+
+    for e in entries {
+        let Entry {
+            tag,
+            name,
+            alias,
+            obs,
+            ..
+        } = e;
+
+        let alias = if let Some(v) = alias {
+            v
+        } else {
+            continue;
+        };
+
+        let retired = obs.as_ref().map(|s| s.starts_with("RET")).unwrap_or(false);
+        if retired && !include_retired {
+            continue;
+        }
+
+        let tag_txt = if let Some(cap) = regex_tag.captures(tag.as_str()) {
+            format!("Tag(0x{}, 0x{})", &cap[1], &cap[2])
+        } else if let Some(cap) = regex_tag_group100.captures(tag.as_str()) {
+            format!("Tag(0x{}00, 0x{})", &cap[1], &cap[2])
+        } else if let Some(cap) = regex_tag_element100.captures(tag.as_str()) {
+            format!("Tag(0x{}, 0x{}00)", &cap[1], &cap[2])
+        } else {
+            continue;
+        };
+
+        let const_name = to_constant_name(&alias);
+        if !names.insert(const_name.clone()) {
+            // a keyword can only be declared once
+            continue;
+        }
+
+        let name = name.unwrap_or_else(|| alias.clone());
+        let retired_txt = if retired { " (retired)" } else { "" };
+        writeln!(
+            f,
+            "/// {} {}{}\npub const {}: Tag = {};",
+            tag, name, retired_txt, const_name, tag_txt
+        )?;
+    }
+    Ok(())
+}
+
 fn to_json_file<P: AsRef<Path>, I>(dest_path: P, entries: I) -> DynResult<()>
 where
     I: IntoIterator<Item = Entry>,
@@ -450,3 +719,97 @@ where
     to_writer(f, &entries)?;
     Ok(())
 }
+
+/// Map the text of a UID type in PS3.6 to its variant in `UidType`.
+fn uid_type_variant(uid_type: &str) -> Option<&'static str> {
+    match uid_type.trim().to_ascii_lowercase().as_str() {
+        "sop class" => Some("SopClass"),
+        "meta sop class" => Some("MetaSopClass"),
+        "transfer syntax" => Some("TransferSyntax"),
+        "well-known sop instance" => Some("WellKnownSopInstance"),
+        "well-known frame of reference" => Some("WellKnownFrameOfReference"),
+        "dicom uids as a coding scheme" => Some("DicomUidsAsCodingScheme"),
+        "coding scheme" => Some("CodingScheme"),
+        "application context name" => Some("ApplicationContextName"),
+        "service class" => Some("ServiceClass"),
+        "application hosting model" => Some("ApplicationHostingModel"),
+        "mapping resource" => Some("MappingResource"),
+        "ldap oid" => Some("LdapOid"),
+        "synchronization frame of reference" => Some("SynchronizationFrameOfReference"),
+        "context group name" => Some("ContextGroupName"),
+        _ => None,
+    }
+}
+
+fn to_uid_code_file<P: AsRef<Path>, I>(
+    dest_path: P,
+    entries: I,
+    include_retired: bool,
+) -> DynResult<()>
+where
+    I: IntoIterator<Item = UidEntry>,
+{
+    if let Some(p_dir) = dest_path.as_ref().parent() {
+        create_dir_all(p_dir)?;
+    }
+    let mut f = File::create(&dest_path)?;
+
+    f.write_all(
+        b"//! Automatically generated. Edit at your own risk.\n\n\
+    use dicom_core::dictionary::uid::{UidDictionaryEntryRef, UidType::*};\n\n\
+    type U = UidDictionaryEntryRef<'static>;\n\n\
+    #[rustfmt::skip]\n\
+    pub const UIDS: &[U] = &[\n",
+    )?;
+
+    for e in entries {
+        let UidEntry {
+            uid,
+            name,
+            keyword,
+            uid_type,
+            ..
+        } = e;
+
+        let uid_type = if let Some(v) = uid_type_variant(&uid_type) {
+            v
+        } else {
+            continue;
+        };
+
+        // retired UIDs are marked as such in their name
+        let (name, retired) = match name.find("(Retired)") {
+            Some(i) => (name[..i].trim().to_string(), true),
+            None => (name, false),
+        };
+        if retired && !include_retired {
+            continue;
+        }
+
+        let keyword = keyword.unwrap_or_else(String::new);
+
+        writeln!(
+            f,
+            "    U {{ uid: {:?}, name: {:?}, keyword: {:?}, uid_type: {}, retired: {} }},",
+            uid, name, keyword, uid_type, retired
+        )?;
+    }
+    f.write_all(b"];\n")?;
+    Ok(())
+}
+
+fn to_uid_json_file<P: AsRef<Path>, I>(dest_path: P, entries: I) -> DynResult<()>
+where
+    I: IntoIterator<Item = UidEntry>,
+{
+    if let Some(p_dir) = dest_path.as_ref().parent() {
+        create_dir_all(p_dir)?;
+    }
+    let f = File::create(&dest_path)?;
+
+    let entries: BTreeMap<String, UidEntry> =
+        entries.into_iter().map(|v| (v.uid.clone(), v)).collect();
+
+    to_writer(f, &entries)?;
+    Ok(())
+}
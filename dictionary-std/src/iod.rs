@@ -0,0 +1,149 @@
+//! This module implements the standard information object definitions,
+//! as in DICOM PS3.3.
+//!
+//! Each IOD is associated to the storage SOP classes in the UID registry
+//! whose name starts with the name of the IOD
+//! (e.g. "CT Image Storage" for the CT Image IOD).
+//!
+//! Only the CT Image, MR Image and Secondary Capture Image IODs
+//! are included for now, so the SOP classes of any other IOD are not found.
+
+pub use crate::iods::*;
+use crate::uids::UIDS;
+use dicom_core::dictionary::iod::{IodDictionary, IodSpec};
+use dicom_core::dictionary::uid::{trim_uid, UidType};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+lazy_static! {
+    static ref IOD_DICT: StandardIodRegistry = init_iod_dictionary();
+}
+
+/// Retrieve a singleton instance of the standard IOD registry.
+pub fn iod_registry() -> &'static StandardIodRegistry {
+    &IOD_DICT
+}
+
+/// The data struct containing the standard IOD registry.
+#[derive(Debug)]
+pub struct StandardIodRegistry {
+    /// mapping: SOP class UID → IOD
+    by_sop_class: HashMap<&'static str, &'static IodSpec<'static>>,
+    /// mapping: name → IOD
+    by_name: HashMap<&'static str, &'static IodSpec<'static>>,
+}
+
+/// An IOD dictionary which consults the library's global IOD registry.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StandardIodDictionary;
+
+impl StandardIodDictionary {
+    /// Fetch the IOD of the given SOP class UID,
+    /// ignoring any trailing padding.
+    pub fn by_sop_class(uid: &str) -> Option<&'static IodSpec<'static>> {
+        iod_registry().by_sop_class.get(trim_uid(uid)).cloned()
+    }
+
+    /// Fetch an IOD by its name (e.g. "CT Image").
+    pub fn by_name(name: &str) -> Option<&'static IodSpec<'static>> {
+        iod_registry().by_name.get(name).cloned()
+    }
+}
+
+impl IodDictionary for StandardIodDictionary {
+    fn by_sop_class(&self, uid: &str) -> Option<&IodSpec<'_>> {
+        StandardIodDictionary::by_sop_class(uid)
+    }
+
+    fn by_name(&self, name: &str) -> Option<&IodSpec<'_>> {
+        StandardIodDictionary::by_name(name)
+    }
+}
+
+impl Display for StandardIodDictionary {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("Standard DICOM IOD Registry")
+    }
+}
+
+fn init_iod_dictionary() -> StandardIodRegistry {
+    let by_name: HashMap<_, _> = IODS.iter().map(|iod| (iod.name, iod)).collect();
+    let mut by_sop_class = HashMap::new();
+    for entry in UIDS {
+        if entry.uid_type != UidType::SopClass {
+            continue;
+        }
+        // pick the longest IOD name, so that "Enhanced CT Image Storage"
+        // is never attributed to a shorter IOD name
+        let iod = IODS
+            .iter()
+            .filter(|iod| {
+                entry.name.starts_with(iod.name)
+                    && entry.name[iod.name.len()..].starts_with(" Storage")
+            })
+            .max_by_key(|iod| iod.name.len());
+        if let Some(iod) = iod {
+            by_sop_class.insert(entry.uid, iod);
+        }
+    }
+    StandardIodRegistry {
+        by_sop_class,
+        by_name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StandardIodDictionary;
+    use crate::tags;
+    use dicom_core::dictionary::iod::{AttributeType, ModuleUsage};
+
+    #[test]
+    fn iod_lookup() {
+        let ct = StandardIodDictionary::by_sop_class("1.2.840.10008.5.1.4.1.1.2\0")
+            .expect("CT Image IOD should exist");
+        assert_eq!(ct.name, "CT Image");
+        let patient = ct
+            .modules
+            .iter()
+            .find(|m| m.module.name == "Patient")
+            .unwrap();
+        assert_eq!(patient.usage, ModuleUsage::Mandatory);
+        let sex = patient
+            .module
+            .attributes
+            .iter()
+            .find(|a| a.tag == tags::PATIENT_SEX)
+            .unwrap();
+        assert_eq!(sex.attribute_type, AttributeType::Type2);
+        assert_eq!(sex.enumerated_values, &["M", "F", "O"]);
+
+        // macros are expanded into the sequence items
+        let sop_common = ct
+            .modules
+            .iter()
+            .find(|m| m.module.name == "SOP Common")
+            .unwrap();
+        let equipment = sop_common
+            .module
+            .attributes
+            .iter()
+            .find(|a| a.tag == tags::CONTRIBUTING_EQUIPMENT_SEQUENCE)
+            .unwrap();
+        let purpose = &equipment.items[0];
+        assert_eq!(purpose.tag, tags::PURPOSE_OF_REFERENCE_CODE_SEQUENCE);
+        assert!(purpose.items.iter().any(|a| a.tag == tags::CODE_MEANING));
+
+        let sc = StandardIodDictionary::by_name("Secondary Capture Image").unwrap();
+        assert_eq!(
+            StandardIodDictionary::by_sop_class("1.2.840.10008.5.1.4.1.1.7"),
+            Some(sc)
+        );
+        assert_eq!(
+            StandardIodDictionary::by_sop_class("1.2.840.10008.1.1"),
+            None
+        );
+    }
+}
@@ -0,0 +1,416 @@
+//! A subset of the output of `dictionary-builder -t iods`,
+//! limited to the CT Image, MR Image and Secondary Capture Image IODs
+//! and the modules which they use, maintained by hand.
+//!
+//! Run `dictionary-builder -t iods -o dictionary-std/src/iods.rs`
+//! on PS3.3 to replace it with the information object definitions
+//! of the whole standard.
+
+use dicom_core::dictionary::iod::{
+    AttributeSpec, AttributeType::*, IodModuleSpec, IodSpec, ModuleSpec, ModuleUsage::*,
+};
+use dicom_core::Tag;
+
+type A = AttributeSpec<'static>;
+type M = IodModuleSpec<'static>;
+
+/// CT Image Module
+#[rustfmt::skip]
+pub const CT_IMAGE_MODULE: ModuleSpec<'static> = ModuleSpec { name: "CT Image", attributes: &[
+    A { tag: Tag(0x0008, 0x0008), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0002), attribute_type: Type1, enumerated_values: &["1"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0004), attribute_type: Type1, enumerated_values: &["MONOCHROME1", "MONOCHROME2"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0100), attribute_type: Type1, enumerated_values: &["16"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0101), attribute_type: Type1, enumerated_values: &["12", "13", "14", "15", "16"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0102), attribute_type: Type1, enumerated_values: &["11", "12", "13", "14", "15"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1052), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1053), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1054), attribute_type: Type1C, enumerated_values: &[], defined_terms: &["HU", "US", "MGML", "Z_EFF", "ED", "EDW", "HU_MOD", "PCT"], items: &[] },
+    A { tag: Tag(0x0018, 0x0060), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0012), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0022), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0090), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1100), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1110), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1111), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1120), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1130), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1140), attribute_type: Type3, enumerated_values: &["CW", "CC"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1150), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1151), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1152), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1160), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1170), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1190), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1210), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x9305), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x9306), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x9307), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x9309), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x9310), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x9311), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x9345), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// Contrast/Bolus Module
+#[rustfmt::skip]
+pub const CONTRAST_BOLUS_MODULE: ModuleSpec<'static> = ModuleSpec { name: "Contrast/Bolus", attributes: &[
+    A { tag: Tag(0x0018, 0x0010), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0012), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0008, 0x0100), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0102), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0103), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0104), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0119), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0120), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+    A { tag: Tag(0x0018, 0x1040), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1041), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1042), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1043), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1044), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1048), attribute_type: Type3, enumerated_values: &[], defined_terms: &["IODINE", "GADOLINIUM", "CARBON DIOXIDE", "BARIUM"], items: &[] },
+    A { tag: Tag(0x0018, 0x1049), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// Frame of Reference Module
+#[rustfmt::skip]
+pub const FRAME_OF_REFERENCE_MODULE: ModuleSpec<'static> = ModuleSpec { name: "Frame of Reference", attributes: &[
+    A { tag: Tag(0x0020, 0x0052), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x1040), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// General Equipment Module
+#[rustfmt::skip]
+pub const GENERAL_EQUIPMENT_MODULE: ModuleSpec<'static> = ModuleSpec { name: "General Equipment", attributes: &[
+    A { tag: Tag(0x0008, 0x0070), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0080), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0081), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1010), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1040), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1090), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1000), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1020), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1050), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0120), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// General Image Module
+#[rustfmt::skip]
+pub const GENERAL_IMAGE_MODULE: ModuleSpec<'static> = ModuleSpec { name: "General Image", attributes: &[
+    A { tag: Tag(0x0020, 0x0013), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0020), attribute_type: Type2C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0023), attribute_type: Type2C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0033), attribute_type: Type2C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0008), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0012), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0022), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0032), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x2111), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x2112), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0008, 0x1150), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x1155), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x1160), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0062, 0x000B), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+    A { tag: Tag(0x0020, 0x1002), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x4000), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0300), attribute_type: Type3, enumerated_values: &["YES", "NO", "BOTH"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0301), attribute_type: Type3, enumerated_values: &["YES", "NO"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0302), attribute_type: Type3, enumerated_values: &["YES", "NO"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x2110), attribute_type: Type3, enumerated_values: &["00", "01"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x2112), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x2114), attribute_type: Type3, enumerated_values: &[], defined_terms: &["ISO_10918_1", "ISO_14495_1", "ISO_15444_1", "ISO_15444_15", "ISO_18181_1", "ISO_13818_2", "ISO_14496_10", "ISO_23008_2"], items: &[] },
+    A { tag: Tag(0x2050, 0x0020), attribute_type: Type3, enumerated_values: &["IDENTITY", "INVERSE"], defined_terms: &[], items: &[] },
+] };
+
+/// General Series Module
+#[rustfmt::skip]
+pub const GENERAL_SERIES_MODULE: ModuleSpec<'static> = ModuleSpec { name: "General Series", attributes: &[
+    A { tag: Tag(0x0008, 0x0060), attribute_type: Type1, enumerated_values: &[], defined_terms: &["ANN", "AR", "ASMT", "AU", "BDUS", "BI", "BMD", "CR", "CT", "CTPROTOCOL", "DG", "DOC", "DX", "ECG", "EPS", "ES", "FID", "GM", "HC", "HD", "IO", "IOL", "IVOCT", "IVUS", "KER", "KO", "LEN", "LS", "MG", "MR", "M3D", "NM", "OAM", "OCT", "OP", "OPM", "OPT", "OPTBSV", "OPTENF", "OPV", "OSS", "OT", "PLAN", "PR", "PT", "PX", "REG", "RESP", "RF", "RG", "RTDOSE", "RTIMAGE", "RTINTENT", "RTPLAN", "RTRAD", "RTRECORD", "RTSEGANN", "RTSTRUCT", "RWV", "SEG", "SM", "SMR", "SR", "SRF", "STAIN", "TEXTUREMAP", "TG", "US", "VA", "XA", "XC"], items: &[] },
+    A { tag: Tag(0x0020, 0x000E), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0011), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0060), attribute_type: Type2C, enumerated_values: &["R", "L"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0021), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0031), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1050), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1030), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x103E), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1070), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1111), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0008, 0x1150), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x1155), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+    A { tag: Tag(0x0018, 0x0015), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x5100), attribute_type: Type2C, enumerated_values: &[], defined_terms: &["HFP", "HFS", "HFDR", "HFDL", "FFDR", "FFDL", "FFP", "FFS", "LFP", "LFS", "RFP", "RFS", "AFDR", "AFDL", "PFDR", "PFDL"], items: &[] },
+    A { tag: Tag(0x0028, 0x0108), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0109), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// General Study Module
+#[rustfmt::skip]
+pub const GENERAL_STUDY_MODULE: ModuleSpec<'static> = ModuleSpec { name: "General Study", attributes: &[
+    A { tag: Tag(0x0020, 0x000D), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0020), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0030), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0090), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0010), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0050), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1030), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1032), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0008, 0x0100), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0102), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0103), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0104), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0119), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0120), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+    A { tag: Tag(0x0008, 0x1110), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0008, 0x1150), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x1155), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+] };
+
+/// Image Pixel Module
+#[rustfmt::skip]
+pub const IMAGE_PIXEL_MODULE: ModuleSpec<'static> = ModuleSpec { name: "Image Pixel", attributes: &[
+    A { tag: Tag(0x0028, 0x0002), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0004), attribute_type: Type1, enumerated_values: &[], defined_terms: &["MONOCHROME1", "MONOCHROME2", "PALETTE COLOR", "RGB", "YBR_FULL", "YBR_FULL_422", "YBR_PARTIAL_422", "YBR_PARTIAL_420", "YBR_ICT", "YBR_RCT", "XYB"], items: &[] },
+    A { tag: Tag(0x0028, 0x0010), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0011), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0100), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0101), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0102), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0103), attribute_type: Type1, enumerated_values: &["0000H", "0001H"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0006), attribute_type: Type1C, enumerated_values: &["0", "1"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0034), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0106), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0107), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1101), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1102), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1103), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1201), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1202), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1203), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x2000), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x2002), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x7FE0, 0x0010), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x7FE0), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0121), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x7FE0, 0x0001), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x7FE0, 0x0002), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// Image Plane Module
+#[rustfmt::skip]
+pub const IMAGE_PLANE_MODULE: ModuleSpec<'static> = ModuleSpec { name: "Image Plane", attributes: &[
+    A { tag: Tag(0x0028, 0x0030), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0037), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x0032), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0050), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0088), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0020, 0x1041), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// MR Image Module
+#[rustfmt::skip]
+pub const MR_IMAGE_MODULE: ModuleSpec<'static> = ModuleSpec { name: "MR Image", attributes: &[
+    A { tag: Tag(0x0008, 0x0008), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0002), attribute_type: Type1, enumerated_values: &["1"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0004), attribute_type: Type1, enumerated_values: &["MONOCHROME1", "MONOCHROME2"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0100), attribute_type: Type1, enumerated_values: &["16"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0020), attribute_type: Type1, enumerated_values: &[], defined_terms: &["SE", "IR", "GR", "EP", "RM"], items: &[] },
+    A { tag: Tag(0x0018, 0x0021), attribute_type: Type1, enumerated_values: &[], defined_terms: &["SK", "MTC", "SS", "TRSS", "SP", "MP", "OSP", "NONE"], items: &[] },
+    A { tag: Tag(0x0018, 0x0022), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0023), attribute_type: Type2, enumerated_values: &["2D", "3D"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0080), attribute_type: Type2C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0081), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0091), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0082), attribute_type: Type2C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1060), attribute_type: Type2C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0024), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0025), attribute_type: Type3, enumerated_values: &["Y", "N"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0083), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0084), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0085), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0086), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0087), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0088), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0089), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0093), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0094), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x0095), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1250), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1251), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1312), attribute_type: Type3, enumerated_values: &["ROW", "COL"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1314), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1315), attribute_type: Type3, enumerated_values: &["Y", "N"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1316), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// Patient Module
+#[rustfmt::skip]
+pub const PATIENT_MODULE: ModuleSpec<'static> = ModuleSpec { name: "Patient", attributes: &[
+    A { tag: Tag(0x0010, 0x0010), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x0020), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x0021), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x0030), attribute_type: Type2, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x0040), attribute_type: Type2, enumerated_values: &["M", "F", "O"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x1120), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0008, 0x1150), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x1155), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+    A { tag: Tag(0x0010, 0x1002), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0010, 0x0020), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0010, 0x0021), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0010, 0x0022), attribute_type: Type1, enumerated_values: &[], defined_terms: &["TEXT", "RFID", "BARCODE"], items: &[] },
+    ] },
+    A { tag: Tag(0x0010, 0x0032), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x4000), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0012, 0x0062), attribute_type: Type3, enumerated_values: &["YES", "NO"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0012, 0x0063), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0012, 0x0064), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0008, 0x0100), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0102), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0103), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0104), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0119), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0120), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+] };
+
+/// Patient Study Module
+#[rustfmt::skip]
+pub const PATIENT_STUDY_MODULE: ModuleSpec<'static> = ModuleSpec { name: "Patient Study", attributes: &[
+    A { tag: Tag(0x0008, 0x1080), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x1010), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x1020), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x1030), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x21A0), attribute_type: Type3, enumerated_values: &["YES", "NO", "UNKNOWN"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0010, 0x21C0), attribute_type: Type3, enumerated_values: &["0001H", "0002H", "0003H", "0004H"], defined_terms: &[], items: &[] },
+] };
+
+/// SC Equipment Module
+#[rustfmt::skip]
+pub const SC_EQUIPMENT_MODULE: ModuleSpec<'static> = ModuleSpec { name: "SC Equipment", attributes: &[
+    A { tag: Tag(0x0008, 0x0064), attribute_type: Type1, enumerated_values: &[], defined_terms: &["DV", "DI", "DF", "WSD", "SD", "SI", "DRW", "SYN"], items: &[] },
+    A { tag: Tag(0x0008, 0x0060), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1010), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1016), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1018), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1019), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1022), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1023), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+] };
+
+/// SC Image Module
+#[rustfmt::skip]
+pub const SC_IMAGE_MODULE: ModuleSpec<'static> = ModuleSpec { name: "SC Image", attributes: &[
+    A { tag: Tag(0x0018, 0x1012), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x1014), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0x2010), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0030), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0A02), attribute_type: Type1C, enumerated_values: &["GEOMETRY", "FIDUCIAL"], defined_terms: &[], items: &[] },
+] };
+
+/// SOP Common Module
+#[rustfmt::skip]
+pub const SOP_COMMON_MODULE: ModuleSpec<'static> = ModuleSpec { name: "SOP Common", attributes: &[
+    A { tag: Tag(0x0008, 0x0016), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0018), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0005), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0012), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0013), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0015), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0014), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x001A), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x001B), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0201), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0018, 0xA001), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0040, 0xA170), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[
+            A { tag: Tag(0x0008, 0x0100), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+            A { tag: Tag(0x0008, 0x0102), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+            A { tag: Tag(0x0008, 0x0103), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+            A { tag: Tag(0x0008, 0x0104), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+            A { tag: Tag(0x0008, 0x0119), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+            A { tag: Tag(0x0008, 0x0120), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+        ] },
+        A { tag: Tag(0x0008, 0x0070), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x0080), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x1010), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0008, 0x1090), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0018, 0x1000), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0018, 0x1020), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0018, 0xA002), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0018, 0xA003), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+    A { tag: Tag(0x0020, 0x0013), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0100, 0x0410), attribute_type: Type3, enumerated_values: &["NS", "OR", "AO", "AC"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0100, 0x0420), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0100, 0x0424), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0100, 0x0426), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x0303), attribute_type: Type3, enumerated_values: &["UNMODIFIED", "MODIFIED", "REMOVED"], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0008, 0x0053), attribute_type: Type3, enumerated_values: &["CLASSIC", "ENHANCED"], defined_terms: &[], items: &[] },
+] };
+
+/// VOI LUT Module
+#[rustfmt::skip]
+pub const VOI_LUT_MODULE: ModuleSpec<'static> = ModuleSpec { name: "VOI LUT", attributes: &[
+    A { tag: Tag(0x0028, 0x3010), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[
+        A { tag: Tag(0x0028, 0x3002), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0028, 0x3003), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+        A { tag: Tag(0x0028, 0x3006), attribute_type: Type1, enumerated_values: &[], defined_terms: &[], items: &[] },
+    ] },
+    A { tag: Tag(0x0028, 0x1050), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1051), attribute_type: Type1C, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1055), attribute_type: Type3, enumerated_values: &[], defined_terms: &[], items: &[] },
+    A { tag: Tag(0x0028, 0x1056), attribute_type: Type3, enumerated_values: &[], defined_terms: &["LINEAR", "LINEAR_EXACT", "SIGMOID"], items: &[] },
+] };
+
+#[rustfmt::skip]
+pub const IODS: &[IodSpec<'static>] = &[
+    IodSpec { name: "CT Image", modules: &[
+        M { module: &PATIENT_MODULE, usage: Mandatory },
+        M { module: &GENERAL_STUDY_MODULE, usage: Mandatory },
+        M { module: &PATIENT_STUDY_MODULE, usage: UserOption },
+        M { module: &GENERAL_SERIES_MODULE, usage: Mandatory },
+        M { module: &FRAME_OF_REFERENCE_MODULE, usage: Mandatory },
+        M { module: &GENERAL_EQUIPMENT_MODULE, usage: Mandatory },
+        M { module: &GENERAL_IMAGE_MODULE, usage: Mandatory },
+        M { module: &IMAGE_PLANE_MODULE, usage: Mandatory },
+        M { module: &IMAGE_PIXEL_MODULE, usage: Mandatory },
+        M { module: &CONTRAST_BOLUS_MODULE, usage: Conditional },
+        M { module: &CT_IMAGE_MODULE, usage: Mandatory },
+        M { module: &VOI_LUT_MODULE, usage: UserOption },
+        M { module: &SOP_COMMON_MODULE, usage: Mandatory },
+    ] },
+    IodSpec { name: "MR Image", modules: &[
+        M { module: &PATIENT_MODULE, usage: Mandatory },
+        M { module: &GENERAL_STUDY_MODULE, usage: Mandatory },
+        M { module: &PATIENT_STUDY_MODULE, usage: UserOption },
+        M { module: &GENERAL_SERIES_MODULE, usage: Mandatory },
+        M { module: &FRAME_OF_REFERENCE_MODULE, usage: Mandatory },
+        M { module: &GENERAL_EQUIPMENT_MODULE, usage: Mandatory },
+        M { module: &GENERAL_IMAGE_MODULE, usage: Mandatory },
+        M { module: &IMAGE_PLANE_MODULE, usage: Mandatory },
+        M { module: &IMAGE_PIXEL_MODULE, usage: Mandatory },
+        M { module: &CONTRAST_BOLUS_MODULE, usage: Conditional },
+        M { module: &MR_IMAGE_MODULE, usage: Mandatory },
+        M { module: &VOI_LUT_MODULE, usage: UserOption },
+        M { module: &SOP_COMMON_MODULE, usage: Mandatory },
+    ] },
+    IodSpec { name: "Secondary Capture Image", modules: &[
+        M { module: &PATIENT_MODULE, usage: Mandatory },
+        M { module: &GENERAL_STUDY_MODULE, usage: Mandatory },
+        M { module: &PATIENT_STUDY_MODULE, usage: UserOption },
+        M { module: &GENERAL_SERIES_MODULE, usage: Mandatory },
+        M { module: &GENERAL_EQUIPMENT_MODULE, usage: UserOption },
+        M { module: &SC_EQUIPMENT_MODULE, usage: Mandatory },
+        M { module: &GENERAL_IMAGE_MODULE, usage: Mandatory },
+        M { module: &IMAGE_PLANE_MODULE, usage: UserOption },
+        M { module: &IMAGE_PIXEL_MODULE, usage: Mandatory },
+        M { module: &SC_IMAGE_MODULE, usage: Mandatory },
+        M { module: &VOI_LUT_MODULE, usage: UserOption },
+        M { module: &SOP_COMMON_MODULE, usage: Mandatory },
+    ] },
+];
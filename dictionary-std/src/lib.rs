@@ -5,10 +5,29 @@
 //! and it will be used by default in most other abstractions available.
 //!
 //! When not using private tags, this dictionary should suffice.
+//!
+//! Constants for the tags of all standard attributes are available in the
+//! [`tags`](tags/index.html) module.
+//!
+//! The registry of unique identifiers (SOP classes, transfer syntaxes, and
+//! so on) is available in the [`uid`](uid/index.html) module.
+//!
+//! The information object definitions of PS3.3, with their modules and
+//! attribute requirements, are available in the [`iod`](iod/index.html)
+//! module. Only the CT Image, MR Image and Secondary Capture Image IODs
+//! are currently included.
 
 mod entries;
+pub mod iod;
+mod iods;
+pub mod load;
+#[rustfmt::skip]
+pub mod tags;
+pub mod uid;
+mod uids;
 
 use crate::entries::ENTRIES;
+use dicom_core::dictionary::owned::OwnedDataDictionary;
 use dicom_core::dictionary::{DataDictionary, DictionaryEntryRef, TagRange::*};
 use dicom_core::header::{Tag, VR};
 use lazy_static::lazy_static;
@@ -16,6 +35,9 @@ use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
+pub use crate::iod::StandardIodDictionary;
+pub use crate::uid::StandardUidDictionary;
+
 lazy_static! {
     static ref DICT: StandardDictionaryRegistry = { init_dictionary() };
 }
@@ -76,12 +98,12 @@ impl StandardDataDictionary {
         r.by_tag
             .get(&tag)
             .or_else(|| {
-                let group_trimmed = Tag(tag.0 & 0xFF00, tag.1);
+                let group_trimmed = tags::repeating_group_base(tag);
 
                 if r.repeating_ggxx.contains(&group_trimmed) {
                     r.by_tag.get(&group_trimmed)
                 } else {
-                    let elem_trimmed = Tag(tag.0, tag.1 & 0xFF00);
+                    let elem_trimmed = tags::repeating_element_base(tag);
                     if r.repeating_eexx.contains(&elem_trimmed) {
                         r.by_tag.get(&elem_trimmed)
                     } else {
@@ -91,6 +113,14 @@ impl StandardDataDictionary {
             })
             .cloned()
     }
+
+    /// Create an owned copy of the standard dictionary. The copy can be
+    /// extended at run time with more entries, such as those loaded with the
+    /// [`load`](load/index.html) module, which take precedence over the
+    /// standard ones.
+    pub fn to_owned_dictionary(&self) -> OwnedDataDictionary {
+        registry().by_tag.values().cloned().collect()
+    }
 }
 
 impl DataDictionary for StandardDataDictionary {
@@ -126,83 +156,322 @@ impl Display for StandardDataDictionary {
 fn init_dictionary() -> StandardDictionaryRegistry {
     let mut d = StandardDictionaryRegistry::new();
     for entry in ENTRIES {
-        d.index(&entry);
+        d.index(entry);
+    }
+    for entry in COMMAND_ENTRIES {
+        d.index(entry);
     }
     for entry in META_ENTRIES {
-        d.index(&entry);
+        d.index(entry);
     }
     d
 }
 
-// meta information entries
 type E<'a> = DictionaryEntryRef<'a>;
+
+// command elements (PS3.7 Annex E)
+const COMMAND_ENTRIES: &[E<'static>] = &[
+    E {
+        tag: Single(Tag(0x0000, 0x0000)),
+        alias: "CommandGroupLength",
+        name: "Command Group Length",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0002)),
+        alias: "AffectedSOPClassUID",
+        name: "Affected SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0003)),
+        alias: "RequestedSOPClassUID",
+        name: "Requested SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0100)),
+        alias: "CommandField",
+        name: "Command Field",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0110)),
+        alias: "MessageID",
+        name: "Message ID",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0120)),
+        alias: "MessageIDBeingRespondedTo",
+        name: "Message ID Being Responded To",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0600)),
+        alias: "MoveDestination",
+        name: "Move Destination",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0700)),
+        alias: "Priority",
+        name: "Priority",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0800)),
+        alias: "CommandDataSetType",
+        name: "Command Data Set Type",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0900)),
+        alias: "Status",
+        name: "Status",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0901)),
+        alias: "OffendingElement",
+        name: "Offending Element",
+        vrs: &[VR::AT],
+        vm: "1-n",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0902)),
+        alias: "ErrorComment",
+        name: "Error Comment",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x0903)),
+        alias: "ErrorID",
+        name: "Error ID",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1000)),
+        alias: "AffectedSOPInstanceUID",
+        name: "Affected SOP Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1001)),
+        alias: "RequestedSOPInstanceUID",
+        name: "Requested SOP Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1002)),
+        alias: "EventTypeID",
+        name: "Event Type ID",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1005)),
+        alias: "AttributeIdentifierList",
+        name: "Attribute Identifier List",
+        vrs: &[VR::AT],
+        vm: "1-n",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1008)),
+        alias: "ActionTypeID",
+        name: "Action Type ID",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1020)),
+        alias: "NumberOfRemainingSuboperations",
+        name: "Number of Remaining Sub-operations",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1021)),
+        alias: "NumberOfCompletedSuboperations",
+        name: "Number of Completed Sub-operations",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1022)),
+        alias: "NumberOfFailedSuboperations",
+        name: "Number of Failed Sub-operations",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1023)),
+        alias: "NumberOfWarningSuboperations",
+        name: "Number of Warning Sub-operations",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1030)),
+        alias: "MoveOriginatorApplicationEntityTitle",
+        name: "Move Originator Application Entity Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
+    },
+    E {
+        tag: Single(Tag(0x0000, 0x1031)),
+        alias: "MoveOriginatorMessageID",
+        name: "Move Originator Message ID",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+];
+
+// meta information entries
 const META_ENTRIES: &[E<'static>] = &[
     E {
         tag: Single(Tag(0x0002, 0x0000)),
         alias: "FileMetaInformationGroupLength",
-        vr: VR::UL,
+        name: "File Meta Information Group Length",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0001)),
         alias: "FileMetaInformationVersion",
-        vr: VR::OB,
+        name: "File Meta Information Version",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0002)),
         alias: "MediaStorageSOPClassUID",
-        vr: VR::UI,
+        name: "Media Storage SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0003)),
         alias: "MediaStorageSOPInstanceUID",
-        vr: VR::UI,
+        name: "Media Storage SOP Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0010)),
         alias: "TransferSyntaxUID",
-        vr: VR::UI,
+        name: "Transfer Syntax UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0012)),
         alias: "ImplementationClassUID",
-        vr: VR::UI,
+        name: "Implementation Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0013)),
         alias: "ImplentationVersionName",
-        vr: VR::SH,
+        name: "Implementation Version Name",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0016)),
         alias: "SourceApplicationEntityTitle",
-        vr: VR::AE,
+        name: "Source Application Entity Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0017)),
         alias: "SendingApplicationEntityTitle",
-        vr: VR::AE,
+        name: "Sending Application Entity Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0018)),
         alias: "ReceivingApplicationEntityTitle",
-        vr: VR::AE,
+        name: "Receiving Application Entity Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0100)),
         alias: "PrivateInformationCreatorUID",
-        vr: VR::UI,
+        name: "Private Information Creator UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
     },
     E {
         tag: Single(Tag(0x0002, 0x0102)),
         alias: "PrivateInformation",
-        vr: VR::OB,
+        name: "Private Information",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
     },
 ];
 
 #[cfg(test)]
 mod tests {
     use super::StandardDataDictionary;
-    use dicom_core::dictionary::{DataDictionary, DictionaryEntryRef, TagRange::*};
+    use dicom_core::dictionary::owned::OwnedDataDictionary;
+    use dicom_core::dictionary::{
+        DataDictionary, DictionaryEntry, DictionaryEntryRef, TagRange::*,
+    };
     use dicom_core::header::{Tag, VR};
 
     // tests for just a few attributes to make sure that the entries
@@ -216,7 +485,10 @@ mod tests {
             Some(&DictionaryEntryRef {
                 tag: Single(Tag(0x0010, 0x0010)),
                 alias: "PatientName",
-                vr: VR::PN,
+                name: "Patient's Name",
+                vrs: &[VR::PN],
+                vm: "1",
+                retired: false,
             })
         );
 
@@ -225,7 +497,10 @@ mod tests {
             Some(&DictionaryEntryRef {
                 tag: Single(Tag(0x0008, 0x0060)),
                 alias: "Modality",
-                vr: VR::CS,
+                name: "Modality",
+                vrs: &[VR::CS],
+                vm: "1",
+                retired: false,
             })
         );
 
@@ -235,14 +510,14 @@ mod tests {
         eprintln!("{:X?}", pixel_data.tag);
         assert_eq!(pixel_data.tag, Single(Tag(0x7FE0, 0x0010)));
         assert_eq!(pixel_data.alias, "PixelData");
-        assert!(pixel_data.vr == VR::OB || pixel_data.vr == VR::OW);
+        assert_eq!(pixel_data.vrs, &[VR::OB, VR::OW]);
 
         let overlay_data = dict
             .by_tag(Tag(0x6000, 0x3000))
             .expect("Overlay Data attribute should exist");
         assert_eq!(overlay_data.tag, Group100(Tag(0x6000, 0x3000)));
         assert_eq!(overlay_data.alias, "OverlayData");
-        assert!(overlay_data.vr == VR::OB || overlay_data.vr == VR::OW);
+        assert_eq!(overlay_data.vr(), VR::OB);
 
         // repeated overlay data
         let overlay_data = dict
@@ -250,6 +525,76 @@ mod tests {
             .expect("Repeated Overlay Data attribute should exist");
         assert_eq!(overlay_data.tag, Group100(Tag(0x6000, 0x3000)));
         assert_eq!(overlay_data.alias, "OverlayData");
-        assert!(overlay_data.vr == VR::OB || overlay_data.vr == VR::OW);
+        assert_eq!(overlay_data.vr(), VR::OB);
+    }
+
+    #[test]
+    fn entry_metadata() {
+        let dict = StandardDataDictionary;
+
+        let length_to_end = dict
+            .by_tag(Tag(0x0008, 0x0001))
+            .expect("Length to End attribute should exist");
+        assert_eq!(length_to_end.name(), "Length to End");
+        assert!(length_to_end.is_retired());
+
+        let image_type = dict.by_name("ImageType").unwrap();
+        assert!(!image_type.is_retired());
+        assert_eq!(image_type.vm(), "2-n");
+        let vm = image_type.value_multiplicity().unwrap();
+        assert!(vm.contains(2));
+        assert!(!vm.contains(1));
+
+        let smallest = dict.by_name("SmallestImagePixelValue").unwrap();
+        assert_eq!(smallest.vrs(), &[VR::US, VR::SS]);
+        assert_eq!(smallest.vr(), VR::US);
+    }
+
+    #[test]
+    fn tag_constants() {
+        use crate::tags;
+
+        let dict = StandardDataDictionary;
+        assert_eq!(
+            dict.by_tag(tags::PATIENT_NAME).map(|e| e.alias()),
+            Some("PatientName")
+        );
+        assert_eq!(
+            dict.by_tag(tags::TRANSFER_SYNTAX_UID).map(|e| e.alias()),
+            Some("TransferSyntaxUID")
+        );
+        assert_eq!(tags::SOP_INSTANCE_UID, Tag(0x0008, 0x0018));
+
+        // repeating groups are declared with the `xx` portion zeroed
+        assert_eq!(tags::OVERLAY_DATA, Tag(0x6000, 0x3000));
+        assert_eq!(
+            tags::repeating_group_base(Tag(0x6002, 0x3000)),
+            tags::OVERLAY_DATA
+        );
+        assert_ne!(
+            tags::repeating_group_base(Tag(0x6002, 0x3001)),
+            tags::OVERLAY_DATA
+        );
+        assert_eq!(
+            tags::repeating_element_base(Tag(0x0020, 0x3105)),
+            Tag(0x0020, 0x3100)
+        );
+    }
+
+    #[test]
+    fn owned_copy() {
+        let mut dict: OwnedDataDictionary = StandardDataDictionary.to_owned_dictionary();
+        assert_eq!(
+            dict.by_name("PatientName").map(|e| e.tag()),
+            Some(Tag(0x0010, 0x0010))
+        );
+        let mut patient_name = dict.by_name("PatientName").unwrap().clone();
+        patient_name.alias = "NameOfPatient".to_string();
+        dict.add(patient_name);
+        assert!(dict.by_name("PatientName").is_none());
+        assert_eq!(
+            dict.by_name("NameOfPatient").map(|e| e.tag()),
+            Some(Tag(0x0010, 0x0010))
+        );
     }
 }
@@ -0,0 +1,386 @@
+//! This module provides the means to load data dictionaries at run time.
+//!
+//! Two formats are supported:
+//!
+//! - the JSON format emitted by `dicom-dictionary-builder -f json`,
+//!   which is an object of attribute entries keyed by tag;
+//! - DCMTK-style `dicom.dic` text files, with one attribute per line
+//!   in the form `(gggg,eeee) VR Name VM Version`.
+//!
+//! The resulting dictionaries can be used on their own, or layered on top of
+//! an owned copy of the standard dictionary:
+//!
+//! ```no_run
+//! # use dicom_dictionary_std::StandardDataDictionary;
+//! # use dicom_dictionary_std::load::json_entries;
+//! # use std::fs::File;
+//! let mut dict = StandardDataDictionary.to_owned_dictionary();
+//! dict.extend(json_entries(File::open("local.json")?)?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use dicom_core::dictionary::owned::OwnedDataDictionary;
+use dicom_core::dictionary::private::PrivateDictionaryEntry;
+use dicom_core::dictionary::{DictionaryEntryBuf, TagRange};
+use dicom_core::header::{Tag, VR};
+use quick_error::quick_error;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Read};
+use std::str::FromStr;
+
+quick_error! {
+    /// An error which may occur when loading a data dictionary.
+    #[derive(Debug)]
+    pub enum Error {
+        /// Failed to read the dictionary source.
+        Io(err: io::Error) {
+            description("I/O error")
+            from()
+            cause(err)
+            display("I/O error: {}", err)
+        }
+        /// The JSON dictionary is malformed.
+        Json(err: serde_json::Error) {
+            description("Invalid JSON dictionary")
+            from()
+            cause(err)
+            display("Invalid JSON dictionary: {}", err)
+        }
+        /// A line in a text dictionary is malformed.
+        Syntax(line: usize, reason: &'static str) {
+            description("Malformed dictionary entry")
+            display("Malformed dictionary entry at line {}: {}", line, reason)
+        }
+    }
+}
+
+/// Type alias for a result from this module.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An attribute entry as emitted by the dictionary builder.
+#[derive(Debug, Deserialize)]
+struct JsonEntry {
+    tag: String,
+    name: Option<String>,
+    alias: Option<String>,
+    vr: Option<String>,
+    vm: Option<String>,
+    obs: Option<String>,
+}
+
+/// Read all attribute entries from a JSON dictionary.
+///
+/// Entries without an alias or with an unsupported tag range are skipped.
+pub fn json_entries<R: Read>(reader: R) -> Result<Vec<DictionaryEntryBuf>> {
+    let entries: BTreeMap<String, JsonEntry> = serde_json::from_reader(reader)?;
+    Ok(entries
+        .into_values()
+        .filter_map(|e| {
+            let alias = e.alias.filter(|a| !a.is_empty())?;
+            let tag = TagRange::from_str(&e.tag).ok()?;
+            // alternatives are listed as in "US or SS",
+            // whereas items and delimiters refer to a note instead
+            let vrs: Vec<VR> =
+                e.vr.as_ref()
+                    .map(|vr| {
+                        vr.split(" or ")
+                            .filter_map(|vr| VR::from_str(vr.trim()).ok())
+                            .collect()
+                    })
+                    .filter(|vrs: &Vec<VR>| !vrs.is_empty())
+                    .unwrap_or_else(|| vec![VR::UN]);
+            Some(DictionaryEntryBuf {
+                tag,
+                name: e.name.unwrap_or_else(|| alias.clone()),
+                alias,
+                vrs,
+                vm: e.vm.unwrap_or_default(),
+                retired: e.obs.map(|obs| obs.starts_with("RET")).unwrap_or(false),
+            })
+        })
+        .collect())
+}
+
+/// Create a data dictionary from a JSON dictionary.
+pub fn from_json<R: Read>(reader: R) -> Result<OwnedDataDictionary> {
+    Ok(json_entries(reader)?.into_iter().collect())
+}
+
+/// The entries of a DCMTK-style dictionary file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DcmtkEntries {
+    /// The entries of public attributes.
+    pub entries: Vec<DictionaryEntryBuf>,
+    /// The entries of private attributes, identified by private creator.
+    pub private_entries: Vec<PrivateDictionaryEntry>,
+}
+
+/// The tag portion of a line in a DCMTK-style dictionary.
+enum DcmtkTag {
+    Public(TagRange),
+    Private {
+        group: u16,
+        creator: String,
+        offset: u8,
+    },
+}
+
+/// Read all attribute entries from a DCMTK-style dictionary file.
+///
+/// Comments and blank lines are ignored, as well as entries with tag ranges
+/// which cannot be represented by a [`TagRange`].
+///
+/// [`TagRange`]: ../../dicom_core/dictionary/enum.TagRange.html
+pub fn dcmtk_entries<R: BufRead>(reader: R) -> Result<DcmtkEntries> {
+    let mut out = DcmtkEntries::default();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (tag, rest) = split_dcmtk_tag(line).ok_or(Error::Syntax(
+            line_number,
+            "expected tag enclosed in parentheses",
+        ))?;
+        let mut fields = rest.split_whitespace();
+        let vr = fields
+            .next()
+            .ok_or(Error::Syntax(line_number, "missing value representation"))?;
+        let name = fields
+            .next()
+            .ok_or(Error::Syntax(line_number, "missing attribute name"))?;
+        let vm = fields
+            .next()
+            .ok_or(Error::Syntax(line_number, "missing value multiplicity"))?;
+        let vrs = match vr {
+            // no value representation (items and delimiters)
+            "na" => continue,
+            _ => parse_dcmtk_vr(vr)
+                .ok_or(Error::Syntax(line_number, "invalid value representation"))?,
+        };
+        let retired = name.starts_with("RETIRED_");
+        let alias = name.trim_start_matches("RETIRED_").to_string();
+
+        match parse_dcmtk_tag(tag).ok_or(Error::Syntax(line_number, "invalid tag"))? {
+            Some(DcmtkTag::Public(tag)) => {
+                out.entries.push(DictionaryEntryBuf {
+                    tag,
+                    // DCMTK dictionaries do not provide a separate name
+                    name: alias.clone(),
+                    alias,
+                    vrs,
+                    vm: vm.to_string(),
+                    retired,
+                });
+            }
+            Some(DcmtkTag::Private {
+                group,
+                creator,
+                offset,
+            }) => {
+                out.private_entries.push(PrivateDictionaryEntry {
+                    creator,
+                    group,
+                    offset,
+                    alias,
+                    vr: vrs[0],
+                });
+            }
+            None => {
+                // unsupported tag range
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Create a data dictionary from the public attributes
+/// of a DCMTK-style dictionary file.
+pub fn from_dcmtk<R: BufRead>(reader: R) -> Result<OwnedDataDictionary> {
+    Ok(dcmtk_entries(reader)?.entries.into_iter().collect())
+}
+
+/// Split a dictionary line into the tag portion (without parentheses)
+/// and the rest of the line.
+fn split_dcmtk_tag(line: &str) -> Option<(&str, &str)> {
+    if !line.starts_with('(') {
+        return None;
+    }
+    // private creators are quoted and may contain spaces or parentheses
+    let mut in_quotes = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ')' if !in_quotes => return Some((&line[1..i], &line[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse the tag portion of a dictionary line.
+/// Returns `Some(None)` if the tag is valid but not supported.
+fn parse_dcmtk_tag(tag: &str) -> Option<Option<DcmtkTag>> {
+    let mut parts = tag.splitn(2, ',');
+    let group = parts.next()?.trim();
+    let rest = parts.next()?.trim();
+
+    if let Some(rest) = rest.strip_prefix('"') {
+        // private attribute: (gggg,"creator",ee)
+        let end = rest.find('"')?;
+        let creator = rest[..end].to_string();
+        let offset = rest[end + 1..].trim_start_matches(',').trim();
+        let group = match parse_hex_range(group)? {
+            (lo, hi) if lo == hi => lo,
+            _ => return Some(None),
+        };
+        // offsets may be given with the block placeholder, as in `xx10`
+        let offset = offset.trim_start_matches("xx");
+        let offset = u8::from_str_radix(offset, 16).ok()?;
+        return Some(Some(DcmtkTag::Private {
+            group,
+            creator,
+            offset,
+        }));
+    }
+
+    let (group_lo, group_hi) = parse_hex_range(group)?;
+    let (elem_lo, elem_hi) = parse_hex_range(rest)?;
+    let range = match (group_lo == group_hi, elem_lo == elem_hi) {
+        (true, true) => Some(TagRange::Single(Tag(group_lo, elem_lo))),
+        (false, true) if is_range_100(group_lo, group_hi) => {
+            Some(TagRange::Group100(Tag(group_lo, elem_lo)))
+        }
+        (true, false) if is_range_100(elem_lo, elem_hi) => {
+            Some(TagRange::Element100(Tag(group_lo, elem_lo)))
+        }
+        _ => None,
+    };
+    Some(range.map(DcmtkTag::Public))
+}
+
+/// Parse a hexadecimal number or an inclusive range in the form `lo-hi`.
+/// Parity markers of DCMTK ranges (as in `6000-o-60ff`) are ignored.
+fn parse_hex_range(s: &str) -> Option<(u16, u16)> {
+    let mut parts = s.split('-').filter(|p| *p != "o" && *p != "e" && *p != "u");
+    let lo = u16::from_str_radix(parts.next()?, 16).ok()?;
+    let hi = match parts.next() {
+        Some(hi) => u16::from_str_radix(hi, 16).ok()?,
+        None => lo,
+    };
+    Some((lo, hi))
+}
+
+/// Check whether the range covers all values of the two rightmost digits.
+fn is_range_100(lo: u16, hi: u16) -> bool {
+    lo & 0xFF == 0 && hi & 0xFF00 == lo && hi & 0xFF == 0xFF
+}
+
+/// Parse a value representation, including the pseudo VRs used by DCMTK,
+/// into the list of value representations which it stands for.
+fn parse_dcmtk_vr(vr: &str) -> Option<Vec<VR>> {
+    match vr {
+        "ox" | "px" => Some(vec![VR::OB, VR::OW]),
+        "xs" => Some(vec![VR::US, VR::SS]),
+        "lt" => Some(vec![VR::US, VR::SS, VR::OW]),
+        "up" => Some(vec![VR::UL]),
+        vr => VR::from_str(vr).ok().map(|vr| vec![vr]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_core::dictionary::private::{PrivateDataDictionary, PrivateDictionary};
+    use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
+
+    #[test]
+    fn load_json_dictionary() {
+        let json = r#"{
+            "(0008,0001)": {"tag": "(0008,0001)", "name": "Length to End",
+                "alias": "LengthToEnd", "vr": "UL", "vm": "1", "obs": "RET"},
+            "(0028,0106)": {"tag": "(0028,0106)", "name": "Smallest Image Pixel Value",
+                "alias": "SmallestImagePixelValue", "vr": "US or SS", "vm": "1"},
+            "(60xx,3000)": {"tag": "(60xx,3000)", "name": "Overlay Data",
+                "alias": "OverlayData", "vr": "OB or OW", "vm": "1"},
+            "(0028,04x0)": {"tag": "(0028,04x0)", "name": "Rows For Nth Order Coefficients",
+                "alias": "RowsForNthOrderCoefficients", "vr": "US", "vm": "1"},
+            "(FFFE,E000)": {"tag": "(FFFE,E000)", "name": "Item",
+                "alias": "Item", "vr": "See Note", "vm": "1"}
+        }"#;
+
+        let dict = from_json(json.as_bytes()).unwrap();
+        assert_eq!(dict.len(), 4);
+        assert_eq!(
+            dict.by_tag(Tag(0x0028, 0x0106))
+                .map(|e| (e.alias(), e.vr())),
+            Some(("SmallestImagePixelValue", VR::US))
+        );
+        assert_eq!(
+            dict.by_tag(Tag(0x6002, 0x3000)).map(|e| e.alias()),
+            Some("OverlayData")
+        );
+        assert_eq!(dict.by_name("Item").map(|e| e.vr()), Some(VR::UN));
+
+        let length_to_end = dict.by_name("LengthToEnd").unwrap();
+        assert_eq!(length_to_end.name(), "Length to End");
+        assert_eq!(length_to_end.vm(), "1");
+        assert!(length_to_end.is_retired());
+        assert_eq!(
+            dict.by_name("SmallestImagePixelValue").map(|e| e.vrs()),
+            Some(&[VR::US, VR::SS][..])
+        );
+    }
+
+    #[test]
+    fn load_dcmtk_dictionary() {
+        let dic = "\
+# a comment
+(0008,0001)\tUL\tRETIRED_LengthToEnd\t1\tACR/NEMA2
+(0028,0106)\txs\tSmallestImagePixelValue\t1\tDICOM
+
+(6000-60ff,3000)\tox\tOverlayData\t1\tDICOM
+(0020,3100-31ff)\tCS\tRETIRED_SourceImageIDs\t1-n\tACR/NEMA2
+(fffe,e000)\tna\tItem\t1\tDICOM
+(0029,\"SIEMENS CSA HEADER\",08)\tCS\tCSAImageHeaderType\t1\tPrivateTag
+";
+        let entries = dcmtk_entries(dic.as_bytes()).unwrap();
+        assert_eq!(entries.entries.len(), 4);
+        assert_eq!(entries.private_entries.len(), 1);
+
+        let dict: OwnedDataDictionary = entries.entries.into_iter().collect();
+        assert_eq!(
+            dict.by_tag(Tag(0x0008, 0x0001)).map(|e| e.alias()),
+            Some("LengthToEnd")
+        );
+        assert_eq!(
+            dict.by_tag(Tag(0x6004, 0x3000)).map(|e| e.vr()),
+            Some(VR::OB)
+        );
+        let source_image_ids = dict.by_tag(Tag(0x0020, 0x3105)).unwrap();
+        assert_eq!(source_image_ids.alias(), "SourceImageIDs");
+        assert_eq!(source_image_ids.vm(), "1-n");
+        assert!(source_image_ids.is_retired());
+
+        let private_dict: PrivateDictionary = entries.private_entries.into_iter().collect();
+        assert_eq!(
+            private_dict
+                .by_creator("SIEMENS CSA HEADER", 0x0029, 0x08)
+                .map(|e| e.vr),
+            Some(VR::CS)
+        );
+    }
+
+    #[test]
+    fn load_dcmtk_dictionary_malformed() {
+        let dic = "(0008,0001)\tUL\tLengthToEnd\t1\tACR/NEMA2\n0008,0002 UL Foo 1 DICOM\n";
+        match dcmtk_entries(dic.as_bytes()) {
+            Err(Error::Syntax(2, _)) => {}
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,9235 @@
+//! Automatically generated. Edit at your own risk.
+//!
+//! Constants for the tags of all attributes in the standard dictionary.
+//! Attributes in a repeating group `(ggxx,eeee)` or with a repeating
+//! element `(gggg,eexx)` are declared with the `xx` portion zeroed.
+//! Use [`repeating_group_base`] or [`repeating_element_base`]
+//! before comparing a tag against these constants.
+
+use dicom_core::Tag;
+
+/// Mask the `xx` portion of a tag in a repeating group `(ggxx,eeee)`,
+/// such as those of overlays and curves.
+#[inline]
+pub const fn repeating_group_base(tag: Tag) -> Tag {
+    Tag(tag.0 & 0xFF00, tag.1)
+}
+
+/// Mask the `xx` portion of a tag with a repeating element `(gggg,eexx)`.
+#[inline]
+pub const fn repeating_element_base(tag: Tag) -> Tag {
+    Tag(tag.0, tag.1 & 0xFF00)
+}
+
+/// (0000,0000) Command Group Length
+pub const COMMAND_GROUP_LENGTH: Tag = Tag(0x0000, 0x0000);
+/// (0000,0002) Affected SOP Class UID
+pub const AFFECTED_SOP_CLASS_UID: Tag = Tag(0x0000, 0x0002);
+/// (0000,0003) Requested SOP Class UID
+pub const REQUESTED_SOP_CLASS_UID: Tag = Tag(0x0000, 0x0003);
+/// (0000,0100) Command Field
+pub const COMMAND_FIELD: Tag = Tag(0x0000, 0x0100);
+/// (0000,0110) Message ID
+pub const MESSAGE_ID: Tag = Tag(0x0000, 0x0110);
+/// (0000,0120) Message ID Being Responded To
+pub const MESSAGE_ID_BEING_RESPONDED_TO: Tag = Tag(0x0000, 0x0120);
+/// (0000,0600) Move Destination
+pub const MOVE_DESTINATION: Tag = Tag(0x0000, 0x0600);
+/// (0000,0700) Priority
+pub const PRIORITY: Tag = Tag(0x0000, 0x0700);
+/// (0000,0800) Command Data Set Type
+pub const COMMAND_DATA_SET_TYPE: Tag = Tag(0x0000, 0x0800);
+/// (0000,0900) Status
+pub const STATUS: Tag = Tag(0x0000, 0x0900);
+/// (0000,0901) Offending Element
+pub const OFFENDING_ELEMENT: Tag = Tag(0x0000, 0x0901);
+/// (0000,0902) Error Comment
+pub const ERROR_COMMENT: Tag = Tag(0x0000, 0x0902);
+/// (0000,0903) Error ID
+pub const ERROR_ID: Tag = Tag(0x0000, 0x0903);
+/// (0000,1000) Affected SOP Instance UID
+pub const AFFECTED_SOP_INSTANCE_UID: Tag = Tag(0x0000, 0x1000);
+/// (0000,1001) Requested SOP Instance UID
+pub const REQUESTED_SOP_INSTANCE_UID: Tag = Tag(0x0000, 0x1001);
+/// (0000,1002) Event Type ID
+pub const EVENT_TYPE_ID: Tag = Tag(0x0000, 0x1002);
+/// (0000,1005) Attribute Identifier List
+pub const ATTRIBUTE_IDENTIFIER_LIST: Tag = Tag(0x0000, 0x1005);
+/// (0000,1008) Action Type ID
+pub const ACTION_TYPE_ID: Tag = Tag(0x0000, 0x1008);
+/// (0000,1020) Number of Remaining Sub-operations
+pub const NUMBER_OF_REMAINING_SUBOPERATIONS: Tag = Tag(0x0000, 0x1020);
+/// (0000,1021) Number of Completed Sub-operations
+pub const NUMBER_OF_COMPLETED_SUBOPERATIONS: Tag = Tag(0x0000, 0x1021);
+/// (0000,1022) Number of Failed Sub-operations
+pub const NUMBER_OF_FAILED_SUBOPERATIONS: Tag = Tag(0x0000, 0x1022);
+/// (0000,1023) Number of Warning Sub-operations
+pub const NUMBER_OF_WARNING_SUBOPERATIONS: Tag = Tag(0x0000, 0x1023);
+/// (0000,1030) Move Originator Application Entity Title
+pub const MOVE_ORIGINATOR_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0000, 0x1030);
+/// (0000,1031) Move Originator Message ID
+pub const MOVE_ORIGINATOR_MESSAGE_ID: Tag = Tag(0x0000, 0x1031);
+/// (0002,0000) File Meta Information Group Length
+pub const FILE_META_INFORMATION_GROUP_LENGTH: Tag = Tag(0x0002, 0x0000);
+/// (0002,0001) File Meta Information Version
+pub const FILE_META_INFORMATION_VERSION: Tag = Tag(0x0002, 0x0001);
+/// (0002,0002) Media Storage SOP Class UID
+pub const MEDIA_STORAGE_SOP_CLASS_UID: Tag = Tag(0x0002, 0x0002);
+/// (0002,0003) Media Storage SOP Instance UID
+pub const MEDIA_STORAGE_SOP_INSTANCE_UID: Tag = Tag(0x0002, 0x0003);
+/// (0002,0010) Transfer Syntax UID
+pub const TRANSFER_SYNTAX_UID: Tag = Tag(0x0002, 0x0010);
+/// (0002,0012) Implementation Class UID
+pub const IMPLEMENTATION_CLASS_UID: Tag = Tag(0x0002, 0x0012);
+/// (0002,0013) Implementation Version Name
+pub const IMPLEMENTATION_VERSION_NAME: Tag = Tag(0x0002, 0x0013);
+/// (0002,0016) Source Application Entity Title
+pub const SOURCE_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0002, 0x0016);
+/// (0002,0017) Sending Application Entity Title
+pub const SENDING_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0002, 0x0017);
+/// (0002,0018) Receiving Application Entity Title
+pub const RECEIVING_APPLICATION_ENTITY_TITLE: Tag = Tag(0x0002, 0x0018);
+/// (0002,0100) Private Information Creator UID
+pub const PRIVATE_INFORMATION_CREATOR_UID: Tag = Tag(0x0002, 0x0100);
+/// (0002,0102) Private Information
+pub const PRIVATE_INFORMATION: Tag = Tag(0x0002, 0x0102);
+/// (0004,1130) File-set ID
+pub const FILE_SET_ID: Tag = Tag(0x0004, 0x1130);
+/// (0004,1141) File-set Descriptor File ID
+pub const FILE_SET_DESCRIPTOR_FILE_ID: Tag = Tag(0x0004, 0x1141);
+/// (0004,1142) Specific Character Set of File-set Descriptor File
+pub const SPECIFIC_CHARACTER_SET_OF_FILE_SET_DESCRIPTOR_FILE: Tag = Tag(0x0004, 0x1142);
+/// (0004,1200) Offset of the First Directory Record of the Root Directory Entity
+pub const OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY: Tag = Tag(0x0004, 0x1200);
+/// (0004,1202) Offset of the Last Directory Record of the Root Directory Entity
+pub const OFFSET_OF_THE_LAST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY: Tag = Tag(0x0004, 0x1202);
+/// (0004,1212) File-set Consistency Flag
+pub const FILE_SET_CONSISTENCY_FLAG: Tag = Tag(0x0004, 0x1212);
+/// (0004,1220) Directory Record Sequence
+pub const DIRECTORY_RECORD_SEQUENCE: Tag = Tag(0x0004, 0x1220);
+/// (0004,1400) Offset of the Next Directory Record
+pub const OFFSET_OF_THE_NEXT_DIRECTORY_RECORD: Tag = Tag(0x0004, 0x1400);
+/// (0004,1410) Record In-use Flag
+pub const RECORD_IN_USE_FLAG: Tag = Tag(0x0004, 0x1410);
+/// (0004,1420) Offset of Referenced Lower-Level Directory Entity
+pub const OFFSET_OF_REFERENCED_LOWER_LEVEL_DIRECTORY_ENTITY: Tag = Tag(0x0004, 0x1420);
+/// (0004,1430) Directory Record Type
+pub const DIRECTORY_RECORD_TYPE: Tag = Tag(0x0004, 0x1430);
+/// (0004,1432) Private Record UID
+pub const PRIVATE_RECORD_UID: Tag = Tag(0x0004, 0x1432);
+/// (0004,1500) Referenced File ID
+pub const REFERENCED_FILE_ID: Tag = Tag(0x0004, 0x1500);
+/// (0004,1504) MRDR Directory Record Offset (retired)
+pub const MRDR_DIRECTORY_RECORD_OFFSET: Tag = Tag(0x0004, 0x1504);
+/// (0004,1510) Referenced SOP Class UID in File
+pub const REFERENCED_SOP_CLASS_UID_IN_FILE: Tag = Tag(0x0004, 0x1510);
+/// (0004,1511) Referenced SOP Instance UID in File
+pub const REFERENCED_SOP_INSTANCE_UID_IN_FILE: Tag = Tag(0x0004, 0x1511);
+/// (0004,1512) Referenced Transfer Syntax UID in File
+pub const REFERENCED_TRANSFER_SYNTAX_UID_IN_FILE: Tag = Tag(0x0004, 0x1512);
+/// (0004,151A) Referenced Related General SOP Class UID in File
+pub const REFERENCED_RELATED_GENERAL_SOP_CLASS_UID_IN_FILE: Tag = Tag(0x0004, 0x151A);
+/// (0004,1600) Number of References (retired)
+pub const NUMBER_OF_REFERENCES: Tag = Tag(0x0004, 0x1600);
+/// (0008,0001) Length to End (retired)
+pub const LENGTH_TO_END: Tag = Tag(0x0008, 0x0001);
+/// (0008,0005) Specific Character Set
+pub const SPECIFIC_CHARACTER_SET: Tag = Tag(0x0008, 0x0005);
+/// (0008,0006) Language Code Sequence
+pub const LANGUAGE_CODE_SEQUENCE: Tag = Tag(0x0008, 0x0006);
+/// (0008,0008) Image Type
+pub const IMAGE_TYPE: Tag = Tag(0x0008, 0x0008);
+/// (0008,0010) Recognition Code (retired)
+pub const RECOGNITION_CODE: Tag = Tag(0x0008, 0x0010);
+/// (0008,0012) Instance Creation Date
+pub const INSTANCE_CREATION_DATE: Tag = Tag(0x0008, 0x0012);
+/// (0008,0013) Instance Creation Time
+pub const INSTANCE_CREATION_TIME: Tag = Tag(0x0008, 0x0013);
+/// (0008,0014) Instance Creator UID
+pub const INSTANCE_CREATOR_UID: Tag = Tag(0x0008, 0x0014);
+/// (0008,0015) Instance Coercion Date Time
+pub const INSTANCE_COERCION_DATE_TIME: Tag = Tag(0x0008, 0x0015);
+/// (0008,0016) SOP Class UID
+pub const SOP_CLASS_UID: Tag = Tag(0x0008, 0x0016);
+/// (0008,0018) SOP Instance UID
+pub const SOP_INSTANCE_UID: Tag = Tag(0x0008, 0x0018);
+/// (0008,001A) Related General SOP Class UID
+pub const RELATED_GENERAL_SOP_CLASS_UID: Tag = Tag(0x0008, 0x001A);
+/// (0008,001B) Original Specialized SOP Class UID
+pub const ORIGINAL_SPECIALIZED_SOP_CLASS_UID: Tag = Tag(0x0008, 0x001B);
+/// (0008,0020) Study Date
+pub const STUDY_DATE: Tag = Tag(0x0008, 0x0020);
+/// (0008,0021) Series Date
+pub const SERIES_DATE: Tag = Tag(0x0008, 0x0021);
+/// (0008,0022) Acquisition Date
+pub const ACQUISITION_DATE: Tag = Tag(0x0008, 0x0022);
+/// (0008,0023) Content Date
+pub const CONTENT_DATE: Tag = Tag(0x0008, 0x0023);
+/// (0008,0024) Overlay Date (retired)
+pub const OVERLAY_DATE: Tag = Tag(0x0008, 0x0024);
+/// (0008,0025) Curve Date (retired)
+pub const CURVE_DATE: Tag = Tag(0x0008, 0x0025);
+/// (0008,002A) Acquisition Date Time
+pub const ACQUISITION_DATE_TIME: Tag = Tag(0x0008, 0x002A);
+/// (0008,0030) Study Time
+pub const STUDY_TIME: Tag = Tag(0x0008, 0x0030);
+/// (0008,0031) Series Time
+pub const SERIES_TIME: Tag = Tag(0x0008, 0x0031);
+/// (0008,0032) Acquisition Time
+pub const ACQUISITION_TIME: Tag = Tag(0x0008, 0x0032);
+/// (0008,0033) Content Time
+pub const CONTENT_TIME: Tag = Tag(0x0008, 0x0033);
+/// (0008,0034) Overlay Time (retired)
+pub const OVERLAY_TIME: Tag = Tag(0x0008, 0x0034);
+/// (0008,0035) Curve Time (retired)
+pub const CURVE_TIME: Tag = Tag(0x0008, 0x0035);
+/// (0008,0040) Data Set Type (retired)
+pub const DATA_SET_TYPE: Tag = Tag(0x0008, 0x0040);
+/// (0008,0041) Data Set Subtype (retired)
+pub const DATA_SET_SUBTYPE: Tag = Tag(0x0008, 0x0041);
+/// (0008,0042) Nuclear Medicine Series Type (retired)
+pub const NUCLEAR_MEDICINE_SERIES_TYPE: Tag = Tag(0x0008, 0x0042);
+/// (0008,0050) Accession Number
+pub const ACCESSION_NUMBER: Tag = Tag(0x0008, 0x0050);
+/// (0008,0051) Issuer of Accession Number Sequence
+pub const ISSUER_OF_ACCESSION_NUMBER_SEQUENCE: Tag = Tag(0x0008, 0x0051);
+/// (0008,0052) Query Retrieve Level
+pub const QUERY_RETRIEVE_LEVEL: Tag = Tag(0x0008, 0x0052);
+/// (0008,0053) Query Retrieve View
+pub const QUERY_RETRIEVE_VIEW: Tag = Tag(0x0008, 0x0053);
+/// (0008,0054) Retrieve AE Title
+pub const RETRIEVE_AE_TITLE: Tag = Tag(0x0008, 0x0054);
+/// (0008,0055) Station AE Title
+pub const STATION_AE_TITLE: Tag = Tag(0x0008, 0x0055);
+/// (0008,0056) Instance Availability
+pub const INSTANCE_AVAILABILITY: Tag = Tag(0x0008, 0x0056);
+/// (0008,0058) Failed SOP Instance UID List
+pub const FAILED_SOP_INSTANCE_UID_LIST: Tag = Tag(0x0008, 0x0058);
+/// (0008,0060) Modality
+pub const MODALITY: Tag = Tag(0x0008, 0x0060);
+/// (0008,0061) Modalities in Study
+pub const MODALITIES_IN_STUDY: Tag = Tag(0x0008, 0x0061);
+/// (0008,0062) SOP Classes in Study
+pub const SOP_CLASSES_IN_STUDY: Tag = Tag(0x0008, 0x0062);
+/// (0008,0063) Anatomic Regions in Study Code Sequence
+pub const ANATOMIC_REGIONS_IN_STUDY_CODE_SEQUENCE: Tag = Tag(0x0008, 0x0063);
+/// (0008,0064) Conversion Type
+pub const CONVERSION_TYPE: Tag = Tag(0x0008, 0x0064);
+/// (0008,0068) Presentation Intent Type
+pub const PRESENTATION_INTENT_TYPE: Tag = Tag(0x0008, 0x0068);
+/// (0008,0070) Manufacturer
+pub const MANUFACTURER: Tag = Tag(0x0008, 0x0070);
+/// (0008,0080) Institution Name
+pub const INSTITUTION_NAME: Tag = Tag(0x0008, 0x0080);
+/// (0008,0081) Institution Address
+pub const INSTITUTION_ADDRESS: Tag = Tag(0x0008, 0x0081);
+/// (0008,0082) Institution Code Sequence
+pub const INSTITUTION_CODE_SEQUENCE: Tag = Tag(0x0008, 0x0082);
+/// (0008,0090) Referring Physician's Name
+pub const REFERRING_PHYSICIAN_NAME: Tag = Tag(0x0008, 0x0090);
+/// (0008,0092) Referring Physician's Address
+pub const REFERRING_PHYSICIAN_ADDRESS: Tag = Tag(0x0008, 0x0092);
+/// (0008,0094) Referring Physician's Telephone Numbers
+pub const REFERRING_PHYSICIAN_TELEPHONE_NUMBERS: Tag = Tag(0x0008, 0x0094);
+/// (0008,0096) Referring Physician Identification Sequence
+pub const REFERRING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x0096);
+/// (0008,009C) Consulting Physician Name
+pub const CONSULTING_PHYSICIAN_NAME: Tag = Tag(0x0008, 0x009C);
+/// (0008,009D) Consulting Physician Identification Sequence
+pub const CONSULTING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x009D);
+/// (0008,0100) Code Value
+pub const CODE_VALUE: Tag = Tag(0x0008, 0x0100);
+/// (0008,0101) Extended Code Value
+pub const EXTENDED_CODE_VALUE: Tag = Tag(0x0008, 0x0101);
+/// (0008,0102) Coding Scheme Designator
+pub const CODING_SCHEME_DESIGNATOR: Tag = Tag(0x0008, 0x0102);
+/// (0008,0103) Coding Scheme Version
+pub const CODING_SCHEME_VERSION: Tag = Tag(0x0008, 0x0103);
+/// (0008,0104) Code Meaning
+pub const CODE_MEANING: Tag = Tag(0x0008, 0x0104);
+/// (0008,0105) Mapping Resource
+pub const MAPPING_RESOURCE: Tag = Tag(0x0008, 0x0105);
+/// (0008,0106) Context Group Version
+pub const CONTEXT_GROUP_VERSION: Tag = Tag(0x0008, 0x0106);
+/// (0008,0107) Context Group Local Version
+pub const CONTEXT_GROUP_LOCAL_VERSION: Tag = Tag(0x0008, 0x0107);
+/// (0008,0108) Extended Code Meaning
+pub const EXTENDED_CODE_MEANING: Tag = Tag(0x0008, 0x0108);
+/// (0008,0109) Coding Scheme Resources Sequence
+pub const CODING_SCHEME_RESOURCES_SEQUENCE: Tag = Tag(0x0008, 0x0109);
+/// (0008,010A) Coding Scheme URL Type
+pub const CODING_SCHEME_URL_TYPE: Tag = Tag(0x0008, 0x010A);
+/// (0008,010B) Context Group Extension Flag
+pub const CONTEXT_GROUP_EXTENSION_FLAG: Tag = Tag(0x0008, 0x010B);
+/// (0008,010C) Coding Scheme UID
+pub const CODING_SCHEME_UID: Tag = Tag(0x0008, 0x010C);
+/// (0008,010D) Context Group Extension Creator UID
+pub const CONTEXT_GROUP_EXTENSION_CREATOR_UID: Tag = Tag(0x0008, 0x010D);
+/// (0008,010E) Coding Scheme URL
+pub const CODING_SCHEME_URL: Tag = Tag(0x0008, 0x010E);
+/// (0008,010F) Context Identifier
+pub const CONTEXT_IDENTIFIER: Tag = Tag(0x0008, 0x010F);
+/// (0008,0110) Coding Scheme Identification Sequence
+pub const CODING_SCHEME_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x0110);
+/// (0008,0112) Coding Scheme Registry
+pub const CODING_SCHEME_REGISTRY: Tag = Tag(0x0008, 0x0112);
+/// (0008,0114) Coding Scheme External ID
+pub const CODING_SCHEME_EXTERNAL_ID: Tag = Tag(0x0008, 0x0114);
+/// (0008,0115) Coding Scheme Name
+pub const CODING_SCHEME_NAME: Tag = Tag(0x0008, 0x0115);
+/// (0008,0116) Coding Scheme Responsible Organization
+pub const CODING_SCHEME_RESPONSIBLE_ORGANIZATION: Tag = Tag(0x0008, 0x0116);
+/// (0008,0117) Context UID
+pub const CONTEXT_UID: Tag = Tag(0x0008, 0x0117);
+/// (0008,0118) Mapping Resource UID
+pub const MAPPING_RESOURCE_UID: Tag = Tag(0x0008, 0x0118);
+/// (0008,0119) Long Code Value
+pub const LONG_CODE_VALUE: Tag = Tag(0x0008, 0x0119);
+/// (0008,0120) URN Code Value
+pub const URN_CODE_VALUE: Tag = Tag(0x0008, 0x0120);
+/// (0008,0121) Equivalent Code Sequence
+pub const EQUIVALENT_CODE_SEQUENCE: Tag = Tag(0x0008, 0x0121);
+/// (0008,0122) Mapping Resource Name
+pub const MAPPING_RESOURCE_NAME: Tag = Tag(0x0008, 0x0122);
+/// (0008,0123) Context Group Identification Sequence
+pub const CONTEXT_GROUP_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x0123);
+/// (0008,0124) Mapping Resource Identification Sequence
+pub const MAPPING_RESOURCE_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x0124);
+/// (0008,0201) Timezone Offset from UTC
+pub const TIMEZONE_OFFSET_FROM_UTC: Tag = Tag(0x0008, 0x0201);
+/// (0008,0220) Responsible Group Code Sequence
+pub const RESPONSIBLE_GROUP_CODE_SEQUENCE: Tag = Tag(0x0008, 0x0220);
+/// (0008,0221) Equipment Modality
+pub const EQUIPMENT_MODALITY: Tag = Tag(0x0008, 0x0221);
+/// (0008,0222) Manufacturer Related Model Group
+pub const MANUFACTURER_RELATED_MODEL_GROUP: Tag = Tag(0x0008, 0x0222);
+/// (0008,0300) Private Data Element Characteristics Sequence
+pub const PRIVATE_DATA_ELEMENT_CHARACTERISTICS_SEQUENCE: Tag = Tag(0x0008, 0x0300);
+/// (0008,0301) Private Group Reference
+pub const PRIVATE_GROUP_REFERENCE: Tag = Tag(0x0008, 0x0301);
+/// (0008,0302) Private Creator Reference
+pub const PRIVATE_CREATOR_REFERENCE: Tag = Tag(0x0008, 0x0302);
+/// (0008,0303) Block Identifying Information Status
+pub const BLOCK_IDENTIFYING_INFORMATION_STATUS: Tag = Tag(0x0008, 0x0303);
+/// (0008,0304) Nonidentifying Private Elements
+pub const NONIDENTIFYING_PRIVATE_ELEMENTS: Tag = Tag(0x0008, 0x0304);
+/// (0008,0305) Deidentification Action Sequence
+pub const DEIDENTIFICATION_ACTION_SEQUENCE: Tag = Tag(0x0008, 0x0305);
+/// (0008,0306) Identifying Private Elements
+pub const IDENTIFYING_PRIVATE_ELEMENTS: Tag = Tag(0x0008, 0x0306);
+/// (0008,0307) Deidentification Action
+pub const DEIDENTIFICATION_ACTION: Tag = Tag(0x0008, 0x0307);
+/// (0008,0308) Private Data Element
+pub const PRIVATE_DATA_ELEMENT: Tag = Tag(0x0008, 0x0308);
+/// (0008,0309) Private Data Element Value Multiplicity
+pub const PRIVATE_DATA_ELEMENT_VALUE_MULTIPLICITY: Tag = Tag(0x0008, 0x0309);
+/// (0008,030A) Private Data Element Value Representation
+pub const PRIVATE_DATA_ELEMENT_VALUE_REPRESENTATION: Tag = Tag(0x0008, 0x030A);
+/// (0008,030B) Private Data Element Number of Items
+pub const PRIVATE_DATA_ELEMENT_NUMBER_OF_ITEMS: Tag = Tag(0x0008, 0x030B);
+/// (0008,030C) Private Data Element Name
+pub const PRIVATE_DATA_ELEMENT_NAME: Tag = Tag(0x0008, 0x030C);
+/// (0008,030D) Private Data Element Keyword
+pub const PRIVATE_DATA_ELEMENT_KEYWORD: Tag = Tag(0x0008, 0x030D);
+/// (0008,030E) Private Data Element Description
+pub const PRIVATE_DATA_ELEMENT_DESCRIPTION: Tag = Tag(0x0008, 0x030E);
+/// (0008,030F) Private Data Element Encoding
+pub const PRIVATE_DATA_ELEMENT_ENCODING: Tag = Tag(0x0008, 0x030F);
+/// (0008,0310) Private Data Element Definition Sequence
+pub const PRIVATE_DATA_ELEMENT_DEFINITION_SEQUENCE: Tag = Tag(0x0008, 0x0310);
+/// (0008,1000) Network ID (retired)
+pub const NETWORK_ID: Tag = Tag(0x0008, 0x1000);
+/// (0008,1010) Station Name
+pub const STATION_NAME: Tag = Tag(0x0008, 0x1010);
+/// (0008,1030) Study Description
+pub const STUDY_DESCRIPTION: Tag = Tag(0x0008, 0x1030);
+/// (0008,1032) Procedure Code Sequence
+pub const PROCEDURE_CODE_SEQUENCE: Tag = Tag(0x0008, 0x1032);
+/// (0008,103E) Series Description
+pub const SERIES_DESCRIPTION: Tag = Tag(0x0008, 0x103E);
+/// (0008,103F) Series Description Code Sequence
+pub const SERIES_DESCRIPTION_CODE_SEQUENCE: Tag = Tag(0x0008, 0x103F);
+/// (0008,1040) Institutional Department Name
+pub const INSTITUTIONAL_DEPARTMENT_NAME: Tag = Tag(0x0008, 0x1040);
+/// (0008,1041) Institutional Department Type Code Sequence
+pub const INSTITUTIONAL_DEPARTMENT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0008, 0x1041);
+/// (0008,1048) Physician(s) of Record
+pub const PHYSICIANS_OF_RECORD: Tag = Tag(0x0008, 0x1048);
+/// (0008,1049) Physician(s) of Record Identification Sequence
+pub const PHYSICIANS_OF_RECORD_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x1049);
+/// (0008,1050) Performing Physician's Name
+pub const PERFORMING_PHYSICIAN_NAME: Tag = Tag(0x0008, 0x1050);
+/// (0008,1052) Performing Physician Identification Sequence
+pub const PERFORMING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x1052);
+/// (0008,1060) Name of Physician(s) Reading Study
+pub const NAME_OF_PHYSICIANS_READING_STUDY: Tag = Tag(0x0008, 0x1060);
+/// (0008,1062) Physician(s) Reading Study Identification Sequence
+pub const PHYSICIANS_READING_STUDY_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x1062);
+/// (0008,1070) Operators' Name
+pub const OPERATORS_NAME: Tag = Tag(0x0008, 0x1070);
+/// (0008,1072) Operator Identification Sequence
+pub const OPERATOR_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0008, 0x1072);
+/// (0008,1080) Admitting Diagnoses Description
+pub const ADMITTING_DIAGNOSES_DESCRIPTION: Tag = Tag(0x0008, 0x1080);
+/// (0008,1084) Admitting Diagnoses Code Sequence
+pub const ADMITTING_DIAGNOSES_CODE_SEQUENCE: Tag = Tag(0x0008, 0x1084);
+/// (0008,1090) Manufacturer Model Name
+pub const MANUFACTURER_MODEL_NAME: Tag = Tag(0x0008, 0x1090);
+/// (0008,1100) Referenced Results Sequence (retired)
+pub const REFERENCED_RESULTS_SEQUENCE: Tag = Tag(0x0008, 0x1100);
+/// (0008,1110) Referenced Study Sequence
+pub const REFERENCED_STUDY_SEQUENCE: Tag = Tag(0x0008, 0x1110);
+/// (0008,1111) Referenced Performed Procedure Step Sequence
+pub const REFERENCED_PERFORMED_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x0008, 0x1111);
+/// (0008,1115) Referenced Series Sequence
+pub const REFERENCED_SERIES_SEQUENCE: Tag = Tag(0x0008, 0x1115);
+/// (0008,1120) Referenced Patient Sequence
+pub const REFERENCED_PATIENT_SEQUENCE: Tag = Tag(0x0008, 0x1120);
+/// (0008,1125) Referenced Visit Sequence
+pub const REFERENCED_VISIT_SEQUENCE: Tag = Tag(0x0008, 0x1125);
+/// (0008,1130) Referenced Overlay Sequence (retired)
+pub const REFERENCED_OVERLAY_SEQUENCE: Tag = Tag(0x0008, 0x1130);
+/// (0008,1134) Referenced Stereometric Instance Sequence
+pub const REFERENCED_STEREOMETRIC_INSTANCE_SEQUENCE: Tag = Tag(0x0008, 0x1134);
+/// (0008,113A) Referenced Waveform Sequence
+pub const REFERENCED_WAVEFORM_SEQUENCE: Tag = Tag(0x0008, 0x113A);
+/// (0008,1140) Referenced Image Sequence
+pub const REFERENCED_IMAGE_SEQUENCE: Tag = Tag(0x0008, 0x1140);
+/// (0008,1145) Referenced Curve Sequence (retired)
+pub const REFERENCED_CURVE_SEQUENCE: Tag = Tag(0x0008, 0x1145);
+/// (0008,114A) Referenced Instance Sequence
+pub const REFERENCED_INSTANCE_SEQUENCE: Tag = Tag(0x0008, 0x114A);
+/// (0008,114B) Referenced Real World Value Mapping Instance Sequence
+pub const REFERENCED_REAL_WORLD_VALUE_MAPPING_INSTANCE_SEQUENCE: Tag = Tag(0x0008, 0x114B);
+/// (0008,1150) Referenced SOP Class UID
+pub const REFERENCED_SOP_CLASS_UID: Tag = Tag(0x0008, 0x1150);
+/// (0008,1155) Referenced SOP Instance UID
+pub const REFERENCED_SOP_INSTANCE_UID: Tag = Tag(0x0008, 0x1155);
+/// (0008,1156) Definition Source Sequence
+pub const DEFINITION_SOURCE_SEQUENCE: Tag = Tag(0x0008, 0x1156);
+/// (0008,115A) SOP Classes Supported
+pub const SOP_CLASSES_SUPPORTED: Tag = Tag(0x0008, 0x115A);
+/// (0008,1160) Referenced Frame Number
+pub const REFERENCED_FRAME_NUMBER: Tag = Tag(0x0008, 0x1160);
+/// (0008,1161) Simple Frame List
+pub const SIMPLE_FRAME_LIST: Tag = Tag(0x0008, 0x1161);
+/// (0008,1162) Calculated Frame List
+pub const CALCULATED_FRAME_LIST: Tag = Tag(0x0008, 0x1162);
+/// (0008,1163) Time Range
+pub const TIME_RANGE: Tag = Tag(0x0008, 0x1163);
+/// (0008,1164) Frame Extraction Sequence
+pub const FRAME_EXTRACTION_SEQUENCE: Tag = Tag(0x0008, 0x1164);
+/// (0008,1167) Multi-frame Source SOP Instance UID
+pub const MULTI_FRAME_SOURCE_SOP_INSTANCE_UID: Tag = Tag(0x0008, 0x1167);
+/// (0008,1190) Retrieve URL
+pub const RETRIEVE_URL: Tag = Tag(0x0008, 0x1190);
+/// (0008,1195) Transaction UID
+pub const TRANSACTION_UID: Tag = Tag(0x0008, 0x1195);
+/// (0008,1196) Warning Reason
+pub const WARNING_REASON: Tag = Tag(0x0008, 0x1196);
+/// (0008,1197) Failure Reason
+pub const FAILURE_REASON: Tag = Tag(0x0008, 0x1197);
+/// (0008,1198) Failed SOP Sequence
+pub const FAILED_SOP_SEQUENCE: Tag = Tag(0x0008, 0x1198);
+/// (0008,1199) Referenced SOP Sequence
+pub const REFERENCED_SOP_SEQUENCE: Tag = Tag(0x0008, 0x1199);
+/// (0008,119A) Other Failures Sequence
+pub const OTHER_FAILURES_SEQUENCE: Tag = Tag(0x0008, 0x119A);
+/// (0008,1200) Studies Containing Other Referenced Instances Sequence
+pub const STUDIES_CONTAINING_OTHER_REFERENCED_INSTANCES_SEQUENCE: Tag = Tag(0x0008, 0x1200);
+/// (0008,1250) Related Series Sequence
+pub const RELATED_SERIES_SEQUENCE: Tag = Tag(0x0008, 0x1250);
+/// (0008,2110) Lossy Image Compression Retired (retired)
+pub const LOSSY_IMAGE_COMPRESSION_RETIRED: Tag = Tag(0x0008, 0x2110);
+/// (0008,2111) Derivation Description
+pub const DERIVATION_DESCRIPTION: Tag = Tag(0x0008, 0x2111);
+/// (0008,2112) Source Image Sequence
+pub const SOURCE_IMAGE_SEQUENCE: Tag = Tag(0x0008, 0x2112);
+/// (0008,2120) Stage Name
+pub const STAGE_NAME: Tag = Tag(0x0008, 0x2120);
+/// (0008,2122) Stage Number
+pub const STAGE_NUMBER: Tag = Tag(0x0008, 0x2122);
+/// (0008,2124) Number of Stages
+pub const NUMBER_OF_STAGES: Tag = Tag(0x0008, 0x2124);
+/// (0008,2127) View Name
+pub const VIEW_NAME: Tag = Tag(0x0008, 0x2127);
+/// (0008,2128) View Number
+pub const VIEW_NUMBER: Tag = Tag(0x0008, 0x2128);
+/// (0008,2129) Number of Event Timers
+pub const NUMBER_OF_EVENT_TIMERS: Tag = Tag(0x0008, 0x2129);
+/// (0008,212A) Number of Views in Stage
+pub const NUMBER_OF_VIEWS_IN_STAGE: Tag = Tag(0x0008, 0x212A);
+/// (0008,2130) Event Elapsed Times
+pub const EVENT_ELAPSED_TIMES: Tag = Tag(0x0008, 0x2130);
+/// (0008,2132) Event Timer Names
+pub const EVENT_TIMER_NAMES: Tag = Tag(0x0008, 0x2132);
+/// (0008,2133) Event Timer Sequence
+pub const EVENT_TIMER_SEQUENCE: Tag = Tag(0x0008, 0x2133);
+/// (0008,2134) Event Time Offset
+pub const EVENT_TIME_OFFSET: Tag = Tag(0x0008, 0x2134);
+/// (0008,2135) Event Code Sequence
+pub const EVENT_CODE_SEQUENCE: Tag = Tag(0x0008, 0x2135);
+/// (0008,2142) Start Trim
+pub const START_TRIM: Tag = Tag(0x0008, 0x2142);
+/// (0008,2143) Stop Trim
+pub const STOP_TRIM: Tag = Tag(0x0008, 0x2143);
+/// (0008,2144) Recommended Display Frame Rate
+pub const RECOMMENDED_DISPLAY_FRAME_RATE: Tag = Tag(0x0008, 0x2144);
+/// (0008,2200) Transducer Position (retired)
+pub const TRANSDUCER_POSITION: Tag = Tag(0x0008, 0x2200);
+/// (0008,2204) Transducer Orientation (retired)
+pub const TRANSDUCER_ORIENTATION: Tag = Tag(0x0008, 0x2204);
+/// (0008,2208) Anatomic Structure (retired)
+pub const ANATOMIC_STRUCTURE: Tag = Tag(0x0008, 0x2208);
+/// (0008,2218) Anatomic Region Sequence
+pub const ANATOMIC_REGION_SEQUENCE: Tag = Tag(0x0008, 0x2218);
+/// (0008,2220) Anatomic Region Modifier Sequence
+pub const ANATOMIC_REGION_MODIFIER_SEQUENCE: Tag = Tag(0x0008, 0x2220);
+/// (0008,2228) Primary Anatomic Structure Sequence
+pub const PRIMARY_ANATOMIC_STRUCTURE_SEQUENCE: Tag = Tag(0x0008, 0x2228);
+/// (0008,2229) Anatomic Structure Space or Region Sequence (retired)
+pub const ANATOMIC_STRUCTURE_SPACE_OR_REGION_SEQUENCE: Tag = Tag(0x0008, 0x2229);
+/// (0008,2230) Primary Anatomic Structure Modifier Sequence
+pub const PRIMARY_ANATOMIC_STRUCTURE_MODIFIER_SEQUENCE: Tag = Tag(0x0008, 0x2230);
+/// (0008,2240) Transducer Position Sequence (retired)
+pub const TRANSDUCER_POSITION_SEQUENCE: Tag = Tag(0x0008, 0x2240);
+/// (0008,2242) Transducer Position Modifier Sequence (retired)
+pub const TRANSDUCER_POSITION_MODIFIER_SEQUENCE: Tag = Tag(0x0008, 0x2242);
+/// (0008,2244) Transducer Orientation Sequence (retired)
+pub const TRANSDUCER_ORIENTATION_SEQUENCE: Tag = Tag(0x0008, 0x2244);
+/// (0008,2246) Transducer Orientation Modifier Sequence (retired)
+pub const TRANSDUCER_ORIENTATION_MODIFIER_SEQUENCE: Tag = Tag(0x0008, 0x2246);
+/// (0008,2251) Anatomic Structure Space or Region Code Sequence Trial (retired)
+pub const ANATOMIC_STRUCTURE_SPACE_OR_REGION_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0008, 0x2251);
+/// (0008,2253) Anatomic Portal of Entrance Code Sequence Trial (retired)
+pub const ANATOMIC_PORTAL_OF_ENTRANCE_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0008, 0x2253);
+/// (0008,2255) Anatomic Approach Direction Code Sequence Trial (retired)
+pub const ANATOMIC_APPROACH_DIRECTION_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0008, 0x2255);
+/// (0008,2256) Anatomic Perspective Description Trial (retired)
+pub const ANATOMIC_PERSPECTIVE_DESCRIPTION_TRIAL: Tag = Tag(0x0008, 0x2256);
+/// (0008,2257) Anatomic Perspective Code Sequence Trial (retired)
+pub const ANATOMIC_PERSPECTIVE_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0008, 0x2257);
+/// (0008,2258) Anatomic Location of Examining Instrument Description Trial (retired)
+pub const ANATOMIC_LOCATION_OF_EXAMINING_INSTRUMENT_DESCRIPTION_TRIAL: Tag = Tag(0x0008, 0x2258);
+/// (0008,2259) Anatomic Location of Examining Instrument Code Sequence Trial (retired)
+pub const ANATOMIC_LOCATION_OF_EXAMINING_INSTRUMENT_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0008, 0x2259);
+/// (0008,225A) Anatomic Structure Space or Region Modifier Code Sequence Trial (retired)
+pub const ANATOMIC_STRUCTURE_SPACE_OR_REGION_MODIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0008, 0x225A);
+/// (0008,225C) On Axis Background Anatomic Structure Code Sequence Trial (retired)
+pub const ON_AXIS_BACKGROUND_ANATOMIC_STRUCTURE_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0008, 0x225C);
+/// (0008,3001) Alternate Representation Sequence
+pub const ALTERNATE_REPRESENTATION_SEQUENCE: Tag = Tag(0x0008, 0x3001);
+/// (0008,3010) Irradiation Event UID
+pub const IRRADIATION_EVENT_UID: Tag = Tag(0x0008, 0x3010);
+/// (0008,3011) Source Irradiation Event Sequence
+pub const SOURCE_IRRADIATION_EVENT_SEQUENCE: Tag = Tag(0x0008, 0x3011);
+/// (0008,3012) Radiopharmaceutical Administration Event UID
+pub const RADIOPHARMACEUTICAL_ADMINISTRATION_EVENT_UID: Tag = Tag(0x0008, 0x3012);
+/// (0008,4000) Identifying Comments (retired)
+pub const IDENTIFYING_COMMENTS: Tag = Tag(0x0008, 0x4000);
+/// (0008,9007) Frame Type
+pub const FRAME_TYPE: Tag = Tag(0x0008, 0x9007);
+/// (0008,9092) Referenced Image Evidence Sequence
+pub const REFERENCED_IMAGE_EVIDENCE_SEQUENCE: Tag = Tag(0x0008, 0x9092);
+/// (0008,9121) Referenced Raw Data Sequence
+pub const REFERENCED_RAW_DATA_SEQUENCE: Tag = Tag(0x0008, 0x9121);
+/// (0008,9123) Creator Version UID
+pub const CREATOR_VERSION_UID: Tag = Tag(0x0008, 0x9123);
+/// (0008,9124) Derivation Image Sequence
+pub const DERIVATION_IMAGE_SEQUENCE: Tag = Tag(0x0008, 0x9124);
+/// (0008,9154) Source Image Evidence Sequence
+pub const SOURCE_IMAGE_EVIDENCE_SEQUENCE: Tag = Tag(0x0008, 0x9154);
+/// (0008,9205) Pixel Presentation
+pub const PIXEL_PRESENTATION: Tag = Tag(0x0008, 0x9205);
+/// (0008,9206) Volumetric Properties
+pub const VOLUMETRIC_PROPERTIES: Tag = Tag(0x0008, 0x9206);
+/// (0008,9207) Volume Based Calculation Technique
+pub const VOLUME_BASED_CALCULATION_TECHNIQUE: Tag = Tag(0x0008, 0x9207);
+/// (0008,9208) Complex Image Component
+pub const COMPLEX_IMAGE_COMPONENT: Tag = Tag(0x0008, 0x9208);
+/// (0008,9209) Acquisition Contrast
+pub const ACQUISITION_CONTRAST: Tag = Tag(0x0008, 0x9209);
+/// (0008,9215) Derivation Code Sequence
+pub const DERIVATION_CODE_SEQUENCE: Tag = Tag(0x0008, 0x9215);
+/// (0008,9237) Referenced Presentation State Sequence
+pub const REFERENCED_PRESENTATION_STATE_SEQUENCE: Tag = Tag(0x0008, 0x9237);
+/// (0008,9410) Referenced Other Plane Sequence
+pub const REFERENCED_OTHER_PLANE_SEQUENCE: Tag = Tag(0x0008, 0x9410);
+/// (0008,9458) Frame Display Sequence
+pub const FRAME_DISPLAY_SEQUENCE: Tag = Tag(0x0008, 0x9458);
+/// (0008,9459) Recommended Display Frame Rate in Float
+pub const RECOMMENDED_DISPLAY_FRAME_RATE_IN_FLOAT: Tag = Tag(0x0008, 0x9459);
+/// (0008,9460) Skip Frame Range Flag
+pub const SKIP_FRAME_RANGE_FLAG: Tag = Tag(0x0008, 0x9460);
+/// (0010,0010) Patient's Name
+pub const PATIENT_NAME: Tag = Tag(0x0010, 0x0010);
+/// (0010,0020) Patient ID
+pub const PATIENT_ID: Tag = Tag(0x0010, 0x0020);
+/// (0010,0021) Issuer of Patient ID
+pub const ISSUER_OF_PATIENT_ID: Tag = Tag(0x0010, 0x0021);
+/// (0010,0022) Type of Patient ID
+pub const TYPE_OF_PATIENT_ID: Tag = Tag(0x0010, 0x0022);
+/// (0010,0024) Issuer of Patient ID Qualifiers Sequence
+pub const ISSUER_OF_PATIENT_ID_QUALIFIERS_SEQUENCE: Tag = Tag(0x0010, 0x0024);
+/// (0010,0026) Source Patient Group Identification Sequence
+pub const SOURCE_PATIENT_GROUP_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0010, 0x0026);
+/// (0010,0027) Group of Patients Identification Sequence
+pub const GROUP_OF_PATIENTS_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0010, 0x0027);
+/// (0010,0028) Subject Relative Position in Image
+pub const SUBJECT_RELATIVE_POSITION_IN_IMAGE: Tag = Tag(0x0010, 0x0028);
+/// (0010,0030) Patient's Birth Date
+pub const PATIENT_BIRTH_DATE: Tag = Tag(0x0010, 0x0030);
+/// (0010,0032) Patient's Birth Time
+pub const PATIENT_BIRTH_TIME: Tag = Tag(0x0010, 0x0032);
+/// (0010,0033) Patient Birth Date in Alternative Calendar
+pub const PATIENT_BIRTH_DATE_IN_ALTERNATIVE_CALENDAR: Tag = Tag(0x0010, 0x0033);
+/// (0010,0034) Patient Death Date in Alternative Calendar
+pub const PATIENT_DEATH_DATE_IN_ALTERNATIVE_CALENDAR: Tag = Tag(0x0010, 0x0034);
+/// (0010,0035) Patient Alternative Calendar
+pub const PATIENT_ALTERNATIVE_CALENDAR: Tag = Tag(0x0010, 0x0035);
+/// (0010,0040) Patient's Sex
+pub const PATIENT_SEX: Tag = Tag(0x0010, 0x0040);
+/// (0010,0050) Patient's Insurance Plan Code Sequence
+pub const PATIENT_INSURANCE_PLAN_CODE_SEQUENCE: Tag = Tag(0x0010, 0x0050);
+/// (0010,0101) Patient's Primary Language Code Sequence
+pub const PATIENT_PRIMARY_LANGUAGE_CODE_SEQUENCE: Tag = Tag(0x0010, 0x0101);
+/// (0010,0102) Patient's Primary Language Modifier Code Sequence
+pub const PATIENT_PRIMARY_LANGUAGE_MODIFIER_CODE_SEQUENCE: Tag = Tag(0x0010, 0x0102);
+/// (0010,0200) Quality Control Subject
+pub const QUALITY_CONTROL_SUBJECT: Tag = Tag(0x0010, 0x0200);
+/// (0010,0201) Quality Control Subject Type Code Sequence
+pub const QUALITY_CONTROL_SUBJECT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0010, 0x0201);
+/// (0010,0212) Strain Description
+pub const STRAIN_DESCRIPTION: Tag = Tag(0x0010, 0x0212);
+/// (0010,0213) Strain Nomenclature
+pub const STRAIN_NOMENCLATURE: Tag = Tag(0x0010, 0x0213);
+/// (0010,0214) Strain Stock Number
+pub const STRAIN_STOCK_NUMBER: Tag = Tag(0x0010, 0x0214);
+/// (0010,0215) Strain Source Registry Code Sequence
+pub const STRAIN_SOURCE_REGISTRY_CODE_SEQUENCE: Tag = Tag(0x0010, 0x0215);
+/// (0010,0216) Strain Stock Sequence
+pub const STRAIN_STOCK_SEQUENCE: Tag = Tag(0x0010, 0x0216);
+/// (0010,0217) Strain Source
+pub const STRAIN_SOURCE: Tag = Tag(0x0010, 0x0217);
+/// (0010,0218) Strain Additional Information
+pub const STRAIN_ADDITIONAL_INFORMATION: Tag = Tag(0x0010, 0x0218);
+/// (0010,0219) Strain Code Sequence
+pub const STRAIN_CODE_SEQUENCE: Tag = Tag(0x0010, 0x0219);
+/// (0010,0221) Genetic Modifications Sequence
+pub const GENETIC_MODIFICATIONS_SEQUENCE: Tag = Tag(0x0010, 0x0221);
+/// (0010,0222) Genetic Modifications Description
+pub const GENETIC_MODIFICATIONS_DESCRIPTION: Tag = Tag(0x0010, 0x0222);
+/// (0010,0223) Genetic Modifications Nomenclature
+pub const GENETIC_MODIFICATIONS_NOMENCLATURE: Tag = Tag(0x0010, 0x0223);
+/// (0010,0229) Genetic Modifications Code Sequence
+pub const GENETIC_MODIFICATIONS_CODE_SEQUENCE: Tag = Tag(0x0010, 0x0229);
+/// (0010,1000) Other Patient I Ds (retired)
+pub const OTHER_PATIENT_IDS: Tag = Tag(0x0010, 0x1000);
+/// (0010,1001) Other Patient Names
+pub const OTHER_PATIENT_NAMES: Tag = Tag(0x0010, 0x1001);
+/// (0010,1002) Other Patient I Ds Sequence
+pub const OTHER_PATIENT_IDS_SEQUENCE: Tag = Tag(0x0010, 0x1002);
+/// (0010,1005) Patient's Birth Name
+pub const PATIENT_BIRTH_NAME: Tag = Tag(0x0010, 0x1005);
+/// (0010,1010) Patient's Age
+pub const PATIENT_AGE: Tag = Tag(0x0010, 0x1010);
+/// (0010,1020) Patient's Size
+pub const PATIENT_SIZE: Tag = Tag(0x0010, 0x1020);
+/// (0010,1021) Patient's Size Code Sequence
+pub const PATIENT_SIZE_CODE_SEQUENCE: Tag = Tag(0x0010, 0x1021);
+/// (0010,1022) Patient Body Mass Index
+pub const PATIENT_BODY_MASS_INDEX: Tag = Tag(0x0010, 0x1022);
+/// (0010,1023) Measured AP Dimension
+pub const MEASURED_AP_DIMENSION: Tag = Tag(0x0010, 0x1023);
+/// (0010,1024) Measured Lateral Dimension
+pub const MEASURED_LATERAL_DIMENSION: Tag = Tag(0x0010, 0x1024);
+/// (0010,1030) Patient's Weight
+pub const PATIENT_WEIGHT: Tag = Tag(0x0010, 0x1030);
+/// (0010,1040) Patient's Address
+pub const PATIENT_ADDRESS: Tag = Tag(0x0010, 0x1040);
+/// (0010,1050) Insurance Plan Identification (retired)
+pub const INSURANCE_PLAN_IDENTIFICATION: Tag = Tag(0x0010, 0x1050);
+/// (0010,1060) Patient's Mother's Birth Name
+pub const PATIENT_MOTHER_BIRTH_NAME: Tag = Tag(0x0010, 0x1060);
+/// (0010,1080) Military Rank
+pub const MILITARY_RANK: Tag = Tag(0x0010, 0x1080);
+/// (0010,1081) Branch of Service
+pub const BRANCH_OF_SERVICE: Tag = Tag(0x0010, 0x1081);
+/// (0010,1090) Medical Record Locator (retired)
+pub const MEDICAL_RECORD_LOCATOR: Tag = Tag(0x0010, 0x1090);
+/// (0010,1100) Referenced Patient Photo Sequence
+pub const REFERENCED_PATIENT_PHOTO_SEQUENCE: Tag = Tag(0x0010, 0x1100);
+/// (0010,2000) Medical Alerts
+pub const MEDICAL_ALERTS: Tag = Tag(0x0010, 0x2000);
+/// (0010,2110) Allergies
+pub const ALLERGIES: Tag = Tag(0x0010, 0x2110);
+/// (0010,2150) Country of Residence
+pub const COUNTRY_OF_RESIDENCE: Tag = Tag(0x0010, 0x2150);
+/// (0010,2152) Region of Residence
+pub const REGION_OF_RESIDENCE: Tag = Tag(0x0010, 0x2152);
+/// (0010,2154) Patient's Telephone Numbers
+pub const PATIENT_TELEPHONE_NUMBERS: Tag = Tag(0x0010, 0x2154);
+/// (0010,2155) Patient Telecom Information
+pub const PATIENT_TELECOM_INFORMATION: Tag = Tag(0x0010, 0x2155);
+/// (0010,2160) Ethnic Group
+pub const ETHNIC_GROUP: Tag = Tag(0x0010, 0x2160);
+/// (0010,2180) Occupation
+pub const OCCUPATION: Tag = Tag(0x0010, 0x2180);
+/// (0010,21A0) Smoking Status
+pub const SMOKING_STATUS: Tag = Tag(0x0010, 0x21A0);
+/// (0010,21B0) Additional Patient History
+pub const ADDITIONAL_PATIENT_HISTORY: Tag = Tag(0x0010, 0x21B0);
+/// (0010,21C0) Pregnancy Status
+pub const PREGNANCY_STATUS: Tag = Tag(0x0010, 0x21C0);
+/// (0010,21D0) Last Menstrual Date
+pub const LAST_MENSTRUAL_DATE: Tag = Tag(0x0010, 0x21D0);
+/// (0010,21F0) Patient's Religious Preference
+pub const PATIENT_RELIGIOUS_PREFERENCE: Tag = Tag(0x0010, 0x21F0);
+/// (0010,2201) Patient Species Description
+pub const PATIENT_SPECIES_DESCRIPTION: Tag = Tag(0x0010, 0x2201);
+/// (0010,2202) Patient Species Code Sequence
+pub const PATIENT_SPECIES_CODE_SEQUENCE: Tag = Tag(0x0010, 0x2202);
+/// (0010,2203) Patient Sex Neutered
+pub const PATIENT_SEX_NEUTERED: Tag = Tag(0x0010, 0x2203);
+/// (0010,2210) Anatomical Orientation Type
+pub const ANATOMICAL_ORIENTATION_TYPE: Tag = Tag(0x0010, 0x2210);
+/// (0010,2292) Patient Breed Description
+pub const PATIENT_BREED_DESCRIPTION: Tag = Tag(0x0010, 0x2292);
+/// (0010,2293) Patient Breed Code Sequence
+pub const PATIENT_BREED_CODE_SEQUENCE: Tag = Tag(0x0010, 0x2293);
+/// (0010,2294) Breed Registration Sequence
+pub const BREED_REGISTRATION_SEQUENCE: Tag = Tag(0x0010, 0x2294);
+/// (0010,2295) Breed Registration Number
+pub const BREED_REGISTRATION_NUMBER: Tag = Tag(0x0010, 0x2295);
+/// (0010,2296) Breed Registry Code Sequence
+pub const BREED_REGISTRY_CODE_SEQUENCE: Tag = Tag(0x0010, 0x2296);
+/// (0010,2297) Responsible Person
+pub const RESPONSIBLE_PERSON: Tag = Tag(0x0010, 0x2297);
+/// (0010,2298) Responsible Person Role
+pub const RESPONSIBLE_PERSON_ROLE: Tag = Tag(0x0010, 0x2298);
+/// (0010,2299) Responsible Organization
+pub const RESPONSIBLE_ORGANIZATION: Tag = Tag(0x0010, 0x2299);
+/// (0010,4000) Patient Comments
+pub const PATIENT_COMMENTS: Tag = Tag(0x0010, 0x4000);
+/// (0010,9431) Examined Body Thickness
+pub const EXAMINED_BODY_THICKNESS: Tag = Tag(0x0010, 0x9431);
+/// (0012,0010) Clinical Trial Sponsor Name
+pub const CLINICAL_TRIAL_SPONSOR_NAME: Tag = Tag(0x0012, 0x0010);
+/// (0012,0020) Clinical Trial Protocol ID
+pub const CLINICAL_TRIAL_PROTOCOL_ID: Tag = Tag(0x0012, 0x0020);
+/// (0012,0021) Clinical Trial Protocol Name
+pub const CLINICAL_TRIAL_PROTOCOL_NAME: Tag = Tag(0x0012, 0x0021);
+/// (0012,0030) Clinical Trial Site ID
+pub const CLINICAL_TRIAL_SITE_ID: Tag = Tag(0x0012, 0x0030);
+/// (0012,0031) Clinical Trial Site Name
+pub const CLINICAL_TRIAL_SITE_NAME: Tag = Tag(0x0012, 0x0031);
+/// (0012,0040) Clinical Trial Subject ID
+pub const CLINICAL_TRIAL_SUBJECT_ID: Tag = Tag(0x0012, 0x0040);
+/// (0012,0042) Clinical Trial Subject Reading ID
+pub const CLINICAL_TRIAL_SUBJECT_READING_ID: Tag = Tag(0x0012, 0x0042);
+/// (0012,0050) Clinical Trial Time Point ID
+pub const CLINICAL_TRIAL_TIME_POINT_ID: Tag = Tag(0x0012, 0x0050);
+/// (0012,0051) Clinical Trial Time Point Description
+pub const CLINICAL_TRIAL_TIME_POINT_DESCRIPTION: Tag = Tag(0x0012, 0x0051);
+/// (0012,0052) Longitudinal Temporal Offset from Event
+pub const LONGITUDINAL_TEMPORAL_OFFSET_FROM_EVENT: Tag = Tag(0x0012, 0x0052);
+/// (0012,0053) Longitudinal Temporal Event Type
+pub const LONGITUDINAL_TEMPORAL_EVENT_TYPE: Tag = Tag(0x0012, 0x0053);
+/// (0012,0060) Clinical Trial Coordinating Center Name
+pub const CLINICAL_TRIAL_COORDINATING_CENTER_NAME: Tag = Tag(0x0012, 0x0060);
+/// (0012,0062) Patient Identity Removed
+pub const PATIENT_IDENTITY_REMOVED: Tag = Tag(0x0012, 0x0062);
+/// (0012,0063) Deidentification Method
+pub const DEIDENTIFICATION_METHOD: Tag = Tag(0x0012, 0x0063);
+/// (0012,0064) Deidentification Method Code Sequence
+pub const DEIDENTIFICATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0012, 0x0064);
+/// (0012,0071) Clinical Trial Series ID
+pub const CLINICAL_TRIAL_SERIES_ID: Tag = Tag(0x0012, 0x0071);
+/// (0012,0072) Clinical Trial Series Description
+pub const CLINICAL_TRIAL_SERIES_DESCRIPTION: Tag = Tag(0x0012, 0x0072);
+/// (0012,0081) Clinical Trial Protocol Ethics Committee Name
+pub const CLINICAL_TRIAL_PROTOCOL_ETHICS_COMMITTEE_NAME: Tag = Tag(0x0012, 0x0081);
+/// (0012,0082) Clinical Trial Protocol Ethics Committee Approval Number
+pub const CLINICAL_TRIAL_PROTOCOL_ETHICS_COMMITTEE_APPROVAL_NUMBER: Tag = Tag(0x0012, 0x0082);
+/// (0012,0083) Consent for Clinical Trial Use Sequence
+pub const CONSENT_FOR_CLINICAL_TRIAL_USE_SEQUENCE: Tag = Tag(0x0012, 0x0083);
+/// (0012,0084) Distribution Type
+pub const DISTRIBUTION_TYPE: Tag = Tag(0x0012, 0x0084);
+/// (0012,0085) Consent for Distribution Flag
+pub const CONSENT_FOR_DISTRIBUTION_FLAG: Tag = Tag(0x0012, 0x0085);
+/// (0012,0086) Ethics Committee Approval Effectiveness Start Date
+pub const ETHICS_COMMITTEE_APPROVAL_EFFECTIVENESS_START_DATE: Tag = Tag(0x0012, 0x0086);
+/// (0012,0087) Ethics Committee Approval Effectiveness End Date
+pub const ETHICS_COMMITTEE_APPROVAL_EFFECTIVENESS_END_DATE: Tag = Tag(0x0012, 0x0087);
+/// (0014,0023) CAD File Format (retired)
+pub const CAD_FILE_FORMAT: Tag = Tag(0x0014, 0x0023);
+/// (0014,0024) Component Reference System (retired)
+pub const COMPONENT_REFERENCE_SYSTEM: Tag = Tag(0x0014, 0x0024);
+/// (0014,0025) Component Manufacturing Procedure
+pub const COMPONENT_MANUFACTURING_PROCEDURE: Tag = Tag(0x0014, 0x0025);
+/// (0014,0028) Component Manufacturer
+pub const COMPONENT_MANUFACTURER: Tag = Tag(0x0014, 0x0028);
+/// (0014,0030) Material Thickness
+pub const MATERIAL_THICKNESS: Tag = Tag(0x0014, 0x0030);
+/// (0014,0032) Material Pipe Diameter
+pub const MATERIAL_PIPE_DIAMETER: Tag = Tag(0x0014, 0x0032);
+/// (0014,0034) Material Isolation Diameter
+pub const MATERIAL_ISOLATION_DIAMETER: Tag = Tag(0x0014, 0x0034);
+/// (0014,0042) Material Grade
+pub const MATERIAL_GRADE: Tag = Tag(0x0014, 0x0042);
+/// (0014,0044) Material Properties Description
+pub const MATERIAL_PROPERTIES_DESCRIPTION: Tag = Tag(0x0014, 0x0044);
+/// (0014,0045) Material Properties File Format Retired (retired)
+pub const MATERIAL_PROPERTIES_FILE_FORMAT_RETIRED: Tag = Tag(0x0014, 0x0045);
+/// (0014,0046) Material Notes
+pub const MATERIAL_NOTES: Tag = Tag(0x0014, 0x0046);
+/// (0014,0050) Component Shape
+pub const COMPONENT_SHAPE: Tag = Tag(0x0014, 0x0050);
+/// (0014,0052) Curvature Type
+pub const CURVATURE_TYPE: Tag = Tag(0x0014, 0x0052);
+/// (0014,0054) Outer Diameter
+pub const OUTER_DIAMETER: Tag = Tag(0x0014, 0x0054);
+/// (0014,0056) Inner Diameter
+pub const INNER_DIAMETER: Tag = Tag(0x0014, 0x0056);
+/// (0014,0100) Component Welder I Ds
+pub const COMPONENT_WELDER_IDS: Tag = Tag(0x0014, 0x0100);
+/// (0014,0101) Secondary Approval Status
+pub const SECONDARY_APPROVAL_STATUS: Tag = Tag(0x0014, 0x0101);
+/// (0014,0102) Secondary Review Date
+pub const SECONDARY_REVIEW_DATE: Tag = Tag(0x0014, 0x0102);
+/// (0014,0103) Secondary Review Time
+pub const SECONDARY_REVIEW_TIME: Tag = Tag(0x0014, 0x0103);
+/// (0014,0104) Secondary Reviewer Name
+pub const SECONDARY_REVIEWER_NAME: Tag = Tag(0x0014, 0x0104);
+/// (0014,0105) Repair ID
+pub const REPAIR_ID: Tag = Tag(0x0014, 0x0105);
+/// (0014,0106) Multiple Component Approval Sequence
+pub const MULTIPLE_COMPONENT_APPROVAL_SEQUENCE: Tag = Tag(0x0014, 0x0106);
+/// (0014,0107) Other Approval Status
+pub const OTHER_APPROVAL_STATUS: Tag = Tag(0x0014, 0x0107);
+/// (0014,0108) Other Secondary Approval Status
+pub const OTHER_SECONDARY_APPROVAL_STATUS: Tag = Tag(0x0014, 0x0108);
+/// (0014,1010) Actual Environmental Conditions
+pub const ACTUAL_ENVIRONMENTAL_CONDITIONS: Tag = Tag(0x0014, 0x1010);
+/// (0014,1020) Expiry Date
+pub const EXPIRY_DATE: Tag = Tag(0x0014, 0x1020);
+/// (0014,1040) Environmental Conditions
+pub const ENVIRONMENTAL_CONDITIONS: Tag = Tag(0x0014, 0x1040);
+/// (0014,2002) Evaluator Sequence
+pub const EVALUATOR_SEQUENCE: Tag = Tag(0x0014, 0x2002);
+/// (0014,2004) Evaluator Number
+pub const EVALUATOR_NUMBER: Tag = Tag(0x0014, 0x2004);
+/// (0014,2006) Evaluator Name
+pub const EVALUATOR_NAME: Tag = Tag(0x0014, 0x2006);
+/// (0014,2008) Evaluation Attempt
+pub const EVALUATION_ATTEMPT: Tag = Tag(0x0014, 0x2008);
+/// (0014,2012) Indication Sequence
+pub const INDICATION_SEQUENCE: Tag = Tag(0x0014, 0x2012);
+/// (0014,2014) Indication Number
+pub const INDICATION_NUMBER: Tag = Tag(0x0014, 0x2014);
+/// (0014,2016) Indication Label
+pub const INDICATION_LABEL: Tag = Tag(0x0014, 0x2016);
+/// (0014,2018) Indication Description
+pub const INDICATION_DESCRIPTION: Tag = Tag(0x0014, 0x2018);
+/// (0014,201A) Indication Type
+pub const INDICATION_TYPE: Tag = Tag(0x0014, 0x201A);
+/// (0014,201C) Indication Disposition
+pub const INDICATION_DISPOSITION: Tag = Tag(0x0014, 0x201C);
+/// (0014,201E) Indication ROI Sequence
+pub const INDICATION_ROI_SEQUENCE: Tag = Tag(0x0014, 0x201E);
+/// (0014,2030) Indication Physical Property Sequence
+pub const INDICATION_PHYSICAL_PROPERTY_SEQUENCE: Tag = Tag(0x0014, 0x2030);
+/// (0014,2032) Property Label
+pub const PROPERTY_LABEL: Tag = Tag(0x0014, 0x2032);
+/// (0014,2202) Coordinate System Number of Axes
+pub const COORDINATE_SYSTEM_NUMBER_OF_AXES: Tag = Tag(0x0014, 0x2202);
+/// (0014,2204) Coordinate System Axes Sequence
+pub const COORDINATE_SYSTEM_AXES_SEQUENCE: Tag = Tag(0x0014, 0x2204);
+/// (0014,2206) Coordinate System Axis Description
+pub const COORDINATE_SYSTEM_AXIS_DESCRIPTION: Tag = Tag(0x0014, 0x2206);
+/// (0014,2208) Coordinate System Data Set Mapping
+pub const COORDINATE_SYSTEM_DATA_SET_MAPPING: Tag = Tag(0x0014, 0x2208);
+/// (0014,220A) Coordinate System Axis Number
+pub const COORDINATE_SYSTEM_AXIS_NUMBER: Tag = Tag(0x0014, 0x220A);
+/// (0014,220C) Coordinate System Axis Type
+pub const COORDINATE_SYSTEM_AXIS_TYPE: Tag = Tag(0x0014, 0x220C);
+/// (0014,220E) Coordinate System Axis Units
+pub const COORDINATE_SYSTEM_AXIS_UNITS: Tag = Tag(0x0014, 0x220E);
+/// (0014,2210) Coordinate System Axis Values
+pub const COORDINATE_SYSTEM_AXIS_VALUES: Tag = Tag(0x0014, 0x2210);
+/// (0014,2220) Coordinate System Transform Sequence
+pub const COORDINATE_SYSTEM_TRANSFORM_SEQUENCE: Tag = Tag(0x0014, 0x2220);
+/// (0014,2222) Transform Description
+pub const TRANSFORM_DESCRIPTION: Tag = Tag(0x0014, 0x2222);
+/// (0014,2224) Transform Number of Axes
+pub const TRANSFORM_NUMBER_OF_AXES: Tag = Tag(0x0014, 0x2224);
+/// (0014,2226) Transform Order of Axes
+pub const TRANSFORM_ORDER_OF_AXES: Tag = Tag(0x0014, 0x2226);
+/// (0014,2228) Transformed Axis Units
+pub const TRANSFORMED_AXIS_UNITS: Tag = Tag(0x0014, 0x2228);
+/// (0014,222A) Coordinate System Transform Rotation and Scale Matrix
+pub const COORDINATE_SYSTEM_TRANSFORM_ROTATION_AND_SCALE_MATRIX: Tag = Tag(0x0014, 0x222A);
+/// (0014,222C) Coordinate System Transform Translation Matrix
+pub const COORDINATE_SYSTEM_TRANSFORM_TRANSLATION_MATRIX: Tag = Tag(0x0014, 0x222C);
+/// (0014,3011) Internal Detector Frame Time
+pub const INTERNAL_DETECTOR_FRAME_TIME: Tag = Tag(0x0014, 0x3011);
+/// (0014,3012) Number of Frames Integrated
+pub const NUMBER_OF_FRAMES_INTEGRATED: Tag = Tag(0x0014, 0x3012);
+/// (0014,3020) Detector Temperature Sequence
+pub const DETECTOR_TEMPERATURE_SEQUENCE: Tag = Tag(0x0014, 0x3020);
+/// (0014,3022) Sensor Name
+pub const SENSOR_NAME: Tag = Tag(0x0014, 0x3022);
+/// (0014,3024) Horizontal Offset of Sensor
+pub const HORIZONTAL_OFFSET_OF_SENSOR: Tag = Tag(0x0014, 0x3024);
+/// (0014,3026) Vertical Offset of Sensor
+pub const VERTICAL_OFFSET_OF_SENSOR: Tag = Tag(0x0014, 0x3026);
+/// (0014,3028) Sensor Temperature
+pub const SENSOR_TEMPERATURE: Tag = Tag(0x0014, 0x3028);
+/// (0014,3040) Dark Current Sequence
+pub const DARK_CURRENT_SEQUENCE: Tag = Tag(0x0014, 0x3040);
+/// (0014,3050) Dark Current Counts
+pub const DARK_CURRENT_COUNTS: Tag = Tag(0x0014, 0x3050);
+/// (0014,3060) Gain Correction Reference Sequence
+pub const GAIN_CORRECTION_REFERENCE_SEQUENCE: Tag = Tag(0x0014, 0x3060);
+/// (0014,3070) Air Counts
+pub const AIR_COUNTS: Tag = Tag(0x0014, 0x3070);
+/// (0014,3071) KV Used in Gain Calibration
+pub const KV_USED_IN_GAIN_CALIBRATION: Tag = Tag(0x0014, 0x3071);
+/// (0014,3072) MA Used in Gain Calibration
+pub const MA_USED_IN_GAIN_CALIBRATION: Tag = Tag(0x0014, 0x3072);
+/// (0014,3073) Number of Frames Used for Integration
+pub const NUMBER_OF_FRAMES_USED_FOR_INTEGRATION: Tag = Tag(0x0014, 0x3073);
+/// (0014,3074) Filter Material Used in Gain Calibration
+pub const FILTER_MATERIAL_USED_IN_GAIN_CALIBRATION: Tag = Tag(0x0014, 0x3074);
+/// (0014,3075) Filter Thickness Used in Gain Calibration
+pub const FILTER_THICKNESS_USED_IN_GAIN_CALIBRATION: Tag = Tag(0x0014, 0x3075);
+/// (0014,3076) Date of Gain Calibration
+pub const DATE_OF_GAIN_CALIBRATION: Tag = Tag(0x0014, 0x3076);
+/// (0014,3077) Time of Gain Calibration
+pub const TIME_OF_GAIN_CALIBRATION: Tag = Tag(0x0014, 0x3077);
+/// (0014,3080) Bad Pixel Image
+pub const BAD_PIXEL_IMAGE: Tag = Tag(0x0014, 0x3080);
+/// (0014,3099) Calibration Notes
+pub const CALIBRATION_NOTES: Tag = Tag(0x0014, 0x3099);
+/// (0014,4002) Pulser Equipment Sequence
+pub const PULSER_EQUIPMENT_SEQUENCE: Tag = Tag(0x0014, 0x4002);
+/// (0014,4004) Pulser Type
+pub const PULSER_TYPE: Tag = Tag(0x0014, 0x4004);
+/// (0014,4006) Pulser Notes
+pub const PULSER_NOTES: Tag = Tag(0x0014, 0x4006);
+/// (0014,4008) Receiver Equipment Sequence
+pub const RECEIVER_EQUIPMENT_SEQUENCE: Tag = Tag(0x0014, 0x4008);
+/// (0014,400A) Amplifier Type
+pub const AMPLIFIER_TYPE: Tag = Tag(0x0014, 0x400A);
+/// (0014,400C) Receiver Notes
+pub const RECEIVER_NOTES: Tag = Tag(0x0014, 0x400C);
+/// (0014,400E) Pre Amplifier Equipment Sequence
+pub const PRE_AMPLIFIER_EQUIPMENT_SEQUENCE: Tag = Tag(0x0014, 0x400E);
+/// (0014,400F) Pre Amplifier Notes
+pub const PRE_AMPLIFIER_NOTES: Tag = Tag(0x0014, 0x400F);
+/// (0014,4010) Transmit Transducer Sequence
+pub const TRANSMIT_TRANSDUCER_SEQUENCE: Tag = Tag(0x0014, 0x4010);
+/// (0014,4011) Receive Transducer Sequence
+pub const RECEIVE_TRANSDUCER_SEQUENCE: Tag = Tag(0x0014, 0x4011);
+/// (0014,4012) Number of Elements
+pub const NUMBER_OF_ELEMENTS: Tag = Tag(0x0014, 0x4012);
+/// (0014,4013) Element Shape
+pub const ELEMENT_SHAPE: Tag = Tag(0x0014, 0x4013);
+/// (0014,4014) Element Dimension A
+pub const ELEMENT_DIMENSION_A: Tag = Tag(0x0014, 0x4014);
+/// (0014,4015) Element Dimension B
+pub const ELEMENT_DIMENSION_B: Tag = Tag(0x0014, 0x4015);
+/// (0014,4016) Element Pitch A
+pub const ELEMENT_PITCH_A: Tag = Tag(0x0014, 0x4016);
+/// (0014,4017) Measured Beam Dimension A
+pub const MEASURED_BEAM_DIMENSION_A: Tag = Tag(0x0014, 0x4017);
+/// (0014,4018) Measured Beam Dimension B
+pub const MEASURED_BEAM_DIMENSION_B: Tag = Tag(0x0014, 0x4018);
+/// (0014,4019) Location of Measured Beam Diameter
+pub const LOCATION_OF_MEASURED_BEAM_DIAMETER: Tag = Tag(0x0014, 0x4019);
+/// (0014,401A) Nominal Frequency
+pub const NOMINAL_FREQUENCY: Tag = Tag(0x0014, 0x401A);
+/// (0014,401B) Measured Center Frequency
+pub const MEASURED_CENTER_FREQUENCY: Tag = Tag(0x0014, 0x401B);
+/// (0014,401C) Measured Bandwidth
+pub const MEASURED_BANDWIDTH: Tag = Tag(0x0014, 0x401C);
+/// (0014,401D) Element Pitch B
+pub const ELEMENT_PITCH_B: Tag = Tag(0x0014, 0x401D);
+/// (0014,4020) Pulser Settings Sequence
+pub const PULSER_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4020);
+/// (0014,4022) Pulse Width
+pub const PULSE_WIDTH: Tag = Tag(0x0014, 0x4022);
+/// (0014,4024) Excitation Frequency
+pub const EXCITATION_FREQUENCY: Tag = Tag(0x0014, 0x4024);
+/// (0014,4026) Modulation Type
+pub const MODULATION_TYPE: Tag = Tag(0x0014, 0x4026);
+/// (0014,4028) Damping
+pub const DAMPING: Tag = Tag(0x0014, 0x4028);
+/// (0014,4030) Receiver Settings Sequence
+pub const RECEIVER_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4030);
+/// (0014,4031) Acquired Soundpath Length
+pub const ACQUIRED_SOUNDPATH_LENGTH: Tag = Tag(0x0014, 0x4031);
+/// (0014,4032) Acquisition Compression Type
+pub const ACQUISITION_COMPRESSION_TYPE: Tag = Tag(0x0014, 0x4032);
+/// (0014,4033) Acquisition Sample Size
+pub const ACQUISITION_SAMPLE_SIZE: Tag = Tag(0x0014, 0x4033);
+/// (0014,4034) Rectifier Smoothing
+pub const RECTIFIER_SMOOTHING: Tag = Tag(0x0014, 0x4034);
+/// (0014,4035) DAC Sequence
+pub const DAC_SEQUENCE: Tag = Tag(0x0014, 0x4035);
+/// (0014,4036) DAC Type
+pub const DAC_TYPE: Tag = Tag(0x0014, 0x4036);
+/// (0014,4038) DAC Gain Points
+pub const DAC_GAIN_POINTS: Tag = Tag(0x0014, 0x4038);
+/// (0014,403A) DAC Time Points
+pub const DAC_TIME_POINTS: Tag = Tag(0x0014, 0x403A);
+/// (0014,403C) DAC Amplitude
+pub const DAC_AMPLITUDE: Tag = Tag(0x0014, 0x403C);
+/// (0014,4040) Pre Amplifier Settings Sequence
+pub const PRE_AMPLIFIER_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4040);
+/// (0014,4050) Transmit Transducer Settings Sequence
+pub const TRANSMIT_TRANSDUCER_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4050);
+/// (0014,4051) Receive Transducer Settings Sequence
+pub const RECEIVE_TRANSDUCER_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4051);
+/// (0014,4052) Incident Angle
+pub const INCIDENT_ANGLE: Tag = Tag(0x0014, 0x4052);
+/// (0014,4054) Coupling Technique
+pub const COUPLING_TECHNIQUE: Tag = Tag(0x0014, 0x4054);
+/// (0014,4056) Coupling Medium
+pub const COUPLING_MEDIUM: Tag = Tag(0x0014, 0x4056);
+/// (0014,4057) Coupling Velocity
+pub const COUPLING_VELOCITY: Tag = Tag(0x0014, 0x4057);
+/// (0014,4058) Probe Center Location X
+pub const PROBE_CENTER_LOCATION_X: Tag = Tag(0x0014, 0x4058);
+/// (0014,4059) Probe Center Location Z
+pub const PROBE_CENTER_LOCATION_Z: Tag = Tag(0x0014, 0x4059);
+/// (0014,405A) Sound Path Length
+pub const SOUND_PATH_LENGTH: Tag = Tag(0x0014, 0x405A);
+/// (0014,405C) Delay Law Identifier
+pub const DELAY_LAW_IDENTIFIER: Tag = Tag(0x0014, 0x405C);
+/// (0014,4060) Gate Settings Sequence
+pub const GATE_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4060);
+/// (0014,4062) Gate Threshold
+pub const GATE_THRESHOLD: Tag = Tag(0x0014, 0x4062);
+/// (0014,4064) Velocity of Sound
+pub const VELOCITY_OF_SOUND: Tag = Tag(0x0014, 0x4064);
+/// (0014,4070) Calibration Settings Sequence
+pub const CALIBRATION_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4070);
+/// (0014,4072) Calibration Procedure
+pub const CALIBRATION_PROCEDURE: Tag = Tag(0x0014, 0x4072);
+/// (0014,4074) Procedure Version
+pub const PROCEDURE_VERSION: Tag = Tag(0x0014, 0x4074);
+/// (0014,4076) Procedure Creation Date
+pub const PROCEDURE_CREATION_DATE: Tag = Tag(0x0014, 0x4076);
+/// (0014,4078) Procedure Expiration Date
+pub const PROCEDURE_EXPIRATION_DATE: Tag = Tag(0x0014, 0x4078);
+/// (0014,407A) Procedure Last Modified Date
+pub const PROCEDURE_LAST_MODIFIED_DATE: Tag = Tag(0x0014, 0x407A);
+/// (0014,407C) Calibration Time
+pub const CALIBRATION_TIME: Tag = Tag(0x0014, 0x407C);
+/// (0014,407E) Calibration Date
+pub const CALIBRATION_DATE: Tag = Tag(0x0014, 0x407E);
+/// (0014,4080) Probe Drive Equipment Sequence
+pub const PROBE_DRIVE_EQUIPMENT_SEQUENCE: Tag = Tag(0x0014, 0x4080);
+/// (0014,4081) Drive Type
+pub const DRIVE_TYPE: Tag = Tag(0x0014, 0x4081);
+/// (0014,4082) Probe Drive Notes
+pub const PROBE_DRIVE_NOTES: Tag = Tag(0x0014, 0x4082);
+/// (0014,4083) Drive Probe Sequence
+pub const DRIVE_PROBE_SEQUENCE: Tag = Tag(0x0014, 0x4083);
+/// (0014,4084) Probe Inductance
+pub const PROBE_INDUCTANCE: Tag = Tag(0x0014, 0x4084);
+/// (0014,4085) Probe Resistance
+pub const PROBE_RESISTANCE: Tag = Tag(0x0014, 0x4085);
+/// (0014,4086) Receive Probe Sequence
+pub const RECEIVE_PROBE_SEQUENCE: Tag = Tag(0x0014, 0x4086);
+/// (0014,4087) Probe Drive Settings Sequence
+pub const PROBE_DRIVE_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4087);
+/// (0014,4088) Bridge Resistors
+pub const BRIDGE_RESISTORS: Tag = Tag(0x0014, 0x4088);
+/// (0014,4089) Probe Orientation Angle
+pub const PROBE_ORIENTATION_ANGLE: Tag = Tag(0x0014, 0x4089);
+/// (0014,408B) User Selected Gain Y
+pub const USER_SELECTED_GAIN_Y: Tag = Tag(0x0014, 0x408B);
+/// (0014,408C) User Selected Phase
+pub const USER_SELECTED_PHASE: Tag = Tag(0x0014, 0x408C);
+/// (0014,408D) User Selected Offset X
+pub const USER_SELECTED_OFFSET_X: Tag = Tag(0x0014, 0x408D);
+/// (0014,408E) User Selected Offset Y
+pub const USER_SELECTED_OFFSET_Y: Tag = Tag(0x0014, 0x408E);
+/// (0014,4091) Channel Settings Sequence
+pub const CHANNEL_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x4091);
+/// (0014,4092) Channel Threshold
+pub const CHANNEL_THRESHOLD: Tag = Tag(0x0014, 0x4092);
+/// (0014,409A) Scanner Settings Sequence
+pub const SCANNER_SETTINGS_SEQUENCE: Tag = Tag(0x0014, 0x409A);
+/// (0014,409B) Scan Procedure
+pub const SCAN_PROCEDURE: Tag = Tag(0x0014, 0x409B);
+/// (0014,409C) Translation Rate X
+pub const TRANSLATION_RATE_X: Tag = Tag(0x0014, 0x409C);
+/// (0014,409D) Translation Rate Y
+pub const TRANSLATION_RATE_Y: Tag = Tag(0x0014, 0x409D);
+/// (0014,409F) Channel Overlap
+pub const CHANNEL_OVERLAP: Tag = Tag(0x0014, 0x409F);
+/// (0014,40A0) Image Quality Indicator Type
+pub const IMAGE_QUALITY_INDICATOR_TYPE: Tag = Tag(0x0014, 0x40A0);
+/// (0014,40A1) Image Quality Indicator Material
+pub const IMAGE_QUALITY_INDICATOR_MATERIAL: Tag = Tag(0x0014, 0x40A1);
+/// (0014,40A2) Image Quality Indicator Size
+pub const IMAGE_QUALITY_INDICATOR_SIZE: Tag = Tag(0x0014, 0x40A2);
+/// (0014,5002) LINAC Energy
+pub const LINAC_ENERGY: Tag = Tag(0x0014, 0x5002);
+/// (0014,5004) LINAC Output
+pub const LINAC_OUTPUT: Tag = Tag(0x0014, 0x5004);
+/// (0014,5100) Active Aperture
+pub const ACTIVE_APERTURE: Tag = Tag(0x0014, 0x5100);
+/// (0014,5101) Total Aperture
+pub const TOTAL_APERTURE: Tag = Tag(0x0014, 0x5101);
+/// (0014,5102) Aperture Elevation
+pub const APERTURE_ELEVATION: Tag = Tag(0x0014, 0x5102);
+/// (0014,5103) Main Lobe Angle
+pub const MAIN_LOBE_ANGLE: Tag = Tag(0x0014, 0x5103);
+/// (0014,5104) Main Roof Angle
+pub const MAIN_ROOF_ANGLE: Tag = Tag(0x0014, 0x5104);
+/// (0014,5105) Connector Type
+pub const CONNECTOR_TYPE: Tag = Tag(0x0014, 0x5105);
+/// (0014,5106) Wedge Model Number
+pub const WEDGE_MODEL_NUMBER: Tag = Tag(0x0014, 0x5106);
+/// (0014,5107) Wedge Angle Float
+pub const WEDGE_ANGLE_FLOAT: Tag = Tag(0x0014, 0x5107);
+/// (0014,5108) Wedge Roof Angle
+pub const WEDGE_ROOF_ANGLE: Tag = Tag(0x0014, 0x5108);
+/// (0014,5109) Wedge Element 1 Position
+pub const WEDGE_ELEMENT1POSITION: Tag = Tag(0x0014, 0x5109);
+/// (0014,510A) Wedge Material Velocity
+pub const WEDGE_MATERIAL_VELOCITY: Tag = Tag(0x0014, 0x510A);
+/// (0014,510B) Wedge Material
+pub const WEDGE_MATERIAL: Tag = Tag(0x0014, 0x510B);
+/// (0014,510C) Wedge Offset Z
+pub const WEDGE_OFFSET_Z: Tag = Tag(0x0014, 0x510C);
+/// (0014,510D) Wedge Origin Offset X
+pub const WEDGE_ORIGIN_OFFSET_X: Tag = Tag(0x0014, 0x510D);
+/// (0014,510E) Wedge Time Delay
+pub const WEDGE_TIME_DELAY: Tag = Tag(0x0014, 0x510E);
+/// (0014,510F) Wedge Name
+pub const WEDGE_NAME: Tag = Tag(0x0014, 0x510F);
+/// (0014,5110) Wedge Manufacturer Name
+pub const WEDGE_MANUFACTURER_NAME: Tag = Tag(0x0014, 0x5110);
+/// (0014,5111) Wedge Description
+pub const WEDGE_DESCRIPTION: Tag = Tag(0x0014, 0x5111);
+/// (0014,5112) Nominal Beam Angle
+pub const NOMINAL_BEAM_ANGLE: Tag = Tag(0x0014, 0x5112);
+/// (0014,5113) Wedge Offset X
+pub const WEDGE_OFFSET_X: Tag = Tag(0x0014, 0x5113);
+/// (0014,5114) Wedge Offset Y
+pub const WEDGE_OFFSET_Y: Tag = Tag(0x0014, 0x5114);
+/// (0014,5115) Wedge Total Length
+pub const WEDGE_TOTAL_LENGTH: Tag = Tag(0x0014, 0x5115);
+/// (0014,5116) Wedge in Contact Length
+pub const WEDGE_IN_CONTACT_LENGTH: Tag = Tag(0x0014, 0x5116);
+/// (0014,5117) Wedge Front Gap
+pub const WEDGE_FRONT_GAP: Tag = Tag(0x0014, 0x5117);
+/// (0014,5118) Wedge Total Height
+pub const WEDGE_TOTAL_HEIGHT: Tag = Tag(0x0014, 0x5118);
+/// (0014,5119) Wedge Front Height
+pub const WEDGE_FRONT_HEIGHT: Tag = Tag(0x0014, 0x5119);
+/// (0014,511A) Wedge Rear Height
+pub const WEDGE_REAR_HEIGHT: Tag = Tag(0x0014, 0x511A);
+/// (0014,511B) Wedge Total Width
+pub const WEDGE_TOTAL_WIDTH: Tag = Tag(0x0014, 0x511B);
+/// (0014,511C) Wedge in Contact Width
+pub const WEDGE_IN_CONTACT_WIDTH: Tag = Tag(0x0014, 0x511C);
+/// (0014,511D) Wedge Chamfer Height
+pub const WEDGE_CHAMFER_HEIGHT: Tag = Tag(0x0014, 0x511D);
+/// (0014,511E) Wedge Curve
+pub const WEDGE_CURVE: Tag = Tag(0x0014, 0x511E);
+/// (0014,511F) Radius Along Wedge
+pub const RADIUS_ALONG_WEDGE: Tag = Tag(0x0014, 0x511F);
+/// (0016,0001) White Point
+pub const WHITE_POINT: Tag = Tag(0x0016, 0x0001);
+/// (0016,0002) Primary Chromaticities
+pub const PRIMARY_CHROMATICITIES: Tag = Tag(0x0016, 0x0002);
+/// (0016,0003) Battery Level
+pub const BATTERY_LEVEL: Tag = Tag(0x0016, 0x0003);
+/// (0016,0004) Exposure Time in Seconds
+pub const EXPOSURE_TIME_IN_SECONDS: Tag = Tag(0x0016, 0x0004);
+/// (0016,0005) F Number
+pub const F_NUMBER: Tag = Tag(0x0016, 0x0005);
+/// (0016,0006) OECF Rows
+pub const OECF_ROWS: Tag = Tag(0x0016, 0x0006);
+/// (0016,0007) OECF Columns
+pub const OECF_COLUMNS: Tag = Tag(0x0016, 0x0007);
+/// (0016,0008) OECF Column Names
+pub const OECF_COLUMN_NAMES: Tag = Tag(0x0016, 0x0008);
+/// (0016,0009) OECF Values
+pub const OECF_VALUES: Tag = Tag(0x0016, 0x0009);
+/// (0016,000A) Spatial Frequency Response Rows
+pub const SPATIAL_FREQUENCY_RESPONSE_ROWS: Tag = Tag(0x0016, 0x000A);
+/// (0016,000B) Spatial Frequency Response Columns
+pub const SPATIAL_FREQUENCY_RESPONSE_COLUMNS: Tag = Tag(0x0016, 0x000B);
+/// (0016,000C) Spatial Frequency Response Column Names
+pub const SPATIAL_FREQUENCY_RESPONSE_COLUMN_NAMES: Tag = Tag(0x0016, 0x000C);
+/// (0016,000D) Spatial Frequency Response Values
+pub const SPATIAL_FREQUENCY_RESPONSE_VALUES: Tag = Tag(0x0016, 0x000D);
+/// (0016,000E) Color Filter Array Pattern Rows
+pub const COLOR_FILTER_ARRAY_PATTERN_ROWS: Tag = Tag(0x0016, 0x000E);
+/// (0016,000F) Color Filter Array Pattern Columns
+pub const COLOR_FILTER_ARRAY_PATTERN_COLUMNS: Tag = Tag(0x0016, 0x000F);
+/// (0016,0010) Color Filter Array Pattern Values
+pub const COLOR_FILTER_ARRAY_PATTERN_VALUES: Tag = Tag(0x0016, 0x0010);
+/// (0016,0011) Flash Firing Status
+pub const FLASH_FIRING_STATUS: Tag = Tag(0x0016, 0x0011);
+/// (0016,0012) Flash Return Status
+pub const FLASH_RETURN_STATUS: Tag = Tag(0x0016, 0x0012);
+/// (0016,0013) Flash Mode
+pub const FLASH_MODE: Tag = Tag(0x0016, 0x0013);
+/// (0016,0014) Flash Function Present
+pub const FLASH_FUNCTION_PRESENT: Tag = Tag(0x0016, 0x0014);
+/// (0016,0015) Flash Red Eye Mode
+pub const FLASH_RED_EYE_MODE: Tag = Tag(0x0016, 0x0015);
+/// (0016,0016) Exposure Program
+pub const EXPOSURE_PROGRAM: Tag = Tag(0x0016, 0x0016);
+/// (0016,0017) Spectral Sensitivity
+pub const SPECTRAL_SENSITIVITY: Tag = Tag(0x0016, 0x0017);
+/// (0016,0018) Photographic Sensitivity
+pub const PHOTOGRAPHIC_SENSITIVITY: Tag = Tag(0x0016, 0x0018);
+/// (0016,0019) Self Timer Mode
+pub const SELF_TIMER_MODE: Tag = Tag(0x0016, 0x0019);
+/// (0016,001A) Sensitivity Type
+pub const SENSITIVITY_TYPE: Tag = Tag(0x0016, 0x001A);
+/// (0016,001B) Standard Output Sensitivity
+pub const STANDARD_OUTPUT_SENSITIVITY: Tag = Tag(0x0016, 0x001B);
+/// (0016,001C) Recommended Exposure Index
+pub const RECOMMENDED_EXPOSURE_INDEX: Tag = Tag(0x0016, 0x001C);
+/// (0016,001D) ISO Speed
+pub const ISO_SPEED: Tag = Tag(0x0016, 0x001D);
+/// (0016,001E) ISO Speed Latitudeyyy
+pub const ISO_SPEED_LATITUDEYYY: Tag = Tag(0x0016, 0x001E);
+/// (0016,001F) ISO Speed Latitudezzz
+pub const ISO_SPEED_LATITUDEZZZ: Tag = Tag(0x0016, 0x001F);
+/// (0016,0020) EXIF Version
+pub const EXIF_VERSION: Tag = Tag(0x0016, 0x0020);
+/// (0016,0021) Shutter Speed Value
+pub const SHUTTER_SPEED_VALUE: Tag = Tag(0x0016, 0x0021);
+/// (0016,0022) Aperture Value
+pub const APERTURE_VALUE: Tag = Tag(0x0016, 0x0022);
+/// (0016,0023) Brightness Value
+pub const BRIGHTNESS_VALUE: Tag = Tag(0x0016, 0x0023);
+/// (0016,0024) Exposure Bias Value
+pub const EXPOSURE_BIAS_VALUE: Tag = Tag(0x0016, 0x0024);
+/// (0016,0025) Max Aperture Value
+pub const MAX_APERTURE_VALUE: Tag = Tag(0x0016, 0x0025);
+/// (0016,0026) Subject Distance
+pub const SUBJECT_DISTANCE: Tag = Tag(0x0016, 0x0026);
+/// (0016,0027) Metering Mode
+pub const METERING_MODE: Tag = Tag(0x0016, 0x0027);
+/// (0016,0028) Light Source
+pub const LIGHT_SOURCE: Tag = Tag(0x0016, 0x0028);
+/// (0016,0029) Focal Length
+pub const FOCAL_LENGTH: Tag = Tag(0x0016, 0x0029);
+/// (0016,002A) Subject Area
+pub const SUBJECT_AREA: Tag = Tag(0x0016, 0x002A);
+/// (0016,002B) Maker Note
+pub const MAKER_NOTE: Tag = Tag(0x0016, 0x002B);
+/// (0016,0030) Temperature
+pub const TEMPERATURE: Tag = Tag(0x0016, 0x0030);
+/// (0016,0031) Humidity
+pub const HUMIDITY: Tag = Tag(0x0016, 0x0031);
+/// (0016,0032) Pressure
+pub const PRESSURE: Tag = Tag(0x0016, 0x0032);
+/// (0016,0033) Water Depth
+pub const WATER_DEPTH: Tag = Tag(0x0016, 0x0033);
+/// (0016,0034) Acceleration
+pub const ACCELERATION: Tag = Tag(0x0016, 0x0034);
+/// (0016,0035) Camera Elevation Angle
+pub const CAMERA_ELEVATION_ANGLE: Tag = Tag(0x0016, 0x0035);
+/// (0016,0036) Flash Energy
+pub const FLASH_ENERGY: Tag = Tag(0x0016, 0x0036);
+/// (0016,0037) Subject Location
+pub const SUBJECT_LOCATION: Tag = Tag(0x0016, 0x0037);
+/// (0016,0038) Photographic Exposure Index
+pub const PHOTOGRAPHIC_EXPOSURE_INDEX: Tag = Tag(0x0016, 0x0038);
+/// (0016,0039) Sensing Method
+pub const SENSING_METHOD: Tag = Tag(0x0016, 0x0039);
+/// (0016,003A) File Source
+pub const FILE_SOURCE: Tag = Tag(0x0016, 0x003A);
+/// (0016,003B) Scene Type
+pub const SCENE_TYPE: Tag = Tag(0x0016, 0x003B);
+/// (0016,0041) Custom Rendered
+pub const CUSTOM_RENDERED: Tag = Tag(0x0016, 0x0041);
+/// (0016,0042) Exposure Mode
+pub const EXPOSURE_MODE: Tag = Tag(0x0016, 0x0042);
+/// (0016,0043) White Balance
+pub const WHITE_BALANCE: Tag = Tag(0x0016, 0x0043);
+/// (0016,0044) Digital Zoom Ratio
+pub const DIGITAL_ZOOM_RATIO: Tag = Tag(0x0016, 0x0044);
+/// (0016,0045) Focal Length in 35 mm Film
+pub const FOCAL_LENGTH_IN35MM_FILM: Tag = Tag(0x0016, 0x0045);
+/// (0016,0046) Scene Capture Type
+pub const SCENE_CAPTURE_TYPE: Tag = Tag(0x0016, 0x0046);
+/// (0016,0047) Gain Control
+pub const GAIN_CONTROL: Tag = Tag(0x0016, 0x0047);
+/// (0016,0048) Contrast
+pub const CONTRAST: Tag = Tag(0x0016, 0x0048);
+/// (0016,0049) Saturation
+pub const SATURATION: Tag = Tag(0x0016, 0x0049);
+/// (0016,004A) Sharpness
+pub const SHARPNESS: Tag = Tag(0x0016, 0x004A);
+/// (0016,004B) Device Setting Description
+pub const DEVICE_SETTING_DESCRIPTION: Tag = Tag(0x0016, 0x004B);
+/// (0016,004C) Subject Distance Range
+pub const SUBJECT_DISTANCE_RANGE: Tag = Tag(0x0016, 0x004C);
+/// (0016,004D) Camera Owner Name
+pub const CAMERA_OWNER_NAME: Tag = Tag(0x0016, 0x004D);
+/// (0016,004E) Lens Specification
+pub const LENS_SPECIFICATION: Tag = Tag(0x0016, 0x004E);
+/// (0016,004F) Lens Make
+pub const LENS_MAKE: Tag = Tag(0x0016, 0x004F);
+/// (0016,0050) Lens Model
+pub const LENS_MODEL: Tag = Tag(0x0016, 0x0050);
+/// (0016,0051) Lens Serial Number
+pub const LENS_SERIAL_NUMBER: Tag = Tag(0x0016, 0x0051);
+/// (0016,0061) Interoperability Index
+pub const INTEROPERABILITY_INDEX: Tag = Tag(0x0016, 0x0061);
+/// (0016,0062) Interoperability Version
+pub const INTEROPERABILITY_VERSION: Tag = Tag(0x0016, 0x0062);
+/// (0016,0070) GPS Version ID
+pub const GPS_VERSION_ID: Tag = Tag(0x0016, 0x0070);
+/// (0016,0071) GPS Latitude Ref
+pub const GPS_LATITUDE_REF: Tag = Tag(0x0016, 0x0071);
+/// (0016,0072) GPS Latitude
+pub const GPS_LATITUDE: Tag = Tag(0x0016, 0x0072);
+/// (0016,0073) GPS Longitude Ref
+pub const GPS_LONGITUDE_REF: Tag = Tag(0x0016, 0x0073);
+/// (0016,0074) GPS Longitude
+pub const GPS_LONGITUDE: Tag = Tag(0x0016, 0x0074);
+/// (0016,0075) GPS Altitude Ref
+pub const GPS_ALTITUDE_REF: Tag = Tag(0x0016, 0x0075);
+/// (0016,0076) GPS Altitude
+pub const GPS_ALTITUDE: Tag = Tag(0x0016, 0x0076);
+/// (0016,0077) GPS Time Stamp
+pub const GPS_TIME_STAMP: Tag = Tag(0x0016, 0x0077);
+/// (0016,0078) GPS Satellites
+pub const GPS_SATELLITES: Tag = Tag(0x0016, 0x0078);
+/// (0016,0079) GPS Status
+pub const GPS_STATUS: Tag = Tag(0x0016, 0x0079);
+/// (0016,007A) GPS Measure Mode
+pub const GPS_MEASURE_MODE: Tag = Tag(0x0016, 0x007A);
+/// (0016,007B) GPSDOP
+pub const GPSDOP: Tag = Tag(0x0016, 0x007B);
+/// (0016,007C) GPS Speed Ref
+pub const GPS_SPEED_REF: Tag = Tag(0x0016, 0x007C);
+/// (0016,007D) GPS Speed
+pub const GPS_SPEED: Tag = Tag(0x0016, 0x007D);
+/// (0016,007E) GPS Track Ref
+pub const GPS_TRACK_REF: Tag = Tag(0x0016, 0x007E);
+/// (0016,007F) GPS Track
+pub const GPS_TRACK: Tag = Tag(0x0016, 0x007F);
+/// (0016,0080) GPS Img Direction Ref
+pub const GPS_IMG_DIRECTION_REF: Tag = Tag(0x0016, 0x0080);
+/// (0016,0081) GPS Img Direction
+pub const GPS_IMG_DIRECTION: Tag = Tag(0x0016, 0x0081);
+/// (0016,0082) GPS Map Datum
+pub const GPS_MAP_DATUM: Tag = Tag(0x0016, 0x0082);
+/// (0016,0083) GPS Dest Latitude Ref
+pub const GPS_DEST_LATITUDE_REF: Tag = Tag(0x0016, 0x0083);
+/// (0016,0084) GPS Dest Latitude
+pub const GPS_DEST_LATITUDE: Tag = Tag(0x0016, 0x0084);
+/// (0016,0085) GPS Dest Longitude Ref
+pub const GPS_DEST_LONGITUDE_REF: Tag = Tag(0x0016, 0x0085);
+/// (0016,0086) GPS Dest Longitude
+pub const GPS_DEST_LONGITUDE: Tag = Tag(0x0016, 0x0086);
+/// (0016,0087) GPS Dest Bearing Ref
+pub const GPS_DEST_BEARING_REF: Tag = Tag(0x0016, 0x0087);
+/// (0016,0088) GPS Dest Bearing
+pub const GPS_DEST_BEARING: Tag = Tag(0x0016, 0x0088);
+/// (0016,0089) GPS Dest Distance Ref
+pub const GPS_DEST_DISTANCE_REF: Tag = Tag(0x0016, 0x0089);
+/// (0016,008A) GPS Dest Distance
+pub const GPS_DEST_DISTANCE: Tag = Tag(0x0016, 0x008A);
+/// (0016,008B) GPS Processing Method
+pub const GPS_PROCESSING_METHOD: Tag = Tag(0x0016, 0x008B);
+/// (0016,008C) GPS Area Information
+pub const GPS_AREA_INFORMATION: Tag = Tag(0x0016, 0x008C);
+/// (0016,008D) GPS Date Stamp
+pub const GPS_DATE_STAMP: Tag = Tag(0x0016, 0x008D);
+/// (0016,008E) GPS Differential
+pub const GPS_DIFFERENTIAL: Tag = Tag(0x0016, 0x008E);
+/// (0018,0010) Contrast Bolus Agent
+pub const CONTRAST_BOLUS_AGENT: Tag = Tag(0x0018, 0x0010);
+/// (0018,0012) Contrast Bolus Agent Sequence
+pub const CONTRAST_BOLUS_AGENT_SEQUENCE: Tag = Tag(0x0018, 0x0012);
+/// (0018,0013) Contrast Bolus T 1 Relaxivity
+pub const CONTRAST_BOLUS_T1RELAXIVITY: Tag = Tag(0x0018, 0x0013);
+/// (0018,0014) Contrast Bolus Administration Route Sequence
+pub const CONTRAST_BOLUS_ADMINISTRATION_ROUTE_SEQUENCE: Tag = Tag(0x0018, 0x0014);
+/// (0018,0015) Body Part Examined
+pub const BODY_PART_EXAMINED: Tag = Tag(0x0018, 0x0015);
+/// (0018,0020) Scanning Sequence
+pub const SCANNING_SEQUENCE: Tag = Tag(0x0018, 0x0020);
+/// (0018,0021) Sequence Variant
+pub const SEQUENCE_VARIANT: Tag = Tag(0x0018, 0x0021);
+/// (0018,0022) Scan Options
+pub const SCAN_OPTIONS: Tag = Tag(0x0018, 0x0022);
+/// (0018,0023) MR Acquisition Type
+pub const MR_ACQUISITION_TYPE: Tag = Tag(0x0018, 0x0023);
+/// (0018,0024) Sequence Name
+pub const SEQUENCE_NAME: Tag = Tag(0x0018, 0x0024);
+/// (0018,0025) Angio Flag
+pub const ANGIO_FLAG: Tag = Tag(0x0018, 0x0025);
+/// (0018,0026) Intervention Drug Information Sequence
+pub const INTERVENTION_DRUG_INFORMATION_SEQUENCE: Tag = Tag(0x0018, 0x0026);
+/// (0018,0027) Intervention Drug Stop Time
+pub const INTERVENTION_DRUG_STOP_TIME: Tag = Tag(0x0018, 0x0027);
+/// (0018,0028) Intervention Drug Dose
+pub const INTERVENTION_DRUG_DOSE: Tag = Tag(0x0018, 0x0028);
+/// (0018,0029) Intervention Drug Code Sequence
+pub const INTERVENTION_DRUG_CODE_SEQUENCE: Tag = Tag(0x0018, 0x0029);
+/// (0018,002A) Additional Drug Sequence
+pub const ADDITIONAL_DRUG_SEQUENCE: Tag = Tag(0x0018, 0x002A);
+/// (0018,0030) Radionuclide (retired)
+pub const RADIONUCLIDE: Tag = Tag(0x0018, 0x0030);
+/// (0018,0031) Radiopharmaceutical
+pub const RADIOPHARMACEUTICAL: Tag = Tag(0x0018, 0x0031);
+/// (0018,0032) Energy Window Centerline (retired)
+pub const ENERGY_WINDOW_CENTERLINE: Tag = Tag(0x0018, 0x0032);
+/// (0018,0033) Energy Window Total Width (retired)
+pub const ENERGY_WINDOW_TOTAL_WIDTH: Tag = Tag(0x0018, 0x0033);
+/// (0018,0034) Intervention Drug Name
+pub const INTERVENTION_DRUG_NAME: Tag = Tag(0x0018, 0x0034);
+/// (0018,0035) Intervention Drug Start Time
+pub const INTERVENTION_DRUG_START_TIME: Tag = Tag(0x0018, 0x0035);
+/// (0018,0036) Intervention Sequence
+pub const INTERVENTION_SEQUENCE: Tag = Tag(0x0018, 0x0036);
+/// (0018,0037) Therapy Type (retired)
+pub const THERAPY_TYPE: Tag = Tag(0x0018, 0x0037);
+/// (0018,0038) Intervention Status
+pub const INTERVENTION_STATUS: Tag = Tag(0x0018, 0x0038);
+/// (0018,0039) Therapy Description (retired)
+pub const THERAPY_DESCRIPTION: Tag = Tag(0x0018, 0x0039);
+/// (0018,003A) Intervention Description
+pub const INTERVENTION_DESCRIPTION: Tag = Tag(0x0018, 0x003A);
+/// (0018,0040) Cine Rate
+pub const CINE_RATE: Tag = Tag(0x0018, 0x0040);
+/// (0018,0042) Initial Cine Run State
+pub const INITIAL_CINE_RUN_STATE: Tag = Tag(0x0018, 0x0042);
+/// (0018,0050) Slice Thickness
+pub const SLICE_THICKNESS: Tag = Tag(0x0018, 0x0050);
+/// (0018,0060) KVP
+pub const KVP: Tag = Tag(0x0018, 0x0060);
+/// (0018,0070) Counts Accumulated
+pub const COUNTS_ACCUMULATED: Tag = Tag(0x0018, 0x0070);
+/// (0018,0071) Acquisition Termination Condition
+pub const ACQUISITION_TERMINATION_CONDITION: Tag = Tag(0x0018, 0x0071);
+/// (0018,0072) Effective Duration
+pub const EFFECTIVE_DURATION: Tag = Tag(0x0018, 0x0072);
+/// (0018,0073) Acquisition Start Condition
+pub const ACQUISITION_START_CONDITION: Tag = Tag(0x0018, 0x0073);
+/// (0018,0074) Acquisition Start Condition Data
+pub const ACQUISITION_START_CONDITION_DATA: Tag = Tag(0x0018, 0x0074);
+/// (0018,0075) Acquisition Termination Condition Data
+pub const ACQUISITION_TERMINATION_CONDITION_DATA: Tag = Tag(0x0018, 0x0075);
+/// (0018,0080) Repetition Time
+pub const REPETITION_TIME: Tag = Tag(0x0018, 0x0080);
+/// (0018,0081) Echo Time
+pub const ECHO_TIME: Tag = Tag(0x0018, 0x0081);
+/// (0018,0082) Inversion Time
+pub const INVERSION_TIME: Tag = Tag(0x0018, 0x0082);
+/// (0018,0083) Number of Averages
+pub const NUMBER_OF_AVERAGES: Tag = Tag(0x0018, 0x0083);
+/// (0018,0084) Imaging Frequency
+pub const IMAGING_FREQUENCY: Tag = Tag(0x0018, 0x0084);
+/// (0018,0085) Imaged Nucleus
+pub const IMAGED_NUCLEUS: Tag = Tag(0x0018, 0x0085);
+/// (0018,0086) Echo Numbers
+pub const ECHO_NUMBERS: Tag = Tag(0x0018, 0x0086);
+/// (0018,0087) Magnetic Field Strength
+pub const MAGNETIC_FIELD_STRENGTH: Tag = Tag(0x0018, 0x0087);
+/// (0018,0088) Spacing Between Slices
+pub const SPACING_BETWEEN_SLICES: Tag = Tag(0x0018, 0x0088);
+/// (0018,0089) Number of Phase Encoding Steps
+pub const NUMBER_OF_PHASE_ENCODING_STEPS: Tag = Tag(0x0018, 0x0089);
+/// (0018,0090) Data Collection Diameter
+pub const DATA_COLLECTION_DIAMETER: Tag = Tag(0x0018, 0x0090);
+/// (0018,0091) Echo Train Length
+pub const ECHO_TRAIN_LENGTH: Tag = Tag(0x0018, 0x0091);
+/// (0018,0093) Percent Sampling
+pub const PERCENT_SAMPLING: Tag = Tag(0x0018, 0x0093);
+/// (0018,0094) Percent Phase Field of View
+pub const PERCENT_PHASE_FIELD_OF_VIEW: Tag = Tag(0x0018, 0x0094);
+/// (0018,0095) Pixel Bandwidth
+pub const PIXEL_BANDWIDTH: Tag = Tag(0x0018, 0x0095);
+/// (0018,1000) Device Serial Number
+pub const DEVICE_SERIAL_NUMBER: Tag = Tag(0x0018, 0x1000);
+/// (0018,1002) Device UID
+pub const DEVICE_UID: Tag = Tag(0x0018, 0x1002);
+/// (0018,1003) Device ID
+pub const DEVICE_ID: Tag = Tag(0x0018, 0x1003);
+/// (0018,1004) Plate ID
+pub const PLATE_ID: Tag = Tag(0x0018, 0x1004);
+/// (0018,1005) Generator ID
+pub const GENERATOR_ID: Tag = Tag(0x0018, 0x1005);
+/// (0018,1006) Grid ID
+pub const GRID_ID: Tag = Tag(0x0018, 0x1006);
+/// (0018,1007) Cassette ID
+pub const CASSETTE_ID: Tag = Tag(0x0018, 0x1007);
+/// (0018,1008) Gantry ID
+pub const GANTRY_ID: Tag = Tag(0x0018, 0x1008);
+/// (0018,1009) Unique Device Identifier
+pub const UNIQUE_DEVICE_IDENTIFIER: Tag = Tag(0x0018, 0x1009);
+/// (0018,100A) UDI Sequence
+pub const UDI_SEQUENCE: Tag = Tag(0x0018, 0x100A);
+/// (0018,1010) Secondary Capture Device ID
+pub const SECONDARY_CAPTURE_DEVICE_ID: Tag = Tag(0x0018, 0x1010);
+/// (0018,1011) Hardcopy Creation Device ID (retired)
+pub const HARDCOPY_CREATION_DEVICE_ID: Tag = Tag(0x0018, 0x1011);
+/// (0018,1012) Date of Secondary Capture
+pub const DATE_OF_SECONDARY_CAPTURE: Tag = Tag(0x0018, 0x1012);
+/// (0018,1014) Time of Secondary Capture
+pub const TIME_OF_SECONDARY_CAPTURE: Tag = Tag(0x0018, 0x1014);
+/// (0018,1016) Secondary Capture Device Manufacturer
+pub const SECONDARY_CAPTURE_DEVICE_MANUFACTURER: Tag = Tag(0x0018, 0x1016);
+/// (0018,1017) Hardcopy Device Manufacturer (retired)
+pub const HARDCOPY_DEVICE_MANUFACTURER: Tag = Tag(0x0018, 0x1017);
+/// (0018,1018) Secondary Capture Device Manufacturer Model Name
+pub const SECONDARY_CAPTURE_DEVICE_MANUFACTURER_MODEL_NAME: Tag = Tag(0x0018, 0x1018);
+/// (0018,1019) Secondary Capture Device Software Versions
+pub const SECONDARY_CAPTURE_DEVICE_SOFTWARE_VERSIONS: Tag = Tag(0x0018, 0x1019);
+/// (0018,101A) Hardcopy Device Software Version (retired)
+pub const HARDCOPY_DEVICE_SOFTWARE_VERSION: Tag = Tag(0x0018, 0x101A);
+/// (0018,101B) Hardcopy Device Manufacturer Model Name (retired)
+pub const HARDCOPY_DEVICE_MANUFACTURER_MODEL_NAME: Tag = Tag(0x0018, 0x101B);
+/// (0018,1020) Software Versions
+pub const SOFTWARE_VERSIONS: Tag = Tag(0x0018, 0x1020);
+/// (0018,1022) Video Image Format Acquired
+pub const VIDEO_IMAGE_FORMAT_ACQUIRED: Tag = Tag(0x0018, 0x1022);
+/// (0018,1023) Digital Image Format Acquired
+pub const DIGITAL_IMAGE_FORMAT_ACQUIRED: Tag = Tag(0x0018, 0x1023);
+/// (0018,1030) Protocol Name
+pub const PROTOCOL_NAME: Tag = Tag(0x0018, 0x1030);
+/// (0018,1040) Contrast Bolus Route
+pub const CONTRAST_BOLUS_ROUTE: Tag = Tag(0x0018, 0x1040);
+/// (0018,1041) Contrast Bolus Volume
+pub const CONTRAST_BOLUS_VOLUME: Tag = Tag(0x0018, 0x1041);
+/// (0018,1042) Contrast Bolus Start Time
+pub const CONTRAST_BOLUS_START_TIME: Tag = Tag(0x0018, 0x1042);
+/// (0018,1043) Contrast Bolus Stop Time
+pub const CONTRAST_BOLUS_STOP_TIME: Tag = Tag(0x0018, 0x1043);
+/// (0018,1044) Contrast Bolus Total Dose
+pub const CONTRAST_BOLUS_TOTAL_DOSE: Tag = Tag(0x0018, 0x1044);
+/// (0018,1045) Syringe Counts
+pub const SYRINGE_COUNTS: Tag = Tag(0x0018, 0x1045);
+/// (0018,1046) Contrast Flow Rate
+pub const CONTRAST_FLOW_RATE: Tag = Tag(0x0018, 0x1046);
+/// (0018,1047) Contrast Flow Duration
+pub const CONTRAST_FLOW_DURATION: Tag = Tag(0x0018, 0x1047);
+/// (0018,1048) Contrast Bolus Ingredient
+pub const CONTRAST_BOLUS_INGREDIENT: Tag = Tag(0x0018, 0x1048);
+/// (0018,1049) Contrast Bolus Ingredient Concentration
+pub const CONTRAST_BOLUS_INGREDIENT_CONCENTRATION: Tag = Tag(0x0018, 0x1049);
+/// (0018,1050) Spatial Resolution
+pub const SPATIAL_RESOLUTION: Tag = Tag(0x0018, 0x1050);
+/// (0018,1060) Trigger Time
+pub const TRIGGER_TIME: Tag = Tag(0x0018, 0x1060);
+/// (0018,1061) Trigger Source or Type
+pub const TRIGGER_SOURCE_OR_TYPE: Tag = Tag(0x0018, 0x1061);
+/// (0018,1062) Nominal Interval
+pub const NOMINAL_INTERVAL: Tag = Tag(0x0018, 0x1062);
+/// (0018,1063) Frame Time
+pub const FRAME_TIME: Tag = Tag(0x0018, 0x1063);
+/// (0018,1064) Cardiac Framing Type
+pub const CARDIAC_FRAMING_TYPE: Tag = Tag(0x0018, 0x1064);
+/// (0018,1065) Frame Time Vector
+pub const FRAME_TIME_VECTOR: Tag = Tag(0x0018, 0x1065);
+/// (0018,1066) Frame Delay
+pub const FRAME_DELAY: Tag = Tag(0x0018, 0x1066);
+/// (0018,1067) Image Trigger Delay
+pub const IMAGE_TRIGGER_DELAY: Tag = Tag(0x0018, 0x1067);
+/// (0018,1068) Multiplex Group Time Offset
+pub const MULTIPLEX_GROUP_TIME_OFFSET: Tag = Tag(0x0018, 0x1068);
+/// (0018,1069) Trigger Time Offset
+pub const TRIGGER_TIME_OFFSET: Tag = Tag(0x0018, 0x1069);
+/// (0018,106A) Synchronization Trigger
+pub const SYNCHRONIZATION_TRIGGER: Tag = Tag(0x0018, 0x106A);
+/// (0018,106C) Synchronization Channel
+pub const SYNCHRONIZATION_CHANNEL: Tag = Tag(0x0018, 0x106C);
+/// (0018,106E) Trigger Sample Position
+pub const TRIGGER_SAMPLE_POSITION: Tag = Tag(0x0018, 0x106E);
+/// (0018,1070) Radiopharmaceutical Route
+pub const RADIOPHARMACEUTICAL_ROUTE: Tag = Tag(0x0018, 0x1070);
+/// (0018,1071) Radiopharmaceutical Volume
+pub const RADIOPHARMACEUTICAL_VOLUME: Tag = Tag(0x0018, 0x1071);
+/// (0018,1072) Radiopharmaceutical Start Time
+pub const RADIOPHARMACEUTICAL_START_TIME: Tag = Tag(0x0018, 0x1072);
+/// (0018,1073) Radiopharmaceutical Stop Time
+pub const RADIOPHARMACEUTICAL_STOP_TIME: Tag = Tag(0x0018, 0x1073);
+/// (0018,1074) Radionuclide Total Dose
+pub const RADIONUCLIDE_TOTAL_DOSE: Tag = Tag(0x0018, 0x1074);
+/// (0018,1075) Radionuclide Half Life
+pub const RADIONUCLIDE_HALF_LIFE: Tag = Tag(0x0018, 0x1075);
+/// (0018,1076) Radionuclide Positron Fraction
+pub const RADIONUCLIDE_POSITRON_FRACTION: Tag = Tag(0x0018, 0x1076);
+/// (0018,1077) Radiopharmaceutical Specific Activity
+pub const RADIOPHARMACEUTICAL_SPECIFIC_ACTIVITY: Tag = Tag(0x0018, 0x1077);
+/// (0018,1078) Radiopharmaceutical Start Date Time
+pub const RADIOPHARMACEUTICAL_START_DATE_TIME: Tag = Tag(0x0018, 0x1078);
+/// (0018,1079) Radiopharmaceutical Stop Date Time
+pub const RADIOPHARMACEUTICAL_STOP_DATE_TIME: Tag = Tag(0x0018, 0x1079);
+/// (0018,1080) Beat Rejection Flag
+pub const BEAT_REJECTION_FLAG: Tag = Tag(0x0018, 0x1080);
+/// (0018,1081) Low RR Value
+pub const LOW_RR_VALUE: Tag = Tag(0x0018, 0x1081);
+/// (0018,1082) High RR Value
+pub const HIGH_RR_VALUE: Tag = Tag(0x0018, 0x1082);
+/// (0018,1083) Intervals Acquired
+pub const INTERVALS_ACQUIRED: Tag = Tag(0x0018, 0x1083);
+/// (0018,1084) Intervals Rejected
+pub const INTERVALS_REJECTED: Tag = Tag(0x0018, 0x1084);
+/// (0018,1085) PVC Rejection
+pub const PVC_REJECTION: Tag = Tag(0x0018, 0x1085);
+/// (0018,1086) Skip Beats
+pub const SKIP_BEATS: Tag = Tag(0x0018, 0x1086);
+/// (0018,1088) Heart Rate
+pub const HEART_RATE: Tag = Tag(0x0018, 0x1088);
+/// (0018,1090) Cardiac Number of Images
+pub const CARDIAC_NUMBER_OF_IMAGES: Tag = Tag(0x0018, 0x1090);
+/// (0018,1094) Trigger Window
+pub const TRIGGER_WINDOW: Tag = Tag(0x0018, 0x1094);
+/// (0018,1100) Reconstruction Diameter
+pub const RECONSTRUCTION_DIAMETER: Tag = Tag(0x0018, 0x1100);
+/// (0018,1110) Distance Source to Detector
+pub const DISTANCE_SOURCE_TO_DETECTOR: Tag = Tag(0x0018, 0x1110);
+/// (0018,1111) Distance Source to Patient
+pub const DISTANCE_SOURCE_TO_PATIENT: Tag = Tag(0x0018, 0x1111);
+/// (0018,1114) Estimated Radiographic Magnification Factor
+pub const ESTIMATED_RADIOGRAPHIC_MAGNIFICATION_FACTOR: Tag = Tag(0x0018, 0x1114);
+/// (0018,1120) Gantry Detector Tilt
+pub const GANTRY_DETECTOR_TILT: Tag = Tag(0x0018, 0x1120);
+/// (0018,1121) Gantry Detector Slew
+pub const GANTRY_DETECTOR_SLEW: Tag = Tag(0x0018, 0x1121);
+/// (0018,1130) Table Height
+pub const TABLE_HEIGHT: Tag = Tag(0x0018, 0x1130);
+/// (0018,1131) Table Traverse
+pub const TABLE_TRAVERSE: Tag = Tag(0x0018, 0x1131);
+/// (0018,1134) Table Motion
+pub const TABLE_MOTION: Tag = Tag(0x0018, 0x1134);
+/// (0018,1135) Table Vertical Increment
+pub const TABLE_VERTICAL_INCREMENT: Tag = Tag(0x0018, 0x1135);
+/// (0018,1136) Table Lateral Increment
+pub const TABLE_LATERAL_INCREMENT: Tag = Tag(0x0018, 0x1136);
+/// (0018,1137) Table Longitudinal Increment
+pub const TABLE_LONGITUDINAL_INCREMENT: Tag = Tag(0x0018, 0x1137);
+/// (0018,1138) Table Angle
+pub const TABLE_ANGLE: Tag = Tag(0x0018, 0x1138);
+/// (0018,113A) Table Type
+pub const TABLE_TYPE: Tag = Tag(0x0018, 0x113A);
+/// (0018,1140) Rotation Direction
+pub const ROTATION_DIRECTION: Tag = Tag(0x0018, 0x1140);
+/// (0018,1141) Angular Position (retired)
+pub const ANGULAR_POSITION: Tag = Tag(0x0018, 0x1141);
+/// (0018,1142) Radial Position
+pub const RADIAL_POSITION: Tag = Tag(0x0018, 0x1142);
+/// (0018,1143) Scan Arc
+pub const SCAN_ARC: Tag = Tag(0x0018, 0x1143);
+/// (0018,1144) Angular Step
+pub const ANGULAR_STEP: Tag = Tag(0x0018, 0x1144);
+/// (0018,1145) Center of Rotation Offset
+pub const CENTER_OF_ROTATION_OFFSET: Tag = Tag(0x0018, 0x1145);
+/// (0018,1146) Rotation Offset (retired)
+pub const ROTATION_OFFSET: Tag = Tag(0x0018, 0x1146);
+/// (0018,1147) Field of View Shape
+pub const FIELD_OF_VIEW_SHAPE: Tag = Tag(0x0018, 0x1147);
+/// (0018,1149) Field of View Dimensions
+pub const FIELD_OF_VIEW_DIMENSIONS: Tag = Tag(0x0018, 0x1149);
+/// (0018,1150) Exposure Time
+pub const EXPOSURE_TIME: Tag = Tag(0x0018, 0x1150);
+/// (0018,1151) X-Ray Tube Current
+pub const X_RAY_TUBE_CURRENT: Tag = Tag(0x0018, 0x1151);
+/// (0018,1152) Exposure
+pub const EXPOSURE: Tag = Tag(0x0018, 0x1152);
+/// (0018,1153) Exposure in uAs
+pub const EXPOSURE_INU_AS: Tag = Tag(0x0018, 0x1153);
+/// (0018,1154) Average Pulse Width
+pub const AVERAGE_PULSE_WIDTH: Tag = Tag(0x0018, 0x1154);
+/// (0018,1155) Radiation Setting
+pub const RADIATION_SETTING: Tag = Tag(0x0018, 0x1155);
+/// (0018,1156) Rectification Type
+pub const RECTIFICATION_TYPE: Tag = Tag(0x0018, 0x1156);
+/// (0018,115A) Radiation Mode
+pub const RADIATION_MODE: Tag = Tag(0x0018, 0x115A);
+/// (0018,115E) Image and Fluoroscopy Area Dose Product
+pub const IMAGE_AND_FLUOROSCOPY_AREA_DOSE_PRODUCT: Tag = Tag(0x0018, 0x115E);
+/// (0018,1160) Filter Type
+pub const FILTER_TYPE: Tag = Tag(0x0018, 0x1160);
+/// (0018,1161) Type of Filters
+pub const TYPE_OF_FILTERS: Tag = Tag(0x0018, 0x1161);
+/// (0018,1162) Intensifier Size
+pub const INTENSIFIER_SIZE: Tag = Tag(0x0018, 0x1162);
+/// (0018,1164) Imager Pixel Spacing
+pub const IMAGER_PIXEL_SPACING: Tag = Tag(0x0018, 0x1164);
+/// (0018,1166) Grid
+pub const GRID: Tag = Tag(0x0018, 0x1166);
+/// (0018,1170) Generator Power
+pub const GENERATOR_POWER: Tag = Tag(0x0018, 0x1170);
+/// (0018,1180) Collimator Grid Name
+pub const COLLIMATOR_GRID_NAME: Tag = Tag(0x0018, 0x1180);
+/// (0018,1181) Collimator Type
+pub const COLLIMATOR_TYPE: Tag = Tag(0x0018, 0x1181);
+/// (0018,1182) Focal Distance
+pub const FOCAL_DISTANCE: Tag = Tag(0x0018, 0x1182);
+/// (0018,1183) X Focus Center
+pub const X_FOCUS_CENTER: Tag = Tag(0x0018, 0x1183);
+/// (0018,1184) Y Focus Center
+pub const Y_FOCUS_CENTER: Tag = Tag(0x0018, 0x1184);
+/// (0018,1190) Focal Spots
+pub const FOCAL_SPOTS: Tag = Tag(0x0018, 0x1190);
+/// (0018,1191) Anode Target Material
+pub const ANODE_TARGET_MATERIAL: Tag = Tag(0x0018, 0x1191);
+/// (0018,11A0) Body Part Thickness
+pub const BODY_PART_THICKNESS: Tag = Tag(0x0018, 0x11A0);
+/// (0018,11A2) Compression Force
+pub const COMPRESSION_FORCE: Tag = Tag(0x0018, 0x11A2);
+/// (0018,11A3) Compression Pressure
+pub const COMPRESSION_PRESSURE: Tag = Tag(0x0018, 0x11A3);
+/// (0018,11A4) Paddle Description
+pub const PADDLE_DESCRIPTION: Tag = Tag(0x0018, 0x11A4);
+/// (0018,11A5) Compression Contact Area
+pub const COMPRESSION_CONTACT_AREA: Tag = Tag(0x0018, 0x11A5);
+/// (0018,1200) Date of Last Calibration
+pub const DATE_OF_LAST_CALIBRATION: Tag = Tag(0x0018, 0x1200);
+/// (0018,1201) Time of Last Calibration
+pub const TIME_OF_LAST_CALIBRATION: Tag = Tag(0x0018, 0x1201);
+/// (0018,1202) Date Time of Last Calibration
+pub const DATE_TIME_OF_LAST_CALIBRATION: Tag = Tag(0x0018, 0x1202);
+/// (0018,1210) Convolution Kernel
+pub const CONVOLUTION_KERNEL: Tag = Tag(0x0018, 0x1210);
+/// (0018,1240) Upper Lower Pixel Values (retired)
+pub const UPPER_LOWER_PIXEL_VALUES: Tag = Tag(0x0018, 0x1240);
+/// (0018,1242) Actual Frame Duration
+pub const ACTUAL_FRAME_DURATION: Tag = Tag(0x0018, 0x1242);
+/// (0018,1243) Count Rate
+pub const COUNT_RATE: Tag = Tag(0x0018, 0x1243);
+/// (0018,1244) Preferred Playback Sequencing
+pub const PREFERRED_PLAYBACK_SEQUENCING: Tag = Tag(0x0018, 0x1244);
+/// (0018,1250) Receive Coil Name
+pub const RECEIVE_COIL_NAME: Tag = Tag(0x0018, 0x1250);
+/// (0018,1251) Transmit Coil Name
+pub const TRANSMIT_COIL_NAME: Tag = Tag(0x0018, 0x1251);
+/// (0018,1260) Plate Type
+pub const PLATE_TYPE: Tag = Tag(0x0018, 0x1260);
+/// (0018,1261) Phosphor Type
+pub const PHOSPHOR_TYPE: Tag = Tag(0x0018, 0x1261);
+/// (0018,1271) Water Equivalent Diameter
+pub const WATER_EQUIVALENT_DIAMETER: Tag = Tag(0x0018, 0x1271);
+/// (0018,1272) Water Equivalent Diameter Calculation Method Code Sequence
+pub const WATER_EQUIVALENT_DIAMETER_CALCULATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0018, 0x1272);
+/// (0018,1300) Scan Velocity
+pub const SCAN_VELOCITY: Tag = Tag(0x0018, 0x1300);
+/// (0018,1301) Whole Body Technique
+pub const WHOLE_BODY_TECHNIQUE: Tag = Tag(0x0018, 0x1301);
+/// (0018,1302) Scan Length
+pub const SCAN_LENGTH: Tag = Tag(0x0018, 0x1302);
+/// (0018,1310) Acquisition Matrix
+pub const ACQUISITION_MATRIX: Tag = Tag(0x0018, 0x1310);
+/// (0018,1312) In Plane Phase Encoding Direction
+pub const IN_PLANE_PHASE_ENCODING_DIRECTION: Tag = Tag(0x0018, 0x1312);
+/// (0018,1314) Flip Angle
+pub const FLIP_ANGLE: Tag = Tag(0x0018, 0x1314);
+/// (0018,1315) Variable Flip Angle Flag
+pub const VARIABLE_FLIP_ANGLE_FLAG: Tag = Tag(0x0018, 0x1315);
+/// (0018,1316) SAR
+pub const SAR: Tag = Tag(0x0018, 0x1316);
+/// (0018,1318) d Bdt
+pub const DBDT: Tag = Tag(0x0018, 0x1318);
+/// (0018,1320) B 1 rms
+pub const B1RMS: Tag = Tag(0x0018, 0x1320);
+/// (0018,1400) Acquisition Device Processing Description
+pub const ACQUISITION_DEVICE_PROCESSING_DESCRIPTION: Tag = Tag(0x0018, 0x1400);
+/// (0018,1401) Acquisition Device Processing Code
+pub const ACQUISITION_DEVICE_PROCESSING_CODE: Tag = Tag(0x0018, 0x1401);
+/// (0018,1402) Cassette Orientation
+pub const CASSETTE_ORIENTATION: Tag = Tag(0x0018, 0x1402);
+/// (0018,1403) Cassette Size
+pub const CASSETTE_SIZE: Tag = Tag(0x0018, 0x1403);
+/// (0018,1404) Exposures on Plate
+pub const EXPOSURES_ON_PLATE: Tag = Tag(0x0018, 0x1404);
+/// (0018,1405) Relative X-Ray Exposure
+pub const RELATIVE_X_RAY_EXPOSURE: Tag = Tag(0x0018, 0x1405);
+/// (0018,1411) Exposure Index
+pub const EXPOSURE_INDEX: Tag = Tag(0x0018, 0x1411);
+/// (0018,1412) Target Exposure Index
+pub const TARGET_EXPOSURE_INDEX: Tag = Tag(0x0018, 0x1412);
+/// (0018,1413) Deviation Index
+pub const DEVIATION_INDEX: Tag = Tag(0x0018, 0x1413);
+/// (0018,1450) Column Angulation
+pub const COLUMN_ANGULATION: Tag = Tag(0x0018, 0x1450);
+/// (0018,1460) Tomo Layer Height
+pub const TOMO_LAYER_HEIGHT: Tag = Tag(0x0018, 0x1460);
+/// (0018,1470) Tomo Angle
+pub const TOMO_ANGLE: Tag = Tag(0x0018, 0x1470);
+/// (0018,1480) Tomo Time
+pub const TOMO_TIME: Tag = Tag(0x0018, 0x1480);
+/// (0018,1490) Tomo Type
+pub const TOMO_TYPE: Tag = Tag(0x0018, 0x1490);
+/// (0018,1491) Tomo Class
+pub const TOMO_CLASS: Tag = Tag(0x0018, 0x1491);
+/// (0018,1495) Number of Tomosynthesis Source Images
+pub const NUMBER_OF_TOMOSYNTHESIS_SOURCE_IMAGES: Tag = Tag(0x0018, 0x1495);
+/// (0018,1500) Positioner Motion
+pub const POSITIONER_MOTION: Tag = Tag(0x0018, 0x1500);
+/// (0018,1508) Positioner Type
+pub const POSITIONER_TYPE: Tag = Tag(0x0018, 0x1508);
+/// (0018,1510) Positioner Primary Angle
+pub const POSITIONER_PRIMARY_ANGLE: Tag = Tag(0x0018, 0x1510);
+/// (0018,1511) Positioner Secondary Angle
+pub const POSITIONER_SECONDARY_ANGLE: Tag = Tag(0x0018, 0x1511);
+/// (0018,1520) Positioner Primary Angle Increment
+pub const POSITIONER_PRIMARY_ANGLE_INCREMENT: Tag = Tag(0x0018, 0x1520);
+/// (0018,1521) Positioner Secondary Angle Increment
+pub const POSITIONER_SECONDARY_ANGLE_INCREMENT: Tag = Tag(0x0018, 0x1521);
+/// (0018,1530) Detector Primary Angle
+pub const DETECTOR_PRIMARY_ANGLE: Tag = Tag(0x0018, 0x1530);
+/// (0018,1531) Detector Secondary Angle
+pub const DETECTOR_SECONDARY_ANGLE: Tag = Tag(0x0018, 0x1531);
+/// (0018,1600) Shutter Shape
+pub const SHUTTER_SHAPE: Tag = Tag(0x0018, 0x1600);
+/// (0018,1602) Shutter Left Vertical Edge
+pub const SHUTTER_LEFT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x1602);
+/// (0018,1604) Shutter Right Vertical Edge
+pub const SHUTTER_RIGHT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x1604);
+/// (0018,1606) Shutter Upper Horizontal Edge
+pub const SHUTTER_UPPER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x1606);
+/// (0018,1608) Shutter Lower Horizontal Edge
+pub const SHUTTER_LOWER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x1608);
+/// (0018,1610) Center of Circular Shutter
+pub const CENTER_OF_CIRCULAR_SHUTTER: Tag = Tag(0x0018, 0x1610);
+/// (0018,1612) Radius of Circular Shutter
+pub const RADIUS_OF_CIRCULAR_SHUTTER: Tag = Tag(0x0018, 0x1612);
+/// (0018,1620) Vertices of the Polygonal Shutter
+pub const VERTICES_OF_THE_POLYGONAL_SHUTTER: Tag = Tag(0x0018, 0x1620);
+/// (0018,1622) Shutter Presentation Value
+pub const SHUTTER_PRESENTATION_VALUE: Tag = Tag(0x0018, 0x1622);
+/// (0018,1623) Shutter Overlay Group
+pub const SHUTTER_OVERLAY_GROUP: Tag = Tag(0x0018, 0x1623);
+/// (0018,1624) Shutter Presentation Color CIE Lab Value
+pub const SHUTTER_PRESENTATION_COLOR_CIE_LAB_VALUE: Tag = Tag(0x0018, 0x1624);
+/// (0018,1700) Collimator Shape
+pub const COLLIMATOR_SHAPE: Tag = Tag(0x0018, 0x1700);
+/// (0018,1702) Collimator Left Vertical Edge
+pub const COLLIMATOR_LEFT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x1702);
+/// (0018,1704) Collimator Right Vertical Edge
+pub const COLLIMATOR_RIGHT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x1704);
+/// (0018,1706) Collimator Upper Horizontal Edge
+pub const COLLIMATOR_UPPER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x1706);
+/// (0018,1708) Collimator Lower Horizontal Edge
+pub const COLLIMATOR_LOWER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x1708);
+/// (0018,1710) Center of Circular Collimator
+pub const CENTER_OF_CIRCULAR_COLLIMATOR: Tag = Tag(0x0018, 0x1710);
+/// (0018,1712) Radius of Circular Collimator
+pub const RADIUS_OF_CIRCULAR_COLLIMATOR: Tag = Tag(0x0018, 0x1712);
+/// (0018,1720) Vertices of the Polygonal Collimator
+pub const VERTICES_OF_THE_POLYGONAL_COLLIMATOR: Tag = Tag(0x0018, 0x1720);
+/// (0018,1800) Acquisition Time Synchronized
+pub const ACQUISITION_TIME_SYNCHRONIZED: Tag = Tag(0x0018, 0x1800);
+/// (0018,1801) Time Source
+pub const TIME_SOURCE: Tag = Tag(0x0018, 0x1801);
+/// (0018,1802) Time Distribution Protocol
+pub const TIME_DISTRIBUTION_PROTOCOL: Tag = Tag(0x0018, 0x1802);
+/// (0018,1803) NTP Source Address
+pub const NTP_SOURCE_ADDRESS: Tag = Tag(0x0018, 0x1803);
+/// (0018,2001) Page Number Vector
+pub const PAGE_NUMBER_VECTOR: Tag = Tag(0x0018, 0x2001);
+/// (0018,2002) Frame Label Vector
+pub const FRAME_LABEL_VECTOR: Tag = Tag(0x0018, 0x2002);
+/// (0018,2003) Frame Primary Angle Vector
+pub const FRAME_PRIMARY_ANGLE_VECTOR: Tag = Tag(0x0018, 0x2003);
+/// (0018,2004) Frame Secondary Angle Vector
+pub const FRAME_SECONDARY_ANGLE_VECTOR: Tag = Tag(0x0018, 0x2004);
+/// (0018,2005) Slice Location Vector
+pub const SLICE_LOCATION_VECTOR: Tag = Tag(0x0018, 0x2005);
+/// (0018,2006) Display Window Label Vector
+pub const DISPLAY_WINDOW_LABEL_VECTOR: Tag = Tag(0x0018, 0x2006);
+/// (0018,2010) Nominal Scanned Pixel Spacing
+pub const NOMINAL_SCANNED_PIXEL_SPACING: Tag = Tag(0x0018, 0x2010);
+/// (0018,2020) Digitizing Device Transport Direction
+pub const DIGITIZING_DEVICE_TRANSPORT_DIRECTION: Tag = Tag(0x0018, 0x2020);
+/// (0018,2030) Rotation of Scanned Film
+pub const ROTATION_OF_SCANNED_FILM: Tag = Tag(0x0018, 0x2030);
+/// (0018,2041) Biopsy Target Sequence
+pub const BIOPSY_TARGET_SEQUENCE: Tag = Tag(0x0018, 0x2041);
+/// (0018,2042) Target UID
+pub const TARGET_UID: Tag = Tag(0x0018, 0x2042);
+/// (0018,2043) Localizing Cursor Position
+pub const LOCALIZING_CURSOR_POSITION: Tag = Tag(0x0018, 0x2043);
+/// (0018,2044) Calculated Target Position
+pub const CALCULATED_TARGET_POSITION: Tag = Tag(0x0018, 0x2044);
+/// (0018,2045) Target Label
+pub const TARGET_LABEL: Tag = Tag(0x0018, 0x2045);
+/// (0018,2046) Displayed Z Value
+pub const DISPLAYED_Z_VALUE: Tag = Tag(0x0018, 0x2046);
+/// (0018,3100) IVUS Acquisition
+pub const IVUS_ACQUISITION: Tag = Tag(0x0018, 0x3100);
+/// (0018,3101) IVUS Pullback Rate
+pub const IVUS_PULLBACK_RATE: Tag = Tag(0x0018, 0x3101);
+/// (0018,3102) IVUS Gated Rate
+pub const IVUS_GATED_RATE: Tag = Tag(0x0018, 0x3102);
+/// (0018,3103) IVUS Pullback Start Frame Number
+pub const IVUS_PULLBACK_START_FRAME_NUMBER: Tag = Tag(0x0018, 0x3103);
+/// (0018,3104) IVUS Pullback Stop Frame Number
+pub const IVUS_PULLBACK_STOP_FRAME_NUMBER: Tag = Tag(0x0018, 0x3104);
+/// (0018,3105) Lesion Number
+pub const LESION_NUMBER: Tag = Tag(0x0018, 0x3105);
+/// (0018,4000) Acquisition Comments (retired)
+pub const ACQUISITION_COMMENTS: Tag = Tag(0x0018, 0x4000);
+/// (0018,5000) Output Power
+pub const OUTPUT_POWER: Tag = Tag(0x0018, 0x5000);
+/// (0018,5010) Transducer Data
+pub const TRANSDUCER_DATA: Tag = Tag(0x0018, 0x5010);
+/// (0018,5012) Focus Depth
+pub const FOCUS_DEPTH: Tag = Tag(0x0018, 0x5012);
+/// (0018,5020) Processing Function
+pub const PROCESSING_FUNCTION: Tag = Tag(0x0018, 0x5020);
+/// (0018,5021) Postprocessing Function (retired)
+pub const POSTPROCESSING_FUNCTION: Tag = Tag(0x0018, 0x5021);
+/// (0018,5022) Mechanical Index
+pub const MECHANICAL_INDEX: Tag = Tag(0x0018, 0x5022);
+/// (0018,5024) Bone Thermal Index
+pub const BONE_THERMAL_INDEX: Tag = Tag(0x0018, 0x5024);
+/// (0018,5026) Cranial Thermal Index
+pub const CRANIAL_THERMAL_INDEX: Tag = Tag(0x0018, 0x5026);
+/// (0018,5027) Soft Tissue Thermal Index
+pub const SOFT_TISSUE_THERMAL_INDEX: Tag = Tag(0x0018, 0x5027);
+/// (0018,5028) Soft Tissue Focus Thermal Index
+pub const SOFT_TISSUE_FOCUS_THERMAL_INDEX: Tag = Tag(0x0018, 0x5028);
+/// (0018,5029) Soft Tissue Surface Thermal Index
+pub const SOFT_TISSUE_SURFACE_THERMAL_INDEX: Tag = Tag(0x0018, 0x5029);
+/// (0018,5030) Dynamic Range (retired)
+pub const DYNAMIC_RANGE: Tag = Tag(0x0018, 0x5030);
+/// (0018,5040) Total Gain (retired)
+pub const TOTAL_GAIN: Tag = Tag(0x0018, 0x5040);
+/// (0018,5050) Depth of Scan Field
+pub const DEPTH_OF_SCAN_FIELD: Tag = Tag(0x0018, 0x5050);
+/// (0018,5100) Patient Position
+pub const PATIENT_POSITION: Tag = Tag(0x0018, 0x5100);
+/// (0018,5101) View Position
+pub const VIEW_POSITION: Tag = Tag(0x0018, 0x5101);
+/// (0018,5104) Projection Eponymous Name Code Sequence
+pub const PROJECTION_EPONYMOUS_NAME_CODE_SEQUENCE: Tag = Tag(0x0018, 0x5104);
+/// (0018,5210) Image Transformation Matrix (retired)
+pub const IMAGE_TRANSFORMATION_MATRIX: Tag = Tag(0x0018, 0x5210);
+/// (0018,5212) Image Translation Vector (retired)
+pub const IMAGE_TRANSLATION_VECTOR: Tag = Tag(0x0018, 0x5212);
+/// (0018,6000) Sensitivity
+pub const SENSITIVITY: Tag = Tag(0x0018, 0x6000);
+/// (0018,6011) Sequence of Ultrasound Regions
+pub const SEQUENCE_OF_ULTRASOUND_REGIONS: Tag = Tag(0x0018, 0x6011);
+/// (0018,6012) Region Spatial Format
+pub const REGION_SPATIAL_FORMAT: Tag = Tag(0x0018, 0x6012);
+/// (0018,6014) Region Data Type
+pub const REGION_DATA_TYPE: Tag = Tag(0x0018, 0x6014);
+/// (0018,6016) Region Flags
+pub const REGION_FLAGS: Tag = Tag(0x0018, 0x6016);
+/// (0018,6018) Region Location Min X 0
+pub const REGION_LOCATION_MIN_X0: Tag = Tag(0x0018, 0x6018);
+/// (0018,601A) Region Location Min Y 0
+pub const REGION_LOCATION_MIN_Y0: Tag = Tag(0x0018, 0x601A);
+/// (0018,601C) Region Location Max X 1
+pub const REGION_LOCATION_MAX_X1: Tag = Tag(0x0018, 0x601C);
+/// (0018,601E) Region Location Max Y 1
+pub const REGION_LOCATION_MAX_Y1: Tag = Tag(0x0018, 0x601E);
+/// (0018,6020) Reference Pixel X 0
+pub const REFERENCE_PIXEL_X0: Tag = Tag(0x0018, 0x6020);
+/// (0018,6022) Reference Pixel Y 0
+pub const REFERENCE_PIXEL_Y0: Tag = Tag(0x0018, 0x6022);
+/// (0018,6024) Physical Units X Direction
+pub const PHYSICAL_UNITS_X_DIRECTION: Tag = Tag(0x0018, 0x6024);
+/// (0018,6026) Physical Units Y Direction
+pub const PHYSICAL_UNITS_Y_DIRECTION: Tag = Tag(0x0018, 0x6026);
+/// (0018,6028) Reference Pixel Physical Value X
+pub const REFERENCE_PIXEL_PHYSICAL_VALUE_X: Tag = Tag(0x0018, 0x6028);
+/// (0018,602A) Reference Pixel Physical Value Y
+pub const REFERENCE_PIXEL_PHYSICAL_VALUE_Y: Tag = Tag(0x0018, 0x602A);
+/// (0018,602C) Physical Delta X
+pub const PHYSICAL_DELTA_X: Tag = Tag(0x0018, 0x602C);
+/// (0018,602E) Physical Delta Y
+pub const PHYSICAL_DELTA_Y: Tag = Tag(0x0018, 0x602E);
+/// (0018,6030) Transducer Frequency
+pub const TRANSDUCER_FREQUENCY: Tag = Tag(0x0018, 0x6030);
+/// (0018,6031) Transducer Type
+pub const TRANSDUCER_TYPE: Tag = Tag(0x0018, 0x6031);
+/// (0018,6032) Pulse Repetition Frequency
+pub const PULSE_REPETITION_FREQUENCY: Tag = Tag(0x0018, 0x6032);
+/// (0018,6034) Doppler Correction Angle
+pub const DOPPLER_CORRECTION_ANGLE: Tag = Tag(0x0018, 0x6034);
+/// (0018,6036) Steering Angle
+pub const STEERING_ANGLE: Tag = Tag(0x0018, 0x6036);
+/// (0018,6038) Doppler Sample Volume X Position Retired (retired)
+pub const DOPPLER_SAMPLE_VOLUME_X_POSITION_RETIRED: Tag = Tag(0x0018, 0x6038);
+/// (0018,6039) Doppler Sample Volume X Position
+pub const DOPPLER_SAMPLE_VOLUME_X_POSITION: Tag = Tag(0x0018, 0x6039);
+/// (0018,603A) Doppler Sample Volume Y Position Retired (retired)
+pub const DOPPLER_SAMPLE_VOLUME_Y_POSITION_RETIRED: Tag = Tag(0x0018, 0x603A);
+/// (0018,603B) Doppler Sample Volume Y Position
+pub const DOPPLER_SAMPLE_VOLUME_Y_POSITION: Tag = Tag(0x0018, 0x603B);
+/// (0018,603C) TM Line Position X 0 Retired (retired)
+pub const TM_LINE_POSITION_X0RETIRED: Tag = Tag(0x0018, 0x603C);
+/// (0018,603D) TM Line Position X 0
+pub const TM_LINE_POSITION_X0: Tag = Tag(0x0018, 0x603D);
+/// (0018,603E) TM Line Position Y 0 Retired (retired)
+pub const TM_LINE_POSITION_Y0RETIRED: Tag = Tag(0x0018, 0x603E);
+/// (0018,603F) TM Line Position Y 0
+pub const TM_LINE_POSITION_Y0: Tag = Tag(0x0018, 0x603F);
+/// (0018,6040) TM Line Position X 1 Retired (retired)
+pub const TM_LINE_POSITION_X1RETIRED: Tag = Tag(0x0018, 0x6040);
+/// (0018,6041) TM Line Position X 1
+pub const TM_LINE_POSITION_X1: Tag = Tag(0x0018, 0x6041);
+/// (0018,6042) TM Line Position Y 1 Retired (retired)
+pub const TM_LINE_POSITION_Y1RETIRED: Tag = Tag(0x0018, 0x6042);
+/// (0018,6043) TM Line Position Y 1
+pub const TM_LINE_POSITION_Y1: Tag = Tag(0x0018, 0x6043);
+/// (0018,6044) Pixel Component Organization
+pub const PIXEL_COMPONENT_ORGANIZATION: Tag = Tag(0x0018, 0x6044);
+/// (0018,6046) Pixel Component Mask
+pub const PIXEL_COMPONENT_MASK: Tag = Tag(0x0018, 0x6046);
+/// (0018,6048) Pixel Component Range Start
+pub const PIXEL_COMPONENT_RANGE_START: Tag = Tag(0x0018, 0x6048);
+/// (0018,604A) Pixel Component Range Stop
+pub const PIXEL_COMPONENT_RANGE_STOP: Tag = Tag(0x0018, 0x604A);
+/// (0018,604C) Pixel Component Physical Units
+pub const PIXEL_COMPONENT_PHYSICAL_UNITS: Tag = Tag(0x0018, 0x604C);
+/// (0018,604E) Pixel Component Data Type
+pub const PIXEL_COMPONENT_DATA_TYPE: Tag = Tag(0x0018, 0x604E);
+/// (0018,6050) Number of Table Break Points
+pub const NUMBER_OF_TABLE_BREAK_POINTS: Tag = Tag(0x0018, 0x6050);
+/// (0018,6052) Table of X Break Points
+pub const TABLE_OF_X_BREAK_POINTS: Tag = Tag(0x0018, 0x6052);
+/// (0018,6054) Table of Y Break Points
+pub const TABLE_OF_Y_BREAK_POINTS: Tag = Tag(0x0018, 0x6054);
+/// (0018,6056) Number of Table Entries
+pub const NUMBER_OF_TABLE_ENTRIES: Tag = Tag(0x0018, 0x6056);
+/// (0018,6058) Table of Pixel Values
+pub const TABLE_OF_PIXEL_VALUES: Tag = Tag(0x0018, 0x6058);
+/// (0018,605A) Table of Parameter Values
+pub const TABLE_OF_PARAMETER_VALUES: Tag = Tag(0x0018, 0x605A);
+/// (0018,6060) R Wave Time Vector
+pub const R_WAVE_TIME_VECTOR: Tag = Tag(0x0018, 0x6060);
+/// (0018,7000) Detector Conditions Nominal Flag
+pub const DETECTOR_CONDITIONS_NOMINAL_FLAG: Tag = Tag(0x0018, 0x7000);
+/// (0018,7001) Detector Temperature
+pub const DETECTOR_TEMPERATURE: Tag = Tag(0x0018, 0x7001);
+/// (0018,7004) Detector Type
+pub const DETECTOR_TYPE: Tag = Tag(0x0018, 0x7004);
+/// (0018,7005) Detector Configuration
+pub const DETECTOR_CONFIGURATION: Tag = Tag(0x0018, 0x7005);
+/// (0018,7006) Detector Description
+pub const DETECTOR_DESCRIPTION: Tag = Tag(0x0018, 0x7006);
+/// (0018,7008) Detector Mode
+pub const DETECTOR_MODE: Tag = Tag(0x0018, 0x7008);
+/// (0018,700A) Detector ID
+pub const DETECTOR_ID: Tag = Tag(0x0018, 0x700A);
+/// (0018,700C) Date of Last Detector Calibration
+pub const DATE_OF_LAST_DETECTOR_CALIBRATION: Tag = Tag(0x0018, 0x700C);
+/// (0018,700E) Time of Last Detector Calibration
+pub const TIME_OF_LAST_DETECTOR_CALIBRATION: Tag = Tag(0x0018, 0x700E);
+/// (0018,7010) Exposures on Detector Since Last Calibration
+pub const EXPOSURES_ON_DETECTOR_SINCE_LAST_CALIBRATION: Tag = Tag(0x0018, 0x7010);
+/// (0018,7011) Exposures on Detector Since Manufactured
+pub const EXPOSURES_ON_DETECTOR_SINCE_MANUFACTURED: Tag = Tag(0x0018, 0x7011);
+/// (0018,7012) Detector Time Since Last Exposure
+pub const DETECTOR_TIME_SINCE_LAST_EXPOSURE: Tag = Tag(0x0018, 0x7012);
+/// (0018,7014) Detector Active Time
+pub const DETECTOR_ACTIVE_TIME: Tag = Tag(0x0018, 0x7014);
+/// (0018,7016) Detector Activation Offset from Exposure
+pub const DETECTOR_ACTIVATION_OFFSET_FROM_EXPOSURE: Tag = Tag(0x0018, 0x7016);
+/// (0018,701A) Detector Binning
+pub const DETECTOR_BINNING: Tag = Tag(0x0018, 0x701A);
+/// (0018,7020) Detector Element Physical Size
+pub const DETECTOR_ELEMENT_PHYSICAL_SIZE: Tag = Tag(0x0018, 0x7020);
+/// (0018,7022) Detector Element Spacing
+pub const DETECTOR_ELEMENT_SPACING: Tag = Tag(0x0018, 0x7022);
+/// (0018,7024) Detector Active Shape
+pub const DETECTOR_ACTIVE_SHAPE: Tag = Tag(0x0018, 0x7024);
+/// (0018,7026) Detector Active Dimensions
+pub const DETECTOR_ACTIVE_DIMENSIONS: Tag = Tag(0x0018, 0x7026);
+/// (0018,7028) Detector Active Origin
+pub const DETECTOR_ACTIVE_ORIGIN: Tag = Tag(0x0018, 0x7028);
+/// (0018,702A) Detector Manufacturer Name
+pub const DETECTOR_MANUFACTURER_NAME: Tag = Tag(0x0018, 0x702A);
+/// (0018,702B) Detector Manufacturer Model Name
+pub const DETECTOR_MANUFACTURER_MODEL_NAME: Tag = Tag(0x0018, 0x702B);
+/// (0018,7030) Field of View Origin
+pub const FIELD_OF_VIEW_ORIGIN: Tag = Tag(0x0018, 0x7030);
+/// (0018,7032) Field of View Rotation
+pub const FIELD_OF_VIEW_ROTATION: Tag = Tag(0x0018, 0x7032);
+/// (0018,7034) Field of View Horizontal Flip
+pub const FIELD_OF_VIEW_HORIZONTAL_FLIP: Tag = Tag(0x0018, 0x7034);
+/// (0018,7036) Pixel Data Area Origin Relative to FOV
+pub const PIXEL_DATA_AREA_ORIGIN_RELATIVE_TO_FOV: Tag = Tag(0x0018, 0x7036);
+/// (0018,7038) Pixel Data Area Rotation Angle Relative to FOV
+pub const PIXEL_DATA_AREA_ROTATION_ANGLE_RELATIVE_TO_FOV: Tag = Tag(0x0018, 0x7038);
+/// (0018,7040) Grid Absorbing Material
+pub const GRID_ABSORBING_MATERIAL: Tag = Tag(0x0018, 0x7040);
+/// (0018,7041) Grid Spacing Material
+pub const GRID_SPACING_MATERIAL: Tag = Tag(0x0018, 0x7041);
+/// (0018,7042) Grid Thickness
+pub const GRID_THICKNESS: Tag = Tag(0x0018, 0x7042);
+/// (0018,7044) Grid Pitch
+pub const GRID_PITCH: Tag = Tag(0x0018, 0x7044);
+/// (0018,7046) Grid Aspect Ratio
+pub const GRID_ASPECT_RATIO: Tag = Tag(0x0018, 0x7046);
+/// (0018,7048) Grid Period
+pub const GRID_PERIOD: Tag = Tag(0x0018, 0x7048);
+/// (0018,704C) Grid Focal Distance
+pub const GRID_FOCAL_DISTANCE: Tag = Tag(0x0018, 0x704C);
+/// (0018,7050) Filter Material
+pub const FILTER_MATERIAL: Tag = Tag(0x0018, 0x7050);
+/// (0018,7052) Filter Thickness Minimum
+pub const FILTER_THICKNESS_MINIMUM: Tag = Tag(0x0018, 0x7052);
+/// (0018,7054) Filter Thickness Maximum
+pub const FILTER_THICKNESS_MAXIMUM: Tag = Tag(0x0018, 0x7054);
+/// (0018,7056) Filter Beam Path Length Minimum
+pub const FILTER_BEAM_PATH_LENGTH_MINIMUM: Tag = Tag(0x0018, 0x7056);
+/// (0018,7058) Filter Beam Path Length Maximum
+pub const FILTER_BEAM_PATH_LENGTH_MAXIMUM: Tag = Tag(0x0018, 0x7058);
+/// (0018,7060) Exposure Control Mode
+pub const EXPOSURE_CONTROL_MODE: Tag = Tag(0x0018, 0x7060);
+/// (0018,7062) Exposure Control Mode Description
+pub const EXPOSURE_CONTROL_MODE_DESCRIPTION: Tag = Tag(0x0018, 0x7062);
+/// (0018,7064) Exposure Status
+pub const EXPOSURE_STATUS: Tag = Tag(0x0018, 0x7064);
+/// (0018,7065) Phototimer Setting
+pub const PHOTOTIMER_SETTING: Tag = Tag(0x0018, 0x7065);
+/// (0018,8150) Exposure Time Inu S
+pub const EXPOSURE_TIME_INU_S: Tag = Tag(0x0018, 0x8150);
+/// (0018,8151) X-Ray Tube Current in uA
+pub const X_RAY_TUBE_CURRENT_INU_A: Tag = Tag(0x0018, 0x8151);
+/// (0018,9004) Content Qualification
+pub const CONTENT_QUALIFICATION: Tag = Tag(0x0018, 0x9004);
+/// (0018,9005) Pulse Sequence Name
+pub const PULSE_SEQUENCE_NAME: Tag = Tag(0x0018, 0x9005);
+/// (0018,9006) MR Imaging Modifier Sequence
+pub const MR_IMAGING_MODIFIER_SEQUENCE: Tag = Tag(0x0018, 0x9006);
+/// (0018,9008) Echo Pulse Sequence
+pub const ECHO_PULSE_SEQUENCE: Tag = Tag(0x0018, 0x9008);
+/// (0018,9009) Inversion Recovery
+pub const INVERSION_RECOVERY: Tag = Tag(0x0018, 0x9009);
+/// (0018,9010) Flow Compensation
+pub const FLOW_COMPENSATION: Tag = Tag(0x0018, 0x9010);
+/// (0018,9011) Multiple Spin Echo
+pub const MULTIPLE_SPIN_ECHO: Tag = Tag(0x0018, 0x9011);
+/// (0018,9012) Multi Planar Excitation
+pub const MULTI_PLANAR_EXCITATION: Tag = Tag(0x0018, 0x9012);
+/// (0018,9014) Phase Contrast
+pub const PHASE_CONTRAST: Tag = Tag(0x0018, 0x9014);
+/// (0018,9015) Time of Flight Contrast
+pub const TIME_OF_FLIGHT_CONTRAST: Tag = Tag(0x0018, 0x9015);
+/// (0018,9016) Spoiling
+pub const SPOILING: Tag = Tag(0x0018, 0x9016);
+/// (0018,9017) Steady State Pulse Sequence
+pub const STEADY_STATE_PULSE_SEQUENCE: Tag = Tag(0x0018, 0x9017);
+/// (0018,9018) Echo Planar Pulse Sequence
+pub const ECHO_PLANAR_PULSE_SEQUENCE: Tag = Tag(0x0018, 0x9018);
+/// (0018,9019) Tag Angle First Axis
+pub const TAG_ANGLE_FIRST_AXIS: Tag = Tag(0x0018, 0x9019);
+/// (0018,9020) Magnetization Transfer
+pub const MAGNETIZATION_TRANSFER: Tag = Tag(0x0018, 0x9020);
+/// (0018,9021) T 2 Preparation
+pub const T2PREPARATION: Tag = Tag(0x0018, 0x9021);
+/// (0018,9022) Blood Signal Nulling
+pub const BLOOD_SIGNAL_NULLING: Tag = Tag(0x0018, 0x9022);
+/// (0018,9024) Saturation Recovery
+pub const SATURATION_RECOVERY: Tag = Tag(0x0018, 0x9024);
+/// (0018,9025) Spectrally Selected Suppression
+pub const SPECTRALLY_SELECTED_SUPPRESSION: Tag = Tag(0x0018, 0x9025);
+/// (0018,9026) Spectrally Selected Excitation
+pub const SPECTRALLY_SELECTED_EXCITATION: Tag = Tag(0x0018, 0x9026);
+/// (0018,9027) Spatial Presaturation
+pub const SPATIAL_PRESATURATION: Tag = Tag(0x0018, 0x9027);
+/// (0018,9028) Tagging
+pub const TAGGING: Tag = Tag(0x0018, 0x9028);
+/// (0018,9029) Oversampling Phase
+pub const OVERSAMPLING_PHASE: Tag = Tag(0x0018, 0x9029);
+/// (0018,9030) Tag Spacing First Dimension
+pub const TAG_SPACING_FIRST_DIMENSION: Tag = Tag(0x0018, 0x9030);
+/// (0018,9032) Geometry of K Space Traversal
+pub const GEOMETRY_OF_K_SPACE_TRAVERSAL: Tag = Tag(0x0018, 0x9032);
+/// (0018,9033) Segmented K Space Traversal
+pub const SEGMENTED_K_SPACE_TRAVERSAL: Tag = Tag(0x0018, 0x9033);
+/// (0018,9034) Rectilinear Phase Encode Reordering
+pub const RECTILINEAR_PHASE_ENCODE_REORDERING: Tag = Tag(0x0018, 0x9034);
+/// (0018,9035) Tag Thickness
+pub const TAG_THICKNESS: Tag = Tag(0x0018, 0x9035);
+/// (0018,9036) Partial Fourier Direction
+pub const PARTIAL_FOURIER_DIRECTION: Tag = Tag(0x0018, 0x9036);
+/// (0018,9037) Cardiac Synchronization Technique
+pub const CARDIAC_SYNCHRONIZATION_TECHNIQUE: Tag = Tag(0x0018, 0x9037);
+/// (0018,9041) Receive Coil Manufacturer Name
+pub const RECEIVE_COIL_MANUFACTURER_NAME: Tag = Tag(0x0018, 0x9041);
+/// (0018,9042) MR Receive Coil Sequence
+pub const MR_RECEIVE_COIL_SEQUENCE: Tag = Tag(0x0018, 0x9042);
+/// (0018,9043) Receive Coil Type
+pub const RECEIVE_COIL_TYPE: Tag = Tag(0x0018, 0x9043);
+/// (0018,9044) Quadrature Receive Coil
+pub const QUADRATURE_RECEIVE_COIL: Tag = Tag(0x0018, 0x9044);
+/// (0018,9045) Multi Coil Definition Sequence
+pub const MULTI_COIL_DEFINITION_SEQUENCE: Tag = Tag(0x0018, 0x9045);
+/// (0018,9046) Multi Coil Configuration
+pub const MULTI_COIL_CONFIGURATION: Tag = Tag(0x0018, 0x9046);
+/// (0018,9047) Multi Coil Element Name
+pub const MULTI_COIL_ELEMENT_NAME: Tag = Tag(0x0018, 0x9047);
+/// (0018,9048) Multi Coil Element Used
+pub const MULTI_COIL_ELEMENT_USED: Tag = Tag(0x0018, 0x9048);
+/// (0018,9049) MR Transmit Coil Sequence
+pub const MR_TRANSMIT_COIL_SEQUENCE: Tag = Tag(0x0018, 0x9049);
+/// (0018,9050) Transmit Coil Manufacturer Name
+pub const TRANSMIT_COIL_MANUFACTURER_NAME: Tag = Tag(0x0018, 0x9050);
+/// (0018,9051) Transmit Coil Type
+pub const TRANSMIT_COIL_TYPE: Tag = Tag(0x0018, 0x9051);
+/// (0018,9052) Spectral Width
+pub const SPECTRAL_WIDTH: Tag = Tag(0x0018, 0x9052);
+/// (0018,9053) Chemical Shift Reference
+pub const CHEMICAL_SHIFT_REFERENCE: Tag = Tag(0x0018, 0x9053);
+/// (0018,9054) Volume Localization Technique
+pub const VOLUME_LOCALIZATION_TECHNIQUE: Tag = Tag(0x0018, 0x9054);
+/// (0018,9058) MR Acquisition Frequency Encoding Steps
+pub const MR_ACQUISITION_FREQUENCY_ENCODING_STEPS: Tag = Tag(0x0018, 0x9058);
+/// (0018,9059) Decoupling
+pub const DECOUPLING: Tag = Tag(0x0018, 0x9059);
+/// (0018,9060) Decoupled Nucleus
+pub const DECOUPLED_NUCLEUS: Tag = Tag(0x0018, 0x9060);
+/// (0018,9061) Decoupling Frequency
+pub const DECOUPLING_FREQUENCY: Tag = Tag(0x0018, 0x9061);
+/// (0018,9062) Decoupling Method
+pub const DECOUPLING_METHOD: Tag = Tag(0x0018, 0x9062);
+/// (0018,9063) Decoupling Chemical Shift Reference
+pub const DECOUPLING_CHEMICAL_SHIFT_REFERENCE: Tag = Tag(0x0018, 0x9063);
+/// (0018,9064) K Space Filtering
+pub const K_SPACE_FILTERING: Tag = Tag(0x0018, 0x9064);
+/// (0018,9065) Time Domain Filtering
+pub const TIME_DOMAIN_FILTERING: Tag = Tag(0x0018, 0x9065);
+/// (0018,9066) Number of Zero Fills
+pub const NUMBER_OF_ZERO_FILLS: Tag = Tag(0x0018, 0x9066);
+/// (0018,9067) Baseline Correction
+pub const BASELINE_CORRECTION: Tag = Tag(0x0018, 0x9067);
+/// (0018,9069) Parallel Reduction Factor in Plane
+pub const PARALLEL_REDUCTION_FACTOR_IN_PLANE: Tag = Tag(0x0018, 0x9069);
+/// (0018,9070) Cardiac RR Interval Specified
+pub const CARDIAC_RR_INTERVAL_SPECIFIED: Tag = Tag(0x0018, 0x9070);
+/// (0018,9073) Acquisition Duration
+pub const ACQUISITION_DURATION: Tag = Tag(0x0018, 0x9073);
+/// (0018,9074) Frame Acquisition Date Time
+pub const FRAME_ACQUISITION_DATE_TIME: Tag = Tag(0x0018, 0x9074);
+/// (0018,9075) Diffusion Directionality
+pub const DIFFUSION_DIRECTIONALITY: Tag = Tag(0x0018, 0x9075);
+/// (0018,9076) Diffusion Gradient Direction Sequence
+pub const DIFFUSION_GRADIENT_DIRECTION_SEQUENCE: Tag = Tag(0x0018, 0x9076);
+/// (0018,9077) Parallel Acquisition
+pub const PARALLEL_ACQUISITION: Tag = Tag(0x0018, 0x9077);
+/// (0018,9078) Parallel Acquisition Technique
+pub const PARALLEL_ACQUISITION_TECHNIQUE: Tag = Tag(0x0018, 0x9078);
+/// (0018,9079) Inversion Times
+pub const INVERSION_TIMES: Tag = Tag(0x0018, 0x9079);
+/// (0018,9080) Metabolite Map Description
+pub const METABOLITE_MAP_DESCRIPTION: Tag = Tag(0x0018, 0x9080);
+/// (0018,9081) Partial Fourier
+pub const PARTIAL_FOURIER: Tag = Tag(0x0018, 0x9081);
+/// (0018,9082) Effective Echo Time
+pub const EFFECTIVE_ECHO_TIME: Tag = Tag(0x0018, 0x9082);
+/// (0018,9083) Metabolite Map Code Sequence
+pub const METABOLITE_MAP_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9083);
+/// (0018,9084) Chemical Shift Sequence
+pub const CHEMICAL_SHIFT_SEQUENCE: Tag = Tag(0x0018, 0x9084);
+/// (0018,9085) Cardiac Signal Source
+pub const CARDIAC_SIGNAL_SOURCE: Tag = Tag(0x0018, 0x9085);
+/// (0018,9087) Diffusion B Value
+pub const DIFFUSION_B_VALUE: Tag = Tag(0x0018, 0x9087);
+/// (0018,9089) Diffusion Gradient Orientation
+pub const DIFFUSION_GRADIENT_ORIENTATION: Tag = Tag(0x0018, 0x9089);
+/// (0018,9090) Velocity Encoding Direction
+pub const VELOCITY_ENCODING_DIRECTION: Tag = Tag(0x0018, 0x9090);
+/// (0018,9091) Velocity Encoding Minimum Value
+pub const VELOCITY_ENCODING_MINIMUM_VALUE: Tag = Tag(0x0018, 0x9091);
+/// (0018,9092) Velocity Encoding Acquisition Sequence
+pub const VELOCITY_ENCODING_ACQUISITION_SEQUENCE: Tag = Tag(0x0018, 0x9092);
+/// (0018,9093) Number of K Space Trajectories
+pub const NUMBER_OF_K_SPACE_TRAJECTORIES: Tag = Tag(0x0018, 0x9093);
+/// (0018,9094) Coverage of K Space
+pub const COVERAGE_OF_K_SPACE: Tag = Tag(0x0018, 0x9094);
+/// (0018,9095) Spectroscopy Acquisition Phase Rows
+pub const SPECTROSCOPY_ACQUISITION_PHASE_ROWS: Tag = Tag(0x0018, 0x9095);
+/// (0018,9096) Parallel Reduction Factor in Plane Retired (retired)
+pub const PARALLEL_REDUCTION_FACTOR_IN_PLANE_RETIRED: Tag = Tag(0x0018, 0x9096);
+/// (0018,9098) Transmitter Frequency
+pub const TRANSMITTER_FREQUENCY: Tag = Tag(0x0018, 0x9098);
+/// (0018,9100) Resonant Nucleus
+pub const RESONANT_NUCLEUS: Tag = Tag(0x0018, 0x9100);
+/// (0018,9101) Frequency Correction
+pub const FREQUENCY_CORRECTION: Tag = Tag(0x0018, 0x9101);
+/// (0018,9103) MR Spectroscopy FOV Geometry Sequence
+pub const MR_SPECTROSCOPY_FOV_GEOMETRY_SEQUENCE: Tag = Tag(0x0018, 0x9103);
+/// (0018,9104) Slab Thickness
+pub const SLAB_THICKNESS: Tag = Tag(0x0018, 0x9104);
+/// (0018,9105) Slab Orientation
+pub const SLAB_ORIENTATION: Tag = Tag(0x0018, 0x9105);
+/// (0018,9106) Mid Slab Position
+pub const MID_SLAB_POSITION: Tag = Tag(0x0018, 0x9106);
+/// (0018,9107) MR Spatial Saturation Sequence
+pub const MR_SPATIAL_SATURATION_SEQUENCE: Tag = Tag(0x0018, 0x9107);
+/// (0018,9112) MR Timing and Related Parameters Sequence
+pub const MR_TIMING_AND_RELATED_PARAMETERS_SEQUENCE: Tag = Tag(0x0018, 0x9112);
+/// (0018,9114) MR Echo Sequence
+pub const MR_ECHO_SEQUENCE: Tag = Tag(0x0018, 0x9114);
+/// (0018,9115) MR Modifier Sequence
+pub const MR_MODIFIER_SEQUENCE: Tag = Tag(0x0018, 0x9115);
+/// (0018,9117) MR Diffusion Sequence
+pub const MR_DIFFUSION_SEQUENCE: Tag = Tag(0x0018, 0x9117);
+/// (0018,9118) Cardiac Synchronization Sequence
+pub const CARDIAC_SYNCHRONIZATION_SEQUENCE: Tag = Tag(0x0018, 0x9118);
+/// (0018,9119) MR Averages Sequence
+pub const MR_AVERAGES_SEQUENCE: Tag = Tag(0x0018, 0x9119);
+/// (0018,9125) MRFOV Geometry Sequence
+pub const MRFOV_GEOMETRY_SEQUENCE: Tag = Tag(0x0018, 0x9125);
+/// (0018,9126) Volume Localization Sequence
+pub const VOLUME_LOCALIZATION_SEQUENCE: Tag = Tag(0x0018, 0x9126);
+/// (0018,9127) Spectroscopy Acquisition Data Columns
+pub const SPECTROSCOPY_ACQUISITION_DATA_COLUMNS: Tag = Tag(0x0018, 0x9127);
+/// (0018,9147) Diffusion Anisotropy Type
+pub const DIFFUSION_ANISOTROPY_TYPE: Tag = Tag(0x0018, 0x9147);
+/// (0018,9151) Frame Reference Date Time
+pub const FRAME_REFERENCE_DATE_TIME: Tag = Tag(0x0018, 0x9151);
+/// (0018,9152) MR Metabolite Map Sequence
+pub const MR_METABOLITE_MAP_SEQUENCE: Tag = Tag(0x0018, 0x9152);
+/// (0018,9155) Parallel Reduction Factor Out of Plane
+pub const PARALLEL_REDUCTION_FACTOR_OUT_OF_PLANE: Tag = Tag(0x0018, 0x9155);
+/// (0018,9159) Spectroscopy Acquisition Out of Plane Phase Steps
+pub const SPECTROSCOPY_ACQUISITION_OUT_OF_PLANE_PHASE_STEPS: Tag = Tag(0x0018, 0x9159);
+/// (0018,9166) Bulk Motion Status (retired)
+pub const BULK_MOTION_STATUS: Tag = Tag(0x0018, 0x9166);
+/// (0018,9168) Parallel Reduction Factor Second in Plane
+pub const PARALLEL_REDUCTION_FACTOR_SECOND_IN_PLANE: Tag = Tag(0x0018, 0x9168);
+/// (0018,9169) Cardiac Beat Rejection Technique
+pub const CARDIAC_BEAT_REJECTION_TECHNIQUE: Tag = Tag(0x0018, 0x9169);
+/// (0018,9170) Respiratory Motion Compensation Technique
+pub const RESPIRATORY_MOTION_COMPENSATION_TECHNIQUE: Tag = Tag(0x0018, 0x9170);
+/// (0018,9171) Respiratory Signal Source
+pub const RESPIRATORY_SIGNAL_SOURCE: Tag = Tag(0x0018, 0x9171);
+/// (0018,9172) Bulk Motion Compensation Technique
+pub const BULK_MOTION_COMPENSATION_TECHNIQUE: Tag = Tag(0x0018, 0x9172);
+/// (0018,9173) Bulk Motion Signal Source
+pub const BULK_MOTION_SIGNAL_SOURCE: Tag = Tag(0x0018, 0x9173);
+/// (0018,9174) Applicable Safety Standard Agency
+pub const APPLICABLE_SAFETY_STANDARD_AGENCY: Tag = Tag(0x0018, 0x9174);
+/// (0018,9175) Applicable Safety Standard Description
+pub const APPLICABLE_SAFETY_STANDARD_DESCRIPTION: Tag = Tag(0x0018, 0x9175);
+/// (0018,9176) Operating Mode Sequence
+pub const OPERATING_MODE_SEQUENCE: Tag = Tag(0x0018, 0x9176);
+/// (0018,9177) Operating Mode Type
+pub const OPERATING_MODE_TYPE: Tag = Tag(0x0018, 0x9177);
+/// (0018,9178) Operating Mode
+pub const OPERATING_MODE: Tag = Tag(0x0018, 0x9178);
+/// (0018,9179) Specific Absorption Rate Definition
+pub const SPECIFIC_ABSORPTION_RATE_DEFINITION: Tag = Tag(0x0018, 0x9179);
+/// (0018,9180) Gradient Output Type
+pub const GRADIENT_OUTPUT_TYPE: Tag = Tag(0x0018, 0x9180);
+/// (0018,9181) Specific Absorption Rate Value
+pub const SPECIFIC_ABSORPTION_RATE_VALUE: Tag = Tag(0x0018, 0x9181);
+/// (0018,9182) Gradient Output
+pub const GRADIENT_OUTPUT: Tag = Tag(0x0018, 0x9182);
+/// (0018,9183) Flow Compensation Direction
+pub const FLOW_COMPENSATION_DIRECTION: Tag = Tag(0x0018, 0x9183);
+/// (0018,9184) Tagging Delay
+pub const TAGGING_DELAY: Tag = Tag(0x0018, 0x9184);
+/// (0018,9185) Respiratory Motion Compensation Technique Description
+pub const RESPIRATORY_MOTION_COMPENSATION_TECHNIQUE_DESCRIPTION: Tag = Tag(0x0018, 0x9185);
+/// (0018,9186) Respiratory Signal Source ID
+pub const RESPIRATORY_SIGNAL_SOURCE_ID: Tag = Tag(0x0018, 0x9186);
+/// (0018,9195) Chemical Shift Minimum Integration Limit in Hz (retired)
+pub const CHEMICAL_SHIFT_MINIMUM_INTEGRATION_LIMIT_IN_HZ: Tag = Tag(0x0018, 0x9195);
+/// (0018,9196) Chemical Shift Maximum Integration Limit in Hz (retired)
+pub const CHEMICAL_SHIFT_MAXIMUM_INTEGRATION_LIMIT_IN_HZ: Tag = Tag(0x0018, 0x9196);
+/// (0018,9197) MR Velocity Encoding Sequence
+pub const MR_VELOCITY_ENCODING_SEQUENCE: Tag = Tag(0x0018, 0x9197);
+/// (0018,9198) First Order Phase Correction
+pub const FIRST_ORDER_PHASE_CORRECTION: Tag = Tag(0x0018, 0x9198);
+/// (0018,9199) Water Referenced Phase Correction
+pub const WATER_REFERENCED_PHASE_CORRECTION: Tag = Tag(0x0018, 0x9199);
+/// (0018,9200) MR Spectroscopy Acquisition Type
+pub const MR_SPECTROSCOPY_ACQUISITION_TYPE: Tag = Tag(0x0018, 0x9200);
+/// (0018,9214) Respiratory Cycle Position
+pub const RESPIRATORY_CYCLE_POSITION: Tag = Tag(0x0018, 0x9214);
+/// (0018,9217) Velocity Encoding Maximum Value
+pub const VELOCITY_ENCODING_MAXIMUM_VALUE: Tag = Tag(0x0018, 0x9217);
+/// (0018,9218) Tag Spacing Second Dimension
+pub const TAG_SPACING_SECOND_DIMENSION: Tag = Tag(0x0018, 0x9218);
+/// (0018,9219) Tag Angle Second Axis
+pub const TAG_ANGLE_SECOND_AXIS: Tag = Tag(0x0018, 0x9219);
+/// (0018,9220) Frame Acquisition Duration
+pub const FRAME_ACQUISITION_DURATION: Tag = Tag(0x0018, 0x9220);
+/// (0018,9226) MR Image Frame Type Sequence
+pub const MR_IMAGE_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0018, 0x9226);
+/// (0018,9227) MR Spectroscopy Frame Type Sequence
+pub const MR_SPECTROSCOPY_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0018, 0x9227);
+/// (0018,9231) MR Acquisition Phase Encoding Steps in Plane
+pub const MR_ACQUISITION_PHASE_ENCODING_STEPS_IN_PLANE: Tag = Tag(0x0018, 0x9231);
+/// (0018,9232) MR Acquisition Phase Encoding Steps Out of Plane
+pub const MR_ACQUISITION_PHASE_ENCODING_STEPS_OUT_OF_PLANE: Tag = Tag(0x0018, 0x9232);
+/// (0018,9234) Spectroscopy Acquisition Phase Columns
+pub const SPECTROSCOPY_ACQUISITION_PHASE_COLUMNS: Tag = Tag(0x0018, 0x9234);
+/// (0018,9236) Cardiac Cycle Position
+pub const CARDIAC_CYCLE_POSITION: Tag = Tag(0x0018, 0x9236);
+/// (0018,9239) Specific Absorption Rate Sequence
+pub const SPECIFIC_ABSORPTION_RATE_SEQUENCE: Tag = Tag(0x0018, 0x9239);
+/// (0018,9240) RF Echo Train Length
+pub const RF_ECHO_TRAIN_LENGTH: Tag = Tag(0x0018, 0x9240);
+/// (0018,9241) Gradient Echo Train Length
+pub const GRADIENT_ECHO_TRAIN_LENGTH: Tag = Tag(0x0018, 0x9241);
+/// (0018,9250) Arterial Spin Labeling Contrast
+pub const ARTERIAL_SPIN_LABELING_CONTRAST: Tag = Tag(0x0018, 0x9250);
+/// (0018,9251) MR Arterial Spin Labeling Sequence
+pub const MR_ARTERIAL_SPIN_LABELING_SEQUENCE: Tag = Tag(0x0018, 0x9251);
+/// (0018,9252) ASL Technique Description
+pub const ASL_TECHNIQUE_DESCRIPTION: Tag = Tag(0x0018, 0x9252);
+/// (0018,9253) ASL Slab Number
+pub const ASL_SLAB_NUMBER: Tag = Tag(0x0018, 0x9253);
+/// (0018,9254) ASL Slab Thickness
+pub const ASL_SLAB_THICKNESS: Tag = Tag(0x0018, 0x9254);
+/// (0018,9255) ASL Slab Orientation
+pub const ASL_SLAB_ORIENTATION: Tag = Tag(0x0018, 0x9255);
+/// (0018,9256) ASL Mid Slab Position
+pub const ASL_MID_SLAB_POSITION: Tag = Tag(0x0018, 0x9256);
+/// (0018,9257) ASL Context
+pub const ASL_CONTEXT: Tag = Tag(0x0018, 0x9257);
+/// (0018,9258) ASL Pulse Train Duration
+pub const ASL_PULSE_TRAIN_DURATION: Tag = Tag(0x0018, 0x9258);
+/// (0018,9259) ASL Crusher Flag
+pub const ASL_CRUSHER_FLAG: Tag = Tag(0x0018, 0x9259);
+/// (0018,925A) ASL Crusher Flow Limit
+pub const ASL_CRUSHER_FLOW_LIMIT: Tag = Tag(0x0018, 0x925A);
+/// (0018,925B) ASL Crusher Description
+pub const ASL_CRUSHER_DESCRIPTION: Tag = Tag(0x0018, 0x925B);
+/// (0018,925C) ASL Bolus Cutoff Flag
+pub const ASL_BOLUS_CUTOFF_FLAG: Tag = Tag(0x0018, 0x925C);
+/// (0018,925D) ASL Bolus Cutoff Timing Sequence
+pub const ASL_BOLUS_CUTOFF_TIMING_SEQUENCE: Tag = Tag(0x0018, 0x925D);
+/// (0018,925E) ASL Bolus Cutoff Technique
+pub const ASL_BOLUS_CUTOFF_TECHNIQUE: Tag = Tag(0x0018, 0x925E);
+/// (0018,925F) ASL Bolus Cutoff Delay Time
+pub const ASL_BOLUS_CUTOFF_DELAY_TIME: Tag = Tag(0x0018, 0x925F);
+/// (0018,9260) ASL Slab Sequence
+pub const ASL_SLAB_SEQUENCE: Tag = Tag(0x0018, 0x9260);
+/// (0018,9295) Chemical Shift Minimum Integration Limit Inppm
+pub const CHEMICAL_SHIFT_MINIMUM_INTEGRATION_LIMIT_INPPM: Tag = Tag(0x0018, 0x9295);
+/// (0018,9296) Chemical Shift Maximum Integration Limit Inppm
+pub const CHEMICAL_SHIFT_MAXIMUM_INTEGRATION_LIMIT_INPPM: Tag = Tag(0x0018, 0x9296);
+/// (0018,9297) Water Reference Acquisition
+pub const WATER_REFERENCE_ACQUISITION: Tag = Tag(0x0018, 0x9297);
+/// (0018,9298) Echo Peak Position
+pub const ECHO_PEAK_POSITION: Tag = Tag(0x0018, 0x9298);
+/// (0018,9301) CT Acquisition Type Sequence
+pub const CT_ACQUISITION_TYPE_SEQUENCE: Tag = Tag(0x0018, 0x9301);
+/// (0018,9302) Acquisition Type
+pub const ACQUISITION_TYPE: Tag = Tag(0x0018, 0x9302);
+/// (0018,9303) Tube Angle
+pub const TUBE_ANGLE: Tag = Tag(0x0018, 0x9303);
+/// (0018,9304) CT Acquisition Details Sequence
+pub const CT_ACQUISITION_DETAILS_SEQUENCE: Tag = Tag(0x0018, 0x9304);
+/// (0018,9305) Revolution Time
+pub const REVOLUTION_TIME: Tag = Tag(0x0018, 0x9305);
+/// (0018,9306) Single Collimation Width
+pub const SINGLE_COLLIMATION_WIDTH: Tag = Tag(0x0018, 0x9306);
+/// (0018,9307) Total Collimation Width
+pub const TOTAL_COLLIMATION_WIDTH: Tag = Tag(0x0018, 0x9307);
+/// (0018,9308) CT Table Dynamics Sequence
+pub const CT_TABLE_DYNAMICS_SEQUENCE: Tag = Tag(0x0018, 0x9308);
+/// (0018,9309) Table Speed
+pub const TABLE_SPEED: Tag = Tag(0x0018, 0x9309);
+/// (0018,9310) Table Feed per Rotation
+pub const TABLE_FEED_PER_ROTATION: Tag = Tag(0x0018, 0x9310);
+/// (0018,9311) Spiral Pitch Factor
+pub const SPIRAL_PITCH_FACTOR: Tag = Tag(0x0018, 0x9311);
+/// (0018,9312) CT Geometry Sequence
+pub const CT_GEOMETRY_SEQUENCE: Tag = Tag(0x0018, 0x9312);
+/// (0018,9313) Data Collection Center Patient
+pub const DATA_COLLECTION_CENTER_PATIENT: Tag = Tag(0x0018, 0x9313);
+/// (0018,9314) CT Reconstruction Sequence
+pub const CT_RECONSTRUCTION_SEQUENCE: Tag = Tag(0x0018, 0x9314);
+/// (0018,9315) Reconstruction Algorithm
+pub const RECONSTRUCTION_ALGORITHM: Tag = Tag(0x0018, 0x9315);
+/// (0018,9316) Convolution Kernel Group
+pub const CONVOLUTION_KERNEL_GROUP: Tag = Tag(0x0018, 0x9316);
+/// (0018,9317) Reconstruction Field of View
+pub const RECONSTRUCTION_FIELD_OF_VIEW: Tag = Tag(0x0018, 0x9317);
+/// (0018,9318) Reconstruction Target Center Patient
+pub const RECONSTRUCTION_TARGET_CENTER_PATIENT: Tag = Tag(0x0018, 0x9318);
+/// (0018,9319) Reconstruction Angle
+pub const RECONSTRUCTION_ANGLE: Tag = Tag(0x0018, 0x9319);
+/// (0018,9320) Image Filter
+pub const IMAGE_FILTER: Tag = Tag(0x0018, 0x9320);
+/// (0018,9321) CT Exposure Sequence
+pub const CT_EXPOSURE_SEQUENCE: Tag = Tag(0x0018, 0x9321);
+/// (0018,9322) Reconstruction Pixel Spacing
+pub const RECONSTRUCTION_PIXEL_SPACING: Tag = Tag(0x0018, 0x9322);
+/// (0018,9323) Exposure Modulation Type
+pub const EXPOSURE_MODULATION_TYPE: Tag = Tag(0x0018, 0x9323);
+/// (0018,9324) Estimated Dose Saving
+pub const ESTIMATED_DOSE_SAVING: Tag = Tag(0x0018, 0x9324);
+/// (0018,9325) CTX-Ray Details Sequence
+pub const CTX_RAY_DETAILS_SEQUENCE: Tag = Tag(0x0018, 0x9325);
+/// (0018,9326) CT Position Sequence
+pub const CT_POSITION_SEQUENCE: Tag = Tag(0x0018, 0x9326);
+/// (0018,9327) Table Position
+pub const TABLE_POSITION: Tag = Tag(0x0018, 0x9327);
+/// (0018,9328) Exposure Time Inms
+pub const EXPOSURE_TIME_INMS: Tag = Tag(0x0018, 0x9328);
+/// (0018,9329) CT Image Frame Type Sequence
+pub const CT_IMAGE_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0018, 0x9329);
+/// (0018,9330) X-Ray Tube Current in mA
+pub const X_RAY_TUBE_CURRENT_INM_A: Tag = Tag(0x0018, 0x9330);
+/// (0018,9332) Exposure in mAs
+pub const EXPOSURE_INM_AS: Tag = Tag(0x0018, 0x9332);
+/// (0018,9333) Constant Volume Flag
+pub const CONSTANT_VOLUME_FLAG: Tag = Tag(0x0018, 0x9333);
+/// (0018,9334) Fluoroscopy Flag
+pub const FLUOROSCOPY_FLAG: Tag = Tag(0x0018, 0x9334);
+/// (0018,9335) Distance Source to Data Collection Center
+pub const DISTANCE_SOURCE_TO_DATA_COLLECTION_CENTER: Tag = Tag(0x0018, 0x9335);
+/// (0018,9337) Contrast Bolus Agent Number
+pub const CONTRAST_BOLUS_AGENT_NUMBER: Tag = Tag(0x0018, 0x9337);
+/// (0018,9338) Contrast Bolus Ingredient Code Sequence
+pub const CONTRAST_BOLUS_INGREDIENT_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9338);
+/// (0018,9340) Contrast Administration Profile Sequence
+pub const CONTRAST_ADMINISTRATION_PROFILE_SEQUENCE: Tag = Tag(0x0018, 0x9340);
+/// (0018,9341) Contrast Bolus Usage Sequence
+pub const CONTRAST_BOLUS_USAGE_SEQUENCE: Tag = Tag(0x0018, 0x9341);
+/// (0018,9342) Contrast Bolus Agent Administered
+pub const CONTRAST_BOLUS_AGENT_ADMINISTERED: Tag = Tag(0x0018, 0x9342);
+/// (0018,9343) Contrast Bolus Agent Detected
+pub const CONTRAST_BOLUS_AGENT_DETECTED: Tag = Tag(0x0018, 0x9343);
+/// (0018,9344) Contrast Bolus Agent Phase
+pub const CONTRAST_BOLUS_AGENT_PHASE: Tag = Tag(0x0018, 0x9344);
+/// (0018,9345) CTDIvol
+pub const CTDIVOL: Tag = Tag(0x0018, 0x9345);
+/// (0018,9346) CTDI Phantom Type Code Sequence
+pub const CTDI_PHANTOM_TYPE_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9346);
+/// (0018,9351) Calcium Scoring Mass Factor Patient
+pub const CALCIUM_SCORING_MASS_FACTOR_PATIENT: Tag = Tag(0x0018, 0x9351);
+/// (0018,9352) Calcium Scoring Mass Factor Device
+pub const CALCIUM_SCORING_MASS_FACTOR_DEVICE: Tag = Tag(0x0018, 0x9352);
+/// (0018,9353) Energy Weighting Factor
+pub const ENERGY_WEIGHTING_FACTOR: Tag = Tag(0x0018, 0x9353);
+/// (0018,9360) CT Additional X-Ray Source Sequence
+pub const CT_ADDITIONAL_X_RAY_SOURCE_SEQUENCE: Tag = Tag(0x0018, 0x9360);
+/// (0018,9361) Multienergy CT Acquisition
+pub const MULTIENERGY_CT_ACQUISITION: Tag = Tag(0x0018, 0x9361);
+/// (0018,9362) Multienergy CT Acquisition Sequence
+pub const MULTIENERGY_CT_ACQUISITION_SEQUENCE: Tag = Tag(0x0018, 0x9362);
+/// (0018,9363) Multienergy CT Processing Sequence
+pub const MULTIENERGY_CT_PROCESSING_SEQUENCE: Tag = Tag(0x0018, 0x9363);
+/// (0018,9364) Multienergy CT Characteristics Sequence
+pub const MULTIENERGY_CT_CHARACTERISTICS_SEQUENCE: Tag = Tag(0x0018, 0x9364);
+/// (0018,9365) Multienergy CTX-Ray Source Sequence
+pub const MULTIENERGY_CTX_RAY_SOURCE_SEQUENCE: Tag = Tag(0x0018, 0x9365);
+/// (0018,9366) X-Ray Source Index
+pub const X_RAY_SOURCE_INDEX: Tag = Tag(0x0018, 0x9366);
+/// (0018,9367) X-Ray Source ID
+pub const X_RAY_SOURCE_ID: Tag = Tag(0x0018, 0x9367);
+/// (0018,9368) Multienergy Source Technique
+pub const MULTIENERGY_SOURCE_TECHNIQUE: Tag = Tag(0x0018, 0x9368);
+/// (0018,9369) Source Start Date Time
+pub const SOURCE_START_DATE_TIME: Tag = Tag(0x0018, 0x9369);
+/// (0018,936A) Source End Date Time
+pub const SOURCE_END_DATE_TIME: Tag = Tag(0x0018, 0x936A);
+/// (0018,936B) Switching Phase Number
+pub const SWITCHING_PHASE_NUMBER: Tag = Tag(0x0018, 0x936B);
+/// (0018,936C) Switching Phase Nominal Duration
+pub const SWITCHING_PHASE_NOMINAL_DURATION: Tag = Tag(0x0018, 0x936C);
+/// (0018,936D) Switching Phase Transition Duration
+pub const SWITCHING_PHASE_TRANSITION_DURATION: Tag = Tag(0x0018, 0x936D);
+/// (0018,936E) Effective Bin Energy
+pub const EFFECTIVE_BIN_ENERGY: Tag = Tag(0x0018, 0x936E);
+/// (0018,936F) Multienergy CTX-Ray Detector Sequence
+pub const MULTIENERGY_CTX_RAY_DETECTOR_SEQUENCE: Tag = Tag(0x0018, 0x936F);
+/// (0018,9370) X-Ray Detector Index
+pub const X_RAY_DETECTOR_INDEX: Tag = Tag(0x0018, 0x9370);
+/// (0018,9371) X-Ray Detector ID
+pub const X_RAY_DETECTOR_ID: Tag = Tag(0x0018, 0x9371);
+/// (0018,9372) Multienergy Detector Type
+pub const MULTIENERGY_DETECTOR_TYPE: Tag = Tag(0x0018, 0x9372);
+/// (0018,9373) X-Ray Detector Label
+pub const X_RAY_DETECTOR_LABEL: Tag = Tag(0x0018, 0x9373);
+/// (0018,9374) Nominal Max Energy
+pub const NOMINAL_MAX_ENERGY: Tag = Tag(0x0018, 0x9374);
+/// (0018,9375) Nominal Min Energy
+pub const NOMINAL_MIN_ENERGY: Tag = Tag(0x0018, 0x9375);
+/// (0018,9376) Referenced X-Ray Detector Index
+pub const REFERENCED_X_RAY_DETECTOR_INDEX: Tag = Tag(0x0018, 0x9376);
+/// (0018,9377) Referenced X-Ray Source Index
+pub const REFERENCED_X_RAY_SOURCE_INDEX: Tag = Tag(0x0018, 0x9377);
+/// (0018,9378) Referenced Path Index
+pub const REFERENCED_PATH_INDEX: Tag = Tag(0x0018, 0x9378);
+/// (0018,9379) Multienergy CT Path Sequence
+pub const MULTIENERGY_CT_PATH_SEQUENCE: Tag = Tag(0x0018, 0x9379);
+/// (0018,937A) Multienergy CT Path Index
+pub const MULTIENERGY_CT_PATH_INDEX: Tag = Tag(0x0018, 0x937A);
+/// (0018,937B) Multienergy Acquisition Description
+pub const MULTIENERGY_ACQUISITION_DESCRIPTION: Tag = Tag(0x0018, 0x937B);
+/// (0018,937C) Monoenergetic Energy Equivalent
+pub const MONOENERGETIC_ENERGY_EQUIVALENT: Tag = Tag(0x0018, 0x937C);
+/// (0018,937D) Material Code Sequence
+pub const MATERIAL_CODE_SEQUENCE: Tag = Tag(0x0018, 0x937D);
+/// (0018,937E) Decomposition Method
+pub const DECOMPOSITION_METHOD: Tag = Tag(0x0018, 0x937E);
+/// (0018,937F) Decomposition Description
+pub const DECOMPOSITION_DESCRIPTION: Tag = Tag(0x0018, 0x937F);
+/// (0018,9380) Decomposition Algorithm Identification Sequence
+pub const DECOMPOSITION_ALGORITHM_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x9380);
+/// (0018,9381) Decomposition Material Sequence
+pub const DECOMPOSITION_MATERIAL_SEQUENCE: Tag = Tag(0x0018, 0x9381);
+/// (0018,9382) Material Attenuation Sequence
+pub const MATERIAL_ATTENUATION_SEQUENCE: Tag = Tag(0x0018, 0x9382);
+/// (0018,9383) Photon Energy
+pub const PHOTON_ENERGY: Tag = Tag(0x0018, 0x9383);
+/// (0018,9384) X-Ray Mass Attenuation Coefficient
+pub const X_RAY_MASS_ATTENUATION_COEFFICIENT: Tag = Tag(0x0018, 0x9384);
+/// (0018,9401) Projection Pixel Calibration Sequence
+pub const PROJECTION_PIXEL_CALIBRATION_SEQUENCE: Tag = Tag(0x0018, 0x9401);
+/// (0018,9402) Distance Source to Isocenter
+pub const DISTANCE_SOURCE_TO_ISOCENTER: Tag = Tag(0x0018, 0x9402);
+/// (0018,9403) Distance Object to Table Top
+pub const DISTANCE_OBJECT_TO_TABLE_TOP: Tag = Tag(0x0018, 0x9403);
+/// (0018,9404) Object Pixel Spacing in Center of Beam
+pub const OBJECT_PIXEL_SPACING_IN_CENTER_OF_BEAM: Tag = Tag(0x0018, 0x9404);
+/// (0018,9405) Positioner Position Sequence
+pub const POSITIONER_POSITION_SEQUENCE: Tag = Tag(0x0018, 0x9405);
+/// (0018,9406) Table Position Sequence
+pub const TABLE_POSITION_SEQUENCE: Tag = Tag(0x0018, 0x9406);
+/// (0018,9407) Collimator Shape Sequence
+pub const COLLIMATOR_SHAPE_SEQUENCE: Tag = Tag(0x0018, 0x9407);
+/// (0018,9410) Planes in Acquisition
+pub const PLANES_IN_ACQUISITION: Tag = Tag(0x0018, 0x9410);
+/// (0018,9412) XAXRF Frame Characteristics Sequence
+pub const XAXRF_FRAME_CHARACTERISTICS_SEQUENCE: Tag = Tag(0x0018, 0x9412);
+/// (0018,9417) Frame Acquisition Sequence
+pub const FRAME_ACQUISITION_SEQUENCE: Tag = Tag(0x0018, 0x9417);
+/// (0018,9420) X-Ray Receptor Type
+pub const X_RAY_RECEPTOR_TYPE: Tag = Tag(0x0018, 0x9420);
+/// (0018,9423) Acquisition Protocol Name
+pub const ACQUISITION_PROTOCOL_NAME: Tag = Tag(0x0018, 0x9423);
+/// (0018,9424) Acquisition Protocol Description
+pub const ACQUISITION_PROTOCOL_DESCRIPTION: Tag = Tag(0x0018, 0x9424);
+/// (0018,9425) Contrast Bolus Ingredient Opaque
+pub const CONTRAST_BOLUS_INGREDIENT_OPAQUE: Tag = Tag(0x0018, 0x9425);
+/// (0018,9426) Distance Receptor Plane to Detector Housing
+pub const DISTANCE_RECEPTOR_PLANE_TO_DETECTOR_HOUSING: Tag = Tag(0x0018, 0x9426);
+/// (0018,9427) Intensifier Active Shape
+pub const INTENSIFIER_ACTIVE_SHAPE: Tag = Tag(0x0018, 0x9427);
+/// (0018,9428) Intensifier Active Dimensions
+pub const INTENSIFIER_ACTIVE_DIMENSIONS: Tag = Tag(0x0018, 0x9428);
+/// (0018,9429) Physical Detector Size
+pub const PHYSICAL_DETECTOR_SIZE: Tag = Tag(0x0018, 0x9429);
+/// (0018,9430) Position of Isocenter Projection
+pub const POSITION_OF_ISOCENTER_PROJECTION: Tag = Tag(0x0018, 0x9430);
+/// (0018,9432) Field of View Sequence
+pub const FIELD_OF_VIEW_SEQUENCE: Tag = Tag(0x0018, 0x9432);
+/// (0018,9433) Field of View Description
+pub const FIELD_OF_VIEW_DESCRIPTION: Tag = Tag(0x0018, 0x9433);
+/// (0018,9434) Exposure Control Sensing Regions Sequence
+pub const EXPOSURE_CONTROL_SENSING_REGIONS_SEQUENCE: Tag = Tag(0x0018, 0x9434);
+/// (0018,9435) Exposure Control Sensing Region Shape
+pub const EXPOSURE_CONTROL_SENSING_REGION_SHAPE: Tag = Tag(0x0018, 0x9435);
+/// (0018,9436) Exposure Control Sensing Region Left Vertical Edge
+pub const EXPOSURE_CONTROL_SENSING_REGION_LEFT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x9436);
+/// (0018,9437) Exposure Control Sensing Region Right Vertical Edge
+pub const EXPOSURE_CONTROL_SENSING_REGION_RIGHT_VERTICAL_EDGE: Tag = Tag(0x0018, 0x9437);
+/// (0018,9438) Exposure Control Sensing Region Upper Horizontal Edge
+pub const EXPOSURE_CONTROL_SENSING_REGION_UPPER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x9438);
+/// (0018,9439) Exposure Control Sensing Region Lower Horizontal Edge
+pub const EXPOSURE_CONTROL_SENSING_REGION_LOWER_HORIZONTAL_EDGE: Tag = Tag(0x0018, 0x9439);
+/// (0018,9440) Center of Circular Exposure Control Sensing Region
+pub const CENTER_OF_CIRCULAR_EXPOSURE_CONTROL_SENSING_REGION: Tag = Tag(0x0018, 0x9440);
+/// (0018,9441) Radius of Circular Exposure Control Sensing Region
+pub const RADIUS_OF_CIRCULAR_EXPOSURE_CONTROL_SENSING_REGION: Tag = Tag(0x0018, 0x9441);
+/// (0018,9442) Vertices of the Polygonal Exposure Control Sensing Region
+pub const VERTICES_OF_THE_POLYGONAL_EXPOSURE_CONTROL_SENSING_REGION: Tag = Tag(0x0018, 0x9442);
+/// (0018,9447) Column Angulation Patient
+pub const COLUMN_ANGULATION_PATIENT: Tag = Tag(0x0018, 0x9447);
+/// (0018,9449) Beam Angle
+pub const BEAM_ANGLE: Tag = Tag(0x0018, 0x9449);
+/// (0018,9451) Frame Detector Parameters Sequence
+pub const FRAME_DETECTOR_PARAMETERS_SEQUENCE: Tag = Tag(0x0018, 0x9451);
+/// (0018,9452) Calculated Anatomy Thickness
+pub const CALCULATED_ANATOMY_THICKNESS: Tag = Tag(0x0018, 0x9452);
+/// (0018,9455) Calibration Sequence
+pub const CALIBRATION_SEQUENCE: Tag = Tag(0x0018, 0x9455);
+/// (0018,9456) Object Thickness Sequence
+pub const OBJECT_THICKNESS_SEQUENCE: Tag = Tag(0x0018, 0x9456);
+/// (0018,9457) Plane Identification
+pub const PLANE_IDENTIFICATION: Tag = Tag(0x0018, 0x9457);
+/// (0018,9461) Field of View Dimensions in Float
+pub const FIELD_OF_VIEW_DIMENSIONS_IN_FLOAT: Tag = Tag(0x0018, 0x9461);
+/// (0018,9462) Isocenter Reference System Sequence
+pub const ISOCENTER_REFERENCE_SYSTEM_SEQUENCE: Tag = Tag(0x0018, 0x9462);
+/// (0018,9463) Positioner Isocenter Primary Angle
+pub const POSITIONER_ISOCENTER_PRIMARY_ANGLE: Tag = Tag(0x0018, 0x9463);
+/// (0018,9464) Positioner Isocenter Secondary Angle
+pub const POSITIONER_ISOCENTER_SECONDARY_ANGLE: Tag = Tag(0x0018, 0x9464);
+/// (0018,9465) Positioner Isocenter Detector Rotation Angle
+pub const POSITIONER_ISOCENTER_DETECTOR_ROTATION_ANGLE: Tag = Tag(0x0018, 0x9465);
+/// (0018,9466) Table X Position to Isocenter
+pub const TABLE_X_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9466);
+/// (0018,9467) Table Y Position to Isocenter
+pub const TABLE_Y_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9467);
+/// (0018,9468) Table Z Position to Isocenter
+pub const TABLE_Z_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9468);
+/// (0018,9469) Table Horizontal Rotation Angle
+pub const TABLE_HORIZONTAL_ROTATION_ANGLE: Tag = Tag(0x0018, 0x9469);
+/// (0018,9470) Table Head Tilt Angle
+pub const TABLE_HEAD_TILT_ANGLE: Tag = Tag(0x0018, 0x9470);
+/// (0018,9471) Table Cradle Tilt Angle
+pub const TABLE_CRADLE_TILT_ANGLE: Tag = Tag(0x0018, 0x9471);
+/// (0018,9472) Frame Display Shutter Sequence
+pub const FRAME_DISPLAY_SHUTTER_SEQUENCE: Tag = Tag(0x0018, 0x9472);
+/// (0018,9473) Acquired Image Area Dose Product
+pub const ACQUIRED_IMAGE_AREA_DOSE_PRODUCT: Tag = Tag(0x0018, 0x9473);
+/// (0018,9474) C Arm Positioner Tabletop Relationship
+pub const C_ARM_POSITIONER_TABLETOP_RELATIONSHIP: Tag = Tag(0x0018, 0x9474);
+/// (0018,9476) X-Ray Geometry Sequence
+pub const X_RAY_GEOMETRY_SEQUENCE: Tag = Tag(0x0018, 0x9476);
+/// (0018,9477) Irradiation Event Identification Sequence
+pub const IRRADIATION_EVENT_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x9477);
+/// (0018,9504) X-Ray 3D Frame Type Sequence
+pub const X_RAY3D_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0018, 0x9504);
+/// (0018,9506) Contributing Sources Sequence
+pub const CONTRIBUTING_SOURCES_SEQUENCE: Tag = Tag(0x0018, 0x9506);
+/// (0018,9507) X-Ray 3D Acquisition Sequence
+pub const X_RAY3D_ACQUISITION_SEQUENCE: Tag = Tag(0x0018, 0x9507);
+/// (0018,9508) Primary Positioner Scan Arc
+pub const PRIMARY_POSITIONER_SCAN_ARC: Tag = Tag(0x0018, 0x9508);
+/// (0018,9509) Secondary Positioner Scan Arc
+pub const SECONDARY_POSITIONER_SCAN_ARC: Tag = Tag(0x0018, 0x9509);
+/// (0018,9510) Primary Positioner Scan Start Angle
+pub const PRIMARY_POSITIONER_SCAN_START_ANGLE: Tag = Tag(0x0018, 0x9510);
+/// (0018,9511) Secondary Positioner Scan Start Angle
+pub const SECONDARY_POSITIONER_SCAN_START_ANGLE: Tag = Tag(0x0018, 0x9511);
+/// (0018,9514) Primary Positioner Increment
+pub const PRIMARY_POSITIONER_INCREMENT: Tag = Tag(0x0018, 0x9514);
+/// (0018,9515) Secondary Positioner Increment
+pub const SECONDARY_POSITIONER_INCREMENT: Tag = Tag(0x0018, 0x9515);
+/// (0018,9516) Start Acquisition Date Time
+pub const START_ACQUISITION_DATE_TIME: Tag = Tag(0x0018, 0x9516);
+/// (0018,9517) End Acquisition Date Time
+pub const END_ACQUISITION_DATE_TIME: Tag = Tag(0x0018, 0x9517);
+/// (0018,9518) Primary Positioner Increment Sign
+pub const PRIMARY_POSITIONER_INCREMENT_SIGN: Tag = Tag(0x0018, 0x9518);
+/// (0018,9519) Secondary Positioner Increment Sign
+pub const SECONDARY_POSITIONER_INCREMENT_SIGN: Tag = Tag(0x0018, 0x9519);
+/// (0018,9524) Application Name
+pub const APPLICATION_NAME: Tag = Tag(0x0018, 0x9524);
+/// (0018,9525) Application Version
+pub const APPLICATION_VERSION: Tag = Tag(0x0018, 0x9525);
+/// (0018,9526) Application Manufacturer
+pub const APPLICATION_MANUFACTURER: Tag = Tag(0x0018, 0x9526);
+/// (0018,9527) Algorithm Type
+pub const ALGORITHM_TYPE: Tag = Tag(0x0018, 0x9527);
+/// (0018,9528) Algorithm Description
+pub const ALGORITHM_DESCRIPTION: Tag = Tag(0x0018, 0x9528);
+/// (0018,9530) X-Ray 3D Reconstruction Sequence
+pub const X_RAY3D_RECONSTRUCTION_SEQUENCE: Tag = Tag(0x0018, 0x9530);
+/// (0018,9531) Reconstruction Description
+pub const RECONSTRUCTION_DESCRIPTION: Tag = Tag(0x0018, 0x9531);
+/// (0018,9538) Per Projection Acquisition Sequence
+pub const PER_PROJECTION_ACQUISITION_SEQUENCE: Tag = Tag(0x0018, 0x9538);
+/// (0018,9541) Detector Position Sequence
+pub const DETECTOR_POSITION_SEQUENCE: Tag = Tag(0x0018, 0x9541);
+/// (0018,9542) X-Ray Acquisition Dose Sequence
+pub const X_RAY_ACQUISITION_DOSE_SEQUENCE: Tag = Tag(0x0018, 0x9542);
+/// (0018,9543) X-Ray Source Isocenter Primary Angle
+pub const X_RAY_SOURCE_ISOCENTER_PRIMARY_ANGLE: Tag = Tag(0x0018, 0x9543);
+/// (0018,9544) X-Ray Source Isocenter Secondary Angle
+pub const X_RAY_SOURCE_ISOCENTER_SECONDARY_ANGLE: Tag = Tag(0x0018, 0x9544);
+/// (0018,9545) Breast Support Isocenter Primary Angle
+pub const BREAST_SUPPORT_ISOCENTER_PRIMARY_ANGLE: Tag = Tag(0x0018, 0x9545);
+/// (0018,9546) Breast Support Isocenter Secondary Angle
+pub const BREAST_SUPPORT_ISOCENTER_SECONDARY_ANGLE: Tag = Tag(0x0018, 0x9546);
+/// (0018,9547) Breast Support X Position to Isocenter
+pub const BREAST_SUPPORT_X_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9547);
+/// (0018,9548) Breast Support Y Position to Isocenter
+pub const BREAST_SUPPORT_Y_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9548);
+/// (0018,9549) Breast Support Z Position to Isocenter
+pub const BREAST_SUPPORT_Z_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9549);
+/// (0018,9550) Detector Isocenter Primary Angle
+pub const DETECTOR_ISOCENTER_PRIMARY_ANGLE: Tag = Tag(0x0018, 0x9550);
+/// (0018,9551) Detector Isocenter Secondary Angle
+pub const DETECTOR_ISOCENTER_SECONDARY_ANGLE: Tag = Tag(0x0018, 0x9551);
+/// (0018,9552) Detector X Position to Isocenter
+pub const DETECTOR_X_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9552);
+/// (0018,9553) Detector Y Position to Isocenter
+pub const DETECTOR_Y_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9553);
+/// (0018,9554) Detector Z Position to Isocenter
+pub const DETECTOR_Z_POSITION_TO_ISOCENTER: Tag = Tag(0x0018, 0x9554);
+/// (0018,9555) X-Ray Grid Sequence
+pub const X_RAY_GRID_SEQUENCE: Tag = Tag(0x0018, 0x9555);
+/// (0018,9556) X-Ray Filter Sequence
+pub const X_RAY_FILTER_SEQUENCE: Tag = Tag(0x0018, 0x9556);
+/// (0018,9557) Detector Active Area TLHC Position
+pub const DETECTOR_ACTIVE_AREA_TLHC_POSITION: Tag = Tag(0x0018, 0x9557);
+/// (0018,9558) Detector Active Area Orientation
+pub const DETECTOR_ACTIVE_AREA_ORIENTATION: Tag = Tag(0x0018, 0x9558);
+/// (0018,9559) Positioner Primary Angle Direction
+pub const POSITIONER_PRIMARY_ANGLE_DIRECTION: Tag = Tag(0x0018, 0x9559);
+/// (0018,9601) Diffusion B Matrix Sequence
+pub const DIFFUSION_B_MATRIX_SEQUENCE: Tag = Tag(0x0018, 0x9601);
+/// (0018,9602) Diffusion B Value XX
+pub const DIFFUSION_B_VALUE_XX: Tag = Tag(0x0018, 0x9602);
+/// (0018,9603) Diffusion B Value XY
+pub const DIFFUSION_B_VALUE_XY: Tag = Tag(0x0018, 0x9603);
+/// (0018,9604) Diffusion B Value XZ
+pub const DIFFUSION_B_VALUE_XZ: Tag = Tag(0x0018, 0x9604);
+/// (0018,9605) Diffusion B Value YY
+pub const DIFFUSION_B_VALUE_YY: Tag = Tag(0x0018, 0x9605);
+/// (0018,9606) Diffusion B Value YZ
+pub const DIFFUSION_B_VALUE_YZ: Tag = Tag(0x0018, 0x9606);
+/// (0018,9607) Diffusion B Value ZZ
+pub const DIFFUSION_B_VALUE_ZZ: Tag = Tag(0x0018, 0x9607);
+/// (0018,9621) Functional MR Sequence
+pub const FUNCTIONAL_MR_SEQUENCE: Tag = Tag(0x0018, 0x9621);
+/// (0018,9622) Functional Settling Phase Frames Present
+pub const FUNCTIONAL_SETTLING_PHASE_FRAMES_PRESENT: Tag = Tag(0x0018, 0x9622);
+/// (0018,9623) Functional Sync Pulse
+pub const FUNCTIONAL_SYNC_PULSE: Tag = Tag(0x0018, 0x9623);
+/// (0018,9624) Settling Phase Frame
+pub const SETTLING_PHASE_FRAME: Tag = Tag(0x0018, 0x9624);
+/// (0018,9701) Decay Correction Date Time
+pub const DECAY_CORRECTION_DATE_TIME: Tag = Tag(0x0018, 0x9701);
+/// (0018,9715) Start Density Threshold
+pub const START_DENSITY_THRESHOLD: Tag = Tag(0x0018, 0x9715);
+/// (0018,9716) Start Relative Density Difference Threshold
+pub const START_RELATIVE_DENSITY_DIFFERENCE_THRESHOLD: Tag = Tag(0x0018, 0x9716);
+/// (0018,9717) Start Cardiac Trigger Count Threshold
+pub const START_CARDIAC_TRIGGER_COUNT_THRESHOLD: Tag = Tag(0x0018, 0x9717);
+/// (0018,9718) Start Respiratory Trigger Count Threshold
+pub const START_RESPIRATORY_TRIGGER_COUNT_THRESHOLD: Tag = Tag(0x0018, 0x9718);
+/// (0018,9719) Termination Counts Threshold
+pub const TERMINATION_COUNTS_THRESHOLD: Tag = Tag(0x0018, 0x9719);
+/// (0018,9720) Termination Density Threshold
+pub const TERMINATION_DENSITY_THRESHOLD: Tag = Tag(0x0018, 0x9720);
+/// (0018,9721) Termination Relative Density Threshold
+pub const TERMINATION_RELATIVE_DENSITY_THRESHOLD: Tag = Tag(0x0018, 0x9721);
+/// (0018,9722) Termination Time Threshold
+pub const TERMINATION_TIME_THRESHOLD: Tag = Tag(0x0018, 0x9722);
+/// (0018,9723) Termination Cardiac Trigger Count Threshold
+pub const TERMINATION_CARDIAC_TRIGGER_COUNT_THRESHOLD: Tag = Tag(0x0018, 0x9723);
+/// (0018,9724) Termination Respiratory Trigger Count Threshold
+pub const TERMINATION_RESPIRATORY_TRIGGER_COUNT_THRESHOLD: Tag = Tag(0x0018, 0x9724);
+/// (0018,9725) Detector Geometry
+pub const DETECTOR_GEOMETRY: Tag = Tag(0x0018, 0x9725);
+/// (0018,9726) Transverse Detector Separation
+pub const TRANSVERSE_DETECTOR_SEPARATION: Tag = Tag(0x0018, 0x9726);
+/// (0018,9727) Axial Detector Dimension
+pub const AXIAL_DETECTOR_DIMENSION: Tag = Tag(0x0018, 0x9727);
+/// (0018,9729) Radiopharmaceutical Agent Number
+pub const RADIOPHARMACEUTICAL_AGENT_NUMBER: Tag = Tag(0x0018, 0x9729);
+/// (0018,9732) PET Frame Acquisition Sequence
+pub const PET_FRAME_ACQUISITION_SEQUENCE: Tag = Tag(0x0018, 0x9732);
+/// (0018,9733) PET Detector Motion Details Sequence
+pub const PET_DETECTOR_MOTION_DETAILS_SEQUENCE: Tag = Tag(0x0018, 0x9733);
+/// (0018,9734) PET Table Dynamics Sequence
+pub const PET_TABLE_DYNAMICS_SEQUENCE: Tag = Tag(0x0018, 0x9734);
+/// (0018,9735) PET Position Sequence
+pub const PET_POSITION_SEQUENCE: Tag = Tag(0x0018, 0x9735);
+/// (0018,9736) PET Frame Correction Factors Sequence
+pub const PET_FRAME_CORRECTION_FACTORS_SEQUENCE: Tag = Tag(0x0018, 0x9736);
+/// (0018,9737) Radiopharmaceutical Usage Sequence
+pub const RADIOPHARMACEUTICAL_USAGE_SEQUENCE: Tag = Tag(0x0018, 0x9737);
+/// (0018,9738) Attenuation Correction Source
+pub const ATTENUATION_CORRECTION_SOURCE: Tag = Tag(0x0018, 0x9738);
+/// (0018,9739) Number of Iterations
+pub const NUMBER_OF_ITERATIONS: Tag = Tag(0x0018, 0x9739);
+/// (0018,9740) Number of Subsets
+pub const NUMBER_OF_SUBSETS: Tag = Tag(0x0018, 0x9740);
+/// (0018,9749) PET Reconstruction Sequence
+pub const PET_RECONSTRUCTION_SEQUENCE: Tag = Tag(0x0018, 0x9749);
+/// (0018,9751) PET Frame Type Sequence
+pub const PET_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0018, 0x9751);
+/// (0018,9755) Time of Flight Information Used
+pub const TIME_OF_FLIGHT_INFORMATION_USED: Tag = Tag(0x0018, 0x9755);
+/// (0018,9756) Reconstruction Type
+pub const RECONSTRUCTION_TYPE: Tag = Tag(0x0018, 0x9756);
+/// (0018,9758) Decay Corrected
+pub const DECAY_CORRECTED: Tag = Tag(0x0018, 0x9758);
+/// (0018,9759) Attenuation Corrected
+pub const ATTENUATION_CORRECTED: Tag = Tag(0x0018, 0x9759);
+/// (0018,9760) Scatter Corrected
+pub const SCATTER_CORRECTED: Tag = Tag(0x0018, 0x9760);
+/// (0018,9761) Dead Time Corrected
+pub const DEAD_TIME_CORRECTED: Tag = Tag(0x0018, 0x9761);
+/// (0018,9762) Gantry Motion Corrected
+pub const GANTRY_MOTION_CORRECTED: Tag = Tag(0x0018, 0x9762);
+/// (0018,9763) Patient Motion Corrected
+pub const PATIENT_MOTION_CORRECTED: Tag = Tag(0x0018, 0x9763);
+/// (0018,9764) Count Loss Normalization Corrected
+pub const COUNT_LOSS_NORMALIZATION_CORRECTED: Tag = Tag(0x0018, 0x9764);
+/// (0018,9765) Randoms Corrected
+pub const RANDOMS_CORRECTED: Tag = Tag(0x0018, 0x9765);
+/// (0018,9766) Non Uniform Radial Sampling Corrected
+pub const NON_UNIFORM_RADIAL_SAMPLING_CORRECTED: Tag = Tag(0x0018, 0x9766);
+/// (0018,9767) Sensitivity Calibrated
+pub const SENSITIVITY_CALIBRATED: Tag = Tag(0x0018, 0x9767);
+/// (0018,9768) Detector Normalization Correction
+pub const DETECTOR_NORMALIZATION_CORRECTION: Tag = Tag(0x0018, 0x9768);
+/// (0018,9769) Iterative Reconstruction Method
+pub const ITERATIVE_RECONSTRUCTION_METHOD: Tag = Tag(0x0018, 0x9769);
+/// (0018,9770) Attenuation Correction Temporal Relationship
+pub const ATTENUATION_CORRECTION_TEMPORAL_RELATIONSHIP: Tag = Tag(0x0018, 0x9770);
+/// (0018,9771) Patient Physiological State Sequence
+pub const PATIENT_PHYSIOLOGICAL_STATE_SEQUENCE: Tag = Tag(0x0018, 0x9771);
+/// (0018,9772) Patient Physiological State Code Sequence
+pub const PATIENT_PHYSIOLOGICAL_STATE_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9772);
+/// (0018,9801) Depths of Focus
+pub const DEPTHS_OF_FOCUS: Tag = Tag(0x0018, 0x9801);
+/// (0018,9803) Excluded Intervals Sequence
+pub const EXCLUDED_INTERVALS_SEQUENCE: Tag = Tag(0x0018, 0x9803);
+/// (0018,9804) Exclusion Start Date Time
+pub const EXCLUSION_START_DATE_TIME: Tag = Tag(0x0018, 0x9804);
+/// (0018,9805) Exclusion Duration
+pub const EXCLUSION_DURATION: Tag = Tag(0x0018, 0x9805);
+/// (0018,9806) US Image Description Sequence
+pub const US_IMAGE_DESCRIPTION_SEQUENCE: Tag = Tag(0x0018, 0x9806);
+/// (0018,9807) Image Data Type Sequence
+pub const IMAGE_DATA_TYPE_SEQUENCE: Tag = Tag(0x0018, 0x9807);
+/// (0018,9808) Data Type
+pub const DATA_TYPE: Tag = Tag(0x0018, 0x9808);
+/// (0018,9809) Transducer Scan Pattern Code Sequence
+pub const TRANSDUCER_SCAN_PATTERN_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9809);
+/// (0018,980B) Aliased Data Type
+pub const ALIASED_DATA_TYPE: Tag = Tag(0x0018, 0x980B);
+/// (0018,980C) Position Measuring Device Used
+pub const POSITION_MEASURING_DEVICE_USED: Tag = Tag(0x0018, 0x980C);
+/// (0018,980D) Transducer Geometry Code Sequence
+pub const TRANSDUCER_GEOMETRY_CODE_SEQUENCE: Tag = Tag(0x0018, 0x980D);
+/// (0018,980E) Transducer Beam Steering Code Sequence
+pub const TRANSDUCER_BEAM_STEERING_CODE_SEQUENCE: Tag = Tag(0x0018, 0x980E);
+/// (0018,980F) Transducer Application Code Sequence
+pub const TRANSDUCER_APPLICATION_CODE_SEQUENCE: Tag = Tag(0x0018, 0x980F);
+/// (0018,9810) Zero Velocity Pixel Value
+pub const ZERO_VELOCITY_PIXEL_VALUE: Tag = Tag(0x0018, 0x9810);
+/// (0018,9900) Reference Location Label
+pub const REFERENCE_LOCATION_LABEL: Tag = Tag(0x0018, 0x9900);
+/// (0018,9901) Reference Location Description
+pub const REFERENCE_LOCATION_DESCRIPTION: Tag = Tag(0x0018, 0x9901);
+/// (0018,9902) Reference Basis Code Sequence
+pub const REFERENCE_BASIS_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9902);
+/// (0018,9903) Reference Geometry Code Sequence
+pub const REFERENCE_GEOMETRY_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9903);
+/// (0018,9904) Offset Distance
+pub const OFFSET_DISTANCE: Tag = Tag(0x0018, 0x9904);
+/// (0018,9905) Offset Direction
+pub const OFFSET_DIRECTION: Tag = Tag(0x0018, 0x9905);
+/// (0018,9906) Potential Scheduled Protocol Code Sequence
+pub const POTENTIAL_SCHEDULED_PROTOCOL_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9906);
+/// (0018,9907) Potential Requested Procedure Code Sequence
+pub const POTENTIAL_REQUESTED_PROCEDURE_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9907);
+/// (0018,9908) Potential Reasons for Procedure
+pub const POTENTIAL_REASONS_FOR_PROCEDURE: Tag = Tag(0x0018, 0x9908);
+/// (0018,9909) Potential Reasons for Procedure Code Sequence
+pub const POTENTIAL_REASONS_FOR_PROCEDURE_CODE_SEQUENCE: Tag = Tag(0x0018, 0x9909);
+/// (0018,990A) Potential Diagnostic Tasks
+pub const POTENTIAL_DIAGNOSTIC_TASKS: Tag = Tag(0x0018, 0x990A);
+/// (0018,990B) Contraindications Code Sequence
+pub const CONTRAINDICATIONS_CODE_SEQUENCE: Tag = Tag(0x0018, 0x990B);
+/// (0018,990C) Referenced Defined Protocol Sequence
+pub const REFERENCED_DEFINED_PROTOCOL_SEQUENCE: Tag = Tag(0x0018, 0x990C);
+/// (0018,990D) Referenced Performed Protocol Sequence
+pub const REFERENCED_PERFORMED_PROTOCOL_SEQUENCE: Tag = Tag(0x0018, 0x990D);
+/// (0018,990E) Predecessor Protocol Sequence
+pub const PREDECESSOR_PROTOCOL_SEQUENCE: Tag = Tag(0x0018, 0x990E);
+/// (0018,990F) Protocol Planning Information
+pub const PROTOCOL_PLANNING_INFORMATION: Tag = Tag(0x0018, 0x990F);
+/// (0018,9910) Protocol Design Rationale
+pub const PROTOCOL_DESIGN_RATIONALE: Tag = Tag(0x0018, 0x9910);
+/// (0018,9911) Patient Specification Sequence
+pub const PATIENT_SPECIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x9911);
+/// (0018,9912) Model Specification Sequence
+pub const MODEL_SPECIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x9912);
+/// (0018,9913) Parameters Specification Sequence
+pub const PARAMETERS_SPECIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x9913);
+/// (0018,9914) Instruction Sequence
+pub const INSTRUCTION_SEQUENCE: Tag = Tag(0x0018, 0x9914);
+/// (0018,9915) Instruction Index
+pub const INSTRUCTION_INDEX: Tag = Tag(0x0018, 0x9915);
+/// (0018,9916) Instruction Text
+pub const INSTRUCTION_TEXT: Tag = Tag(0x0018, 0x9916);
+/// (0018,9917) Instruction Description
+pub const INSTRUCTION_DESCRIPTION: Tag = Tag(0x0018, 0x9917);
+/// (0018,9918) Instruction Performed Flag
+pub const INSTRUCTION_PERFORMED_FLAG: Tag = Tag(0x0018, 0x9918);
+/// (0018,9919) Instruction Performed Date Time
+pub const INSTRUCTION_PERFORMED_DATE_TIME: Tag = Tag(0x0018, 0x9919);
+/// (0018,991A) Instruction Performance Comment
+pub const INSTRUCTION_PERFORMANCE_COMMENT: Tag = Tag(0x0018, 0x991A);
+/// (0018,991B) Patient Positioning Instruction Sequence
+pub const PATIENT_POSITIONING_INSTRUCTION_SEQUENCE: Tag = Tag(0x0018, 0x991B);
+/// (0018,991C) Positioning Method Code Sequence
+pub const POSITIONING_METHOD_CODE_SEQUENCE: Tag = Tag(0x0018, 0x991C);
+/// (0018,991D) Positioning Landmark Sequence
+pub const POSITIONING_LANDMARK_SEQUENCE: Tag = Tag(0x0018, 0x991D);
+/// (0018,991E) Target Frame of Reference UID
+pub const TARGET_FRAME_OF_REFERENCE_UID: Tag = Tag(0x0018, 0x991E);
+/// (0018,991F) Acquisition Protocol Element Specification Sequence
+pub const ACQUISITION_PROTOCOL_ELEMENT_SPECIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x991F);
+/// (0018,9920) Acquisition Protocol Element Sequence
+pub const ACQUISITION_PROTOCOL_ELEMENT_SEQUENCE: Tag = Tag(0x0018, 0x9920);
+/// (0018,9921) Protocol Element Number
+pub const PROTOCOL_ELEMENT_NUMBER: Tag = Tag(0x0018, 0x9921);
+/// (0018,9922) Protocol Element Name
+pub const PROTOCOL_ELEMENT_NAME: Tag = Tag(0x0018, 0x9922);
+/// (0018,9923) Protocol Element Characteristics Summary
+pub const PROTOCOL_ELEMENT_CHARACTERISTICS_SUMMARY: Tag = Tag(0x0018, 0x9923);
+/// (0018,9924) Protocol Element Purpose
+pub const PROTOCOL_ELEMENT_PURPOSE: Tag = Tag(0x0018, 0x9924);
+/// (0018,9930) Acquisition Motion
+pub const ACQUISITION_MOTION: Tag = Tag(0x0018, 0x9930);
+/// (0018,9931) Acquisition Start Location Sequence
+pub const ACQUISITION_START_LOCATION_SEQUENCE: Tag = Tag(0x0018, 0x9931);
+/// (0018,9932) Acquisition End Location Sequence
+pub const ACQUISITION_END_LOCATION_SEQUENCE: Tag = Tag(0x0018, 0x9932);
+/// (0018,9933) Reconstruction Protocol Element Specification Sequence
+pub const RECONSTRUCTION_PROTOCOL_ELEMENT_SPECIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x9933);
+/// (0018,9934) Reconstruction Protocol Element Sequence
+pub const RECONSTRUCTION_PROTOCOL_ELEMENT_SEQUENCE: Tag = Tag(0x0018, 0x9934);
+/// (0018,9935) Storage Protocol Element Specification Sequence
+pub const STORAGE_PROTOCOL_ELEMENT_SPECIFICATION_SEQUENCE: Tag = Tag(0x0018, 0x9935);
+/// (0018,9936) Storage Protocol Element Sequence
+pub const STORAGE_PROTOCOL_ELEMENT_SEQUENCE: Tag = Tag(0x0018, 0x9936);
+/// (0018,9937) Requested Series Description
+pub const REQUESTED_SERIES_DESCRIPTION: Tag = Tag(0x0018, 0x9937);
+/// (0018,9938) Source Acquisition Protocol Element Number
+pub const SOURCE_ACQUISITION_PROTOCOL_ELEMENT_NUMBER: Tag = Tag(0x0018, 0x9938);
+/// (0018,9939) Source Acquisition Beam Number
+pub const SOURCE_ACQUISITION_BEAM_NUMBER: Tag = Tag(0x0018, 0x9939);
+/// (0018,993A) Source Reconstruction Protocol Element Number
+pub const SOURCE_RECONSTRUCTION_PROTOCOL_ELEMENT_NUMBER: Tag = Tag(0x0018, 0x993A);
+/// (0018,993B) Reconstruction Start Location Sequence
+pub const RECONSTRUCTION_START_LOCATION_SEQUENCE: Tag = Tag(0x0018, 0x993B);
+/// (0018,993C) Reconstruction End Location Sequence
+pub const RECONSTRUCTION_END_LOCATION_SEQUENCE: Tag = Tag(0x0018, 0x993C);
+/// (0018,993D) Reconstruction Algorithm Sequence
+pub const RECONSTRUCTION_ALGORITHM_SEQUENCE: Tag = Tag(0x0018, 0x993D);
+/// (0018,993E) Reconstruction Target Center Location Sequence
+pub const RECONSTRUCTION_TARGET_CENTER_LOCATION_SEQUENCE: Tag = Tag(0x0018, 0x993E);
+/// (0018,9941) Image Filter Description
+pub const IMAGE_FILTER_DESCRIPTION: Tag = Tag(0x0018, 0x9941);
+/// (0018,9942) CTD Ivol Notification Trigger
+pub const CTDIVOL_NOTIFICATION_TRIGGER: Tag = Tag(0x0018, 0x9942);
+/// (0018,9943) DLP Notification Trigger
+pub const DLP_NOTIFICATION_TRIGGER: Tag = Tag(0x0018, 0x9943);
+/// (0018,9944) Auto KVP Selection Type
+pub const AUTO_KVP_SELECTION_TYPE: Tag = Tag(0x0018, 0x9944);
+/// (0018,9945) Auto KVP Upper Bound
+pub const AUTO_KVP_UPPER_BOUND: Tag = Tag(0x0018, 0x9945);
+/// (0018,9946) Auto KVP Lower Bound
+pub const AUTO_KVP_LOWER_BOUND: Tag = Tag(0x0018, 0x9946);
+/// (0018,9947) Protocol Defined Patient Position
+pub const PROTOCOL_DEFINED_PATIENT_POSITION: Tag = Tag(0x0018, 0x9947);
+/// (0018,A001) Contributing Equipment Sequence
+pub const CONTRIBUTING_EQUIPMENT_SEQUENCE: Tag = Tag(0x0018, 0xA001);
+/// (0018,A002) Contribution Date Time
+pub const CONTRIBUTION_DATE_TIME: Tag = Tag(0x0018, 0xA002);
+/// (0018,A003) Contribution Description
+pub const CONTRIBUTION_DESCRIPTION: Tag = Tag(0x0018, 0xA003);
+/// (0020,000D) Study Instance UID
+pub const STUDY_INSTANCE_UID: Tag = Tag(0x0020, 0x000D);
+/// (0020,000E) Series Instance UID
+pub const SERIES_INSTANCE_UID: Tag = Tag(0x0020, 0x000E);
+/// (0020,0010) Study ID
+pub const STUDY_ID: Tag = Tag(0x0020, 0x0010);
+/// (0020,0011) Series Number
+pub const SERIES_NUMBER: Tag = Tag(0x0020, 0x0011);
+/// (0020,0012) Acquisition Number
+pub const ACQUISITION_NUMBER: Tag = Tag(0x0020, 0x0012);
+/// (0020,0013) Instance Number
+pub const INSTANCE_NUMBER: Tag = Tag(0x0020, 0x0013);
+/// (0020,0014) Isotope Number (retired)
+pub const ISOTOPE_NUMBER: Tag = Tag(0x0020, 0x0014);
+/// (0020,0015) Phase Number (retired)
+pub const PHASE_NUMBER: Tag = Tag(0x0020, 0x0015);
+/// (0020,0016) Interval Number (retired)
+pub const INTERVAL_NUMBER: Tag = Tag(0x0020, 0x0016);
+/// (0020,0017) Time Slot Number (retired)
+pub const TIME_SLOT_NUMBER: Tag = Tag(0x0020, 0x0017);
+/// (0020,0018) Angle Number (retired)
+pub const ANGLE_NUMBER: Tag = Tag(0x0020, 0x0018);
+/// (0020,0019) Item Number
+pub const ITEM_NUMBER: Tag = Tag(0x0020, 0x0019);
+/// (0020,0020) Patient Orientation
+pub const PATIENT_ORIENTATION: Tag = Tag(0x0020, 0x0020);
+/// (0020,0022) Overlay Number (retired)
+pub const OVERLAY_NUMBER: Tag = Tag(0x0020, 0x0022);
+/// (0020,0024) Curve Number (retired)
+pub const CURVE_NUMBER: Tag = Tag(0x0020, 0x0024);
+/// (0020,0026) LUT Number (retired)
+pub const LUT_NUMBER: Tag = Tag(0x0020, 0x0026);
+/// (0020,0030) Image Position (retired)
+pub const IMAGE_POSITION: Tag = Tag(0x0020, 0x0030);
+/// (0020,0032) Image Position (Patient)
+pub const IMAGE_POSITION_PATIENT: Tag = Tag(0x0020, 0x0032);
+/// (0020,0035) Image Orientation (retired)
+pub const IMAGE_ORIENTATION: Tag = Tag(0x0020, 0x0035);
+/// (0020,0037) Image Orientation (Patient)
+pub const IMAGE_ORIENTATION_PATIENT: Tag = Tag(0x0020, 0x0037);
+/// (0020,0050) Location (retired)
+pub const LOCATION: Tag = Tag(0x0020, 0x0050);
+/// (0020,0052) Frame of Reference UID
+pub const FRAME_OF_REFERENCE_UID: Tag = Tag(0x0020, 0x0052);
+/// (0020,0060) Laterality
+pub const LATERALITY: Tag = Tag(0x0020, 0x0060);
+/// (0020,0062) Image Laterality
+pub const IMAGE_LATERALITY: Tag = Tag(0x0020, 0x0062);
+/// (0020,0070) Image Geometry Type (retired)
+pub const IMAGE_GEOMETRY_TYPE: Tag = Tag(0x0020, 0x0070);
+/// (0020,0080) Masking Image (retired)
+pub const MASKING_IMAGE: Tag = Tag(0x0020, 0x0080);
+/// (0020,00AA) Report Number (retired)
+pub const REPORT_NUMBER: Tag = Tag(0x0020, 0x00AA);
+/// (0020,0100) Temporal Position Identifier
+pub const TEMPORAL_POSITION_IDENTIFIER: Tag = Tag(0x0020, 0x0100);
+/// (0020,0105) Number of Temporal Positions
+pub const NUMBER_OF_TEMPORAL_POSITIONS: Tag = Tag(0x0020, 0x0105);
+/// (0020,0110) Temporal Resolution
+pub const TEMPORAL_RESOLUTION: Tag = Tag(0x0020, 0x0110);
+/// (0020,0200) Synchronization Frame of Reference UID
+pub const SYNCHRONIZATION_FRAME_OF_REFERENCE_UID: Tag = Tag(0x0020, 0x0200);
+/// (0020,0242) SOP Instance UID of Concatenation Source
+pub const SOP_INSTANCE_UID_OF_CONCATENATION_SOURCE: Tag = Tag(0x0020, 0x0242);
+/// (0020,1000) Series in Study (retired)
+pub const SERIES_IN_STUDY: Tag = Tag(0x0020, 0x1000);
+/// (0020,1001) Acquisitions in Series (retired)
+pub const ACQUISITIONS_IN_SERIES: Tag = Tag(0x0020, 0x1001);
+/// (0020,1002) Images in Acquisition
+pub const IMAGES_IN_ACQUISITION: Tag = Tag(0x0020, 0x1002);
+/// (0020,1003) Images in Series (retired)
+pub const IMAGES_IN_SERIES: Tag = Tag(0x0020, 0x1003);
+/// (0020,1004) Acquisitions in Study (retired)
+pub const ACQUISITIONS_IN_STUDY: Tag = Tag(0x0020, 0x1004);
+/// (0020,1005) Images in Study (retired)
+pub const IMAGES_IN_STUDY: Tag = Tag(0x0020, 0x1005);
+/// (0020,1020) Reference (retired)
+pub const REFERENCE: Tag = Tag(0x0020, 0x1020);
+/// (0020,103F) Target Position Reference Indicator
+pub const TARGET_POSITION_REFERENCE_INDICATOR: Tag = Tag(0x0020, 0x103F);
+/// (0020,1040) Position Reference Indicator
+pub const POSITION_REFERENCE_INDICATOR: Tag = Tag(0x0020, 0x1040);
+/// (0020,1041) Slice Location
+pub const SLICE_LOCATION: Tag = Tag(0x0020, 0x1041);
+/// (0020,1070) Other Study Numbers (retired)
+pub const OTHER_STUDY_NUMBERS: Tag = Tag(0x0020, 0x1070);
+/// (0020,1200) Number of Patient Related Studies
+pub const NUMBER_OF_PATIENT_RELATED_STUDIES: Tag = Tag(0x0020, 0x1200);
+/// (0020,1202) Number of Patient Related Series
+pub const NUMBER_OF_PATIENT_RELATED_SERIES: Tag = Tag(0x0020, 0x1202);
+/// (0020,1204) Number of Patient Related Instances
+pub const NUMBER_OF_PATIENT_RELATED_INSTANCES: Tag = Tag(0x0020, 0x1204);
+/// (0020,1206) Number of Study Related Series
+pub const NUMBER_OF_STUDY_RELATED_SERIES: Tag = Tag(0x0020, 0x1206);
+/// (0020,1208) Number of Study Related Instances
+pub const NUMBER_OF_STUDY_RELATED_INSTANCES: Tag = Tag(0x0020, 0x1208);
+/// (0020,1209) Number of Series Related Instances
+pub const NUMBER_OF_SERIES_RELATED_INSTANCES: Tag = Tag(0x0020, 0x1209);
+/// (0020,31xx) Source Image I Ds (retired)
+pub const SOURCE_IMAGE_IDS: Tag = Tag(0x0020, 0x3100);
+/// (0020,3401) Modifying Device ID (retired)
+pub const MODIFYING_DEVICE_ID: Tag = Tag(0x0020, 0x3401);
+/// (0020,3402) Modified Image ID (retired)
+pub const MODIFIED_IMAGE_ID: Tag = Tag(0x0020, 0x3402);
+/// (0020,3403) Modified Image Date (retired)
+pub const MODIFIED_IMAGE_DATE: Tag = Tag(0x0020, 0x3403);
+/// (0020,3404) Modifying Device Manufacturer (retired)
+pub const MODIFYING_DEVICE_MANUFACTURER: Tag = Tag(0x0020, 0x3404);
+/// (0020,3405) Modified Image Time (retired)
+pub const MODIFIED_IMAGE_TIME: Tag = Tag(0x0020, 0x3405);
+/// (0020,3406) Modified Image Description (retired)
+pub const MODIFIED_IMAGE_DESCRIPTION: Tag = Tag(0x0020, 0x3406);
+/// (0020,4000) Image Comments
+pub const IMAGE_COMMENTS: Tag = Tag(0x0020, 0x4000);
+/// (0020,5000) Original Image Identification (retired)
+pub const ORIGINAL_IMAGE_IDENTIFICATION: Tag = Tag(0x0020, 0x5000);
+/// (0020,5002) Original Image Identification Nomenclature (retired)
+pub const ORIGINAL_IMAGE_IDENTIFICATION_NOMENCLATURE: Tag = Tag(0x0020, 0x5002);
+/// (0020,9056) Stack ID
+pub const STACK_ID: Tag = Tag(0x0020, 0x9056);
+/// (0020,9057) In Stack Position Number
+pub const IN_STACK_POSITION_NUMBER: Tag = Tag(0x0020, 0x9057);
+/// (0020,9071) Frame Anatomy Sequence
+pub const FRAME_ANATOMY_SEQUENCE: Tag = Tag(0x0020, 0x9071);
+/// (0020,9072) Frame Laterality
+pub const FRAME_LATERALITY: Tag = Tag(0x0020, 0x9072);
+/// (0020,9111) Frame Content Sequence
+pub const FRAME_CONTENT_SEQUENCE: Tag = Tag(0x0020, 0x9111);
+/// (0020,9113) Plane Position Sequence
+pub const PLANE_POSITION_SEQUENCE: Tag = Tag(0x0020, 0x9113);
+/// (0020,9116) Plane Orientation Sequence
+pub const PLANE_ORIENTATION_SEQUENCE: Tag = Tag(0x0020, 0x9116);
+/// (0020,9128) Temporal Position Index
+pub const TEMPORAL_POSITION_INDEX: Tag = Tag(0x0020, 0x9128);
+/// (0020,9153) Nominal Cardiac Trigger Delay Time
+pub const NOMINAL_CARDIAC_TRIGGER_DELAY_TIME: Tag = Tag(0x0020, 0x9153);
+/// (0020,9154) Nominal Cardiac Trigger Time Prior to R Peak
+pub const NOMINAL_CARDIAC_TRIGGER_TIME_PRIOR_TO_R_PEAK: Tag = Tag(0x0020, 0x9154);
+/// (0020,9155) Actual Cardiac Trigger Time Prior to R Peak
+pub const ACTUAL_CARDIAC_TRIGGER_TIME_PRIOR_TO_R_PEAK: Tag = Tag(0x0020, 0x9155);
+/// (0020,9156) Frame Acquisition Number
+pub const FRAME_ACQUISITION_NUMBER: Tag = Tag(0x0020, 0x9156);
+/// (0020,9157) Dimension Index Values
+pub const DIMENSION_INDEX_VALUES: Tag = Tag(0x0020, 0x9157);
+/// (0020,9158) Frame Comments
+pub const FRAME_COMMENTS: Tag = Tag(0x0020, 0x9158);
+/// (0020,9161) Concatenation UID
+pub const CONCATENATION_UID: Tag = Tag(0x0020, 0x9161);
+/// (0020,9162) In Concatenation Number
+pub const IN_CONCATENATION_NUMBER: Tag = Tag(0x0020, 0x9162);
+/// (0020,9163) In Concatenation Total Number
+pub const IN_CONCATENATION_TOTAL_NUMBER: Tag = Tag(0x0020, 0x9163);
+/// (0020,9164) Dimension Organization UID
+pub const DIMENSION_ORGANIZATION_UID: Tag = Tag(0x0020, 0x9164);
+/// (0020,9165) Dimension Index Pointer
+pub const DIMENSION_INDEX_POINTER: Tag = Tag(0x0020, 0x9165);
+/// (0020,9167) Functional Group Pointer
+pub const FUNCTIONAL_GROUP_POINTER: Tag = Tag(0x0020, 0x9167);
+/// (0020,9170) Unassigned Shared Converted Attributes Sequence
+pub const UNASSIGNED_SHARED_CONVERTED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0020, 0x9170);
+/// (0020,9171) Unassigned per Frame Converted Attributes Sequence
+pub const UNASSIGNED_PER_FRAME_CONVERTED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0020, 0x9171);
+/// (0020,9172) Conversion Source Attributes Sequence
+pub const CONVERSION_SOURCE_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0020, 0x9172);
+/// (0020,9213) Dimension Index Private Creator
+pub const DIMENSION_INDEX_PRIVATE_CREATOR: Tag = Tag(0x0020, 0x9213);
+/// (0020,9221) Dimension Organization Sequence
+pub const DIMENSION_ORGANIZATION_SEQUENCE: Tag = Tag(0x0020, 0x9221);
+/// (0020,9222) Dimension Index Sequence
+pub const DIMENSION_INDEX_SEQUENCE: Tag = Tag(0x0020, 0x9222);
+/// (0020,9228) Concatenation Frame Offset Number
+pub const CONCATENATION_FRAME_OFFSET_NUMBER: Tag = Tag(0x0020, 0x9228);
+/// (0020,9238) Functional Group Private Creator
+pub const FUNCTIONAL_GROUP_PRIVATE_CREATOR: Tag = Tag(0x0020, 0x9238);
+/// (0020,9241) Nominal Percentage of Cardiac Phase
+pub const NOMINAL_PERCENTAGE_OF_CARDIAC_PHASE: Tag = Tag(0x0020, 0x9241);
+/// (0020,9245) Nominal Percentage of Respiratory Phase
+pub const NOMINAL_PERCENTAGE_OF_RESPIRATORY_PHASE: Tag = Tag(0x0020, 0x9245);
+/// (0020,9246) Starting Respiratory Amplitude
+pub const STARTING_RESPIRATORY_AMPLITUDE: Tag = Tag(0x0020, 0x9246);
+/// (0020,9247) Starting Respiratory Phase
+pub const STARTING_RESPIRATORY_PHASE: Tag = Tag(0x0020, 0x9247);
+/// (0020,9248) Ending Respiratory Amplitude
+pub const ENDING_RESPIRATORY_AMPLITUDE: Tag = Tag(0x0020, 0x9248);
+/// (0020,9249) Ending Respiratory Phase
+pub const ENDING_RESPIRATORY_PHASE: Tag = Tag(0x0020, 0x9249);
+/// (0020,9250) Respiratory Trigger Type
+pub const RESPIRATORY_TRIGGER_TYPE: Tag = Tag(0x0020, 0x9250);
+/// (0020,9251) RR Interval Time Nominal
+pub const RR_INTERVAL_TIME_NOMINAL: Tag = Tag(0x0020, 0x9251);
+/// (0020,9252) Actual Cardiac Trigger Delay Time
+pub const ACTUAL_CARDIAC_TRIGGER_DELAY_TIME: Tag = Tag(0x0020, 0x9252);
+/// (0020,9253) Respiratory Synchronization Sequence
+pub const RESPIRATORY_SYNCHRONIZATION_SEQUENCE: Tag = Tag(0x0020, 0x9253);
+/// (0020,9254) Respiratory Interval Time
+pub const RESPIRATORY_INTERVAL_TIME: Tag = Tag(0x0020, 0x9254);
+/// (0020,9255) Nominal Respiratory Trigger Delay Time
+pub const NOMINAL_RESPIRATORY_TRIGGER_DELAY_TIME: Tag = Tag(0x0020, 0x9255);
+/// (0020,9256) Respiratory Trigger Delay Threshold
+pub const RESPIRATORY_TRIGGER_DELAY_THRESHOLD: Tag = Tag(0x0020, 0x9256);
+/// (0020,9257) Actual Respiratory Trigger Delay Time
+pub const ACTUAL_RESPIRATORY_TRIGGER_DELAY_TIME: Tag = Tag(0x0020, 0x9257);
+/// (0020,9301) Image Position Volume
+pub const IMAGE_POSITION_VOLUME: Tag = Tag(0x0020, 0x9301);
+/// (0020,9302) Image Orientation Volume
+pub const IMAGE_ORIENTATION_VOLUME: Tag = Tag(0x0020, 0x9302);
+/// (0020,9307) Ultrasound Acquisition Geometry
+pub const ULTRASOUND_ACQUISITION_GEOMETRY: Tag = Tag(0x0020, 0x9307);
+/// (0020,9308) Apex Position
+pub const APEX_POSITION: Tag = Tag(0x0020, 0x9308);
+/// (0020,9309) Volume to Transducer Mapping Matrix
+pub const VOLUME_TO_TRANSDUCER_MAPPING_MATRIX: Tag = Tag(0x0020, 0x9309);
+/// (0020,930A) Volume to Table Mapping Matrix
+pub const VOLUME_TO_TABLE_MAPPING_MATRIX: Tag = Tag(0x0020, 0x930A);
+/// (0020,930B) Volume to Transducer Relationship
+pub const VOLUME_TO_TRANSDUCER_RELATIONSHIP: Tag = Tag(0x0020, 0x930B);
+/// (0020,930C) Patient Frame of Reference Source
+pub const PATIENT_FRAME_OF_REFERENCE_SOURCE: Tag = Tag(0x0020, 0x930C);
+/// (0020,930D) Temporal Position Time Offset
+pub const TEMPORAL_POSITION_TIME_OFFSET: Tag = Tag(0x0020, 0x930D);
+/// (0020,930E) Plane Position Volume Sequence
+pub const PLANE_POSITION_VOLUME_SEQUENCE: Tag = Tag(0x0020, 0x930E);
+/// (0020,930F) Plane Orientation Volume Sequence
+pub const PLANE_ORIENTATION_VOLUME_SEQUENCE: Tag = Tag(0x0020, 0x930F);
+/// (0020,9310) Temporal Position Sequence
+pub const TEMPORAL_POSITION_SEQUENCE: Tag = Tag(0x0020, 0x9310);
+/// (0020,9311) Dimension Organization Type
+pub const DIMENSION_ORGANIZATION_TYPE: Tag = Tag(0x0020, 0x9311);
+/// (0020,9312) Volume Frame of Reference UID
+pub const VOLUME_FRAME_OF_REFERENCE_UID: Tag = Tag(0x0020, 0x9312);
+/// (0020,9313) Table Frame of Reference UID
+pub const TABLE_FRAME_OF_REFERENCE_UID: Tag = Tag(0x0020, 0x9313);
+/// (0020,9421) Dimension Description Label
+pub const DIMENSION_DESCRIPTION_LABEL: Tag = Tag(0x0020, 0x9421);
+/// (0020,9450) Patient Orientation in Frame Sequence
+pub const PATIENT_ORIENTATION_IN_FRAME_SEQUENCE: Tag = Tag(0x0020, 0x9450);
+/// (0020,9453) Frame Label
+pub const FRAME_LABEL: Tag = Tag(0x0020, 0x9453);
+/// (0020,9518) Acquisition Index
+pub const ACQUISITION_INDEX: Tag = Tag(0x0020, 0x9518);
+/// (0020,9529) Contributing SOP Instances Reference Sequence
+pub const CONTRIBUTING_SOP_INSTANCES_REFERENCE_SEQUENCE: Tag = Tag(0x0020, 0x9529);
+/// (0020,9536) Reconstruction Index
+pub const RECONSTRUCTION_INDEX: Tag = Tag(0x0020, 0x9536);
+/// (0022,0001) Light Path Filter Pass Through Wavelength
+pub const LIGHT_PATH_FILTER_PASS_THROUGH_WAVELENGTH: Tag = Tag(0x0022, 0x0001);
+/// (0022,0002) Light Path Filter Pass Band
+pub const LIGHT_PATH_FILTER_PASS_BAND: Tag = Tag(0x0022, 0x0002);
+/// (0022,0003) Image Path Filter Pass Through Wavelength
+pub const IMAGE_PATH_FILTER_PASS_THROUGH_WAVELENGTH: Tag = Tag(0x0022, 0x0003);
+/// (0022,0004) Image Path Filter Pass Band
+pub const IMAGE_PATH_FILTER_PASS_BAND: Tag = Tag(0x0022, 0x0004);
+/// (0022,0005) Patient Eye Movement Commanded
+pub const PATIENT_EYE_MOVEMENT_COMMANDED: Tag = Tag(0x0022, 0x0005);
+/// (0022,0006) Patient Eye Movement Command Code Sequence
+pub const PATIENT_EYE_MOVEMENT_COMMAND_CODE_SEQUENCE: Tag = Tag(0x0022, 0x0006);
+/// (0022,0007) Spherical Lens Power
+pub const SPHERICAL_LENS_POWER: Tag = Tag(0x0022, 0x0007);
+/// (0022,0008) Cylinder Lens Power
+pub const CYLINDER_LENS_POWER: Tag = Tag(0x0022, 0x0008);
+/// (0022,0009) Cylinder Axis
+pub const CYLINDER_AXIS: Tag = Tag(0x0022, 0x0009);
+/// (0022,000A) Emmetropic Magnification
+pub const EMMETROPIC_MAGNIFICATION: Tag = Tag(0x0022, 0x000A);
+/// (0022,000B) Intra Ocular Pressure
+pub const INTRA_OCULAR_PRESSURE: Tag = Tag(0x0022, 0x000B);
+/// (0022,000C) Horizontal Field of View
+pub const HORIZONTAL_FIELD_OF_VIEW: Tag = Tag(0x0022, 0x000C);
+/// (0022,000D) Pupil Dilated
+pub const PUPIL_DILATED: Tag = Tag(0x0022, 0x000D);
+/// (0022,000E) Degree of Dilation
+pub const DEGREE_OF_DILATION: Tag = Tag(0x0022, 0x000E);
+/// (0022,0010) Stereo Baseline Angle
+pub const STEREO_BASELINE_ANGLE: Tag = Tag(0x0022, 0x0010);
+/// (0022,0011) Stereo Baseline Displacement
+pub const STEREO_BASELINE_DISPLACEMENT: Tag = Tag(0x0022, 0x0011);
+/// (0022,0012) Stereo Horizontal Pixel Offset
+pub const STEREO_HORIZONTAL_PIXEL_OFFSET: Tag = Tag(0x0022, 0x0012);
+/// (0022,0013) Stereo Vertical Pixel Offset
+pub const STEREO_VERTICAL_PIXEL_OFFSET: Tag = Tag(0x0022, 0x0013);
+/// (0022,0014) Stereo Rotation
+pub const STEREO_ROTATION: Tag = Tag(0x0022, 0x0014);
+/// (0022,0015) Acquisition Device Type Code Sequence
+pub const ACQUISITION_DEVICE_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x0015);
+/// (0022,0016) Illumination Type Code Sequence
+pub const ILLUMINATION_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x0016);
+/// (0022,0017) Light Path Filter Type Stack Code Sequence
+pub const LIGHT_PATH_FILTER_TYPE_STACK_CODE_SEQUENCE: Tag = Tag(0x0022, 0x0017);
+/// (0022,0018) Image Path Filter Type Stack Code Sequence
+pub const IMAGE_PATH_FILTER_TYPE_STACK_CODE_SEQUENCE: Tag = Tag(0x0022, 0x0018);
+/// (0022,0019) Lenses Code Sequence
+pub const LENSES_CODE_SEQUENCE: Tag = Tag(0x0022, 0x0019);
+/// (0022,001A) Channel Description Code Sequence
+pub const CHANNEL_DESCRIPTION_CODE_SEQUENCE: Tag = Tag(0x0022, 0x001A);
+/// (0022,001B) Refractive State Sequence
+pub const REFRACTIVE_STATE_SEQUENCE: Tag = Tag(0x0022, 0x001B);
+/// (0022,001C) Mydriatic Agent Code Sequence
+pub const MYDRIATIC_AGENT_CODE_SEQUENCE: Tag = Tag(0x0022, 0x001C);
+/// (0022,001D) Relative Image Position Code Sequence
+pub const RELATIVE_IMAGE_POSITION_CODE_SEQUENCE: Tag = Tag(0x0022, 0x001D);
+/// (0022,001E) Camera Angle of View
+pub const CAMERA_ANGLE_OF_VIEW: Tag = Tag(0x0022, 0x001E);
+/// (0022,0020) Stereo Pairs Sequence
+pub const STEREO_PAIRS_SEQUENCE: Tag = Tag(0x0022, 0x0020);
+/// (0022,0021) Left Image Sequence
+pub const LEFT_IMAGE_SEQUENCE: Tag = Tag(0x0022, 0x0021);
+/// (0022,0022) Right Image Sequence
+pub const RIGHT_IMAGE_SEQUENCE: Tag = Tag(0x0022, 0x0022);
+/// (0022,0028) Stereo Pairs Present
+pub const STEREO_PAIRS_PRESENT: Tag = Tag(0x0022, 0x0028);
+/// (0022,0030) Axial Length of the Eye
+pub const AXIAL_LENGTH_OF_THE_EYE: Tag = Tag(0x0022, 0x0030);
+/// (0022,0031) Ophthalmic Frame Location Sequence
+pub const OPHTHALMIC_FRAME_LOCATION_SEQUENCE: Tag = Tag(0x0022, 0x0031);
+/// (0022,0032) Reference Coordinates
+pub const REFERENCE_COORDINATES: Tag = Tag(0x0022, 0x0032);
+/// (0022,0035) Depth Spatial Resolution
+pub const DEPTH_SPATIAL_RESOLUTION: Tag = Tag(0x0022, 0x0035);
+/// (0022,0036) Maximum Depth Distortion
+pub const MAXIMUM_DEPTH_DISTORTION: Tag = Tag(0x0022, 0x0036);
+/// (0022,0037) Along Scan Spatial Resolution
+pub const ALONG_SCAN_SPATIAL_RESOLUTION: Tag = Tag(0x0022, 0x0037);
+/// (0022,0038) Maximum Along Scan Distortion
+pub const MAXIMUM_ALONG_SCAN_DISTORTION: Tag = Tag(0x0022, 0x0038);
+/// (0022,0039) Ophthalmic Image Orientation
+pub const OPHTHALMIC_IMAGE_ORIENTATION: Tag = Tag(0x0022, 0x0039);
+/// (0022,0041) Depth of Transverse Image
+pub const DEPTH_OF_TRANSVERSE_IMAGE: Tag = Tag(0x0022, 0x0041);
+/// (0022,0042) Mydriatic Agent Concentration Units Sequence
+pub const MYDRIATIC_AGENT_CONCENTRATION_UNITS_SEQUENCE: Tag = Tag(0x0022, 0x0042);
+/// (0022,0048) Across Scan Spatial Resolution
+pub const ACROSS_SCAN_SPATIAL_RESOLUTION: Tag = Tag(0x0022, 0x0048);
+/// (0022,0049) Maximum Across Scan Distortion
+pub const MAXIMUM_ACROSS_SCAN_DISTORTION: Tag = Tag(0x0022, 0x0049);
+/// (0022,004E) Mydriatic Agent Concentration
+pub const MYDRIATIC_AGENT_CONCENTRATION: Tag = Tag(0x0022, 0x004E);
+/// (0022,0055) Illumination Wave Length
+pub const ILLUMINATION_WAVE_LENGTH: Tag = Tag(0x0022, 0x0055);
+/// (0022,0056) Illumination Power
+pub const ILLUMINATION_POWER: Tag = Tag(0x0022, 0x0056);
+/// (0022,0057) Illumination Bandwidth
+pub const ILLUMINATION_BANDWIDTH: Tag = Tag(0x0022, 0x0057);
+/// (0022,0058) Mydriatic Agent Sequence
+pub const MYDRIATIC_AGENT_SEQUENCE: Tag = Tag(0x0022, 0x0058);
+/// (0022,1007) Ophthalmic Axial Measurements Right Eye Sequence
+pub const OPHTHALMIC_AXIAL_MEASUREMENTS_RIGHT_EYE_SEQUENCE: Tag = Tag(0x0022, 0x1007);
+/// (0022,1008) Ophthalmic Axial Measurements Left Eye Sequence
+pub const OPHTHALMIC_AXIAL_MEASUREMENTS_LEFT_EYE_SEQUENCE: Tag = Tag(0x0022, 0x1008);
+/// (0022,1009) Ophthalmic Axial Measurements Device Type
+pub const OPHTHALMIC_AXIAL_MEASUREMENTS_DEVICE_TYPE: Tag = Tag(0x0022, 0x1009);
+/// (0022,1010) Ophthalmic Axial Length Measurements Type
+pub const OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_TYPE: Tag = Tag(0x0022, 0x1010);
+/// (0022,1012) Ophthalmic Axial Length Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_SEQUENCE: Tag = Tag(0x0022, 0x1012);
+/// (0022,1019) Ophthalmic Axial Length
+pub const OPHTHALMIC_AXIAL_LENGTH: Tag = Tag(0x0022, 0x1019);
+/// (0022,1024) Lens Status Code Sequence
+pub const LENS_STATUS_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1024);
+/// (0022,1025) Vitreous Status Code Sequence
+pub const VITREOUS_STATUS_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1025);
+/// (0022,1028) IOL Formula Code Sequence
+pub const IOL_FORMULA_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1028);
+/// (0022,1029) IOL Formula Detail
+pub const IOL_FORMULA_DETAIL: Tag = Tag(0x0022, 0x1029);
+/// (0022,1033) Keratometer Index
+pub const KERATOMETER_INDEX: Tag = Tag(0x0022, 0x1033);
+/// (0022,1035) Source of Ophthalmic Axial Length Code Sequence
+pub const SOURCE_OF_OPHTHALMIC_AXIAL_LENGTH_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1035);
+/// (0022,1036) Source of Corneal Size Data Code Sequence
+pub const SOURCE_OF_CORNEAL_SIZE_DATA_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1036);
+/// (0022,1037) Target Refraction
+pub const TARGET_REFRACTION: Tag = Tag(0x0022, 0x1037);
+/// (0022,1039) Refractive Procedure Occurred
+pub const REFRACTIVE_PROCEDURE_OCCURRED: Tag = Tag(0x0022, 0x1039);
+/// (0022,1040) Refractive Surgery Type Code Sequence
+pub const REFRACTIVE_SURGERY_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1040);
+/// (0022,1044) Ophthalmic Ultrasound Method Code Sequence
+pub const OPHTHALMIC_ULTRASOUND_METHOD_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1044);
+/// (0022,1045) Surgically Induced Astigmatism Sequence
+pub const SURGICALLY_INDUCED_ASTIGMATISM_SEQUENCE: Tag = Tag(0x0022, 0x1045);
+/// (0022,1046) Type of Optical Correction
+pub const TYPE_OF_OPTICAL_CORRECTION: Tag = Tag(0x0022, 0x1046);
+/// (0022,1047) Toric IOL Power Sequence
+pub const TORIC_IOL_POWER_SEQUENCE: Tag = Tag(0x0022, 0x1047);
+/// (0022,1048) Predicted Toric Error Sequence
+pub const PREDICTED_TORIC_ERROR_SEQUENCE: Tag = Tag(0x0022, 0x1048);
+/// (0022,1049) Pre Selected for Implantation
+pub const PRE_SELECTED_FOR_IMPLANTATION: Tag = Tag(0x0022, 0x1049);
+/// (0022,104A) Toric IOL Power for Exact Emmetropia Sequence
+pub const TORIC_IOL_POWER_FOR_EXACT_EMMETROPIA_SEQUENCE: Tag = Tag(0x0022, 0x104A);
+/// (0022,104B) Toric IOL Power for Exact Target Refraction Sequence
+pub const TORIC_IOL_POWER_FOR_EXACT_TARGET_REFRACTION_SEQUENCE: Tag = Tag(0x0022, 0x104B);
+/// (0022,1050) Ophthalmic Axial Length Measurements Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_SEQUENCE: Tag = Tag(0x0022, 0x1050);
+/// (0022,1053) IOL Power
+pub const IOL_POWER: Tag = Tag(0x0022, 0x1053);
+/// (0022,1054) Predicted Refractive Error
+pub const PREDICTED_REFRACTIVE_ERROR: Tag = Tag(0x0022, 0x1054);
+/// (0022,1059) Ophthalmic Axial Length Velocity
+pub const OPHTHALMIC_AXIAL_LENGTH_VELOCITY: Tag = Tag(0x0022, 0x1059);
+/// (0022,1065) Lens Status Description
+pub const LENS_STATUS_DESCRIPTION: Tag = Tag(0x0022, 0x1065);
+/// (0022,1066) Vitreous Status Description
+pub const VITREOUS_STATUS_DESCRIPTION: Tag = Tag(0x0022, 0x1066);
+/// (0022,1090) IOL Power Sequence
+pub const IOL_POWER_SEQUENCE: Tag = Tag(0x0022, 0x1090);
+/// (0022,1092) Lens Constant Sequence
+pub const LENS_CONSTANT_SEQUENCE: Tag = Tag(0x0022, 0x1092);
+/// (0022,1093) IOL Manufacturer
+pub const IOL_MANUFACTURER: Tag = Tag(0x0022, 0x1093);
+/// (0022,1094) Lens Constant Description (retired)
+pub const LENS_CONSTANT_DESCRIPTION: Tag = Tag(0x0022, 0x1094);
+/// (0022,1095) Implant Name
+pub const IMPLANT_NAME: Tag = Tag(0x0022, 0x1095);
+/// (0022,1096) Keratometry Measurement Type Code Sequence
+pub const KERATOMETRY_MEASUREMENT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1096);
+/// (0022,1097) Implant Part Number
+pub const IMPLANT_PART_NUMBER: Tag = Tag(0x0022, 0x1097);
+/// (0022,1100) Referenced Ophthalmic Axial Measurements Sequence
+pub const REFERENCED_OPHTHALMIC_AXIAL_MEASUREMENTS_SEQUENCE: Tag = Tag(0x0022, 0x1100);
+/// (0022,1101) Ophthalmic Axial Length Measurements Segment Name Code Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_SEGMENT_NAME_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1101);
+/// (0022,1103) Refractive Error Before Refractive Surgery Code Sequence
+pub const REFRACTIVE_ERROR_BEFORE_REFRACTIVE_SURGERY_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1103);
+/// (0022,1121) IOL Power for Exact Emmetropia
+pub const IOL_POWER_FOR_EXACT_EMMETROPIA: Tag = Tag(0x0022, 0x1121);
+/// (0022,1122) IOL Power for Exact Target Refraction
+pub const IOL_POWER_FOR_EXACT_TARGET_REFRACTION: Tag = Tag(0x0022, 0x1122);
+/// (0022,1125) Anterior Chamber Depth Definition Code Sequence
+pub const ANTERIOR_CHAMBER_DEPTH_DEFINITION_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1125);
+/// (0022,1127) Lens Thickness Sequence
+pub const LENS_THICKNESS_SEQUENCE: Tag = Tag(0x0022, 0x1127);
+/// (0022,1128) Anterior Chamber Depth Sequence
+pub const ANTERIOR_CHAMBER_DEPTH_SEQUENCE: Tag = Tag(0x0022, 0x1128);
+/// (0022,112A) Calculation Comment Sequence
+pub const CALCULATION_COMMENT_SEQUENCE: Tag = Tag(0x0022, 0x112A);
+/// (0022,112B) Calculation Comment Type
+pub const CALCULATION_COMMENT_TYPE: Tag = Tag(0x0022, 0x112B);
+/// (0022,112C) Calculation Comment
+pub const CALCULATION_COMMENT: Tag = Tag(0x0022, 0x112C);
+/// (0022,1130) Lens Thickness
+pub const LENS_THICKNESS: Tag = Tag(0x0022, 0x1130);
+/// (0022,1131) Anterior Chamber Depth
+pub const ANTERIOR_CHAMBER_DEPTH: Tag = Tag(0x0022, 0x1131);
+/// (0022,1132) Source of Lens Thickness Data Code Sequence
+pub const SOURCE_OF_LENS_THICKNESS_DATA_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1132);
+/// (0022,1133) Source of Anterior Chamber Depth Data Code Sequence
+pub const SOURCE_OF_ANTERIOR_CHAMBER_DEPTH_DATA_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1133);
+/// (0022,1134) Source of Refractive Measurements Sequence
+pub const SOURCE_OF_REFRACTIVE_MEASUREMENTS_SEQUENCE: Tag = Tag(0x0022, 0x1134);
+/// (0022,1135) Source of Refractive Measurements Code Sequence
+pub const SOURCE_OF_REFRACTIVE_MEASUREMENTS_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1135);
+/// (0022,1140) Ophthalmic Axial Length Measurement Modified
+pub const OPHTHALMIC_AXIAL_LENGTH_MEASUREMENT_MODIFIED: Tag = Tag(0x0022, 0x1140);
+/// (0022,1150) Ophthalmic Axial Length Data Source Code Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_DATA_SOURCE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1150);
+/// (0022,1153) Ophthalmic Axial Length Acquisition Method Code Sequence (retired)
+pub const OPHTHALMIC_AXIAL_LENGTH_ACQUISITION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1153);
+/// (0022,1155) Signal to Noise Ratio
+pub const SIGNAL_TO_NOISE_RATIO: Tag = Tag(0x0022, 0x1155);
+/// (0022,1159) Ophthalmic Axial Length Data Source Description
+pub const OPHTHALMIC_AXIAL_LENGTH_DATA_SOURCE_DESCRIPTION: Tag = Tag(0x0022, 0x1159);
+/// (0022,1210) Ophthalmic Axial Length Measurements Total Length Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_TOTAL_LENGTH_SEQUENCE: Tag = Tag(0x0022, 0x1210);
+/// (0022,1211) Ophthalmic Axial Length Measurements Segmental Length Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_SEGMENTAL_LENGTH_SEQUENCE: Tag = Tag(0x0022, 0x1211);
+/// (0022,1212) Ophthalmic Axial Length Measurements Length Summation Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_LENGTH_SUMMATION_SEQUENCE: Tag = Tag(0x0022, 0x1212);
+/// (0022,1220) Ultrasound Ophthalmic Axial Length Measurements Sequence
+pub const ULTRASOUND_OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_SEQUENCE: Tag = Tag(0x0022, 0x1220);
+/// (0022,1225) Optical Ophthalmic Axial Length Measurements Sequence
+pub const OPTICAL_OPHTHALMIC_AXIAL_LENGTH_MEASUREMENTS_SEQUENCE: Tag = Tag(0x0022, 0x1225);
+/// (0022,1230) Ultrasound Selected Ophthalmic Axial Length Sequence
+pub const ULTRASOUND_SELECTED_OPHTHALMIC_AXIAL_LENGTH_SEQUENCE: Tag = Tag(0x0022, 0x1230);
+/// (0022,1250) Ophthalmic Axial Length Selection Method Code Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_SELECTION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1250);
+/// (0022,1255) Optical Selected Ophthalmic Axial Length Sequence
+pub const OPTICAL_SELECTED_OPHTHALMIC_AXIAL_LENGTH_SEQUENCE: Tag = Tag(0x0022, 0x1255);
+/// (0022,1257) Selected Segmental Ophthalmic Axial Length Sequence
+pub const SELECTED_SEGMENTAL_OPHTHALMIC_AXIAL_LENGTH_SEQUENCE: Tag = Tag(0x0022, 0x1257);
+/// (0022,1260) Selected Total Ophthalmic Axial Length Sequence
+pub const SELECTED_TOTAL_OPHTHALMIC_AXIAL_LENGTH_SEQUENCE: Tag = Tag(0x0022, 0x1260);
+/// (0022,1262) Ophthalmic Axial Length Quality Metric Sequence
+pub const OPHTHALMIC_AXIAL_LENGTH_QUALITY_METRIC_SEQUENCE: Tag = Tag(0x0022, 0x1262);
+/// (0022,1265) Ophthalmic Axial Length Quality Metric Type Code Sequence (retired)
+pub const OPHTHALMIC_AXIAL_LENGTH_QUALITY_METRIC_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1265);
+/// (0022,1273) Ophthalmic Axial Length Quality Metric Type Description (retired)
+pub const OPHTHALMIC_AXIAL_LENGTH_QUALITY_METRIC_TYPE_DESCRIPTION: Tag = Tag(0x0022, 0x1273);
+/// (0022,1300) Intraocular Lens Calculations Right Eye Sequence
+pub const INTRAOCULAR_LENS_CALCULATIONS_RIGHT_EYE_SEQUENCE: Tag = Tag(0x0022, 0x1300);
+/// (0022,1310) Intraocular Lens Calculations Left Eye Sequence
+pub const INTRAOCULAR_LENS_CALCULATIONS_LEFT_EYE_SEQUENCE: Tag = Tag(0x0022, 0x1310);
+/// (0022,1330) Referenced Ophthalmic Axial Length Measurement QC Image Sequence
+pub const REFERENCED_OPHTHALMIC_AXIAL_LENGTH_MEASUREMENT_QC_IMAGE_SEQUENCE: Tag = Tag(0x0022, 0x1330);
+/// (0022,1415) Ophthalmic Mapping Device Type
+pub const OPHTHALMIC_MAPPING_DEVICE_TYPE: Tag = Tag(0x0022, 0x1415);
+/// (0022,1420) Acquisition Method Code Sequence
+pub const ACQUISITION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1420);
+/// (0022,1423) Acquisition Method Algorithm Sequence
+pub const ACQUISITION_METHOD_ALGORITHM_SEQUENCE: Tag = Tag(0x0022, 0x1423);
+/// (0022,1436) Ophthalmic Thickness Map Type Code Sequence
+pub const OPHTHALMIC_THICKNESS_MAP_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1436);
+/// (0022,1443) Ophthalmic Thickness Mapping Normals Sequence
+pub const OPHTHALMIC_THICKNESS_MAPPING_NORMALS_SEQUENCE: Tag = Tag(0x0022, 0x1443);
+/// (0022,1445) Retinal Thickness Definition Code Sequence
+pub const RETINAL_THICKNESS_DEFINITION_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1445);
+/// (0022,1450) Pixel Value Mapping to Coded Concept Sequence
+pub const PIXEL_VALUE_MAPPING_TO_CODED_CONCEPT_SEQUENCE: Tag = Tag(0x0022, 0x1450);
+/// (0022,1452) Mapped Pixel Value
+pub const MAPPED_PIXEL_VALUE: Tag = Tag(0x0022, 0x1452);
+/// (0022,1454) Pixel Value Mapping Explanation
+pub const PIXEL_VALUE_MAPPING_EXPLANATION: Tag = Tag(0x0022, 0x1454);
+/// (0022,1458) Ophthalmic Thickness Map Quality Threshold Sequence
+pub const OPHTHALMIC_THICKNESS_MAP_QUALITY_THRESHOLD_SEQUENCE: Tag = Tag(0x0022, 0x1458);
+/// (0022,1460) Ophthalmic Thickness Map Threshold Quality Rating
+pub const OPHTHALMIC_THICKNESS_MAP_THRESHOLD_QUALITY_RATING: Tag = Tag(0x0022, 0x1460);
+/// (0022,1463) Anatomic Structure Reference Point
+pub const ANATOMIC_STRUCTURE_REFERENCE_POINT: Tag = Tag(0x0022, 0x1463);
+/// (0022,1465) Registration to Localizer Sequence
+pub const REGISTRATION_TO_LOCALIZER_SEQUENCE: Tag = Tag(0x0022, 0x1465);
+/// (0022,1466) Registered Localizer Units
+pub const REGISTERED_LOCALIZER_UNITS: Tag = Tag(0x0022, 0x1466);
+/// (0022,1467) Registered Localizer Top Left Hand Corner
+pub const REGISTERED_LOCALIZER_TOP_LEFT_HAND_CORNER: Tag = Tag(0x0022, 0x1467);
+/// (0022,1468) Registered Localizer Bottom Right Hand Corner
+pub const REGISTERED_LOCALIZER_BOTTOM_RIGHT_HAND_CORNER: Tag = Tag(0x0022, 0x1468);
+/// (0022,1470) Ophthalmic Thickness Map Quality Rating Sequence
+pub const OPHTHALMIC_THICKNESS_MAP_QUALITY_RATING_SEQUENCE: Tag = Tag(0x0022, 0x1470);
+/// (0022,1472) Relevant OPT Attributes Sequence
+pub const RELEVANT_OPT_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0022, 0x1472);
+/// (0022,1512) Transformation Method Code Sequence
+pub const TRANSFORMATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1512);
+/// (0022,1513) Transformation Algorithm Sequence
+pub const TRANSFORMATION_ALGORITHM_SEQUENCE: Tag = Tag(0x0022, 0x1513);
+/// (0022,1515) Ophthalmic Axial Length Method
+pub const OPHTHALMIC_AXIAL_LENGTH_METHOD: Tag = Tag(0x0022, 0x1515);
+/// (0022,1517) Ophthalmic FOV
+pub const OPHTHALMIC_FOV: Tag = Tag(0x0022, 0x1517);
+/// (0022,1518) Two Dimensional to Three Dimensional Map Sequence
+pub const TWO_DIMENSIONAL_TO_THREE_DIMENSIONAL_MAP_SEQUENCE: Tag = Tag(0x0022, 0x1518);
+/// (0022,1525) Wide Field Ophthalmic Photography Quality Rating Sequence
+pub const WIDE_FIELD_OPHTHALMIC_PHOTOGRAPHY_QUALITY_RATING_SEQUENCE: Tag = Tag(0x0022, 0x1525);
+/// (0022,1526) Wide Field Ophthalmic Photography Quality Threshold Sequence
+pub const WIDE_FIELD_OPHTHALMIC_PHOTOGRAPHY_QUALITY_THRESHOLD_SEQUENCE: Tag = Tag(0x0022, 0x1526);
+/// (0022,1527) Wide Field Ophthalmic Photography Threshold Quality Rating
+pub const WIDE_FIELD_OPHTHALMIC_PHOTOGRAPHY_THRESHOLD_QUALITY_RATING: Tag = Tag(0x0022, 0x1527);
+/// (0022,1528) X Coordinates Center Pixel View Angle
+pub const X_COORDINATES_CENTER_PIXEL_VIEW_ANGLE: Tag = Tag(0x0022, 0x1528);
+/// (0022,1529) Y Coordinates Center Pixel View Angle
+pub const Y_COORDINATES_CENTER_PIXEL_VIEW_ANGLE: Tag = Tag(0x0022, 0x1529);
+/// (0022,1530) Number of Map Points
+pub const NUMBER_OF_MAP_POINTS: Tag = Tag(0x0022, 0x1530);
+/// (0022,1531) Two Dimensional to Three Dimensional Map Data
+pub const TWO_DIMENSIONAL_TO_THREE_DIMENSIONAL_MAP_DATA: Tag = Tag(0x0022, 0x1531);
+/// (0022,1612) Derivation Algorithm Sequence
+pub const DERIVATION_ALGORITHM_SEQUENCE: Tag = Tag(0x0022, 0x1612);
+/// (0022,1615) Ophthalmic Image Type Code Sequence
+pub const OPHTHALMIC_IMAGE_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1615);
+/// (0022,1616) Ophthalmic Image Type Description
+pub const OPHTHALMIC_IMAGE_TYPE_DESCRIPTION: Tag = Tag(0x0022, 0x1616);
+/// (0022,1618) Scan Pattern Type Code Sequence
+pub const SCAN_PATTERN_TYPE_CODE_SEQUENCE: Tag = Tag(0x0022, 0x1618);
+/// (0022,1620) Referenced Surface Mesh Identification Sequence
+pub const REFERENCED_SURFACE_MESH_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0022, 0x1620);
+/// (0022,1622) Ophthalmic Volumetric Properties Flag
+pub const OPHTHALMIC_VOLUMETRIC_PROPERTIES_FLAG: Tag = Tag(0x0022, 0x1622);
+/// (0022,1624) Ophthalmic Anatomic Reference Point X Coordinate
+pub const OPHTHALMIC_ANATOMIC_REFERENCE_POINT_X_COORDINATE: Tag = Tag(0x0022, 0x1624);
+/// (0022,1626) Ophthalmic Anatomic Reference Point Y Coordinate
+pub const OPHTHALMIC_ANATOMIC_REFERENCE_POINT_Y_COORDINATE: Tag = Tag(0x0022, 0x1626);
+/// (0022,1628) Ophthalmic En Face Image Quality Rating Sequence
+pub const OPHTHALMIC_EN_FACE_IMAGE_QUALITY_RATING_SEQUENCE: Tag = Tag(0x0022, 0x1628);
+/// (0022,1630) Quality Threshold
+pub const QUALITY_THRESHOLD: Tag = Tag(0x0022, 0x1630);
+/// (0022,1640) OCT Bscan Analysis Acquisition Parameters Sequence
+pub const OCT_BSCAN_ANALYSIS_ACQUISITION_PARAMETERS_SEQUENCE: Tag = Tag(0x0022, 0x1640);
+/// (0022,1642) Numberof Bscans per Frame
+pub const NUMBEROF_BSCANS_PER_FRAME: Tag = Tag(0x0022, 0x1642);
+/// (0022,1643) Bscan Slab Thickness
+pub const BSCAN_SLAB_THICKNESS: Tag = Tag(0x0022, 0x1643);
+/// (0022,1644) Distance Between Bscan Slabs
+pub const DISTANCE_BETWEEN_BSCAN_SLABS: Tag = Tag(0x0022, 0x1644);
+/// (0022,1645) Bscan Cycle Time
+pub const BSCAN_CYCLE_TIME: Tag = Tag(0x0022, 0x1645);
+/// (0022,1646) Bscan Cycle Time Vector
+pub const BSCAN_CYCLE_TIME_VECTOR: Tag = Tag(0x0022, 0x1646);
+/// (0022,1649) Ascan Rate
+pub const ASCAN_RATE: Tag = Tag(0x0022, 0x1649);
+/// (0022,1650) Bscan Rate
+pub const BSCAN_RATE: Tag = Tag(0x0022, 0x1650);
+/// (0022,1658) Surface Mesh Z Pixel Offset
+pub const SURFACE_MESH_Z_PIXEL_OFFSET: Tag = Tag(0x0022, 0x1658);
+/// (0024,0010) Visual Field Horizontal Extent
+pub const VISUAL_FIELD_HORIZONTAL_EXTENT: Tag = Tag(0x0024, 0x0010);
+/// (0024,0011) Visual Field Vertical Extent
+pub const VISUAL_FIELD_VERTICAL_EXTENT: Tag = Tag(0x0024, 0x0011);
+/// (0024,0012) Visual Field Shape
+pub const VISUAL_FIELD_SHAPE: Tag = Tag(0x0024, 0x0012);
+/// (0024,0016) Screening Test Mode Code Sequence
+pub const SCREENING_TEST_MODE_CODE_SEQUENCE: Tag = Tag(0x0024, 0x0016);
+/// (0024,0018) Maximum Stimulus Luminance
+pub const MAXIMUM_STIMULUS_LUMINANCE: Tag = Tag(0x0024, 0x0018);
+/// (0024,0020) Background Luminance
+pub const BACKGROUND_LUMINANCE: Tag = Tag(0x0024, 0x0020);
+/// (0024,0021) Stimulus Color Code Sequence
+pub const STIMULUS_COLOR_CODE_SEQUENCE: Tag = Tag(0x0024, 0x0021);
+/// (0024,0024) Background Illumination Color Code Sequence
+pub const BACKGROUND_ILLUMINATION_COLOR_CODE_SEQUENCE: Tag = Tag(0x0024, 0x0024);
+/// (0024,0025) Stimulus Area
+pub const STIMULUS_AREA: Tag = Tag(0x0024, 0x0025);
+/// (0024,0028) Stimulus Presentation Time
+pub const STIMULUS_PRESENTATION_TIME: Tag = Tag(0x0024, 0x0028);
+/// (0024,0032) Fixation Sequence
+pub const FIXATION_SEQUENCE: Tag = Tag(0x0024, 0x0032);
+/// (0024,0033) Fixation Monitoring Code Sequence
+pub const FIXATION_MONITORING_CODE_SEQUENCE: Tag = Tag(0x0024, 0x0033);
+/// (0024,0034) Visual Field Catch Trial Sequence
+pub const VISUAL_FIELD_CATCH_TRIAL_SEQUENCE: Tag = Tag(0x0024, 0x0034);
+/// (0024,0035) Fixation Checked Quantity
+pub const FIXATION_CHECKED_QUANTITY: Tag = Tag(0x0024, 0x0035);
+/// (0024,0036) Patient Not Properly Fixated Quantity
+pub const PATIENT_NOT_PROPERLY_FIXATED_QUANTITY: Tag = Tag(0x0024, 0x0036);
+/// (0024,0037) Presented Visual Stimuli Data Flag
+pub const PRESENTED_VISUAL_STIMULI_DATA_FLAG: Tag = Tag(0x0024, 0x0037);
+/// (0024,0038) Number of Visual Stimuli
+pub const NUMBER_OF_VISUAL_STIMULI: Tag = Tag(0x0024, 0x0038);
+/// (0024,0039) Excessive Fixation Losses Data Flag
+pub const EXCESSIVE_FIXATION_LOSSES_DATA_FLAG: Tag = Tag(0x0024, 0x0039);
+/// (0024,0040) Excessive Fixation Losses
+pub const EXCESSIVE_FIXATION_LOSSES: Tag = Tag(0x0024, 0x0040);
+/// (0024,0042) Stimuli Retesting Quantity
+pub const STIMULI_RETESTING_QUANTITY: Tag = Tag(0x0024, 0x0042);
+/// (0024,0044) Comments on Patient Performance of Visual Field
+pub const COMMENTS_ON_PATIENT_PERFORMANCE_OF_VISUAL_FIELD: Tag = Tag(0x0024, 0x0044);
+/// (0024,0045) False Negatives Estimate Flag
+pub const FALSE_NEGATIVES_ESTIMATE_FLAG: Tag = Tag(0x0024, 0x0045);
+/// (0024,0046) False Negatives Estimate
+pub const FALSE_NEGATIVES_ESTIMATE: Tag = Tag(0x0024, 0x0046);
+/// (0024,0048) Negative Catch Trials Quantity
+pub const NEGATIVE_CATCH_TRIALS_QUANTITY: Tag = Tag(0x0024, 0x0048);
+/// (0024,0050) False Negatives Quantity
+pub const FALSE_NEGATIVES_QUANTITY: Tag = Tag(0x0024, 0x0050);
+/// (0024,0051) Excessive False Negatives Data Flag
+pub const EXCESSIVE_FALSE_NEGATIVES_DATA_FLAG: Tag = Tag(0x0024, 0x0051);
+/// (0024,0052) Excessive False Negatives
+pub const EXCESSIVE_FALSE_NEGATIVES: Tag = Tag(0x0024, 0x0052);
+/// (0024,0053) False Positives Estimate Flag
+pub const FALSE_POSITIVES_ESTIMATE_FLAG: Tag = Tag(0x0024, 0x0053);
+/// (0024,0054) False Positives Estimate
+pub const FALSE_POSITIVES_ESTIMATE: Tag = Tag(0x0024, 0x0054);
+/// (0024,0055) Catch Trials Data Flag
+pub const CATCH_TRIALS_DATA_FLAG: Tag = Tag(0x0024, 0x0055);
+/// (0024,0056) Positive Catch Trials Quantity
+pub const POSITIVE_CATCH_TRIALS_QUANTITY: Tag = Tag(0x0024, 0x0056);
+/// (0024,0057) Test Point Normals Data Flag
+pub const TEST_POINT_NORMALS_DATA_FLAG: Tag = Tag(0x0024, 0x0057);
+/// (0024,0058) Test Point Normals Sequence
+pub const TEST_POINT_NORMALS_SEQUENCE: Tag = Tag(0x0024, 0x0058);
+/// (0024,0059) Global Deviation Probability Normals Flag
+pub const GLOBAL_DEVIATION_PROBABILITY_NORMALS_FLAG: Tag = Tag(0x0024, 0x0059);
+/// (0024,0060) False Positives Quantity
+pub const FALSE_POSITIVES_QUANTITY: Tag = Tag(0x0024, 0x0060);
+/// (0024,0061) Excessive False Positives Data Flag
+pub const EXCESSIVE_FALSE_POSITIVES_DATA_FLAG: Tag = Tag(0x0024, 0x0061);
+/// (0024,0062) Excessive False Positives
+pub const EXCESSIVE_FALSE_POSITIVES: Tag = Tag(0x0024, 0x0062);
+/// (0024,0063) Visual Field Test Normals Flag
+pub const VISUAL_FIELD_TEST_NORMALS_FLAG: Tag = Tag(0x0024, 0x0063);
+/// (0024,0064) Results Normals Sequence
+pub const RESULTS_NORMALS_SEQUENCE: Tag = Tag(0x0024, 0x0064);
+/// (0024,0065) Age Corrected Sensitivity Deviation Algorithm Sequence
+pub const AGE_CORRECTED_SENSITIVITY_DEVIATION_ALGORITHM_SEQUENCE: Tag = Tag(0x0024, 0x0065);
+/// (0024,0066) Global Deviation from Normal
+pub const GLOBAL_DEVIATION_FROM_NORMAL: Tag = Tag(0x0024, 0x0066);
+/// (0024,0067) Generalized Defect Sensitivity Deviation Algorithm Sequence
+pub const GENERALIZED_DEFECT_SENSITIVITY_DEVIATION_ALGORITHM_SEQUENCE: Tag = Tag(0x0024, 0x0067);
+/// (0024,0068) Localized Deviation from Normal
+pub const LOCALIZED_DEVIATION_FROM_NORMAL: Tag = Tag(0x0024, 0x0068);
+/// (0024,0069) Patient Reliability Indicator
+pub const PATIENT_RELIABILITY_INDICATOR: Tag = Tag(0x0024, 0x0069);
+/// (0024,0070) Visual Field Mean Sensitivity
+pub const VISUAL_FIELD_MEAN_SENSITIVITY: Tag = Tag(0x0024, 0x0070);
+/// (0024,0071) Global Deviation Probability
+pub const GLOBAL_DEVIATION_PROBABILITY: Tag = Tag(0x0024, 0x0071);
+/// (0024,0072) Local Deviation Probability Normals Flag
+pub const LOCAL_DEVIATION_PROBABILITY_NORMALS_FLAG: Tag = Tag(0x0024, 0x0072);
+/// (0024,0073) Localized Deviation Probability
+pub const LOCALIZED_DEVIATION_PROBABILITY: Tag = Tag(0x0024, 0x0073);
+/// (0024,0074) Short Term Fluctuation Calculated
+pub const SHORT_TERM_FLUCTUATION_CALCULATED: Tag = Tag(0x0024, 0x0074);
+/// (0024,0075) Short Term Fluctuation
+pub const SHORT_TERM_FLUCTUATION: Tag = Tag(0x0024, 0x0075);
+/// (0024,0076) Short Term Fluctuation Probability Calculated
+pub const SHORT_TERM_FLUCTUATION_PROBABILITY_CALCULATED: Tag = Tag(0x0024, 0x0076);
+/// (0024,0077) Short Term Fluctuation Probability
+pub const SHORT_TERM_FLUCTUATION_PROBABILITY: Tag = Tag(0x0024, 0x0077);
+/// (0024,0078) Corrected Localized Deviation from Normal Calculated
+pub const CORRECTED_LOCALIZED_DEVIATION_FROM_NORMAL_CALCULATED: Tag = Tag(0x0024, 0x0078);
+/// (0024,0079) Corrected Localized Deviation from Normal
+pub const CORRECTED_LOCALIZED_DEVIATION_FROM_NORMAL: Tag = Tag(0x0024, 0x0079);
+/// (0024,0080) Corrected Localized Deviation from Normal Probability Calculated
+pub const CORRECTED_LOCALIZED_DEVIATION_FROM_NORMAL_PROBABILITY_CALCULATED: Tag = Tag(0x0024, 0x0080);
+/// (0024,0081) Corrected Localized Deviation from Normal Probability
+pub const CORRECTED_LOCALIZED_DEVIATION_FROM_NORMAL_PROBABILITY: Tag = Tag(0x0024, 0x0081);
+/// (0024,0083) Global Deviation Probability Sequence
+pub const GLOBAL_DEVIATION_PROBABILITY_SEQUENCE: Tag = Tag(0x0024, 0x0083);
+/// (0024,0085) Localized Deviation Probability Sequence
+pub const LOCALIZED_DEVIATION_PROBABILITY_SEQUENCE: Tag = Tag(0x0024, 0x0085);
+/// (0024,0086) Foveal Sensitivity Measured
+pub const FOVEAL_SENSITIVITY_MEASURED: Tag = Tag(0x0024, 0x0086);
+/// (0024,0087) Foveal Sensitivity
+pub const FOVEAL_SENSITIVITY: Tag = Tag(0x0024, 0x0087);
+/// (0024,0088) Visual Field Test Duration
+pub const VISUAL_FIELD_TEST_DURATION: Tag = Tag(0x0024, 0x0088);
+/// (0024,0089) Visual Field Test Point Sequence
+pub const VISUAL_FIELD_TEST_POINT_SEQUENCE: Tag = Tag(0x0024, 0x0089);
+/// (0024,0090) Visual Field Test Point X Coordinate
+pub const VISUAL_FIELD_TEST_POINT_X_COORDINATE: Tag = Tag(0x0024, 0x0090);
+/// (0024,0091) Visual Field Test Point Y Coordinate
+pub const VISUAL_FIELD_TEST_POINT_Y_COORDINATE: Tag = Tag(0x0024, 0x0091);
+/// (0024,0092) Age Corrected Sensitivity Deviation Value
+pub const AGE_CORRECTED_SENSITIVITY_DEVIATION_VALUE: Tag = Tag(0x0024, 0x0092);
+/// (0024,0093) Stimulus Results
+pub const STIMULUS_RESULTS: Tag = Tag(0x0024, 0x0093);
+/// (0024,0094) Sensitivity Value
+pub const SENSITIVITY_VALUE: Tag = Tag(0x0024, 0x0094);
+/// (0024,0095) Retest Stimulus Seen
+pub const RETEST_STIMULUS_SEEN: Tag = Tag(0x0024, 0x0095);
+/// (0024,0096) Retest Sensitivity Value
+pub const RETEST_SENSITIVITY_VALUE: Tag = Tag(0x0024, 0x0096);
+/// (0024,0097) Visual Field Test Point Normals Sequence
+pub const VISUAL_FIELD_TEST_POINT_NORMALS_SEQUENCE: Tag = Tag(0x0024, 0x0097);
+/// (0024,0098) Quantified Defect
+pub const QUANTIFIED_DEFECT: Tag = Tag(0x0024, 0x0098);
+/// (0024,0100) Age Corrected Sensitivity Deviation Probability Value
+pub const AGE_CORRECTED_SENSITIVITY_DEVIATION_PROBABILITY_VALUE: Tag = Tag(0x0024, 0x0100);
+/// (0024,0102) Generalized Defect Corrected Sensitivity Deviation Flag
+pub const GENERALIZED_DEFECT_CORRECTED_SENSITIVITY_DEVIATION_FLAG: Tag = Tag(0x0024, 0x0102);
+/// (0024,0103) Generalized Defect Corrected Sensitivity Deviation Value
+pub const GENERALIZED_DEFECT_CORRECTED_SENSITIVITY_DEVIATION_VALUE: Tag = Tag(0x0024, 0x0103);
+/// (0024,0104) Generalized Defect Corrected Sensitivity Deviation Probability Value
+pub const GENERALIZED_DEFECT_CORRECTED_SENSITIVITY_DEVIATION_PROBABILITY_VALUE: Tag = Tag(0x0024, 0x0104);
+/// (0024,0105) Minimum Sensitivity Value
+pub const MINIMUM_SENSITIVITY_VALUE: Tag = Tag(0x0024, 0x0105);
+/// (0024,0106) Blind Spot Localized
+pub const BLIND_SPOT_LOCALIZED: Tag = Tag(0x0024, 0x0106);
+/// (0024,0107) Blind Spot X Coordinate
+pub const BLIND_SPOT_X_COORDINATE: Tag = Tag(0x0024, 0x0107);
+/// (0024,0108) Blind Spot Y Coordinate
+pub const BLIND_SPOT_Y_COORDINATE: Tag = Tag(0x0024, 0x0108);
+/// (0024,0110) Visual Acuity Measurement Sequence
+pub const VISUAL_ACUITY_MEASUREMENT_SEQUENCE: Tag = Tag(0x0024, 0x0110);
+/// (0024,0112) Refractive Parameters Used on Patient Sequence
+pub const REFRACTIVE_PARAMETERS_USED_ON_PATIENT_SEQUENCE: Tag = Tag(0x0024, 0x0112);
+/// (0024,0113) Measurement Laterality
+pub const MEASUREMENT_LATERALITY: Tag = Tag(0x0024, 0x0113);
+/// (0024,0114) Ophthalmic Patient Clinical Information Left Eye Sequence
+pub const OPHTHALMIC_PATIENT_CLINICAL_INFORMATION_LEFT_EYE_SEQUENCE: Tag = Tag(0x0024, 0x0114);
+/// (0024,0115) Ophthalmic Patient Clinical Information Right Eye Sequence
+pub const OPHTHALMIC_PATIENT_CLINICAL_INFORMATION_RIGHT_EYE_SEQUENCE: Tag = Tag(0x0024, 0x0115);
+/// (0024,0117) Foveal Point Normative Data Flag
+pub const FOVEAL_POINT_NORMATIVE_DATA_FLAG: Tag = Tag(0x0024, 0x0117);
+/// (0024,0118) Foveal Point Probability Value
+pub const FOVEAL_POINT_PROBABILITY_VALUE: Tag = Tag(0x0024, 0x0118);
+/// (0024,0120) Screening Baseline Measured
+pub const SCREENING_BASELINE_MEASURED: Tag = Tag(0x0024, 0x0120);
+/// (0024,0122) Screening Baseline Measured Sequence
+pub const SCREENING_BASELINE_MEASURED_SEQUENCE: Tag = Tag(0x0024, 0x0122);
+/// (0024,0124) Screening Baseline Type
+pub const SCREENING_BASELINE_TYPE: Tag = Tag(0x0024, 0x0124);
+/// (0024,0126) Screening Baseline Value
+pub const SCREENING_BASELINE_VALUE: Tag = Tag(0x0024, 0x0126);
+/// (0024,0202) Algorithm Source
+pub const ALGORITHM_SOURCE: Tag = Tag(0x0024, 0x0202);
+/// (0024,0306) Data Set Name
+pub const DATA_SET_NAME: Tag = Tag(0x0024, 0x0306);
+/// (0024,0307) Data Set Version
+pub const DATA_SET_VERSION: Tag = Tag(0x0024, 0x0307);
+/// (0024,0308) Data Set Source
+pub const DATA_SET_SOURCE: Tag = Tag(0x0024, 0x0308);
+/// (0024,0309) Data Set Description
+pub const DATA_SET_DESCRIPTION: Tag = Tag(0x0024, 0x0309);
+/// (0024,0317) Visual Field Test Reliability Global Index Sequence
+pub const VISUAL_FIELD_TEST_RELIABILITY_GLOBAL_INDEX_SEQUENCE: Tag = Tag(0x0024, 0x0317);
+/// (0024,0320) Visual Field Global Results Index Sequence
+pub const VISUAL_FIELD_GLOBAL_RESULTS_INDEX_SEQUENCE: Tag = Tag(0x0024, 0x0320);
+/// (0024,0325) Data Observation Sequence
+pub const DATA_OBSERVATION_SEQUENCE: Tag = Tag(0x0024, 0x0325);
+/// (0024,0338) Index Normals Flag
+pub const INDEX_NORMALS_FLAG: Tag = Tag(0x0024, 0x0338);
+/// (0024,0341) Index Probability
+pub const INDEX_PROBABILITY: Tag = Tag(0x0024, 0x0341);
+/// (0024,0344) Index Probability Sequence
+pub const INDEX_PROBABILITY_SEQUENCE: Tag = Tag(0x0024, 0x0344);
+/// (0028,0002) Samples per Pixel
+pub const SAMPLES_PER_PIXEL: Tag = Tag(0x0028, 0x0002);
+/// (0028,0003) Samples per Pixel Used
+pub const SAMPLES_PER_PIXEL_USED: Tag = Tag(0x0028, 0x0003);
+/// (0028,0004) Photometric Interpretation
+pub const PHOTOMETRIC_INTERPRETATION: Tag = Tag(0x0028, 0x0004);
+/// (0028,0005) Image Dimensions (retired)
+pub const IMAGE_DIMENSIONS: Tag = Tag(0x0028, 0x0005);
+/// (0028,0006) Planar Configuration
+pub const PLANAR_CONFIGURATION: Tag = Tag(0x0028, 0x0006);
+/// (0028,0008) Number of Frames
+pub const NUMBER_OF_FRAMES: Tag = Tag(0x0028, 0x0008);
+/// (0028,0009) Frame Increment Pointer
+pub const FRAME_INCREMENT_POINTER: Tag = Tag(0x0028, 0x0009);
+/// (0028,000A) Frame Dimension Pointer
+pub const FRAME_DIMENSION_POINTER: Tag = Tag(0x0028, 0x000A);
+/// (0028,0010) Rows
+pub const ROWS: Tag = Tag(0x0028, 0x0010);
+/// (0028,0011) Columns
+pub const COLUMNS: Tag = Tag(0x0028, 0x0011);
+/// (0028,0012) Planes (retired)
+pub const PLANES: Tag = Tag(0x0028, 0x0012);
+/// (0028,0014) Ultrasound Color Data Present
+pub const ULTRASOUND_COLOR_DATA_PRESENT: Tag = Tag(0x0028, 0x0014);
+/// (0028,0030) Pixel Spacing
+pub const PIXEL_SPACING: Tag = Tag(0x0028, 0x0030);
+/// (0028,0031) Zoom Factor
+pub const ZOOM_FACTOR: Tag = Tag(0x0028, 0x0031);
+/// (0028,0032) Zoom Center
+pub const ZOOM_CENTER: Tag = Tag(0x0028, 0x0032);
+/// (0028,0034) Pixel Aspect Ratio
+pub const PIXEL_ASPECT_RATIO: Tag = Tag(0x0028, 0x0034);
+/// (0028,0040) Image Format (retired)
+pub const IMAGE_FORMAT: Tag = Tag(0x0028, 0x0040);
+/// (0028,0050) Manipulated Image (retired)
+pub const MANIPULATED_IMAGE: Tag = Tag(0x0028, 0x0050);
+/// (0028,0051) Corrected Image
+pub const CORRECTED_IMAGE: Tag = Tag(0x0028, 0x0051);
+/// (0028,005F) Compression Recognition Code (retired)
+pub const COMPRESSION_RECOGNITION_CODE: Tag = Tag(0x0028, 0x005F);
+/// (0028,0060) Compression Code (retired)
+pub const COMPRESSION_CODE: Tag = Tag(0x0028, 0x0060);
+/// (0028,0061) Compression Originator (retired)
+pub const COMPRESSION_ORIGINATOR: Tag = Tag(0x0028, 0x0061);
+/// (0028,0062) Compression Label (retired)
+pub const COMPRESSION_LABEL: Tag = Tag(0x0028, 0x0062);
+/// (0028,0063) Compression Description (retired)
+pub const COMPRESSION_DESCRIPTION: Tag = Tag(0x0028, 0x0063);
+/// (0028,0065) Compression Sequence (retired)
+pub const COMPRESSION_SEQUENCE: Tag = Tag(0x0028, 0x0065);
+/// (0028,0066) Compression Step Pointers (retired)
+pub const COMPRESSION_STEP_POINTERS: Tag = Tag(0x0028, 0x0066);
+/// (0028,0068) Repeat Interval (retired)
+pub const REPEAT_INTERVAL: Tag = Tag(0x0028, 0x0068);
+/// (0028,0069) Bits Grouped (retired)
+pub const BITS_GROUPED: Tag = Tag(0x0028, 0x0069);
+/// (0028,0070) Perimeter Table (retired)
+pub const PERIMETER_TABLE: Tag = Tag(0x0028, 0x0070);
+/// (0028,0071) Perimeter Value (retired)
+pub const PERIMETER_VALUE: Tag = Tag(0x0028, 0x0071);
+/// (0028,0080) Predictor Rows (retired)
+pub const PREDICTOR_ROWS: Tag = Tag(0x0028, 0x0080);
+/// (0028,0081) Predictor Columns (retired)
+pub const PREDICTOR_COLUMNS: Tag = Tag(0x0028, 0x0081);
+/// (0028,0082) Predictor Constants (retired)
+pub const PREDICTOR_CONSTANTS: Tag = Tag(0x0028, 0x0082);
+/// (0028,0090) Blocked Pixels (retired)
+pub const BLOCKED_PIXELS: Tag = Tag(0x0028, 0x0090);
+/// (0028,0091) Block Rows (retired)
+pub const BLOCK_ROWS: Tag = Tag(0x0028, 0x0091);
+/// (0028,0092) Block Columns (retired)
+pub const BLOCK_COLUMNS: Tag = Tag(0x0028, 0x0092);
+/// (0028,0093) Row Overlap (retired)
+pub const ROW_OVERLAP: Tag = Tag(0x0028, 0x0093);
+/// (0028,0094) Column Overlap (retired)
+pub const COLUMN_OVERLAP: Tag = Tag(0x0028, 0x0094);
+/// (0028,0100) Bits Allocated
+pub const BITS_ALLOCATED: Tag = Tag(0x0028, 0x0100);
+/// (0028,0101) Bits Stored
+pub const BITS_STORED: Tag = Tag(0x0028, 0x0101);
+/// (0028,0102) High Bit
+pub const HIGH_BIT: Tag = Tag(0x0028, 0x0102);
+/// (0028,0103) Pixel Representation
+pub const PIXEL_REPRESENTATION: Tag = Tag(0x0028, 0x0103);
+/// (0028,0104) Smallest Valid Pixel Value (retired)
+pub const SMALLEST_VALID_PIXEL_VALUE: Tag = Tag(0x0028, 0x0104);
+/// (0028,0105) Largest Valid Pixel Value (retired)
+pub const LARGEST_VALID_PIXEL_VALUE: Tag = Tag(0x0028, 0x0105);
+/// (0028,0106) Smallest Image Pixel Value
+pub const SMALLEST_IMAGE_PIXEL_VALUE: Tag = Tag(0x0028, 0x0106);
+/// (0028,0107) Largest Image Pixel Value
+pub const LARGEST_IMAGE_PIXEL_VALUE: Tag = Tag(0x0028, 0x0107);
+/// (0028,0108) Smallest Pixel Value in Series
+pub const SMALLEST_PIXEL_VALUE_IN_SERIES: Tag = Tag(0x0028, 0x0108);
+/// (0028,0109) Largest Pixel Value in Series
+pub const LARGEST_PIXEL_VALUE_IN_SERIES: Tag = Tag(0x0028, 0x0109);
+/// (0028,0110) Smallest Image Pixel Value in Plane (retired)
+pub const SMALLEST_IMAGE_PIXEL_VALUE_IN_PLANE: Tag = Tag(0x0028, 0x0110);
+/// (0028,0111) Largest Image Pixel Value in Plane (retired)
+pub const LARGEST_IMAGE_PIXEL_VALUE_IN_PLANE: Tag = Tag(0x0028, 0x0111);
+/// (0028,0120) Pixel Padding Value
+pub const PIXEL_PADDING_VALUE: Tag = Tag(0x0028, 0x0120);
+/// (0028,0121) Pixel Padding Range Limit
+pub const PIXEL_PADDING_RANGE_LIMIT: Tag = Tag(0x0028, 0x0121);
+/// (0028,0122) Float Pixel Padding Value
+pub const FLOAT_PIXEL_PADDING_VALUE: Tag = Tag(0x0028, 0x0122);
+/// (0028,0123) Double Float Pixel Padding Value
+pub const DOUBLE_FLOAT_PIXEL_PADDING_VALUE: Tag = Tag(0x0028, 0x0123);
+/// (0028,0124) Float Pixel Padding Range Limit
+pub const FLOAT_PIXEL_PADDING_RANGE_LIMIT: Tag = Tag(0x0028, 0x0124);
+/// (0028,0125) Double Float Pixel Padding Range Limit
+pub const DOUBLE_FLOAT_PIXEL_PADDING_RANGE_LIMIT: Tag = Tag(0x0028, 0x0125);
+/// (0028,0200) Image Location (retired)
+pub const IMAGE_LOCATION: Tag = Tag(0x0028, 0x0200);
+/// (0028,0300) Quality Control Image
+pub const QUALITY_CONTROL_IMAGE: Tag = Tag(0x0028, 0x0300);
+/// (0028,0301) Burned in Annotation
+pub const BURNED_IN_ANNOTATION: Tag = Tag(0x0028, 0x0301);
+/// (0028,0302) Recognizable Visual Features
+pub const RECOGNIZABLE_VISUAL_FEATURES: Tag = Tag(0x0028, 0x0302);
+/// (0028,0303) Longitudinal Temporal Information Modified
+pub const LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED: Tag = Tag(0x0028, 0x0303);
+/// (0028,0304) Referenced Color Palette Instance UID
+pub const REFERENCED_COLOR_PALETTE_INSTANCE_UID: Tag = Tag(0x0028, 0x0304);
+/// (0028,0400) Transform Label (retired)
+pub const TRANSFORM_LABEL: Tag = Tag(0x0028, 0x0400);
+/// (0028,0401) Transform Version Number (retired)
+pub const TRANSFORM_VERSION_NUMBER: Tag = Tag(0x0028, 0x0401);
+/// (0028,0402) Number of Transform Steps (retired)
+pub const NUMBER_OF_TRANSFORM_STEPS: Tag = Tag(0x0028, 0x0402);
+/// (0028,0403) Sequence of Compressed Data (retired)
+pub const SEQUENCE_OF_COMPRESSED_DATA: Tag = Tag(0x0028, 0x0403);
+/// (0028,0404) Details of Coefficients (retired)
+pub const DETAILS_OF_COEFFICIENTS: Tag = Tag(0x0028, 0x0404);
+/// (0028,0700) DCT Label (retired)
+pub const DCT_LABEL: Tag = Tag(0x0028, 0x0700);
+/// (0028,0701) Data Block Description (retired)
+pub const DATA_BLOCK_DESCRIPTION: Tag = Tag(0x0028, 0x0701);
+/// (0028,0702) Data Block (retired)
+pub const DATA_BLOCK: Tag = Tag(0x0028, 0x0702);
+/// (0028,0710) Normalization Factor Format (retired)
+pub const NORMALIZATION_FACTOR_FORMAT: Tag = Tag(0x0028, 0x0710);
+/// (0028,0720) Zonal Map Number Format (retired)
+pub const ZONAL_MAP_NUMBER_FORMAT: Tag = Tag(0x0028, 0x0720);
+/// (0028,0721) Zonal Map Location (retired)
+pub const ZONAL_MAP_LOCATION: Tag = Tag(0x0028, 0x0721);
+/// (0028,0722) Zonal Map Format (retired)
+pub const ZONAL_MAP_FORMAT: Tag = Tag(0x0028, 0x0722);
+/// (0028,0730) Adaptive Map Format (retired)
+pub const ADAPTIVE_MAP_FORMAT: Tag = Tag(0x0028, 0x0730);
+/// (0028,0740) Code Number Format (retired)
+pub const CODE_NUMBER_FORMAT: Tag = Tag(0x0028, 0x0740);
+/// (0028,0A02) Pixel Spacing Calibration Type
+pub const PIXEL_SPACING_CALIBRATION_TYPE: Tag = Tag(0x0028, 0x0A02);
+/// (0028,0A04) Pixel Spacing Calibration Description
+pub const PIXEL_SPACING_CALIBRATION_DESCRIPTION: Tag = Tag(0x0028, 0x0A04);
+/// (0028,1040) Pixel Intensity Relationship
+pub const PIXEL_INTENSITY_RELATIONSHIP: Tag = Tag(0x0028, 0x1040);
+/// (0028,1041) Pixel Intensity Relationship Sign
+pub const PIXEL_INTENSITY_RELATIONSHIP_SIGN: Tag = Tag(0x0028, 0x1041);
+/// (0028,1050) Window Center
+pub const WINDOW_CENTER: Tag = Tag(0x0028, 0x1050);
+/// (0028,1051) Window Width
+pub const WINDOW_WIDTH: Tag = Tag(0x0028, 0x1051);
+/// (0028,1052) Rescale Intercept
+pub const RESCALE_INTERCEPT: Tag = Tag(0x0028, 0x1052);
+/// (0028,1053) Rescale Slope
+pub const RESCALE_SLOPE: Tag = Tag(0x0028, 0x1053);
+/// (0028,1054) Rescale Type
+pub const RESCALE_TYPE: Tag = Tag(0x0028, 0x1054);
+/// (0028,1055) Window Center Width Explanation
+pub const WINDOW_CENTER_WIDTH_EXPLANATION: Tag = Tag(0x0028, 0x1055);
+/// (0028,1056) VOILUT Function
+pub const VOILUT_FUNCTION: Tag = Tag(0x0028, 0x1056);
+/// (0028,1080) Gray Scale (retired)
+pub const GRAY_SCALE: Tag = Tag(0x0028, 0x1080);
+/// (0028,1090) Recommended Viewing Mode
+pub const RECOMMENDED_VIEWING_MODE: Tag = Tag(0x0028, 0x1090);
+/// (0028,1100) Gray Lookup Table Descriptor (retired)
+pub const GRAY_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1100);
+/// (0028,1101) Red Palette Color Lookup Table Descriptor
+pub const RED_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1101);
+/// (0028,1102) Green Palette Color Lookup Table Descriptor
+pub const GREEN_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1102);
+/// (0028,1103) Blue Palette Color Lookup Table Descriptor
+pub const BLUE_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1103);
+/// (0028,1104) Alpha Palette Color Lookup Table Descriptor
+pub const ALPHA_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1104);
+/// (0028,1111) Large Red Palette Color Lookup Table Descriptor (retired)
+pub const LARGE_RED_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1111);
+/// (0028,1112) Large Green Palette Color Lookup Table Descriptor (retired)
+pub const LARGE_GREEN_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1112);
+/// (0028,1113) Large Blue Palette Color Lookup Table Descriptor (retired)
+pub const LARGE_BLUE_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1113);
+/// (0028,1199) Palette Color Lookup Table UID
+pub const PALETTE_COLOR_LOOKUP_TABLE_UID: Tag = Tag(0x0028, 0x1199);
+/// (0028,1200) Gray Lookup Table Data (retired)
+pub const GRAY_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1200);
+/// (0028,1201) Red Palette Color Lookup Table Data
+pub const RED_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1201);
+/// (0028,1202) Green Palette Color Lookup Table Data
+pub const GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1202);
+/// (0028,1203) Blue Palette Color Lookup Table Data
+pub const BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1203);
+/// (0028,1204) Alpha Palette Color Lookup Table Data
+pub const ALPHA_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1204);
+/// (0028,1211) Large Red Palette Color Lookup Table Data (retired)
+pub const LARGE_RED_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1211);
+/// (0028,1212) Large Green Palette Color Lookup Table Data (retired)
+pub const LARGE_GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1212);
+/// (0028,1213) Large Blue Palette Color Lookup Table Data (retired)
+pub const LARGE_BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1213);
+/// (0028,1214) Large Palette Color Lookup Table UID (retired)
+pub const LARGE_PALETTE_COLOR_LOOKUP_TABLE_UID: Tag = Tag(0x0028, 0x1214);
+/// (0028,1221) Segmented Red Palette Color Lookup Table Data
+pub const SEGMENTED_RED_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1221);
+/// (0028,1222) Segmented Green Palette Color Lookup Table Data
+pub const SEGMENTED_GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1222);
+/// (0028,1223) Segmented Blue Palette Color Lookup Table Data
+pub const SEGMENTED_BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1223);
+/// (0028,1224) Segmented Alpha Palette Color Lookup Table Data
+pub const SEGMENTED_ALPHA_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1224);
+/// (0028,1230) Stored Value Color Range Sequence
+pub const STORED_VALUE_COLOR_RANGE_SEQUENCE: Tag = Tag(0x0028, 0x1230);
+/// (0028,1231) Minimum Stored Value Mapped
+pub const MINIMUM_STORED_VALUE_MAPPED: Tag = Tag(0x0028, 0x1231);
+/// (0028,1232) Maximum Stored Value Mapped
+pub const MAXIMUM_STORED_VALUE_MAPPED: Tag = Tag(0x0028, 0x1232);
+/// (0028,1300) Breast Implant Present
+pub const BREAST_IMPLANT_PRESENT: Tag = Tag(0x0028, 0x1300);
+/// (0028,1350) Partial View
+pub const PARTIAL_VIEW: Tag = Tag(0x0028, 0x1350);
+/// (0028,1351) Partial View Description
+pub const PARTIAL_VIEW_DESCRIPTION: Tag = Tag(0x0028, 0x1351);
+/// (0028,1352) Partial View Code Sequence
+pub const PARTIAL_VIEW_CODE_SEQUENCE: Tag = Tag(0x0028, 0x1352);
+/// (0028,135A) Spatial Locations Preserved
+pub const SPATIAL_LOCATIONS_PRESERVED: Tag = Tag(0x0028, 0x135A);
+/// (0028,1401) Data Frame Assignment Sequence
+pub const DATA_FRAME_ASSIGNMENT_SEQUENCE: Tag = Tag(0x0028, 0x1401);
+/// (0028,1402) Data Path Assignment
+pub const DATA_PATH_ASSIGNMENT: Tag = Tag(0x0028, 0x1402);
+/// (0028,1403) Bits Mapped to Color Lookup Table
+pub const BITS_MAPPED_TO_COLOR_LOOKUP_TABLE: Tag = Tag(0x0028, 0x1403);
+/// (0028,1404) Blending LUT 1 Sequence
+pub const BLENDING_LUT1SEQUENCE: Tag = Tag(0x0028, 0x1404);
+/// (0028,1405) Blending LUT 1 Transfer Function
+pub const BLENDING_LUT1TRANSFER_FUNCTION: Tag = Tag(0x0028, 0x1405);
+/// (0028,1406) Blending Weight Constant
+pub const BLENDING_WEIGHT_CONSTANT: Tag = Tag(0x0028, 0x1406);
+/// (0028,1407) Blending Lookup Table Descriptor
+pub const BLENDING_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0028, 0x1407);
+/// (0028,1408) Blending Lookup Table Data
+pub const BLENDING_LOOKUP_TABLE_DATA: Tag = Tag(0x0028, 0x1408);
+/// (0028,140B) Enhanced Palette Color Lookup Table Sequence
+pub const ENHANCED_PALETTE_COLOR_LOOKUP_TABLE_SEQUENCE: Tag = Tag(0x0028, 0x140B);
+/// (0028,140C) Blending LUT 2 Sequence
+pub const BLENDING_LUT2SEQUENCE: Tag = Tag(0x0028, 0x140C);
+/// (0028,140D) Blending LUT 2 Transfer Function
+pub const BLENDING_LUT2TRANSFER_FUNCTION: Tag = Tag(0x0028, 0x140D);
+/// (0028,140E) Data Path ID
+pub const DATA_PATH_ID: Tag = Tag(0x0028, 0x140E);
+/// (0028,140F) RGBLUT Transfer Function
+pub const RGBLUT_TRANSFER_FUNCTION: Tag = Tag(0x0028, 0x140F);
+/// (0028,1410) Alpha LUT Transfer Function
+pub const ALPHA_LUT_TRANSFER_FUNCTION: Tag = Tag(0x0028, 0x1410);
+/// (0028,2000) ICC Profile
+pub const ICC_PROFILE: Tag = Tag(0x0028, 0x2000);
+/// (0028,2002) Color Space
+pub const COLOR_SPACE: Tag = Tag(0x0028, 0x2002);
+/// (0028,2110) Lossy Image Compression
+pub const LOSSY_IMAGE_COMPRESSION: Tag = Tag(0x0028, 0x2110);
+/// (0028,2112) Lossy Image Compression Ratio
+pub const LOSSY_IMAGE_COMPRESSION_RATIO: Tag = Tag(0x0028, 0x2112);
+/// (0028,2114) Lossy Image Compression Method
+pub const LOSSY_IMAGE_COMPRESSION_METHOD: Tag = Tag(0x0028, 0x2114);
+/// (0028,3000) Modality LUT Sequence
+pub const MODALITY_LUT_SEQUENCE: Tag = Tag(0x0028, 0x3000);
+/// (0028,3002) LUT Descriptor
+pub const LUT_DESCRIPTOR: Tag = Tag(0x0028, 0x3002);
+/// (0028,3003) LUT Explanation
+pub const LUT_EXPLANATION: Tag = Tag(0x0028, 0x3003);
+/// (0028,3004) Modality LUT Type
+pub const MODALITY_LUT_TYPE: Tag = Tag(0x0028, 0x3004);
+/// (0028,3006) LUT Data
+pub const LUT_DATA: Tag = Tag(0x0028, 0x3006);
+/// (0028,3010) VOILUT Sequence
+pub const VOILUT_SEQUENCE: Tag = Tag(0x0028, 0x3010);
+/// (0028,3110) Softcopy VOILUT Sequence
+pub const SOFTCOPY_VOILUT_SEQUENCE: Tag = Tag(0x0028, 0x3110);
+/// (0028,4000) Image Presentation Comments (retired)
+pub const IMAGE_PRESENTATION_COMMENTS: Tag = Tag(0x0028, 0x4000);
+/// (0028,5000) Bi Plane Acquisition Sequence (retired)
+pub const BI_PLANE_ACQUISITION_SEQUENCE: Tag = Tag(0x0028, 0x5000);
+/// (0028,6010) Representative Frame Number
+pub const REPRESENTATIVE_FRAME_NUMBER: Tag = Tag(0x0028, 0x6010);
+/// (0028,6020) Frame Numbers of Interest
+pub const FRAME_NUMBERS_OF_INTEREST: Tag = Tag(0x0028, 0x6020);
+/// (0028,6022) Frame of Interest Description
+pub const FRAME_OF_INTEREST_DESCRIPTION: Tag = Tag(0x0028, 0x6022);
+/// (0028,6023) Frame of Interest Type
+pub const FRAME_OF_INTEREST_TYPE: Tag = Tag(0x0028, 0x6023);
+/// (0028,6030) Mask Pointers (retired)
+pub const MASK_POINTERS: Tag = Tag(0x0028, 0x6030);
+/// (0028,6040) R Wave Pointer
+pub const R_WAVE_POINTER: Tag = Tag(0x0028, 0x6040);
+/// (0028,6100) Mask Subtraction Sequence
+pub const MASK_SUBTRACTION_SEQUENCE: Tag = Tag(0x0028, 0x6100);
+/// (0028,6101) Mask Operation
+pub const MASK_OPERATION: Tag = Tag(0x0028, 0x6101);
+/// (0028,6102) Applicable Frame Range
+pub const APPLICABLE_FRAME_RANGE: Tag = Tag(0x0028, 0x6102);
+/// (0028,6110) Mask Frame Numbers
+pub const MASK_FRAME_NUMBERS: Tag = Tag(0x0028, 0x6110);
+/// (0028,6112) Contrast Frame Averaging
+pub const CONTRAST_FRAME_AVERAGING: Tag = Tag(0x0028, 0x6112);
+/// (0028,6114) Mask Sub Pixel Shift
+pub const MASK_SUB_PIXEL_SHIFT: Tag = Tag(0x0028, 0x6114);
+/// (0028,6120) TID Offset
+pub const TID_OFFSET: Tag = Tag(0x0028, 0x6120);
+/// (0028,6190) Mask Operation Explanation
+pub const MASK_OPERATION_EXPLANATION: Tag = Tag(0x0028, 0x6190);
+/// (0028,7000) Equipment Administrator Sequence
+pub const EQUIPMENT_ADMINISTRATOR_SEQUENCE: Tag = Tag(0x0028, 0x7000);
+/// (0028,7001) Number of Display Subsystems
+pub const NUMBER_OF_DISPLAY_SUBSYSTEMS: Tag = Tag(0x0028, 0x7001);
+/// (0028,7002) Current Configuration ID
+pub const CURRENT_CONFIGURATION_ID: Tag = Tag(0x0028, 0x7002);
+/// (0028,7003) Display Subsystem ID
+pub const DISPLAY_SUBSYSTEM_ID: Tag = Tag(0x0028, 0x7003);
+/// (0028,7004) Display Subsystem Name
+pub const DISPLAY_SUBSYSTEM_NAME: Tag = Tag(0x0028, 0x7004);
+/// (0028,7005) Display Subsystem Description
+pub const DISPLAY_SUBSYSTEM_DESCRIPTION: Tag = Tag(0x0028, 0x7005);
+/// (0028,7006) System Status
+pub const SYSTEM_STATUS: Tag = Tag(0x0028, 0x7006);
+/// (0028,7007) System Status Comment
+pub const SYSTEM_STATUS_COMMENT: Tag = Tag(0x0028, 0x7007);
+/// (0028,7008) Target Luminance Characteristics Sequence
+pub const TARGET_LUMINANCE_CHARACTERISTICS_SEQUENCE: Tag = Tag(0x0028, 0x7008);
+/// (0028,7009) Luminance Characteristics ID
+pub const LUMINANCE_CHARACTERISTICS_ID: Tag = Tag(0x0028, 0x7009);
+/// (0028,700A) Display Subsystem Configuration Sequence
+pub const DISPLAY_SUBSYSTEM_CONFIGURATION_SEQUENCE: Tag = Tag(0x0028, 0x700A);
+/// (0028,700B) Configuration ID
+pub const CONFIGURATION_ID: Tag = Tag(0x0028, 0x700B);
+/// (0028,700C) Configuration Name
+pub const CONFIGURATION_NAME: Tag = Tag(0x0028, 0x700C);
+/// (0028,700D) Configuration Description
+pub const CONFIGURATION_DESCRIPTION: Tag = Tag(0x0028, 0x700D);
+/// (0028,700E) Referenced Target Luminance Characteristics ID
+pub const REFERENCED_TARGET_LUMINANCE_CHARACTERISTICS_ID: Tag = Tag(0x0028, 0x700E);
+/// (0028,700F) QA Results Sequence
+pub const QA_RESULTS_SEQUENCE: Tag = Tag(0x0028, 0x700F);
+/// (0028,7010) Display Subsystem QA Results Sequence
+pub const DISPLAY_SUBSYSTEM_QA_RESULTS_SEQUENCE: Tag = Tag(0x0028, 0x7010);
+/// (0028,7011) Configuration QA Results Sequence
+pub const CONFIGURATION_QA_RESULTS_SEQUENCE: Tag = Tag(0x0028, 0x7011);
+/// (0028,7012) Measurement Equipment Sequence
+pub const MEASUREMENT_EQUIPMENT_SEQUENCE: Tag = Tag(0x0028, 0x7012);
+/// (0028,7013) Measurement Functions
+pub const MEASUREMENT_FUNCTIONS: Tag = Tag(0x0028, 0x7013);
+/// (0028,7014) Measurement Equipment Type
+pub const MEASUREMENT_EQUIPMENT_TYPE: Tag = Tag(0x0028, 0x7014);
+/// (0028,7015) Visual Evaluation Result Sequence
+pub const VISUAL_EVALUATION_RESULT_SEQUENCE: Tag = Tag(0x0028, 0x7015);
+/// (0028,7016) Display Calibration Result Sequence
+pub const DISPLAY_CALIBRATION_RESULT_SEQUENCE: Tag = Tag(0x0028, 0x7016);
+/// (0028,7017) DDL Value
+pub const DDL_VALUE: Tag = Tag(0x0028, 0x7017);
+/// (0028,7018) CI Exy White Point
+pub const CI_EXY_WHITE_POINT: Tag = Tag(0x0028, 0x7018);
+/// (0028,7019) Display Function Type
+pub const DISPLAY_FUNCTION_TYPE: Tag = Tag(0x0028, 0x7019);
+/// (0028,701A) Gamma Value
+pub const GAMMA_VALUE: Tag = Tag(0x0028, 0x701A);
+/// (0028,701B) Number of Luminance Points
+pub const NUMBER_OF_LUMINANCE_POINTS: Tag = Tag(0x0028, 0x701B);
+/// (0028,701C) Luminance Response Sequence
+pub const LUMINANCE_RESPONSE_SEQUENCE: Tag = Tag(0x0028, 0x701C);
+/// (0028,701D) Target Minimum Luminance
+pub const TARGET_MINIMUM_LUMINANCE: Tag = Tag(0x0028, 0x701D);
+/// (0028,701E) Target Maximum Luminance
+pub const TARGET_MAXIMUM_LUMINANCE: Tag = Tag(0x0028, 0x701E);
+/// (0028,701F) Luminance Value
+pub const LUMINANCE_VALUE: Tag = Tag(0x0028, 0x701F);
+/// (0028,7020) Luminance Response Description
+pub const LUMINANCE_RESPONSE_DESCRIPTION: Tag = Tag(0x0028, 0x7020);
+/// (0028,7021) White Point Flag
+pub const WHITE_POINT_FLAG: Tag = Tag(0x0028, 0x7021);
+/// (0028,7022) Display Device Type Code Sequence
+pub const DISPLAY_DEVICE_TYPE_CODE_SEQUENCE: Tag = Tag(0x0028, 0x7022);
+/// (0028,7023) Display Subsystem Sequence
+pub const DISPLAY_SUBSYSTEM_SEQUENCE: Tag = Tag(0x0028, 0x7023);
+/// (0028,7024) Luminance Result Sequence
+pub const LUMINANCE_RESULT_SEQUENCE: Tag = Tag(0x0028, 0x7024);
+/// (0028,7025) Ambient Light Value Source
+pub const AMBIENT_LIGHT_VALUE_SOURCE: Tag = Tag(0x0028, 0x7025);
+/// (0028,7026) Measured Characteristics
+pub const MEASURED_CHARACTERISTICS: Tag = Tag(0x0028, 0x7026);
+/// (0028,7027) Luminance Uniformity Result Sequence
+pub const LUMINANCE_UNIFORMITY_RESULT_SEQUENCE: Tag = Tag(0x0028, 0x7027);
+/// (0028,7028) Visual Evaluation Test Sequence
+pub const VISUAL_EVALUATION_TEST_SEQUENCE: Tag = Tag(0x0028, 0x7028);
+/// (0028,7029) Test Result
+pub const TEST_RESULT: Tag = Tag(0x0028, 0x7029);
+/// (0028,702A) Test Result Comment
+pub const TEST_RESULT_COMMENT: Tag = Tag(0x0028, 0x702A);
+/// (0028,702B) Test Image Validation
+pub const TEST_IMAGE_VALIDATION: Tag = Tag(0x0028, 0x702B);
+/// (0028,702C) Test Pattern Code Sequence
+pub const TEST_PATTERN_CODE_SEQUENCE: Tag = Tag(0x0028, 0x702C);
+/// (0028,702D) Measurement Pattern Code Sequence
+pub const MEASUREMENT_PATTERN_CODE_SEQUENCE: Tag = Tag(0x0028, 0x702D);
+/// (0028,702E) Visual Evaluation Method Code Sequence
+pub const VISUAL_EVALUATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0028, 0x702E);
+/// (0028,7FE0) Pixel Data Provider URL
+pub const PIXEL_DATA_PROVIDER_URL: Tag = Tag(0x0028, 0x7FE0);
+/// (0028,9001) Data Point Rows
+pub const DATA_POINT_ROWS: Tag = Tag(0x0028, 0x9001);
+/// (0028,9002) Data Point Columns
+pub const DATA_POINT_COLUMNS: Tag = Tag(0x0028, 0x9002);
+/// (0028,9003) Signal Domain Columns
+pub const SIGNAL_DOMAIN_COLUMNS: Tag = Tag(0x0028, 0x9003);
+/// (0028,9099) Largest Monochrome Pixel Value (retired)
+pub const LARGEST_MONOCHROME_PIXEL_VALUE: Tag = Tag(0x0028, 0x9099);
+/// (0028,9108) Data Representation
+pub const DATA_REPRESENTATION: Tag = Tag(0x0028, 0x9108);
+/// (0028,9110) Pixel Measures Sequence
+pub const PIXEL_MEASURES_SEQUENCE: Tag = Tag(0x0028, 0x9110);
+/// (0028,9132) Frame VOILUT Sequence
+pub const FRAME_VOILUT_SEQUENCE: Tag = Tag(0x0028, 0x9132);
+/// (0028,9145) Pixel Value Transformation Sequence
+pub const PIXEL_VALUE_TRANSFORMATION_SEQUENCE: Tag = Tag(0x0028, 0x9145);
+/// (0028,9235) Signal Domain Rows
+pub const SIGNAL_DOMAIN_ROWS: Tag = Tag(0x0028, 0x9235);
+/// (0028,9411) Display Filter Percentage
+pub const DISPLAY_FILTER_PERCENTAGE: Tag = Tag(0x0028, 0x9411);
+/// (0028,9415) Frame Pixel Shift Sequence
+pub const FRAME_PIXEL_SHIFT_SEQUENCE: Tag = Tag(0x0028, 0x9415);
+/// (0028,9416) Subtraction Item ID
+pub const SUBTRACTION_ITEM_ID: Tag = Tag(0x0028, 0x9416);
+/// (0028,9422) Pixel Intensity Relationship LUT Sequence
+pub const PIXEL_INTENSITY_RELATIONSHIP_LUT_SEQUENCE: Tag = Tag(0x0028, 0x9422);
+/// (0028,9443) Frame Pixel Data Properties Sequence
+pub const FRAME_PIXEL_DATA_PROPERTIES_SEQUENCE: Tag = Tag(0x0028, 0x9443);
+/// (0028,9444) Geometrical Properties
+pub const GEOMETRICAL_PROPERTIES: Tag = Tag(0x0028, 0x9444);
+/// (0028,9445) Geometric Maximum Distortion
+pub const GEOMETRIC_MAXIMUM_DISTORTION: Tag = Tag(0x0028, 0x9445);
+/// (0028,9446) Image Processing Applied
+pub const IMAGE_PROCESSING_APPLIED: Tag = Tag(0x0028, 0x9446);
+/// (0028,9454) Mask Selection Mode
+pub const MASK_SELECTION_MODE: Tag = Tag(0x0028, 0x9454);
+/// (0028,9474) LUT Function
+pub const LUT_FUNCTION: Tag = Tag(0x0028, 0x9474);
+/// (0028,9478) Mask Visibility Percentage
+pub const MASK_VISIBILITY_PERCENTAGE: Tag = Tag(0x0028, 0x9478);
+/// (0028,9501) Pixel Shift Sequence
+pub const PIXEL_SHIFT_SEQUENCE: Tag = Tag(0x0028, 0x9501);
+/// (0028,9502) Region Pixel Shift Sequence
+pub const REGION_PIXEL_SHIFT_SEQUENCE: Tag = Tag(0x0028, 0x9502);
+/// (0028,9503) Vertices of the Region
+pub const VERTICES_OF_THE_REGION: Tag = Tag(0x0028, 0x9503);
+/// (0028,9505) Multi-frame Presentation Sequence
+pub const MULTI_FRAME_PRESENTATION_SEQUENCE: Tag = Tag(0x0028, 0x9505);
+/// (0028,9506) Pixel Shift Frame Range
+pub const PIXEL_SHIFT_FRAME_RANGE: Tag = Tag(0x0028, 0x9506);
+/// (0028,9507) LUT Frame Range
+pub const LUT_FRAME_RANGE: Tag = Tag(0x0028, 0x9507);
+/// (0028,9520) Image to Equipment Mapping Matrix
+pub const IMAGE_TO_EQUIPMENT_MAPPING_MATRIX: Tag = Tag(0x0028, 0x9520);
+/// (0028,9537) Equipment Coordinate System Identification
+pub const EQUIPMENT_COORDINATE_SYSTEM_IDENTIFICATION: Tag = Tag(0x0028, 0x9537);
+/// (0032,000A) Study Status ID (retired)
+pub const STUDY_STATUS_ID: Tag = Tag(0x0032, 0x000A);
+/// (0032,000C) Study Priority ID (retired)
+pub const STUDY_PRIORITY_ID: Tag = Tag(0x0032, 0x000C);
+/// (0032,0012) Study ID Issuer (retired)
+pub const STUDY_ID_ISSUER: Tag = Tag(0x0032, 0x0012);
+/// (0032,0032) Study Verified Date (retired)
+pub const STUDY_VERIFIED_DATE: Tag = Tag(0x0032, 0x0032);
+/// (0032,0033) Study Verified Time (retired)
+pub const STUDY_VERIFIED_TIME: Tag = Tag(0x0032, 0x0033);
+/// (0032,0034) Study Read Date (retired)
+pub const STUDY_READ_DATE: Tag = Tag(0x0032, 0x0034);
+/// (0032,0035) Study Read Time (retired)
+pub const STUDY_READ_TIME: Tag = Tag(0x0032, 0x0035);
+/// (0032,1000) Scheduled Study Start Date (retired)
+pub const SCHEDULED_STUDY_START_DATE: Tag = Tag(0x0032, 0x1000);
+/// (0032,1001) Scheduled Study Start Time (retired)
+pub const SCHEDULED_STUDY_START_TIME: Tag = Tag(0x0032, 0x1001);
+/// (0032,1010) Scheduled Study Stop Date (retired)
+pub const SCHEDULED_STUDY_STOP_DATE: Tag = Tag(0x0032, 0x1010);
+/// (0032,1011) Scheduled Study Stop Time (retired)
+pub const SCHEDULED_STUDY_STOP_TIME: Tag = Tag(0x0032, 0x1011);
+/// (0032,1020) Scheduled Study Location (retired)
+pub const SCHEDULED_STUDY_LOCATION: Tag = Tag(0x0032, 0x1020);
+/// (0032,1021) Scheduled Study Location AE Title (retired)
+pub const SCHEDULED_STUDY_LOCATION_AE_TITLE: Tag = Tag(0x0032, 0x1021);
+/// (0032,1030) Reason for Study (retired)
+pub const REASON_FOR_STUDY: Tag = Tag(0x0032, 0x1030);
+/// (0032,1031) Requesting Physician Identification Sequence
+pub const REQUESTING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0032, 0x1031);
+/// (0032,1032) Requesting Physician
+pub const REQUESTING_PHYSICIAN: Tag = Tag(0x0032, 0x1032);
+/// (0032,1033) Requesting Service
+pub const REQUESTING_SERVICE: Tag = Tag(0x0032, 0x1033);
+/// (0032,1034) Requesting Service Code Sequence
+pub const REQUESTING_SERVICE_CODE_SEQUENCE: Tag = Tag(0x0032, 0x1034);
+/// (0032,1040) Study Arrival Date (retired)
+pub const STUDY_ARRIVAL_DATE: Tag = Tag(0x0032, 0x1040);
+/// (0032,1041) Study Arrival Time (retired)
+pub const STUDY_ARRIVAL_TIME: Tag = Tag(0x0032, 0x1041);
+/// (0032,1050) Study Completion Date (retired)
+pub const STUDY_COMPLETION_DATE: Tag = Tag(0x0032, 0x1050);
+/// (0032,1051) Study Completion Time (retired)
+pub const STUDY_COMPLETION_TIME: Tag = Tag(0x0032, 0x1051);
+/// (0032,1055) Study Component Status ID (retired)
+pub const STUDY_COMPONENT_STATUS_ID: Tag = Tag(0x0032, 0x1055);
+/// (0032,1060) Requested Procedure Description
+pub const REQUESTED_PROCEDURE_DESCRIPTION: Tag = Tag(0x0032, 0x1060);
+/// (0032,1064) Requested Procedure Code Sequence
+pub const REQUESTED_PROCEDURE_CODE_SEQUENCE: Tag = Tag(0x0032, 0x1064);
+/// (0032,1066) Reason for Visit
+pub const REASON_FOR_VISIT: Tag = Tag(0x0032, 0x1066);
+/// (0032,1067) Reason for Visit Code Sequence
+pub const REASON_FOR_VISIT_CODE_SEQUENCE: Tag = Tag(0x0032, 0x1067);
+/// (0032,1070) Requested Contrast Agent
+pub const REQUESTED_CONTRAST_AGENT: Tag = Tag(0x0032, 0x1070);
+/// (0032,4000) Study Comments (retired)
+pub const STUDY_COMMENTS: Tag = Tag(0x0032, 0x4000);
+/// (0038,0004) Referenced Patient Alias Sequence
+pub const REFERENCED_PATIENT_ALIAS_SEQUENCE: Tag = Tag(0x0038, 0x0004);
+/// (0038,0008) Visit Status ID
+pub const VISIT_STATUS_ID: Tag = Tag(0x0038, 0x0008);
+/// (0038,0010) Admission ID
+pub const ADMISSION_ID: Tag = Tag(0x0038, 0x0010);
+/// (0038,0011) Issuer of Admission ID (retired)
+pub const ISSUER_OF_ADMISSION_ID: Tag = Tag(0x0038, 0x0011);
+/// (0038,0014) Issuer of Admission ID Sequence
+pub const ISSUER_OF_ADMISSION_ID_SEQUENCE: Tag = Tag(0x0038, 0x0014);
+/// (0038,0016) Route of Admissions
+pub const ROUTE_OF_ADMISSIONS: Tag = Tag(0x0038, 0x0016);
+/// (0038,001A) Scheduled Admission Date (retired)
+pub const SCHEDULED_ADMISSION_DATE: Tag = Tag(0x0038, 0x001A);
+/// (0038,001B) Scheduled Admission Time (retired)
+pub const SCHEDULED_ADMISSION_TIME: Tag = Tag(0x0038, 0x001B);
+/// (0038,001C) Scheduled Discharge Date (retired)
+pub const SCHEDULED_DISCHARGE_DATE: Tag = Tag(0x0038, 0x001C);
+/// (0038,001D) Scheduled Discharge Time (retired)
+pub const SCHEDULED_DISCHARGE_TIME: Tag = Tag(0x0038, 0x001D);
+/// (0038,001E) Scheduled Patient Institution Residence (retired)
+pub const SCHEDULED_PATIENT_INSTITUTION_RESIDENCE: Tag = Tag(0x0038, 0x001E);
+/// (0038,0020) Admitting Date
+pub const ADMITTING_DATE: Tag = Tag(0x0038, 0x0020);
+/// (0038,0021) Admitting Time
+pub const ADMITTING_TIME: Tag = Tag(0x0038, 0x0021);
+/// (0038,0030) Discharge Date (retired)
+pub const DISCHARGE_DATE: Tag = Tag(0x0038, 0x0030);
+/// (0038,0032) Discharge Time (retired)
+pub const DISCHARGE_TIME: Tag = Tag(0x0038, 0x0032);
+/// (0038,0040) Discharge Diagnosis Description (retired)
+pub const DISCHARGE_DIAGNOSIS_DESCRIPTION: Tag = Tag(0x0038, 0x0040);
+/// (0038,0044) Discharge Diagnosis Code Sequence (retired)
+pub const DISCHARGE_DIAGNOSIS_CODE_SEQUENCE: Tag = Tag(0x0038, 0x0044);
+/// (0038,0050) Special Needs
+pub const SPECIAL_NEEDS: Tag = Tag(0x0038, 0x0050);
+/// (0038,0060) Service Episode ID
+pub const SERVICE_EPISODE_ID: Tag = Tag(0x0038, 0x0060);
+/// (0038,0061) Issuer of Service Episode ID (retired)
+pub const ISSUER_OF_SERVICE_EPISODE_ID: Tag = Tag(0x0038, 0x0061);
+/// (0038,0062) Service Episode Description
+pub const SERVICE_EPISODE_DESCRIPTION: Tag = Tag(0x0038, 0x0062);
+/// (0038,0064) Issuer of Service Episode ID Sequence
+pub const ISSUER_OF_SERVICE_EPISODE_ID_SEQUENCE: Tag = Tag(0x0038, 0x0064);
+/// (0038,0100) Pertinent Documents Sequence
+pub const PERTINENT_DOCUMENTS_SEQUENCE: Tag = Tag(0x0038, 0x0100);
+/// (0038,0101) Pertinent Resources Sequence
+pub const PERTINENT_RESOURCES_SEQUENCE: Tag = Tag(0x0038, 0x0101);
+/// (0038,0102) Resource Description
+pub const RESOURCE_DESCRIPTION: Tag = Tag(0x0038, 0x0102);
+/// (0038,0300) Current Patient Location
+pub const CURRENT_PATIENT_LOCATION: Tag = Tag(0x0038, 0x0300);
+/// (0038,0400) Patient Institution Residence
+pub const PATIENT_INSTITUTION_RESIDENCE: Tag = Tag(0x0038, 0x0400);
+/// (0038,0500) Patient State
+pub const PATIENT_STATE: Tag = Tag(0x0038, 0x0500);
+/// (0038,0502) Patient Clinical Trial Participation Sequence
+pub const PATIENT_CLINICAL_TRIAL_PARTICIPATION_SEQUENCE: Tag = Tag(0x0038, 0x0502);
+/// (0038,4000) Visit Comments
+pub const VISIT_COMMENTS: Tag = Tag(0x0038, 0x4000);
+/// (003A,0004) Waveform Originality
+pub const WAVEFORM_ORIGINALITY: Tag = Tag(0x003A, 0x0004);
+/// (003A,0005) Number of Waveform Channels
+pub const NUMBER_OF_WAVEFORM_CHANNELS: Tag = Tag(0x003A, 0x0005);
+/// (003A,0010) Number of Waveform Samples
+pub const NUMBER_OF_WAVEFORM_SAMPLES: Tag = Tag(0x003A, 0x0010);
+/// (003A,001A) Sampling Frequency
+pub const SAMPLING_FREQUENCY: Tag = Tag(0x003A, 0x001A);
+/// (003A,0020) Multiplex Group Label
+pub const MULTIPLEX_GROUP_LABEL: Tag = Tag(0x003A, 0x0020);
+/// (003A,0200) Channel Definition Sequence
+pub const CHANNEL_DEFINITION_SEQUENCE: Tag = Tag(0x003A, 0x0200);
+/// (003A,0202) Waveform Channel Number
+pub const WAVEFORM_CHANNEL_NUMBER: Tag = Tag(0x003A, 0x0202);
+/// (003A,0203) Channel Label
+pub const CHANNEL_LABEL: Tag = Tag(0x003A, 0x0203);
+/// (003A,0205) Channel Status
+pub const CHANNEL_STATUS: Tag = Tag(0x003A, 0x0205);
+/// (003A,0208) Channel Source Sequence
+pub const CHANNEL_SOURCE_SEQUENCE: Tag = Tag(0x003A, 0x0208);
+/// (003A,0209) Channel Source Modifiers Sequence
+pub const CHANNEL_SOURCE_MODIFIERS_SEQUENCE: Tag = Tag(0x003A, 0x0209);
+/// (003A,020A) Source Waveform Sequence
+pub const SOURCE_WAVEFORM_SEQUENCE: Tag = Tag(0x003A, 0x020A);
+/// (003A,020C) Channel Derivation Description
+pub const CHANNEL_DERIVATION_DESCRIPTION: Tag = Tag(0x003A, 0x020C);
+/// (003A,0210) Channel Sensitivity
+pub const CHANNEL_SENSITIVITY: Tag = Tag(0x003A, 0x0210);
+/// (003A,0211) Channel Sensitivity Units Sequence
+pub const CHANNEL_SENSITIVITY_UNITS_SEQUENCE: Tag = Tag(0x003A, 0x0211);
+/// (003A,0212) Channel Sensitivity Correction Factor
+pub const CHANNEL_SENSITIVITY_CORRECTION_FACTOR: Tag = Tag(0x003A, 0x0212);
+/// (003A,0213) Channel Baseline
+pub const CHANNEL_BASELINE: Tag = Tag(0x003A, 0x0213);
+/// (003A,0214) Channel Time Skew
+pub const CHANNEL_TIME_SKEW: Tag = Tag(0x003A, 0x0214);
+/// (003A,0215) Channel Sample Skew
+pub const CHANNEL_SAMPLE_SKEW: Tag = Tag(0x003A, 0x0215);
+/// (003A,0218) Channel Offset
+pub const CHANNEL_OFFSET: Tag = Tag(0x003A, 0x0218);
+/// (003A,021A) Waveform Bits Stored
+pub const WAVEFORM_BITS_STORED: Tag = Tag(0x003A, 0x021A);
+/// (003A,0220) Filter Low Frequency
+pub const FILTER_LOW_FREQUENCY: Tag = Tag(0x003A, 0x0220);
+/// (003A,0221) Filter High Frequency
+pub const FILTER_HIGH_FREQUENCY: Tag = Tag(0x003A, 0x0221);
+/// (003A,0222) Notch Filter Frequency
+pub const NOTCH_FILTER_FREQUENCY: Tag = Tag(0x003A, 0x0222);
+/// (003A,0223) Notch Filter Bandwidth
+pub const NOTCH_FILTER_BANDWIDTH: Tag = Tag(0x003A, 0x0223);
+/// (003A,0230) Waveform Data Display Scale
+pub const WAVEFORM_DATA_DISPLAY_SCALE: Tag = Tag(0x003A, 0x0230);
+/// (003A,0231) Waveform Display Background CIE Lab Value
+pub const WAVEFORM_DISPLAY_BACKGROUND_CIE_LAB_VALUE: Tag = Tag(0x003A, 0x0231);
+/// (003A,0240) Waveform Presentation Group Sequence
+pub const WAVEFORM_PRESENTATION_GROUP_SEQUENCE: Tag = Tag(0x003A, 0x0240);
+/// (003A,0241) Presentation Group Number
+pub const PRESENTATION_GROUP_NUMBER: Tag = Tag(0x003A, 0x0241);
+/// (003A,0242) Channel Display Sequence
+pub const CHANNEL_DISPLAY_SEQUENCE: Tag = Tag(0x003A, 0x0242);
+/// (003A,0244) Channel Recommended Display CIE Lab Value
+pub const CHANNEL_RECOMMENDED_DISPLAY_CIE_LAB_VALUE: Tag = Tag(0x003A, 0x0244);
+/// (003A,0245) Channel Position
+pub const CHANNEL_POSITION: Tag = Tag(0x003A, 0x0245);
+/// (003A,0246) Display Shading Flag
+pub const DISPLAY_SHADING_FLAG: Tag = Tag(0x003A, 0x0246);
+/// (003A,0247) Fractional Channel Display Scale
+pub const FRACTIONAL_CHANNEL_DISPLAY_SCALE: Tag = Tag(0x003A, 0x0247);
+/// (003A,0248) Absolute Channel Display Scale
+pub const ABSOLUTE_CHANNEL_DISPLAY_SCALE: Tag = Tag(0x003A, 0x0248);
+/// (003A,0300) Multiplexed Audio Channels Description Code Sequence
+pub const MULTIPLEXED_AUDIO_CHANNELS_DESCRIPTION_CODE_SEQUENCE: Tag = Tag(0x003A, 0x0300);
+/// (003A,0301) Channel Identification Code
+pub const CHANNEL_IDENTIFICATION_CODE: Tag = Tag(0x003A, 0x0301);
+/// (003A,0302) Channel Mode
+pub const CHANNEL_MODE: Tag = Tag(0x003A, 0x0302);
+/// (0040,0001) Scheduled Station AE Title
+pub const SCHEDULED_STATION_AE_TITLE: Tag = Tag(0x0040, 0x0001);
+/// (0040,0002) Scheduled Procedure Step Start Date
+pub const SCHEDULED_PROCEDURE_STEP_START_DATE: Tag = Tag(0x0040, 0x0002);
+/// (0040,0003) Scheduled Procedure Step Start Time
+pub const SCHEDULED_PROCEDURE_STEP_START_TIME: Tag = Tag(0x0040, 0x0003);
+/// (0040,0004) Scheduled Procedure Step End Date
+pub const SCHEDULED_PROCEDURE_STEP_END_DATE: Tag = Tag(0x0040, 0x0004);
+/// (0040,0005) Scheduled Procedure Step End Time
+pub const SCHEDULED_PROCEDURE_STEP_END_TIME: Tag = Tag(0x0040, 0x0005);
+/// (0040,0006) Scheduled Performing Physician's Name
+pub const SCHEDULED_PERFORMING_PHYSICIAN_NAME: Tag = Tag(0x0040, 0x0006);
+/// (0040,0007) Scheduled Procedure Step Description
+pub const SCHEDULED_PROCEDURE_STEP_DESCRIPTION: Tag = Tag(0x0040, 0x0007);
+/// (0040,0008) Scheduled Protocol Code Sequence
+pub const SCHEDULED_PROTOCOL_CODE_SEQUENCE: Tag = Tag(0x0040, 0x0008);
+/// (0040,0009) Scheduled Procedure Step ID
+pub const SCHEDULED_PROCEDURE_STEP_ID: Tag = Tag(0x0040, 0x0009);
+/// (0040,000A) Stage Code Sequence
+pub const STAGE_CODE_SEQUENCE: Tag = Tag(0x0040, 0x000A);
+/// (0040,000B) Scheduled Performing Physician Identification Sequence
+pub const SCHEDULED_PERFORMING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0040, 0x000B);
+/// (0040,0010) Scheduled Station Name
+pub const SCHEDULED_STATION_NAME: Tag = Tag(0x0040, 0x0010);
+/// (0040,0011) Scheduled Procedure Step Location
+pub const SCHEDULED_PROCEDURE_STEP_LOCATION: Tag = Tag(0x0040, 0x0011);
+/// (0040,0012) Pre Medication
+pub const PRE_MEDICATION: Tag = Tag(0x0040, 0x0012);
+/// (0040,0020) Scheduled Procedure Step Status
+pub const SCHEDULED_PROCEDURE_STEP_STATUS: Tag = Tag(0x0040, 0x0020);
+/// (0040,0026) Order Placer Identifier Sequence
+pub const ORDER_PLACER_IDENTIFIER_SEQUENCE: Tag = Tag(0x0040, 0x0026);
+/// (0040,0027) Order Filler Identifier Sequence
+pub const ORDER_FILLER_IDENTIFIER_SEQUENCE: Tag = Tag(0x0040, 0x0027);
+/// (0040,0031) Local Namespace Entity ID
+pub const LOCAL_NAMESPACE_ENTITY_ID: Tag = Tag(0x0040, 0x0031);
+/// (0040,0032) Universal Entity ID
+pub const UNIVERSAL_ENTITY_ID: Tag = Tag(0x0040, 0x0032);
+/// (0040,0033) Universal Entity ID Type
+pub const UNIVERSAL_ENTITY_ID_TYPE: Tag = Tag(0x0040, 0x0033);
+/// (0040,0035) Identifier Type Code
+pub const IDENTIFIER_TYPE_CODE: Tag = Tag(0x0040, 0x0035);
+/// (0040,0036) Assigning Facility Sequence
+pub const ASSIGNING_FACILITY_SEQUENCE: Tag = Tag(0x0040, 0x0036);
+/// (0040,0039) Assigning Jurisdiction Code Sequence
+pub const ASSIGNING_JURISDICTION_CODE_SEQUENCE: Tag = Tag(0x0040, 0x0039);
+/// (0040,003A) Assigning Agency or Department Code Sequence
+pub const ASSIGNING_AGENCY_OR_DEPARTMENT_CODE_SEQUENCE: Tag = Tag(0x0040, 0x003A);
+/// (0040,0100) Scheduled Procedure Step Sequence
+pub const SCHEDULED_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x0040, 0x0100);
+/// (0040,0220) Referenced Non Image Composite SOP Instance Sequence
+pub const REFERENCED_NON_IMAGE_COMPOSITE_SOP_INSTANCE_SEQUENCE: Tag = Tag(0x0040, 0x0220);
+/// (0040,0241) Performed Station AE Title
+pub const PERFORMED_STATION_AE_TITLE: Tag = Tag(0x0040, 0x0241);
+/// (0040,0242) Performed Station Name
+pub const PERFORMED_STATION_NAME: Tag = Tag(0x0040, 0x0242);
+/// (0040,0243) Performed Location
+pub const PERFORMED_LOCATION: Tag = Tag(0x0040, 0x0243);
+/// (0040,0244) Performed Procedure Step Start Date
+pub const PERFORMED_PROCEDURE_STEP_START_DATE: Tag = Tag(0x0040, 0x0244);
+/// (0040,0245) Performed Procedure Step Start Time
+pub const PERFORMED_PROCEDURE_STEP_START_TIME: Tag = Tag(0x0040, 0x0245);
+/// (0040,0250) Performed Procedure Step End Date
+pub const PERFORMED_PROCEDURE_STEP_END_DATE: Tag = Tag(0x0040, 0x0250);
+/// (0040,0251) Performed Procedure Step End Time
+pub const PERFORMED_PROCEDURE_STEP_END_TIME: Tag = Tag(0x0040, 0x0251);
+/// (0040,0252) Performed Procedure Step Status
+pub const PERFORMED_PROCEDURE_STEP_STATUS: Tag = Tag(0x0040, 0x0252);
+/// (0040,0253) Performed Procedure Step ID
+pub const PERFORMED_PROCEDURE_STEP_ID: Tag = Tag(0x0040, 0x0253);
+/// (0040,0254) Performed Procedure Step Description
+pub const PERFORMED_PROCEDURE_STEP_DESCRIPTION: Tag = Tag(0x0040, 0x0254);
+/// (0040,0255) Performed Procedure Type Description
+pub const PERFORMED_PROCEDURE_TYPE_DESCRIPTION: Tag = Tag(0x0040, 0x0255);
+/// (0040,0260) Performed Protocol Code Sequence
+pub const PERFORMED_PROTOCOL_CODE_SEQUENCE: Tag = Tag(0x0040, 0x0260);
+/// (0040,0261) Performed Protocol Type
+pub const PERFORMED_PROTOCOL_TYPE: Tag = Tag(0x0040, 0x0261);
+/// (0040,0270) Scheduled Step Attributes Sequence
+pub const SCHEDULED_STEP_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0040, 0x0270);
+/// (0040,0275) Request Attributes Sequence
+pub const REQUEST_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0040, 0x0275);
+/// (0040,0280) Comments on the Performed Procedure Step
+pub const COMMENTS_ON_THE_PERFORMED_PROCEDURE_STEP: Tag = Tag(0x0040, 0x0280);
+/// (0040,0281) Performed Procedure Step Discontinuation Reason Code Sequence
+pub const PERFORMED_PROCEDURE_STEP_DISCONTINUATION_REASON_CODE_SEQUENCE: Tag = Tag(0x0040, 0x0281);
+/// (0040,0293) Quantity Sequence
+pub const QUANTITY_SEQUENCE: Tag = Tag(0x0040, 0x0293);
+/// (0040,0294) Quantity
+pub const QUANTITY: Tag = Tag(0x0040, 0x0294);
+/// (0040,0295) Measuring Units Sequence
+pub const MEASURING_UNITS_SEQUENCE: Tag = Tag(0x0040, 0x0295);
+/// (0040,0296) Billing Item Sequence
+pub const BILLING_ITEM_SEQUENCE: Tag = Tag(0x0040, 0x0296);
+/// (0040,0300) Total Time of Fluoroscopy (retired)
+pub const TOTAL_TIME_OF_FLUOROSCOPY: Tag = Tag(0x0040, 0x0300);
+/// (0040,0301) Total Number of Exposures (retired)
+pub const TOTAL_NUMBER_OF_EXPOSURES: Tag = Tag(0x0040, 0x0301);
+/// (0040,0302) Entrance Dose
+pub const ENTRANCE_DOSE: Tag = Tag(0x0040, 0x0302);
+/// (0040,0303) Exposed Area
+pub const EXPOSED_AREA: Tag = Tag(0x0040, 0x0303);
+/// (0040,0306) Distance Source to Entrance
+pub const DISTANCE_SOURCE_TO_ENTRANCE: Tag = Tag(0x0040, 0x0306);
+/// (0040,0307) Distance Source to Support (retired)
+pub const DISTANCE_SOURCE_TO_SUPPORT: Tag = Tag(0x0040, 0x0307);
+/// (0040,030E) Exposure Dose Sequence (retired)
+pub const EXPOSURE_DOSE_SEQUENCE: Tag = Tag(0x0040, 0x030E);
+/// (0040,0310) Comments on Radiation Dose
+pub const COMMENTS_ON_RADIATION_DOSE: Tag = Tag(0x0040, 0x0310);
+/// (0040,0312) X-Ray Output
+pub const X_RAY_OUTPUT: Tag = Tag(0x0040, 0x0312);
+/// (0040,0314) Half Value Layer
+pub const HALF_VALUE_LAYER: Tag = Tag(0x0040, 0x0314);
+/// (0040,0316) Organ Dose
+pub const ORGAN_DOSE: Tag = Tag(0x0040, 0x0316);
+/// (0040,0318) Organ Exposed
+pub const ORGAN_EXPOSED: Tag = Tag(0x0040, 0x0318);
+/// (0040,0320) Billing Procedure Step Sequence
+pub const BILLING_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x0040, 0x0320);
+/// (0040,0321) Film Consumption Sequence
+pub const FILM_CONSUMPTION_SEQUENCE: Tag = Tag(0x0040, 0x0321);
+/// (0040,0324) Billing Supplies and Devices Sequence
+pub const BILLING_SUPPLIES_AND_DEVICES_SEQUENCE: Tag = Tag(0x0040, 0x0324);
+/// (0040,0330) Referenced Procedure Step Sequence (retired)
+pub const REFERENCED_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x0040, 0x0330);
+/// (0040,0340) Performed Series Sequence
+pub const PERFORMED_SERIES_SEQUENCE: Tag = Tag(0x0040, 0x0340);
+/// (0040,0400) Comments on the Scheduled Procedure Step
+pub const COMMENTS_ON_THE_SCHEDULED_PROCEDURE_STEP: Tag = Tag(0x0040, 0x0400);
+/// (0040,0440) Protocol Context Sequence
+pub const PROTOCOL_CONTEXT_SEQUENCE: Tag = Tag(0x0040, 0x0440);
+/// (0040,0441) Content Item Modifier Sequence
+pub const CONTENT_ITEM_MODIFIER_SEQUENCE: Tag = Tag(0x0040, 0x0441);
+/// (0040,0500) Scheduled Specimen Sequence
+pub const SCHEDULED_SPECIMEN_SEQUENCE: Tag = Tag(0x0040, 0x0500);
+/// (0040,050A) Specimen Accession Number (retired)
+pub const SPECIMEN_ACCESSION_NUMBER: Tag = Tag(0x0040, 0x050A);
+/// (0040,0512) Container Identifier
+pub const CONTAINER_IDENTIFIER: Tag = Tag(0x0040, 0x0512);
+/// (0040,0513) Issuer of the Container Identifier Sequence
+pub const ISSUER_OF_THE_CONTAINER_IDENTIFIER_SEQUENCE: Tag = Tag(0x0040, 0x0513);
+/// (0040,0515) Alternate Container Identifier Sequence
+pub const ALTERNATE_CONTAINER_IDENTIFIER_SEQUENCE: Tag = Tag(0x0040, 0x0515);
+/// (0040,0518) Container Type Code Sequence
+pub const CONTAINER_TYPE_CODE_SEQUENCE: Tag = Tag(0x0040, 0x0518);
+/// (0040,051A) Container Description
+pub const CONTAINER_DESCRIPTION: Tag = Tag(0x0040, 0x051A);
+/// (0040,0520) Container Component Sequence
+pub const CONTAINER_COMPONENT_SEQUENCE: Tag = Tag(0x0040, 0x0520);
+/// (0040,0550) Specimen Sequence (retired)
+pub const SPECIMEN_SEQUENCE: Tag = Tag(0x0040, 0x0550);
+/// (0040,0551) Specimen Identifier
+pub const SPECIMEN_IDENTIFIER: Tag = Tag(0x0040, 0x0551);
+/// (0040,0552) Specimen Description Sequence Trial (retired)
+pub const SPECIMEN_DESCRIPTION_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0x0552);
+/// (0040,0553) Specimen Description Trial (retired)
+pub const SPECIMEN_DESCRIPTION_TRIAL: Tag = Tag(0x0040, 0x0553);
+/// (0040,0554) Specimen UID
+pub const SPECIMEN_UID: Tag = Tag(0x0040, 0x0554);
+/// (0040,0555) Acquisition Context Sequence
+pub const ACQUISITION_CONTEXT_SEQUENCE: Tag = Tag(0x0040, 0x0555);
+/// (0040,0556) Acquisition Context Description
+pub const ACQUISITION_CONTEXT_DESCRIPTION: Tag = Tag(0x0040, 0x0556);
+/// (0040,0560) Specimen Description Sequence
+pub const SPECIMEN_DESCRIPTION_SEQUENCE: Tag = Tag(0x0040, 0x0560);
+/// (0040,0562) Issuer of the Specimen Identifier Sequence
+pub const ISSUER_OF_THE_SPECIMEN_IDENTIFIER_SEQUENCE: Tag = Tag(0x0040, 0x0562);
+/// (0040,059A) Specimen Type Code Sequence
+pub const SPECIMEN_TYPE_CODE_SEQUENCE: Tag = Tag(0x0040, 0x059A);
+/// (0040,0600) Specimen Short Description
+pub const SPECIMEN_SHORT_DESCRIPTION: Tag = Tag(0x0040, 0x0600);
+/// (0040,0602) Specimen Detailed Description
+pub const SPECIMEN_DETAILED_DESCRIPTION: Tag = Tag(0x0040, 0x0602);
+/// (0040,0610) Specimen Preparation Sequence
+pub const SPECIMEN_PREPARATION_SEQUENCE: Tag = Tag(0x0040, 0x0610);
+/// (0040,0612) Specimen Preparation Step Content Item Sequence
+pub const SPECIMEN_PREPARATION_STEP_CONTENT_ITEM_SEQUENCE: Tag = Tag(0x0040, 0x0612);
+/// (0040,0620) Specimen Localization Content Item Sequence
+pub const SPECIMEN_LOCALIZATION_CONTENT_ITEM_SEQUENCE: Tag = Tag(0x0040, 0x0620);
+/// (0040,06FA) Slide Identifier (retired)
+pub const SLIDE_IDENTIFIER: Tag = Tag(0x0040, 0x06FA);
+/// (0040,0710) Whole Slide Microscopy Image Frame Type Sequence
+pub const WHOLE_SLIDE_MICROSCOPY_IMAGE_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0040, 0x0710);
+/// (0040,071A) Image Center Point Coordinates Sequence
+pub const IMAGE_CENTER_POINT_COORDINATES_SEQUENCE: Tag = Tag(0x0040, 0x071A);
+/// (0040,072A) X Offset in Slide Coordinate System
+pub const X_OFFSET_IN_SLIDE_COORDINATE_SYSTEM: Tag = Tag(0x0040, 0x072A);
+/// (0040,073A) Y Offset in Slide Coordinate System
+pub const Y_OFFSET_IN_SLIDE_COORDINATE_SYSTEM: Tag = Tag(0x0040, 0x073A);
+/// (0040,074A) Z Offset in Slide Coordinate System
+pub const Z_OFFSET_IN_SLIDE_COORDINATE_SYSTEM: Tag = Tag(0x0040, 0x074A);
+/// (0040,08D8) Pixel Spacing Sequence (retired)
+pub const PIXEL_SPACING_SEQUENCE: Tag = Tag(0x0040, 0x08D8);
+/// (0040,08DA) Coordinate System Axis Code Sequence (retired)
+pub const COORDINATE_SYSTEM_AXIS_CODE_SEQUENCE: Tag = Tag(0x0040, 0x08DA);
+/// (0040,08EA) Measurement Units Code Sequence
+pub const MEASUREMENT_UNITS_CODE_SEQUENCE: Tag = Tag(0x0040, 0x08EA);
+/// (0040,09F8) Vital Stain Code Sequence Trial (retired)
+pub const VITAL_STAIN_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0x09F8);
+/// (0040,1001) Requested Procedure ID
+pub const REQUESTED_PROCEDURE_ID: Tag = Tag(0x0040, 0x1001);
+/// (0040,1002) Reason for the Requested Procedure
+pub const REASON_FOR_THE_REQUESTED_PROCEDURE: Tag = Tag(0x0040, 0x1002);
+/// (0040,1003) Requested Procedure Priority
+pub const REQUESTED_PROCEDURE_PRIORITY: Tag = Tag(0x0040, 0x1003);
+/// (0040,1004) Patient Transport Arrangements
+pub const PATIENT_TRANSPORT_ARRANGEMENTS: Tag = Tag(0x0040, 0x1004);
+/// (0040,1005) Requested Procedure Location
+pub const REQUESTED_PROCEDURE_LOCATION: Tag = Tag(0x0040, 0x1005);
+/// (0040,1006) Placer Order Number Procedure (retired)
+pub const PLACER_ORDER_NUMBER_PROCEDURE: Tag = Tag(0x0040, 0x1006);
+/// (0040,1007) Filler Order Number Procedure (retired)
+pub const FILLER_ORDER_NUMBER_PROCEDURE: Tag = Tag(0x0040, 0x1007);
+/// (0040,1008) Confidentiality Code
+pub const CONFIDENTIALITY_CODE: Tag = Tag(0x0040, 0x1008);
+/// (0040,1009) Reporting Priority
+pub const REPORTING_PRIORITY: Tag = Tag(0x0040, 0x1009);
+/// (0040,100A) Reason for Requested Procedure Code Sequence
+pub const REASON_FOR_REQUESTED_PROCEDURE_CODE_SEQUENCE: Tag = Tag(0x0040, 0x100A);
+/// (0040,1010) Names of Intended Recipients of Results
+pub const NAMES_OF_INTENDED_RECIPIENTS_OF_RESULTS: Tag = Tag(0x0040, 0x1010);
+/// (0040,1011) Intended Recipients of Results Identification Sequence
+pub const INTENDED_RECIPIENTS_OF_RESULTS_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0040, 0x1011);
+/// (0040,1012) Reason for Performed Procedure Code Sequence
+pub const REASON_FOR_PERFORMED_PROCEDURE_CODE_SEQUENCE: Tag = Tag(0x0040, 0x1012);
+/// (0040,1060) Requested Procedure Description Trial (retired)
+pub const REQUESTED_PROCEDURE_DESCRIPTION_TRIAL: Tag = Tag(0x0040, 0x1060);
+/// (0040,1101) Person Identification Code Sequence
+pub const PERSON_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag(0x0040, 0x1101);
+/// (0040,1102) Person Address
+pub const PERSON_ADDRESS: Tag = Tag(0x0040, 0x1102);
+/// (0040,1103) Person Telephone Numbers
+pub const PERSON_TELEPHONE_NUMBERS: Tag = Tag(0x0040, 0x1103);
+/// (0040,1104) Person Telecom Information
+pub const PERSON_TELECOM_INFORMATION: Tag = Tag(0x0040, 0x1104);
+/// (0040,1400) Requested Procedure Comments
+pub const REQUESTED_PROCEDURE_COMMENTS: Tag = Tag(0x0040, 0x1400);
+/// (0040,2001) Reason for the Imaging Service Request (retired)
+pub const REASON_FOR_THE_IMAGING_SERVICE_REQUEST: Tag = Tag(0x0040, 0x2001);
+/// (0040,2004) Issue Date of Imaging Service Request
+pub const ISSUE_DATE_OF_IMAGING_SERVICE_REQUEST: Tag = Tag(0x0040, 0x2004);
+/// (0040,2005) Issue Time of Imaging Service Request
+pub const ISSUE_TIME_OF_IMAGING_SERVICE_REQUEST: Tag = Tag(0x0040, 0x2005);
+/// (0040,2006) Placer Order Number Imaging Service Request Retired (retired)
+pub const PLACER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST_RETIRED: Tag = Tag(0x0040, 0x2006);
+/// (0040,2007) Filler Order Number Imaging Service Request Retired (retired)
+pub const FILLER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST_RETIRED: Tag = Tag(0x0040, 0x2007);
+/// (0040,2008) Order Entered by
+pub const ORDER_ENTERED_BY: Tag = Tag(0x0040, 0x2008);
+/// (0040,2009) Order Enterer Location
+pub const ORDER_ENTERER_LOCATION: Tag = Tag(0x0040, 0x2009);
+/// (0040,2010) Order Callback Phone Number
+pub const ORDER_CALLBACK_PHONE_NUMBER: Tag = Tag(0x0040, 0x2010);
+/// (0040,2011) Order Callback Telecom Information
+pub const ORDER_CALLBACK_TELECOM_INFORMATION: Tag = Tag(0x0040, 0x2011);
+/// (0040,2016) Placer Order Number Imaging Service Request
+pub const PLACER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST: Tag = Tag(0x0040, 0x2016);
+/// (0040,2017) Filler Order Number Imaging Service Request
+pub const FILLER_ORDER_NUMBER_IMAGING_SERVICE_REQUEST: Tag = Tag(0x0040, 0x2017);
+/// (0040,2400) Imaging Service Request Comments
+pub const IMAGING_SERVICE_REQUEST_COMMENTS: Tag = Tag(0x0040, 0x2400);
+/// (0040,3001) Confidentiality Constraint on Patient Data Description
+pub const CONFIDENTIALITY_CONSTRAINT_ON_PATIENT_DATA_DESCRIPTION: Tag = Tag(0x0040, 0x3001);
+/// (0040,4001) General Purpose Scheduled Procedure Step Status (retired)
+pub const GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_STATUS: Tag = Tag(0x0040, 0x4001);
+/// (0040,4002) General Purpose Performed Procedure Step Status (retired)
+pub const GENERAL_PURPOSE_PERFORMED_PROCEDURE_STEP_STATUS: Tag = Tag(0x0040, 0x4002);
+/// (0040,4003) General Purpose Scheduled Procedure Step Priority (retired)
+pub const GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_PRIORITY: Tag = Tag(0x0040, 0x4003);
+/// (0040,4004) Scheduled Processing Applications Code Sequence (retired)
+pub const SCHEDULED_PROCESSING_APPLICATIONS_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4004);
+/// (0040,4005) Scheduled Procedure Step Start Date Time
+pub const SCHEDULED_PROCEDURE_STEP_START_DATE_TIME: Tag = Tag(0x0040, 0x4005);
+/// (0040,4006) Multiple Copies Flag (retired)
+pub const MULTIPLE_COPIES_FLAG: Tag = Tag(0x0040, 0x4006);
+/// (0040,4007) Performed Processing Applications Code Sequence (retired)
+pub const PERFORMED_PROCESSING_APPLICATIONS_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4007);
+/// (0040,4008) Scheduled Procedure Step Expiration Date Time
+pub const SCHEDULED_PROCEDURE_STEP_EXPIRATION_DATE_TIME: Tag = Tag(0x0040, 0x4008);
+/// (0040,4009) Human Performer Code Sequence
+pub const HUMAN_PERFORMER_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4009);
+/// (0040,4010) Scheduled Procedure Step Modification Date Time
+pub const SCHEDULED_PROCEDURE_STEP_MODIFICATION_DATE_TIME: Tag = Tag(0x0040, 0x4010);
+/// (0040,4011) Expected Completion Date Time
+pub const EXPECTED_COMPLETION_DATE_TIME: Tag = Tag(0x0040, 0x4011);
+/// (0040,4015) Resulting General Purpose Performed Procedure Steps Sequence (retired)
+pub const RESULTING_GENERAL_PURPOSE_PERFORMED_PROCEDURE_STEPS_SEQUENCE: Tag = Tag(0x0040, 0x4015);
+/// (0040,4016) Referenced General Purpose Scheduled Procedure Step Sequence (retired)
+pub const REFERENCED_GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x0040, 0x4016);
+/// (0040,4018) Scheduled Workitem Code Sequence
+pub const SCHEDULED_WORKITEM_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4018);
+/// (0040,4019) Performed Workitem Code Sequence
+pub const PERFORMED_WORKITEM_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4019);
+/// (0040,4020) Input Availability Flag (retired)
+pub const INPUT_AVAILABILITY_FLAG: Tag = Tag(0x0040, 0x4020);
+/// (0040,4021) Input Information Sequence
+pub const INPUT_INFORMATION_SEQUENCE: Tag = Tag(0x0040, 0x4021);
+/// (0040,4022) Relevant Information Sequence (retired)
+pub const RELEVANT_INFORMATION_SEQUENCE: Tag = Tag(0x0040, 0x4022);
+/// (0040,4023) Referenced General Purpose Scheduled Procedure Step Transaction UID (retired)
+pub const REFERENCED_GENERAL_PURPOSE_SCHEDULED_PROCEDURE_STEP_TRANSACTION_UID: Tag = Tag(0x0040, 0x4023);
+/// (0040,4025) Scheduled Station Name Code Sequence
+pub const SCHEDULED_STATION_NAME_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4025);
+/// (0040,4026) Scheduled Station Class Code Sequence
+pub const SCHEDULED_STATION_CLASS_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4026);
+/// (0040,4027) Scheduled Station Geographic Location Code Sequence
+pub const SCHEDULED_STATION_GEOGRAPHIC_LOCATION_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4027);
+/// (0040,4028) Performed Station Name Code Sequence
+pub const PERFORMED_STATION_NAME_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4028);
+/// (0040,4029) Performed Station Class Code Sequence
+pub const PERFORMED_STATION_CLASS_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4029);
+/// (0040,4030) Performed Station Geographic Location Code Sequence
+pub const PERFORMED_STATION_GEOGRAPHIC_LOCATION_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4030);
+/// (0040,4031) Requested Subsequent Workitem Code Sequence (retired)
+pub const REQUESTED_SUBSEQUENT_WORKITEM_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4031);
+/// (0040,4032) Non DICOM Output Code Sequence (retired)
+pub const NON_DICOM_OUTPUT_CODE_SEQUENCE: Tag = Tag(0x0040, 0x4032);
+/// (0040,4033) Output Information Sequence
+pub const OUTPUT_INFORMATION_SEQUENCE: Tag = Tag(0x0040, 0x4033);
+/// (0040,4034) Scheduled Human Performers Sequence
+pub const SCHEDULED_HUMAN_PERFORMERS_SEQUENCE: Tag = Tag(0x0040, 0x4034);
+/// (0040,4035) Actual Human Performers Sequence
+pub const ACTUAL_HUMAN_PERFORMERS_SEQUENCE: Tag = Tag(0x0040, 0x4035);
+/// (0040,4036) Human Performer Organization
+pub const HUMAN_PERFORMER_ORGANIZATION: Tag = Tag(0x0040, 0x4036);
+/// (0040,4037) Human Performer Name
+pub const HUMAN_PERFORMER_NAME: Tag = Tag(0x0040, 0x4037);
+/// (0040,4040) Raw Data Handling
+pub const RAW_DATA_HANDLING: Tag = Tag(0x0040, 0x4040);
+/// (0040,4041) Input Readiness State
+pub const INPUT_READINESS_STATE: Tag = Tag(0x0040, 0x4041);
+/// (0040,4050) Performed Procedure Step Start Date Time
+pub const PERFORMED_PROCEDURE_STEP_START_DATE_TIME: Tag = Tag(0x0040, 0x4050);
+/// (0040,4051) Performed Procedure Step End Date Time
+pub const PERFORMED_PROCEDURE_STEP_END_DATE_TIME: Tag = Tag(0x0040, 0x4051);
+/// (0040,4052) Procedure Step Cancellation Date Time
+pub const PROCEDURE_STEP_CANCELLATION_DATE_TIME: Tag = Tag(0x0040, 0x4052);
+/// (0040,4070) Output Destination Sequence
+pub const OUTPUT_DESTINATION_SEQUENCE: Tag = Tag(0x0040, 0x4070);
+/// (0040,4071) DICOM Storage Sequence
+pub const DICOM_STORAGE_SEQUENCE: Tag = Tag(0x0040, 0x4071);
+/// (0040,4072) STOWRS Storage Sequence
+pub const STOWRS_STORAGE_SEQUENCE: Tag = Tag(0x0040, 0x4072);
+/// (0040,4073) Storage URL
+pub const STORAGE_URL: Tag = Tag(0x0040, 0x4073);
+/// (0040,4074) XDS Storage Sequence
+pub const XDS_STORAGE_SEQUENCE: Tag = Tag(0x0040, 0x4074);
+/// (0040,8302) Entrance Dose Inm Gy
+pub const ENTRANCE_DOSE_INM_GY: Tag = Tag(0x0040, 0x8302);
+/// (0040,8303) Entrance Dose Derivation
+pub const ENTRANCE_DOSE_DERIVATION: Tag = Tag(0x0040, 0x8303);
+/// (0040,9092) Parametric Map Frame Type Sequence
+pub const PARAMETRIC_MAP_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0040, 0x9092);
+/// (0040,9094) Referenced Image Real World Value Mapping Sequence
+pub const REFERENCED_IMAGE_REAL_WORLD_VALUE_MAPPING_SEQUENCE: Tag = Tag(0x0040, 0x9094);
+/// (0040,9096) Real World Value Mapping Sequence
+pub const REAL_WORLD_VALUE_MAPPING_SEQUENCE: Tag = Tag(0x0040, 0x9096);
+/// (0040,9098) Pixel Value Mapping Code Sequence
+pub const PIXEL_VALUE_MAPPING_CODE_SEQUENCE: Tag = Tag(0x0040, 0x9098);
+/// (0040,9210) LUT Label
+pub const LUT_LABEL: Tag = Tag(0x0040, 0x9210);
+/// (0040,9211) Real World Value Last Value Mapped
+pub const REAL_WORLD_VALUE_LAST_VALUE_MAPPED: Tag = Tag(0x0040, 0x9211);
+/// (0040,9212) Real World Value LUT Data
+pub const REAL_WORLD_VALUE_LUT_DATA: Tag = Tag(0x0040, 0x9212);
+/// (0040,9213) Double Float Real World Value Last Value Mapped
+pub const DOUBLE_FLOAT_REAL_WORLD_VALUE_LAST_VALUE_MAPPED: Tag = Tag(0x0040, 0x9213);
+/// (0040,9214) Double Float Real World Value First Value Mapped
+pub const DOUBLE_FLOAT_REAL_WORLD_VALUE_FIRST_VALUE_MAPPED: Tag = Tag(0x0040, 0x9214);
+/// (0040,9216) Real World Value First Value Mapped
+pub const REAL_WORLD_VALUE_FIRST_VALUE_MAPPED: Tag = Tag(0x0040, 0x9216);
+/// (0040,9220) Quantity Definition Sequence
+pub const QUANTITY_DEFINITION_SEQUENCE: Tag = Tag(0x0040, 0x9220);
+/// (0040,9224) Real World Value Intercept
+pub const REAL_WORLD_VALUE_INTERCEPT: Tag = Tag(0x0040, 0x9224);
+/// (0040,9225) Real World Value Slope
+pub const REAL_WORLD_VALUE_SLOPE: Tag = Tag(0x0040, 0x9225);
+/// (0040,A007) Findings Flag Trial (retired)
+pub const FINDINGS_FLAG_TRIAL: Tag = Tag(0x0040, 0xA007);
+/// (0040,A010) Relationship Type
+pub const RELATIONSHIP_TYPE: Tag = Tag(0x0040, 0xA010);
+/// (0040,A020) Findings Sequence Trial (retired)
+pub const FINDINGS_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA020);
+/// (0040,A021) Findings Group UID Trial (retired)
+pub const FINDINGS_GROUP_UID_TRIAL: Tag = Tag(0x0040, 0xA021);
+/// (0040,A022) Referenced Findings Group UID Trial (retired)
+pub const REFERENCED_FINDINGS_GROUP_UID_TRIAL: Tag = Tag(0x0040, 0xA022);
+/// (0040,A023) Findings Group Recording Date Trial (retired)
+pub const FINDINGS_GROUP_RECORDING_DATE_TRIAL: Tag = Tag(0x0040, 0xA023);
+/// (0040,A024) Findings Group Recording Time Trial (retired)
+pub const FINDINGS_GROUP_RECORDING_TIME_TRIAL: Tag = Tag(0x0040, 0xA024);
+/// (0040,A026) Findings Source Category Code Sequence Trial (retired)
+pub const FINDINGS_SOURCE_CATEGORY_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA026);
+/// (0040,A027) Verifying Organization
+pub const VERIFYING_ORGANIZATION: Tag = Tag(0x0040, 0xA027);
+/// (0040,A028) Documenting Organization Identifier Code Sequence Trial (retired)
+pub const DOCUMENTING_ORGANIZATION_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA028);
+/// (0040,A030) Verification Date Time
+pub const VERIFICATION_DATE_TIME: Tag = Tag(0x0040, 0xA030);
+/// (0040,A032) Observation Date Time
+pub const OBSERVATION_DATE_TIME: Tag = Tag(0x0040, 0xA032);
+/// (0040,A040) Value Type
+pub const VALUE_TYPE: Tag = Tag(0x0040, 0xA040);
+/// (0040,A043) Concept Name Code Sequence
+pub const CONCEPT_NAME_CODE_SEQUENCE: Tag = Tag(0x0040, 0xA043);
+/// (0040,A047) Measurement Precision Description Trial (retired)
+pub const MEASUREMENT_PRECISION_DESCRIPTION_TRIAL: Tag = Tag(0x0040, 0xA047);
+/// (0040,A050) Continuity of Content
+pub const CONTINUITY_OF_CONTENT: Tag = Tag(0x0040, 0xA050);
+/// (0040,A057) Urgency or Priority Alerts Trial (retired)
+pub const URGENCY_OR_PRIORITY_ALERTS_TRIAL: Tag = Tag(0x0040, 0xA057);
+/// (0040,A060) Sequencing Indicator Trial (retired)
+pub const SEQUENCING_INDICATOR_TRIAL: Tag = Tag(0x0040, 0xA060);
+/// (0040,A066) Document Identifier Code Sequence Trial (retired)
+pub const DOCUMENT_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA066);
+/// (0040,A067) Document Author Trial (retired)
+pub const DOCUMENT_AUTHOR_TRIAL: Tag = Tag(0x0040, 0xA067);
+/// (0040,A068) Document Author Identifier Code Sequence Trial (retired)
+pub const DOCUMENT_AUTHOR_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA068);
+/// (0040,A070) Identifier Code Sequence Trial (retired)
+pub const IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA070);
+/// (0040,A073) Verifying Observer Sequence
+pub const VERIFYING_OBSERVER_SEQUENCE: Tag = Tag(0x0040, 0xA073);
+/// (0040,A074) Object Binary Identifier Trial (retired)
+pub const OBJECT_BINARY_IDENTIFIER_TRIAL: Tag = Tag(0x0040, 0xA074);
+/// (0040,A075) Verifying Observer Name
+pub const VERIFYING_OBSERVER_NAME: Tag = Tag(0x0040, 0xA075);
+/// (0040,A076) Documenting Observer Identifier Code Sequence Trial (retired)
+pub const DOCUMENTING_OBSERVER_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA076);
+/// (0040,A078) Author Observer Sequence
+pub const AUTHOR_OBSERVER_SEQUENCE: Tag = Tag(0x0040, 0xA078);
+/// (0040,A07A) Participant Sequence
+pub const PARTICIPANT_SEQUENCE: Tag = Tag(0x0040, 0xA07A);
+/// (0040,A07C) Custodial Organization Sequence
+pub const CUSTODIAL_ORGANIZATION_SEQUENCE: Tag = Tag(0x0040, 0xA07C);
+/// (0040,A080) Participation Type
+pub const PARTICIPATION_TYPE: Tag = Tag(0x0040, 0xA080);
+/// (0040,A082) Participation Date Time
+pub const PARTICIPATION_DATE_TIME: Tag = Tag(0x0040, 0xA082);
+/// (0040,A084) Observer Type
+pub const OBSERVER_TYPE: Tag = Tag(0x0040, 0xA084);
+/// (0040,A085) Procedure Identifier Code Sequence Trial (retired)
+pub const PROCEDURE_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA085);
+/// (0040,A088) Verifying Observer Identification Code Sequence
+pub const VERIFYING_OBSERVER_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag(0x0040, 0xA088);
+/// (0040,A089) Object Directory Binary Identifier Trial (retired)
+pub const OBJECT_DIRECTORY_BINARY_IDENTIFIER_TRIAL: Tag = Tag(0x0040, 0xA089);
+/// (0040,A090) Equivalent CDA Document Sequence (retired)
+pub const EQUIVALENT_CDA_DOCUMENT_SEQUENCE: Tag = Tag(0x0040, 0xA090);
+/// (0040,A0B0) Referenced Waveform Channels
+pub const REFERENCED_WAVEFORM_CHANNELS: Tag = Tag(0x0040, 0xA0B0);
+/// (0040,A110) Date of Document or Verbal Transaction Trial (retired)
+pub const DATE_OF_DOCUMENT_OR_VERBAL_TRANSACTION_TRIAL: Tag = Tag(0x0040, 0xA110);
+/// (0040,A112) Time of Document Creation or Verbal Transaction Trial (retired)
+pub const TIME_OF_DOCUMENT_CREATION_OR_VERBAL_TRANSACTION_TRIAL: Tag = Tag(0x0040, 0xA112);
+/// (0040,A120) Date Time
+pub const DATE_TIME: Tag = Tag(0x0040, 0xA120);
+/// (0040,A121) Date
+pub const DATE: Tag = Tag(0x0040, 0xA121);
+/// (0040,A122) Time
+pub const TIME: Tag = Tag(0x0040, 0xA122);
+/// (0040,A123) Person Name
+pub const PERSON_NAME: Tag = Tag(0x0040, 0xA123);
+/// (0040,A124) UID
+pub const UID: Tag = Tag(0x0040, 0xA124);
+/// (0040,A125) Report Status ID Trial (retired)
+pub const REPORT_STATUS_ID_TRIAL: Tag = Tag(0x0040, 0xA125);
+/// (0040,A130) Temporal Range Type
+pub const TEMPORAL_RANGE_TYPE: Tag = Tag(0x0040, 0xA130);
+/// (0040,A132) Referenced Sample Positions
+pub const REFERENCED_SAMPLE_POSITIONS: Tag = Tag(0x0040, 0xA132);
+/// (0040,A136) Referenced Frame Numbers (retired)
+pub const REFERENCED_FRAME_NUMBERS: Tag = Tag(0x0040, 0xA136);
+/// (0040,A138) Referenced Time Offsets
+pub const REFERENCED_TIME_OFFSETS: Tag = Tag(0x0040, 0xA138);
+/// (0040,A13A) Referenced Date Time
+pub const REFERENCED_DATE_TIME: Tag = Tag(0x0040, 0xA13A);
+/// (0040,A160) Text Value
+pub const TEXT_VALUE: Tag = Tag(0x0040, 0xA160);
+/// (0040,A161) Floating Point Value
+pub const FLOATING_POINT_VALUE: Tag = Tag(0x0040, 0xA161);
+/// (0040,A162) Rational Numerator Value
+pub const RATIONAL_NUMERATOR_VALUE: Tag = Tag(0x0040, 0xA162);
+/// (0040,A163) Rational Denominator Value
+pub const RATIONAL_DENOMINATOR_VALUE: Tag = Tag(0x0040, 0xA163);
+/// (0040,A167) Observation Category Code Sequence Trial (retired)
+pub const OBSERVATION_CATEGORY_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA167);
+/// (0040,A168) Concept Code Sequence
+pub const CONCEPT_CODE_SEQUENCE: Tag = Tag(0x0040, 0xA168);
+/// (0040,A16A) Bibliographic Citation Trial (retired)
+pub const BIBLIOGRAPHIC_CITATION_TRIAL: Tag = Tag(0x0040, 0xA16A);
+/// (0040,A170) Purpose of Reference Code Sequence
+pub const PURPOSE_OF_REFERENCE_CODE_SEQUENCE: Tag = Tag(0x0040, 0xA170);
+/// (0040,A171) Observation UID
+pub const OBSERVATION_UID: Tag = Tag(0x0040, 0xA171);
+/// (0040,A172) Referenced Observation UID Trial (retired)
+pub const REFERENCED_OBSERVATION_UID_TRIAL: Tag = Tag(0x0040, 0xA172);
+/// (0040,A173) Referenced Observation Class Trial (retired)
+pub const REFERENCED_OBSERVATION_CLASS_TRIAL: Tag = Tag(0x0040, 0xA173);
+/// (0040,A174) Referenced Object Observation Class Trial (retired)
+pub const REFERENCED_OBJECT_OBSERVATION_CLASS_TRIAL: Tag = Tag(0x0040, 0xA174);
+/// (0040,A180) Annotation Group Number
+pub const ANNOTATION_GROUP_NUMBER: Tag = Tag(0x0040, 0xA180);
+/// (0040,A192) Observation Date Trial (retired)
+pub const OBSERVATION_DATE_TRIAL: Tag = Tag(0x0040, 0xA192);
+/// (0040,A193) Observation Time Trial (retired)
+pub const OBSERVATION_TIME_TRIAL: Tag = Tag(0x0040, 0xA193);
+/// (0040,A194) Measurement Automation Trial (retired)
+pub const MEASUREMENT_AUTOMATION_TRIAL: Tag = Tag(0x0040, 0xA194);
+/// (0040,A195) Modifier Code Sequence
+pub const MODIFIER_CODE_SEQUENCE: Tag = Tag(0x0040, 0xA195);
+/// (0040,A224) Identification Description Trial (retired)
+pub const IDENTIFICATION_DESCRIPTION_TRIAL: Tag = Tag(0x0040, 0xA224);
+/// (0040,A290) Coordinates Set Geometric Type Trial (retired)
+pub const COORDINATES_SET_GEOMETRIC_TYPE_TRIAL: Tag = Tag(0x0040, 0xA290);
+/// (0040,A296) Algorithm Code Sequence Trial (retired)
+pub const ALGORITHM_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA296);
+/// (0040,A297) Algorithm Description Trial (retired)
+pub const ALGORITHM_DESCRIPTION_TRIAL: Tag = Tag(0x0040, 0xA297);
+/// (0040,A29A) Pixel Coordinates Set Trial (retired)
+pub const PIXEL_COORDINATES_SET_TRIAL: Tag = Tag(0x0040, 0xA29A);
+/// (0040,A300) Measured Value Sequence
+pub const MEASURED_VALUE_SEQUENCE: Tag = Tag(0x0040, 0xA300);
+/// (0040,A301) Numeric Value Qualifier Code Sequence
+pub const NUMERIC_VALUE_QUALIFIER_CODE_SEQUENCE: Tag = Tag(0x0040, 0xA301);
+/// (0040,A307) Current Observer Trial (retired)
+pub const CURRENT_OBSERVER_TRIAL: Tag = Tag(0x0040, 0xA307);
+/// (0040,A30A) Numeric Value
+pub const NUMERIC_VALUE: Tag = Tag(0x0040, 0xA30A);
+/// (0040,A313) Referenced Accession Sequence Trial (retired)
+pub const REFERENCED_ACCESSION_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA313);
+/// (0040,A33A) Report Status Comment Trial (retired)
+pub const REPORT_STATUS_COMMENT_TRIAL: Tag = Tag(0x0040, 0xA33A);
+/// (0040,A340) Procedure Context Sequence Trial (retired)
+pub const PROCEDURE_CONTEXT_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA340);
+/// (0040,A352) Verbal Source Trial (retired)
+pub const VERBAL_SOURCE_TRIAL: Tag = Tag(0x0040, 0xA352);
+/// (0040,A353) Address Trial (retired)
+pub const ADDRESS_TRIAL: Tag = Tag(0x0040, 0xA353);
+/// (0040,A354) Telephone Number Trial (retired)
+pub const TELEPHONE_NUMBER_TRIAL: Tag = Tag(0x0040, 0xA354);
+/// (0040,A358) Verbal Source Identifier Code Sequence Trial (retired)
+pub const VERBAL_SOURCE_IDENTIFIER_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA358);
+/// (0040,A360) Predecessor Documents Sequence
+pub const PREDECESSOR_DOCUMENTS_SEQUENCE: Tag = Tag(0x0040, 0xA360);
+/// (0040,A370) Referenced Request Sequence
+pub const REFERENCED_REQUEST_SEQUENCE: Tag = Tag(0x0040, 0xA370);
+/// (0040,A372) Performed Procedure Code Sequence
+pub const PERFORMED_PROCEDURE_CODE_SEQUENCE: Tag = Tag(0x0040, 0xA372);
+/// (0040,A375) Current Requested Procedure Evidence Sequence
+pub const CURRENT_REQUESTED_PROCEDURE_EVIDENCE_SEQUENCE: Tag = Tag(0x0040, 0xA375);
+/// (0040,A380) Report Detail Sequence Trial (retired)
+pub const REPORT_DETAIL_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA380);
+/// (0040,A385) Pertinent Other Evidence Sequence
+pub const PERTINENT_OTHER_EVIDENCE_SEQUENCE: Tag = Tag(0x0040, 0xA385);
+/// (0040,A390) HL 7 Structured Document Reference Sequence
+pub const HL7STRUCTURED_DOCUMENT_REFERENCE_SEQUENCE: Tag = Tag(0x0040, 0xA390);
+/// (0040,A402) Observation Subject UID Trial (retired)
+pub const OBSERVATION_SUBJECT_UID_TRIAL: Tag = Tag(0x0040, 0xA402);
+/// (0040,A403) Observation Subject Class Trial (retired)
+pub const OBSERVATION_SUBJECT_CLASS_TRIAL: Tag = Tag(0x0040, 0xA403);
+/// (0040,A404) Observation Subject Type Code Sequence Trial (retired)
+pub const OBSERVATION_SUBJECT_TYPE_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA404);
+/// (0040,A491) Completion Flag
+pub const COMPLETION_FLAG: Tag = Tag(0x0040, 0xA491);
+/// (0040,A492) Completion Flag Description
+pub const COMPLETION_FLAG_DESCRIPTION: Tag = Tag(0x0040, 0xA492);
+/// (0040,A493) Verification Flag
+pub const VERIFICATION_FLAG: Tag = Tag(0x0040, 0xA493);
+/// (0040,A494) Archive Requested
+pub const ARCHIVE_REQUESTED: Tag = Tag(0x0040, 0xA494);
+/// (0040,A496) Preliminary Flag
+pub const PRELIMINARY_FLAG: Tag = Tag(0x0040, 0xA496);
+/// (0040,A504) Content Template Sequence
+pub const CONTENT_TEMPLATE_SEQUENCE: Tag = Tag(0x0040, 0xA504);
+/// (0040,A525) Identical Documents Sequence
+pub const IDENTICAL_DOCUMENTS_SEQUENCE: Tag = Tag(0x0040, 0xA525);
+/// (0040,A600) Observation Subject Context Flag Trial (retired)
+pub const OBSERVATION_SUBJECT_CONTEXT_FLAG_TRIAL: Tag = Tag(0x0040, 0xA600);
+/// (0040,A601) Observer Context Flag Trial (retired)
+pub const OBSERVER_CONTEXT_FLAG_TRIAL: Tag = Tag(0x0040, 0xA601);
+/// (0040,A603) Procedure Context Flag Trial (retired)
+pub const PROCEDURE_CONTEXT_FLAG_TRIAL: Tag = Tag(0x0040, 0xA603);
+/// (0040,A730) Content Sequence
+pub const CONTENT_SEQUENCE: Tag = Tag(0x0040, 0xA730);
+/// (0040,A731) Relationship Sequence Trial (retired)
+pub const RELATIONSHIP_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA731);
+/// (0040,A732) Relationship Type Code Sequence Trial (retired)
+pub const RELATIONSHIP_TYPE_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA732);
+/// (0040,A744) Language Code Sequence Trial (retired)
+pub const LANGUAGE_CODE_SEQUENCE_TRIAL: Tag = Tag(0x0040, 0xA744);
+/// (0040,A992) Uniform Resource Locator Trial (retired)
+pub const UNIFORM_RESOURCE_LOCATOR_TRIAL: Tag = Tag(0x0040, 0xA992);
+/// (0040,B020) Waveform Annotation Sequence
+pub const WAVEFORM_ANNOTATION_SEQUENCE: Tag = Tag(0x0040, 0xB020);
+/// (0040,DB00) Template Identifier
+pub const TEMPLATE_IDENTIFIER: Tag = Tag(0x0040, 0xDB00);
+/// (0040,DB06) Template Version (retired)
+pub const TEMPLATE_VERSION: Tag = Tag(0x0040, 0xDB06);
+/// (0040,DB07) Template Local Version (retired)
+pub const TEMPLATE_LOCAL_VERSION: Tag = Tag(0x0040, 0xDB07);
+/// (0040,DB0B) Template Extension Flag (retired)
+pub const TEMPLATE_EXTENSION_FLAG: Tag = Tag(0x0040, 0xDB0B);
+/// (0040,DB0C) Template Extension Organization UID (retired)
+pub const TEMPLATE_EXTENSION_ORGANIZATION_UID: Tag = Tag(0x0040, 0xDB0C);
+/// (0040,DB0D) Template Extension Creator UID (retired)
+pub const TEMPLATE_EXTENSION_CREATOR_UID: Tag = Tag(0x0040, 0xDB0D);
+/// (0040,DB73) Referenced Content Item Identifier
+pub const REFERENCED_CONTENT_ITEM_IDENTIFIER: Tag = Tag(0x0040, 0xDB73);
+/// (0040,E001) HL 7 Instance Identifier
+pub const HL7INSTANCE_IDENTIFIER: Tag = Tag(0x0040, 0xE001);
+/// (0040,E004) HL 7 Document Effective Time
+pub const HL7DOCUMENT_EFFECTIVE_TIME: Tag = Tag(0x0040, 0xE004);
+/// (0040,E006) HL 7 Document Type Code Sequence
+pub const HL7DOCUMENT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0040, 0xE006);
+/// (0040,E008) Document Class Code Sequence
+pub const DOCUMENT_CLASS_CODE_SEQUENCE: Tag = Tag(0x0040, 0xE008);
+/// (0040,E010) Retrieve URI
+pub const RETRIEVE_URI: Tag = Tag(0x0040, 0xE010);
+/// (0040,E011) Retrieve Location UID
+pub const RETRIEVE_LOCATION_UID: Tag = Tag(0x0040, 0xE011);
+/// (0040,E020) Type of Instances
+pub const TYPE_OF_INSTANCES: Tag = Tag(0x0040, 0xE020);
+/// (0040,E021) DICOM Retrieval Sequence
+pub const DICOM_RETRIEVAL_SEQUENCE: Tag = Tag(0x0040, 0xE021);
+/// (0040,E022) DICOM Media Retrieval Sequence
+pub const DICOM_MEDIA_RETRIEVAL_SEQUENCE: Tag = Tag(0x0040, 0xE022);
+/// (0040,E023) WADO Retrieval Sequence
+pub const WADO_RETRIEVAL_SEQUENCE: Tag = Tag(0x0040, 0xE023);
+/// (0040,E024) XDS Retrieval Sequence
+pub const XDS_RETRIEVAL_SEQUENCE: Tag = Tag(0x0040, 0xE024);
+/// (0040,E025) WADORS Retrieval Sequence
+pub const WADORS_RETRIEVAL_SEQUENCE: Tag = Tag(0x0040, 0xE025);
+/// (0040,E030) Repository Unique ID
+pub const REPOSITORY_UNIQUE_ID: Tag = Tag(0x0040, 0xE030);
+/// (0040,E031) Home Community ID
+pub const HOME_COMMUNITY_ID: Tag = Tag(0x0040, 0xE031);
+/// (0042,0010) Document Title
+pub const DOCUMENT_TITLE: Tag = Tag(0x0042, 0x0010);
+/// (0042,0011) Encapsulated Document
+pub const ENCAPSULATED_DOCUMENT: Tag = Tag(0x0042, 0x0011);
+/// (0042,0012) MIME Type of Encapsulated Document
+pub const MIME_TYPE_OF_ENCAPSULATED_DOCUMENT: Tag = Tag(0x0042, 0x0012);
+/// (0042,0013) Source Instance Sequence
+pub const SOURCE_INSTANCE_SEQUENCE: Tag = Tag(0x0042, 0x0013);
+/// (0042,0014) List of MIME Types
+pub const LIST_OF_MIME_TYPES: Tag = Tag(0x0042, 0x0014);
+/// (0042,0015) Encapsulated Document Length
+pub const ENCAPSULATED_DOCUMENT_LENGTH: Tag = Tag(0x0042, 0x0015);
+/// (0044,0001) Product Package Identifier
+pub const PRODUCT_PACKAGE_IDENTIFIER: Tag = Tag(0x0044, 0x0001);
+/// (0044,0002) Substance Administration Approval
+pub const SUBSTANCE_ADMINISTRATION_APPROVAL: Tag = Tag(0x0044, 0x0002);
+/// (0044,0003) Approval Status Further Description
+pub const APPROVAL_STATUS_FURTHER_DESCRIPTION: Tag = Tag(0x0044, 0x0003);
+/// (0044,0004) Approval Status Date Time
+pub const APPROVAL_STATUS_DATE_TIME: Tag = Tag(0x0044, 0x0004);
+/// (0044,0007) Product Type Code Sequence
+pub const PRODUCT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0044, 0x0007);
+/// (0044,0008) Product Name
+pub const PRODUCT_NAME: Tag = Tag(0x0044, 0x0008);
+/// (0044,0009) Product Description
+pub const PRODUCT_DESCRIPTION: Tag = Tag(0x0044, 0x0009);
+/// (0044,000A) Product Lot Identifier
+pub const PRODUCT_LOT_IDENTIFIER: Tag = Tag(0x0044, 0x000A);
+/// (0044,000B) Product Expiration Date Time
+pub const PRODUCT_EXPIRATION_DATE_TIME: Tag = Tag(0x0044, 0x000B);
+/// (0044,0010) Substance Administration Date Time
+pub const SUBSTANCE_ADMINISTRATION_DATE_TIME: Tag = Tag(0x0044, 0x0010);
+/// (0044,0011) Substance Administration Notes
+pub const SUBSTANCE_ADMINISTRATION_NOTES: Tag = Tag(0x0044, 0x0011);
+/// (0044,0012) Substance Administration Device ID
+pub const SUBSTANCE_ADMINISTRATION_DEVICE_ID: Tag = Tag(0x0044, 0x0012);
+/// (0044,0013) Product Parameter Sequence
+pub const PRODUCT_PARAMETER_SEQUENCE: Tag = Tag(0x0044, 0x0013);
+/// (0044,0019) Substance Administration Parameter Sequence
+pub const SUBSTANCE_ADMINISTRATION_PARAMETER_SEQUENCE: Tag = Tag(0x0044, 0x0019);
+/// (0044,0100) Approval Sequence
+pub const APPROVAL_SEQUENCE: Tag = Tag(0x0044, 0x0100);
+/// (0044,0101) Assertion Code Sequence
+pub const ASSERTION_CODE_SEQUENCE: Tag = Tag(0x0044, 0x0101);
+/// (0044,0102) Assertion UID
+pub const ASSERTION_UID: Tag = Tag(0x0044, 0x0102);
+/// (0044,0103) Asserter Identification Sequence
+pub const ASSERTER_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0044, 0x0103);
+/// (0044,0104) Assertion Date Time
+pub const ASSERTION_DATE_TIME: Tag = Tag(0x0044, 0x0104);
+/// (0044,0105) Assertion Expiration Date Time
+pub const ASSERTION_EXPIRATION_DATE_TIME: Tag = Tag(0x0044, 0x0105);
+/// (0044,0106) Assertion Comments
+pub const ASSERTION_COMMENTS: Tag = Tag(0x0044, 0x0106);
+/// (0044,0107) Related Assertion Sequence
+pub const RELATED_ASSERTION_SEQUENCE: Tag = Tag(0x0044, 0x0107);
+/// (0044,0108) Referenced Assertion UID
+pub const REFERENCED_ASSERTION_UID: Tag = Tag(0x0044, 0x0108);
+/// (0044,0109) Approval Subject Sequence
+pub const APPROVAL_SUBJECT_SEQUENCE: Tag = Tag(0x0044, 0x0109);
+/// (0044,010A) Organizational Role Code Sequence
+pub const ORGANIZATIONAL_ROLE_CODE_SEQUENCE: Tag = Tag(0x0044, 0x010A);
+/// (0046,0012) Lens Description
+pub const LENS_DESCRIPTION: Tag = Tag(0x0046, 0x0012);
+/// (0046,0014) Right Lens Sequence
+pub const RIGHT_LENS_SEQUENCE: Tag = Tag(0x0046, 0x0014);
+/// (0046,0015) Left Lens Sequence
+pub const LEFT_LENS_SEQUENCE: Tag = Tag(0x0046, 0x0015);
+/// (0046,0016) Unspecified Laterality Lens Sequence
+pub const UNSPECIFIED_LATERALITY_LENS_SEQUENCE: Tag = Tag(0x0046, 0x0016);
+/// (0046,0018) Cylinder Sequence
+pub const CYLINDER_SEQUENCE: Tag = Tag(0x0046, 0x0018);
+/// (0046,0028) Prism Sequence
+pub const PRISM_SEQUENCE: Tag = Tag(0x0046, 0x0028);
+/// (0046,0030) Horizontal Prism Power
+pub const HORIZONTAL_PRISM_POWER: Tag = Tag(0x0046, 0x0030);
+/// (0046,0032) Horizontal Prism Base
+pub const HORIZONTAL_PRISM_BASE: Tag = Tag(0x0046, 0x0032);
+/// (0046,0034) Vertical Prism Power
+pub const VERTICAL_PRISM_POWER: Tag = Tag(0x0046, 0x0034);
+/// (0046,0036) Vertical Prism Base
+pub const VERTICAL_PRISM_BASE: Tag = Tag(0x0046, 0x0036);
+/// (0046,0038) Lens Segment Type
+pub const LENS_SEGMENT_TYPE: Tag = Tag(0x0046, 0x0038);
+/// (0046,0040) Optical Transmittance
+pub const OPTICAL_TRANSMITTANCE: Tag = Tag(0x0046, 0x0040);
+/// (0046,0042) Channel Width
+pub const CHANNEL_WIDTH: Tag = Tag(0x0046, 0x0042);
+/// (0046,0044) Pupil Size
+pub const PUPIL_SIZE: Tag = Tag(0x0046, 0x0044);
+/// (0046,0046) Corneal Size
+pub const CORNEAL_SIZE: Tag = Tag(0x0046, 0x0046);
+/// (0046,0047) Corneal Size Sequence
+pub const CORNEAL_SIZE_SEQUENCE: Tag = Tag(0x0046, 0x0047);
+/// (0046,0050) Autorefraction Right Eye Sequence
+pub const AUTOREFRACTION_RIGHT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0050);
+/// (0046,0052) Autorefraction Left Eye Sequence
+pub const AUTOREFRACTION_LEFT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0052);
+/// (0046,0060) Distance Pupillary Distance
+pub const DISTANCE_PUPILLARY_DISTANCE: Tag = Tag(0x0046, 0x0060);
+/// (0046,0062) Near Pupillary Distance
+pub const NEAR_PUPILLARY_DISTANCE: Tag = Tag(0x0046, 0x0062);
+/// (0046,0063) Intermediate Pupillary Distance
+pub const INTERMEDIATE_PUPILLARY_DISTANCE: Tag = Tag(0x0046, 0x0063);
+/// (0046,0064) Other Pupillary Distance
+pub const OTHER_PUPILLARY_DISTANCE: Tag = Tag(0x0046, 0x0064);
+/// (0046,0070) Keratometry Right Eye Sequence
+pub const KERATOMETRY_RIGHT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0070);
+/// (0046,0071) Keratometry Left Eye Sequence
+pub const KERATOMETRY_LEFT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0071);
+/// (0046,0074) Steep Keratometric Axis Sequence
+pub const STEEP_KERATOMETRIC_AXIS_SEQUENCE: Tag = Tag(0x0046, 0x0074);
+/// (0046,0075) Radius of Curvature
+pub const RADIUS_OF_CURVATURE: Tag = Tag(0x0046, 0x0075);
+/// (0046,0076) Keratometric Power
+pub const KERATOMETRIC_POWER: Tag = Tag(0x0046, 0x0076);
+/// (0046,0077) Keratometric Axis
+pub const KERATOMETRIC_AXIS: Tag = Tag(0x0046, 0x0077);
+/// (0046,0080) Flat Keratometric Axis Sequence
+pub const FLAT_KERATOMETRIC_AXIS_SEQUENCE: Tag = Tag(0x0046, 0x0080);
+/// (0046,0092) Background Color
+pub const BACKGROUND_COLOR: Tag = Tag(0x0046, 0x0092);
+/// (0046,0094) Optotype
+pub const OPTOTYPE: Tag = Tag(0x0046, 0x0094);
+/// (0046,0095) Optotype Presentation
+pub const OPTOTYPE_PRESENTATION: Tag = Tag(0x0046, 0x0095);
+/// (0046,0097) Subjective Refraction Right Eye Sequence
+pub const SUBJECTIVE_REFRACTION_RIGHT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0097);
+/// (0046,0098) Subjective Refraction Left Eye Sequence
+pub const SUBJECTIVE_REFRACTION_LEFT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0098);
+/// (0046,0100) Add Near Sequence
+pub const ADD_NEAR_SEQUENCE: Tag = Tag(0x0046, 0x0100);
+/// (0046,0101) Add Intermediate Sequence
+pub const ADD_INTERMEDIATE_SEQUENCE: Tag = Tag(0x0046, 0x0101);
+/// (0046,0102) Add Other Sequence
+pub const ADD_OTHER_SEQUENCE: Tag = Tag(0x0046, 0x0102);
+/// (0046,0104) Add Power
+pub const ADD_POWER: Tag = Tag(0x0046, 0x0104);
+/// (0046,0106) Viewing Distance
+pub const VIEWING_DISTANCE: Tag = Tag(0x0046, 0x0106);
+/// (0046,0110) Cornea Measurements Sequence
+pub const CORNEA_MEASUREMENTS_SEQUENCE: Tag = Tag(0x0046, 0x0110);
+/// (0046,0111) Source of Cornea Measurement Data Code Sequence
+pub const SOURCE_OF_CORNEA_MEASUREMENT_DATA_CODE_SEQUENCE: Tag = Tag(0x0046, 0x0111);
+/// (0046,0112) Steep Corneal Axis Sequence
+pub const STEEP_CORNEAL_AXIS_SEQUENCE: Tag = Tag(0x0046, 0x0112);
+/// (0046,0113) Flat Corneal Axis Sequence
+pub const FLAT_CORNEAL_AXIS_SEQUENCE: Tag = Tag(0x0046, 0x0113);
+/// (0046,0114) Corneal Power
+pub const CORNEAL_POWER: Tag = Tag(0x0046, 0x0114);
+/// (0046,0115) Corneal Axis
+pub const CORNEAL_AXIS: Tag = Tag(0x0046, 0x0115);
+/// (0046,0116) Cornea Measurement Method Code Sequence
+pub const CORNEA_MEASUREMENT_METHOD_CODE_SEQUENCE: Tag = Tag(0x0046, 0x0116);
+/// (0046,0117) Refractive Index of Cornea
+pub const REFRACTIVE_INDEX_OF_CORNEA: Tag = Tag(0x0046, 0x0117);
+/// (0046,0118) Refractive Index of Aqueous Humor
+pub const REFRACTIVE_INDEX_OF_AQUEOUS_HUMOR: Tag = Tag(0x0046, 0x0118);
+/// (0046,0121) Visual Acuity Type Code Sequence
+pub const VISUAL_ACUITY_TYPE_CODE_SEQUENCE: Tag = Tag(0x0046, 0x0121);
+/// (0046,0122) Visual Acuity Right Eye Sequence
+pub const VISUAL_ACUITY_RIGHT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0122);
+/// (0046,0123) Visual Acuity Left Eye Sequence
+pub const VISUAL_ACUITY_LEFT_EYE_SEQUENCE: Tag = Tag(0x0046, 0x0123);
+/// (0046,0124) Visual Acuity Both Eyes Open Sequence
+pub const VISUAL_ACUITY_BOTH_EYES_OPEN_SEQUENCE: Tag = Tag(0x0046, 0x0124);
+/// (0046,0125) Viewing Distance Type
+pub const VIEWING_DISTANCE_TYPE: Tag = Tag(0x0046, 0x0125);
+/// (0046,0135) Visual Acuity Modifiers
+pub const VISUAL_ACUITY_MODIFIERS: Tag = Tag(0x0046, 0x0135);
+/// (0046,0137) Decimal Visual Acuity
+pub const DECIMAL_VISUAL_ACUITY: Tag = Tag(0x0046, 0x0137);
+/// (0046,0139) Optotype Detailed Definition
+pub const OPTOTYPE_DETAILED_DEFINITION: Tag = Tag(0x0046, 0x0139);
+/// (0046,0145) Referenced Refractive Measurements Sequence
+pub const REFERENCED_REFRACTIVE_MEASUREMENTS_SEQUENCE: Tag = Tag(0x0046, 0x0145);
+/// (0046,0146) Sphere Power
+pub const SPHERE_POWER: Tag = Tag(0x0046, 0x0146);
+/// (0046,0147) Cylinder Power
+pub const CYLINDER_POWER: Tag = Tag(0x0046, 0x0147);
+/// (0046,0201) Corneal Topography Surface
+pub const CORNEAL_TOPOGRAPHY_SURFACE: Tag = Tag(0x0046, 0x0201);
+/// (0046,0202) Corneal Vertex Location
+pub const CORNEAL_VERTEX_LOCATION: Tag = Tag(0x0046, 0x0202);
+/// (0046,0203) Pupil Centroid X Coordinate
+pub const PUPIL_CENTROID_X_COORDINATE: Tag = Tag(0x0046, 0x0203);
+/// (0046,0204) Pupil Centroid Y Coordinate
+pub const PUPIL_CENTROID_Y_COORDINATE: Tag = Tag(0x0046, 0x0204);
+/// (0046,0205) Equivalent Pupil Radius
+pub const EQUIVALENT_PUPIL_RADIUS: Tag = Tag(0x0046, 0x0205);
+/// (0046,0207) Corneal Topography Map Type Code Sequence
+pub const CORNEAL_TOPOGRAPHY_MAP_TYPE_CODE_SEQUENCE: Tag = Tag(0x0046, 0x0207);
+/// (0046,0208) Vertices of the Outline of Pupil
+pub const VERTICES_OF_THE_OUTLINE_OF_PUPIL: Tag = Tag(0x0046, 0x0208);
+/// (0046,0210) Corneal Topography Mapping Normals Sequence
+pub const CORNEAL_TOPOGRAPHY_MAPPING_NORMALS_SEQUENCE: Tag = Tag(0x0046, 0x0210);
+/// (0046,0211) Maximum Corneal Curvature Sequence
+pub const MAXIMUM_CORNEAL_CURVATURE_SEQUENCE: Tag = Tag(0x0046, 0x0211);
+/// (0046,0212) Maximum Corneal Curvature
+pub const MAXIMUM_CORNEAL_CURVATURE: Tag = Tag(0x0046, 0x0212);
+/// (0046,0213) Maximum Corneal Curvature Location
+pub const MAXIMUM_CORNEAL_CURVATURE_LOCATION: Tag = Tag(0x0046, 0x0213);
+/// (0046,0215) Minimum Keratometric Sequence
+pub const MINIMUM_KERATOMETRIC_SEQUENCE: Tag = Tag(0x0046, 0x0215);
+/// (0046,0218) Simulated Keratometric Cylinder Sequence
+pub const SIMULATED_KERATOMETRIC_CYLINDER_SEQUENCE: Tag = Tag(0x0046, 0x0218);
+/// (0046,0220) Average Corneal Power
+pub const AVERAGE_CORNEAL_POWER: Tag = Tag(0x0046, 0x0220);
+/// (0046,0224) Corneal IS Value
+pub const CORNEAL_IS_VALUE: Tag = Tag(0x0046, 0x0224);
+/// (0046,0227) Analyzed Area
+pub const ANALYZED_AREA: Tag = Tag(0x0046, 0x0227);
+/// (0046,0230) Surface Regularity Index
+pub const SURFACE_REGULARITY_INDEX: Tag = Tag(0x0046, 0x0230);
+/// (0046,0232) Surface Asymmetry Index
+pub const SURFACE_ASYMMETRY_INDEX: Tag = Tag(0x0046, 0x0232);
+/// (0046,0234) Corneal Eccentricity Index
+pub const CORNEAL_ECCENTRICITY_INDEX: Tag = Tag(0x0046, 0x0234);
+/// (0046,0236) Keratoconus Prediction Index
+pub const KERATOCONUS_PREDICTION_INDEX: Tag = Tag(0x0046, 0x0236);
+/// (0046,0238) Decimal Potential Visual Acuity
+pub const DECIMAL_POTENTIAL_VISUAL_ACUITY: Tag = Tag(0x0046, 0x0238);
+/// (0046,0242) Corneal Topography Map Quality Evaluation
+pub const CORNEAL_TOPOGRAPHY_MAP_QUALITY_EVALUATION: Tag = Tag(0x0046, 0x0242);
+/// (0046,0244) Source Image Corneal Processed Data Sequence
+pub const SOURCE_IMAGE_CORNEAL_PROCESSED_DATA_SEQUENCE: Tag = Tag(0x0046, 0x0244);
+/// (0046,0247) Corneal Point Location
+pub const CORNEAL_POINT_LOCATION: Tag = Tag(0x0046, 0x0247);
+/// (0046,0248) Corneal Point Estimated
+pub const CORNEAL_POINT_ESTIMATED: Tag = Tag(0x0046, 0x0248);
+/// (0046,0249) Axial Power
+pub const AXIAL_POWER: Tag = Tag(0x0046, 0x0249);
+/// (0046,0250) Tangential Power
+pub const TANGENTIAL_POWER: Tag = Tag(0x0046, 0x0250);
+/// (0046,0251) Refractive Power
+pub const REFRACTIVE_POWER: Tag = Tag(0x0046, 0x0251);
+/// (0046,0252) Relative Elevation
+pub const RELATIVE_ELEVATION: Tag = Tag(0x0046, 0x0252);
+/// (0046,0253) Corneal Wavefront
+pub const CORNEAL_WAVEFRONT: Tag = Tag(0x0046, 0x0253);
+/// (0048,0001) Imaged Volume Width
+pub const IMAGED_VOLUME_WIDTH: Tag = Tag(0x0048, 0x0001);
+/// (0048,0002) Imaged Volume Height
+pub const IMAGED_VOLUME_HEIGHT: Tag = Tag(0x0048, 0x0002);
+/// (0048,0003) Imaged Volume Depth
+pub const IMAGED_VOLUME_DEPTH: Tag = Tag(0x0048, 0x0003);
+/// (0048,0006) Total Pixel Matrix Columns
+pub const TOTAL_PIXEL_MATRIX_COLUMNS: Tag = Tag(0x0048, 0x0006);
+/// (0048,0007) Total Pixel Matrix Rows
+pub const TOTAL_PIXEL_MATRIX_ROWS: Tag = Tag(0x0048, 0x0007);
+/// (0048,0008) Total Pixel Matrix Origin Sequence
+pub const TOTAL_PIXEL_MATRIX_ORIGIN_SEQUENCE: Tag = Tag(0x0048, 0x0008);
+/// (0048,0010) Specimen Label in Image
+pub const SPECIMEN_LABEL_IN_IMAGE: Tag = Tag(0x0048, 0x0010);
+/// (0048,0011) Focus Method
+pub const FOCUS_METHOD: Tag = Tag(0x0048, 0x0011);
+/// (0048,0012) Extended Depth of Field
+pub const EXTENDED_DEPTH_OF_FIELD: Tag = Tag(0x0048, 0x0012);
+/// (0048,0013) Number of Focal Planes
+pub const NUMBER_OF_FOCAL_PLANES: Tag = Tag(0x0048, 0x0013);
+/// (0048,0014) Distance Between Focal Planes
+pub const DISTANCE_BETWEEN_FOCAL_PLANES: Tag = Tag(0x0048, 0x0014);
+/// (0048,0015) Recommended Absent Pixel CIE Lab Value
+pub const RECOMMENDED_ABSENT_PIXEL_CIE_LAB_VALUE: Tag = Tag(0x0048, 0x0015);
+/// (0048,0100) Illuminator Type Code Sequence
+pub const ILLUMINATOR_TYPE_CODE_SEQUENCE: Tag = Tag(0x0048, 0x0100);
+/// (0048,0102) Image Orientation Slide
+pub const IMAGE_ORIENTATION_SLIDE: Tag = Tag(0x0048, 0x0102);
+/// (0048,0105) Optical Path Sequence
+pub const OPTICAL_PATH_SEQUENCE: Tag = Tag(0x0048, 0x0105);
+/// (0048,0106) Optical Path Identifier
+pub const OPTICAL_PATH_IDENTIFIER: Tag = Tag(0x0048, 0x0106);
+/// (0048,0107) Optical Path Description
+pub const OPTICAL_PATH_DESCRIPTION: Tag = Tag(0x0048, 0x0107);
+/// (0048,0108) Illumination Color Code Sequence
+pub const ILLUMINATION_COLOR_CODE_SEQUENCE: Tag = Tag(0x0048, 0x0108);
+/// (0048,0110) Specimen Reference Sequence
+pub const SPECIMEN_REFERENCE_SEQUENCE: Tag = Tag(0x0048, 0x0110);
+/// (0048,0111) Condenser Lens Power
+pub const CONDENSER_LENS_POWER: Tag = Tag(0x0048, 0x0111);
+/// (0048,0112) Objective Lens Power
+pub const OBJECTIVE_LENS_POWER: Tag = Tag(0x0048, 0x0112);
+/// (0048,0113) Objective Lens Numerical Aperture
+pub const OBJECTIVE_LENS_NUMERICAL_APERTURE: Tag = Tag(0x0048, 0x0113);
+/// (0048,0120) Palette Color Lookup Table Sequence
+pub const PALETTE_COLOR_LOOKUP_TABLE_SEQUENCE: Tag = Tag(0x0048, 0x0120);
+/// (0048,0200) Referenced Image Navigation Sequence
+pub const REFERENCED_IMAGE_NAVIGATION_SEQUENCE: Tag = Tag(0x0048, 0x0200);
+/// (0048,0201) Top Left Hand Corner of Localizer Area
+pub const TOP_LEFT_HAND_CORNER_OF_LOCALIZER_AREA: Tag = Tag(0x0048, 0x0201);
+/// (0048,0202) Bottom Right Hand Corner of Localizer Area
+pub const BOTTOM_RIGHT_HAND_CORNER_OF_LOCALIZER_AREA: Tag = Tag(0x0048, 0x0202);
+/// (0048,0207) Optical Path Identification Sequence
+pub const OPTICAL_PATH_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0048, 0x0207);
+/// (0048,021A) Plane Position Slide Sequence
+pub const PLANE_POSITION_SLIDE_SEQUENCE: Tag = Tag(0x0048, 0x021A);
+/// (0048,021E) Column Position in Total Image Pixel Matrix
+pub const COLUMN_POSITION_IN_TOTAL_IMAGE_PIXEL_MATRIX: Tag = Tag(0x0048, 0x021E);
+/// (0048,021F) Row Position in Total Image Pixel Matrix
+pub const ROW_POSITION_IN_TOTAL_IMAGE_PIXEL_MATRIX: Tag = Tag(0x0048, 0x021F);
+/// (0048,0301) Pixel Origin Interpretation
+pub const PIXEL_ORIGIN_INTERPRETATION: Tag = Tag(0x0048, 0x0301);
+/// (0048,0302) Number of Optical Paths
+pub const NUMBER_OF_OPTICAL_PATHS: Tag = Tag(0x0048, 0x0302);
+/// (0048,0303) Total Pixel Matrix Focal Planes
+pub const TOTAL_PIXEL_MATRIX_FOCAL_PLANES: Tag = Tag(0x0048, 0x0303);
+/// (0050,0004) Calibration Image
+pub const CALIBRATION_IMAGE: Tag = Tag(0x0050, 0x0004);
+/// (0050,0010) Device Sequence
+pub const DEVICE_SEQUENCE: Tag = Tag(0x0050, 0x0010);
+/// (0050,0012) Container Component Type Code Sequence
+pub const CONTAINER_COMPONENT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0050, 0x0012);
+/// (0050,0013) Container Component Thickness
+pub const CONTAINER_COMPONENT_THICKNESS: Tag = Tag(0x0050, 0x0013);
+/// (0050,0014) Device Length
+pub const DEVICE_LENGTH: Tag = Tag(0x0050, 0x0014);
+/// (0050,0015) Container Component Width
+pub const CONTAINER_COMPONENT_WIDTH: Tag = Tag(0x0050, 0x0015);
+/// (0050,0016) Device Diameter
+pub const DEVICE_DIAMETER: Tag = Tag(0x0050, 0x0016);
+/// (0050,0017) Device Diameter Units
+pub const DEVICE_DIAMETER_UNITS: Tag = Tag(0x0050, 0x0017);
+/// (0050,0018) Device Volume
+pub const DEVICE_VOLUME: Tag = Tag(0x0050, 0x0018);
+/// (0050,0019) Inter Marker Distance
+pub const INTER_MARKER_DISTANCE: Tag = Tag(0x0050, 0x0019);
+/// (0050,001A) Container Component Material
+pub const CONTAINER_COMPONENT_MATERIAL: Tag = Tag(0x0050, 0x001A);
+/// (0050,001B) Container Component ID
+pub const CONTAINER_COMPONENT_ID: Tag = Tag(0x0050, 0x001B);
+/// (0050,001C) Container Component Length
+pub const CONTAINER_COMPONENT_LENGTH: Tag = Tag(0x0050, 0x001C);
+/// (0050,001D) Container Component Diameter
+pub const CONTAINER_COMPONENT_DIAMETER: Tag = Tag(0x0050, 0x001D);
+/// (0050,001E) Container Component Description
+pub const CONTAINER_COMPONENT_DESCRIPTION: Tag = Tag(0x0050, 0x001E);
+/// (0050,0020) Device Description
+pub const DEVICE_DESCRIPTION: Tag = Tag(0x0050, 0x0020);
+/// (0050,0021) Long Device Description
+pub const LONG_DEVICE_DESCRIPTION: Tag = Tag(0x0050, 0x0021);
+/// (0052,0001) Contrast Bolus Ingredient Percent by Volume
+pub const CONTRAST_BOLUS_INGREDIENT_PERCENT_BY_VOLUME: Tag = Tag(0x0052, 0x0001);
+/// (0052,0002) OCT Focal Distance
+pub const OCT_FOCAL_DISTANCE: Tag = Tag(0x0052, 0x0002);
+/// (0052,0003) Beam Spot Size
+pub const BEAM_SPOT_SIZE: Tag = Tag(0x0052, 0x0003);
+/// (0052,0004) Effective Refractive Index
+pub const EFFECTIVE_REFRACTIVE_INDEX: Tag = Tag(0x0052, 0x0004);
+/// (0052,0006) OCT Acquisition Domain
+pub const OCT_ACQUISITION_DOMAIN: Tag = Tag(0x0052, 0x0006);
+/// (0052,0007) OCT Optical Center Wavelength
+pub const OCT_OPTICAL_CENTER_WAVELENGTH: Tag = Tag(0x0052, 0x0007);
+/// (0052,0008) Axial Resolution
+pub const AXIAL_RESOLUTION: Tag = Tag(0x0052, 0x0008);
+/// (0052,0009) Ranging Depth
+pub const RANGING_DEPTH: Tag = Tag(0x0052, 0x0009);
+/// (0052,0011) A Line Rate
+pub const A_LINE_RATE: Tag = Tag(0x0052, 0x0011);
+/// (0052,0012) A Lines per Frame
+pub const A_LINES_PER_FRAME: Tag = Tag(0x0052, 0x0012);
+/// (0052,0013) Catheter Rotational Rate
+pub const CATHETER_ROTATIONAL_RATE: Tag = Tag(0x0052, 0x0013);
+/// (0052,0014) A Line Pixel Spacing
+pub const A_LINE_PIXEL_SPACING: Tag = Tag(0x0052, 0x0014);
+/// (0052,0016) Mode of Percutaneous Access Sequence
+pub const MODE_OF_PERCUTANEOUS_ACCESS_SEQUENCE: Tag = Tag(0x0052, 0x0016);
+/// (0052,0025) Intravascular OCT Frame Type Sequence
+pub const INTRAVASCULAR_OCT_FRAME_TYPE_SEQUENCE: Tag = Tag(0x0052, 0x0025);
+/// (0052,0026) OCTZ Offset Applied
+pub const OCTZ_OFFSET_APPLIED: Tag = Tag(0x0052, 0x0026);
+/// (0052,0027) Intravascular Frame Content Sequence
+pub const INTRAVASCULAR_FRAME_CONTENT_SEQUENCE: Tag = Tag(0x0052, 0x0027);
+/// (0052,0028) Intravascular Longitudinal Distance
+pub const INTRAVASCULAR_LONGITUDINAL_DISTANCE: Tag = Tag(0x0052, 0x0028);
+/// (0052,0029) Intravascular OCT Frame Content Sequence
+pub const INTRAVASCULAR_OCT_FRAME_CONTENT_SEQUENCE: Tag = Tag(0x0052, 0x0029);
+/// (0052,0030) OCTZ Offset Correction
+pub const OCTZ_OFFSET_CORRECTION: Tag = Tag(0x0052, 0x0030);
+/// (0052,0031) Catheter Direction of Rotation
+pub const CATHETER_DIRECTION_OF_ROTATION: Tag = Tag(0x0052, 0x0031);
+/// (0052,0033) Seam Line Location
+pub const SEAM_LINE_LOCATION: Tag = Tag(0x0052, 0x0033);
+/// (0052,0034) First A Line Location
+pub const FIRST_A_LINE_LOCATION: Tag = Tag(0x0052, 0x0034);
+/// (0052,0036) Seam Line Index
+pub const SEAM_LINE_INDEX: Tag = Tag(0x0052, 0x0036);
+/// (0052,0038) Number of Padded A Lines
+pub const NUMBER_OF_PADDED_A_LINES: Tag = Tag(0x0052, 0x0038);
+/// (0052,0039) Interpolation Type
+pub const INTERPOLATION_TYPE: Tag = Tag(0x0052, 0x0039);
+/// (0052,003A) Refractive Index Applied
+pub const REFRACTIVE_INDEX_APPLIED: Tag = Tag(0x0052, 0x003A);
+/// (0054,0010) Energy Window Vector
+pub const ENERGY_WINDOW_VECTOR: Tag = Tag(0x0054, 0x0010);
+/// (0054,0011) Number of Energy Windows
+pub const NUMBER_OF_ENERGY_WINDOWS: Tag = Tag(0x0054, 0x0011);
+/// (0054,0012) Energy Window Information Sequence
+pub const ENERGY_WINDOW_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0012);
+/// (0054,0013) Energy Window Range Sequence
+pub const ENERGY_WINDOW_RANGE_SEQUENCE: Tag = Tag(0x0054, 0x0013);
+/// (0054,0014) Energy Window Lower Limit
+pub const ENERGY_WINDOW_LOWER_LIMIT: Tag = Tag(0x0054, 0x0014);
+/// (0054,0015) Energy Window Upper Limit
+pub const ENERGY_WINDOW_UPPER_LIMIT: Tag = Tag(0x0054, 0x0015);
+/// (0054,0016) Radiopharmaceutical Information Sequence
+pub const RADIOPHARMACEUTICAL_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0016);
+/// (0054,0017) Residual Syringe Counts
+pub const RESIDUAL_SYRINGE_COUNTS: Tag = Tag(0x0054, 0x0017);
+/// (0054,0018) Energy Window Name
+pub const ENERGY_WINDOW_NAME: Tag = Tag(0x0054, 0x0018);
+/// (0054,0020) Detector Vector
+pub const DETECTOR_VECTOR: Tag = Tag(0x0054, 0x0020);
+/// (0054,0021) Number of Detectors
+pub const NUMBER_OF_DETECTORS: Tag = Tag(0x0054, 0x0021);
+/// (0054,0022) Detector Information Sequence
+pub const DETECTOR_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0022);
+/// (0054,0030) Phase Vector
+pub const PHASE_VECTOR: Tag = Tag(0x0054, 0x0030);
+/// (0054,0031) Number of Phases
+pub const NUMBER_OF_PHASES: Tag = Tag(0x0054, 0x0031);
+/// (0054,0032) Phase Information Sequence
+pub const PHASE_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0032);
+/// (0054,0033) Number of Frames in Phase
+pub const NUMBER_OF_FRAMES_IN_PHASE: Tag = Tag(0x0054, 0x0033);
+/// (0054,0036) Phase Delay
+pub const PHASE_DELAY: Tag = Tag(0x0054, 0x0036);
+/// (0054,0038) Pause Between Frames
+pub const PAUSE_BETWEEN_FRAMES: Tag = Tag(0x0054, 0x0038);
+/// (0054,0039) Phase Description
+pub const PHASE_DESCRIPTION: Tag = Tag(0x0054, 0x0039);
+/// (0054,0050) Rotation Vector
+pub const ROTATION_VECTOR: Tag = Tag(0x0054, 0x0050);
+/// (0054,0051) Number of Rotations
+pub const NUMBER_OF_ROTATIONS: Tag = Tag(0x0054, 0x0051);
+/// (0054,0052) Rotation Information Sequence
+pub const ROTATION_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0052);
+/// (0054,0053) Number of Frames in Rotation
+pub const NUMBER_OF_FRAMES_IN_ROTATION: Tag = Tag(0x0054, 0x0053);
+/// (0054,0060) RR Interval Vector
+pub const RR_INTERVAL_VECTOR: Tag = Tag(0x0054, 0x0060);
+/// (0054,0061) Number of RR Intervals
+pub const NUMBER_OF_RR_INTERVALS: Tag = Tag(0x0054, 0x0061);
+/// (0054,0062) Gated Information Sequence
+pub const GATED_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0062);
+/// (0054,0063) Data Information Sequence
+pub const DATA_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0063);
+/// (0054,0070) Time Slot Vector
+pub const TIME_SLOT_VECTOR: Tag = Tag(0x0054, 0x0070);
+/// (0054,0071) Number of Time Slots
+pub const NUMBER_OF_TIME_SLOTS: Tag = Tag(0x0054, 0x0071);
+/// (0054,0072) Time Slot Information Sequence
+pub const TIME_SLOT_INFORMATION_SEQUENCE: Tag = Tag(0x0054, 0x0072);
+/// (0054,0073) Time Slot Time
+pub const TIME_SLOT_TIME: Tag = Tag(0x0054, 0x0073);
+/// (0054,0080) Slice Vector
+pub const SLICE_VECTOR: Tag = Tag(0x0054, 0x0080);
+/// (0054,0081) Number of Slices
+pub const NUMBER_OF_SLICES: Tag = Tag(0x0054, 0x0081);
+/// (0054,0090) Angular View Vector
+pub const ANGULAR_VIEW_VECTOR: Tag = Tag(0x0054, 0x0090);
+/// (0054,0100) Time Slice Vector
+pub const TIME_SLICE_VECTOR: Tag = Tag(0x0054, 0x0100);
+/// (0054,0101) Number of Time Slices
+pub const NUMBER_OF_TIME_SLICES: Tag = Tag(0x0054, 0x0101);
+/// (0054,0200) Start Angle
+pub const START_ANGLE: Tag = Tag(0x0054, 0x0200);
+/// (0054,0202) Type of Detector Motion
+pub const TYPE_OF_DETECTOR_MOTION: Tag = Tag(0x0054, 0x0202);
+/// (0054,0210) Trigger Vector
+pub const TRIGGER_VECTOR: Tag = Tag(0x0054, 0x0210);
+/// (0054,0211) Number of Triggers in Phase
+pub const NUMBER_OF_TRIGGERS_IN_PHASE: Tag = Tag(0x0054, 0x0211);
+/// (0054,0220) View Code Sequence
+pub const VIEW_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0220);
+/// (0054,0222) View Modifier Code Sequence
+pub const VIEW_MODIFIER_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0222);
+/// (0054,0300) Radionuclide Code Sequence
+pub const RADIONUCLIDE_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0300);
+/// (0054,0302) Administration Route Code Sequence
+pub const ADMINISTRATION_ROUTE_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0302);
+/// (0054,0304) Radiopharmaceutical Code Sequence
+pub const RADIOPHARMACEUTICAL_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0304);
+/// (0054,0306) Calibration Data Sequence
+pub const CALIBRATION_DATA_SEQUENCE: Tag = Tag(0x0054, 0x0306);
+/// (0054,0308) Energy Window Number
+pub const ENERGY_WINDOW_NUMBER: Tag = Tag(0x0054, 0x0308);
+/// (0054,0400) Image ID
+pub const IMAGE_ID: Tag = Tag(0x0054, 0x0400);
+/// (0054,0410) Patient Orientation Code Sequence
+pub const PATIENT_ORIENTATION_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0410);
+/// (0054,0412) Patient Orientation Modifier Code Sequence
+pub const PATIENT_ORIENTATION_MODIFIER_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0412);
+/// (0054,0414) Patient Gantry Relationship Code Sequence
+pub const PATIENT_GANTRY_RELATIONSHIP_CODE_SEQUENCE: Tag = Tag(0x0054, 0x0414);
+/// (0054,0500) Slice Progression Direction
+pub const SLICE_PROGRESSION_DIRECTION: Tag = Tag(0x0054, 0x0500);
+/// (0054,0501) Scan Progression Direction
+pub const SCAN_PROGRESSION_DIRECTION: Tag = Tag(0x0054, 0x0501);
+/// (0054,1000) Series Type
+pub const SERIES_TYPE: Tag = Tag(0x0054, 0x1000);
+/// (0054,1001) Units
+pub const UNITS: Tag = Tag(0x0054, 0x1001);
+/// (0054,1002) Counts Source
+pub const COUNTS_SOURCE: Tag = Tag(0x0054, 0x1002);
+/// (0054,1004) Reprojection Method
+pub const REPROJECTION_METHOD: Tag = Tag(0x0054, 0x1004);
+/// (0054,1006) SUV Type
+pub const SUV_TYPE: Tag = Tag(0x0054, 0x1006);
+/// (0054,1100) Randoms Correction Method
+pub const RANDOMS_CORRECTION_METHOD: Tag = Tag(0x0054, 0x1100);
+/// (0054,1101) Attenuation Correction Method
+pub const ATTENUATION_CORRECTION_METHOD: Tag = Tag(0x0054, 0x1101);
+/// (0054,1102) Decay Correction
+pub const DECAY_CORRECTION: Tag = Tag(0x0054, 0x1102);
+/// (0054,1103) Reconstruction Method
+pub const RECONSTRUCTION_METHOD: Tag = Tag(0x0054, 0x1103);
+/// (0054,1104) Detector Lines of Response Used
+pub const DETECTOR_LINES_OF_RESPONSE_USED: Tag = Tag(0x0054, 0x1104);
+/// (0054,1105) Scatter Correction Method
+pub const SCATTER_CORRECTION_METHOD: Tag = Tag(0x0054, 0x1105);
+/// (0054,1200) Axial Acceptance
+pub const AXIAL_ACCEPTANCE: Tag = Tag(0x0054, 0x1200);
+/// (0054,1201) Axial Mash
+pub const AXIAL_MASH: Tag = Tag(0x0054, 0x1201);
+/// (0054,1202) Transverse Mash
+pub const TRANSVERSE_MASH: Tag = Tag(0x0054, 0x1202);
+/// (0054,1203) Detector Element Size
+pub const DETECTOR_ELEMENT_SIZE: Tag = Tag(0x0054, 0x1203);
+/// (0054,1210) Coincidence Window Width
+pub const COINCIDENCE_WINDOW_WIDTH: Tag = Tag(0x0054, 0x1210);
+/// (0054,1220) Secondary Counts Type
+pub const SECONDARY_COUNTS_TYPE: Tag = Tag(0x0054, 0x1220);
+/// (0054,1300) Frame Reference Time
+pub const FRAME_REFERENCE_TIME: Tag = Tag(0x0054, 0x1300);
+/// (0054,1310) Primary Prompts Counts Accumulated
+pub const PRIMARY_PROMPTS_COUNTS_ACCUMULATED: Tag = Tag(0x0054, 0x1310);
+/// (0054,1311) Secondary Counts Accumulated
+pub const SECONDARY_COUNTS_ACCUMULATED: Tag = Tag(0x0054, 0x1311);
+/// (0054,1320) Slice Sensitivity Factor
+pub const SLICE_SENSITIVITY_FACTOR: Tag = Tag(0x0054, 0x1320);
+/// (0054,1321) Decay Factor
+pub const DECAY_FACTOR: Tag = Tag(0x0054, 0x1321);
+/// (0054,1322) Dose Calibration Factor
+pub const DOSE_CALIBRATION_FACTOR: Tag = Tag(0x0054, 0x1322);
+/// (0054,1323) Scatter Fraction Factor
+pub const SCATTER_FRACTION_FACTOR: Tag = Tag(0x0054, 0x1323);
+/// (0054,1324) Dead Time Factor
+pub const DEAD_TIME_FACTOR: Tag = Tag(0x0054, 0x1324);
+/// (0054,1330) Image Index
+pub const IMAGE_INDEX: Tag = Tag(0x0054, 0x1330);
+/// (0054,1400) Counts Included (retired)
+pub const COUNTS_INCLUDED: Tag = Tag(0x0054, 0x1400);
+/// (0054,1401) Dead Time Correction Flag (retired)
+pub const DEAD_TIME_CORRECTION_FLAG: Tag = Tag(0x0054, 0x1401);
+/// (0060,3000) Histogram Sequence
+pub const HISTOGRAM_SEQUENCE: Tag = Tag(0x0060, 0x3000);
+/// (0060,3002) Histogram Number of Bins
+pub const HISTOGRAM_NUMBER_OF_BINS: Tag = Tag(0x0060, 0x3002);
+/// (0060,3004) Histogram First Bin Value
+pub const HISTOGRAM_FIRST_BIN_VALUE: Tag = Tag(0x0060, 0x3004);
+/// (0060,3006) Histogram Last Bin Value
+pub const HISTOGRAM_LAST_BIN_VALUE: Tag = Tag(0x0060, 0x3006);
+/// (0060,3008) Histogram Bin Width
+pub const HISTOGRAM_BIN_WIDTH: Tag = Tag(0x0060, 0x3008);
+/// (0060,3010) Histogram Explanation
+pub const HISTOGRAM_EXPLANATION: Tag = Tag(0x0060, 0x3010);
+/// (0060,3020) Histogram Data
+pub const HISTOGRAM_DATA: Tag = Tag(0x0060, 0x3020);
+/// (0062,0001) Segmentation Type
+pub const SEGMENTATION_TYPE: Tag = Tag(0x0062, 0x0001);
+/// (0062,0002) Segment Sequence
+pub const SEGMENT_SEQUENCE: Tag = Tag(0x0062, 0x0002);
+/// (0062,0003) Segmented Property Category Code Sequence
+pub const SEGMENTED_PROPERTY_CATEGORY_CODE_SEQUENCE: Tag = Tag(0x0062, 0x0003);
+/// (0062,0004) Segment Number
+pub const SEGMENT_NUMBER: Tag = Tag(0x0062, 0x0004);
+/// (0062,0005) Segment Label
+pub const SEGMENT_LABEL: Tag = Tag(0x0062, 0x0005);
+/// (0062,0006) Segment Description
+pub const SEGMENT_DESCRIPTION: Tag = Tag(0x0062, 0x0006);
+/// (0062,0007) Segmentation Algorithm Identification Sequence
+pub const SEGMENTATION_ALGORITHM_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0062, 0x0007);
+/// (0062,0008) Segment Algorithm Type
+pub const SEGMENT_ALGORITHM_TYPE: Tag = Tag(0x0062, 0x0008);
+/// (0062,0009) Segment Algorithm Name
+pub const SEGMENT_ALGORITHM_NAME: Tag = Tag(0x0062, 0x0009);
+/// (0062,000A) Segment Identification Sequence
+pub const SEGMENT_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0062, 0x000A);
+/// (0062,000B) Referenced Segment Number
+pub const REFERENCED_SEGMENT_NUMBER: Tag = Tag(0x0062, 0x000B);
+/// (0062,000C) Recommended Display Grayscale Value
+pub const RECOMMENDED_DISPLAY_GRAYSCALE_VALUE: Tag = Tag(0x0062, 0x000C);
+/// (0062,000D) Recommended Display CIE Lab Value
+pub const RECOMMENDED_DISPLAY_CIE_LAB_VALUE: Tag = Tag(0x0062, 0x000D);
+/// (0062,000E) Maximum Fractional Value
+pub const MAXIMUM_FRACTIONAL_VALUE: Tag = Tag(0x0062, 0x000E);
+/// (0062,000F) Segmented Property Type Code Sequence
+pub const SEGMENTED_PROPERTY_TYPE_CODE_SEQUENCE: Tag = Tag(0x0062, 0x000F);
+/// (0062,0010) Segmentation Fractional Type
+pub const SEGMENTATION_FRACTIONAL_TYPE: Tag = Tag(0x0062, 0x0010);
+/// (0062,0011) Segmented Property Type Modifier Code Sequence
+pub const SEGMENTED_PROPERTY_TYPE_MODIFIER_CODE_SEQUENCE: Tag = Tag(0x0062, 0x0011);
+/// (0062,0012) Used Segments Sequence
+pub const USED_SEGMENTS_SEQUENCE: Tag = Tag(0x0062, 0x0012);
+/// (0062,0013) Segments Overlap
+pub const SEGMENTS_OVERLAP: Tag = Tag(0x0062, 0x0013);
+/// (0062,0020) Tracking ID
+pub const TRACKING_ID: Tag = Tag(0x0062, 0x0020);
+/// (0062,0021) Tracking UID
+pub const TRACKING_UID: Tag = Tag(0x0062, 0x0021);
+/// (0064,0002) Deformable Registration Sequence
+pub const DEFORMABLE_REGISTRATION_SEQUENCE: Tag = Tag(0x0064, 0x0002);
+/// (0064,0003) Source Frame of Reference UID
+pub const SOURCE_FRAME_OF_REFERENCE_UID: Tag = Tag(0x0064, 0x0003);
+/// (0064,0005) Deformable Registration Grid Sequence
+pub const DEFORMABLE_REGISTRATION_GRID_SEQUENCE: Tag = Tag(0x0064, 0x0005);
+/// (0064,0007) Grid Dimensions
+pub const GRID_DIMENSIONS: Tag = Tag(0x0064, 0x0007);
+/// (0064,0008) Grid Resolution
+pub const GRID_RESOLUTION: Tag = Tag(0x0064, 0x0008);
+/// (0064,0009) Vector Grid Data
+pub const VECTOR_GRID_DATA: Tag = Tag(0x0064, 0x0009);
+/// (0064,000F) Pre Deformation Matrix Registration Sequence
+pub const PRE_DEFORMATION_MATRIX_REGISTRATION_SEQUENCE: Tag = Tag(0x0064, 0x000F);
+/// (0064,0010) Post Deformation Matrix Registration Sequence
+pub const POST_DEFORMATION_MATRIX_REGISTRATION_SEQUENCE: Tag = Tag(0x0064, 0x0010);
+/// (0066,0001) Number of Surfaces
+pub const NUMBER_OF_SURFACES: Tag = Tag(0x0066, 0x0001);
+/// (0066,0002) Surface Sequence
+pub const SURFACE_SEQUENCE: Tag = Tag(0x0066, 0x0002);
+/// (0066,0003) Surface Number
+pub const SURFACE_NUMBER: Tag = Tag(0x0066, 0x0003);
+/// (0066,0004) Surface Comments
+pub const SURFACE_COMMENTS: Tag = Tag(0x0066, 0x0004);
+/// (0066,0009) Surface Processing
+pub const SURFACE_PROCESSING: Tag = Tag(0x0066, 0x0009);
+/// (0066,000A) Surface Processing Ratio
+pub const SURFACE_PROCESSING_RATIO: Tag = Tag(0x0066, 0x000A);
+/// (0066,000B) Surface Processing Description
+pub const SURFACE_PROCESSING_DESCRIPTION: Tag = Tag(0x0066, 0x000B);
+/// (0066,000C) Recommended Presentation Opacity
+pub const RECOMMENDED_PRESENTATION_OPACITY: Tag = Tag(0x0066, 0x000C);
+/// (0066,000D) Recommended Presentation Type
+pub const RECOMMENDED_PRESENTATION_TYPE: Tag = Tag(0x0066, 0x000D);
+/// (0066,000E) Finite Volume
+pub const FINITE_VOLUME: Tag = Tag(0x0066, 0x000E);
+/// (0066,0010) Manifold
+pub const MANIFOLD: Tag = Tag(0x0066, 0x0010);
+/// (0066,0011) Surface Points Sequence
+pub const SURFACE_POINTS_SEQUENCE: Tag = Tag(0x0066, 0x0011);
+/// (0066,0012) Surface Points Normals Sequence
+pub const SURFACE_POINTS_NORMALS_SEQUENCE: Tag = Tag(0x0066, 0x0012);
+/// (0066,0013) Surface Mesh Primitives Sequence
+pub const SURFACE_MESH_PRIMITIVES_SEQUENCE: Tag = Tag(0x0066, 0x0013);
+/// (0066,0015) Number of Surface Points
+pub const NUMBER_OF_SURFACE_POINTS: Tag = Tag(0x0066, 0x0015);
+/// (0066,0016) Point Coordinates Data
+pub const POINT_COORDINATES_DATA: Tag = Tag(0x0066, 0x0016);
+/// (0066,0017) Point Position Accuracy
+pub const POINT_POSITION_ACCURACY: Tag = Tag(0x0066, 0x0017);
+/// (0066,0018) Mean Point Distance
+pub const MEAN_POINT_DISTANCE: Tag = Tag(0x0066, 0x0018);
+/// (0066,0019) Maximum Point Distance
+pub const MAXIMUM_POINT_DISTANCE: Tag = Tag(0x0066, 0x0019);
+/// (0066,001A) Points Bounding Box Coordinates
+pub const POINTS_BOUNDING_BOX_COORDINATES: Tag = Tag(0x0066, 0x001A);
+/// (0066,001B) Axis of Rotation
+pub const AXIS_OF_ROTATION: Tag = Tag(0x0066, 0x001B);
+/// (0066,001C) Center of Rotation
+pub const CENTER_OF_ROTATION: Tag = Tag(0x0066, 0x001C);
+/// (0066,001E) Number of Vectors
+pub const NUMBER_OF_VECTORS: Tag = Tag(0x0066, 0x001E);
+/// (0066,001F) Vector Dimensionality
+pub const VECTOR_DIMENSIONALITY: Tag = Tag(0x0066, 0x001F);
+/// (0066,0020) Vector Accuracy
+pub const VECTOR_ACCURACY: Tag = Tag(0x0066, 0x0020);
+/// (0066,0021) Vector Coordinate Data
+pub const VECTOR_COORDINATE_DATA: Tag = Tag(0x0066, 0x0021);
+/// (0066,0023) Triangle Point Index List (retired)
+pub const TRIANGLE_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0023);
+/// (0066,0024) Edge Point Index List (retired)
+pub const EDGE_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0024);
+/// (0066,0025) Vertex Point Index List (retired)
+pub const VERTEX_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0025);
+/// (0066,0026) Triangle Strip Sequence
+pub const TRIANGLE_STRIP_SEQUENCE: Tag = Tag(0x0066, 0x0026);
+/// (0066,0027) Triangle Fan Sequence
+pub const TRIANGLE_FAN_SEQUENCE: Tag = Tag(0x0066, 0x0027);
+/// (0066,0028) Line Sequence
+pub const LINE_SEQUENCE: Tag = Tag(0x0066, 0x0028);
+/// (0066,0029) Primitive Point Index List (retired)
+pub const PRIMITIVE_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0029);
+/// (0066,002A) Surface Count
+pub const SURFACE_COUNT: Tag = Tag(0x0066, 0x002A);
+/// (0066,002B) Referenced Surface Sequence
+pub const REFERENCED_SURFACE_SEQUENCE: Tag = Tag(0x0066, 0x002B);
+/// (0066,002C) Referenced Surface Number
+pub const REFERENCED_SURFACE_NUMBER: Tag = Tag(0x0066, 0x002C);
+/// (0066,002D) Segment Surface Generation Algorithm Identification Sequence
+pub const SEGMENT_SURFACE_GENERATION_ALGORITHM_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0066, 0x002D);
+/// (0066,002E) Segment Surface Source Instance Sequence
+pub const SEGMENT_SURFACE_SOURCE_INSTANCE_SEQUENCE: Tag = Tag(0x0066, 0x002E);
+/// (0066,002F) Algorithm Family Code Sequence
+pub const ALGORITHM_FAMILY_CODE_SEQUENCE: Tag = Tag(0x0066, 0x002F);
+/// (0066,0030) Algorithm Name Code Sequence
+pub const ALGORITHM_NAME_CODE_SEQUENCE: Tag = Tag(0x0066, 0x0030);
+/// (0066,0031) Algorithm Version
+pub const ALGORITHM_VERSION: Tag = Tag(0x0066, 0x0031);
+/// (0066,0032) Algorithm Parameters
+pub const ALGORITHM_PARAMETERS: Tag = Tag(0x0066, 0x0032);
+/// (0066,0034) Facet Sequence
+pub const FACET_SEQUENCE: Tag = Tag(0x0066, 0x0034);
+/// (0066,0035) Surface Processing Algorithm Identification Sequence
+pub const SURFACE_PROCESSING_ALGORITHM_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0066, 0x0035);
+/// (0066,0036) Algorithm Name
+pub const ALGORITHM_NAME: Tag = Tag(0x0066, 0x0036);
+/// (0066,0037) Recommended Point Radius
+pub const RECOMMENDED_POINT_RADIUS: Tag = Tag(0x0066, 0x0037);
+/// (0066,0038) Recommended Line Thickness
+pub const RECOMMENDED_LINE_THICKNESS: Tag = Tag(0x0066, 0x0038);
+/// (0066,0040) Long Primitive Point Index List
+pub const LONG_PRIMITIVE_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0040);
+/// (0066,0041) Long Triangle Point Index List
+pub const LONG_TRIANGLE_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0041);
+/// (0066,0042) Long Edge Point Index List
+pub const LONG_EDGE_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0042);
+/// (0066,0043) Long Vertex Point Index List
+pub const LONG_VERTEX_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0043);
+/// (0066,0101) Track Set Sequence
+pub const TRACK_SET_SEQUENCE: Tag = Tag(0x0066, 0x0101);
+/// (0066,0102) Track Sequence
+pub const TRACK_SEQUENCE: Tag = Tag(0x0066, 0x0102);
+/// (0066,0103) Recommended Display CIE Lab Value List
+pub const RECOMMENDED_DISPLAY_CIE_LAB_VALUE_LIST: Tag = Tag(0x0066, 0x0103);
+/// (0066,0104) Tracking Algorithm Identification Sequence
+pub const TRACKING_ALGORITHM_IDENTIFICATION_SEQUENCE: Tag = Tag(0x0066, 0x0104);
+/// (0066,0105) Track Set Number
+pub const TRACK_SET_NUMBER: Tag = Tag(0x0066, 0x0105);
+/// (0066,0106) Track Set Label
+pub const TRACK_SET_LABEL: Tag = Tag(0x0066, 0x0106);
+/// (0066,0107) Track Set Description
+pub const TRACK_SET_DESCRIPTION: Tag = Tag(0x0066, 0x0107);
+/// (0066,0108) Track Set Anatomical Type Code Sequence
+pub const TRACK_SET_ANATOMICAL_TYPE_CODE_SEQUENCE: Tag = Tag(0x0066, 0x0108);
+/// (0066,0121) Measurements Sequence
+pub const MEASUREMENTS_SEQUENCE: Tag = Tag(0x0066, 0x0121);
+/// (0066,0124) Track Set Statistics Sequence
+pub const TRACK_SET_STATISTICS_SEQUENCE: Tag = Tag(0x0066, 0x0124);
+/// (0066,0125) Floating Point Values
+pub const FLOATING_POINT_VALUES: Tag = Tag(0x0066, 0x0125);
+/// (0066,0129) Track Point Index List
+pub const TRACK_POINT_INDEX_LIST: Tag = Tag(0x0066, 0x0129);
+/// (0066,0130) Track Statistics Sequence
+pub const TRACK_STATISTICS_SEQUENCE: Tag = Tag(0x0066, 0x0130);
+/// (0066,0132) Measurement Values Sequence
+pub const MEASUREMENT_VALUES_SEQUENCE: Tag = Tag(0x0066, 0x0132);
+/// (0066,0133) Diffusion Acquisition Code Sequence
+pub const DIFFUSION_ACQUISITION_CODE_SEQUENCE: Tag = Tag(0x0066, 0x0133);
+/// (0066,0134) Diffusion Model Code Sequence
+pub const DIFFUSION_MODEL_CODE_SEQUENCE: Tag = Tag(0x0066, 0x0134);
+/// (0068,6210) Implant Size
+pub const IMPLANT_SIZE: Tag = Tag(0x0068, 0x6210);
+/// (0068,6221) Implant Template Version
+pub const IMPLANT_TEMPLATE_VERSION: Tag = Tag(0x0068, 0x6221);
+/// (0068,6222) Replaced Implant Template Sequence
+pub const REPLACED_IMPLANT_TEMPLATE_SEQUENCE: Tag = Tag(0x0068, 0x6222);
+/// (0068,6223) Implant Type
+pub const IMPLANT_TYPE: Tag = Tag(0x0068, 0x6223);
+/// (0068,6224) Derivation Implant Template Sequence
+pub const DERIVATION_IMPLANT_TEMPLATE_SEQUENCE: Tag = Tag(0x0068, 0x6224);
+/// (0068,6225) Original Implant Template Sequence
+pub const ORIGINAL_IMPLANT_TEMPLATE_SEQUENCE: Tag = Tag(0x0068, 0x6225);
+/// (0068,6226) Effective Date Time
+pub const EFFECTIVE_DATE_TIME: Tag = Tag(0x0068, 0x6226);
+/// (0068,6230) Implant Target Anatomy Sequence
+pub const IMPLANT_TARGET_ANATOMY_SEQUENCE: Tag = Tag(0x0068, 0x6230);
+/// (0068,6260) Information from Manufacturer Sequence
+pub const INFORMATION_FROM_MANUFACTURER_SEQUENCE: Tag = Tag(0x0068, 0x6260);
+/// (0068,6265) Notification from Manufacturer Sequence
+pub const NOTIFICATION_FROM_MANUFACTURER_SEQUENCE: Tag = Tag(0x0068, 0x6265);
+/// (0068,6270) Information Issue Date Time
+pub const INFORMATION_ISSUE_DATE_TIME: Tag = Tag(0x0068, 0x6270);
+/// (0068,6280) Information Summary
+pub const INFORMATION_SUMMARY: Tag = Tag(0x0068, 0x6280);
+/// (0068,62A0) Implant Regulatory Disapproval Code Sequence
+pub const IMPLANT_REGULATORY_DISAPPROVAL_CODE_SEQUENCE: Tag = Tag(0x0068, 0x62A0);
+/// (0068,62A5) Overall Template Spatial Tolerance
+pub const OVERALL_TEMPLATE_SPATIAL_TOLERANCE: Tag = Tag(0x0068, 0x62A5);
+/// (0068,62C0) HPGL Document Sequence
+pub const HPGL_DOCUMENT_SEQUENCE: Tag = Tag(0x0068, 0x62C0);
+/// (0068,62D0) HPGL Document ID
+pub const HPGL_DOCUMENT_ID: Tag = Tag(0x0068, 0x62D0);
+/// (0068,62D5) HPGL Document Label
+pub const HPGL_DOCUMENT_LABEL: Tag = Tag(0x0068, 0x62D5);
+/// (0068,62E0) View Orientation Code Sequence
+pub const VIEW_ORIENTATION_CODE_SEQUENCE: Tag = Tag(0x0068, 0x62E0);
+/// (0068,62F0) View Orientation Modifier Code Sequence
+pub const VIEW_ORIENTATION_MODIFIER_CODE_SEQUENCE: Tag = Tag(0x0068, 0x62F0);
+/// (0068,62F2) HPGL Document Scaling
+pub const HPGL_DOCUMENT_SCALING: Tag = Tag(0x0068, 0x62F2);
+/// (0068,6300) HPGL Document
+pub const HPGL_DOCUMENT: Tag = Tag(0x0068, 0x6300);
+/// (0068,6310) HPGL Contour Pen Number
+pub const HPGL_CONTOUR_PEN_NUMBER: Tag = Tag(0x0068, 0x6310);
+/// (0068,6320) HPGL Pen Sequence
+pub const HPGL_PEN_SEQUENCE: Tag = Tag(0x0068, 0x6320);
+/// (0068,6330) HPGL Pen Number
+pub const HPGL_PEN_NUMBER: Tag = Tag(0x0068, 0x6330);
+/// (0068,6340) HPGL Pen Label
+pub const HPGL_PEN_LABEL: Tag = Tag(0x0068, 0x6340);
+/// (0068,6345) HPGL Pen Description
+pub const HPGL_PEN_DESCRIPTION: Tag = Tag(0x0068, 0x6345);
+/// (0068,6346) Recommended Rotation Point
+pub const RECOMMENDED_ROTATION_POINT: Tag = Tag(0x0068, 0x6346);
+/// (0068,6347) Bounding Rectangle
+pub const BOUNDING_RECTANGLE: Tag = Tag(0x0068, 0x6347);
+/// (0068,6350) Implant Template 3D Model Surface Number
+pub const IMPLANT_TEMPLATE3D_MODEL_SURFACE_NUMBER: Tag = Tag(0x0068, 0x6350);
+/// (0068,6360) Surface Model Description Sequence
+pub const SURFACE_MODEL_DESCRIPTION_SEQUENCE: Tag = Tag(0x0068, 0x6360);
+/// (0068,6380) Surface Model Label
+pub const SURFACE_MODEL_LABEL: Tag = Tag(0x0068, 0x6380);
+/// (0068,6390) Surface Model Scaling Factor
+pub const SURFACE_MODEL_SCALING_FACTOR: Tag = Tag(0x0068, 0x6390);
+/// (0068,63A0) Materials Code Sequence
+pub const MATERIALS_CODE_SEQUENCE: Tag = Tag(0x0068, 0x63A0);
+/// (0068,63A4) Coating Materials Code Sequence
+pub const COATING_MATERIALS_CODE_SEQUENCE: Tag = Tag(0x0068, 0x63A4);
+/// (0068,63A8) Implant Type Code Sequence
+pub const IMPLANT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0068, 0x63A8);
+/// (0068,63AC) Fixation Method Code Sequence
+pub const FIXATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0068, 0x63AC);
+/// (0068,63B0) Mating Feature Sets Sequence
+pub const MATING_FEATURE_SETS_SEQUENCE: Tag = Tag(0x0068, 0x63B0);
+/// (0068,63C0) Mating Feature Set ID
+pub const MATING_FEATURE_SET_ID: Tag = Tag(0x0068, 0x63C0);
+/// (0068,63D0) Mating Feature Set Label
+pub const MATING_FEATURE_SET_LABEL: Tag = Tag(0x0068, 0x63D0);
+/// (0068,63E0) Mating Feature Sequence
+pub const MATING_FEATURE_SEQUENCE: Tag = Tag(0x0068, 0x63E0);
+/// (0068,63F0) Mating Feature ID
+pub const MATING_FEATURE_ID: Tag = Tag(0x0068, 0x63F0);
+/// (0068,6400) Mating Feature Degree of Freedom Sequence
+pub const MATING_FEATURE_DEGREE_OF_FREEDOM_SEQUENCE: Tag = Tag(0x0068, 0x6400);
+/// (0068,6410) Degree of Freedom ID
+pub const DEGREE_OF_FREEDOM_ID: Tag = Tag(0x0068, 0x6410);
+/// (0068,6420) Degree of Freedom Type
+pub const DEGREE_OF_FREEDOM_TYPE: Tag = Tag(0x0068, 0x6420);
+/// (0068,6430) Two D Mating Feature Coordinates Sequence
+pub const TWO_D_MATING_FEATURE_COORDINATES_SEQUENCE: Tag = Tag(0x0068, 0x6430);
+/// (0068,6440) Referenced HPGL Document ID
+pub const REFERENCED_HPGL_DOCUMENT_ID: Tag = Tag(0x0068, 0x6440);
+/// (0068,6450) Two D Mating Point
+pub const TWO_D_MATING_POINT: Tag = Tag(0x0068, 0x6450);
+/// (0068,6460) Two D Mating Axes
+pub const TWO_D_MATING_AXES: Tag = Tag(0x0068, 0x6460);
+/// (0068,6470) Two D Degree of Freedom Sequence
+pub const TWO_D_DEGREE_OF_FREEDOM_SEQUENCE: Tag = Tag(0x0068, 0x6470);
+/// (0068,6490) Three D Degree of Freedom Axis
+pub const THREE_D_DEGREE_OF_FREEDOM_AXIS: Tag = Tag(0x0068, 0x6490);
+/// (0068,64A0) Range of Freedom
+pub const RANGE_OF_FREEDOM: Tag = Tag(0x0068, 0x64A0);
+/// (0068,64C0) Three D Mating Point
+pub const THREE_D_MATING_POINT: Tag = Tag(0x0068, 0x64C0);
+/// (0068,64D0) Three D Mating Axes
+pub const THREE_D_MATING_AXES: Tag = Tag(0x0068, 0x64D0);
+/// (0068,64F0) Two D Degree of Freedom Axis
+pub const TWO_D_DEGREE_OF_FREEDOM_AXIS: Tag = Tag(0x0068, 0x64F0);
+/// (0068,6500) Planning Landmark Point Sequence
+pub const PLANNING_LANDMARK_POINT_SEQUENCE: Tag = Tag(0x0068, 0x6500);
+/// (0068,6510) Planning Landmark Line Sequence
+pub const PLANNING_LANDMARK_LINE_SEQUENCE: Tag = Tag(0x0068, 0x6510);
+/// (0068,6520) Planning Landmark Plane Sequence
+pub const PLANNING_LANDMARK_PLANE_SEQUENCE: Tag = Tag(0x0068, 0x6520);
+/// (0068,6530) Planning Landmark ID
+pub const PLANNING_LANDMARK_ID: Tag = Tag(0x0068, 0x6530);
+/// (0068,6540) Planning Landmark Description
+pub const PLANNING_LANDMARK_DESCRIPTION: Tag = Tag(0x0068, 0x6540);
+/// (0068,6545) Planning Landmark Identification Code Sequence
+pub const PLANNING_LANDMARK_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag(0x0068, 0x6545);
+/// (0068,6550) Two D Point Coordinates Sequence
+pub const TWO_D_POINT_COORDINATES_SEQUENCE: Tag = Tag(0x0068, 0x6550);
+/// (0068,6560) Two D Point Coordinates
+pub const TWO_D_POINT_COORDINATES: Tag = Tag(0x0068, 0x6560);
+/// (0068,6590) Three D Point Coordinates
+pub const THREE_D_POINT_COORDINATES: Tag = Tag(0x0068, 0x6590);
+/// (0068,65A0) Two D Line Coordinates Sequence
+pub const TWO_D_LINE_COORDINATES_SEQUENCE: Tag = Tag(0x0068, 0x65A0);
+/// (0068,65B0) Two D Line Coordinates
+pub const TWO_D_LINE_COORDINATES: Tag = Tag(0x0068, 0x65B0);
+/// (0068,65D0) Three D Line Coordinates
+pub const THREE_D_LINE_COORDINATES: Tag = Tag(0x0068, 0x65D0);
+/// (0068,65E0) Two D Plane Coordinates Sequence
+pub const TWO_D_PLANE_COORDINATES_SEQUENCE: Tag = Tag(0x0068, 0x65E0);
+/// (0068,65F0) Two D Plane Intersection
+pub const TWO_D_PLANE_INTERSECTION: Tag = Tag(0x0068, 0x65F0);
+/// (0068,6610) Three D Plane Origin
+pub const THREE_D_PLANE_ORIGIN: Tag = Tag(0x0068, 0x6610);
+/// (0068,6620) Three D Plane Normal
+pub const THREE_D_PLANE_NORMAL: Tag = Tag(0x0068, 0x6620);
+/// (0068,7001) Model Modification
+pub const MODEL_MODIFICATION: Tag = Tag(0x0068, 0x7001);
+/// (0068,7002) Model Mirroring
+pub const MODEL_MIRRORING: Tag = Tag(0x0068, 0x7002);
+/// (0068,7003) Model Usage Code Sequence
+pub const MODEL_USAGE_CODE_SEQUENCE: Tag = Tag(0x0068, 0x7003);
+/// (0070,0001) Graphic Annotation Sequence
+pub const GRAPHIC_ANNOTATION_SEQUENCE: Tag = Tag(0x0070, 0x0001);
+/// (0070,0002) Graphic Layer
+pub const GRAPHIC_LAYER: Tag = Tag(0x0070, 0x0002);
+/// (0070,0003) Bounding Box Annotation Units
+pub const BOUNDING_BOX_ANNOTATION_UNITS: Tag = Tag(0x0070, 0x0003);
+/// (0070,0004) Anchor Point Annotation Units
+pub const ANCHOR_POINT_ANNOTATION_UNITS: Tag = Tag(0x0070, 0x0004);
+/// (0070,0005) Graphic Annotation Units
+pub const GRAPHIC_ANNOTATION_UNITS: Tag = Tag(0x0070, 0x0005);
+/// (0070,0006) Unformatted Text Value
+pub const UNFORMATTED_TEXT_VALUE: Tag = Tag(0x0070, 0x0006);
+/// (0070,0008) Text Object Sequence
+pub const TEXT_OBJECT_SEQUENCE: Tag = Tag(0x0070, 0x0008);
+/// (0070,0009) Graphic Object Sequence
+pub const GRAPHIC_OBJECT_SEQUENCE: Tag = Tag(0x0070, 0x0009);
+/// (0070,0010) Bounding Box Top Left Hand Corner
+pub const BOUNDING_BOX_TOP_LEFT_HAND_CORNER: Tag = Tag(0x0070, 0x0010);
+/// (0070,0011) Bounding Box Bottom Right Hand Corner
+pub const BOUNDING_BOX_BOTTOM_RIGHT_HAND_CORNER: Tag = Tag(0x0070, 0x0011);
+/// (0070,0012) Bounding Box Text Horizontal Justification
+pub const BOUNDING_BOX_TEXT_HORIZONTAL_JUSTIFICATION: Tag = Tag(0x0070, 0x0012);
+/// (0070,0014) Anchor Point
+pub const ANCHOR_POINT: Tag = Tag(0x0070, 0x0014);
+/// (0070,0015) Anchor Point Visibility
+pub const ANCHOR_POINT_VISIBILITY: Tag = Tag(0x0070, 0x0015);
+/// (0070,0020) Graphic Dimensions
+pub const GRAPHIC_DIMENSIONS: Tag = Tag(0x0070, 0x0020);
+/// (0070,0021) Number of Graphic Points
+pub const NUMBER_OF_GRAPHIC_POINTS: Tag = Tag(0x0070, 0x0021);
+/// (0070,0022) Graphic Data
+pub const GRAPHIC_DATA: Tag = Tag(0x0070, 0x0022);
+/// (0070,0023) Graphic Type
+pub const GRAPHIC_TYPE: Tag = Tag(0x0070, 0x0023);
+/// (0070,0024) Graphic Filled
+pub const GRAPHIC_FILLED: Tag = Tag(0x0070, 0x0024);
+/// (0070,0040) Image Rotation Retired (retired)
+pub const IMAGE_ROTATION_RETIRED: Tag = Tag(0x0070, 0x0040);
+/// (0070,0041) Image Horizontal Flip
+pub const IMAGE_HORIZONTAL_FLIP: Tag = Tag(0x0070, 0x0041);
+/// (0070,0042) Image Rotation
+pub const IMAGE_ROTATION: Tag = Tag(0x0070, 0x0042);
+/// (0070,0050) Displayed Area Top Left Hand Corner Trial (retired)
+pub const DISPLAYED_AREA_TOP_LEFT_HAND_CORNER_TRIAL: Tag = Tag(0x0070, 0x0050);
+/// (0070,0051) Displayed Area Bottom Right Hand Corner Trial (retired)
+pub const DISPLAYED_AREA_BOTTOM_RIGHT_HAND_CORNER_TRIAL: Tag = Tag(0x0070, 0x0051);
+/// (0070,0052) Displayed Area Top Left Hand Corner
+pub const DISPLAYED_AREA_TOP_LEFT_HAND_CORNER: Tag = Tag(0x0070, 0x0052);
+/// (0070,0053) Displayed Area Bottom Right Hand Corner
+pub const DISPLAYED_AREA_BOTTOM_RIGHT_HAND_CORNER: Tag = Tag(0x0070, 0x0053);
+/// (0070,005A) Displayed Area Selection Sequence
+pub const DISPLAYED_AREA_SELECTION_SEQUENCE: Tag = Tag(0x0070, 0x005A);
+/// (0070,0060) Graphic Layer Sequence
+pub const GRAPHIC_LAYER_SEQUENCE: Tag = Tag(0x0070, 0x0060);
+/// (0070,0062) Graphic Layer Order
+pub const GRAPHIC_LAYER_ORDER: Tag = Tag(0x0070, 0x0062);
+/// (0070,0066) Graphic Layer Recommended Display Grayscale Value
+pub const GRAPHIC_LAYER_RECOMMENDED_DISPLAY_GRAYSCALE_VALUE: Tag = Tag(0x0070, 0x0066);
+/// (0070,0067) Graphic Layer Recommended Display RGB Value (retired)
+pub const GRAPHIC_LAYER_RECOMMENDED_DISPLAY_RGB_VALUE: Tag = Tag(0x0070, 0x0067);
+/// (0070,0068) Graphic Layer Description
+pub const GRAPHIC_LAYER_DESCRIPTION: Tag = Tag(0x0070, 0x0068);
+/// (0070,0080) Content Label
+pub const CONTENT_LABEL: Tag = Tag(0x0070, 0x0080);
+/// (0070,0081) Content Description
+pub const CONTENT_DESCRIPTION: Tag = Tag(0x0070, 0x0081);
+/// (0070,0082) Presentation Creation Date
+pub const PRESENTATION_CREATION_DATE: Tag = Tag(0x0070, 0x0082);
+/// (0070,0083) Presentation Creation Time
+pub const PRESENTATION_CREATION_TIME: Tag = Tag(0x0070, 0x0083);
+/// (0070,0084) Content Creator Name
+pub const CONTENT_CREATOR_NAME: Tag = Tag(0x0070, 0x0084);
+/// (0070,0086) Content Creator Identification Code Sequence
+pub const CONTENT_CREATOR_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag(0x0070, 0x0086);
+/// (0070,0087) Alternate Content Description Sequence
+pub const ALTERNATE_CONTENT_DESCRIPTION_SEQUENCE: Tag = Tag(0x0070, 0x0087);
+/// (0070,0100) Presentation Size Mode
+pub const PRESENTATION_SIZE_MODE: Tag = Tag(0x0070, 0x0100);
+/// (0070,0101) Presentation Pixel Spacing
+pub const PRESENTATION_PIXEL_SPACING: Tag = Tag(0x0070, 0x0101);
+/// (0070,0102) Presentation Pixel Aspect Ratio
+pub const PRESENTATION_PIXEL_ASPECT_RATIO: Tag = Tag(0x0070, 0x0102);
+/// (0070,0103) Presentation Pixel Magnification Ratio
+pub const PRESENTATION_PIXEL_MAGNIFICATION_RATIO: Tag = Tag(0x0070, 0x0103);
+/// (0070,0207) Graphic Group Label
+pub const GRAPHIC_GROUP_LABEL: Tag = Tag(0x0070, 0x0207);
+/// (0070,0208) Graphic Group Description
+pub const GRAPHIC_GROUP_DESCRIPTION: Tag = Tag(0x0070, 0x0208);
+/// (0070,0209) Compound Graphic Sequence
+pub const COMPOUND_GRAPHIC_SEQUENCE: Tag = Tag(0x0070, 0x0209);
+/// (0070,0226) Compound Graphic Instance ID
+pub const COMPOUND_GRAPHIC_INSTANCE_ID: Tag = Tag(0x0070, 0x0226);
+/// (0070,0227) Font Name
+pub const FONT_NAME: Tag = Tag(0x0070, 0x0227);
+/// (0070,0228) Font Name Type
+pub const FONT_NAME_TYPE: Tag = Tag(0x0070, 0x0228);
+/// (0070,0229) CSS Font Name
+pub const CSS_FONT_NAME: Tag = Tag(0x0070, 0x0229);
+/// (0070,0230) Rotation Angle
+pub const ROTATION_ANGLE: Tag = Tag(0x0070, 0x0230);
+/// (0070,0231) Text Style Sequence
+pub const TEXT_STYLE_SEQUENCE: Tag = Tag(0x0070, 0x0231);
+/// (0070,0232) Line Style Sequence
+pub const LINE_STYLE_SEQUENCE: Tag = Tag(0x0070, 0x0232);
+/// (0070,0233) Fill Style Sequence
+pub const FILL_STYLE_SEQUENCE: Tag = Tag(0x0070, 0x0233);
+/// (0070,0234) Graphic Group Sequence
+pub const GRAPHIC_GROUP_SEQUENCE: Tag = Tag(0x0070, 0x0234);
+/// (0070,0241) Text Color CIE Lab Value
+pub const TEXT_COLOR_CIE_LAB_VALUE: Tag = Tag(0x0070, 0x0241);
+/// (0070,0242) Horizontal Alignment
+pub const HORIZONTAL_ALIGNMENT: Tag = Tag(0x0070, 0x0242);
+/// (0070,0243) Vertical Alignment
+pub const VERTICAL_ALIGNMENT: Tag = Tag(0x0070, 0x0243);
+/// (0070,0244) Shadow Style
+pub const SHADOW_STYLE: Tag = Tag(0x0070, 0x0244);
+/// (0070,0245) Shadow Offset X
+pub const SHADOW_OFFSET_X: Tag = Tag(0x0070, 0x0245);
+/// (0070,0246) Shadow Offset Y
+pub const SHADOW_OFFSET_Y: Tag = Tag(0x0070, 0x0246);
+/// (0070,0247) Shadow Color CIE Lab Value
+pub const SHADOW_COLOR_CIE_LAB_VALUE: Tag = Tag(0x0070, 0x0247);
+/// (0070,0248) Underlined
+pub const UNDERLINED: Tag = Tag(0x0070, 0x0248);
+/// (0070,0249) Bold
+pub const BOLD: Tag = Tag(0x0070, 0x0249);
+/// (0070,0250) Italic
+pub const ITALIC: Tag = Tag(0x0070, 0x0250);
+/// (0070,0251) Pattern on Color CIE Lab Value
+pub const PATTERN_ON_COLOR_CIE_LAB_VALUE: Tag = Tag(0x0070, 0x0251);
+/// (0070,0252) Pattern Off Color CIE Lab Value
+pub const PATTERN_OFF_COLOR_CIE_LAB_VALUE: Tag = Tag(0x0070, 0x0252);
+/// (0070,0253) Line Thickness
+pub const LINE_THICKNESS: Tag = Tag(0x0070, 0x0253);
+/// (0070,0254) Line Dashing Style
+pub const LINE_DASHING_STYLE: Tag = Tag(0x0070, 0x0254);
+/// (0070,0255) Line Pattern
+pub const LINE_PATTERN: Tag = Tag(0x0070, 0x0255);
+/// (0070,0256) Fill Pattern
+pub const FILL_PATTERN: Tag = Tag(0x0070, 0x0256);
+/// (0070,0257) Fill Mode
+pub const FILL_MODE: Tag = Tag(0x0070, 0x0257);
+/// (0070,0258) Shadow Opacity
+pub const SHADOW_OPACITY: Tag = Tag(0x0070, 0x0258);
+/// (0070,0261) Gap Length
+pub const GAP_LENGTH: Tag = Tag(0x0070, 0x0261);
+/// (0070,0262) Diameter of Visibility
+pub const DIAMETER_OF_VISIBILITY: Tag = Tag(0x0070, 0x0262);
+/// (0070,0273) Rotation Point
+pub const ROTATION_POINT: Tag = Tag(0x0070, 0x0273);
+/// (0070,0274) Tick Alignment
+pub const TICK_ALIGNMENT: Tag = Tag(0x0070, 0x0274);
+/// (0070,0278) Show Tick Label
+pub const SHOW_TICK_LABEL: Tag = Tag(0x0070, 0x0278);
+/// (0070,0279) Tick Label Alignment
+pub const TICK_LABEL_ALIGNMENT: Tag = Tag(0x0070, 0x0279);
+/// (0070,0282) Compound Graphic Units
+pub const COMPOUND_GRAPHIC_UNITS: Tag = Tag(0x0070, 0x0282);
+/// (0070,0284) Pattern on Opacity
+pub const PATTERN_ON_OPACITY: Tag = Tag(0x0070, 0x0284);
+/// (0070,0285) Pattern Off Opacity
+pub const PATTERN_OFF_OPACITY: Tag = Tag(0x0070, 0x0285);
+/// (0070,0287) Major Ticks Sequence
+pub const MAJOR_TICKS_SEQUENCE: Tag = Tag(0x0070, 0x0287);
+/// (0070,0288) Tick Position
+pub const TICK_POSITION: Tag = Tag(0x0070, 0x0288);
+/// (0070,0289) Tick Label
+pub const TICK_LABEL: Tag = Tag(0x0070, 0x0289);
+/// (0070,0294) Compound Graphic Type
+pub const COMPOUND_GRAPHIC_TYPE: Tag = Tag(0x0070, 0x0294);
+/// (0070,0295) Graphic Group ID
+pub const GRAPHIC_GROUP_ID: Tag = Tag(0x0070, 0x0295);
+/// (0070,0306) Shape Type
+pub const SHAPE_TYPE: Tag = Tag(0x0070, 0x0306);
+/// (0070,0308) Registration Sequence
+pub const REGISTRATION_SEQUENCE: Tag = Tag(0x0070, 0x0308);
+/// (0070,0309) Matrix Registration Sequence
+pub const MATRIX_REGISTRATION_SEQUENCE: Tag = Tag(0x0070, 0x0309);
+/// (0070,030A) Matrix Sequence
+pub const MATRIX_SEQUENCE: Tag = Tag(0x0070, 0x030A);
+/// (0070,030B) Frame of Reference to Displayed Coordinate System Transformation Matrix
+pub const FRAME_OF_REFERENCE_TO_DISPLAYED_COORDINATE_SYSTEM_TRANSFORMATION_MATRIX: Tag = Tag(0x0070, 0x030B);
+/// (0070,030C) Frame of Reference Transformation Matrix Type
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_MATRIX_TYPE: Tag = Tag(0x0070, 0x030C);
+/// (0070,030D) Registration Type Code Sequence
+pub const REGISTRATION_TYPE_CODE_SEQUENCE: Tag = Tag(0x0070, 0x030D);
+/// (0070,030F) Fiducial Description
+pub const FIDUCIAL_DESCRIPTION: Tag = Tag(0x0070, 0x030F);
+/// (0070,0310) Fiducial Identifier
+pub const FIDUCIAL_IDENTIFIER: Tag = Tag(0x0070, 0x0310);
+/// (0070,0311) Fiducial Identifier Code Sequence
+pub const FIDUCIAL_IDENTIFIER_CODE_SEQUENCE: Tag = Tag(0x0070, 0x0311);
+/// (0070,0312) Contour Uncertainty Radius
+pub const CONTOUR_UNCERTAINTY_RADIUS: Tag = Tag(0x0070, 0x0312);
+/// (0070,0314) Used Fiducials Sequence
+pub const USED_FIDUCIALS_SEQUENCE: Tag = Tag(0x0070, 0x0314);
+/// (0070,0318) Graphic Coordinates Data Sequence
+pub const GRAPHIC_COORDINATES_DATA_SEQUENCE: Tag = Tag(0x0070, 0x0318);
+/// (0070,031A) Fiducial UID
+pub const FIDUCIAL_UID: Tag = Tag(0x0070, 0x031A);
+/// (0070,031B) Referenced Fiducial UID
+pub const REFERENCED_FIDUCIAL_UID: Tag = Tag(0x0070, 0x031B);
+/// (0070,031C) Fiducial Set Sequence
+pub const FIDUCIAL_SET_SEQUENCE: Tag = Tag(0x0070, 0x031C);
+/// (0070,031E) Fiducial Sequence
+pub const FIDUCIAL_SEQUENCE: Tag = Tag(0x0070, 0x031E);
+/// (0070,031F) Fiducials Property Category Code Sequence
+pub const FIDUCIALS_PROPERTY_CATEGORY_CODE_SEQUENCE: Tag = Tag(0x0070, 0x031F);
+/// (0070,0401) Graphic Layer Recommended Display CIE Lab Value
+pub const GRAPHIC_LAYER_RECOMMENDED_DISPLAY_CIE_LAB_VALUE: Tag = Tag(0x0070, 0x0401);
+/// (0070,0402) Blending Sequence
+pub const BLENDING_SEQUENCE: Tag = Tag(0x0070, 0x0402);
+/// (0070,0403) Relative Opacity
+pub const RELATIVE_OPACITY: Tag = Tag(0x0070, 0x0403);
+/// (0070,0404) Referenced Spatial Registration Sequence
+pub const REFERENCED_SPATIAL_REGISTRATION_SEQUENCE: Tag = Tag(0x0070, 0x0404);
+/// (0070,0405) Blending Position
+pub const BLENDING_POSITION: Tag = Tag(0x0070, 0x0405);
+/// (0070,1101) Presentation Display Collection UID
+pub const PRESENTATION_DISPLAY_COLLECTION_UID: Tag = Tag(0x0070, 0x1101);
+/// (0070,1102) Presentation Sequence Collection UID
+pub const PRESENTATION_SEQUENCE_COLLECTION_UID: Tag = Tag(0x0070, 0x1102);
+/// (0070,1103) Presentation Sequence Position Index
+pub const PRESENTATION_SEQUENCE_POSITION_INDEX: Tag = Tag(0x0070, 0x1103);
+/// (0070,1104) Rendered Image Reference Sequence
+pub const RENDERED_IMAGE_REFERENCE_SEQUENCE: Tag = Tag(0x0070, 0x1104);
+/// (0070,1201) Volumetric Presentation State Input Sequence
+pub const VOLUMETRIC_PRESENTATION_STATE_INPUT_SEQUENCE: Tag = Tag(0x0070, 0x1201);
+/// (0070,1202) Presentation Input Type
+pub const PRESENTATION_INPUT_TYPE: Tag = Tag(0x0070, 0x1202);
+/// (0070,1203) Input Sequence Position Index
+pub const INPUT_SEQUENCE_POSITION_INDEX: Tag = Tag(0x0070, 0x1203);
+/// (0070,1204) Crop
+pub const CROP: Tag = Tag(0x0070, 0x1204);
+/// (0070,1205) Cropping Specification Index
+pub const CROPPING_SPECIFICATION_INDEX: Tag = Tag(0x0070, 0x1205);
+/// (0070,1206) Compositing Method (retired)
+pub const COMPOSITING_METHOD: Tag = Tag(0x0070, 0x1206);
+/// (0070,1207) Volumetric Presentation Input Number
+pub const VOLUMETRIC_PRESENTATION_INPUT_NUMBER: Tag = Tag(0x0070, 0x1207);
+/// (0070,1208) Image Volume Geometry
+pub const IMAGE_VOLUME_GEOMETRY: Tag = Tag(0x0070, 0x1208);
+/// (0070,1209) Volumetric Presentation Input Set UID
+pub const VOLUMETRIC_PRESENTATION_INPUT_SET_UID: Tag = Tag(0x0070, 0x1209);
+/// (0070,120A) Volumetric Presentation Input Set Sequence
+pub const VOLUMETRIC_PRESENTATION_INPUT_SET_SEQUENCE: Tag = Tag(0x0070, 0x120A);
+/// (0070,120B) Global Crop
+pub const GLOBAL_CROP: Tag = Tag(0x0070, 0x120B);
+/// (0070,120C) Global Cropping Specification Index
+pub const GLOBAL_CROPPING_SPECIFICATION_INDEX: Tag = Tag(0x0070, 0x120C);
+/// (0070,120D) Rendering Method
+pub const RENDERING_METHOD: Tag = Tag(0x0070, 0x120D);
+/// (0070,1301) Volume Cropping Sequence
+pub const VOLUME_CROPPING_SEQUENCE: Tag = Tag(0x0070, 0x1301);
+/// (0070,1302) Volume Cropping Method
+pub const VOLUME_CROPPING_METHOD: Tag = Tag(0x0070, 0x1302);
+/// (0070,1303) Bounding Box Crop
+pub const BOUNDING_BOX_CROP: Tag = Tag(0x0070, 0x1303);
+/// (0070,1304) Oblique Cropping Plane Sequence
+pub const OBLIQUE_CROPPING_PLANE_SEQUENCE: Tag = Tag(0x0070, 0x1304);
+/// (0070,1305) Plane
+pub const PLANE: Tag = Tag(0x0070, 0x1305);
+/// (0070,1306) Plane Normal
+pub const PLANE_NORMAL: Tag = Tag(0x0070, 0x1306);
+/// (0070,1309) Cropping Specification Number
+pub const CROPPING_SPECIFICATION_NUMBER: Tag = Tag(0x0070, 0x1309);
+/// (0070,1501) Multi Planar Reconstruction Style
+pub const MULTI_PLANAR_RECONSTRUCTION_STYLE: Tag = Tag(0x0070, 0x1501);
+/// (0070,1502) MPR Thickness Type
+pub const MPR_THICKNESS_TYPE: Tag = Tag(0x0070, 0x1502);
+/// (0070,1503) MPR Slab Thickness
+pub const MPR_SLAB_THICKNESS: Tag = Tag(0x0070, 0x1503);
+/// (0070,1505) MPR Top Left Hand Corner
+pub const MPR_TOP_LEFT_HAND_CORNER: Tag = Tag(0x0070, 0x1505);
+/// (0070,1507) MPR View Width Direction
+pub const MPR_VIEW_WIDTH_DIRECTION: Tag = Tag(0x0070, 0x1507);
+/// (0070,1508) MPR View Width
+pub const MPR_VIEW_WIDTH: Tag = Tag(0x0070, 0x1508);
+/// (0070,150C) Number of Volumetric Curve Points
+pub const NUMBER_OF_VOLUMETRIC_CURVE_POINTS: Tag = Tag(0x0070, 0x150C);
+/// (0070,150D) Volumetric Curve Points
+pub const VOLUMETRIC_CURVE_POINTS: Tag = Tag(0x0070, 0x150D);
+/// (0070,1511) MPR View Height Direction
+pub const MPR_VIEW_HEIGHT_DIRECTION: Tag = Tag(0x0070, 0x1511);
+/// (0070,1512) MPR View Height
+pub const MPR_VIEW_HEIGHT: Tag = Tag(0x0070, 0x1512);
+/// (0070,1602) Render Projection
+pub const RENDER_PROJECTION: Tag = Tag(0x0070, 0x1602);
+/// (0070,1603) Viewpoint Position
+pub const VIEWPOINT_POSITION: Tag = Tag(0x0070, 0x1603);
+/// (0070,1604) Viewpoint Look at Point
+pub const VIEWPOINT_LOOK_AT_POINT: Tag = Tag(0x0070, 0x1604);
+/// (0070,1605) Viewpoint Up Direction
+pub const VIEWPOINT_UP_DIRECTION: Tag = Tag(0x0070, 0x1605);
+/// (0070,1606) Render Field of View
+pub const RENDER_FIELD_OF_VIEW: Tag = Tag(0x0070, 0x1606);
+/// (0070,1607) Sampling Step Size
+pub const SAMPLING_STEP_SIZE: Tag = Tag(0x0070, 0x1607);
+/// (0070,1701) Shading Style
+pub const SHADING_STYLE: Tag = Tag(0x0070, 0x1701);
+/// (0070,1702) Ambient Reflection Intensity
+pub const AMBIENT_REFLECTION_INTENSITY: Tag = Tag(0x0070, 0x1702);
+/// (0070,1703) Light Direction
+pub const LIGHT_DIRECTION: Tag = Tag(0x0070, 0x1703);
+/// (0070,1704) Diffuse Reflection Intensity
+pub const DIFFUSE_REFLECTION_INTENSITY: Tag = Tag(0x0070, 0x1704);
+/// (0070,1705) Specular Reflection Intensity
+pub const SPECULAR_REFLECTION_INTENSITY: Tag = Tag(0x0070, 0x1705);
+/// (0070,1706) Shininess
+pub const SHININESS: Tag = Tag(0x0070, 0x1706);
+/// (0070,1801) Presentation State Classification Component Sequence
+pub const PRESENTATION_STATE_CLASSIFICATION_COMPONENT_SEQUENCE: Tag = Tag(0x0070, 0x1801);
+/// (0070,1802) Component Type
+pub const COMPONENT_TYPE: Tag = Tag(0x0070, 0x1802);
+/// (0070,1803) Component Input Sequence
+pub const COMPONENT_INPUT_SEQUENCE: Tag = Tag(0x0070, 0x1803);
+/// (0070,1804) Volumetric Presentation Input Index
+pub const VOLUMETRIC_PRESENTATION_INPUT_INDEX: Tag = Tag(0x0070, 0x1804);
+/// (0070,1805) Presentation State Compositor Component Sequence
+pub const PRESENTATION_STATE_COMPOSITOR_COMPONENT_SEQUENCE: Tag = Tag(0x0070, 0x1805);
+/// (0070,1806) Weighting Transfer Function Sequence
+pub const WEIGHTING_TRANSFER_FUNCTION_SEQUENCE: Tag = Tag(0x0070, 0x1806);
+/// (0070,1807) Weighting Lookup Table Descriptor
+pub const WEIGHTING_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag(0x0070, 0x1807);
+/// (0070,1808) Weighting Lookup Table Data
+pub const WEIGHTING_LOOKUP_TABLE_DATA: Tag = Tag(0x0070, 0x1808);
+/// (0070,1901) Volumetric Annotation Sequence
+pub const VOLUMETRIC_ANNOTATION_SEQUENCE: Tag = Tag(0x0070, 0x1901);
+/// (0070,1903) Referenced Structured Context Sequence
+pub const REFERENCED_STRUCTURED_CONTEXT_SEQUENCE: Tag = Tag(0x0070, 0x1903);
+/// (0070,1904) Referenced Content Item
+pub const REFERENCED_CONTENT_ITEM: Tag = Tag(0x0070, 0x1904);
+/// (0070,1905) Volumetric Presentation Input Annotation Sequence
+pub const VOLUMETRIC_PRESENTATION_INPUT_ANNOTATION_SEQUENCE: Tag = Tag(0x0070, 0x1905);
+/// (0070,1907) Annotation Clipping
+pub const ANNOTATION_CLIPPING: Tag = Tag(0x0070, 0x1907);
+/// (0070,1A01) Presentation Animation Style
+pub const PRESENTATION_ANIMATION_STYLE: Tag = Tag(0x0070, 0x1A01);
+/// (0070,1A03) Recommended Animation Rate
+pub const RECOMMENDED_ANIMATION_RATE: Tag = Tag(0x0070, 0x1A03);
+/// (0070,1A04) Animation Curve Sequence
+pub const ANIMATION_CURVE_SEQUENCE: Tag = Tag(0x0070, 0x1A04);
+/// (0070,1A05) Animation Step Size
+pub const ANIMATION_STEP_SIZE: Tag = Tag(0x0070, 0x1A05);
+/// (0070,1A06) Swivel Range
+pub const SWIVEL_RANGE: Tag = Tag(0x0070, 0x1A06);
+/// (0070,1A07) Volumetric Curve Up Directions
+pub const VOLUMETRIC_CURVE_UP_DIRECTIONS: Tag = Tag(0x0070, 0x1A07);
+/// (0070,1A08) Volume Stream Sequence
+pub const VOLUME_STREAM_SEQUENCE: Tag = Tag(0x0070, 0x1A08);
+/// (0070,1A09) RGBA Transfer Function Description
+pub const RGBA_TRANSFER_FUNCTION_DESCRIPTION: Tag = Tag(0x0070, 0x1A09);
+/// (0070,1B01) Advanced Blending Sequence
+pub const ADVANCED_BLENDING_SEQUENCE: Tag = Tag(0x0070, 0x1B01);
+/// (0070,1B02) Blending Input Number
+pub const BLENDING_INPUT_NUMBER: Tag = Tag(0x0070, 0x1B02);
+/// (0070,1B03) Blending Display Input Sequence
+pub const BLENDING_DISPLAY_INPUT_SEQUENCE: Tag = Tag(0x0070, 0x1B03);
+/// (0070,1B04) Blending Display Sequence
+pub const BLENDING_DISPLAY_SEQUENCE: Tag = Tag(0x0070, 0x1B04);
+/// (0070,1B06) Blending Mode
+pub const BLENDING_MODE: Tag = Tag(0x0070, 0x1B06);
+/// (0070,1B07) Time Series Blending
+pub const TIME_SERIES_BLENDING: Tag = Tag(0x0070, 0x1B07);
+/// (0070,1B08) Geometry for Display
+pub const GEOMETRY_FOR_DISPLAY: Tag = Tag(0x0070, 0x1B08);
+/// (0070,1B11) Threshold Sequence
+pub const THRESHOLD_SEQUENCE: Tag = Tag(0x0070, 0x1B11);
+/// (0070,1B12) Threshold Value Sequence
+pub const THRESHOLD_VALUE_SEQUENCE: Tag = Tag(0x0070, 0x1B12);
+/// (0070,1B13) Threshold Type
+pub const THRESHOLD_TYPE: Tag = Tag(0x0070, 0x1B13);
+/// (0070,1B14) Threshold Value
+pub const THRESHOLD_VALUE: Tag = Tag(0x0070, 0x1B14);
+/// (0072,0002) Hanging Protocol Name
+pub const HANGING_PROTOCOL_NAME: Tag = Tag(0x0072, 0x0002);
+/// (0072,0004) Hanging Protocol Description
+pub const HANGING_PROTOCOL_DESCRIPTION: Tag = Tag(0x0072, 0x0004);
+/// (0072,0006) Hanging Protocol Level
+pub const HANGING_PROTOCOL_LEVEL: Tag = Tag(0x0072, 0x0006);
+/// (0072,0008) Hanging Protocol Creator
+pub const HANGING_PROTOCOL_CREATOR: Tag = Tag(0x0072, 0x0008);
+/// (0072,000A) Hanging Protocol Creation Date Time
+pub const HANGING_PROTOCOL_CREATION_DATE_TIME: Tag = Tag(0x0072, 0x000A);
+/// (0072,000C) Hanging Protocol Definition Sequence
+pub const HANGING_PROTOCOL_DEFINITION_SEQUENCE: Tag = Tag(0x0072, 0x000C);
+/// (0072,000E) Hanging Protocol User Identification Code Sequence
+pub const HANGING_PROTOCOL_USER_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag(0x0072, 0x000E);
+/// (0072,0010) Hanging Protocol User Group Name
+pub const HANGING_PROTOCOL_USER_GROUP_NAME: Tag = Tag(0x0072, 0x0010);
+/// (0072,0012) Source Hanging Protocol Sequence
+pub const SOURCE_HANGING_PROTOCOL_SEQUENCE: Tag = Tag(0x0072, 0x0012);
+/// (0072,0014) Number of Priors Referenced
+pub const NUMBER_OF_PRIORS_REFERENCED: Tag = Tag(0x0072, 0x0014);
+/// (0072,0020) Image Sets Sequence
+pub const IMAGE_SETS_SEQUENCE: Tag = Tag(0x0072, 0x0020);
+/// (0072,0022) Image Set Selector Sequence
+pub const IMAGE_SET_SELECTOR_SEQUENCE: Tag = Tag(0x0072, 0x0022);
+/// (0072,0024) Image Set Selector Usage Flag
+pub const IMAGE_SET_SELECTOR_USAGE_FLAG: Tag = Tag(0x0072, 0x0024);
+/// (0072,0026) Selector Attribute
+pub const SELECTOR_ATTRIBUTE: Tag = Tag(0x0072, 0x0026);
+/// (0072,0028) Selector Value Number
+pub const SELECTOR_VALUE_NUMBER: Tag = Tag(0x0072, 0x0028);
+/// (0072,0030) Time Based Image Sets Sequence
+pub const TIME_BASED_IMAGE_SETS_SEQUENCE: Tag = Tag(0x0072, 0x0030);
+/// (0072,0032) Image Set Number
+pub const IMAGE_SET_NUMBER: Tag = Tag(0x0072, 0x0032);
+/// (0072,0034) Image Set Selector Category
+pub const IMAGE_SET_SELECTOR_CATEGORY: Tag = Tag(0x0072, 0x0034);
+/// (0072,0038) Relative Time
+pub const RELATIVE_TIME: Tag = Tag(0x0072, 0x0038);
+/// (0072,003A) Relative Time Units
+pub const RELATIVE_TIME_UNITS: Tag = Tag(0x0072, 0x003A);
+/// (0072,003C) Abstract Prior Value
+pub const ABSTRACT_PRIOR_VALUE: Tag = Tag(0x0072, 0x003C);
+/// (0072,003E) Abstract Prior Code Sequence
+pub const ABSTRACT_PRIOR_CODE_SEQUENCE: Tag = Tag(0x0072, 0x003E);
+/// (0072,0040) Image Set Label
+pub const IMAGE_SET_LABEL: Tag = Tag(0x0072, 0x0040);
+/// (0072,0050) Selector Attribute VR
+pub const SELECTOR_ATTRIBUTE_VR: Tag = Tag(0x0072, 0x0050);
+/// (0072,0052) Selector Sequence Pointer
+pub const SELECTOR_SEQUENCE_POINTER: Tag = Tag(0x0072, 0x0052);
+/// (0072,0054) Selector Sequence Pointer Private Creator
+pub const SELECTOR_SEQUENCE_POINTER_PRIVATE_CREATOR: Tag = Tag(0x0072, 0x0054);
+/// (0072,0056) Selector Attribute Private Creator
+pub const SELECTOR_ATTRIBUTE_PRIVATE_CREATOR: Tag = Tag(0x0072, 0x0056);
+/// (0072,005E) Selector AE Value
+pub const SELECTOR_AE_VALUE: Tag = Tag(0x0072, 0x005E);
+/// (0072,005F) Selector AS Value
+pub const SELECTOR_AS_VALUE: Tag = Tag(0x0072, 0x005F);
+/// (0072,0060) Selector AT Value
+pub const SELECTOR_AT_VALUE: Tag = Tag(0x0072, 0x0060);
+/// (0072,0061) Selector DA Value
+pub const SELECTOR_DA_VALUE: Tag = Tag(0x0072, 0x0061);
+/// (0072,0062) Selector CS Value
+pub const SELECTOR_CS_VALUE: Tag = Tag(0x0072, 0x0062);
+/// (0072,0063) Selector DT Value
+pub const SELECTOR_DT_VALUE: Tag = Tag(0x0072, 0x0063);
+/// (0072,0064) Selector IS Value
+pub const SELECTOR_IS_VALUE: Tag = Tag(0x0072, 0x0064);
+/// (0072,0065) Selector OB Value
+pub const SELECTOR_OB_VALUE: Tag = Tag(0x0072, 0x0065);
+/// (0072,0066) Selector LO Value
+pub const SELECTOR_LO_VALUE: Tag = Tag(0x0072, 0x0066);
+/// (0072,0067) Selector OF Value
+pub const SELECTOR_OF_VALUE: Tag = Tag(0x0072, 0x0067);
+/// (0072,0068) Selector LT Value
+pub const SELECTOR_LT_VALUE: Tag = Tag(0x0072, 0x0068);
+/// (0072,0069) Selector OW Value
+pub const SELECTOR_OW_VALUE: Tag = Tag(0x0072, 0x0069);
+/// (0072,006A) Selector PN Value
+pub const SELECTOR_PN_VALUE: Tag = Tag(0x0072, 0x006A);
+/// (0072,006B) Selector TM Value
+pub const SELECTOR_TM_VALUE: Tag = Tag(0x0072, 0x006B);
+/// (0072,006C) Selector SH Value
+pub const SELECTOR_SH_VALUE: Tag = Tag(0x0072, 0x006C);
+/// (0072,006D) Selector UN Value
+pub const SELECTOR_UN_VALUE: Tag = Tag(0x0072, 0x006D);
+/// (0072,006E) Selector ST Value
+pub const SELECTOR_ST_VALUE: Tag = Tag(0x0072, 0x006E);
+/// (0072,006F) Selector UC Value
+pub const SELECTOR_UC_VALUE: Tag = Tag(0x0072, 0x006F);
+/// (0072,0070) Selector UT Value
+pub const SELECTOR_UT_VALUE: Tag = Tag(0x0072, 0x0070);
+/// (0072,0071) Selector UR Value
+pub const SELECTOR_UR_VALUE: Tag = Tag(0x0072, 0x0071);
+/// (0072,0072) Selector DS Value
+pub const SELECTOR_DS_VALUE: Tag = Tag(0x0072, 0x0072);
+/// (0072,0073) Selector OD Value
+pub const SELECTOR_OD_VALUE: Tag = Tag(0x0072, 0x0073);
+/// (0072,0074) Selector FD Value
+pub const SELECTOR_FD_VALUE: Tag = Tag(0x0072, 0x0074);
+/// (0072,0075) Selector OL Value
+pub const SELECTOR_OL_VALUE: Tag = Tag(0x0072, 0x0075);
+/// (0072,0076) Selector FL Value
+pub const SELECTOR_FL_VALUE: Tag = Tag(0x0072, 0x0076);
+/// (0072,0078) Selector UL Value
+pub const SELECTOR_UL_VALUE: Tag = Tag(0x0072, 0x0078);
+/// (0072,007A) Selector US Value
+pub const SELECTOR_US_VALUE: Tag = Tag(0x0072, 0x007A);
+/// (0072,007C) Selector SL Value
+pub const SELECTOR_SL_VALUE: Tag = Tag(0x0072, 0x007C);
+/// (0072,007E) Selector SS Value
+pub const SELECTOR_SS_VALUE: Tag = Tag(0x0072, 0x007E);
+/// (0072,007F) Selector UI Value
+pub const SELECTOR_UI_VALUE: Tag = Tag(0x0072, 0x007F);
+/// (0072,0080) Selector Code Sequence Value
+pub const SELECTOR_CODE_SEQUENCE_VALUE: Tag = Tag(0x0072, 0x0080);
+/// (0072,0100) Number of Screens
+pub const NUMBER_OF_SCREENS: Tag = Tag(0x0072, 0x0100);
+/// (0072,0102) Nominal Screen Definition Sequence
+pub const NOMINAL_SCREEN_DEFINITION_SEQUENCE: Tag = Tag(0x0072, 0x0102);
+/// (0072,0104) Number of Vertical Pixels
+pub const NUMBER_OF_VERTICAL_PIXELS: Tag = Tag(0x0072, 0x0104);
+/// (0072,0106) Number of Horizontal Pixels
+pub const NUMBER_OF_HORIZONTAL_PIXELS: Tag = Tag(0x0072, 0x0106);
+/// (0072,0108) Display Environment Spatial Position
+pub const DISPLAY_ENVIRONMENT_SPATIAL_POSITION: Tag = Tag(0x0072, 0x0108);
+/// (0072,010A) Screen Minimum Grayscale Bit Depth
+pub const SCREEN_MINIMUM_GRAYSCALE_BIT_DEPTH: Tag = Tag(0x0072, 0x010A);
+/// (0072,010C) Screen Minimum Color Bit Depth
+pub const SCREEN_MINIMUM_COLOR_BIT_DEPTH: Tag = Tag(0x0072, 0x010C);
+/// (0072,010E) Application Maximum Repaint Time
+pub const APPLICATION_MAXIMUM_REPAINT_TIME: Tag = Tag(0x0072, 0x010E);
+/// (0072,0200) Display Sets Sequence
+pub const DISPLAY_SETS_SEQUENCE: Tag = Tag(0x0072, 0x0200);
+/// (0072,0202) Display Set Number
+pub const DISPLAY_SET_NUMBER: Tag = Tag(0x0072, 0x0202);
+/// (0072,0203) Display Set Label
+pub const DISPLAY_SET_LABEL: Tag = Tag(0x0072, 0x0203);
+/// (0072,0204) Display Set Presentation Group
+pub const DISPLAY_SET_PRESENTATION_GROUP: Tag = Tag(0x0072, 0x0204);
+/// (0072,0206) Display Set Presentation Group Description
+pub const DISPLAY_SET_PRESENTATION_GROUP_DESCRIPTION: Tag = Tag(0x0072, 0x0206);
+/// (0072,0208) Partial Data Display Handling
+pub const PARTIAL_DATA_DISPLAY_HANDLING: Tag = Tag(0x0072, 0x0208);
+/// (0072,0210) Synchronized Scrolling Sequence
+pub const SYNCHRONIZED_SCROLLING_SEQUENCE: Tag = Tag(0x0072, 0x0210);
+/// (0072,0212) Display Set Scrolling Group
+pub const DISPLAY_SET_SCROLLING_GROUP: Tag = Tag(0x0072, 0x0212);
+/// (0072,0214) Navigation Indicator Sequence
+pub const NAVIGATION_INDICATOR_SEQUENCE: Tag = Tag(0x0072, 0x0214);
+/// (0072,0216) Navigation Display Set
+pub const NAVIGATION_DISPLAY_SET: Tag = Tag(0x0072, 0x0216);
+/// (0072,0218) Reference Display Sets
+pub const REFERENCE_DISPLAY_SETS: Tag = Tag(0x0072, 0x0218);
+/// (0072,0300) Image Boxes Sequence
+pub const IMAGE_BOXES_SEQUENCE: Tag = Tag(0x0072, 0x0300);
+/// (0072,0302) Image Box Number
+pub const IMAGE_BOX_NUMBER: Tag = Tag(0x0072, 0x0302);
+/// (0072,0304) Image Box Layout Type
+pub const IMAGE_BOX_LAYOUT_TYPE: Tag = Tag(0x0072, 0x0304);
+/// (0072,0306) Image Box Tile Horizontal Dimension
+pub const IMAGE_BOX_TILE_HORIZONTAL_DIMENSION: Tag = Tag(0x0072, 0x0306);
+/// (0072,0308) Image Box Tile Vertical Dimension
+pub const IMAGE_BOX_TILE_VERTICAL_DIMENSION: Tag = Tag(0x0072, 0x0308);
+/// (0072,0310) Image Box Scroll Direction
+pub const IMAGE_BOX_SCROLL_DIRECTION: Tag = Tag(0x0072, 0x0310);
+/// (0072,0312) Image Box Small Scroll Type
+pub const IMAGE_BOX_SMALL_SCROLL_TYPE: Tag = Tag(0x0072, 0x0312);
+/// (0072,0314) Image Box Small Scroll Amount
+pub const IMAGE_BOX_SMALL_SCROLL_AMOUNT: Tag = Tag(0x0072, 0x0314);
+/// (0072,0316) Image Box Large Scroll Type
+pub const IMAGE_BOX_LARGE_SCROLL_TYPE: Tag = Tag(0x0072, 0x0316);
+/// (0072,0318) Image Box Large Scroll Amount
+pub const IMAGE_BOX_LARGE_SCROLL_AMOUNT: Tag = Tag(0x0072, 0x0318);
+/// (0072,0320) Image Box Overlap Priority
+pub const IMAGE_BOX_OVERLAP_PRIORITY: Tag = Tag(0x0072, 0x0320);
+/// (0072,0330) Cine Relative to Real Time
+pub const CINE_RELATIVE_TO_REAL_TIME: Tag = Tag(0x0072, 0x0330);
+/// (0072,0400) Filter Operations Sequence
+pub const FILTER_OPERATIONS_SEQUENCE: Tag = Tag(0x0072, 0x0400);
+/// (0072,0402) Filter by Category
+pub const FILTER_BY_CATEGORY: Tag = Tag(0x0072, 0x0402);
+/// (0072,0404) Filter by Attribute Presence
+pub const FILTER_BY_ATTRIBUTE_PRESENCE: Tag = Tag(0x0072, 0x0404);
+/// (0072,0406) Filter by Operator
+pub const FILTER_BY_OPERATOR: Tag = Tag(0x0072, 0x0406);
+/// (0072,0420) Structured Display Background CIE Lab Value
+pub const STRUCTURED_DISPLAY_BACKGROUND_CIE_LAB_VALUE: Tag = Tag(0x0072, 0x0420);
+/// (0072,0421) Empty Image Box CIE Lab Value
+pub const EMPTY_IMAGE_BOX_CIE_LAB_VALUE: Tag = Tag(0x0072, 0x0421);
+/// (0072,0422) Structured Display Image Box Sequence
+pub const STRUCTURED_DISPLAY_IMAGE_BOX_SEQUENCE: Tag = Tag(0x0072, 0x0422);
+/// (0072,0424) Structured Display Text Box Sequence
+pub const STRUCTURED_DISPLAY_TEXT_BOX_SEQUENCE: Tag = Tag(0x0072, 0x0424);
+/// (0072,0427) Referenced First Frame Sequence
+pub const REFERENCED_FIRST_FRAME_SEQUENCE: Tag = Tag(0x0072, 0x0427);
+/// (0072,0430) Image Box Synchronization Sequence
+pub const IMAGE_BOX_SYNCHRONIZATION_SEQUENCE: Tag = Tag(0x0072, 0x0430);
+/// (0072,0432) Synchronized Image Box List
+pub const SYNCHRONIZED_IMAGE_BOX_LIST: Tag = Tag(0x0072, 0x0432);
+/// (0072,0434) Type of Synchronization
+pub const TYPE_OF_SYNCHRONIZATION: Tag = Tag(0x0072, 0x0434);
+/// (0072,0500) Blending Operation Type
+pub const BLENDING_OPERATION_TYPE: Tag = Tag(0x0072, 0x0500);
+/// (0072,0510) Reformatting Operation Type
+pub const REFORMATTING_OPERATION_TYPE: Tag = Tag(0x0072, 0x0510);
+/// (0072,0512) Reformatting Thickness
+pub const REFORMATTING_THICKNESS: Tag = Tag(0x0072, 0x0512);
+/// (0072,0514) Reformatting Interval
+pub const REFORMATTING_INTERVAL: Tag = Tag(0x0072, 0x0514);
+/// (0072,0516) Reformatting Operation Initial View Direction
+pub const REFORMATTING_OPERATION_INITIAL_VIEW_DIRECTION: Tag = Tag(0x0072, 0x0516);
+/// (0072,0520) Three D Rendering Type
+pub const THREE_D_RENDERING_TYPE: Tag = Tag(0x0072, 0x0520);
+/// (0072,0600) Sorting Operations Sequence
+pub const SORTING_OPERATIONS_SEQUENCE: Tag = Tag(0x0072, 0x0600);
+/// (0072,0602) Sort by Category
+pub const SORT_BY_CATEGORY: Tag = Tag(0x0072, 0x0602);
+/// (0072,0604) Sorting Direction
+pub const SORTING_DIRECTION: Tag = Tag(0x0072, 0x0604);
+/// (0072,0700) Display Set Patient Orientation
+pub const DISPLAY_SET_PATIENT_ORIENTATION: Tag = Tag(0x0072, 0x0700);
+/// (0072,0702) VOI Type
+pub const VOI_TYPE: Tag = Tag(0x0072, 0x0702);
+/// (0072,0704) Pseudo Color Type
+pub const PSEUDO_COLOR_TYPE: Tag = Tag(0x0072, 0x0704);
+/// (0072,0705) Pseudo Color Palette Instance Reference Sequence
+pub const PSEUDO_COLOR_PALETTE_INSTANCE_REFERENCE_SEQUENCE: Tag = Tag(0x0072, 0x0705);
+/// (0072,0706) Show Grayscale Inverted
+pub const SHOW_GRAYSCALE_INVERTED: Tag = Tag(0x0072, 0x0706);
+/// (0072,0710) Show Image True Size Flag
+pub const SHOW_IMAGE_TRUE_SIZE_FLAG: Tag = Tag(0x0072, 0x0710);
+/// (0072,0712) Show Graphic Annotation Flag
+pub const SHOW_GRAPHIC_ANNOTATION_FLAG: Tag = Tag(0x0072, 0x0712);
+/// (0072,0714) Show Patient Demographics Flag
+pub const SHOW_PATIENT_DEMOGRAPHICS_FLAG: Tag = Tag(0x0072, 0x0714);
+/// (0072,0716) Show Acquisition Techniques Flag
+pub const SHOW_ACQUISITION_TECHNIQUES_FLAG: Tag = Tag(0x0072, 0x0716);
+/// (0072,0717) Display Set Horizontal Justification
+pub const DISPLAY_SET_HORIZONTAL_JUSTIFICATION: Tag = Tag(0x0072, 0x0717);
+/// (0072,0718) Display Set Vertical Justification
+pub const DISPLAY_SET_VERTICAL_JUSTIFICATION: Tag = Tag(0x0072, 0x0718);
+/// (0074,0120) Continuation Start Meterset
+pub const CONTINUATION_START_METERSET: Tag = Tag(0x0074, 0x0120);
+/// (0074,0121) Continuation End Meterset
+pub const CONTINUATION_END_METERSET: Tag = Tag(0x0074, 0x0121);
+/// (0074,1000) Procedure Step State
+pub const PROCEDURE_STEP_STATE: Tag = Tag(0x0074, 0x1000);
+/// (0074,1002) Procedure Step Progress Information Sequence
+pub const PROCEDURE_STEP_PROGRESS_INFORMATION_SEQUENCE: Tag = Tag(0x0074, 0x1002);
+/// (0074,1004) Procedure Step Progress
+pub const PROCEDURE_STEP_PROGRESS: Tag = Tag(0x0074, 0x1004);
+/// (0074,1006) Procedure Step Progress Description
+pub const PROCEDURE_STEP_PROGRESS_DESCRIPTION: Tag = Tag(0x0074, 0x1006);
+/// (0074,1007) Procedure Step Progress Parameters Sequence
+pub const PROCEDURE_STEP_PROGRESS_PARAMETERS_SEQUENCE: Tag = Tag(0x0074, 0x1007);
+/// (0074,1008) Procedure Step Communications URI Sequence
+pub const PROCEDURE_STEP_COMMUNICATIONS_URI_SEQUENCE: Tag = Tag(0x0074, 0x1008);
+/// (0074,100A) Contact URI
+pub const CONTACT_URI: Tag = Tag(0x0074, 0x100A);
+/// (0074,100C) Contact Display Name
+pub const CONTACT_DISPLAY_NAME: Tag = Tag(0x0074, 0x100C);
+/// (0074,100E) Procedure Step Discontinuation Reason Code Sequence
+pub const PROCEDURE_STEP_DISCONTINUATION_REASON_CODE_SEQUENCE: Tag = Tag(0x0074, 0x100E);
+/// (0074,1020) Beam Task Sequence
+pub const BEAM_TASK_SEQUENCE: Tag = Tag(0x0074, 0x1020);
+/// (0074,1022) Beam Task Type
+pub const BEAM_TASK_TYPE: Tag = Tag(0x0074, 0x1022);
+/// (0074,1024) Beam Order Index Trial (retired)
+pub const BEAM_ORDER_INDEX_TRIAL: Tag = Tag(0x0074, 0x1024);
+/// (0074,1025) Autosequence Flag
+pub const AUTOSEQUENCE_FLAG: Tag = Tag(0x0074, 0x1025);
+/// (0074,1026) Table Top Vertical Adjusted Position
+pub const TABLE_TOP_VERTICAL_ADJUSTED_POSITION: Tag = Tag(0x0074, 0x1026);
+/// (0074,1027) Table Top Longitudinal Adjusted Position
+pub const TABLE_TOP_LONGITUDINAL_ADJUSTED_POSITION: Tag = Tag(0x0074, 0x1027);
+/// (0074,1028) Table Top Lateral Adjusted Position
+pub const TABLE_TOP_LATERAL_ADJUSTED_POSITION: Tag = Tag(0x0074, 0x1028);
+/// (0074,102A) Patient Support Adjusted Angle
+pub const PATIENT_SUPPORT_ADJUSTED_ANGLE: Tag = Tag(0x0074, 0x102A);
+/// (0074,102B) Table Top Eccentric Adjusted Angle
+pub const TABLE_TOP_ECCENTRIC_ADJUSTED_ANGLE: Tag = Tag(0x0074, 0x102B);
+/// (0074,102C) Table Top Pitch Adjusted Angle
+pub const TABLE_TOP_PITCH_ADJUSTED_ANGLE: Tag = Tag(0x0074, 0x102C);
+/// (0074,102D) Table Top Roll Adjusted Angle
+pub const TABLE_TOP_ROLL_ADJUSTED_ANGLE: Tag = Tag(0x0074, 0x102D);
+/// (0074,1030) Delivery Verification Image Sequence
+pub const DELIVERY_VERIFICATION_IMAGE_SEQUENCE: Tag = Tag(0x0074, 0x1030);
+/// (0074,1032) Verification Image Timing
+pub const VERIFICATION_IMAGE_TIMING: Tag = Tag(0x0074, 0x1032);
+/// (0074,1034) Double Exposure Flag
+pub const DOUBLE_EXPOSURE_FLAG: Tag = Tag(0x0074, 0x1034);
+/// (0074,1036) Double Exposure Ordering
+pub const DOUBLE_EXPOSURE_ORDERING: Tag = Tag(0x0074, 0x1036);
+/// (0074,1038) Double Exposure Meterset Trial (retired)
+pub const DOUBLE_EXPOSURE_METERSET_TRIAL: Tag = Tag(0x0074, 0x1038);
+/// (0074,103A) Double Exposure Field Delta Trial (retired)
+pub const DOUBLE_EXPOSURE_FIELD_DELTA_TRIAL: Tag = Tag(0x0074, 0x103A);
+/// (0074,1040) Related Reference RT Image Sequence
+pub const RELATED_REFERENCE_RT_IMAGE_SEQUENCE: Tag = Tag(0x0074, 0x1040);
+/// (0074,1042) General Machine Verification Sequence
+pub const GENERAL_MACHINE_VERIFICATION_SEQUENCE: Tag = Tag(0x0074, 0x1042);
+/// (0074,1044) Conventional Machine Verification Sequence
+pub const CONVENTIONAL_MACHINE_VERIFICATION_SEQUENCE: Tag = Tag(0x0074, 0x1044);
+/// (0074,1046) Ion Machine Verification Sequence
+pub const ION_MACHINE_VERIFICATION_SEQUENCE: Tag = Tag(0x0074, 0x1046);
+/// (0074,1048) Failed Attributes Sequence
+pub const FAILED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0074, 0x1048);
+/// (0074,104A) Overridden Attributes Sequence
+pub const OVERRIDDEN_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0074, 0x104A);
+/// (0074,104C) Conventional Control Point Verification Sequence
+pub const CONVENTIONAL_CONTROL_POINT_VERIFICATION_SEQUENCE: Tag = Tag(0x0074, 0x104C);
+/// (0074,104E) Ion Control Point Verification Sequence
+pub const ION_CONTROL_POINT_VERIFICATION_SEQUENCE: Tag = Tag(0x0074, 0x104E);
+/// (0074,1050) Attribute Occurrence Sequence
+pub const ATTRIBUTE_OCCURRENCE_SEQUENCE: Tag = Tag(0x0074, 0x1050);
+/// (0074,1052) Attribute Occurrence Pointer
+pub const ATTRIBUTE_OCCURRENCE_POINTER: Tag = Tag(0x0074, 0x1052);
+/// (0074,1054) Attribute Item Selector
+pub const ATTRIBUTE_ITEM_SELECTOR: Tag = Tag(0x0074, 0x1054);
+/// (0074,1056) Attribute Occurrence Private Creator
+pub const ATTRIBUTE_OCCURRENCE_PRIVATE_CREATOR: Tag = Tag(0x0074, 0x1056);
+/// (0074,1057) Selector Sequence Pointer Items
+pub const SELECTOR_SEQUENCE_POINTER_ITEMS: Tag = Tag(0x0074, 0x1057);
+/// (0074,1200) Scheduled Procedure Step Priority
+pub const SCHEDULED_PROCEDURE_STEP_PRIORITY: Tag = Tag(0x0074, 0x1200);
+/// (0074,1202) Worklist Label
+pub const WORKLIST_LABEL: Tag = Tag(0x0074, 0x1202);
+/// (0074,1204) Procedure Step Label
+pub const PROCEDURE_STEP_LABEL: Tag = Tag(0x0074, 0x1204);
+/// (0074,1210) Scheduled Processing Parameters Sequence
+pub const SCHEDULED_PROCESSING_PARAMETERS_SEQUENCE: Tag = Tag(0x0074, 0x1210);
+/// (0074,1212) Performed Processing Parameters Sequence
+pub const PERFORMED_PROCESSING_PARAMETERS_SEQUENCE: Tag = Tag(0x0074, 0x1212);
+/// (0074,1216) Unified Procedure Step Performed Procedure Sequence
+pub const UNIFIED_PROCEDURE_STEP_PERFORMED_PROCEDURE_SEQUENCE: Tag = Tag(0x0074, 0x1216);
+/// (0074,1220) Related Procedure Step Sequence (retired)
+pub const RELATED_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x0074, 0x1220);
+/// (0074,1222) Procedure Step Relationship Type (retired)
+pub const PROCEDURE_STEP_RELATIONSHIP_TYPE: Tag = Tag(0x0074, 0x1222);
+/// (0074,1224) Replaced Procedure Step Sequence
+pub const REPLACED_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x0074, 0x1224);
+/// (0074,1230) Deletion Lock
+pub const DELETION_LOCK: Tag = Tag(0x0074, 0x1230);
+/// (0074,1234) Receiving AE
+pub const RECEIVING_AE: Tag = Tag(0x0074, 0x1234);
+/// (0074,1236) Requesting AE
+pub const REQUESTING_AE: Tag = Tag(0x0074, 0x1236);
+/// (0074,1238) Reason for Cancellation
+pub const REASON_FOR_CANCELLATION: Tag = Tag(0x0074, 0x1238);
+/// (0074,1242) SCP Status
+pub const SCP_STATUS: Tag = Tag(0x0074, 0x1242);
+/// (0074,1244) Subscription List Status
+pub const SUBSCRIPTION_LIST_STATUS: Tag = Tag(0x0074, 0x1244);
+/// (0074,1246) Unified Procedure Step List Status
+pub const UNIFIED_PROCEDURE_STEP_LIST_STATUS: Tag = Tag(0x0074, 0x1246);
+/// (0074,1324) Beam Order Index
+pub const BEAM_ORDER_INDEX: Tag = Tag(0x0074, 0x1324);
+/// (0074,1338) Double Exposure Meterset
+pub const DOUBLE_EXPOSURE_METERSET: Tag = Tag(0x0074, 0x1338);
+/// (0074,133A) Double Exposure Field Delta
+pub const DOUBLE_EXPOSURE_FIELD_DELTA: Tag = Tag(0x0074, 0x133A);
+/// (0074,1401) Brachy Task Sequence
+pub const BRACHY_TASK_SEQUENCE: Tag = Tag(0x0074, 0x1401);
+/// (0074,1402) Continuation Start Total Reference Air Kerma
+pub const CONTINUATION_START_TOTAL_REFERENCE_AIR_KERMA: Tag = Tag(0x0074, 0x1402);
+/// (0074,1403) Continuation End Total Reference Air Kerma
+pub const CONTINUATION_END_TOTAL_REFERENCE_AIR_KERMA: Tag = Tag(0x0074, 0x1403);
+/// (0074,1404) Continuation Pulse Number
+pub const CONTINUATION_PULSE_NUMBER: Tag = Tag(0x0074, 0x1404);
+/// (0074,1405) Channel Delivery Order Sequence
+pub const CHANNEL_DELIVERY_ORDER_SEQUENCE: Tag = Tag(0x0074, 0x1405);
+/// (0074,1406) Referenced Channel Number
+pub const REFERENCED_CHANNEL_NUMBER: Tag = Tag(0x0074, 0x1406);
+/// (0074,1407) Start Cumulative Time Weight
+pub const START_CUMULATIVE_TIME_WEIGHT: Tag = Tag(0x0074, 0x1407);
+/// (0074,1408) End Cumulative Time Weight
+pub const END_CUMULATIVE_TIME_WEIGHT: Tag = Tag(0x0074, 0x1408);
+/// (0074,1409) Omitted Channel Sequence
+pub const OMITTED_CHANNEL_SEQUENCE: Tag = Tag(0x0074, 0x1409);
+/// (0074,140A) Reason for Channel Omission
+pub const REASON_FOR_CHANNEL_OMISSION: Tag = Tag(0x0074, 0x140A);
+/// (0074,140B) Reason for Channel Omission Description
+pub const REASON_FOR_CHANNEL_OMISSION_DESCRIPTION: Tag = Tag(0x0074, 0x140B);
+/// (0074,140C) Channel Delivery Order Index
+pub const CHANNEL_DELIVERY_ORDER_INDEX: Tag = Tag(0x0074, 0x140C);
+/// (0074,140D) Channel Delivery Continuation Sequence
+pub const CHANNEL_DELIVERY_CONTINUATION_SEQUENCE: Tag = Tag(0x0074, 0x140D);
+/// (0074,140E) Omitted Application Setup Sequence
+pub const OMITTED_APPLICATION_SETUP_SEQUENCE: Tag = Tag(0x0074, 0x140E);
+/// (0076,0001) Implant Assembly Template Name
+pub const IMPLANT_ASSEMBLY_TEMPLATE_NAME: Tag = Tag(0x0076, 0x0001);
+/// (0076,0003) Implant Assembly Template Issuer
+pub const IMPLANT_ASSEMBLY_TEMPLATE_ISSUER: Tag = Tag(0x0076, 0x0003);
+/// (0076,0006) Implant Assembly Template Version
+pub const IMPLANT_ASSEMBLY_TEMPLATE_VERSION: Tag = Tag(0x0076, 0x0006);
+/// (0076,0008) Replaced Implant Assembly Template Sequence
+pub const REPLACED_IMPLANT_ASSEMBLY_TEMPLATE_SEQUENCE: Tag = Tag(0x0076, 0x0008);
+/// (0076,000A) Implant Assembly Template Type
+pub const IMPLANT_ASSEMBLY_TEMPLATE_TYPE: Tag = Tag(0x0076, 0x000A);
+/// (0076,000C) Original Implant Assembly Template Sequence
+pub const ORIGINAL_IMPLANT_ASSEMBLY_TEMPLATE_SEQUENCE: Tag = Tag(0x0076, 0x000C);
+/// (0076,000E) Derivation Implant Assembly Template Sequence
+pub const DERIVATION_IMPLANT_ASSEMBLY_TEMPLATE_SEQUENCE: Tag = Tag(0x0076, 0x000E);
+/// (0076,0010) Implant Assembly Template Target Anatomy Sequence
+pub const IMPLANT_ASSEMBLY_TEMPLATE_TARGET_ANATOMY_SEQUENCE: Tag = Tag(0x0076, 0x0010);
+/// (0076,0020) Procedure Type Code Sequence
+pub const PROCEDURE_TYPE_CODE_SEQUENCE: Tag = Tag(0x0076, 0x0020);
+/// (0076,0030) Surgical Technique
+pub const SURGICAL_TECHNIQUE: Tag = Tag(0x0076, 0x0030);
+/// (0076,0032) Component Types Sequence
+pub const COMPONENT_TYPES_SEQUENCE: Tag = Tag(0x0076, 0x0032);
+/// (0076,0034) Component Type Code Sequence
+pub const COMPONENT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0076, 0x0034);
+/// (0076,0036) Exclusive Component Type
+pub const EXCLUSIVE_COMPONENT_TYPE: Tag = Tag(0x0076, 0x0036);
+/// (0076,0038) Mandatory Component Type
+pub const MANDATORY_COMPONENT_TYPE: Tag = Tag(0x0076, 0x0038);
+/// (0076,0040) Component Sequence
+pub const COMPONENT_SEQUENCE: Tag = Tag(0x0076, 0x0040);
+/// (0076,0055) Component ID
+pub const COMPONENT_ID: Tag = Tag(0x0076, 0x0055);
+/// (0076,0060) Component Assembly Sequence
+pub const COMPONENT_ASSEMBLY_SEQUENCE: Tag = Tag(0x0076, 0x0060);
+/// (0076,0070) Component 1 Referenced ID
+pub const COMPONENT1REFERENCED_ID: Tag = Tag(0x0076, 0x0070);
+/// (0076,0080) Component 1 Referenced Mating Feature Set ID
+pub const COMPONENT1REFERENCED_MATING_FEATURE_SET_ID: Tag = Tag(0x0076, 0x0080);
+/// (0076,0090) Component 1 Referenced Mating Feature ID
+pub const COMPONENT1REFERENCED_MATING_FEATURE_ID: Tag = Tag(0x0076, 0x0090);
+/// (0076,00A0) Component 2 Referenced ID
+pub const COMPONENT2REFERENCED_ID: Tag = Tag(0x0076, 0x00A0);
+/// (0076,00B0) Component 2 Referenced Mating Feature Set ID
+pub const COMPONENT2REFERENCED_MATING_FEATURE_SET_ID: Tag = Tag(0x0076, 0x00B0);
+/// (0076,00C0) Component 2 Referenced Mating Feature ID
+pub const COMPONENT2REFERENCED_MATING_FEATURE_ID: Tag = Tag(0x0076, 0x00C0);
+/// (0078,0001) Implant Template Group Name
+pub const IMPLANT_TEMPLATE_GROUP_NAME: Tag = Tag(0x0078, 0x0001);
+/// (0078,0010) Implant Template Group Description
+pub const IMPLANT_TEMPLATE_GROUP_DESCRIPTION: Tag = Tag(0x0078, 0x0010);
+/// (0078,0020) Implant Template Group Issuer
+pub const IMPLANT_TEMPLATE_GROUP_ISSUER: Tag = Tag(0x0078, 0x0020);
+/// (0078,0024) Implant Template Group Version
+pub const IMPLANT_TEMPLATE_GROUP_VERSION: Tag = Tag(0x0078, 0x0024);
+/// (0078,0026) Replaced Implant Template Group Sequence
+pub const REPLACED_IMPLANT_TEMPLATE_GROUP_SEQUENCE: Tag = Tag(0x0078, 0x0026);
+/// (0078,0028) Implant Template Group Target Anatomy Sequence
+pub const IMPLANT_TEMPLATE_GROUP_TARGET_ANATOMY_SEQUENCE: Tag = Tag(0x0078, 0x0028);
+/// (0078,002A) Implant Template Group Members Sequence
+pub const IMPLANT_TEMPLATE_GROUP_MEMBERS_SEQUENCE: Tag = Tag(0x0078, 0x002A);
+/// (0078,002E) Implant Template Group Member ID
+pub const IMPLANT_TEMPLATE_GROUP_MEMBER_ID: Tag = Tag(0x0078, 0x002E);
+/// (0078,0050) Three D Implant Template Group Member Matching Point
+pub const THREE_D_IMPLANT_TEMPLATE_GROUP_MEMBER_MATCHING_POINT: Tag = Tag(0x0078, 0x0050);
+/// (0078,0060) Three D Implant Template Group Member Matching Axes
+pub const THREE_D_IMPLANT_TEMPLATE_GROUP_MEMBER_MATCHING_AXES: Tag = Tag(0x0078, 0x0060);
+/// (0078,0070) Implant Template Group Member Matching 2D Coordinates Sequence
+pub const IMPLANT_TEMPLATE_GROUP_MEMBER_MATCHING2D_COORDINATES_SEQUENCE: Tag = Tag(0x0078, 0x0070);
+/// (0078,0090) Two D Implant Template Group Member Matching Point
+pub const TWO_D_IMPLANT_TEMPLATE_GROUP_MEMBER_MATCHING_POINT: Tag = Tag(0x0078, 0x0090);
+/// (0078,00A0) Two D Implant Template Group Member Matching Axes
+pub const TWO_D_IMPLANT_TEMPLATE_GROUP_MEMBER_MATCHING_AXES: Tag = Tag(0x0078, 0x00A0);
+/// (0078,00B0) Implant Template Group Variation Dimension Sequence
+pub const IMPLANT_TEMPLATE_GROUP_VARIATION_DIMENSION_SEQUENCE: Tag = Tag(0x0078, 0x00B0);
+/// (0078,00B2) Implant Template Group Variation Dimension Name
+pub const IMPLANT_TEMPLATE_GROUP_VARIATION_DIMENSION_NAME: Tag = Tag(0x0078, 0x00B2);
+/// (0078,00B4) Implant Template Group Variation Dimension Rank Sequence
+pub const IMPLANT_TEMPLATE_GROUP_VARIATION_DIMENSION_RANK_SEQUENCE: Tag = Tag(0x0078, 0x00B4);
+/// (0078,00B6) Referenced Implant Template Group Member ID
+pub const REFERENCED_IMPLANT_TEMPLATE_GROUP_MEMBER_ID: Tag = Tag(0x0078, 0x00B6);
+/// (0078,00B8) Implant Template Group Variation Dimension Rank
+pub const IMPLANT_TEMPLATE_GROUP_VARIATION_DIMENSION_RANK: Tag = Tag(0x0078, 0x00B8);
+/// (0080,0001) Surface Scan Acquisition Type Code Sequence
+pub const SURFACE_SCAN_ACQUISITION_TYPE_CODE_SEQUENCE: Tag = Tag(0x0080, 0x0001);
+/// (0080,0002) Surface Scan Mode Code Sequence
+pub const SURFACE_SCAN_MODE_CODE_SEQUENCE: Tag = Tag(0x0080, 0x0002);
+/// (0080,0003) Registration Method Code Sequence
+pub const REGISTRATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0080, 0x0003);
+/// (0080,0004) Shot Duration Time
+pub const SHOT_DURATION_TIME: Tag = Tag(0x0080, 0x0004);
+/// (0080,0005) Shot Offset Time
+pub const SHOT_OFFSET_TIME: Tag = Tag(0x0080, 0x0005);
+/// (0080,0006) Surface Point Presentation Value Data
+pub const SURFACE_POINT_PRESENTATION_VALUE_DATA: Tag = Tag(0x0080, 0x0006);
+/// (0080,0007) Surface Point Color CIE Lab Value Data
+pub const SURFACE_POINT_COLOR_CIE_LAB_VALUE_DATA: Tag = Tag(0x0080, 0x0007);
+/// (0080,0008) UV Mapping Sequence
+pub const UV_MAPPING_SEQUENCE: Tag = Tag(0x0080, 0x0008);
+/// (0080,0009) Texture Label
+pub const TEXTURE_LABEL: Tag = Tag(0x0080, 0x0009);
+/// (0080,0010) U Value Data
+pub const U_VALUE_DATA: Tag = Tag(0x0080, 0x0010);
+/// (0080,0011) V Value Data
+pub const V_VALUE_DATA: Tag = Tag(0x0080, 0x0011);
+/// (0080,0012) Referenced Texture Sequence
+pub const REFERENCED_TEXTURE_SEQUENCE: Tag = Tag(0x0080, 0x0012);
+/// (0080,0013) Referenced Surface Data Sequence
+pub const REFERENCED_SURFACE_DATA_SEQUENCE: Tag = Tag(0x0080, 0x0013);
+/// (0082,0001) Assessment Summary
+pub const ASSESSMENT_SUMMARY: Tag = Tag(0x0082, 0x0001);
+/// (0082,0003) Assessment Summary Description
+pub const ASSESSMENT_SUMMARY_DESCRIPTION: Tag = Tag(0x0082, 0x0003);
+/// (0082,0004) Assessed SOP Instance Sequence
+pub const ASSESSED_SOP_INSTANCE_SEQUENCE: Tag = Tag(0x0082, 0x0004);
+/// (0082,0005) Referenced Comparison SOP Instance Sequence
+pub const REFERENCED_COMPARISON_SOP_INSTANCE_SEQUENCE: Tag = Tag(0x0082, 0x0005);
+/// (0082,0006) Number of Assessment Observations
+pub const NUMBER_OF_ASSESSMENT_OBSERVATIONS: Tag = Tag(0x0082, 0x0006);
+/// (0082,0007) Assessment Observations Sequence
+pub const ASSESSMENT_OBSERVATIONS_SEQUENCE: Tag = Tag(0x0082, 0x0007);
+/// (0082,0008) Observation Significance
+pub const OBSERVATION_SIGNIFICANCE: Tag = Tag(0x0082, 0x0008);
+/// (0082,000A) Observation Description
+pub const OBSERVATION_DESCRIPTION: Tag = Tag(0x0082, 0x000A);
+/// (0082,000C) Structured Constraint Observation Sequence
+pub const STRUCTURED_CONSTRAINT_OBSERVATION_SEQUENCE: Tag = Tag(0x0082, 0x000C);
+/// (0082,0010) Assessed Attribute Value Sequence
+pub const ASSESSED_ATTRIBUTE_VALUE_SEQUENCE: Tag = Tag(0x0082, 0x0010);
+/// (0082,0016) Assessment Set ID
+pub const ASSESSMENT_SET_ID: Tag = Tag(0x0082, 0x0016);
+/// (0082,0017) Assessment Requester Sequence
+pub const ASSESSMENT_REQUESTER_SEQUENCE: Tag = Tag(0x0082, 0x0017);
+/// (0082,0018) Selector Attribute Name
+pub const SELECTOR_ATTRIBUTE_NAME: Tag = Tag(0x0082, 0x0018);
+/// (0082,0019) Selector Attribute Keyword
+pub const SELECTOR_ATTRIBUTE_KEYWORD: Tag = Tag(0x0082, 0x0019);
+/// (0082,0021) Assessment Type Code Sequence
+pub const ASSESSMENT_TYPE_CODE_SEQUENCE: Tag = Tag(0x0082, 0x0021);
+/// (0082,0022) Observation Basis Code Sequence
+pub const OBSERVATION_BASIS_CODE_SEQUENCE: Tag = Tag(0x0082, 0x0022);
+/// (0082,0023) Assessment Label
+pub const ASSESSMENT_LABEL: Tag = Tag(0x0082, 0x0023);
+/// (0082,0032) Constraint Type
+pub const CONSTRAINT_TYPE: Tag = Tag(0x0082, 0x0032);
+/// (0082,0033) Specification Selection Guidance
+pub const SPECIFICATION_SELECTION_GUIDANCE: Tag = Tag(0x0082, 0x0033);
+/// (0082,0034) Constraint Value Sequence
+pub const CONSTRAINT_VALUE_SEQUENCE: Tag = Tag(0x0082, 0x0034);
+/// (0082,0035) Recommended Default Value Sequence
+pub const RECOMMENDED_DEFAULT_VALUE_SEQUENCE: Tag = Tag(0x0082, 0x0035);
+/// (0082,0036) Constraint Violation Significance
+pub const CONSTRAINT_VIOLATION_SIGNIFICANCE: Tag = Tag(0x0082, 0x0036);
+/// (0082,0037) Constraint Violation Condition
+pub const CONSTRAINT_VIOLATION_CONDITION: Tag = Tag(0x0082, 0x0037);
+/// (0082,0038) Modifiable Constraint Flag
+pub const MODIFIABLE_CONSTRAINT_FLAG: Tag = Tag(0x0082, 0x0038);
+/// (0088,0130) Storage Media File Set ID
+pub const STORAGE_MEDIA_FILE_SET_ID: Tag = Tag(0x0088, 0x0130);
+/// (0088,0140) Storage Media File Set UID
+pub const STORAGE_MEDIA_FILE_SET_UID: Tag = Tag(0x0088, 0x0140);
+/// (0088,0200) Icon Image Sequence
+pub const ICON_IMAGE_SEQUENCE: Tag = Tag(0x0088, 0x0200);
+/// (0088,0904) Topic Title (retired)
+pub const TOPIC_TITLE: Tag = Tag(0x0088, 0x0904);
+/// (0088,0906) Topic Subject (retired)
+pub const TOPIC_SUBJECT: Tag = Tag(0x0088, 0x0906);
+/// (0088,0910) Topic Author (retired)
+pub const TOPIC_AUTHOR: Tag = Tag(0x0088, 0x0910);
+/// (0088,0912) Topic Keywords (retired)
+pub const TOPIC_KEYWORDS: Tag = Tag(0x0088, 0x0912);
+/// (0100,0410) SOP Instance Status
+pub const SOP_INSTANCE_STATUS: Tag = Tag(0x0100, 0x0410);
+/// (0100,0420) SOP Authorization Date Time
+pub const SOP_AUTHORIZATION_DATE_TIME: Tag = Tag(0x0100, 0x0420);
+/// (0100,0424) SOP Authorization Comment
+pub const SOP_AUTHORIZATION_COMMENT: Tag = Tag(0x0100, 0x0424);
+/// (0100,0426) Authorization Equipment Certification Number
+pub const AUTHORIZATION_EQUIPMENT_CERTIFICATION_NUMBER: Tag = Tag(0x0100, 0x0426);
+/// (0400,0005) MACID Number
+pub const MACID_NUMBER: Tag = Tag(0x0400, 0x0005);
+/// (0400,0010) MAC Calculation Transfer Syntax UID
+pub const MAC_CALCULATION_TRANSFER_SYNTAX_UID: Tag = Tag(0x0400, 0x0010);
+/// (0400,0015) MAC Algorithm
+pub const MAC_ALGORITHM: Tag = Tag(0x0400, 0x0015);
+/// (0400,0020) Data Elements Signed
+pub const DATA_ELEMENTS_SIGNED: Tag = Tag(0x0400, 0x0020);
+/// (0400,0100) Digital Signature UID
+pub const DIGITAL_SIGNATURE_UID: Tag = Tag(0x0400, 0x0100);
+/// (0400,0105) Digital Signature Date Time
+pub const DIGITAL_SIGNATURE_DATE_TIME: Tag = Tag(0x0400, 0x0105);
+/// (0400,0110) Certificate Type
+pub const CERTIFICATE_TYPE: Tag = Tag(0x0400, 0x0110);
+/// (0400,0115) Certificate of Signer
+pub const CERTIFICATE_OF_SIGNER: Tag = Tag(0x0400, 0x0115);
+/// (0400,0120) Signature
+pub const SIGNATURE: Tag = Tag(0x0400, 0x0120);
+/// (0400,0305) Certified Timestamp Type
+pub const CERTIFIED_TIMESTAMP_TYPE: Tag = Tag(0x0400, 0x0305);
+/// (0400,0310) Certified Timestamp
+pub const CERTIFIED_TIMESTAMP: Tag = Tag(0x0400, 0x0310);
+/// (0400,0401) Digital Signature Purpose Code Sequence
+pub const DIGITAL_SIGNATURE_PURPOSE_CODE_SEQUENCE: Tag = Tag(0x0400, 0x0401);
+/// (0400,0402) Referenced Digital Signature Sequence
+pub const REFERENCED_DIGITAL_SIGNATURE_SEQUENCE: Tag = Tag(0x0400, 0x0402);
+/// (0400,0403) Referenced SOP Instance MAC Sequence
+pub const REFERENCED_SOP_INSTANCE_MAC_SEQUENCE: Tag = Tag(0x0400, 0x0403);
+/// (0400,0404) MAC
+pub const MAC: Tag = Tag(0x0400, 0x0404);
+/// (0400,0500) Encrypted Attributes Sequence
+pub const ENCRYPTED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0400, 0x0500);
+/// (0400,0510) Encrypted Content Transfer Syntax UID
+pub const ENCRYPTED_CONTENT_TRANSFER_SYNTAX_UID: Tag = Tag(0x0400, 0x0510);
+/// (0400,0520) Encrypted Content
+pub const ENCRYPTED_CONTENT: Tag = Tag(0x0400, 0x0520);
+/// (0400,0550) Modified Attributes Sequence
+pub const MODIFIED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0400, 0x0550);
+/// (0400,0551) Nonconforming Modified Attributes Sequence
+pub const NONCONFORMING_MODIFIED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0400, 0x0551);
+/// (0400,0552) Nonconforming Data Element Value
+pub const NONCONFORMING_DATA_ELEMENT_VALUE: Tag = Tag(0x0400, 0x0552);
+/// (0400,0561) Original Attributes Sequence
+pub const ORIGINAL_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0400, 0x0561);
+/// (0400,0562) Attribute Modification Date Time
+pub const ATTRIBUTE_MODIFICATION_DATE_TIME: Tag = Tag(0x0400, 0x0562);
+/// (0400,0563) Modifying System
+pub const MODIFYING_SYSTEM: Tag = Tag(0x0400, 0x0563);
+/// (0400,0564) Source of Previous Values
+pub const SOURCE_OF_PREVIOUS_VALUES: Tag = Tag(0x0400, 0x0564);
+/// (0400,0565) Reason for the Attribute Modification
+pub const REASON_FOR_THE_ATTRIBUTE_MODIFICATION: Tag = Tag(0x0400, 0x0565);
+/// (0400,0600) Instance Origin Status
+pub const INSTANCE_ORIGIN_STATUS: Tag = Tag(0x0400, 0x0600);
+/// (2000,0010) Number of Copies
+pub const NUMBER_OF_COPIES: Tag = Tag(0x2000, 0x0010);
+/// (2000,001E) Printer Configuration Sequence
+pub const PRINTER_CONFIGURATION_SEQUENCE: Tag = Tag(0x2000, 0x001E);
+/// (2000,0020) Print Priority
+pub const PRINT_PRIORITY: Tag = Tag(0x2000, 0x0020);
+/// (2000,0030) Medium Type
+pub const MEDIUM_TYPE: Tag = Tag(0x2000, 0x0030);
+/// (2000,0040) Film Destination
+pub const FILM_DESTINATION: Tag = Tag(0x2000, 0x0040);
+/// (2000,0050) Film Session Label
+pub const FILM_SESSION_LABEL: Tag = Tag(0x2000, 0x0050);
+/// (2000,0060) Memory Allocation
+pub const MEMORY_ALLOCATION: Tag = Tag(0x2000, 0x0060);
+/// (2000,0061) Maximum Memory Allocation
+pub const MAXIMUM_MEMORY_ALLOCATION: Tag = Tag(0x2000, 0x0061);
+/// (2000,0062) Color Image Printing Flag (retired)
+pub const COLOR_IMAGE_PRINTING_FLAG: Tag = Tag(0x2000, 0x0062);
+/// (2000,0063) Collation Flag (retired)
+pub const COLLATION_FLAG: Tag = Tag(0x2000, 0x0063);
+/// (2000,0065) Annotation Flag (retired)
+pub const ANNOTATION_FLAG: Tag = Tag(0x2000, 0x0065);
+/// (2000,0067) Image Overlay Flag (retired)
+pub const IMAGE_OVERLAY_FLAG: Tag = Tag(0x2000, 0x0067);
+/// (2000,0069) Presentation LUT Flag (retired)
+pub const PRESENTATION_LUT_FLAG: Tag = Tag(0x2000, 0x0069);
+/// (2000,006A) Image Box Presentation LUT Flag (retired)
+pub const IMAGE_BOX_PRESENTATION_LUT_FLAG: Tag = Tag(0x2000, 0x006A);
+/// (2000,00A0) Memory Bit Depth
+pub const MEMORY_BIT_DEPTH: Tag = Tag(0x2000, 0x00A0);
+/// (2000,00A1) Printing Bit Depth
+pub const PRINTING_BIT_DEPTH: Tag = Tag(0x2000, 0x00A1);
+/// (2000,00A2) Media Installed Sequence
+pub const MEDIA_INSTALLED_SEQUENCE: Tag = Tag(0x2000, 0x00A2);
+/// (2000,00A4) Other Media Available Sequence
+pub const OTHER_MEDIA_AVAILABLE_SEQUENCE: Tag = Tag(0x2000, 0x00A4);
+/// (2000,00A8) Supported Image Display Formats Sequence
+pub const SUPPORTED_IMAGE_DISPLAY_FORMATS_SEQUENCE: Tag = Tag(0x2000, 0x00A8);
+/// (2000,0500) Referenced Film Box Sequence
+pub const REFERENCED_FILM_BOX_SEQUENCE: Tag = Tag(0x2000, 0x0500);
+/// (2000,0510) Referenced Stored Print Sequence (retired)
+pub const REFERENCED_STORED_PRINT_SEQUENCE: Tag = Tag(0x2000, 0x0510);
+/// (2010,0010) Image Display Format
+pub const IMAGE_DISPLAY_FORMAT: Tag = Tag(0x2010, 0x0010);
+/// (2010,0030) Annotation Display Format ID
+pub const ANNOTATION_DISPLAY_FORMAT_ID: Tag = Tag(0x2010, 0x0030);
+/// (2010,0040) Film Orientation
+pub const FILM_ORIENTATION: Tag = Tag(0x2010, 0x0040);
+/// (2010,0050) Film Size ID
+pub const FILM_SIZE_ID: Tag = Tag(0x2010, 0x0050);
+/// (2010,0052) Printer Resolution ID
+pub const PRINTER_RESOLUTION_ID: Tag = Tag(0x2010, 0x0052);
+/// (2010,0054) Default Printer Resolution ID
+pub const DEFAULT_PRINTER_RESOLUTION_ID: Tag = Tag(0x2010, 0x0054);
+/// (2010,0060) Magnification Type
+pub const MAGNIFICATION_TYPE: Tag = Tag(0x2010, 0x0060);
+/// (2010,0080) Smoothing Type
+pub const SMOOTHING_TYPE: Tag = Tag(0x2010, 0x0080);
+/// (2010,00A6) Default Magnification Type
+pub const DEFAULT_MAGNIFICATION_TYPE: Tag = Tag(0x2010, 0x00A6);
+/// (2010,00A7) Other Magnification Types Available
+pub const OTHER_MAGNIFICATION_TYPES_AVAILABLE: Tag = Tag(0x2010, 0x00A7);
+/// (2010,00A8) Default Smoothing Type
+pub const DEFAULT_SMOOTHING_TYPE: Tag = Tag(0x2010, 0x00A8);
+/// (2010,00A9) Other Smoothing Types Available
+pub const OTHER_SMOOTHING_TYPES_AVAILABLE: Tag = Tag(0x2010, 0x00A9);
+/// (2010,0100) Border Density
+pub const BORDER_DENSITY: Tag = Tag(0x2010, 0x0100);
+/// (2010,0110) Empty Image Density
+pub const EMPTY_IMAGE_DENSITY: Tag = Tag(0x2010, 0x0110);
+/// (2010,0120) Min Density
+pub const MIN_DENSITY: Tag = Tag(0x2010, 0x0120);
+/// (2010,0130) Max Density
+pub const MAX_DENSITY: Tag = Tag(0x2010, 0x0130);
+/// (2010,0140) Trim
+pub const TRIM: Tag = Tag(0x2010, 0x0140);
+/// (2010,0150) Configuration Information
+pub const CONFIGURATION_INFORMATION: Tag = Tag(0x2010, 0x0150);
+/// (2010,0152) Configuration Information Description
+pub const CONFIGURATION_INFORMATION_DESCRIPTION: Tag = Tag(0x2010, 0x0152);
+/// (2010,0154) Maximum Collated Films
+pub const MAXIMUM_COLLATED_FILMS: Tag = Tag(0x2010, 0x0154);
+/// (2010,015E) Illumination
+pub const ILLUMINATION: Tag = Tag(0x2010, 0x015E);
+/// (2010,0160) Reflected Ambient Light
+pub const REFLECTED_AMBIENT_LIGHT: Tag = Tag(0x2010, 0x0160);
+/// (2010,0376) Printer Pixel Spacing
+pub const PRINTER_PIXEL_SPACING: Tag = Tag(0x2010, 0x0376);
+/// (2010,0500) Referenced Film Session Sequence
+pub const REFERENCED_FILM_SESSION_SEQUENCE: Tag = Tag(0x2010, 0x0500);
+/// (2010,0510) Referenced Image Box Sequence
+pub const REFERENCED_IMAGE_BOX_SEQUENCE: Tag = Tag(0x2010, 0x0510);
+/// (2010,0520) Referenced Basic Annotation Box Sequence
+pub const REFERENCED_BASIC_ANNOTATION_BOX_SEQUENCE: Tag = Tag(0x2010, 0x0520);
+/// (2020,0010) Image Box Position
+pub const IMAGE_BOX_POSITION: Tag = Tag(0x2020, 0x0010);
+/// (2020,0020) Polarity
+pub const POLARITY: Tag = Tag(0x2020, 0x0020);
+/// (2020,0030) Requested Image Size
+pub const REQUESTED_IMAGE_SIZE: Tag = Tag(0x2020, 0x0030);
+/// (2020,0040) Requested Decimate Crop Behavior
+pub const REQUESTED_DECIMATE_CROP_BEHAVIOR: Tag = Tag(0x2020, 0x0040);
+/// (2020,0050) Requested Resolution ID
+pub const REQUESTED_RESOLUTION_ID: Tag = Tag(0x2020, 0x0050);
+/// (2020,00A0) Requested Image Size Flag
+pub const REQUESTED_IMAGE_SIZE_FLAG: Tag = Tag(0x2020, 0x00A0);
+/// (2020,00A2) Decimate Crop Result
+pub const DECIMATE_CROP_RESULT: Tag = Tag(0x2020, 0x00A2);
+/// (2020,0110) Basic Grayscale Image Sequence
+pub const BASIC_GRAYSCALE_IMAGE_SEQUENCE: Tag = Tag(0x2020, 0x0110);
+/// (2020,0111) Basic Color Image Sequence
+pub const BASIC_COLOR_IMAGE_SEQUENCE: Tag = Tag(0x2020, 0x0111);
+/// (2020,0130) Referenced Image Overlay Box Sequence (retired)
+pub const REFERENCED_IMAGE_OVERLAY_BOX_SEQUENCE: Tag = Tag(0x2020, 0x0130);
+/// (2020,0140) Referenced VOILUT Box Sequence (retired)
+pub const REFERENCED_VOILUT_BOX_SEQUENCE: Tag = Tag(0x2020, 0x0140);
+/// (2030,0010) Annotation Position
+pub const ANNOTATION_POSITION: Tag = Tag(0x2030, 0x0010);
+/// (2030,0020) Text String
+pub const TEXT_STRING: Tag = Tag(0x2030, 0x0020);
+/// (2040,0010) Referenced Overlay Plane Sequence (retired)
+pub const REFERENCED_OVERLAY_PLANE_SEQUENCE: Tag = Tag(0x2040, 0x0010);
+/// (2040,0011) Referenced Overlay Plane Groups (retired)
+pub const REFERENCED_OVERLAY_PLANE_GROUPS: Tag = Tag(0x2040, 0x0011);
+/// (2040,0020) Overlay Pixel Data Sequence (retired)
+pub const OVERLAY_PIXEL_DATA_SEQUENCE: Tag = Tag(0x2040, 0x0020);
+/// (2040,0060) Overlay Magnification Type (retired)
+pub const OVERLAY_MAGNIFICATION_TYPE: Tag = Tag(0x2040, 0x0060);
+/// (2040,0070) Overlay Smoothing Type (retired)
+pub const OVERLAY_SMOOTHING_TYPE: Tag = Tag(0x2040, 0x0070);
+/// (2040,0072) Overlay or Image Magnification (retired)
+pub const OVERLAY_OR_IMAGE_MAGNIFICATION: Tag = Tag(0x2040, 0x0072);
+/// (2040,0074) Magnify to Number of Columns (retired)
+pub const MAGNIFY_TO_NUMBER_OF_COLUMNS: Tag = Tag(0x2040, 0x0074);
+/// (2040,0080) Overlay Foreground Density (retired)
+pub const OVERLAY_FOREGROUND_DENSITY: Tag = Tag(0x2040, 0x0080);
+/// (2040,0082) Overlay Background Density (retired)
+pub const OVERLAY_BACKGROUND_DENSITY: Tag = Tag(0x2040, 0x0082);
+/// (2040,0090) Overlay Mode (retired)
+pub const OVERLAY_MODE: Tag = Tag(0x2040, 0x0090);
+/// (2040,0100) Threshold Density (retired)
+pub const THRESHOLD_DENSITY: Tag = Tag(0x2040, 0x0100);
+/// (2040,0500) Referenced Image Box Sequence Retired (retired)
+pub const REFERENCED_IMAGE_BOX_SEQUENCE_RETIRED: Tag = Tag(0x2040, 0x0500);
+/// (2050,0010) Presentation LUT Sequence
+pub const PRESENTATION_LUT_SEQUENCE: Tag = Tag(0x2050, 0x0010);
+/// (2050,0020) Presentation LUT Shape
+pub const PRESENTATION_LUT_SHAPE: Tag = Tag(0x2050, 0x0020);
+/// (2050,0500) Referenced Presentation LUT Sequence
+pub const REFERENCED_PRESENTATION_LUT_SEQUENCE: Tag = Tag(0x2050, 0x0500);
+/// (2100,0010) Print Job ID (retired)
+pub const PRINT_JOB_ID: Tag = Tag(0x2100, 0x0010);
+/// (2100,0020) Execution Status
+pub const EXECUTION_STATUS: Tag = Tag(0x2100, 0x0020);
+/// (2100,0030) Execution Status Info
+pub const EXECUTION_STATUS_INFO: Tag = Tag(0x2100, 0x0030);
+/// (2100,0040) Creation Date
+pub const CREATION_DATE: Tag = Tag(0x2100, 0x0040);
+/// (2100,0050) Creation Time
+pub const CREATION_TIME: Tag = Tag(0x2100, 0x0050);
+/// (2100,0070) Originator
+pub const ORIGINATOR: Tag = Tag(0x2100, 0x0070);
+/// (2100,0140) Destination AE
+pub const DESTINATION_AE: Tag = Tag(0x2100, 0x0140);
+/// (2100,0160) Owner ID
+pub const OWNER_ID: Tag = Tag(0x2100, 0x0160);
+/// (2100,0170) Number of Films
+pub const NUMBER_OF_FILMS: Tag = Tag(0x2100, 0x0170);
+/// (2100,0500) Referenced Print Job Sequence Pull Stored Print (retired)
+pub const REFERENCED_PRINT_JOB_SEQUENCE_PULL_STORED_PRINT: Tag = Tag(0x2100, 0x0500);
+/// (2110,0010) Printer Status
+pub const PRINTER_STATUS: Tag = Tag(0x2110, 0x0010);
+/// (2110,0020) Printer Status Info
+pub const PRINTER_STATUS_INFO: Tag = Tag(0x2110, 0x0020);
+/// (2110,0030) Printer Name
+pub const PRINTER_NAME: Tag = Tag(0x2110, 0x0030);
+/// (2110,0099) Print Queue ID (retired)
+pub const PRINT_QUEUE_ID: Tag = Tag(0x2110, 0x0099);
+/// (2120,0010) Queue Status (retired)
+pub const QUEUE_STATUS: Tag = Tag(0x2120, 0x0010);
+/// (2120,0050) Print Job Description Sequence (retired)
+pub const PRINT_JOB_DESCRIPTION_SEQUENCE: Tag = Tag(0x2120, 0x0050);
+/// (2120,0070) Referenced Print Job Sequence (retired)
+pub const REFERENCED_PRINT_JOB_SEQUENCE: Tag = Tag(0x2120, 0x0070);
+/// (2130,0010) Print Management Capabilities Sequence (retired)
+pub const PRINT_MANAGEMENT_CAPABILITIES_SEQUENCE: Tag = Tag(0x2130, 0x0010);
+/// (2130,0015) Printer Characteristics Sequence (retired)
+pub const PRINTER_CHARACTERISTICS_SEQUENCE: Tag = Tag(0x2130, 0x0015);
+/// (2130,0030) Film Box Content Sequence (retired)
+pub const FILM_BOX_CONTENT_SEQUENCE: Tag = Tag(0x2130, 0x0030);
+/// (2130,0040) Image Box Content Sequence (retired)
+pub const IMAGE_BOX_CONTENT_SEQUENCE: Tag = Tag(0x2130, 0x0040);
+/// (2130,0050) Annotation Content Sequence (retired)
+pub const ANNOTATION_CONTENT_SEQUENCE: Tag = Tag(0x2130, 0x0050);
+/// (2130,0060) Image Overlay Box Content Sequence (retired)
+pub const IMAGE_OVERLAY_BOX_CONTENT_SEQUENCE: Tag = Tag(0x2130, 0x0060);
+/// (2130,0080) Presentation LUT Content Sequence (retired)
+pub const PRESENTATION_LUT_CONTENT_SEQUENCE: Tag = Tag(0x2130, 0x0080);
+/// (2130,00A0) Proposed Study Sequence (retired)
+pub const PROPOSED_STUDY_SEQUENCE: Tag = Tag(0x2130, 0x00A0);
+/// (2130,00C0) Original Image Sequence (retired)
+pub const ORIGINAL_IMAGE_SEQUENCE: Tag = Tag(0x2130, 0x00C0);
+/// (2200,0001) Label Using Information Extracted from Instances
+pub const LABEL_USING_INFORMATION_EXTRACTED_FROM_INSTANCES: Tag = Tag(0x2200, 0x0001);
+/// (2200,0002) Label Text
+pub const LABEL_TEXT: Tag = Tag(0x2200, 0x0002);
+/// (2200,0003) Label Style Selection
+pub const LABEL_STYLE_SELECTION: Tag = Tag(0x2200, 0x0003);
+/// (2200,0004) Media Disposition
+pub const MEDIA_DISPOSITION: Tag = Tag(0x2200, 0x0004);
+/// (2200,0005) Barcode Value
+pub const BARCODE_VALUE: Tag = Tag(0x2200, 0x0005);
+/// (2200,0006) Barcode Symbology
+pub const BARCODE_SYMBOLOGY: Tag = Tag(0x2200, 0x0006);
+/// (2200,0007) Allow Media Splitting
+pub const ALLOW_MEDIA_SPLITTING: Tag = Tag(0x2200, 0x0007);
+/// (2200,0008) Include Non DICOM Objects
+pub const INCLUDE_NON_DICOM_OBJECTS: Tag = Tag(0x2200, 0x0008);
+/// (2200,0009) Include Display Application
+pub const INCLUDE_DISPLAY_APPLICATION: Tag = Tag(0x2200, 0x0009);
+/// (2200,000A) Preserve Composite Instances After Media Creation
+pub const PRESERVE_COMPOSITE_INSTANCES_AFTER_MEDIA_CREATION: Tag = Tag(0x2200, 0x000A);
+/// (2200,000B) Total Number of Pieces of Media Created
+pub const TOTAL_NUMBER_OF_PIECES_OF_MEDIA_CREATED: Tag = Tag(0x2200, 0x000B);
+/// (2200,000C) Requested Media Application Profile
+pub const REQUESTED_MEDIA_APPLICATION_PROFILE: Tag = Tag(0x2200, 0x000C);
+/// (2200,000D) Referenced Storage Media Sequence
+pub const REFERENCED_STORAGE_MEDIA_SEQUENCE: Tag = Tag(0x2200, 0x000D);
+/// (2200,000E) Failure Attributes
+pub const FAILURE_ATTRIBUTES: Tag = Tag(0x2200, 0x000E);
+/// (2200,000F) Allow Lossy Compression
+pub const ALLOW_LOSSY_COMPRESSION: Tag = Tag(0x2200, 0x000F);
+/// (2200,0020) Request Priority
+pub const REQUEST_PRIORITY: Tag = Tag(0x2200, 0x0020);
+/// (3002,0002) RT Image Label
+pub const RT_IMAGE_LABEL: Tag = Tag(0x3002, 0x0002);
+/// (3002,0003) RT Image Name
+pub const RT_IMAGE_NAME: Tag = Tag(0x3002, 0x0003);
+/// (3002,0004) RT Image Description
+pub const RT_IMAGE_DESCRIPTION: Tag = Tag(0x3002, 0x0004);
+/// (3002,000A) Reported Values Origin
+pub const REPORTED_VALUES_ORIGIN: Tag = Tag(0x3002, 0x000A);
+/// (3002,000C) RT Image Plane
+pub const RT_IMAGE_PLANE: Tag = Tag(0x3002, 0x000C);
+/// (3002,000D) X-Ray Image Receptor Translation
+pub const X_RAY_IMAGE_RECEPTOR_TRANSLATION: Tag = Tag(0x3002, 0x000D);
+/// (3002,000E) X-Ray Image Receptor Angle
+pub const X_RAY_IMAGE_RECEPTOR_ANGLE: Tag = Tag(0x3002, 0x000E);
+/// (3002,0010) RT Image Orientation
+pub const RT_IMAGE_ORIENTATION: Tag = Tag(0x3002, 0x0010);
+/// (3002,0011) Image Plane Pixel Spacing
+pub const IMAGE_PLANE_PIXEL_SPACING: Tag = Tag(0x3002, 0x0011);
+/// (3002,0012) RT Image Position
+pub const RT_IMAGE_POSITION: Tag = Tag(0x3002, 0x0012);
+/// (3002,0020) Radiation Machine Name
+pub const RADIATION_MACHINE_NAME: Tag = Tag(0x3002, 0x0020);
+/// (3002,0022) Radiation Machine SAD
+pub const RADIATION_MACHINE_SAD: Tag = Tag(0x3002, 0x0022);
+/// (3002,0024) Radiation Machine SSD
+pub const RADIATION_MACHINE_SSD: Tag = Tag(0x3002, 0x0024);
+/// (3002,0026) RT Image SID
+pub const RT_IMAGE_SID: Tag = Tag(0x3002, 0x0026);
+/// (3002,0028) Source to Reference Object Distance
+pub const SOURCE_TO_REFERENCE_OBJECT_DISTANCE: Tag = Tag(0x3002, 0x0028);
+/// (3002,0029) Fraction Number
+pub const FRACTION_NUMBER: Tag = Tag(0x3002, 0x0029);
+/// (3002,0030) Exposure Sequence
+pub const EXPOSURE_SEQUENCE: Tag = Tag(0x3002, 0x0030);
+/// (3002,0032) Meterset Exposure
+pub const METERSET_EXPOSURE: Tag = Tag(0x3002, 0x0032);
+/// (3002,0034) Diaphragm Position
+pub const DIAPHRAGM_POSITION: Tag = Tag(0x3002, 0x0034);
+/// (3002,0040) Fluence Map Sequence
+pub const FLUENCE_MAP_SEQUENCE: Tag = Tag(0x3002, 0x0040);
+/// (3002,0041) Fluence Data Source
+pub const FLUENCE_DATA_SOURCE: Tag = Tag(0x3002, 0x0041);
+/// (3002,0042) Fluence Data Scale
+pub const FLUENCE_DATA_SCALE: Tag = Tag(0x3002, 0x0042);
+/// (3002,0050) Primary Fluence Mode Sequence
+pub const PRIMARY_FLUENCE_MODE_SEQUENCE: Tag = Tag(0x3002, 0x0050);
+/// (3002,0051) Fluence Mode
+pub const FLUENCE_MODE: Tag = Tag(0x3002, 0x0051);
+/// (3002,0052) Fluence Mode ID
+pub const FLUENCE_MODE_ID: Tag = Tag(0x3002, 0x0052);
+/// (3004,0001) DVH Type
+pub const DVH_TYPE: Tag = Tag(0x3004, 0x0001);
+/// (3004,0002) Dose Units
+pub const DOSE_UNITS: Tag = Tag(0x3004, 0x0002);
+/// (3004,0004) Dose Type
+pub const DOSE_TYPE: Tag = Tag(0x3004, 0x0004);
+/// (3004,0005) Spatial Transform of Dose
+pub const SPATIAL_TRANSFORM_OF_DOSE: Tag = Tag(0x3004, 0x0005);
+/// (3004,0006) Dose Comment
+pub const DOSE_COMMENT: Tag = Tag(0x3004, 0x0006);
+/// (3004,0008) Normalization Point
+pub const NORMALIZATION_POINT: Tag = Tag(0x3004, 0x0008);
+/// (3004,000A) Dose Summation Type
+pub const DOSE_SUMMATION_TYPE: Tag = Tag(0x3004, 0x000A);
+/// (3004,000C) Grid Frame Offset Vector
+pub const GRID_FRAME_OFFSET_VECTOR: Tag = Tag(0x3004, 0x000C);
+/// (3004,000E) Dose Grid Scaling
+pub const DOSE_GRID_SCALING: Tag = Tag(0x3004, 0x000E);
+/// (3004,0010) RT Dose ROI Sequence
+pub const RT_DOSE_ROI_SEQUENCE: Tag = Tag(0x3004, 0x0010);
+/// (3004,0012) Dose Value
+pub const DOSE_VALUE: Tag = Tag(0x3004, 0x0012);
+/// (3004,0014) Tissue Heterogeneity Correction
+pub const TISSUE_HETEROGENEITY_CORRECTION: Tag = Tag(0x3004, 0x0014);
+/// (3004,0040) DVH Normalization Point
+pub const DVH_NORMALIZATION_POINT: Tag = Tag(0x3004, 0x0040);
+/// (3004,0042) DVH Normalization Dose Value
+pub const DVH_NORMALIZATION_DOSE_VALUE: Tag = Tag(0x3004, 0x0042);
+/// (3004,0050) DVH Sequence
+pub const DVH_SEQUENCE: Tag = Tag(0x3004, 0x0050);
+/// (3004,0052) DVH Dose Scaling
+pub const DVH_DOSE_SCALING: Tag = Tag(0x3004, 0x0052);
+/// (3004,0054) DVH Volume Units
+pub const DVH_VOLUME_UNITS: Tag = Tag(0x3004, 0x0054);
+/// (3004,0056) DVH Number of Bins
+pub const DVH_NUMBER_OF_BINS: Tag = Tag(0x3004, 0x0056);
+/// (3004,0058) DVH Data
+pub const DVH_DATA: Tag = Tag(0x3004, 0x0058);
+/// (3004,0060) DVH Referenced ROI Sequence
+pub const DVH_REFERENCED_ROI_SEQUENCE: Tag = Tag(0x3004, 0x0060);
+/// (3004,0062) DVHROI Contribution Type
+pub const DVHROI_CONTRIBUTION_TYPE: Tag = Tag(0x3004, 0x0062);
+/// (3004,0070) DVH Minimum Dose
+pub const DVH_MINIMUM_DOSE: Tag = Tag(0x3004, 0x0070);
+/// (3004,0072) DVH Maximum Dose
+pub const DVH_MAXIMUM_DOSE: Tag = Tag(0x3004, 0x0072);
+/// (3004,0074) DVH Mean Dose
+pub const DVH_MEAN_DOSE: Tag = Tag(0x3004, 0x0074);
+/// (3006,0002) Structure Set Label
+pub const STRUCTURE_SET_LABEL: Tag = Tag(0x3006, 0x0002);
+/// (3006,0004) Structure Set Name
+pub const STRUCTURE_SET_NAME: Tag = Tag(0x3006, 0x0004);
+/// (3006,0006) Structure Set Description
+pub const STRUCTURE_SET_DESCRIPTION: Tag = Tag(0x3006, 0x0006);
+/// (3006,0008) Structure Set Date
+pub const STRUCTURE_SET_DATE: Tag = Tag(0x3006, 0x0008);
+/// (3006,0009) Structure Set Time
+pub const STRUCTURE_SET_TIME: Tag = Tag(0x3006, 0x0009);
+/// (3006,0010) Referenced Frame of Reference Sequence
+pub const REFERENCED_FRAME_OF_REFERENCE_SEQUENCE: Tag = Tag(0x3006, 0x0010);
+/// (3006,0012) RT Referenced Study Sequence
+pub const RT_REFERENCED_STUDY_SEQUENCE: Tag = Tag(0x3006, 0x0012);
+/// (3006,0014) RT Referenced Series Sequence
+pub const RT_REFERENCED_SERIES_SEQUENCE: Tag = Tag(0x3006, 0x0014);
+/// (3006,0016) Contour Image Sequence
+pub const CONTOUR_IMAGE_SEQUENCE: Tag = Tag(0x3006, 0x0016);
+/// (3006,0018) Predecessor Structure Set Sequence
+pub const PREDECESSOR_STRUCTURE_SET_SEQUENCE: Tag = Tag(0x3006, 0x0018);
+/// (3006,0020) Structure Set ROI Sequence
+pub const STRUCTURE_SET_ROI_SEQUENCE: Tag = Tag(0x3006, 0x0020);
+/// (3006,0022) ROI Number
+pub const ROI_NUMBER: Tag = Tag(0x3006, 0x0022);
+/// (3006,0024) Referenced Frame of Reference UID
+pub const REFERENCED_FRAME_OF_REFERENCE_UID: Tag = Tag(0x3006, 0x0024);
+/// (3006,0026) ROI Name
+pub const ROI_NAME: Tag = Tag(0x3006, 0x0026);
+/// (3006,0028) ROI Description
+pub const ROI_DESCRIPTION: Tag = Tag(0x3006, 0x0028);
+/// (3006,002A) ROI Display Color
+pub const ROI_DISPLAY_COLOR: Tag = Tag(0x3006, 0x002A);
+/// (3006,002C) ROI Volume
+pub const ROI_VOLUME: Tag = Tag(0x3006, 0x002C);
+/// (3006,0030) RT Related ROI Sequence
+pub const RT_RELATED_ROI_SEQUENCE: Tag = Tag(0x3006, 0x0030);
+/// (3006,0033) RTROI Relationship
+pub const RTROI_RELATIONSHIP: Tag = Tag(0x3006, 0x0033);
+/// (3006,0036) ROI Generation Algorithm
+pub const ROI_GENERATION_ALGORITHM: Tag = Tag(0x3006, 0x0036);
+/// (3006,0037) ROI Derivation Algorithm Identification Sequence
+pub const ROI_DERIVATION_ALGORITHM_IDENTIFICATION_SEQUENCE: Tag = Tag(0x3006, 0x0037);
+/// (3006,0038) ROI Generation Description
+pub const ROI_GENERATION_DESCRIPTION: Tag = Tag(0x3006, 0x0038);
+/// (3006,0039) ROI Contour Sequence
+pub const ROI_CONTOUR_SEQUENCE: Tag = Tag(0x3006, 0x0039);
+/// (3006,0040) Contour Sequence
+pub const CONTOUR_SEQUENCE: Tag = Tag(0x3006, 0x0040);
+/// (3006,0042) Contour Geometric Type
+pub const CONTOUR_GEOMETRIC_TYPE: Tag = Tag(0x3006, 0x0042);
+/// (3006,0044) Contour Slab Thickness
+pub const CONTOUR_SLAB_THICKNESS: Tag = Tag(0x3006, 0x0044);
+/// (3006,0045) Contour Offset Vector
+pub const CONTOUR_OFFSET_VECTOR: Tag = Tag(0x3006, 0x0045);
+/// (3006,0046) Number of Contour Points
+pub const NUMBER_OF_CONTOUR_POINTS: Tag = Tag(0x3006, 0x0046);
+/// (3006,0048) Contour Number
+pub const CONTOUR_NUMBER: Tag = Tag(0x3006, 0x0048);
+/// (3006,0049) Attached Contours
+pub const ATTACHED_CONTOURS: Tag = Tag(0x3006, 0x0049);
+/// (3006,0050) Contour Data
+pub const CONTOUR_DATA: Tag = Tag(0x3006, 0x0050);
+/// (3006,0080) RTROI Observations Sequence
+pub const RTROI_OBSERVATIONS_SEQUENCE: Tag = Tag(0x3006, 0x0080);
+/// (3006,0082) Observation Number
+pub const OBSERVATION_NUMBER: Tag = Tag(0x3006, 0x0082);
+/// (3006,0084) Referenced ROI Number
+pub const REFERENCED_ROI_NUMBER: Tag = Tag(0x3006, 0x0084);
+/// (3006,0085) ROI Observation Label
+pub const ROI_OBSERVATION_LABEL: Tag = Tag(0x3006, 0x0085);
+/// (3006,0086) RTROI Identification Code Sequence
+pub const RTROI_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag(0x3006, 0x0086);
+/// (3006,0088) ROI Observation Description
+pub const ROI_OBSERVATION_DESCRIPTION: Tag = Tag(0x3006, 0x0088);
+/// (3006,00A0) Related RTROI Observations Sequence
+pub const RELATED_RTROI_OBSERVATIONS_SEQUENCE: Tag = Tag(0x3006, 0x00A0);
+/// (3006,00A4) RTROI Interpreted Type
+pub const RTROI_INTERPRETED_TYPE: Tag = Tag(0x3006, 0x00A4);
+/// (3006,00A6) ROI Interpreter
+pub const ROI_INTERPRETER: Tag = Tag(0x3006, 0x00A6);
+/// (3006,00B0) ROI Physical Properties Sequence
+pub const ROI_PHYSICAL_PROPERTIES_SEQUENCE: Tag = Tag(0x3006, 0x00B0);
+/// (3006,00B2) ROI Physical Property
+pub const ROI_PHYSICAL_PROPERTY: Tag = Tag(0x3006, 0x00B2);
+/// (3006,00B4) ROI Physical Property Value
+pub const ROI_PHYSICAL_PROPERTY_VALUE: Tag = Tag(0x3006, 0x00B4);
+/// (3006,00B6) ROI Elemental Composition Sequence
+pub const ROI_ELEMENTAL_COMPOSITION_SEQUENCE: Tag = Tag(0x3006, 0x00B6);
+/// (3006,00B7) ROI Elemental Composition Atomic Number
+pub const ROI_ELEMENTAL_COMPOSITION_ATOMIC_NUMBER: Tag = Tag(0x3006, 0x00B7);
+/// (3006,00B8) ROI Elemental Composition Atomic Mass Fraction
+pub const ROI_ELEMENTAL_COMPOSITION_ATOMIC_MASS_FRACTION: Tag = Tag(0x3006, 0x00B8);
+/// (3006,00B9) Additional RTROI Identification Code Sequence (retired)
+pub const ADDITIONAL_RTROI_IDENTIFICATION_CODE_SEQUENCE: Tag = Tag(0x3006, 0x00B9);
+/// (3006,00C0) Frame of Reference Relationship Sequence (retired)
+pub const FRAME_OF_REFERENCE_RELATIONSHIP_SEQUENCE: Tag = Tag(0x3006, 0x00C0);
+/// (3006,00C2) Related Frame of Reference UID (retired)
+pub const RELATED_FRAME_OF_REFERENCE_UID: Tag = Tag(0x3006, 0x00C2);
+/// (3006,00C4) Frame of Reference Transformation Type (retired)
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_TYPE: Tag = Tag(0x3006, 0x00C4);
+/// (3006,00C6) Frame of Reference Transformation Matrix
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_MATRIX: Tag = Tag(0x3006, 0x00C6);
+/// (3006,00C8) Frame of Reference Transformation Comment
+pub const FRAME_OF_REFERENCE_TRANSFORMATION_COMMENT: Tag = Tag(0x3006, 0x00C8);
+/// (3008,0010) Measured Dose Reference Sequence
+pub const MEASURED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x3008, 0x0010);
+/// (3008,0012) Measured Dose Description
+pub const MEASURED_DOSE_DESCRIPTION: Tag = Tag(0x3008, 0x0012);
+/// (3008,0014) Measured Dose Type
+pub const MEASURED_DOSE_TYPE: Tag = Tag(0x3008, 0x0014);
+/// (3008,0016) Measured Dose Value
+pub const MEASURED_DOSE_VALUE: Tag = Tag(0x3008, 0x0016);
+/// (3008,0020) Treatment Session Beam Sequence
+pub const TREATMENT_SESSION_BEAM_SEQUENCE: Tag = Tag(0x3008, 0x0020);
+/// (3008,0021) Treatment Session Ion Beam Sequence
+pub const TREATMENT_SESSION_ION_BEAM_SEQUENCE: Tag = Tag(0x3008, 0x0021);
+/// (3008,0022) Current Fraction Number
+pub const CURRENT_FRACTION_NUMBER: Tag = Tag(0x3008, 0x0022);
+/// (3008,0024) Treatment Control Point Date
+pub const TREATMENT_CONTROL_POINT_DATE: Tag = Tag(0x3008, 0x0024);
+/// (3008,0025) Treatment Control Point Time
+pub const TREATMENT_CONTROL_POINT_TIME: Tag = Tag(0x3008, 0x0025);
+/// (3008,002A) Treatment Termination Status
+pub const TREATMENT_TERMINATION_STATUS: Tag = Tag(0x3008, 0x002A);
+/// (3008,002B) Treatment Termination Code
+pub const TREATMENT_TERMINATION_CODE: Tag = Tag(0x3008, 0x002B);
+/// (3008,002C) Treatment Verification Status
+pub const TREATMENT_VERIFICATION_STATUS: Tag = Tag(0x3008, 0x002C);
+/// (3008,0030) Referenced Treatment Record Sequence
+pub const REFERENCED_TREATMENT_RECORD_SEQUENCE: Tag = Tag(0x3008, 0x0030);
+/// (3008,0032) Specified Primary Meterset
+pub const SPECIFIED_PRIMARY_METERSET: Tag = Tag(0x3008, 0x0032);
+/// (3008,0033) Specified Secondary Meterset
+pub const SPECIFIED_SECONDARY_METERSET: Tag = Tag(0x3008, 0x0033);
+/// (3008,0036) Delivered Primary Meterset
+pub const DELIVERED_PRIMARY_METERSET: Tag = Tag(0x3008, 0x0036);
+/// (3008,0037) Delivered Secondary Meterset
+pub const DELIVERED_SECONDARY_METERSET: Tag = Tag(0x3008, 0x0037);
+/// (3008,003A) Specified Treatment Time
+pub const SPECIFIED_TREATMENT_TIME: Tag = Tag(0x3008, 0x003A);
+/// (3008,003B) Delivered Treatment Time
+pub const DELIVERED_TREATMENT_TIME: Tag = Tag(0x3008, 0x003B);
+/// (3008,0040) Control Point Delivery Sequence
+pub const CONTROL_POINT_DELIVERY_SEQUENCE: Tag = Tag(0x3008, 0x0040);
+/// (3008,0041) Ion Control Point Delivery Sequence
+pub const ION_CONTROL_POINT_DELIVERY_SEQUENCE: Tag = Tag(0x3008, 0x0041);
+/// (3008,0042) Specified Meterset
+pub const SPECIFIED_METERSET: Tag = Tag(0x3008, 0x0042);
+/// (3008,0044) Delivered Meterset
+pub const DELIVERED_METERSET: Tag = Tag(0x3008, 0x0044);
+/// (3008,0045) Meterset Rate Set
+pub const METERSET_RATE_SET: Tag = Tag(0x3008, 0x0045);
+/// (3008,0046) Meterset Rate Delivered
+pub const METERSET_RATE_DELIVERED: Tag = Tag(0x3008, 0x0046);
+/// (3008,0047) Scan Spot Metersets Delivered
+pub const SCAN_SPOT_METERSETS_DELIVERED: Tag = Tag(0x3008, 0x0047);
+/// (3008,0048) Dose Rate Delivered
+pub const DOSE_RATE_DELIVERED: Tag = Tag(0x3008, 0x0048);
+/// (3008,0050) Treatment Summary Calculated Dose Reference Sequence
+pub const TREATMENT_SUMMARY_CALCULATED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x3008, 0x0050);
+/// (3008,0052) Cumulative Dose to Dose Reference
+pub const CUMULATIVE_DOSE_TO_DOSE_REFERENCE: Tag = Tag(0x3008, 0x0052);
+/// (3008,0054) First Treatment Date
+pub const FIRST_TREATMENT_DATE: Tag = Tag(0x3008, 0x0054);
+/// (3008,0056) Most Recent Treatment Date
+pub const MOST_RECENT_TREATMENT_DATE: Tag = Tag(0x3008, 0x0056);
+/// (3008,005A) Number of Fractions Delivered
+pub const NUMBER_OF_FRACTIONS_DELIVERED: Tag = Tag(0x3008, 0x005A);
+/// (3008,0060) Override Sequence
+pub const OVERRIDE_SEQUENCE: Tag = Tag(0x3008, 0x0060);
+/// (3008,0061) Parameter Sequence Pointer
+pub const PARAMETER_SEQUENCE_POINTER: Tag = Tag(0x3008, 0x0061);
+/// (3008,0062) Override Parameter Pointer
+pub const OVERRIDE_PARAMETER_POINTER: Tag = Tag(0x3008, 0x0062);
+/// (3008,0063) Parameter Item Index
+pub const PARAMETER_ITEM_INDEX: Tag = Tag(0x3008, 0x0063);
+/// (3008,0064) Measured Dose Reference Number
+pub const MEASURED_DOSE_REFERENCE_NUMBER: Tag = Tag(0x3008, 0x0064);
+/// (3008,0065) Parameter Pointer
+pub const PARAMETER_POINTER: Tag = Tag(0x3008, 0x0065);
+/// (3008,0066) Override Reason
+pub const OVERRIDE_REASON: Tag = Tag(0x3008, 0x0066);
+/// (3008,0067) Parameter Value Number
+pub const PARAMETER_VALUE_NUMBER: Tag = Tag(0x3008, 0x0067);
+/// (3008,0068) Corrected Parameter Sequence
+pub const CORRECTED_PARAMETER_SEQUENCE: Tag = Tag(0x3008, 0x0068);
+/// (3008,006A) Correction Value
+pub const CORRECTION_VALUE: Tag = Tag(0x3008, 0x006A);
+/// (3008,0070) Calculated Dose Reference Sequence
+pub const CALCULATED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x3008, 0x0070);
+/// (3008,0072) Calculated Dose Reference Number
+pub const CALCULATED_DOSE_REFERENCE_NUMBER: Tag = Tag(0x3008, 0x0072);
+/// (3008,0074) Calculated Dose Reference Description
+pub const CALCULATED_DOSE_REFERENCE_DESCRIPTION: Tag = Tag(0x3008, 0x0074);
+/// (3008,0076) Calculated Dose Reference Dose Value
+pub const CALCULATED_DOSE_REFERENCE_DOSE_VALUE: Tag = Tag(0x3008, 0x0076);
+/// (3008,0078) Start Meterset
+pub const START_METERSET: Tag = Tag(0x3008, 0x0078);
+/// (3008,007A) End Meterset
+pub const END_METERSET: Tag = Tag(0x3008, 0x007A);
+/// (3008,0080) Referenced Measured Dose Reference Sequence
+pub const REFERENCED_MEASURED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x3008, 0x0080);
+/// (3008,0082) Referenced Measured Dose Reference Number
+pub const REFERENCED_MEASURED_DOSE_REFERENCE_NUMBER: Tag = Tag(0x3008, 0x0082);
+/// (3008,0090) Referenced Calculated Dose Reference Sequence
+pub const REFERENCED_CALCULATED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x3008, 0x0090);
+/// (3008,0092) Referenced Calculated Dose Reference Number
+pub const REFERENCED_CALCULATED_DOSE_REFERENCE_NUMBER: Tag = Tag(0x3008, 0x0092);
+/// (3008,00A0) Beam Limiting Device Leaf Pairs Sequence
+pub const BEAM_LIMITING_DEVICE_LEAF_PAIRS_SEQUENCE: Tag = Tag(0x3008, 0x00A0);
+/// (3008,00B0) Recorded Wedge Sequence
+pub const RECORDED_WEDGE_SEQUENCE: Tag = Tag(0x3008, 0x00B0);
+/// (3008,00C0) Recorded Compensator Sequence
+pub const RECORDED_COMPENSATOR_SEQUENCE: Tag = Tag(0x3008, 0x00C0);
+/// (3008,00D0) Recorded Block Sequence
+pub const RECORDED_BLOCK_SEQUENCE: Tag = Tag(0x3008, 0x00D0);
+/// (3008,00E0) Treatment Summary Measured Dose Reference Sequence
+pub const TREATMENT_SUMMARY_MEASURED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x3008, 0x00E0);
+/// (3008,00F0) Recorded Snout Sequence
+pub const RECORDED_SNOUT_SEQUENCE: Tag = Tag(0x3008, 0x00F0);
+/// (3008,00F2) Recorded Range Shifter Sequence
+pub const RECORDED_RANGE_SHIFTER_SEQUENCE: Tag = Tag(0x3008, 0x00F2);
+/// (3008,00F4) Recorded Lateral Spreading Device Sequence
+pub const RECORDED_LATERAL_SPREADING_DEVICE_SEQUENCE: Tag = Tag(0x3008, 0x00F4);
+/// (3008,00F6) Recorded Range Modulator Sequence
+pub const RECORDED_RANGE_MODULATOR_SEQUENCE: Tag = Tag(0x3008, 0x00F6);
+/// (3008,0100) Recorded Source Sequence
+pub const RECORDED_SOURCE_SEQUENCE: Tag = Tag(0x3008, 0x0100);
+/// (3008,0105) Source Serial Number
+pub const SOURCE_SERIAL_NUMBER: Tag = Tag(0x3008, 0x0105);
+/// (3008,0110) Treatment Session Application Setup Sequence
+pub const TREATMENT_SESSION_APPLICATION_SETUP_SEQUENCE: Tag = Tag(0x3008, 0x0110);
+/// (3008,0116) Application Setup Check
+pub const APPLICATION_SETUP_CHECK: Tag = Tag(0x3008, 0x0116);
+/// (3008,0120) Recorded Brachy Accessory Device Sequence
+pub const RECORDED_BRACHY_ACCESSORY_DEVICE_SEQUENCE: Tag = Tag(0x3008, 0x0120);
+/// (3008,0122) Referenced Brachy Accessory Device Number
+pub const REFERENCED_BRACHY_ACCESSORY_DEVICE_NUMBER: Tag = Tag(0x3008, 0x0122);
+/// (3008,0130) Recorded Channel Sequence
+pub const RECORDED_CHANNEL_SEQUENCE: Tag = Tag(0x3008, 0x0130);
+/// (3008,0132) Specified Channel Total Time
+pub const SPECIFIED_CHANNEL_TOTAL_TIME: Tag = Tag(0x3008, 0x0132);
+/// (3008,0134) Delivered Channel Total Time
+pub const DELIVERED_CHANNEL_TOTAL_TIME: Tag = Tag(0x3008, 0x0134);
+/// (3008,0136) Specified Number of Pulses
+pub const SPECIFIED_NUMBER_OF_PULSES: Tag = Tag(0x3008, 0x0136);
+/// (3008,0138) Delivered Number of Pulses
+pub const DELIVERED_NUMBER_OF_PULSES: Tag = Tag(0x3008, 0x0138);
+/// (3008,013A) Specified Pulse Repetition Interval
+pub const SPECIFIED_PULSE_REPETITION_INTERVAL: Tag = Tag(0x3008, 0x013A);
+/// (3008,013C) Delivered Pulse Repetition Interval
+pub const DELIVERED_PULSE_REPETITION_INTERVAL: Tag = Tag(0x3008, 0x013C);
+/// (3008,0140) Recorded Source Applicator Sequence
+pub const RECORDED_SOURCE_APPLICATOR_SEQUENCE: Tag = Tag(0x3008, 0x0140);
+/// (3008,0142) Referenced Source Applicator Number
+pub const REFERENCED_SOURCE_APPLICATOR_NUMBER: Tag = Tag(0x3008, 0x0142);
+/// (3008,0150) Recorded Channel Shield Sequence
+pub const RECORDED_CHANNEL_SHIELD_SEQUENCE: Tag = Tag(0x3008, 0x0150);
+/// (3008,0152) Referenced Channel Shield Number
+pub const REFERENCED_CHANNEL_SHIELD_NUMBER: Tag = Tag(0x3008, 0x0152);
+/// (3008,0160) Brachy Control Point Delivered Sequence
+pub const BRACHY_CONTROL_POINT_DELIVERED_SEQUENCE: Tag = Tag(0x3008, 0x0160);
+/// (3008,0162) Safe Position Exit Date
+pub const SAFE_POSITION_EXIT_DATE: Tag = Tag(0x3008, 0x0162);
+/// (3008,0164) Safe Position Exit Time
+pub const SAFE_POSITION_EXIT_TIME: Tag = Tag(0x3008, 0x0164);
+/// (3008,0166) Safe Position Return Date
+pub const SAFE_POSITION_RETURN_DATE: Tag = Tag(0x3008, 0x0166);
+/// (3008,0168) Safe Position Return Time
+pub const SAFE_POSITION_RETURN_TIME: Tag = Tag(0x3008, 0x0168);
+/// (3008,0171) Pulse Specific Brachy Control Point Delivered Sequence
+pub const PULSE_SPECIFIC_BRACHY_CONTROL_POINT_DELIVERED_SEQUENCE: Tag = Tag(0x3008, 0x0171);
+/// (3008,0172) Pulse Number
+pub const PULSE_NUMBER: Tag = Tag(0x3008, 0x0172);
+/// (3008,0173) Brachy Pulse Control Point Delivered Sequence
+pub const BRACHY_PULSE_CONTROL_POINT_DELIVERED_SEQUENCE: Tag = Tag(0x3008, 0x0173);
+/// (3008,0200) Current Treatment Status
+pub const CURRENT_TREATMENT_STATUS: Tag = Tag(0x3008, 0x0200);
+/// (3008,0202) Treatment Status Comment
+pub const TREATMENT_STATUS_COMMENT: Tag = Tag(0x3008, 0x0202);
+/// (3008,0220) Fraction Group Summary Sequence
+pub const FRACTION_GROUP_SUMMARY_SEQUENCE: Tag = Tag(0x3008, 0x0220);
+/// (3008,0223) Referenced Fraction Number
+pub const REFERENCED_FRACTION_NUMBER: Tag = Tag(0x3008, 0x0223);
+/// (3008,0224) Fraction Group Type
+pub const FRACTION_GROUP_TYPE: Tag = Tag(0x3008, 0x0224);
+/// (3008,0230) Beam Stopper Position
+pub const BEAM_STOPPER_POSITION: Tag = Tag(0x3008, 0x0230);
+/// (3008,0240) Fraction Status Summary Sequence
+pub const FRACTION_STATUS_SUMMARY_SEQUENCE: Tag = Tag(0x3008, 0x0240);
+/// (3008,0250) Treatment Date
+pub const TREATMENT_DATE: Tag = Tag(0x3008, 0x0250);
+/// (3008,0251) Treatment Time
+pub const TREATMENT_TIME: Tag = Tag(0x3008, 0x0251);
+/// (300A,0002) RT Plan Label
+pub const RT_PLAN_LABEL: Tag = Tag(0x300A, 0x0002);
+/// (300A,0003) RT Plan Name
+pub const RT_PLAN_NAME: Tag = Tag(0x300A, 0x0003);
+/// (300A,0004) RT Plan Description
+pub const RT_PLAN_DESCRIPTION: Tag = Tag(0x300A, 0x0004);
+/// (300A,0006) RT Plan Date
+pub const RT_PLAN_DATE: Tag = Tag(0x300A, 0x0006);
+/// (300A,0007) RT Plan Time
+pub const RT_PLAN_TIME: Tag = Tag(0x300A, 0x0007);
+/// (300A,0009) Treatment Protocols
+pub const TREATMENT_PROTOCOLS: Tag = Tag(0x300A, 0x0009);
+/// (300A,000A) Plan Intent
+pub const PLAN_INTENT: Tag = Tag(0x300A, 0x000A);
+/// (300A,000B) Treatment Sites
+pub const TREATMENT_SITES: Tag = Tag(0x300A, 0x000B);
+/// (300A,000C) RT Plan Geometry
+pub const RT_PLAN_GEOMETRY: Tag = Tag(0x300A, 0x000C);
+/// (300A,000E) Prescription Description
+pub const PRESCRIPTION_DESCRIPTION: Tag = Tag(0x300A, 0x000E);
+/// (300A,0010) Dose Reference Sequence
+pub const DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x300A, 0x0010);
+/// (300A,0012) Dose Reference Number
+pub const DOSE_REFERENCE_NUMBER: Tag = Tag(0x300A, 0x0012);
+/// (300A,0013) Dose Reference UID
+pub const DOSE_REFERENCE_UID: Tag = Tag(0x300A, 0x0013);
+/// (300A,0014) Dose Reference Structure Type
+pub const DOSE_REFERENCE_STRUCTURE_TYPE: Tag = Tag(0x300A, 0x0014);
+/// (300A,0015) Nominal Beam Energy Unit
+pub const NOMINAL_BEAM_ENERGY_UNIT: Tag = Tag(0x300A, 0x0015);
+/// (300A,0016) Dose Reference Description
+pub const DOSE_REFERENCE_DESCRIPTION: Tag = Tag(0x300A, 0x0016);
+/// (300A,0018) Dose Reference Point Coordinates
+pub const DOSE_REFERENCE_POINT_COORDINATES: Tag = Tag(0x300A, 0x0018);
+/// (300A,001A) Nominal Prior Dose
+pub const NOMINAL_PRIOR_DOSE: Tag = Tag(0x300A, 0x001A);
+/// (300A,0020) Dose Reference Type
+pub const DOSE_REFERENCE_TYPE: Tag = Tag(0x300A, 0x0020);
+/// (300A,0021) Constraint Weight
+pub const CONSTRAINT_WEIGHT: Tag = Tag(0x300A, 0x0021);
+/// (300A,0022) Delivery Warning Dose
+pub const DELIVERY_WARNING_DOSE: Tag = Tag(0x300A, 0x0022);
+/// (300A,0023) Delivery Maximum Dose
+pub const DELIVERY_MAXIMUM_DOSE: Tag = Tag(0x300A, 0x0023);
+/// (300A,0025) Target Minimum Dose
+pub const TARGET_MINIMUM_DOSE: Tag = Tag(0x300A, 0x0025);
+/// (300A,0026) Target Prescription Dose
+pub const TARGET_PRESCRIPTION_DOSE: Tag = Tag(0x300A, 0x0026);
+/// (300A,0027) Target Maximum Dose
+pub const TARGET_MAXIMUM_DOSE: Tag = Tag(0x300A, 0x0027);
+/// (300A,0028) Target Underdose Volume Fraction
+pub const TARGET_UNDERDOSE_VOLUME_FRACTION: Tag = Tag(0x300A, 0x0028);
+/// (300A,002A) Organ at Risk Full Volume Dose
+pub const ORGAN_AT_RISK_FULL_VOLUME_DOSE: Tag = Tag(0x300A, 0x002A);
+/// (300A,002B) Organ at Risk Limit Dose
+pub const ORGAN_AT_RISK_LIMIT_DOSE: Tag = Tag(0x300A, 0x002B);
+/// (300A,002C) Organ at Risk Maximum Dose
+pub const ORGAN_AT_RISK_MAXIMUM_DOSE: Tag = Tag(0x300A, 0x002C);
+/// (300A,002D) Organ at Risk Overdose Volume Fraction
+pub const ORGAN_AT_RISK_OVERDOSE_VOLUME_FRACTION: Tag = Tag(0x300A, 0x002D);
+/// (300A,0040) Tolerance Table Sequence
+pub const TOLERANCE_TABLE_SEQUENCE: Tag = Tag(0x300A, 0x0040);
+/// (300A,0042) Tolerance Table Number
+pub const TOLERANCE_TABLE_NUMBER: Tag = Tag(0x300A, 0x0042);
+/// (300A,0043) Tolerance Table Label
+pub const TOLERANCE_TABLE_LABEL: Tag = Tag(0x300A, 0x0043);
+/// (300A,0044) Gantry Angle Tolerance
+pub const GANTRY_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x0044);
+/// (300A,0046) Beam Limiting Device Angle Tolerance
+pub const BEAM_LIMITING_DEVICE_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x0046);
+/// (300A,0048) Beam Limiting Device Tolerance Sequence
+pub const BEAM_LIMITING_DEVICE_TOLERANCE_SEQUENCE: Tag = Tag(0x300A, 0x0048);
+/// (300A,004A) Beam Limiting Device Position Tolerance
+pub const BEAM_LIMITING_DEVICE_POSITION_TOLERANCE: Tag = Tag(0x300A, 0x004A);
+/// (300A,004B) Snout Position Tolerance
+pub const SNOUT_POSITION_TOLERANCE: Tag = Tag(0x300A, 0x004B);
+/// (300A,004C) Patient Support Angle Tolerance
+pub const PATIENT_SUPPORT_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x004C);
+/// (300A,004E) Table Top Eccentric Angle Tolerance
+pub const TABLE_TOP_ECCENTRIC_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x004E);
+/// (300A,004F) Table Top Pitch Angle Tolerance
+pub const TABLE_TOP_PITCH_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x004F);
+/// (300A,0050) Table Top Roll Angle Tolerance
+pub const TABLE_TOP_ROLL_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x0050);
+/// (300A,0051) Table Top Vertical Position Tolerance
+pub const TABLE_TOP_VERTICAL_POSITION_TOLERANCE: Tag = Tag(0x300A, 0x0051);
+/// (300A,0052) Table Top Longitudinal Position Tolerance
+pub const TABLE_TOP_LONGITUDINAL_POSITION_TOLERANCE: Tag = Tag(0x300A, 0x0052);
+/// (300A,0053) Table Top Lateral Position Tolerance
+pub const TABLE_TOP_LATERAL_POSITION_TOLERANCE: Tag = Tag(0x300A, 0x0053);
+/// (300A,0055) RT Plan Relationship
+pub const RT_PLAN_RELATIONSHIP: Tag = Tag(0x300A, 0x0055);
+/// (300A,0070) Fraction Group Sequence
+pub const FRACTION_GROUP_SEQUENCE: Tag = Tag(0x300A, 0x0070);
+/// (300A,0071) Fraction Group Number
+pub const FRACTION_GROUP_NUMBER: Tag = Tag(0x300A, 0x0071);
+/// (300A,0072) Fraction Group Description
+pub const FRACTION_GROUP_DESCRIPTION: Tag = Tag(0x300A, 0x0072);
+/// (300A,0078) Number of Fractions Planned
+pub const NUMBER_OF_FRACTIONS_PLANNED: Tag = Tag(0x300A, 0x0078);
+/// (300A,0079) Number of Fraction Pattern Digits per Day
+pub const NUMBER_OF_FRACTION_PATTERN_DIGITS_PER_DAY: Tag = Tag(0x300A, 0x0079);
+/// (300A,007A) Repeat Fraction Cycle Length
+pub const REPEAT_FRACTION_CYCLE_LENGTH: Tag = Tag(0x300A, 0x007A);
+/// (300A,007B) Fraction Pattern
+pub const FRACTION_PATTERN: Tag = Tag(0x300A, 0x007B);
+/// (300A,0080) Number of Beams
+pub const NUMBER_OF_BEAMS: Tag = Tag(0x300A, 0x0080);
+/// (300A,0082) Beam Dose Specification Point
+pub const BEAM_DOSE_SPECIFICATION_POINT: Tag = Tag(0x300A, 0x0082);
+/// (300A,0083) Referenced Dose Reference UID
+pub const REFERENCED_DOSE_REFERENCE_UID: Tag = Tag(0x300A, 0x0083);
+/// (300A,0084) Beam Dose
+pub const BEAM_DOSE: Tag = Tag(0x300A, 0x0084);
+/// (300A,0086) Beam Meterset
+pub const BEAM_METERSET: Tag = Tag(0x300A, 0x0086);
+/// (300A,0088) Beam Dose Point Depth
+pub const BEAM_DOSE_POINT_DEPTH: Tag = Tag(0x300A, 0x0088);
+/// (300A,0089) Beam Dose Point Equivalent Depth
+pub const BEAM_DOSE_POINT_EQUIVALENT_DEPTH: Tag = Tag(0x300A, 0x0089);
+/// (300A,008A) Beam Dose Point SSD
+pub const BEAM_DOSE_POINT_SSD: Tag = Tag(0x300A, 0x008A);
+/// (300A,008B) Beam Dose Meaning
+pub const BEAM_DOSE_MEANING: Tag = Tag(0x300A, 0x008B);
+/// (300A,008C) Beam Dose Verification Control Point Sequence
+pub const BEAM_DOSE_VERIFICATION_CONTROL_POINT_SEQUENCE: Tag = Tag(0x300A, 0x008C);
+/// (300A,008D) Average Beam Dose Point Depth (retired)
+pub const AVERAGE_BEAM_DOSE_POINT_DEPTH: Tag = Tag(0x300A, 0x008D);
+/// (300A,008E) Average Beam Dose Point Equivalent Depth (retired)
+pub const AVERAGE_BEAM_DOSE_POINT_EQUIVALENT_DEPTH: Tag = Tag(0x300A, 0x008E);
+/// (300A,008F) Average Beam Dose Point SSD (retired)
+pub const AVERAGE_BEAM_DOSE_POINT_SSD: Tag = Tag(0x300A, 0x008F);
+/// (300A,0090) Beam Dose Type
+pub const BEAM_DOSE_TYPE: Tag = Tag(0x300A, 0x0090);
+/// (300A,0091) Alternate Beam Dose
+pub const ALTERNATE_BEAM_DOSE: Tag = Tag(0x300A, 0x0091);
+/// (300A,0092) Alternate Beam Dose Type
+pub const ALTERNATE_BEAM_DOSE_TYPE: Tag = Tag(0x300A, 0x0092);
+/// (300A,0093) Depth Value Averaging Flag
+pub const DEPTH_VALUE_AVERAGING_FLAG: Tag = Tag(0x300A, 0x0093);
+/// (300A,0094) Beam Dose Point Source to External Contour Distance
+pub const BEAM_DOSE_POINT_SOURCE_TO_EXTERNAL_CONTOUR_DISTANCE: Tag = Tag(0x300A, 0x0094);
+/// (300A,00A0) Number of Brachy Application Setups
+pub const NUMBER_OF_BRACHY_APPLICATION_SETUPS: Tag = Tag(0x300A, 0x00A0);
+/// (300A,00A2) Brachy Application Setup Dose Specification Point
+pub const BRACHY_APPLICATION_SETUP_DOSE_SPECIFICATION_POINT: Tag = Tag(0x300A, 0x00A2);
+/// (300A,00A4) Brachy Application Setup Dose
+pub const BRACHY_APPLICATION_SETUP_DOSE: Tag = Tag(0x300A, 0x00A4);
+/// (300A,00B0) Beam Sequence
+pub const BEAM_SEQUENCE: Tag = Tag(0x300A, 0x00B0);
+/// (300A,00B2) Treatment Machine Name
+pub const TREATMENT_MACHINE_NAME: Tag = Tag(0x300A, 0x00B2);
+/// (300A,00B3) Primary Dosimeter Unit
+pub const PRIMARY_DOSIMETER_UNIT: Tag = Tag(0x300A, 0x00B3);
+/// (300A,00B4) Source Axis Distance
+pub const SOURCE_AXIS_DISTANCE: Tag = Tag(0x300A, 0x00B4);
+/// (300A,00B6) Beam Limiting Device Sequence
+pub const BEAM_LIMITING_DEVICE_SEQUENCE: Tag = Tag(0x300A, 0x00B6);
+/// (300A,00B8) RT Beam Limiting Device Type
+pub const RT_BEAM_LIMITING_DEVICE_TYPE: Tag = Tag(0x300A, 0x00B8);
+/// (300A,00BA) Source to Beam Limiting Device Distance
+pub const SOURCE_TO_BEAM_LIMITING_DEVICE_DISTANCE: Tag = Tag(0x300A, 0x00BA);
+/// (300A,00BB) Isocenter to Beam Limiting Device Distance
+pub const ISOCENTER_TO_BEAM_LIMITING_DEVICE_DISTANCE: Tag = Tag(0x300A, 0x00BB);
+/// (300A,00BC) Number of Leaf Jaw Pairs
+pub const NUMBER_OF_LEAF_JAW_PAIRS: Tag = Tag(0x300A, 0x00BC);
+/// (300A,00BE) Leaf Position Boundaries
+pub const LEAF_POSITION_BOUNDARIES: Tag = Tag(0x300A, 0x00BE);
+/// (300A,00C0) Beam Number
+pub const BEAM_NUMBER: Tag = Tag(0x300A, 0x00C0);
+/// (300A,00C2) Beam Name
+pub const BEAM_NAME: Tag = Tag(0x300A, 0x00C2);
+/// (300A,00C3) Beam Description
+pub const BEAM_DESCRIPTION: Tag = Tag(0x300A, 0x00C3);
+/// (300A,00C4) Beam Type
+pub const BEAM_TYPE: Tag = Tag(0x300A, 0x00C4);
+/// (300A,00C5) Beam Delivery Duration Limit
+pub const BEAM_DELIVERY_DURATION_LIMIT: Tag = Tag(0x300A, 0x00C5);
+/// (300A,00C6) Radiation Type
+pub const RADIATION_TYPE: Tag = Tag(0x300A, 0x00C6);
+/// (300A,00C7) High Dose Technique Type
+pub const HIGH_DOSE_TECHNIQUE_TYPE: Tag = Tag(0x300A, 0x00C7);
+/// (300A,00C8) Reference Image Number
+pub const REFERENCE_IMAGE_NUMBER: Tag = Tag(0x300A, 0x00C8);
+/// (300A,00CA) Planned Verification Image Sequence
+pub const PLANNED_VERIFICATION_IMAGE_SEQUENCE: Tag = Tag(0x300A, 0x00CA);
+/// (300A,00CC) Imaging Device Specific Acquisition Parameters
+pub const IMAGING_DEVICE_SPECIFIC_ACQUISITION_PARAMETERS: Tag = Tag(0x300A, 0x00CC);
+/// (300A,00CE) Treatment Delivery Type
+pub const TREATMENT_DELIVERY_TYPE: Tag = Tag(0x300A, 0x00CE);
+/// (300A,00D0) Number of Wedges
+pub const NUMBER_OF_WEDGES: Tag = Tag(0x300A, 0x00D0);
+/// (300A,00D1) Wedge Sequence
+pub const WEDGE_SEQUENCE: Tag = Tag(0x300A, 0x00D1);
+/// (300A,00D2) Wedge Number
+pub const WEDGE_NUMBER: Tag = Tag(0x300A, 0x00D2);
+/// (300A,00D3) Wedge Type
+pub const WEDGE_TYPE: Tag = Tag(0x300A, 0x00D3);
+/// (300A,00D4) Wedge ID
+pub const WEDGE_ID: Tag = Tag(0x300A, 0x00D4);
+/// (300A,00D5) Wedge Angle
+pub const WEDGE_ANGLE: Tag = Tag(0x300A, 0x00D5);
+/// (300A,00D6) Wedge Factor
+pub const WEDGE_FACTOR: Tag = Tag(0x300A, 0x00D6);
+/// (300A,00D7) Total Wedge Tray Water Equivalent Thickness
+pub const TOTAL_WEDGE_TRAY_WATER_EQUIVALENT_THICKNESS: Tag = Tag(0x300A, 0x00D7);
+/// (300A,00D8) Wedge Orientation
+pub const WEDGE_ORIENTATION: Tag = Tag(0x300A, 0x00D8);
+/// (300A,00D9) Isocenter to Wedge Tray Distance
+pub const ISOCENTER_TO_WEDGE_TRAY_DISTANCE: Tag = Tag(0x300A, 0x00D9);
+/// (300A,00DA) Source to Wedge Tray Distance
+pub const SOURCE_TO_WEDGE_TRAY_DISTANCE: Tag = Tag(0x300A, 0x00DA);
+/// (300A,00DB) Wedge Thin Edge Position
+pub const WEDGE_THIN_EDGE_POSITION: Tag = Tag(0x300A, 0x00DB);
+/// (300A,00DC) Bolus ID
+pub const BOLUS_ID: Tag = Tag(0x300A, 0x00DC);
+/// (300A,00DD) Bolus Description
+pub const BOLUS_DESCRIPTION: Tag = Tag(0x300A, 0x00DD);
+/// (300A,00DE) Effective Wedge Angle
+pub const EFFECTIVE_WEDGE_ANGLE: Tag = Tag(0x300A, 0x00DE);
+/// (300A,00E0) Number of Compensators
+pub const NUMBER_OF_COMPENSATORS: Tag = Tag(0x300A, 0x00E0);
+/// (300A,00E1) Material ID
+pub const MATERIAL_ID: Tag = Tag(0x300A, 0x00E1);
+/// (300A,00E2) Total Compensator Tray Factor
+pub const TOTAL_COMPENSATOR_TRAY_FACTOR: Tag = Tag(0x300A, 0x00E2);
+/// (300A,00E3) Compensator Sequence
+pub const COMPENSATOR_SEQUENCE: Tag = Tag(0x300A, 0x00E3);
+/// (300A,00E4) Compensator Number
+pub const COMPENSATOR_NUMBER: Tag = Tag(0x300A, 0x00E4);
+/// (300A,00E5) Compensator ID
+pub const COMPENSATOR_ID: Tag = Tag(0x300A, 0x00E5);
+/// (300A,00E6) Source to Compensator Tray Distance
+pub const SOURCE_TO_COMPENSATOR_TRAY_DISTANCE: Tag = Tag(0x300A, 0x00E6);
+/// (300A,00E7) Compensator Rows
+pub const COMPENSATOR_ROWS: Tag = Tag(0x300A, 0x00E7);
+/// (300A,00E8) Compensator Columns
+pub const COMPENSATOR_COLUMNS: Tag = Tag(0x300A, 0x00E8);
+/// (300A,00E9) Compensator Pixel Spacing
+pub const COMPENSATOR_PIXEL_SPACING: Tag = Tag(0x300A, 0x00E9);
+/// (300A,00EA) Compensator Position
+pub const COMPENSATOR_POSITION: Tag = Tag(0x300A, 0x00EA);
+/// (300A,00EB) Compensator Transmission Data
+pub const COMPENSATOR_TRANSMISSION_DATA: Tag = Tag(0x300A, 0x00EB);
+/// (300A,00EC) Compensator Thickness Data
+pub const COMPENSATOR_THICKNESS_DATA: Tag = Tag(0x300A, 0x00EC);
+/// (300A,00ED) Number of Boli
+pub const NUMBER_OF_BOLI: Tag = Tag(0x300A, 0x00ED);
+/// (300A,00EE) Compensator Type
+pub const COMPENSATOR_TYPE: Tag = Tag(0x300A, 0x00EE);
+/// (300A,00EF) Compensator Tray ID
+pub const COMPENSATOR_TRAY_ID: Tag = Tag(0x300A, 0x00EF);
+/// (300A,00F0) Number of Blocks
+pub const NUMBER_OF_BLOCKS: Tag = Tag(0x300A, 0x00F0);
+/// (300A,00F2) Total Block Tray Factor
+pub const TOTAL_BLOCK_TRAY_FACTOR: Tag = Tag(0x300A, 0x00F2);
+/// (300A,00F3) Total Block Tray Water Equivalent Thickness
+pub const TOTAL_BLOCK_TRAY_WATER_EQUIVALENT_THICKNESS: Tag = Tag(0x300A, 0x00F3);
+/// (300A,00F4) Block Sequence
+pub const BLOCK_SEQUENCE: Tag = Tag(0x300A, 0x00F4);
+/// (300A,00F5) Block Tray ID
+pub const BLOCK_TRAY_ID: Tag = Tag(0x300A, 0x00F5);
+/// (300A,00F6) Source to Block Tray Distance
+pub const SOURCE_TO_BLOCK_TRAY_DISTANCE: Tag = Tag(0x300A, 0x00F6);
+/// (300A,00F7) Isocenter to Block Tray Distance
+pub const ISOCENTER_TO_BLOCK_TRAY_DISTANCE: Tag = Tag(0x300A, 0x00F7);
+/// (300A,00F8) Block Type
+pub const BLOCK_TYPE: Tag = Tag(0x300A, 0x00F8);
+/// (300A,00F9) Accessory Code
+pub const ACCESSORY_CODE: Tag = Tag(0x300A, 0x00F9);
+/// (300A,00FA) Block Divergence
+pub const BLOCK_DIVERGENCE: Tag = Tag(0x300A, 0x00FA);
+/// (300A,00FB) Block Mounting Position
+pub const BLOCK_MOUNTING_POSITION: Tag = Tag(0x300A, 0x00FB);
+/// (300A,00FC) Block Number
+pub const BLOCK_NUMBER: Tag = Tag(0x300A, 0x00FC);
+/// (300A,00FE) Block Name
+pub const BLOCK_NAME: Tag = Tag(0x300A, 0x00FE);
+/// (300A,0100) Block Thickness
+pub const BLOCK_THICKNESS: Tag = Tag(0x300A, 0x0100);
+/// (300A,0102) Block Transmission
+pub const BLOCK_TRANSMISSION: Tag = Tag(0x300A, 0x0102);
+/// (300A,0104) Block Number of Points
+pub const BLOCK_NUMBER_OF_POINTS: Tag = Tag(0x300A, 0x0104);
+/// (300A,0106) Block Data
+pub const BLOCK_DATA: Tag = Tag(0x300A, 0x0106);
+/// (300A,0107) Applicator Sequence
+pub const APPLICATOR_SEQUENCE: Tag = Tag(0x300A, 0x0107);
+/// (300A,0108) Applicator ID
+pub const APPLICATOR_ID: Tag = Tag(0x300A, 0x0108);
+/// (300A,0109) Applicator Type
+pub const APPLICATOR_TYPE: Tag = Tag(0x300A, 0x0109);
+/// (300A,010A) Applicator Description
+pub const APPLICATOR_DESCRIPTION: Tag = Tag(0x300A, 0x010A);
+/// (300A,010C) Cumulative Dose Reference Coefficient
+pub const CUMULATIVE_DOSE_REFERENCE_COEFFICIENT: Tag = Tag(0x300A, 0x010C);
+/// (300A,010E) Final Cumulative Meterset Weight
+pub const FINAL_CUMULATIVE_METERSET_WEIGHT: Tag = Tag(0x300A, 0x010E);
+/// (300A,0110) Number of Control Points
+pub const NUMBER_OF_CONTROL_POINTS: Tag = Tag(0x300A, 0x0110);
+/// (300A,0111) Control Point Sequence
+pub const CONTROL_POINT_SEQUENCE: Tag = Tag(0x300A, 0x0111);
+/// (300A,0112) Control Point Index
+pub const CONTROL_POINT_INDEX: Tag = Tag(0x300A, 0x0112);
+/// (300A,0114) Nominal Beam Energy
+pub const NOMINAL_BEAM_ENERGY: Tag = Tag(0x300A, 0x0114);
+/// (300A,0115) Dose Rate Set
+pub const DOSE_RATE_SET: Tag = Tag(0x300A, 0x0115);
+/// (300A,0116) Wedge Position Sequence
+pub const WEDGE_POSITION_SEQUENCE: Tag = Tag(0x300A, 0x0116);
+/// (300A,0118) Wedge Position
+pub const WEDGE_POSITION: Tag = Tag(0x300A, 0x0118);
+/// (300A,011A) Beam Limiting Device Position Sequence
+pub const BEAM_LIMITING_DEVICE_POSITION_SEQUENCE: Tag = Tag(0x300A, 0x011A);
+/// (300A,011C) Leaf Jaw Positions
+pub const LEAF_JAW_POSITIONS: Tag = Tag(0x300A, 0x011C);
+/// (300A,011E) Gantry Angle
+pub const GANTRY_ANGLE: Tag = Tag(0x300A, 0x011E);
+/// (300A,011F) Gantry Rotation Direction
+pub const GANTRY_ROTATION_DIRECTION: Tag = Tag(0x300A, 0x011F);
+/// (300A,0120) Beam Limiting Device Angle
+pub const BEAM_LIMITING_DEVICE_ANGLE: Tag = Tag(0x300A, 0x0120);
+/// (300A,0121) Beam Limiting Device Rotation Direction
+pub const BEAM_LIMITING_DEVICE_ROTATION_DIRECTION: Tag = Tag(0x300A, 0x0121);
+/// (300A,0122) Patient Support Angle
+pub const PATIENT_SUPPORT_ANGLE: Tag = Tag(0x300A, 0x0122);
+/// (300A,0123) Patient Support Rotation Direction
+pub const PATIENT_SUPPORT_ROTATION_DIRECTION: Tag = Tag(0x300A, 0x0123);
+/// (300A,0124) Table Top Eccentric Axis Distance
+pub const TABLE_TOP_ECCENTRIC_AXIS_DISTANCE: Tag = Tag(0x300A, 0x0124);
+/// (300A,0125) Table Top Eccentric Angle
+pub const TABLE_TOP_ECCENTRIC_ANGLE: Tag = Tag(0x300A, 0x0125);
+/// (300A,0126) Table Top Eccentric Rotation Direction
+pub const TABLE_TOP_ECCENTRIC_ROTATION_DIRECTION: Tag = Tag(0x300A, 0x0126);
+/// (300A,0128) Table Top Vertical Position
+pub const TABLE_TOP_VERTICAL_POSITION: Tag = Tag(0x300A, 0x0128);
+/// (300A,0129) Table Top Longitudinal Position
+pub const TABLE_TOP_LONGITUDINAL_POSITION: Tag = Tag(0x300A, 0x0129);
+/// (300A,012A) Table Top Lateral Position
+pub const TABLE_TOP_LATERAL_POSITION: Tag = Tag(0x300A, 0x012A);
+/// (300A,012C) Isocenter Position
+pub const ISOCENTER_POSITION: Tag = Tag(0x300A, 0x012C);
+/// (300A,012E) Surface Entry Point
+pub const SURFACE_ENTRY_POINT: Tag = Tag(0x300A, 0x012E);
+/// (300A,0130) Source to Surface Distance
+pub const SOURCE_TO_SURFACE_DISTANCE: Tag = Tag(0x300A, 0x0130);
+/// (300A,0131) Average Beam Dose Point Source to External Contour Distance
+pub const AVERAGE_BEAM_DOSE_POINT_SOURCE_TO_EXTERNAL_CONTOUR_DISTANCE: Tag = Tag(0x300A, 0x0131);
+/// (300A,0132) Source to External Contour Distance
+pub const SOURCE_TO_EXTERNAL_CONTOUR_DISTANCE: Tag = Tag(0x300A, 0x0132);
+/// (300A,0133) External Contour Entry Point
+pub const EXTERNAL_CONTOUR_ENTRY_POINT: Tag = Tag(0x300A, 0x0133);
+/// (300A,0134) Cumulative Meterset Weight
+pub const CUMULATIVE_METERSET_WEIGHT: Tag = Tag(0x300A, 0x0134);
+/// (300A,0140) Table Top Pitch Angle
+pub const TABLE_TOP_PITCH_ANGLE: Tag = Tag(0x300A, 0x0140);
+/// (300A,0142) Table Top Pitch Rotation Direction
+pub const TABLE_TOP_PITCH_ROTATION_DIRECTION: Tag = Tag(0x300A, 0x0142);
+/// (300A,0144) Table Top Roll Angle
+pub const TABLE_TOP_ROLL_ANGLE: Tag = Tag(0x300A, 0x0144);
+/// (300A,0146) Table Top Roll Rotation Direction
+pub const TABLE_TOP_ROLL_ROTATION_DIRECTION: Tag = Tag(0x300A, 0x0146);
+/// (300A,0148) Head Fixation Angle
+pub const HEAD_FIXATION_ANGLE: Tag = Tag(0x300A, 0x0148);
+/// (300A,014A) Gantry Pitch Angle
+pub const GANTRY_PITCH_ANGLE: Tag = Tag(0x300A, 0x014A);
+/// (300A,014C) Gantry Pitch Rotation Direction
+pub const GANTRY_PITCH_ROTATION_DIRECTION: Tag = Tag(0x300A, 0x014C);
+/// (300A,014E) Gantry Pitch Angle Tolerance
+pub const GANTRY_PITCH_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x014E);
+/// (300A,0150) Fixation Eye
+pub const FIXATION_EYE: Tag = Tag(0x300A, 0x0150);
+/// (300A,0151) Chair Head Frame Position
+pub const CHAIR_HEAD_FRAME_POSITION: Tag = Tag(0x300A, 0x0151);
+/// (300A,0152) Head Fixation Angle Tolerance
+pub const HEAD_FIXATION_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x0152);
+/// (300A,0153) Chair Head Frame Position Tolerance
+pub const CHAIR_HEAD_FRAME_POSITION_TOLERANCE: Tag = Tag(0x300A, 0x0153);
+/// (300A,0154) Fixation Light Azimuthal Angle Tolerance
+pub const FIXATION_LIGHT_AZIMUTHAL_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x0154);
+/// (300A,0155) Fixation Light Polar Angle Tolerance
+pub const FIXATION_LIGHT_POLAR_ANGLE_TOLERANCE: Tag = Tag(0x300A, 0x0155);
+/// (300A,0180) Patient Setup Sequence
+pub const PATIENT_SETUP_SEQUENCE: Tag = Tag(0x300A, 0x0180);
+/// (300A,0182) Patient Setup Number
+pub const PATIENT_SETUP_NUMBER: Tag = Tag(0x300A, 0x0182);
+/// (300A,0183) Patient Setup Label
+pub const PATIENT_SETUP_LABEL: Tag = Tag(0x300A, 0x0183);
+/// (300A,0184) Patient Additional Position
+pub const PATIENT_ADDITIONAL_POSITION: Tag = Tag(0x300A, 0x0184);
+/// (300A,0190) Fixation Device Sequence
+pub const FIXATION_DEVICE_SEQUENCE: Tag = Tag(0x300A, 0x0190);
+/// (300A,0192) Fixation Device Type
+pub const FIXATION_DEVICE_TYPE: Tag = Tag(0x300A, 0x0192);
+/// (300A,0194) Fixation Device Label
+pub const FIXATION_DEVICE_LABEL: Tag = Tag(0x300A, 0x0194);
+/// (300A,0196) Fixation Device Description
+pub const FIXATION_DEVICE_DESCRIPTION: Tag = Tag(0x300A, 0x0196);
+/// (300A,0198) Fixation Device Position
+pub const FIXATION_DEVICE_POSITION: Tag = Tag(0x300A, 0x0198);
+/// (300A,0199) Fixation Device Pitch Angle
+pub const FIXATION_DEVICE_PITCH_ANGLE: Tag = Tag(0x300A, 0x0199);
+/// (300A,019A) Fixation Device Roll Angle
+pub const FIXATION_DEVICE_ROLL_ANGLE: Tag = Tag(0x300A, 0x019A);
+/// (300A,01A0) Shielding Device Sequence
+pub const SHIELDING_DEVICE_SEQUENCE: Tag = Tag(0x300A, 0x01A0);
+/// (300A,01A2) Shielding Device Type
+pub const SHIELDING_DEVICE_TYPE: Tag = Tag(0x300A, 0x01A2);
+/// (300A,01A4) Shielding Device Label
+pub const SHIELDING_DEVICE_LABEL: Tag = Tag(0x300A, 0x01A4);
+/// (300A,01A6) Shielding Device Description
+pub const SHIELDING_DEVICE_DESCRIPTION: Tag = Tag(0x300A, 0x01A6);
+/// (300A,01A8) Shielding Device Position
+pub const SHIELDING_DEVICE_POSITION: Tag = Tag(0x300A, 0x01A8);
+/// (300A,01B0) Setup Technique
+pub const SETUP_TECHNIQUE: Tag = Tag(0x300A, 0x01B0);
+/// (300A,01B2) Setup Technique Description
+pub const SETUP_TECHNIQUE_DESCRIPTION: Tag = Tag(0x300A, 0x01B2);
+/// (300A,01B4) Setup Device Sequence
+pub const SETUP_DEVICE_SEQUENCE: Tag = Tag(0x300A, 0x01B4);
+/// (300A,01B6) Setup Device Type
+pub const SETUP_DEVICE_TYPE: Tag = Tag(0x300A, 0x01B6);
+/// (300A,01B8) Setup Device Label
+pub const SETUP_DEVICE_LABEL: Tag = Tag(0x300A, 0x01B8);
+/// (300A,01BA) Setup Device Description
+pub const SETUP_DEVICE_DESCRIPTION: Tag = Tag(0x300A, 0x01BA);
+/// (300A,01BC) Setup Device Parameter
+pub const SETUP_DEVICE_PARAMETER: Tag = Tag(0x300A, 0x01BC);
+/// (300A,01D0) Setup Reference Description
+pub const SETUP_REFERENCE_DESCRIPTION: Tag = Tag(0x300A, 0x01D0);
+/// (300A,01D2) Table Top Vertical Setup Displacement
+pub const TABLE_TOP_VERTICAL_SETUP_DISPLACEMENT: Tag = Tag(0x300A, 0x01D2);
+/// (300A,01D4) Table Top Longitudinal Setup Displacement
+pub const TABLE_TOP_LONGITUDINAL_SETUP_DISPLACEMENT: Tag = Tag(0x300A, 0x01D4);
+/// (300A,01D6) Table Top Lateral Setup Displacement
+pub const TABLE_TOP_LATERAL_SETUP_DISPLACEMENT: Tag = Tag(0x300A, 0x01D6);
+/// (300A,0200) Brachy Treatment Technique
+pub const BRACHY_TREATMENT_TECHNIQUE: Tag = Tag(0x300A, 0x0200);
+/// (300A,0202) Brachy Treatment Type
+pub const BRACHY_TREATMENT_TYPE: Tag = Tag(0x300A, 0x0202);
+/// (300A,0206) Treatment Machine Sequence
+pub const TREATMENT_MACHINE_SEQUENCE: Tag = Tag(0x300A, 0x0206);
+/// (300A,0210) Source Sequence
+pub const SOURCE_SEQUENCE: Tag = Tag(0x300A, 0x0210);
+/// (300A,0212) Source Number
+pub const SOURCE_NUMBER: Tag = Tag(0x300A, 0x0212);
+/// (300A,0214) Source Type
+pub const SOURCE_TYPE: Tag = Tag(0x300A, 0x0214);
+/// (300A,0216) Source Manufacturer
+pub const SOURCE_MANUFACTURER: Tag = Tag(0x300A, 0x0216);
+/// (300A,0218) Active Source Diameter
+pub const ACTIVE_SOURCE_DIAMETER: Tag = Tag(0x300A, 0x0218);
+/// (300A,021A) Active Source Length
+pub const ACTIVE_SOURCE_LENGTH: Tag = Tag(0x300A, 0x021A);
+/// (300A,021B) Source Model ID
+pub const SOURCE_MODEL_ID: Tag = Tag(0x300A, 0x021B);
+/// (300A,021C) Source Description
+pub const SOURCE_DESCRIPTION: Tag = Tag(0x300A, 0x021C);
+/// (300A,0222) Source Encapsulation Nominal Thickness
+pub const SOURCE_ENCAPSULATION_NOMINAL_THICKNESS: Tag = Tag(0x300A, 0x0222);
+/// (300A,0224) Source Encapsulation Nominal Transmission
+pub const SOURCE_ENCAPSULATION_NOMINAL_TRANSMISSION: Tag = Tag(0x300A, 0x0224);
+/// (300A,0226) Source Isotope Name
+pub const SOURCE_ISOTOPE_NAME: Tag = Tag(0x300A, 0x0226);
+/// (300A,0228) Source Isotope Half Life
+pub const SOURCE_ISOTOPE_HALF_LIFE: Tag = Tag(0x300A, 0x0228);
+/// (300A,0229) Source Strength Units
+pub const SOURCE_STRENGTH_UNITS: Tag = Tag(0x300A, 0x0229);
+/// (300A,022A) Reference Air Kerma Rate
+pub const REFERENCE_AIR_KERMA_RATE: Tag = Tag(0x300A, 0x022A);
+/// (300A,022B) Source Strength
+pub const SOURCE_STRENGTH: Tag = Tag(0x300A, 0x022B);
+/// (300A,022C) Source Strength Reference Date
+pub const SOURCE_STRENGTH_REFERENCE_DATE: Tag = Tag(0x300A, 0x022C);
+/// (300A,022E) Source Strength Reference Time
+pub const SOURCE_STRENGTH_REFERENCE_TIME: Tag = Tag(0x300A, 0x022E);
+/// (300A,0230) Application Setup Sequence
+pub const APPLICATION_SETUP_SEQUENCE: Tag = Tag(0x300A, 0x0230);
+/// (300A,0232) Application Setup Type
+pub const APPLICATION_SETUP_TYPE: Tag = Tag(0x300A, 0x0232);
+/// (300A,0234) Application Setup Number
+pub const APPLICATION_SETUP_NUMBER: Tag = Tag(0x300A, 0x0234);
+/// (300A,0236) Application Setup Name
+pub const APPLICATION_SETUP_NAME: Tag = Tag(0x300A, 0x0236);
+/// (300A,0238) Application Setup Manufacturer
+pub const APPLICATION_SETUP_MANUFACTURER: Tag = Tag(0x300A, 0x0238);
+/// (300A,0240) Template Number
+pub const TEMPLATE_NUMBER: Tag = Tag(0x300A, 0x0240);
+/// (300A,0242) Template Type
+pub const TEMPLATE_TYPE: Tag = Tag(0x300A, 0x0242);
+/// (300A,0244) Template Name
+pub const TEMPLATE_NAME: Tag = Tag(0x300A, 0x0244);
+/// (300A,0250) Total Reference Air Kerma
+pub const TOTAL_REFERENCE_AIR_KERMA: Tag = Tag(0x300A, 0x0250);
+/// (300A,0260) Brachy Accessory Device Sequence
+pub const BRACHY_ACCESSORY_DEVICE_SEQUENCE: Tag = Tag(0x300A, 0x0260);
+/// (300A,0262) Brachy Accessory Device Number
+pub const BRACHY_ACCESSORY_DEVICE_NUMBER: Tag = Tag(0x300A, 0x0262);
+/// (300A,0263) Brachy Accessory Device ID
+pub const BRACHY_ACCESSORY_DEVICE_ID: Tag = Tag(0x300A, 0x0263);
+/// (300A,0264) Brachy Accessory Device Type
+pub const BRACHY_ACCESSORY_DEVICE_TYPE: Tag = Tag(0x300A, 0x0264);
+/// (300A,0266) Brachy Accessory Device Name
+pub const BRACHY_ACCESSORY_DEVICE_NAME: Tag = Tag(0x300A, 0x0266);
+/// (300A,026A) Brachy Accessory Device Nominal Thickness
+pub const BRACHY_ACCESSORY_DEVICE_NOMINAL_THICKNESS: Tag = Tag(0x300A, 0x026A);
+/// (300A,026C) Brachy Accessory Device Nominal Transmission
+pub const BRACHY_ACCESSORY_DEVICE_NOMINAL_TRANSMISSION: Tag = Tag(0x300A, 0x026C);
+/// (300A,0271) Channel Effective Length
+pub const CHANNEL_EFFECTIVE_LENGTH: Tag = Tag(0x300A, 0x0271);
+/// (300A,0272) Channel Inner Length
+pub const CHANNEL_INNER_LENGTH: Tag = Tag(0x300A, 0x0272);
+/// (300A,0273) Afterloader Channel ID
+pub const AFTERLOADER_CHANNEL_ID: Tag = Tag(0x300A, 0x0273);
+/// (300A,0274) Source Applicator Tip Length
+pub const SOURCE_APPLICATOR_TIP_LENGTH: Tag = Tag(0x300A, 0x0274);
+/// (300A,0280) Channel Sequence
+pub const CHANNEL_SEQUENCE: Tag = Tag(0x300A, 0x0280);
+/// (300A,0282) Channel Number
+pub const CHANNEL_NUMBER: Tag = Tag(0x300A, 0x0282);
+/// (300A,0284) Channel Length
+pub const CHANNEL_LENGTH: Tag = Tag(0x300A, 0x0284);
+/// (300A,0286) Channel Total Time
+pub const CHANNEL_TOTAL_TIME: Tag = Tag(0x300A, 0x0286);
+/// (300A,0288) Source Movement Type
+pub const SOURCE_MOVEMENT_TYPE: Tag = Tag(0x300A, 0x0288);
+/// (300A,028A) Number of Pulses
+pub const NUMBER_OF_PULSES: Tag = Tag(0x300A, 0x028A);
+/// (300A,028C) Pulse Repetition Interval
+pub const PULSE_REPETITION_INTERVAL: Tag = Tag(0x300A, 0x028C);
+/// (300A,0290) Source Applicator Number
+pub const SOURCE_APPLICATOR_NUMBER: Tag = Tag(0x300A, 0x0290);
+/// (300A,0291) Source Applicator ID
+pub const SOURCE_APPLICATOR_ID: Tag = Tag(0x300A, 0x0291);
+/// (300A,0292) Source Applicator Type
+pub const SOURCE_APPLICATOR_TYPE: Tag = Tag(0x300A, 0x0292);
+/// (300A,0294) Source Applicator Name
+pub const SOURCE_APPLICATOR_NAME: Tag = Tag(0x300A, 0x0294);
+/// (300A,0296) Source Applicator Length
+pub const SOURCE_APPLICATOR_LENGTH: Tag = Tag(0x300A, 0x0296);
+/// (300A,0298) Source Applicator Manufacturer
+pub const SOURCE_APPLICATOR_MANUFACTURER: Tag = Tag(0x300A, 0x0298);
+/// (300A,029C) Source Applicator Wall Nominal Thickness
+pub const SOURCE_APPLICATOR_WALL_NOMINAL_THICKNESS: Tag = Tag(0x300A, 0x029C);
+/// (300A,029E) Source Applicator Wall Nominal Transmission
+pub const SOURCE_APPLICATOR_WALL_NOMINAL_TRANSMISSION: Tag = Tag(0x300A, 0x029E);
+/// (300A,02A0) Source Applicator Step Size
+pub const SOURCE_APPLICATOR_STEP_SIZE: Tag = Tag(0x300A, 0x02A0);
+/// (300A,02A2) Transfer Tube Number
+pub const TRANSFER_TUBE_NUMBER: Tag = Tag(0x300A, 0x02A2);
+/// (300A,02A4) Transfer Tube Length
+pub const TRANSFER_TUBE_LENGTH: Tag = Tag(0x300A, 0x02A4);
+/// (300A,02B0) Channel Shield Sequence
+pub const CHANNEL_SHIELD_SEQUENCE: Tag = Tag(0x300A, 0x02B0);
+/// (300A,02B2) Channel Shield Number
+pub const CHANNEL_SHIELD_NUMBER: Tag = Tag(0x300A, 0x02B2);
+/// (300A,02B3) Channel Shield ID
+pub const CHANNEL_SHIELD_ID: Tag = Tag(0x300A, 0x02B3);
+/// (300A,02B4) Channel Shield Name
+pub const CHANNEL_SHIELD_NAME: Tag = Tag(0x300A, 0x02B4);
+/// (300A,02B8) Channel Shield Nominal Thickness
+pub const CHANNEL_SHIELD_NOMINAL_THICKNESS: Tag = Tag(0x300A, 0x02B8);
+/// (300A,02BA) Channel Shield Nominal Transmission
+pub const CHANNEL_SHIELD_NOMINAL_TRANSMISSION: Tag = Tag(0x300A, 0x02BA);
+/// (300A,02C8) Final Cumulative Time Weight
+pub const FINAL_CUMULATIVE_TIME_WEIGHT: Tag = Tag(0x300A, 0x02C8);
+/// (300A,02D0) Brachy Control Point Sequence
+pub const BRACHY_CONTROL_POINT_SEQUENCE: Tag = Tag(0x300A, 0x02D0);
+/// (300A,02D2) Control Point Relative Position
+pub const CONTROL_POINT_RELATIVE_POSITION: Tag = Tag(0x300A, 0x02D2);
+/// (300A,02D4) Control Point 3D Position
+pub const CONTROL_POINT3D_POSITION: Tag = Tag(0x300A, 0x02D4);
+/// (300A,02D6) Cumulative Time Weight
+pub const CUMULATIVE_TIME_WEIGHT: Tag = Tag(0x300A, 0x02D6);
+/// (300A,02E0) Compensator Divergence
+pub const COMPENSATOR_DIVERGENCE: Tag = Tag(0x300A, 0x02E0);
+/// (300A,02E1) Compensator Mounting Position
+pub const COMPENSATOR_MOUNTING_POSITION: Tag = Tag(0x300A, 0x02E1);
+/// (300A,02E2) Source to Compensator Distance
+pub const SOURCE_TO_COMPENSATOR_DISTANCE: Tag = Tag(0x300A, 0x02E2);
+/// (300A,02E3) Total Compensator Tray Water Equivalent Thickness
+pub const TOTAL_COMPENSATOR_TRAY_WATER_EQUIVALENT_THICKNESS: Tag = Tag(0x300A, 0x02E3);
+/// (300A,02E4) Isocenter to Compensator Tray Distance
+pub const ISOCENTER_TO_COMPENSATOR_TRAY_DISTANCE: Tag = Tag(0x300A, 0x02E4);
+/// (300A,02E5) Compensator Column Offset
+pub const COMPENSATOR_COLUMN_OFFSET: Tag = Tag(0x300A, 0x02E5);
+/// (300A,02E6) Isocenter to Compensator Distances
+pub const ISOCENTER_TO_COMPENSATOR_DISTANCES: Tag = Tag(0x300A, 0x02E6);
+/// (300A,02E7) Compensator Relative Stopping Power Ratio
+pub const COMPENSATOR_RELATIVE_STOPPING_POWER_RATIO: Tag = Tag(0x300A, 0x02E7);
+/// (300A,02E8) Compensator Milling Tool Diameter
+pub const COMPENSATOR_MILLING_TOOL_DIAMETER: Tag = Tag(0x300A, 0x02E8);
+/// (300A,02EA) Ion Range Compensator Sequence
+pub const ION_RANGE_COMPENSATOR_SEQUENCE: Tag = Tag(0x300A, 0x02EA);
+/// (300A,02EB) Compensator Description
+pub const COMPENSATOR_DESCRIPTION: Tag = Tag(0x300A, 0x02EB);
+/// (300A,0302) Radiation Mass Number
+pub const RADIATION_MASS_NUMBER: Tag = Tag(0x300A, 0x0302);
+/// (300A,0304) Radiation Atomic Number
+pub const RADIATION_ATOMIC_NUMBER: Tag = Tag(0x300A, 0x0304);
+/// (300A,0306) Radiation Charge State
+pub const RADIATION_CHARGE_STATE: Tag = Tag(0x300A, 0x0306);
+/// (300A,0308) Scan Mode
+pub const SCAN_MODE: Tag = Tag(0x300A, 0x0308);
+/// (300A,0309) Modulated Scan Mode Type
+pub const MODULATED_SCAN_MODE_TYPE: Tag = Tag(0x300A, 0x0309);
+/// (300A,030A) Virtual Source Axis Distances
+pub const VIRTUAL_SOURCE_AXIS_DISTANCES: Tag = Tag(0x300A, 0x030A);
+/// (300A,030C) Snout Sequence
+pub const SNOUT_SEQUENCE: Tag = Tag(0x300A, 0x030C);
+/// (300A,030D) Snout Position
+pub const SNOUT_POSITION: Tag = Tag(0x300A, 0x030D);
+/// (300A,030F) Snout ID
+pub const SNOUT_ID: Tag = Tag(0x300A, 0x030F);
+/// (300A,0312) Number of Range Shifters
+pub const NUMBER_OF_RANGE_SHIFTERS: Tag = Tag(0x300A, 0x0312);
+/// (300A,0314) Range Shifter Sequence
+pub const RANGE_SHIFTER_SEQUENCE: Tag = Tag(0x300A, 0x0314);
+/// (300A,0316) Range Shifter Number
+pub const RANGE_SHIFTER_NUMBER: Tag = Tag(0x300A, 0x0316);
+/// (300A,0318) Range Shifter ID
+pub const RANGE_SHIFTER_ID: Tag = Tag(0x300A, 0x0318);
+/// (300A,0320) Range Shifter Type
+pub const RANGE_SHIFTER_TYPE: Tag = Tag(0x300A, 0x0320);
+/// (300A,0322) Range Shifter Description
+pub const RANGE_SHIFTER_DESCRIPTION: Tag = Tag(0x300A, 0x0322);
+/// (300A,0330) Number of Lateral Spreading Devices
+pub const NUMBER_OF_LATERAL_SPREADING_DEVICES: Tag = Tag(0x300A, 0x0330);
+/// (300A,0332) Lateral Spreading Device Sequence
+pub const LATERAL_SPREADING_DEVICE_SEQUENCE: Tag = Tag(0x300A, 0x0332);
+/// (300A,0334) Lateral Spreading Device Number
+pub const LATERAL_SPREADING_DEVICE_NUMBER: Tag = Tag(0x300A, 0x0334);
+/// (300A,0336) Lateral Spreading Device ID
+pub const LATERAL_SPREADING_DEVICE_ID: Tag = Tag(0x300A, 0x0336);
+/// (300A,0338) Lateral Spreading Device Type
+pub const LATERAL_SPREADING_DEVICE_TYPE: Tag = Tag(0x300A, 0x0338);
+/// (300A,033A) Lateral Spreading Device Description
+pub const LATERAL_SPREADING_DEVICE_DESCRIPTION: Tag = Tag(0x300A, 0x033A);
+/// (300A,033C) Lateral Spreading Device Water Equivalent Thickness
+pub const LATERAL_SPREADING_DEVICE_WATER_EQUIVALENT_THICKNESS: Tag = Tag(0x300A, 0x033C);
+/// (300A,0340) Number of Range Modulators
+pub const NUMBER_OF_RANGE_MODULATORS: Tag = Tag(0x300A, 0x0340);
+/// (300A,0342) Range Modulator Sequence
+pub const RANGE_MODULATOR_SEQUENCE: Tag = Tag(0x300A, 0x0342);
+/// (300A,0344) Range Modulator Number
+pub const RANGE_MODULATOR_NUMBER: Tag = Tag(0x300A, 0x0344);
+/// (300A,0346) Range Modulator ID
+pub const RANGE_MODULATOR_ID: Tag = Tag(0x300A, 0x0346);
+/// (300A,0348) Range Modulator Type
+pub const RANGE_MODULATOR_TYPE: Tag = Tag(0x300A, 0x0348);
+/// (300A,034A) Range Modulator Description
+pub const RANGE_MODULATOR_DESCRIPTION: Tag = Tag(0x300A, 0x034A);
+/// (300A,034C) Beam Current Modulation ID
+pub const BEAM_CURRENT_MODULATION_ID: Tag = Tag(0x300A, 0x034C);
+/// (300A,0350) Patient Support Type
+pub const PATIENT_SUPPORT_TYPE: Tag = Tag(0x300A, 0x0350);
+/// (300A,0352) Patient Support ID
+pub const PATIENT_SUPPORT_ID: Tag = Tag(0x300A, 0x0352);
+/// (300A,0354) Patient Support Accessory Code
+pub const PATIENT_SUPPORT_ACCESSORY_CODE: Tag = Tag(0x300A, 0x0354);
+/// (300A,0355) Tray Accessory Code
+pub const TRAY_ACCESSORY_CODE: Tag = Tag(0x300A, 0x0355);
+/// (300A,0356) Fixation Light Azimuthal Angle
+pub const FIXATION_LIGHT_AZIMUTHAL_ANGLE: Tag = Tag(0x300A, 0x0356);
+/// (300A,0358) Fixation Light Polar Angle
+pub const FIXATION_LIGHT_POLAR_ANGLE: Tag = Tag(0x300A, 0x0358);
+/// (300A,035A) Meterset Rate
+pub const METERSET_RATE: Tag = Tag(0x300A, 0x035A);
+/// (300A,0360) Range Shifter Settings Sequence
+pub const RANGE_SHIFTER_SETTINGS_SEQUENCE: Tag = Tag(0x300A, 0x0360);
+/// (300A,0362) Range Shifter Setting
+pub const RANGE_SHIFTER_SETTING: Tag = Tag(0x300A, 0x0362);
+/// (300A,0364) Isocenter to Range Shifter Distance
+pub const ISOCENTER_TO_RANGE_SHIFTER_DISTANCE: Tag = Tag(0x300A, 0x0364);
+/// (300A,0366) Range Shifter Water Equivalent Thickness
+pub const RANGE_SHIFTER_WATER_EQUIVALENT_THICKNESS: Tag = Tag(0x300A, 0x0366);
+/// (300A,0370) Lateral Spreading Device Settings Sequence
+pub const LATERAL_SPREADING_DEVICE_SETTINGS_SEQUENCE: Tag = Tag(0x300A, 0x0370);
+/// (300A,0372) Lateral Spreading Device Setting
+pub const LATERAL_SPREADING_DEVICE_SETTING: Tag = Tag(0x300A, 0x0372);
+/// (300A,0374) Isocenter to Lateral Spreading Device Distance
+pub const ISOCENTER_TO_LATERAL_SPREADING_DEVICE_DISTANCE: Tag = Tag(0x300A, 0x0374);
+/// (300A,0380) Range Modulator Settings Sequence
+pub const RANGE_MODULATOR_SETTINGS_SEQUENCE: Tag = Tag(0x300A, 0x0380);
+/// (300A,0382) Range Modulator Gating Start Value
+pub const RANGE_MODULATOR_GATING_START_VALUE: Tag = Tag(0x300A, 0x0382);
+/// (300A,0384) Range Modulator Gating Stop Value
+pub const RANGE_MODULATOR_GATING_STOP_VALUE: Tag = Tag(0x300A, 0x0384);
+/// (300A,0386) Range Modulator Gating Start Water Equivalent Thickness
+pub const RANGE_MODULATOR_GATING_START_WATER_EQUIVALENT_THICKNESS: Tag = Tag(0x300A, 0x0386);
+/// (300A,0388) Range Modulator Gating Stop Water Equivalent Thickness
+pub const RANGE_MODULATOR_GATING_STOP_WATER_EQUIVALENT_THICKNESS: Tag = Tag(0x300A, 0x0388);
+/// (300A,038A) Isocenter to Range Modulator Distance
+pub const ISOCENTER_TO_RANGE_MODULATOR_DISTANCE: Tag = Tag(0x300A, 0x038A);
+/// (300A,038F) Scan Spot Time Offset
+pub const SCAN_SPOT_TIME_OFFSET: Tag = Tag(0x300A, 0x038F);
+/// (300A,0390) Scan Spot Tune ID
+pub const SCAN_SPOT_TUNE_ID: Tag = Tag(0x300A, 0x0390);
+/// (300A,0391) Scan Spot Prescribed Indices
+pub const SCAN_SPOT_PRESCRIBED_INDICES: Tag = Tag(0x300A, 0x0391);
+/// (300A,0392) Number of Scan Spot Positions
+pub const NUMBER_OF_SCAN_SPOT_POSITIONS: Tag = Tag(0x300A, 0x0392);
+/// (300A,0393) Scan Spot Reordered
+pub const SCAN_SPOT_REORDERED: Tag = Tag(0x300A, 0x0393);
+/// (300A,0394) Scan Spot Position Map
+pub const SCAN_SPOT_POSITION_MAP: Tag = Tag(0x300A, 0x0394);
+/// (300A,0395) Scan Spot Reordering Allowed
+pub const SCAN_SPOT_REORDERING_ALLOWED: Tag = Tag(0x300A, 0x0395);
+/// (300A,0396) Scan Spot Meterset Weights
+pub const SCAN_SPOT_METERSET_WEIGHTS: Tag = Tag(0x300A, 0x0396);
+/// (300A,0398) Scanning Spot Size
+pub const SCANNING_SPOT_SIZE: Tag = Tag(0x300A, 0x0398);
+/// (300A,039A) Number of Paintings
+pub const NUMBER_OF_PAINTINGS: Tag = Tag(0x300A, 0x039A);
+/// (300A,03A0) Ion Tolerance Table Sequence
+pub const ION_TOLERANCE_TABLE_SEQUENCE: Tag = Tag(0x300A, 0x03A0);
+/// (300A,03A2) Ion Beam Sequence
+pub const ION_BEAM_SEQUENCE: Tag = Tag(0x300A, 0x03A2);
+/// (300A,03A4) Ion Beam Limiting Device Sequence
+pub const ION_BEAM_LIMITING_DEVICE_SEQUENCE: Tag = Tag(0x300A, 0x03A4);
+/// (300A,03A6) Ion Block Sequence
+pub const ION_BLOCK_SEQUENCE: Tag = Tag(0x300A, 0x03A6);
+/// (300A,03A8) Ion Control Point Sequence
+pub const ION_CONTROL_POINT_SEQUENCE: Tag = Tag(0x300A, 0x03A8);
+/// (300A,03AA) Ion Wedge Sequence
+pub const ION_WEDGE_SEQUENCE: Tag = Tag(0x300A, 0x03AA);
+/// (300A,03AC) Ion Wedge Position Sequence
+pub const ION_WEDGE_POSITION_SEQUENCE: Tag = Tag(0x300A, 0x03AC);
+/// (300A,0401) Referenced Setup Image Sequence
+pub const REFERENCED_SETUP_IMAGE_SEQUENCE: Tag = Tag(0x300A, 0x0401);
+/// (300A,0402) Setup Image Comment
+pub const SETUP_IMAGE_COMMENT: Tag = Tag(0x300A, 0x0402);
+/// (300A,0410) Motion Synchronization Sequence
+pub const MOTION_SYNCHRONIZATION_SEQUENCE: Tag = Tag(0x300A, 0x0410);
+/// (300A,0412) Control Point Orientation
+pub const CONTROL_POINT_ORIENTATION: Tag = Tag(0x300A, 0x0412);
+/// (300A,0420) General Accessory Sequence
+pub const GENERAL_ACCESSORY_SEQUENCE: Tag = Tag(0x300A, 0x0420);
+/// (300A,0421) General Accessory ID
+pub const GENERAL_ACCESSORY_ID: Tag = Tag(0x300A, 0x0421);
+/// (300A,0422) General Accessory Description
+pub const GENERAL_ACCESSORY_DESCRIPTION: Tag = Tag(0x300A, 0x0422);
+/// (300A,0423) General Accessory Type
+pub const GENERAL_ACCESSORY_TYPE: Tag = Tag(0x300A, 0x0423);
+/// (300A,0424) General Accessory Number
+pub const GENERAL_ACCESSORY_NUMBER: Tag = Tag(0x300A, 0x0424);
+/// (300A,0425) Source to General Accessory Distance
+pub const SOURCE_TO_GENERAL_ACCESSORY_DISTANCE: Tag = Tag(0x300A, 0x0425);
+/// (300A,0431) Applicator Geometry Sequence
+pub const APPLICATOR_GEOMETRY_SEQUENCE: Tag = Tag(0x300A, 0x0431);
+/// (300A,0432) Applicator Aperture Shape
+pub const APPLICATOR_APERTURE_SHAPE: Tag = Tag(0x300A, 0x0432);
+/// (300A,0433) Applicator Opening
+pub const APPLICATOR_OPENING: Tag = Tag(0x300A, 0x0433);
+/// (300A,0434) Applicator Opening X
+pub const APPLICATOR_OPENING_X: Tag = Tag(0x300A, 0x0434);
+/// (300A,0435) Applicator Opening Y
+pub const APPLICATOR_OPENING_Y: Tag = Tag(0x300A, 0x0435);
+/// (300A,0436) Source to Applicator Mounting Position Distance
+pub const SOURCE_TO_APPLICATOR_MOUNTING_POSITION_DISTANCE: Tag = Tag(0x300A, 0x0436);
+/// (300A,0440) Number of Block Slab Items
+pub const NUMBER_OF_BLOCK_SLAB_ITEMS: Tag = Tag(0x300A, 0x0440);
+/// (300A,0441) Block Slab Sequence
+pub const BLOCK_SLAB_SEQUENCE: Tag = Tag(0x300A, 0x0441);
+/// (300A,0442) Block Slab Thickness
+pub const BLOCK_SLAB_THICKNESS: Tag = Tag(0x300A, 0x0442);
+/// (300A,0443) Block Slab Number
+pub const BLOCK_SLAB_NUMBER: Tag = Tag(0x300A, 0x0443);
+/// (300A,0450) Device Motion Control Sequence
+pub const DEVICE_MOTION_CONTROL_SEQUENCE: Tag = Tag(0x300A, 0x0450);
+/// (300A,0451) Device Motion Execution Mode
+pub const DEVICE_MOTION_EXECUTION_MODE: Tag = Tag(0x300A, 0x0451);
+/// (300A,0452) Device Motion Observation Mode
+pub const DEVICE_MOTION_OBSERVATION_MODE: Tag = Tag(0x300A, 0x0452);
+/// (300A,0453) Device Motion Parameter Code Sequence
+pub const DEVICE_MOTION_PARAMETER_CODE_SEQUENCE: Tag = Tag(0x300A, 0x0453);
+/// (300A,0501) Distal Depth Fraction
+pub const DISTAL_DEPTH_FRACTION: Tag = Tag(0x300A, 0x0501);
+/// (300A,0502) Distal Depth
+pub const DISTAL_DEPTH: Tag = Tag(0x300A, 0x0502);
+/// (300A,0503) Nominal Range Modulation Fractions
+pub const NOMINAL_RANGE_MODULATION_FRACTIONS: Tag = Tag(0x300A, 0x0503);
+/// (300A,0504) Nominal Range Modulated Region Depths
+pub const NOMINAL_RANGE_MODULATED_REGION_DEPTHS: Tag = Tag(0x300A, 0x0504);
+/// (300A,0505) Depth Dose Parameters Sequence
+pub const DEPTH_DOSE_PARAMETERS_SEQUENCE: Tag = Tag(0x300A, 0x0505);
+/// (300A,0506) Delivered Depth Dose Parameters Sequence
+pub const DELIVERED_DEPTH_DOSE_PARAMETERS_SEQUENCE: Tag = Tag(0x300A, 0x0506);
+/// (300A,0507) Delivered Distal Depth Fraction
+pub const DELIVERED_DISTAL_DEPTH_FRACTION: Tag = Tag(0x300A, 0x0507);
+/// (300A,0508) Delivered Distal Depth
+pub const DELIVERED_DISTAL_DEPTH: Tag = Tag(0x300A, 0x0508);
+/// (300A,0509) Delivered Nominal Range Modulation Fractions
+pub const DELIVERED_NOMINAL_RANGE_MODULATION_FRACTIONS: Tag = Tag(0x300A, 0x0509);
+/// (300A,0510) Delivered Nominal Range Modulated Region Depths
+pub const DELIVERED_NOMINAL_RANGE_MODULATED_REGION_DEPTHS: Tag = Tag(0x300A, 0x0510);
+/// (300A,0511) Delivered Reference Dose Definition
+pub const DELIVERED_REFERENCE_DOSE_DEFINITION: Tag = Tag(0x300A, 0x0511);
+/// (300A,0512) Reference Dose Definition
+pub const REFERENCE_DOSE_DEFINITION: Tag = Tag(0x300A, 0x0512);
+/// (300C,0002) Referenced RT Plan Sequence
+pub const REFERENCED_RT_PLAN_SEQUENCE: Tag = Tag(0x300C, 0x0002);
+/// (300C,0004) Referenced Beam Sequence
+pub const REFERENCED_BEAM_SEQUENCE: Tag = Tag(0x300C, 0x0004);
+/// (300C,0006) Referenced Beam Number
+pub const REFERENCED_BEAM_NUMBER: Tag = Tag(0x300C, 0x0006);
+/// (300C,0007) Referenced Reference Image Number
+pub const REFERENCED_REFERENCE_IMAGE_NUMBER: Tag = Tag(0x300C, 0x0007);
+/// (300C,0008) Start Cumulative Meterset Weight
+pub const START_CUMULATIVE_METERSET_WEIGHT: Tag = Tag(0x300C, 0x0008);
+/// (300C,0009) End Cumulative Meterset Weight
+pub const END_CUMULATIVE_METERSET_WEIGHT: Tag = Tag(0x300C, 0x0009);
+/// (300C,000A) Referenced Brachy Application Setup Sequence
+pub const REFERENCED_BRACHY_APPLICATION_SETUP_SEQUENCE: Tag = Tag(0x300C, 0x000A);
+/// (300C,000C) Referenced Brachy Application Setup Number
+pub const REFERENCED_BRACHY_APPLICATION_SETUP_NUMBER: Tag = Tag(0x300C, 0x000C);
+/// (300C,000E) Referenced Source Number
+pub const REFERENCED_SOURCE_NUMBER: Tag = Tag(0x300C, 0x000E);
+/// (300C,0020) Referenced Fraction Group Sequence
+pub const REFERENCED_FRACTION_GROUP_SEQUENCE: Tag = Tag(0x300C, 0x0020);
+/// (300C,0022) Referenced Fraction Group Number
+pub const REFERENCED_FRACTION_GROUP_NUMBER: Tag = Tag(0x300C, 0x0022);
+/// (300C,0040) Referenced Verification Image Sequence
+pub const REFERENCED_VERIFICATION_IMAGE_SEQUENCE: Tag = Tag(0x300C, 0x0040);
+/// (300C,0042) Referenced Reference Image Sequence
+pub const REFERENCED_REFERENCE_IMAGE_SEQUENCE: Tag = Tag(0x300C, 0x0042);
+/// (300C,0050) Referenced Dose Reference Sequence
+pub const REFERENCED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x300C, 0x0050);
+/// (300C,0051) Referenced Dose Reference Number
+pub const REFERENCED_DOSE_REFERENCE_NUMBER: Tag = Tag(0x300C, 0x0051);
+/// (300C,0055) Brachy Referenced Dose Reference Sequence
+pub const BRACHY_REFERENCED_DOSE_REFERENCE_SEQUENCE: Tag = Tag(0x300C, 0x0055);
+/// (300C,0060) Referenced Structure Set Sequence
+pub const REFERENCED_STRUCTURE_SET_SEQUENCE: Tag = Tag(0x300C, 0x0060);
+/// (300C,006A) Referenced Patient Setup Number
+pub const REFERENCED_PATIENT_SETUP_NUMBER: Tag = Tag(0x300C, 0x006A);
+/// (300C,0080) Referenced Dose Sequence
+pub const REFERENCED_DOSE_SEQUENCE: Tag = Tag(0x300C, 0x0080);
+/// (300C,00A0) Referenced Tolerance Table Number
+pub const REFERENCED_TOLERANCE_TABLE_NUMBER: Tag = Tag(0x300C, 0x00A0);
+/// (300C,00B0) Referenced Bolus Sequence
+pub const REFERENCED_BOLUS_SEQUENCE: Tag = Tag(0x300C, 0x00B0);
+/// (300C,00C0) Referenced Wedge Number
+pub const REFERENCED_WEDGE_NUMBER: Tag = Tag(0x300C, 0x00C0);
+/// (300C,00D0) Referenced Compensator Number
+pub const REFERENCED_COMPENSATOR_NUMBER: Tag = Tag(0x300C, 0x00D0);
+/// (300C,00E0) Referenced Block Number
+pub const REFERENCED_BLOCK_NUMBER: Tag = Tag(0x300C, 0x00E0);
+/// (300C,00F0) Referenced Control Point Index
+pub const REFERENCED_CONTROL_POINT_INDEX: Tag = Tag(0x300C, 0x00F0);
+/// (300C,00F2) Referenced Control Point Sequence
+pub const REFERENCED_CONTROL_POINT_SEQUENCE: Tag = Tag(0x300C, 0x00F2);
+/// (300C,00F4) Referenced Start Control Point Index
+pub const REFERENCED_START_CONTROL_POINT_INDEX: Tag = Tag(0x300C, 0x00F4);
+/// (300C,00F6) Referenced Stop Control Point Index
+pub const REFERENCED_STOP_CONTROL_POINT_INDEX: Tag = Tag(0x300C, 0x00F6);
+/// (300C,0100) Referenced Range Shifter Number
+pub const REFERENCED_RANGE_SHIFTER_NUMBER: Tag = Tag(0x300C, 0x0100);
+/// (300C,0102) Referenced Lateral Spreading Device Number
+pub const REFERENCED_LATERAL_SPREADING_DEVICE_NUMBER: Tag = Tag(0x300C, 0x0102);
+/// (300C,0104) Referenced Range Modulator Number
+pub const REFERENCED_RANGE_MODULATOR_NUMBER: Tag = Tag(0x300C, 0x0104);
+/// (300C,0111) Omitted Beam Task Sequence
+pub const OMITTED_BEAM_TASK_SEQUENCE: Tag = Tag(0x300C, 0x0111);
+/// (300C,0112) Reason for Omission
+pub const REASON_FOR_OMISSION: Tag = Tag(0x300C, 0x0112);
+/// (300C,0113) Reason for Omission Description
+pub const REASON_FOR_OMISSION_DESCRIPTION: Tag = Tag(0x300C, 0x0113);
+/// (300E,0002) Approval Status
+pub const APPROVAL_STATUS: Tag = Tag(0x300E, 0x0002);
+/// (300E,0004) Review Date
+pub const REVIEW_DATE: Tag = Tag(0x300E, 0x0004);
+/// (300E,0005) Review Time
+pub const REVIEW_TIME: Tag = Tag(0x300E, 0x0005);
+/// (300E,0008) Reviewer Name
+pub const REVIEWER_NAME: Tag = Tag(0x300E, 0x0008);
+/// (3010,0001) Radiobiological Dose Effect Sequence
+pub const RADIOBIOLOGICAL_DOSE_EFFECT_SEQUENCE: Tag = Tag(0x3010, 0x0001);
+/// (3010,0002) Radiobiological Dose Effect Flag
+pub const RADIOBIOLOGICAL_DOSE_EFFECT_FLAG: Tag = Tag(0x3010, 0x0002);
+/// (3010,0003) Effective Dose Calculation Method Category Code Sequence
+pub const EFFECTIVE_DOSE_CALCULATION_METHOD_CATEGORY_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0003);
+/// (3010,0004) Effective Dose Calculation Method Code Sequence
+pub const EFFECTIVE_DOSE_CALCULATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0004);
+/// (3010,0005) Effective Dose Calculation Method Description
+pub const EFFECTIVE_DOSE_CALCULATION_METHOD_DESCRIPTION: Tag = Tag(0x3010, 0x0005);
+/// (3010,0006) Conceptual Volume UID
+pub const CONCEPTUAL_VOLUME_UID: Tag = Tag(0x3010, 0x0006);
+/// (3010,0007) Originating SOP Instance Reference Sequence
+pub const ORIGINATING_SOP_INSTANCE_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0007);
+/// (3010,0008) Conceptual Volume Constituent Sequence
+pub const CONCEPTUAL_VOLUME_CONSTITUENT_SEQUENCE: Tag = Tag(0x3010, 0x0008);
+/// (3010,0009) Equivalent Conceptual Volume Instance Reference Sequence
+pub const EQUIVALENT_CONCEPTUAL_VOLUME_INSTANCE_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0009);
+/// (3010,000A) Equivalent Conceptual Volumes Sequence
+pub const EQUIVALENT_CONCEPTUAL_VOLUMES_SEQUENCE: Tag = Tag(0x3010, 0x000A);
+/// (3010,000B) Referenced Conceptual Volume UID
+pub const REFERENCED_CONCEPTUAL_VOLUME_UID: Tag = Tag(0x3010, 0x000B);
+/// (3010,000C) Conceptual Volume Combination Expression
+pub const CONCEPTUAL_VOLUME_COMBINATION_EXPRESSION: Tag = Tag(0x3010, 0x000C);
+/// (3010,000D) Conceptual Volume Constituent Index
+pub const CONCEPTUAL_VOLUME_CONSTITUENT_INDEX: Tag = Tag(0x3010, 0x000D);
+/// (3010,000E) Conceptual Volume Combination Flag
+pub const CONCEPTUAL_VOLUME_COMBINATION_FLAG: Tag = Tag(0x3010, 0x000E);
+/// (3010,000F) Conceptual Volume Combination Description
+pub const CONCEPTUAL_VOLUME_COMBINATION_DESCRIPTION: Tag = Tag(0x3010, 0x000F);
+/// (3010,0010) Conceptual Volume Segmentation Defined Flag
+pub const CONCEPTUAL_VOLUME_SEGMENTATION_DEFINED_FLAG: Tag = Tag(0x3010, 0x0010);
+/// (3010,0011) Conceptual Volume Segmentation Reference Sequence
+pub const CONCEPTUAL_VOLUME_SEGMENTATION_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0011);
+/// (3010,0012) Conceptual Volume Constituent Segmentation Reference Sequence
+pub const CONCEPTUAL_VOLUME_CONSTITUENT_SEGMENTATION_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0012);
+/// (3010,0013) Constituent Conceptual Volume UID
+pub const CONSTITUENT_CONCEPTUAL_VOLUME_UID: Tag = Tag(0x3010, 0x0013);
+/// (3010,0014) Derivation Conceptual Volume Sequence
+pub const DERIVATION_CONCEPTUAL_VOLUME_SEQUENCE: Tag = Tag(0x3010, 0x0014);
+/// (3010,0015) Source Conceptual Volume UID
+pub const SOURCE_CONCEPTUAL_VOLUME_UID: Tag = Tag(0x3010, 0x0015);
+/// (3010,0016) Conceptual Volume Derivation Algorithm Sequence
+pub const CONCEPTUAL_VOLUME_DERIVATION_ALGORITHM_SEQUENCE: Tag = Tag(0x3010, 0x0016);
+/// (3010,0017) Conceptual Volume Description
+pub const CONCEPTUAL_VOLUME_DESCRIPTION: Tag = Tag(0x3010, 0x0017);
+/// (3010,0018) Source Conceptual Volume Sequence
+pub const SOURCE_CONCEPTUAL_VOLUME_SEQUENCE: Tag = Tag(0x3010, 0x0018);
+/// (3010,0019) Author Identification Sequence
+pub const AUTHOR_IDENTIFICATION_SEQUENCE: Tag = Tag(0x3010, 0x0019);
+/// (3010,001A) Manufacturer Model Version
+pub const MANUFACTURER_MODEL_VERSION: Tag = Tag(0x3010, 0x001A);
+/// (3010,001B) Device Alternate Identifier
+pub const DEVICE_ALTERNATE_IDENTIFIER: Tag = Tag(0x3010, 0x001B);
+/// (3010,001C) Device Alternate Identifier Type
+pub const DEVICE_ALTERNATE_IDENTIFIER_TYPE: Tag = Tag(0x3010, 0x001C);
+/// (3010,001D) Device Alternate Identifier Format
+pub const DEVICE_ALTERNATE_IDENTIFIER_FORMAT: Tag = Tag(0x3010, 0x001D);
+/// (3010,001E) Segmentation Creation Template Label
+pub const SEGMENTATION_CREATION_TEMPLATE_LABEL: Tag = Tag(0x3010, 0x001E);
+/// (3010,001F) Segmentation Template UID
+pub const SEGMENTATION_TEMPLATE_UID: Tag = Tag(0x3010, 0x001F);
+/// (3010,0020) Referenced Segment Reference Index
+pub const REFERENCED_SEGMENT_REFERENCE_INDEX: Tag = Tag(0x3010, 0x0020);
+/// (3010,0021) Segment Reference Sequence
+pub const SEGMENT_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0021);
+/// (3010,0022) Segment Reference Index
+pub const SEGMENT_REFERENCE_INDEX: Tag = Tag(0x3010, 0x0022);
+/// (3010,0023) Direct Segment Reference Sequence
+pub const DIRECT_SEGMENT_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0023);
+/// (3010,0024) Combination Segment Reference Sequence
+pub const COMBINATION_SEGMENT_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0024);
+/// (3010,0025) Conceptual Volume Sequence
+pub const CONCEPTUAL_VOLUME_SEQUENCE: Tag = Tag(0x3010, 0x0025);
+/// (3010,0026) Segmented RT Accessory Device Sequence
+pub const SEGMENTED_RT_ACCESSORY_DEVICE_SEQUENCE: Tag = Tag(0x3010, 0x0026);
+/// (3010,0027) Segment Characteristics Sequence
+pub const SEGMENT_CHARACTERISTICS_SEQUENCE: Tag = Tag(0x3010, 0x0027);
+/// (3010,0028) Related Segment Characteristics Sequence
+pub const RELATED_SEGMENT_CHARACTERISTICS_SEQUENCE: Tag = Tag(0x3010, 0x0028);
+/// (3010,0029) Segment Characteristics Precedence
+pub const SEGMENT_CHARACTERISTICS_PRECEDENCE: Tag = Tag(0x3010, 0x0029);
+/// (3010,002A) RT Segment Annotation Sequence
+pub const RT_SEGMENT_ANNOTATION_SEQUENCE: Tag = Tag(0x3010, 0x002A);
+/// (3010,002B) Segment Annotation Category Code Sequence
+pub const SEGMENT_ANNOTATION_CATEGORY_CODE_SEQUENCE: Tag = Tag(0x3010, 0x002B);
+/// (3010,002C) Segment Annotation Type Code Sequence
+pub const SEGMENT_ANNOTATION_TYPE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x002C);
+/// (3010,002D) Device Label
+pub const DEVICE_LABEL: Tag = Tag(0x3010, 0x002D);
+/// (3010,002E) Device Type Code Sequence
+pub const DEVICE_TYPE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x002E);
+/// (3010,0030) Patient Equipment Relationship Code Sequence
+pub const PATIENT_EQUIPMENT_RELATIONSHIP_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0030);
+/// (3010,0031) Referenced Fiducials UID
+pub const REFERENCED_FIDUCIALS_UID: Tag = Tag(0x3010, 0x0031);
+/// (3010,0032) Patient Treatment Orientation Sequence
+pub const PATIENT_TREATMENT_ORIENTATION_SEQUENCE: Tag = Tag(0x3010, 0x0032);
+/// (3010,0033) User Content Label
+pub const USER_CONTENT_LABEL: Tag = Tag(0x3010, 0x0033);
+/// (3010,0034) User Content Long Label
+pub const USER_CONTENT_LONG_LABEL: Tag = Tag(0x3010, 0x0034);
+/// (3010,0035) Entity Label
+pub const ENTITY_LABEL: Tag = Tag(0x3010, 0x0035);
+/// (3010,0036) Entity Name
+pub const ENTITY_NAME: Tag = Tag(0x3010, 0x0036);
+/// (3010,0037) Entity Description
+pub const ENTITY_DESCRIPTION: Tag = Tag(0x3010, 0x0037);
+/// (3010,0038) Entity Long Label
+pub const ENTITY_LONG_LABEL: Tag = Tag(0x3010, 0x0038);
+/// (3010,0039) Device Index
+pub const DEVICE_INDEX: Tag = Tag(0x3010, 0x0039);
+/// (3010,003A) RT Treatment Phase Index
+pub const RT_TREATMENT_PHASE_INDEX: Tag = Tag(0x3010, 0x003A);
+/// (3010,003B) RT Treatment Phase UID
+pub const RT_TREATMENT_PHASE_UID: Tag = Tag(0x3010, 0x003B);
+/// (3010,003C) RT Prescription Index
+pub const RT_PRESCRIPTION_INDEX: Tag = Tag(0x3010, 0x003C);
+/// (3010,003D) RT Segment Annotation Index
+pub const RT_SEGMENT_ANNOTATION_INDEX: Tag = Tag(0x3010, 0x003D);
+/// (3010,003E) Basis RT Treatment Phase Index
+pub const BASIS_RT_TREATMENT_PHASE_INDEX: Tag = Tag(0x3010, 0x003E);
+/// (3010,003F) Related RT Treatment Phase Index
+pub const RELATED_RT_TREATMENT_PHASE_INDEX: Tag = Tag(0x3010, 0x003F);
+/// (3010,0040) Referenced RT Treatment Phase Index
+pub const REFERENCED_RT_TREATMENT_PHASE_INDEX: Tag = Tag(0x3010, 0x0040);
+/// (3010,0041) Referenced RT Prescription Index
+pub const REFERENCED_RT_PRESCRIPTION_INDEX: Tag = Tag(0x3010, 0x0041);
+/// (3010,0042) Referenced Parent RT Prescription Index
+pub const REFERENCED_PARENT_RT_PRESCRIPTION_INDEX: Tag = Tag(0x3010, 0x0042);
+/// (3010,0043) Manufacturer Device Identifier
+pub const MANUFACTURER_DEVICE_IDENTIFIER: Tag = Tag(0x3010, 0x0043);
+/// (3010,0044) Instance Level Referenced Performed Procedure Step Sequence
+pub const INSTANCE_LEVEL_REFERENCED_PERFORMED_PROCEDURE_STEP_SEQUENCE: Tag = Tag(0x3010, 0x0044);
+/// (3010,0045) RT Treatment Phase Intent Presence Flag
+pub const RT_TREATMENT_PHASE_INTENT_PRESENCE_FLAG: Tag = Tag(0x3010, 0x0045);
+/// (3010,0046) Radiotherapy Treatment Type
+pub const RADIOTHERAPY_TREATMENT_TYPE: Tag = Tag(0x3010, 0x0046);
+/// (3010,0047) Teletherapy Radiation Type
+pub const TELETHERAPY_RADIATION_TYPE: Tag = Tag(0x3010, 0x0047);
+/// (3010,0048) Brachytherapy Source Type
+pub const BRACHYTHERAPY_SOURCE_TYPE: Tag = Tag(0x3010, 0x0048);
+/// (3010,0049) Referenced RT Treatment Phase Sequence
+pub const REFERENCED_RT_TREATMENT_PHASE_SEQUENCE: Tag = Tag(0x3010, 0x0049);
+/// (3010,004A) Referenced Direct Segment Instance Sequence
+pub const REFERENCED_DIRECT_SEGMENT_INSTANCE_SEQUENCE: Tag = Tag(0x3010, 0x004A);
+/// (3010,004B) Intended RT Treatment Phase Sequence
+pub const INTENDED_RT_TREATMENT_PHASE_SEQUENCE: Tag = Tag(0x3010, 0x004B);
+/// (3010,004C) Intended Phase Start Date
+pub const INTENDED_PHASE_START_DATE: Tag = Tag(0x3010, 0x004C);
+/// (3010,004D) Intended Phase End Date
+pub const INTENDED_PHASE_END_DATE: Tag = Tag(0x3010, 0x004D);
+/// (3010,004E) RT Treatment Phase Interval Sequence
+pub const RT_TREATMENT_PHASE_INTERVAL_SEQUENCE: Tag = Tag(0x3010, 0x004E);
+/// (3010,004F) Temporal Relationship Interval Anchor
+pub const TEMPORAL_RELATIONSHIP_INTERVAL_ANCHOR: Tag = Tag(0x3010, 0x004F);
+/// (3010,0050) Minimum Number of Interval Days
+pub const MINIMUM_NUMBER_OF_INTERVAL_DAYS: Tag = Tag(0x3010, 0x0050);
+/// (3010,0051) Maximum Number of Interval Days
+pub const MAXIMUM_NUMBER_OF_INTERVAL_DAYS: Tag = Tag(0x3010, 0x0051);
+/// (3010,0052) Pertinent SOP Classes in Study
+pub const PERTINENT_SOP_CLASSES_IN_STUDY: Tag = Tag(0x3010, 0x0052);
+/// (3010,0053) Pertinent SOP Classes in Series
+pub const PERTINENT_SOP_CLASSES_IN_SERIES: Tag = Tag(0x3010, 0x0053);
+/// (3010,0054) RT Prescription Label
+pub const RT_PRESCRIPTION_LABEL: Tag = Tag(0x3010, 0x0054);
+/// (3010,0055) RT Physician Intent Predecessor Sequence
+pub const RT_PHYSICIAN_INTENT_PREDECESSOR_SEQUENCE: Tag = Tag(0x3010, 0x0055);
+/// (3010,0056) RT Treatment Approach Label
+pub const RT_TREATMENT_APPROACH_LABEL: Tag = Tag(0x3010, 0x0056);
+/// (3010,0057) RT Physician Intent Sequence
+pub const RT_PHYSICIAN_INTENT_SEQUENCE: Tag = Tag(0x3010, 0x0057);
+/// (3010,0058) RT Physician Intent Index
+pub const RT_PHYSICIAN_INTENT_INDEX: Tag = Tag(0x3010, 0x0058);
+/// (3010,0059) RT Treatment Intent Type
+pub const RT_TREATMENT_INTENT_TYPE: Tag = Tag(0x3010, 0x0059);
+/// (3010,005A) RT Physician Intent Narrative
+pub const RT_PHYSICIAN_INTENT_NARRATIVE: Tag = Tag(0x3010, 0x005A);
+/// (3010,005B) RT Protocol Code Sequence
+pub const RT_PROTOCOL_CODE_SEQUENCE: Tag = Tag(0x3010, 0x005B);
+/// (3010,005C) Reason for Superseding
+pub const REASON_FOR_SUPERSEDING: Tag = Tag(0x3010, 0x005C);
+/// (3010,005D) RT Diagnosis Code Sequence
+pub const RT_DIAGNOSIS_CODE_SEQUENCE: Tag = Tag(0x3010, 0x005D);
+/// (3010,005E) Referenced RT Physician Intent Index
+pub const REFERENCED_RT_PHYSICIAN_INTENT_INDEX: Tag = Tag(0x3010, 0x005E);
+/// (3010,005F) RT Physician Intent Input Instance Sequence
+pub const RT_PHYSICIAN_INTENT_INPUT_INSTANCE_SEQUENCE: Tag = Tag(0x3010, 0x005F);
+/// (3010,0060) RT Anatomic Prescription Sequence
+pub const RT_ANATOMIC_PRESCRIPTION_SEQUENCE: Tag = Tag(0x3010, 0x0060);
+/// (3010,0061) Prior Treatment Dose Description
+pub const PRIOR_TREATMENT_DOSE_DESCRIPTION: Tag = Tag(0x3010, 0x0061);
+/// (3010,0062) Prior Treatment Reference Sequence
+pub const PRIOR_TREATMENT_REFERENCE_SEQUENCE: Tag = Tag(0x3010, 0x0062);
+/// (3010,0063) Dosimetric Objective Evaluation Scope
+pub const DOSIMETRIC_OBJECTIVE_EVALUATION_SCOPE: Tag = Tag(0x3010, 0x0063);
+/// (3010,0064) Therapeutic Role Category Code Sequence
+pub const THERAPEUTIC_ROLE_CATEGORY_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0064);
+/// (3010,0065) Therapeutic Role Type Code Sequence
+pub const THERAPEUTIC_ROLE_TYPE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0065);
+/// (3010,0066) Conceptual Volume Optimization Precedence
+pub const CONCEPTUAL_VOLUME_OPTIMIZATION_PRECEDENCE: Tag = Tag(0x3010, 0x0066);
+/// (3010,0067) Conceptual Volume Category Code Sequence
+pub const CONCEPTUAL_VOLUME_CATEGORY_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0067);
+/// (3010,0068) Conceptual Volume Blocking Constraint
+pub const CONCEPTUAL_VOLUME_BLOCKING_CONSTRAINT: Tag = Tag(0x3010, 0x0068);
+/// (3010,0069) Conceptual Volume Type Code Sequence
+pub const CONCEPTUAL_VOLUME_TYPE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0069);
+/// (3010,006A) Conceptual Volume Type Modifier Code Sequence
+pub const CONCEPTUAL_VOLUME_TYPE_MODIFIER_CODE_SEQUENCE: Tag = Tag(0x3010, 0x006A);
+/// (3010,006B) RT Prescription Sequence
+pub const RT_PRESCRIPTION_SEQUENCE: Tag = Tag(0x3010, 0x006B);
+/// (3010,006C) Dosimetric Objective Sequence
+pub const DOSIMETRIC_OBJECTIVE_SEQUENCE: Tag = Tag(0x3010, 0x006C);
+/// (3010,006D) Dosimetric Objective Type Code Sequence
+pub const DOSIMETRIC_OBJECTIVE_TYPE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x006D);
+/// (3010,006E) Dosimetric Objective UID
+pub const DOSIMETRIC_OBJECTIVE_UID: Tag = Tag(0x3010, 0x006E);
+/// (3010,006F) Referenced Dosimetric Objective UID
+pub const REFERENCED_DOSIMETRIC_OBJECTIVE_UID: Tag = Tag(0x3010, 0x006F);
+/// (3010,0070) Dosimetric Objective Parameter Sequence
+pub const DOSIMETRIC_OBJECTIVE_PARAMETER_SEQUENCE: Tag = Tag(0x3010, 0x0070);
+/// (3010,0071) Referenced Dosimetric Objectives Sequence
+pub const REFERENCED_DOSIMETRIC_OBJECTIVES_SEQUENCE: Tag = Tag(0x3010, 0x0071);
+/// (3010,0073) Absolute Dosimetric Objective Flag
+pub const ABSOLUTE_DOSIMETRIC_OBJECTIVE_FLAG: Tag = Tag(0x3010, 0x0073);
+/// (3010,0074) Dosimetric Objective Weight
+pub const DOSIMETRIC_OBJECTIVE_WEIGHT: Tag = Tag(0x3010, 0x0074);
+/// (3010,0075) Dosimetric Objective Purpose
+pub const DOSIMETRIC_OBJECTIVE_PURPOSE: Tag = Tag(0x3010, 0x0075);
+/// (3010,0076) Planning Input Information Sequence
+pub const PLANNING_INPUT_INFORMATION_SEQUENCE: Tag = Tag(0x3010, 0x0076);
+/// (3010,0077) Treatment Site
+pub const TREATMENT_SITE: Tag = Tag(0x3010, 0x0077);
+/// (3010,0078) Treatment Site Code Sequence
+pub const TREATMENT_SITE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0078);
+/// (3010,0079) Fraction Pattern Sequence
+pub const FRACTION_PATTERN_SEQUENCE: Tag = Tag(0x3010, 0x0079);
+/// (3010,007A) Treatment Technique Notes
+pub const TREATMENT_TECHNIQUE_NOTES: Tag = Tag(0x3010, 0x007A);
+/// (3010,007B) Prescription Notes
+pub const PRESCRIPTION_NOTES: Tag = Tag(0x3010, 0x007B);
+/// (3010,007C) Number of Interval Fractions
+pub const NUMBER_OF_INTERVAL_FRACTIONS: Tag = Tag(0x3010, 0x007C);
+/// (3010,007D) Number of Fractions
+pub const NUMBER_OF_FRACTIONS: Tag = Tag(0x3010, 0x007D);
+/// (3010,007E) Intended Delivery Duration
+pub const INTENDED_DELIVERY_DURATION: Tag = Tag(0x3010, 0x007E);
+/// (3010,007F) Fractionation Notes
+pub const FRACTIONATION_NOTES: Tag = Tag(0x3010, 0x007F);
+/// (3010,0080) RT Treatment Technique Code Sequence
+pub const RT_TREATMENT_TECHNIQUE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0080);
+/// (3010,0081) Prescription Notes Sequence
+pub const PRESCRIPTION_NOTES_SEQUENCE: Tag = Tag(0x3010, 0x0081);
+/// (3010,0082) Fraction Based Relationship Sequence
+pub const FRACTION_BASED_RELATIONSHIP_SEQUENCE: Tag = Tag(0x3010, 0x0082);
+/// (3010,0083) Fraction Based Relationship Interval Anchor
+pub const FRACTION_BASED_RELATIONSHIP_INTERVAL_ANCHOR: Tag = Tag(0x3010, 0x0083);
+/// (3010,0084) Minimum Hours Between Fractions
+pub const MINIMUM_HOURS_BETWEEN_FRACTIONS: Tag = Tag(0x3010, 0x0084);
+/// (3010,0085) Intended Fraction Start Time
+pub const INTENDED_FRACTION_START_TIME: Tag = Tag(0x3010, 0x0085);
+/// (3010,0086) Intended Start Day of Week
+pub const INTENDED_START_DAY_OF_WEEK: Tag = Tag(0x3010, 0x0086);
+/// (3010,0087) Weekday Fraction Pattern Sequence
+pub const WEEKDAY_FRACTION_PATTERN_SEQUENCE: Tag = Tag(0x3010, 0x0087);
+/// (3010,0088) Delivery Time Structure Code Sequence
+pub const DELIVERY_TIME_STRUCTURE_CODE_SEQUENCE: Tag = Tag(0x3010, 0x0088);
+/// (4000,0010) Arbitrary (retired)
+pub const ARBITRARY: Tag = Tag(0x4000, 0x0010);
+/// (4000,4000) Text Comments (retired)
+pub const TEXT_COMMENTS: Tag = Tag(0x4000, 0x4000);
+/// (4008,0040) Results ID (retired)
+pub const RESULTS_ID: Tag = Tag(0x4008, 0x0040);
+/// (4008,0042) Results ID Issuer (retired)
+pub const RESULTS_ID_ISSUER: Tag = Tag(0x4008, 0x0042);
+/// (4008,0050) Referenced Interpretation Sequence (retired)
+pub const REFERENCED_INTERPRETATION_SEQUENCE: Tag = Tag(0x4008, 0x0050);
+/// (4008,00FF) Report Production Status Trial (retired)
+pub const REPORT_PRODUCTION_STATUS_TRIAL: Tag = Tag(0x4008, 0x00FF);
+/// (4008,0100) Interpretation Recorded Date (retired)
+pub const INTERPRETATION_RECORDED_DATE: Tag = Tag(0x4008, 0x0100);
+/// (4008,0101) Interpretation Recorded Time (retired)
+pub const INTERPRETATION_RECORDED_TIME: Tag = Tag(0x4008, 0x0101);
+/// (4008,0102) Interpretation Recorder (retired)
+pub const INTERPRETATION_RECORDER: Tag = Tag(0x4008, 0x0102);
+/// (4008,0103) Reference to Recorded Sound (retired)
+pub const REFERENCE_TO_RECORDED_SOUND: Tag = Tag(0x4008, 0x0103);
+/// (4008,0108) Interpretation Transcription Date (retired)
+pub const INTERPRETATION_TRANSCRIPTION_DATE: Tag = Tag(0x4008, 0x0108);
+/// (4008,0109) Interpretation Transcription Time (retired)
+pub const INTERPRETATION_TRANSCRIPTION_TIME: Tag = Tag(0x4008, 0x0109);
+/// (4008,010A) Interpretation Transcriber (retired)
+pub const INTERPRETATION_TRANSCRIBER: Tag = Tag(0x4008, 0x010A);
+/// (4008,010B) Interpretation Text (retired)
+pub const INTERPRETATION_TEXT: Tag = Tag(0x4008, 0x010B);
+/// (4008,010C) Interpretation Author (retired)
+pub const INTERPRETATION_AUTHOR: Tag = Tag(0x4008, 0x010C);
+/// (4008,0111) Interpretation Approver Sequence (retired)
+pub const INTERPRETATION_APPROVER_SEQUENCE: Tag = Tag(0x4008, 0x0111);
+/// (4008,0112) Interpretation Approval Date (retired)
+pub const INTERPRETATION_APPROVAL_DATE: Tag = Tag(0x4008, 0x0112);
+/// (4008,0113) Interpretation Approval Time (retired)
+pub const INTERPRETATION_APPROVAL_TIME: Tag = Tag(0x4008, 0x0113);
+/// (4008,0114) Physician Approving Interpretation (retired)
+pub const PHYSICIAN_APPROVING_INTERPRETATION: Tag = Tag(0x4008, 0x0114);
+/// (4008,0115) Interpretation Diagnosis Description (retired)
+pub const INTERPRETATION_DIAGNOSIS_DESCRIPTION: Tag = Tag(0x4008, 0x0115);
+/// (4008,0117) Interpretation Diagnosis Code Sequence (retired)
+pub const INTERPRETATION_DIAGNOSIS_CODE_SEQUENCE: Tag = Tag(0x4008, 0x0117);
+/// (4008,0118) Results Distribution List Sequence (retired)
+pub const RESULTS_DISTRIBUTION_LIST_SEQUENCE: Tag = Tag(0x4008, 0x0118);
+/// (4008,0119) Distribution Name (retired)
+pub const DISTRIBUTION_NAME: Tag = Tag(0x4008, 0x0119);
+/// (4008,011A) Distribution Address (retired)
+pub const DISTRIBUTION_ADDRESS: Tag = Tag(0x4008, 0x011A);
+/// (4008,0200) Interpretation ID (retired)
+pub const INTERPRETATION_ID: Tag = Tag(0x4008, 0x0200);
+/// (4008,0202) Interpretation ID Issuer (retired)
+pub const INTERPRETATION_ID_ISSUER: Tag = Tag(0x4008, 0x0202);
+/// (4008,0210) Interpretation Type ID (retired)
+pub const INTERPRETATION_TYPE_ID: Tag = Tag(0x4008, 0x0210);
+/// (4008,0212) Interpretation Status ID (retired)
+pub const INTERPRETATION_STATUS_ID: Tag = Tag(0x4008, 0x0212);
+/// (4008,0300) Impressions (retired)
+pub const IMPRESSIONS: Tag = Tag(0x4008, 0x0300);
+/// (4008,4000) Results Comments (retired)
+pub const RESULTS_COMMENTS: Tag = Tag(0x4008, 0x4000);
+/// (4010,0001) Low Energy Detectors
+pub const LOW_ENERGY_DETECTORS: Tag = Tag(0x4010, 0x0001);
+/// (4010,0002) High Energy Detectors
+pub const HIGH_ENERGY_DETECTORS: Tag = Tag(0x4010, 0x0002);
+/// (4010,0004) Detector Geometry Sequence
+pub const DETECTOR_GEOMETRY_SEQUENCE: Tag = Tag(0x4010, 0x0004);
+/// (4010,1001) Threat ROI Voxel Sequence
+pub const THREAT_ROI_VOXEL_SEQUENCE: Tag = Tag(0x4010, 0x1001);
+/// (4010,1004) Threat ROI Base
+pub const THREAT_ROI_BASE: Tag = Tag(0x4010, 0x1004);
+/// (4010,1005) Threat ROI Extents
+pub const THREAT_ROI_EXTENTS: Tag = Tag(0x4010, 0x1005);
+/// (4010,1006) Threat ROI Bitmap
+pub const THREAT_ROI_BITMAP: Tag = Tag(0x4010, 0x1006);
+/// (4010,1007) Route Segment ID
+pub const ROUTE_SEGMENT_ID: Tag = Tag(0x4010, 0x1007);
+/// (4010,1008) Gantry Type
+pub const GANTRY_TYPE: Tag = Tag(0x4010, 0x1008);
+/// (4010,1009) OOI Owner Type
+pub const OOI_OWNER_TYPE: Tag = Tag(0x4010, 0x1009);
+/// (4010,100A) Route Segment Sequence
+pub const ROUTE_SEGMENT_SEQUENCE: Tag = Tag(0x4010, 0x100A);
+/// (4010,1010) Potential Threat Object ID
+pub const POTENTIAL_THREAT_OBJECT_ID: Tag = Tag(0x4010, 0x1010);
+/// (4010,1011) Threat Sequence
+pub const THREAT_SEQUENCE: Tag = Tag(0x4010, 0x1011);
+/// (4010,1012) Threat Category
+pub const THREAT_CATEGORY: Tag = Tag(0x4010, 0x1012);
+/// (4010,1013) Threat Category Description
+pub const THREAT_CATEGORY_DESCRIPTION: Tag = Tag(0x4010, 0x1013);
+/// (4010,1014) ATD Ability Assessment
+pub const ATD_ABILITY_ASSESSMENT: Tag = Tag(0x4010, 0x1014);
+/// (4010,1015) ATD Assessment Flag
+pub const ATD_ASSESSMENT_FLAG: Tag = Tag(0x4010, 0x1015);
+/// (4010,1016) ATD Assessment Probability
+pub const ATD_ASSESSMENT_PROBABILITY: Tag = Tag(0x4010, 0x1016);
+/// (4010,1017) Mass
+pub const MASS: Tag = Tag(0x4010, 0x1017);
+/// (4010,1018) Density
+pub const DENSITY: Tag = Tag(0x4010, 0x1018);
+/// (4010,1019) Z Effective
+pub const Z_EFFECTIVE: Tag = Tag(0x4010, 0x1019);
+/// (4010,101A) Boarding Pass ID
+pub const BOARDING_PASS_ID: Tag = Tag(0x4010, 0x101A);
+/// (4010,101B) Center of Mass
+pub const CENTER_OF_MASS: Tag = Tag(0x4010, 0x101B);
+/// (4010,101C) Center of PTO
+pub const CENTER_OF_PTO: Tag = Tag(0x4010, 0x101C);
+/// (4010,101D) Bounding Polygon
+pub const BOUNDING_POLYGON: Tag = Tag(0x4010, 0x101D);
+/// (4010,101E) Route Segment Start Location ID
+pub const ROUTE_SEGMENT_START_LOCATION_ID: Tag = Tag(0x4010, 0x101E);
+/// (4010,101F) Route Segment End Location ID
+pub const ROUTE_SEGMENT_END_LOCATION_ID: Tag = Tag(0x4010, 0x101F);
+/// (4010,1020) Route Segment Location ID Type
+pub const ROUTE_SEGMENT_LOCATION_ID_TYPE: Tag = Tag(0x4010, 0x1020);
+/// (4010,1021) Abort Reason
+pub const ABORT_REASON: Tag = Tag(0x4010, 0x1021);
+/// (4010,1023) Volume of PTO
+pub const VOLUME_OF_PTO: Tag = Tag(0x4010, 0x1023);
+/// (4010,1024) Abort Flag
+pub const ABORT_FLAG: Tag = Tag(0x4010, 0x1024);
+/// (4010,1025) Route Segment Start Time
+pub const ROUTE_SEGMENT_START_TIME: Tag = Tag(0x4010, 0x1025);
+/// (4010,1026) Route Segment End Time
+pub const ROUTE_SEGMENT_END_TIME: Tag = Tag(0x4010, 0x1026);
+/// (4010,1027) TDR Type
+pub const TDR_TYPE: Tag = Tag(0x4010, 0x1027);
+/// (4010,1028) International Route Segment
+pub const INTERNATIONAL_ROUTE_SEGMENT: Tag = Tag(0x4010, 0x1028);
+/// (4010,1029) Threat Detection Algorithmand Version
+pub const THREAT_DETECTION_ALGORITHMAND_VERSION: Tag = Tag(0x4010, 0x1029);
+/// (4010,102A) Assigned Location
+pub const ASSIGNED_LOCATION: Tag = Tag(0x4010, 0x102A);
+/// (4010,102B) Alarm Decision Time
+pub const ALARM_DECISION_TIME: Tag = Tag(0x4010, 0x102B);
+/// (4010,1031) Alarm Decision
+pub const ALARM_DECISION: Tag = Tag(0x4010, 0x1031);
+/// (4010,1033) Number of Total Objects
+pub const NUMBER_OF_TOTAL_OBJECTS: Tag = Tag(0x4010, 0x1033);
+/// (4010,1034) Number of Alarm Objects
+pub const NUMBER_OF_ALARM_OBJECTS: Tag = Tag(0x4010, 0x1034);
+/// (4010,1037) PTO Representation Sequence
+pub const PTO_REPRESENTATION_SEQUENCE: Tag = Tag(0x4010, 0x1037);
+/// (4010,1038) ATD Assessment Sequence
+pub const ATD_ASSESSMENT_SEQUENCE: Tag = Tag(0x4010, 0x1038);
+/// (4010,1039) TIP Type
+pub const TIP_TYPE: Tag = Tag(0x4010, 0x1039);
+/// (4010,103A) DICOS Version
+pub const DICOS_VERSION: Tag = Tag(0x4010, 0x103A);
+/// (4010,1041) OOI Owner Creation Time
+pub const OOI_OWNER_CREATION_TIME: Tag = Tag(0x4010, 0x1041);
+/// (4010,1042) OOI Type
+pub const OOI_TYPE: Tag = Tag(0x4010, 0x1042);
+/// (4010,1043) OOI Size
+pub const OOI_SIZE: Tag = Tag(0x4010, 0x1043);
+/// (4010,1044) Acquisition Status
+pub const ACQUISITION_STATUS: Tag = Tag(0x4010, 0x1044);
+/// (4010,1045) Basis Materials Code Sequence
+pub const BASIS_MATERIALS_CODE_SEQUENCE: Tag = Tag(0x4010, 0x1045);
+/// (4010,1046) Phantom Type
+pub const PHANTOM_TYPE: Tag = Tag(0x4010, 0x1046);
+/// (4010,1047) OOI Owner Sequence
+pub const OOI_OWNER_SEQUENCE: Tag = Tag(0x4010, 0x1047);
+/// (4010,1048) Scan Type
+pub const SCAN_TYPE: Tag = Tag(0x4010, 0x1048);
+/// (4010,1051) Itinerary ID
+pub const ITINERARY_ID: Tag = Tag(0x4010, 0x1051);
+/// (4010,1052) Itinerary ID Type
+pub const ITINERARY_ID_TYPE: Tag = Tag(0x4010, 0x1052);
+/// (4010,1053) Itinerary ID Assigning Authority
+pub const ITINERARY_ID_ASSIGNING_AUTHORITY: Tag = Tag(0x4010, 0x1053);
+/// (4010,1054) Route ID
+pub const ROUTE_ID: Tag = Tag(0x4010, 0x1054);
+/// (4010,1055) Route ID Assigning Authority
+pub const ROUTE_ID_ASSIGNING_AUTHORITY: Tag = Tag(0x4010, 0x1055);
+/// (4010,1056) Inbound Arrival Type
+pub const INBOUND_ARRIVAL_TYPE: Tag = Tag(0x4010, 0x1056);
+/// (4010,1058) Carrier ID
+pub const CARRIER_ID: Tag = Tag(0x4010, 0x1058);
+/// (4010,1059) Carrier ID Assigning Authority
+pub const CARRIER_ID_ASSIGNING_AUTHORITY: Tag = Tag(0x4010, 0x1059);
+/// (4010,1060) Source Orientation
+pub const SOURCE_ORIENTATION: Tag = Tag(0x4010, 0x1060);
+/// (4010,1061) Source Position
+pub const SOURCE_POSITION: Tag = Tag(0x4010, 0x1061);
+/// (4010,1062) Belt Height
+pub const BELT_HEIGHT: Tag = Tag(0x4010, 0x1062);
+/// (4010,1064) Algorithm Routing Code Sequence
+pub const ALGORITHM_ROUTING_CODE_SEQUENCE: Tag = Tag(0x4010, 0x1064);
+/// (4010,1067) Transport Classification
+pub const TRANSPORT_CLASSIFICATION: Tag = Tag(0x4010, 0x1067);
+/// (4010,1068) OOI Type Descriptor
+pub const OOI_TYPE_DESCRIPTOR: Tag = Tag(0x4010, 0x1068);
+/// (4010,1069) Total Processing Time
+pub const TOTAL_PROCESSING_TIME: Tag = Tag(0x4010, 0x1069);
+/// (4010,106C) Detector Calibration Data
+pub const DETECTOR_CALIBRATION_DATA: Tag = Tag(0x4010, 0x106C);
+/// (4010,106D) Additional Screening Performed
+pub const ADDITIONAL_SCREENING_PERFORMED: Tag = Tag(0x4010, 0x106D);
+/// (4010,106E) Additional Inspection Selection Criteria
+pub const ADDITIONAL_INSPECTION_SELECTION_CRITERIA: Tag = Tag(0x4010, 0x106E);
+/// (4010,106F) Additional Inspection Method Sequence
+pub const ADDITIONAL_INSPECTION_METHOD_SEQUENCE: Tag = Tag(0x4010, 0x106F);
+/// (4010,1070) AIT Device Type
+pub const AIT_DEVICE_TYPE: Tag = Tag(0x4010, 0x1070);
+/// (4010,1071) QR Measurements Sequence
+pub const QR_MEASUREMENTS_SEQUENCE: Tag = Tag(0x4010, 0x1071);
+/// (4010,1072) Target Material Sequence
+pub const TARGET_MATERIAL_SEQUENCE: Tag = Tag(0x4010, 0x1072);
+/// (4010,1073) SNR Threshold
+pub const SNR_THRESHOLD: Tag = Tag(0x4010, 0x1073);
+/// (4010,1075) Image Scale Representation
+pub const IMAGE_SCALE_REPRESENTATION: Tag = Tag(0x4010, 0x1075);
+/// (4010,1076) Referenced PTO Sequence
+pub const REFERENCED_PTO_SEQUENCE: Tag = Tag(0x4010, 0x1076);
+/// (4010,1077) Referenced TDR Instance Sequence
+pub const REFERENCED_TDR_INSTANCE_SEQUENCE: Tag = Tag(0x4010, 0x1077);
+/// (4010,1078) PTO Location Description
+pub const PTO_LOCATION_DESCRIPTION: Tag = Tag(0x4010, 0x1078);
+/// (4010,1079) Anomaly Locator Indicator Sequence
+pub const ANOMALY_LOCATOR_INDICATOR_SEQUENCE: Tag = Tag(0x4010, 0x1079);
+/// (4010,107A) Anomaly Locator Indicator
+pub const ANOMALY_LOCATOR_INDICATOR: Tag = Tag(0x4010, 0x107A);
+/// (4010,107B) PTO Region Sequence
+pub const PTO_REGION_SEQUENCE: Tag = Tag(0x4010, 0x107B);
+/// (4010,107C) Inspection Selection Criteria
+pub const INSPECTION_SELECTION_CRITERIA: Tag = Tag(0x4010, 0x107C);
+/// (4010,107D) Secondary Inspection Method Sequence
+pub const SECONDARY_INSPECTION_METHOD_SEQUENCE: Tag = Tag(0x4010, 0x107D);
+/// (4010,107E) PRCS to RCS Orientation
+pub const PRCS_TO_RCS_ORIENTATION: Tag = Tag(0x4010, 0x107E);
+/// (4FFE,0001) MAC Parameters Sequence
+pub const MAC_PARAMETERS_SEQUENCE: Tag = Tag(0x4FFE, 0x0001);
+/// (50xx,0005) Curve Dimensions (retired)
+pub const CURVE_DIMENSIONS: Tag = Tag(0x5000, 0x0005);
+/// (50xx,0010) Number of Points (retired)
+pub const NUMBER_OF_POINTS: Tag = Tag(0x5000, 0x0010);
+/// (50xx,0020) Type of Data (retired)
+pub const TYPE_OF_DATA: Tag = Tag(0x5000, 0x0020);
+/// (50xx,0022) Curve Description (retired)
+pub const CURVE_DESCRIPTION: Tag = Tag(0x5000, 0x0022);
+/// (50xx,0030) Axis Units (retired)
+pub const AXIS_UNITS: Tag = Tag(0x5000, 0x0030);
+/// (50xx,0040) Axis Labels (retired)
+pub const AXIS_LABELS: Tag = Tag(0x5000, 0x0040);
+/// (50xx,0103) Data Value Representation (retired)
+pub const DATA_VALUE_REPRESENTATION: Tag = Tag(0x5000, 0x0103);
+/// (50xx,0104) Minimum Coordinate Value (retired)
+pub const MINIMUM_COORDINATE_VALUE: Tag = Tag(0x5000, 0x0104);
+/// (50xx,0105) Maximum Coordinate Value (retired)
+pub const MAXIMUM_COORDINATE_VALUE: Tag = Tag(0x5000, 0x0105);
+/// (50xx,0106) Curve Range (retired)
+pub const CURVE_RANGE: Tag = Tag(0x5000, 0x0106);
+/// (50xx,0110) Curve Data Descriptor (retired)
+pub const CURVE_DATA_DESCRIPTOR: Tag = Tag(0x5000, 0x0110);
+/// (50xx,0112) Coordinate Start Value (retired)
+pub const COORDINATE_START_VALUE: Tag = Tag(0x5000, 0x0112);
+/// (50xx,0114) Coordinate Step Value (retired)
+pub const COORDINATE_STEP_VALUE: Tag = Tag(0x5000, 0x0114);
+/// (50xx,1001) Curve Activation Layer (retired)
+pub const CURVE_ACTIVATION_LAYER: Tag = Tag(0x5000, 0x1001);
+/// (50xx,2000) Audio Type (retired)
+pub const AUDIO_TYPE: Tag = Tag(0x5000, 0x2000);
+/// (50xx,2002) Audio Sample Format (retired)
+pub const AUDIO_SAMPLE_FORMAT: Tag = Tag(0x5000, 0x2002);
+/// (50xx,2004) Number of Channels (retired)
+pub const NUMBER_OF_CHANNELS: Tag = Tag(0x5000, 0x2004);
+/// (50xx,2006) Number of Samples (retired)
+pub const NUMBER_OF_SAMPLES: Tag = Tag(0x5000, 0x2006);
+/// (50xx,2008) Sample Rate (retired)
+pub const SAMPLE_RATE: Tag = Tag(0x5000, 0x2008);
+/// (50xx,200A) Total Time (retired)
+pub const TOTAL_TIME: Tag = Tag(0x5000, 0x200A);
+/// (50xx,200C) Audio Sample Data (retired)
+pub const AUDIO_SAMPLE_DATA: Tag = Tag(0x5000, 0x200C);
+/// (50xx,200E) Audio Comments (retired)
+pub const AUDIO_COMMENTS: Tag = Tag(0x5000, 0x200E);
+/// (50xx,2500) Curve Label (retired)
+pub const CURVE_LABEL: Tag = Tag(0x5000, 0x2500);
+/// (50xx,2600) Curve Referenced Overlay Sequence (retired)
+pub const CURVE_REFERENCED_OVERLAY_SEQUENCE: Tag = Tag(0x5000, 0x2600);
+/// (50xx,2610) Curve Referenced Overlay Group (retired)
+pub const CURVE_REFERENCED_OVERLAY_GROUP: Tag = Tag(0x5000, 0x2610);
+/// (50xx,3000) Curve Data (retired)
+pub const CURVE_DATA: Tag = Tag(0x5000, 0x3000);
+/// (5200,9229) Shared Functional Groups Sequence
+pub const SHARED_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag(0x5200, 0x9229);
+/// (5200,9230) Per Frame Functional Groups Sequence
+pub const PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag(0x5200, 0x9230);
+/// (5400,0100) Waveform Sequence
+pub const WAVEFORM_SEQUENCE: Tag = Tag(0x5400, 0x0100);
+/// (5400,0110) Channel Minimum Value
+pub const CHANNEL_MINIMUM_VALUE: Tag = Tag(0x5400, 0x0110);
+/// (5400,0112) Channel Maximum Value
+pub const CHANNEL_MAXIMUM_VALUE: Tag = Tag(0x5400, 0x0112);
+/// (5400,1004) Waveform Bits Allocated
+pub const WAVEFORM_BITS_ALLOCATED: Tag = Tag(0x5400, 0x1004);
+/// (5400,1006) Waveform Sample Interpretation
+pub const WAVEFORM_SAMPLE_INTERPRETATION: Tag = Tag(0x5400, 0x1006);
+/// (5400,100A) Waveform Padding Value
+pub const WAVEFORM_PADDING_VALUE: Tag = Tag(0x5400, 0x100A);
+/// (5400,1010) Waveform Data
+pub const WAVEFORM_DATA: Tag = Tag(0x5400, 0x1010);
+/// (5600,0010) First Order Phase Correction Angle
+pub const FIRST_ORDER_PHASE_CORRECTION_ANGLE: Tag = Tag(0x5600, 0x0010);
+/// (5600,0020) Spectroscopy Data
+pub const SPECTROSCOPY_DATA: Tag = Tag(0x5600, 0x0020);
+/// (60xx,0010) Overlay Rows
+pub const OVERLAY_ROWS: Tag = Tag(0x6000, 0x0010);
+/// (60xx,0011) Overlay Columns
+pub const OVERLAY_COLUMNS: Tag = Tag(0x6000, 0x0011);
+/// (60xx,0012) Overlay Planes (retired)
+pub const OVERLAY_PLANES: Tag = Tag(0x6000, 0x0012);
+/// (60xx,0015) Number of Frames in Overlay
+pub const NUMBER_OF_FRAMES_IN_OVERLAY: Tag = Tag(0x6000, 0x0015);
+/// (60xx,0022) Overlay Description
+pub const OVERLAY_DESCRIPTION: Tag = Tag(0x6000, 0x0022);
+/// (60xx,0040) Overlay Type
+pub const OVERLAY_TYPE: Tag = Tag(0x6000, 0x0040);
+/// (60xx,0045) Overlay Subtype
+pub const OVERLAY_SUBTYPE: Tag = Tag(0x6000, 0x0045);
+/// (60xx,0050) Overlay Origin
+pub const OVERLAY_ORIGIN: Tag = Tag(0x6000, 0x0050);
+/// (60xx,0051) Image Frame Origin
+pub const IMAGE_FRAME_ORIGIN: Tag = Tag(0x6000, 0x0051);
+/// (60xx,0052) Overlay Plane Origin (retired)
+pub const OVERLAY_PLANE_ORIGIN: Tag = Tag(0x6000, 0x0052);
+/// (60xx,0060) Overlay Compression Code (retired)
+pub const OVERLAY_COMPRESSION_CODE: Tag = Tag(0x6000, 0x0060);
+/// (60xx,0061) Overlay Compression Originator (retired)
+pub const OVERLAY_COMPRESSION_ORIGINATOR: Tag = Tag(0x6000, 0x0061);
+/// (60xx,0062) Overlay Compression Label (retired)
+pub const OVERLAY_COMPRESSION_LABEL: Tag = Tag(0x6000, 0x0062);
+/// (60xx,0063) Overlay Compression Description (retired)
+pub const OVERLAY_COMPRESSION_DESCRIPTION: Tag = Tag(0x6000, 0x0063);
+/// (60xx,0066) Overlay Compression Step Pointers (retired)
+pub const OVERLAY_COMPRESSION_STEP_POINTERS: Tag = Tag(0x6000, 0x0066);
+/// (60xx,0068) Overlay Repeat Interval (retired)
+pub const OVERLAY_REPEAT_INTERVAL: Tag = Tag(0x6000, 0x0068);
+/// (60xx,0069) Overlay Bits Grouped (retired)
+pub const OVERLAY_BITS_GROUPED: Tag = Tag(0x6000, 0x0069);
+/// (60xx,0100) Overlay Bits Allocated
+pub const OVERLAY_BITS_ALLOCATED: Tag = Tag(0x6000, 0x0100);
+/// (60xx,0102) Overlay Bit Position
+pub const OVERLAY_BIT_POSITION: Tag = Tag(0x6000, 0x0102);
+/// (60xx,0110) Overlay Format (retired)
+pub const OVERLAY_FORMAT: Tag = Tag(0x6000, 0x0110);
+/// (60xx,0200) Overlay Location (retired)
+pub const OVERLAY_LOCATION: Tag = Tag(0x6000, 0x0200);
+/// (60xx,0800) Overlay Code Label (retired)
+pub const OVERLAY_CODE_LABEL: Tag = Tag(0x6000, 0x0800);
+/// (60xx,0802) Overlay Number of Tables (retired)
+pub const OVERLAY_NUMBER_OF_TABLES: Tag = Tag(0x6000, 0x0802);
+/// (60xx,0803) Overlay Code Table Location (retired)
+pub const OVERLAY_CODE_TABLE_LOCATION: Tag = Tag(0x6000, 0x0803);
+/// (60xx,0804) Overlay Bits for Code Word (retired)
+pub const OVERLAY_BITS_FOR_CODE_WORD: Tag = Tag(0x6000, 0x0804);
+/// (60xx,1001) Overlay Activation Layer
+pub const OVERLAY_ACTIVATION_LAYER: Tag = Tag(0x6000, 0x1001);
+/// (60xx,1100) Overlay Descriptor Gray (retired)
+pub const OVERLAY_DESCRIPTOR_GRAY: Tag = Tag(0x6000, 0x1100);
+/// (60xx,1101) Overlay Descriptor Red (retired)
+pub const OVERLAY_DESCRIPTOR_RED: Tag = Tag(0x6000, 0x1101);
+/// (60xx,1102) Overlay Descriptor Green (retired)
+pub const OVERLAY_DESCRIPTOR_GREEN: Tag = Tag(0x6000, 0x1102);
+/// (60xx,1103) Overlay Descriptor Blue (retired)
+pub const OVERLAY_DESCRIPTOR_BLUE: Tag = Tag(0x6000, 0x1103);
+/// (60xx,1200) Overlays Gray (retired)
+pub const OVERLAYS_GRAY: Tag = Tag(0x6000, 0x1200);
+/// (60xx,1201) Overlays Red (retired)
+pub const OVERLAYS_RED: Tag = Tag(0x6000, 0x1201);
+/// (60xx,1202) Overlays Green (retired)
+pub const OVERLAYS_GREEN: Tag = Tag(0x6000, 0x1202);
+/// (60xx,1203) Overlays Blue (retired)
+pub const OVERLAYS_BLUE: Tag = Tag(0x6000, 0x1203);
+/// (60xx,1301) ROI Area
+pub const ROI_AREA: Tag = Tag(0x6000, 0x1301);
+/// (60xx,1302) ROI Mean
+pub const ROI_MEAN: Tag = Tag(0x6000, 0x1302);
+/// (60xx,1303) ROI Standard Deviation
+pub const ROI_STANDARD_DEVIATION: Tag = Tag(0x6000, 0x1303);
+/// (60xx,1500) Overlay Label
+pub const OVERLAY_LABEL: Tag = Tag(0x6000, 0x1500);
+/// (60xx,3000) Overlay Data
+pub const OVERLAY_DATA: Tag = Tag(0x6000, 0x3000);
+/// (60xx,4000) Overlay Comments (retired)
+pub const OVERLAY_COMMENTS: Tag = Tag(0x6000, 0x4000);
+/// (7FE0,0001) Extended Offset Table
+pub const EXTENDED_OFFSET_TABLE: Tag = Tag(0x7FE0, 0x0001);
+/// (7FE0,0002) Extended Offset Table Lengths
+pub const EXTENDED_OFFSET_TABLE_LENGTHS: Tag = Tag(0x7FE0, 0x0002);
+/// (7FE0,0008) Float Pixel Data
+pub const FLOAT_PIXEL_DATA: Tag = Tag(0x7FE0, 0x0008);
+/// (7FE0,0009) Double Float Pixel Data
+pub const DOUBLE_FLOAT_PIXEL_DATA: Tag = Tag(0x7FE0, 0x0009);
+/// (7FE0,0010) Pixel Data
+pub const PIXEL_DATA: Tag = Tag(0x7FE0, 0x0010);
+/// (7FE0,0020) Coefficients SDVN (retired)
+pub const COEFFICIENTS_SDVN: Tag = Tag(0x7FE0, 0x0020);
+/// (7FE0,0030) Coefficients SDHN (retired)
+pub const COEFFICIENTS_SDHN: Tag = Tag(0x7FE0, 0x0030);
+/// (7FE0,0040) Coefficients SDDN (retired)
+pub const COEFFICIENTS_SDDN: Tag = Tag(0x7FE0, 0x0040);
+/// (7Fxx,0010) Variable Pixel Data (retired)
+pub const VARIABLE_PIXEL_DATA: Tag = Tag(0x7F00, 0x0010);
+/// (7Fxx,0011) Variable Next Data Group (retired)
+pub const VARIABLE_NEXT_DATA_GROUP: Tag = Tag(0x7F00, 0x0011);
+/// (7Fxx,0020) Variable Coefficients SDVN (retired)
+pub const VARIABLE_COEFFICIENTS_SDVN: Tag = Tag(0x7F00, 0x0020);
+/// (7Fxx,0030) Variable Coefficients SDHN (retired)
+pub const VARIABLE_COEFFICIENTS_SDHN: Tag = Tag(0x7F00, 0x0030);
+/// (7Fxx,0040) Variable Coefficients SDDN (retired)
+pub const VARIABLE_COEFFICIENTS_SDDN: Tag = Tag(0x7F00, 0x0040);
+/// (FFFA,FFFA) Digital Signatures Sequence
+pub const DIGITAL_SIGNATURES_SEQUENCE: Tag = Tag(0xFFFA, 0xFFFA);
+/// (FFFC,FFFC) Data Set Trailing Padding
+pub const DATA_SET_TRAILING_PADDING: Tag = Tag(0xFFFC, 0xFFFC);
+/// (FFFE,E000) Item
+pub const ITEM: Tag = Tag(0xFFFE, 0xE000);
+/// (FFFE,E00D) Item Delimitation Item
+pub const ITEM_DELIMITATION_ITEM: Tag = Tag(0xFFFE, 0xE00D);
+/// (FFFE,E0DD) Sequence Delimitation Item
+pub const SEQUENCE_DELIMITATION_ITEM: Tag = Tag(0xFFFE, 0xE0DD);
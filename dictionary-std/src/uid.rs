@@ -0,0 +1,155 @@
+//! This module implements the standard registry of unique identifiers,
+//! as in DICOM PS3.6 Table A-1.
+//!
+//! It enables resolving UIDs such as SOP classes and transfer syntaxes
+//! into their human readable names, which is useful when printing
+//! DICOM objects or reporting on the presentation contexts of an association.
+
+use crate::uids::UIDS;
+use dicom_core::dictionary::uid::{UidDictionary, UidDictionaryEntryRef};
+
+pub use dicom_core::dictionary::uid::trim_uid;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+lazy_static! {
+    static ref UID_DICT: StandardUidRegistry = init_uid_dictionary();
+}
+
+/// Retrieve a singleton instance of the standard UID registry.
+pub fn uid_registry() -> &'static StandardUidRegistry {
+    &UID_DICT
+}
+
+/// The data struct containing the standard UID registry.
+#[derive(Debug)]
+pub struct StandardUidRegistry {
+    /// mapping: UID → entry
+    by_uid: HashMap<&'static str, &'static UidDictionaryEntryRef<'static>>,
+    /// mapping: keyword → entry
+    by_keyword: HashMap<&'static str, &'static UidDictionaryEntryRef<'static>>,
+}
+
+impl StandardUidRegistry {
+    fn new() -> StandardUidRegistry {
+        StandardUidRegistry {
+            by_uid: HashMap::with_capacity(UIDS.len()),
+            by_keyword: HashMap::with_capacity(UIDS.len()),
+        }
+    }
+
+    /// record the given UID entry reference
+    fn index(&mut self, entry: &'static UidDictionaryEntryRef<'static>) -> &mut Self {
+        self.by_uid.insert(entry.uid, entry);
+        if !entry.keyword.is_empty() {
+            self.by_keyword.insert(entry.keyword, entry);
+        }
+        self
+    }
+}
+
+/// A UID dictionary which consults the library's global UID registry.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StandardUidDictionary;
+
+impl StandardUidDictionary {
+    /// Fetch an entry by its UID value,
+    /// ignoring any trailing padding.
+    pub fn by_uid(uid: &str) -> Option<&'static UidDictionaryEntryRef<'static>> {
+        uid_registry().by_uid.get(trim_uid(uid)).cloned()
+    }
+
+    /// Fetch an entry by its keyword.
+    pub fn by_keyword(keyword: &str) -> Option<&'static UidDictionaryEntryRef<'static>> {
+        uid_registry().by_keyword.get(keyword).cloned()
+    }
+
+    /// Retrieve the name of the given UID if it is registered.
+    pub fn name_of(uid: &str) -> Option<&'static str> {
+        StandardUidDictionary::by_uid(uid).map(|e| e.name)
+    }
+
+    /// Describe a UID with its registered name, if it is known,
+    /// as in `1.2.840.10008.1.1 (Verification SOP Class)`.
+    /// The padding of the UID is removed.
+    pub fn summary(uid: &str) -> String {
+        let uid = trim_uid(uid);
+        match StandardUidDictionary::name_of(uid) {
+            Some(name) => format!("{} ({})", uid, name),
+            None => uid.to_string(),
+        }
+    }
+
+    /// Iterate over all entries of the registry, in the order of PS3.6.
+    pub fn iter() -> std::slice::Iter<'static, UidDictionaryEntryRef<'static>> {
+        UIDS.iter()
+    }
+}
+
+impl UidDictionary for StandardUidDictionary {
+    fn by_uid(&self, uid: &str) -> Option<&UidDictionaryEntryRef<'_>> {
+        StandardUidDictionary::by_uid(uid)
+    }
+
+    fn by_keyword(&self, keyword: &str) -> Option<&UidDictionaryEntryRef<'_>> {
+        StandardUidDictionary::by_keyword(keyword)
+    }
+}
+
+impl Display for StandardUidDictionary {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        f.write_str("Standard DICOM UID Registry")
+    }
+}
+
+fn init_uid_dictionary() -> StandardUidRegistry {
+    let mut d = StandardUidRegistry::new();
+    for entry in UIDS {
+        d.index(entry);
+    }
+    d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StandardUidDictionary;
+    use dicom_core::dictionary::uid::{UidDictionary, UidType};
+
+    #[test]
+    fn uid_lookup() {
+        let verification = StandardUidDictionary::by_uid("1.2.840.10008.1.1")
+            .expect("Verification SOP class should exist");
+        assert_eq!(verification.name, "Verification SOP Class");
+        assert_eq!(verification.keyword, "Verification");
+        assert_eq!(verification.uid_type, UidType::SopClass);
+        assert!(!verification.retired);
+
+        // padded UIDs are also accepted
+        let ts = StandardUidDictionary::by_uid("1.2.840.10008.1.2.1\0").unwrap();
+        assert_eq!(ts.name, "Explicit VR Little Endian");
+        assert_eq!(ts.uid_type, UidType::TransferSyntax);
+
+        let big_endian = StandardUidDictionary
+            .by_keyword("ExplicitVRBigEndian")
+            .unwrap();
+        assert_eq!(big_endian.uid, "1.2.840.10008.1.2.2");
+        assert!(big_endian.retired);
+
+        assert_eq!(
+            StandardUidDictionary::name_of("1.2.840.10008.5.1.4.1.1.2"),
+            Some("CT Image Storage")
+        );
+        assert_eq!(StandardUidDictionary::name_of("1.2.3.4"), None);
+
+        assert_eq!(
+            StandardUidDictionary::summary("1.2.840.10008.1.1\0"),
+            "1.2.840.10008.1.1 (Verification SOP Class)"
+        );
+        assert_eq!(StandardUidDictionary::summary("1.2.3.4 "), "1.2.3.4");
+
+        assert!(StandardUidDictionary::iter()
+            .any(|e| e.keyword == "CTImageStorage" && e.uid_type == UidType::SopClass));
+    }
+}
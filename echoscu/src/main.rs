@@ -0,0 +1,133 @@
+//! A simple application that verifies the connection to a DICOM node
+//! by sending a C-ECHO request, in the spirit of DCMTK's `echoscu`.
+//!
+//! The association negotiation details and the status of the response
+//! are printed to the standard output.
+//! The process exits with a non-zero code
+//! if the association fails or the status is not of success.
+use clap::{App, Arg};
+use dicom_dictionary_std::StandardUidDictionary;
+use dicom_ul::association::{ClientAssociation, ClientAssociationOptions};
+use dicom_ul::services::verification::{self, VERIFICATION_SOP_CLASS};
+use std::process;
+
+/// The transfer syntaxes proposed for the verification SOP class:
+/// Implicit VR Little Endian and Explicit VR Little Endian.
+const TRANSFER_SYNTAXES: &[&str] = &["1.2.840.10008.1.2", "1.2.840.10008.1.2.1"];
+
+fn main() {
+    let matches = App::new("echoscu")
+        .about("Send a C-ECHO request to a DICOM node")
+        .arg(
+            Arg::with_name("addr")
+                .help("The address of the SCP (host:port)")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::with_name("calling-ae-title")
+                .help("The calling AE title (this node)")
+                .long("calling-ae-title")
+                .default_value("ECHOSCU")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("called-ae-title")
+                .help("The called AE title (the SCP)")
+                .long("called-ae-title")
+                .default_value("ANY-SCP")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("message-id")
+                .help("The message ID of the C-ECHO request")
+                .short("m")
+                .long("message-id")
+                .default_value("1")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("verbose")
+                .help("Print the association negotiation details")
+                .short("v")
+                .long("verbose"),
+        )
+        .get_matches();
+
+    let addr = matches.value_of("addr").unwrap();
+    let calling_ae_title = matches.value_of("calling-ae-title").unwrap();
+    let called_ae_title = matches.value_of("called-ae-title").unwrap();
+    let verbose = matches.is_present("verbose");
+    let message_id: u16 = match matches.value_of("message-id").unwrap().parse() {
+        Ok(id) => id,
+        Err(e) => {
+            eprintln!("error: invalid message ID: {}", e);
+            process::exit(2);
+        }
+    };
+
+    let mut association = match ClientAssociationOptions::new()
+        .calling_ae_title(calling_ae_title)
+        .called_ae_title(called_ae_title)
+        .with_presentation_context(VERIFICATION_SOP_CLASS, TRANSFER_SYNTAXES.to_vec())
+        .establish(addr)
+    {
+        Ok(association) => association,
+        Err(e) => {
+            eprintln!("error: could not establish association: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "association established with {} ({} -> {})",
+        addr, calling_ae_title, called_ae_title
+    );
+    if verbose {
+        describe_association(&association);
+    }
+
+    let status = match verification::echo(&mut association, message_id) {
+        Ok(status) => status,
+        Err(e) => {
+            eprintln!("error: C-ECHO failed: {}", e);
+            process::exit(1);
+        }
+    };
+    println!("C-ECHO response status: {}", status);
+
+    if let Err(e) = association.release() {
+        eprintln!("error: could not release association: {}", e);
+        process::exit(1);
+    }
+    if verbose {
+        println!("association released");
+    }
+
+    if !status.is_success() {
+        process::exit(1);
+    }
+}
+
+/// Print the outcome of the association negotiation.
+fn describe_association(association: &ClientAssociation) {
+    println!(
+        "  max PDU length: {} (this node), {} (peer)",
+        association.requestor_max_pdu_length(),
+        match association.acceptor_max_pdu_length() {
+            0 => "unlimited".to_string(),
+            len => len.to_string(),
+        }
+    );
+    for pc in association.presentation_contexts() {
+        println!(
+            "  presentation context {}: {}",
+            pc.id,
+            StandardUidDictionary::summary(&pc.abstract_syntax)
+        );
+        println!(
+            "    transfer syntax: {}",
+            StandardUidDictionary::summary(&pc.transfer_syntax)
+        );
+    }
+}
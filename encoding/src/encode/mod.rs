@@ -2,6 +2,7 @@
 use crate::error::Result;
 use byteordered::Endianness;
 use dicom_core::{DataElementHeader, PrimitiveValue, Tag};
+use dicom_dictionary_std::tags;
 use std::fmt;
 use std::io::Write;
 use std::marker::PhantomData;
@@ -212,7 +213,7 @@ pub trait Encode {
     where
         W: Write,
     {
-        self.encode_tag(&mut to, Tag(0xFFFE, 0xE00D))?;
+        self.encode_tag(&mut to, tags::ITEM_DELIMITATION_ITEM)?;
         to.write_all(&[0u8; 4])?;
         Ok(())
     }
@@ -222,7 +223,7 @@ pub trait Encode {
     where
         W: Write,
     {
-        self.encode_tag(&mut to, Tag(0xFFFE, 0xE0DD))?;
+        self.encode_tag(&mut to, tags::SEQUENCE_DELIMITATION_ITEM)?;
         to.write_all(&[0u8; 4])?;
         Ok(())
     }
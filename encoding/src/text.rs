@@ -15,6 +15,8 @@
 //! At the moment, this library supports only IR-6 and IR-192.
 
 use crate::error::{Result, TextEncodingError};
+use dicom_core::value::PrimitiveValue;
+use dicom_core::VR;
 use encoding::all::{ISO_8859_1, UTF_8};
 use encoding::{DecoderTrap, EncoderTrap, Encoding, RawDecoder, StringWriter};
 use std::fmt::Debug;
@@ -88,6 +90,15 @@ impl SpecificCharacterSet {
         }
     }
 
+    /// Obtain the character set named by the first code
+    /// in a value of Specific Character Set (0008,0005), if supported.
+    pub fn from_value(value: &PrimitiveValue) -> Option<Self> {
+        value
+            .strings()
+            .and_then(|codes| codes.first().map(|code| code.trim().to_string()))
+            .and_then(|code| Self::from_code(&code))
+    }
+
     /// Retrieve the respective text codec.
     pub fn codec(self) -> Option<Box<dyn TextCodec>> {
         match self {
@@ -175,7 +186,7 @@ pub fn validate_iso_8859(text: &[u8]) -> TextValidationOutcome {
 /// Check whether the given byte slice contains only valid characters for a
 /// Date value representation.
 pub fn validate_da(text: &[u8]) -> TextValidationOutcome {
-    if text.iter().cloned().all(|c| c >= b'0' && c <= b'9') {
+    if text.iter().all(u8::is_ascii_digit) {
         TextValidationOutcome::Ok
     } else {
         TextValidationOutcome::NotOk
@@ -187,7 +198,7 @@ pub fn validate_da(text: &[u8]) -> TextValidationOutcome {
 pub fn validate_tm(text: &[u8]) -> TextValidationOutcome {
     if text.iter().cloned().all(|c| match c {
         b'\\' | b'.' | b'-' | b' ' => true,
-        c => c >= b'0' && c <= b'9',
+        c => c.is_ascii_digit(),
     }) {
         TextValidationOutcome::Ok
     } else {
@@ -200,7 +211,7 @@ pub fn validate_tm(text: &[u8]) -> TextValidationOutcome {
 pub fn validate_dt(text: &[u8]) -> TextValidationOutcome {
     if text.iter().cloned().all(|c| match c {
         b'.' | b'-' | b'+' | b' ' | b'\\' => true,
-        c => c >= b'0' && c <= b'9',
+        c => c.is_ascii_digit(),
     }) {
         TextValidationOutcome::Ok
     } else {
@@ -213,10 +224,192 @@ pub fn validate_dt(text: &[u8]) -> TextValidationOutcome {
 pub fn validate_cs(text: &[u8]) -> TextValidationOutcome {
     if text.iter().cloned().all(|c| match c {
         b' ' | b'_' => true,
-        c => (c >= b'0' && c <= b'9') || (c >= b'A' && c <= b'Z'),
+        c => c.is_ascii_digit() || c.is_ascii_uppercase(),
+    }) {
+        TextValidationOutcome::Ok
+    } else {
+        TextValidationOutcome::NotOk
+    }
+}
+
+/// Check whether the given byte slice contains only valid characters for an
+/// Application Entity value representation.
+pub fn validate_ae(text: &[u8]) -> TextValidationOutcome {
+    if text
+        .iter()
+        .all(|&c| (b' '..=b'~').contains(&c) && c != b'\\')
+    {
+        TextValidationOutcome::Ok
+    } else {
+        TextValidationOutcome::NotOk
+    }
+}
+
+/// Check whether the given byte slice contains only valid characters for an
+/// Age String value representation.
+pub fn validate_as(text: &[u8]) -> TextValidationOutcome {
+    if text.iter().all(|&c| match c {
+        b'D' | b'W' | b'M' | b'Y' => true,
+        c => c.is_ascii_digit(),
     }) {
         TextValidationOutcome::Ok
     } else {
         TextValidationOutcome::NotOk
     }
 }
+
+/// Check whether the given byte slice contains only valid characters for an
+/// Integer String value representation.
+pub fn validate_is(text: &[u8]) -> TextValidationOutcome {
+    if text.iter().all(|&c| match c {
+        b'+' | b'-' | b' ' => true,
+        c => c.is_ascii_digit(),
+    }) {
+        TextValidationOutcome::Ok
+    } else {
+        TextValidationOutcome::NotOk
+    }
+}
+
+/// Check whether the given byte slice contains only valid characters for a
+/// Decimal String value representation.
+pub fn validate_ds(text: &[u8]) -> TextValidationOutcome {
+    if text.iter().all(|&c| match c {
+        b'+' | b'-' | b'.' | b'E' | b'e' | b' ' => true,
+        c => c.is_ascii_digit(),
+    }) {
+        TextValidationOutcome::Ok
+    } else {
+        TextValidationOutcome::NotOk
+    }
+}
+
+/// Check whether the given byte slice contains only valid characters for a
+/// Unique Identifier value representation. Trailing null padding is accepted.
+pub fn validate_ui(text: &[u8]) -> TextValidationOutcome {
+    let end = text
+        .iter()
+        .rposition(|&c| c != 0)
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    if text[..end].iter().all(|&c| c == b'.' || c.is_ascii_digit()) {
+        TextValidationOutcome::Ok
+    } else {
+        TextValidationOutcome::NotOk
+    }
+}
+
+/// Check whether the given byte slice contains only valid characters for a
+/// single value of the given value representation.
+///
+/// The character repertoire of text value representations (such as LO or
+/// PN) depends on the specific character set, so only the absence of
+/// control characters other than those allowed by the standard is
+/// checked for those. Binary value representations are always accepted.
+pub fn validate_value(vr: VR, text: &[u8]) -> TextValidationOutcome {
+    match vr {
+        VR::AE => validate_ae(text),
+        VR::AS => validate_as(text),
+        VR::CS => validate_cs(text),
+        VR::DA => validate_da(text),
+        VR::DS => validate_ds(text),
+        VR::DT => validate_dt(text),
+        VR::IS => validate_is(text),
+        VR::TM => validate_tm(text),
+        VR::UI => validate_ui(text),
+        // ESC is needed for code extensions
+        VR::LO | VR::PN | VR::SH | VR::UC => {
+            if text.iter().all(|&c| c >= b' ' || c == 0x1B) {
+                TextValidationOutcome::Ok
+            } else {
+                TextValidationOutcome::NotOk
+            }
+        }
+        // formatting characters are also allowed in free text
+        VR::LT | VR::ST | VR::UT => {
+            if text
+                .iter()
+                .all(|&c| c >= b' ' || [b'\t', b'\n', 0x0C, b'\r', 0x1B].contains(&c))
+            {
+                TextValidationOutcome::Ok
+            } else {
+                TextValidationOutcome::NotOk
+            }
+        }
+        _ => TextValidationOutcome::Ok,
+    }
+}
+
+/// Obtain the maximum length in bytes of a single value
+/// of the given value representation, excluding padding.
+/// Returns `None` if the value representation is not textual
+/// or has no length limit other than the length of the element.
+///
+/// The limit for PN applies to each component group.
+pub fn max_value_length(vr: VR) -> Option<usize> {
+    match vr {
+        VR::AE | VR::CS | VR::DS | VR::SH => Some(16),
+        VR::AS => Some(4),
+        VR::DA => Some(8),
+        VR::DT => Some(26),
+        VR::IS => Some(12),
+        VR::LO | VR::PN | VR::UI => Some(64),
+        VR::LT => Some(10240),
+        VR::ST => Some(1024),
+        VR::TM => Some(14),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_values_by_vr() {
+        assert_eq!(
+            validate_value(VR::CS, b"ORIGINAL"),
+            TextValidationOutcome::Ok
+        );
+        assert_eq!(
+            validate_value(VR::CS, b"original"),
+            TextValidationOutcome::NotOk
+        );
+        assert_eq!(
+            validate_value(VR::AE, b"STORE-SCP"),
+            TextValidationOutcome::Ok
+        );
+        assert_eq!(
+            validate_value(VR::AE, b"A\\B"),
+            TextValidationOutcome::NotOk
+        );
+        assert_eq!(
+            validate_value(VR::UI, b"1.2.840.10008.1.2\0"),
+            TextValidationOutcome::Ok
+        );
+        assert_eq!(
+            validate_value(VR::UI, b"1.2.abc"),
+            TextValidationOutcome::NotOk
+        );
+        assert_eq!(validate_value(VR::IS, b"-12 "), TextValidationOutcome::Ok);
+        assert_eq!(validate_value(VR::IS, b"1.5"), TextValidationOutcome::NotOk);
+        assert_eq!(validate_value(VR::DS, b"1.5e-3"), TextValidationOutcome::Ok);
+        assert_eq!(validate_value(VR::DS, b"1,5"), TextValidationOutcome::NotOk);
+        assert_eq!(validate_value(VR::AS, b"042Y"), TextValidationOutcome::Ok);
+        assert_eq!(
+            validate_value(VR::LO, b"Line\nbreak"),
+            TextValidationOutcome::NotOk
+        );
+        assert_eq!(
+            validate_value(VR::LT, b"Line\r\nbreak"),
+            TextValidationOutcome::Ok
+        );
+        assert_eq!(
+            validate_value(VR::OB, &[0, 1, 2]),
+            TextValidationOutcome::Ok
+        );
+
+        assert_eq!(max_value_length(VR::CS), Some(16));
+        assert_eq!(max_value_length(VR::UT), None);
+    }
+}
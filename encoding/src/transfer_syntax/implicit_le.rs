@@ -10,7 +10,7 @@ use byteordered::Endianness;
 use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
 use dicom_core::header::{DataElementHeader, Header, Length, SequenceItemHeader};
 use dicom_core::{PrimitiveValue, Tag, VR};
-use dicom_dictionary_std::StandardDataDictionary;
+use dicom_dictionary_std::{tags, StandardDataDictionary};
 use std::fmt;
 use std::io::{Read, Write};
 
@@ -87,7 +87,7 @@ where
         // VR resolution is done with the help of the data dictionary.
         // In Implicit VR Little Endian, the VR of OB may not be used for Pixel
         // Data (7FE0,0010). This edge case is addressed manually.
-        let vr = if tag == Tag(0x7FE0, 0x0010) {
+        let vr = if tag == tags::PIXEL_DATA {
             VR::OW
         } else {
             self.dict
@@ -281,7 +281,8 @@ mod tests {
     use crate::decode::Decode;
     use crate::encode::Encode;
     use dicom_core::dictionary::stub::StubDataDictionary;
-    use dicom_core::header::{DataElementHeader, Header, Length, Tag, VR};
+    use dicom_core::header::{DataElementHeader, Header, Length, VR};
+    use dicom_dictionary_std::tags;
     use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 
     // manually crafting some DICOM data elements
@@ -393,7 +394,7 @@ mod tests {
             let mut writer = Cursor::new(&mut buf[..]);
 
             // encode first element
-            let de = DataElementHeader::new(Tag(0x0002, 0x0002), VR::UI, Length(26));
+            let de = DataElementHeader::new(tags::MEDIA_STORAGE_SOP_CLASS_UID, VR::UI, Length(26));
             let len = enc
                 .encode_element_header(&mut writer, de)
                 .expect("should write it fine");
@@ -408,7 +409,7 @@ mod tests {
             let mut writer = Cursor::new(&mut buf[34..]);
 
             // encode second element
-            let de = DataElementHeader::new(Tag(0x0002, 0x0010), VR::UI, Length(20));
+            let de = DataElementHeader::new(tags::TRANSFER_SYNTAX_UID, VR::UI, Length(20));
             let len = enc
                 .encode_element_header(&mut writer, de)
                 .expect("should write it fine");
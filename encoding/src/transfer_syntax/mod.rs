@@ -236,6 +236,12 @@ impl<A> TransferSyntax<A> {
         self.byte_order
     }
 
+    /// Check whether this transfer syntax encodes value representations
+    /// explicitly in data element headers.
+    pub const fn explicit_vr(&self) -> bool {
+        self.explicit_vr
+    }
+
     /// Obtain this transfer syntax' codec specification.
     pub fn codec(&self) -> &Codec<A> {
         &self.codec
@@ -0,0 +1,214 @@
+//! A simple application that queries a DICOM node
+//! through C-FIND requests, in the spirit of DCMTK's `findscu`.
+//!
+//! The query keys are given as attribute keywords or tags,
+//! optionally followed by `=` and the value to match.
+//! Keys without a value are returned by the SCP with each match.
+//! The matches are printed to the standard output.
+use clap::{App, Arg};
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
+use dicom_core::header::Header;
+use dicom_core::{DataElement, PrimitiveValue, Tag, VR};
+use dicom_dictionary_std::StandardDataDictionary;
+use dicom_object::mem::InMemDicomObject;
+use dicom_ul::association::ClientAssociationOptions;
+use dicom_ul::services::query::{self, InformationModel, QueryLevel};
+use std::process;
+
+/// The transfer syntaxes proposed for the query:
+/// Explicit VR Little Endian and Implicit VR Little Endian.
+const TRANSFER_SYNTAXES: &[&str] = &["1.2.840.10008.1.2.1", "1.2.840.10008.1.2"];
+
+fn main() {
+    let matches = App::new("findscu")
+        .about("Query a DICOM node with a C-FIND request")
+        .arg(
+            Arg::with_name("addr")
+                .help("The address of the SCP (host:port)")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::with_name("calling-ae-title")
+                .help("The calling AE title (this node)")
+                .long("calling-ae-title")
+                .default_value("FINDSCU")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("called-ae-title")
+                .help("The called AE title (the SCP)")
+                .long("called-ae-title")
+                .default_value("ANY-SCP")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("patient-root")
+                .help("Use the Patient Root information model instead of Study Root")
+                .short("P")
+                .long("patient-root"),
+        )
+        .arg(
+            Arg::with_name("level")
+                .help("The query/retrieve level")
+                .short("L")
+                .long("level")
+                .possible_values(&["PATIENT", "STUDY", "SERIES", "IMAGE"])
+                .default_value("STUDY")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("key")
+                .help("A query key, as KEYWORD[=VALUE] or gggg,eeee[=VALUE]")
+                .short("k")
+                .long("key")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .get_matches();
+
+    let addr = matches.value_of("addr").unwrap();
+    let model = if matches.is_present("patient-root") {
+        InformationModel::PatientRoot
+    } else {
+        InformationModel::StudyRoot
+    };
+    let level = QueryLevel::from_code(matches.value_of("level").unwrap()).unwrap();
+
+    let mut identifier = level.identifier();
+    for key in matches.values_of("key").into_iter().flatten() {
+        match parse_key(key) {
+            Ok(element) => {
+                identifier.put(element);
+            }
+            Err(e) => {
+                eprintln!("error: invalid key {}: {}", key, e);
+                process::exit(2);
+            }
+        }
+    }
+
+    let mut association = match ClientAssociationOptions::new()
+        .calling_ae_title(matches.value_of("calling-ae-title").unwrap())
+        .called_ae_title(matches.value_of("called-ae-title").unwrap())
+        .with_presentation_context(model.find_sop_class(), TRANSFER_SYNTAXES.to_vec())
+        .establish(addr)
+    {
+        Ok(association) => association,
+        Err(e) => {
+            eprintln!("error: could not establish association: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut responses = match query::find(&mut association, model, 1, &identifier) {
+        Ok(responses) => responses,
+        Err(e) => {
+            eprintln!("error: C-FIND failed: {}", e);
+            process::exit(1);
+        }
+    };
+    let mut count = 0;
+    for found in &mut responses {
+        match found {
+            Ok(found) => {
+                count += 1;
+                println!("match {}:", count);
+                print_identifier(&found);
+            }
+            Err(e) => {
+                eprintln!("error: C-FIND failed: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+    let status = responses.status().unwrap();
+    println!("{} matches, final status {:#06X}", count, status.0);
+
+    if let Err(e) = association.release() {
+        eprintln!("error: could not release association: {}", e);
+        process::exit(1);
+    }
+    if !status.is_success() {
+        process::exit(1);
+    }
+}
+
+/// Parse a query key into an element of the identifier.
+fn parse_key(key: &str) -> Result<DataElement<InMemDicomObject<StandardDataDictionary>>, String> {
+    let (name, value) = match key.find('=') {
+        Some(i) => (&key[..i], Some(&key[i + 1..])),
+        None => (key, None),
+    };
+    let tag = match parse_tag(name) {
+        Some(tag) => tag,
+        None => StandardDataDictionary
+            .by_name(name)
+            .map(|e| e.tag())
+            .ok_or_else(|| "unknown attribute".to_string())?,
+    };
+    let vr = StandardDataDictionary
+        .by_tag(tag)
+        .map(|e| e.vr())
+        .unwrap_or(VR::LO);
+    match value {
+        None | Some("") => Ok(DataElement::empty(tag, vr)),
+        Some(value) => match vr {
+            VR::AE
+            | VR::AS
+            | VR::CS
+            | VR::DA
+            | VR::DS
+            | VR::DT
+            | VR::IS
+            | VR::LO
+            | VR::LT
+            | VR::PN
+            | VR::SH
+            | VR::ST
+            | VR::TM
+            | VR::UC
+            | VR::UI
+            | VR::UR
+            | VR::UT => Ok(DataElement::new(
+                tag,
+                vr,
+                PrimitiveValue::Strs([value.to_string()].as_ref().into()).into(),
+            )),
+            vr => Err(format!("values of VR {:?} are not supported", vr)),
+        },
+    }
+}
+
+/// Parse a tag in the form `gggg,eeee`.
+fn parse_tag(name: &str) -> Option<Tag> {
+    let mut parts = name.split(',');
+    let group = u16::from_str_radix(parts.next()?, 16).ok()?;
+    let element = u16::from_str_radix(parts.next()?, 16).ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(Tag(group, element))
+}
+
+/// Print each element of an identifier on its own line.
+fn print_identifier(identifier: &InMemDicomObject<StandardDataDictionary>) {
+    for element in identifier {
+        let keyword = StandardDataDictionary
+            .by_tag(element.tag())
+            .map(|e| e.alias())
+            .unwrap_or("?");
+        let value = element
+            .to_str()
+            .map(|v| v.trim_end_matches(&['\0', ' '][..]).to_string())
+            .unwrap_or_else(|_| "(sequence)".to_string());
+        println!(
+            "  {} {} {:?} [{}]",
+            element.tag(),
+            keyword,
+            element.vr(),
+            value
+        );
+    }
+}
@@ -1,8 +1,484 @@
-use crate::DefaultDicomObject;
-use dicom_parser::error::Result;
-use std::io::Read;
+//! This module contains the functions for opening DICOM files, as well as
+//! the detection of the structure and encoding of files which do not follow
+//! the standard file format, and the transcoding of files between
+//! transfer syntaxes.
+
+use crate::mem::InMemDicomObject;
+use crate::meta::{
+    FileMetaTable, FileMetaTableBuilder, DICM_MAGIC_CODE, IMPLEMENTATION_CLASS_UID,
+    IMPLEMENTATION_VERSION_NAME,
+};
+use crate::{DefaultDicomObject, RootDicomObject};
+use byteordered::Endianness;
+use dicom_core::dictionary::private::PrivateDictionary;
+use dicom_core::dictionary::DataDictionary;
+use dicom_core::{Length, Tag, VR};
+use dicom_dictionary_std::{tags, StandardDataDictionary};
+use dicom_encoding::encode::Encode;
+use dicom_encoding::text::{DefaultCharacterSetCodec, SpecificCharacterSet, TextCodec};
+use dicom_encoding::transfer_syntax::explicit_be::ExplicitVRBigEndianEncoder;
+use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianEncoder;
+use dicom_encoding::transfer_syntax::implicit_le::ImplicitVRLittleEndianEncoder;
+use dicom_encoding::transfer_syntax::{Codec, TransferSyntaxIndex};
+use dicom_encoding::TransferSyntax;
+use dicom_parser::dataset::{
+    DataSetReader, DataSetWriter, DataToken, GroupLengths, TokenTransform,
+};
+use dicom_parser::error::{Error, ReadWarning, Result};
+use dicom_parser::parser::ReadLimits;
+use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Cursor, Read, Write};
 use std::path::Path;
 
+/// The number of bytes at the beginning of a file
+/// needed to detect its format.
+pub const DETECTION_LENGTH: usize = 140;
+
+/// The structure of a DICOM file, as inferred from its first bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FileFormat {
+    /// The data set is preceded by a file meta group.
+    Meta {
+        /// Whether the file starts with the 128-byte preamble
+        preamble: bool,
+        /// Whether the file meta group starts with the "DICM" magic code
+        magic_code: bool,
+        /// Whether the file meta group is in explicit VR,
+        /// as the standard demands
+        explicit_vr: bool,
+    },
+    /// The data set is not preceded by a file meta group
+    /// (such as in ACR-NEMA files and data set dumps).
+    DataSet {
+        /// Whether the file starts with the 128-byte preamble
+        preamble: bool,
+        /// Whether value representations are explicit
+        explicit_vr: bool,
+        /// The byte order of the data set
+        endianness: Endianness,
+    },
+}
+
+impl FileFormat {
+    /// Obtain the UID of the transfer syntax of a data set
+    /// without a file meta group. Returns `None` if the file has a file meta
+    /// group, or if the encoding does not match a transfer syntax
+    /// (implicit VR big endian).
+    pub fn transfer_syntax(self) -> Option<&'static str> {
+        match self {
+            FileFormat::DataSet {
+                explicit_vr: false,
+                endianness: Endianness::Little,
+                ..
+            } => Some("1.2.840.10008.1.2"),
+            FileFormat::DataSet {
+                explicit_vr: true,
+                endianness: Endianness::Little,
+                ..
+            } => Some("1.2.840.10008.1.2.1"),
+            FileFormat::DataSet {
+                explicit_vr: true,
+                endianness: Endianness::Big,
+                ..
+            } => Some("1.2.840.10008.1.2.2"),
+            _ => None,
+        }
+    }
+}
+
+/// Detect the structure and encoding of a DICOM file from its first bytes,
+/// preferably [`DETECTION_LENGTH`] of them.
+///
+/// The file is taken to have a file meta group if the "DICM" magic code is
+/// found at the start of the file or after the preamble, or if the file
+/// starts (optionally after the preamble) with a group 0002 element,
+/// in explicit or implicit VR little endian. Otherwise, the byte order and
+/// VR explicitness of the data set are inferred from its first element
+/// header, which must be of a group length or a standard attribute,
+/// at the start of the file or after the preamble.
+///
+/// [`DETECTION_LENGTH`]: constant.DETECTION_LENGTH.html
+pub fn detect_format(head: &[u8]) -> Result<FileFormat> {
+    let after_preamble = head.get(128..).unwrap_or(&[]);
+    if after_preamble.starts_with(&DICM_MAGIC_CODE) {
+        return Ok(FileFormat::Meta {
+            preamble: true,
+            magic_code: true,
+            explicit_vr: meta_group_start(&after_preamble[4..]).unwrap_or(true),
+        });
+    }
+    if head.starts_with(&DICM_MAGIC_CODE) {
+        return Ok(FileFormat::Meta {
+            preamble: false,
+            magic_code: true,
+            explicit_vr: meta_group_start(&head[4..]).unwrap_or(true),
+        });
+    }
+    for (preamble, head) in [(false, head), (true, after_preamble)] {
+        if let Some(explicit_vr) = meta_group_start(head) {
+            return Ok(FileFormat::Meta {
+                preamble,
+                magic_code: false,
+                explicit_vr,
+            });
+        }
+    }
+    for (preamble, head) in [(false, head), (true, after_preamble)] {
+        if let Some((explicit_vr, endianness)) = data_set_start(head) {
+            return Ok(FileFormat::DataSet {
+                preamble,
+                explicit_vr,
+                endianness,
+            });
+        }
+    }
+    Err(Error::InvalidFormat)
+}
+
+/// Check whether the bytes start with a standard group 0002 element header
+/// in little endian, and whether its value representation is explicit.
+fn meta_group_start(head: &[u8]) -> Option<bool> {
+    if head.len() < 8 || head[0..2] != [0x02, 0x00] {
+        return None;
+    }
+    if VR::from_binary([head[4], head[5]]).is_some() {
+        return Some(true);
+    }
+    let element = u16::from_le_bytes([head[2], head[3]]);
+    StandardDataDictionary
+        .by_tag(Tag(0x0002, element))
+        .map(|_| false)
+}
+
+/// Infer the VR explicitness and the byte order of a data set
+/// from the bytes of its first element header.
+fn data_set_start(head: &[u8]) -> Option<(bool, Endianness)> {
+    if head.len() < 8 {
+        return None;
+    }
+    // data sets usually start with a low group number,
+    // so the byte order is the one which reads it as the lowest
+    let group_le = u16::from_le_bytes([head[0], head[1]]);
+    let group_be = u16::from_be_bytes([head[0], head[1]]);
+    let (group, endianness) = if group_le == group_be {
+        let element_le = u16::from_le_bytes([head[2], head[3]]);
+        let element_be = u16::from_be_bytes([head[2], head[3]]);
+        if element_be < element_le {
+            (group_be, Endianness::Big)
+        } else {
+            (group_le, Endianness::Little)
+        }
+    } else if group_be < group_le {
+        (group_be, Endianness::Big)
+    } else {
+        (group_le, Endianness::Little)
+    };
+    let element = match endianness {
+        Endianness::Little => u16::from_le_bytes([head[2], head[3]]),
+        Endianness::Big => u16::from_be_bytes([head[2], head[3]]),
+    };
+    // groups 0000 to 0003 are never found in a data set,
+    // and a zeroed preamble would otherwise be read as (0000,0000)
+    if group < 0x0004 {
+        return None;
+    }
+    // the first element should be a group length or a standard attribute
+    if element != 0 && StandardDataDictionary.by_tag(Tag(group, element)).is_none() {
+        return None;
+    }
+    Some((VR::from_binary([head[4], head[5]]).is_some(), endianness))
+}
+
+/// Options for reading DICOM objects, from files with a file meta group
+/// or from data sets with no file meta group.
+///
+/// The options can be combined freely:
+///
+/// ```no_run
+/// use dicom_object::{ReadLimits, ReadOptions};
+/// # use dicom_object::Result;
+/// # fn foo() -> Result<()> {
+/// let (obj, warnings) = ReadOptions::new()
+///     .lenient(true)
+///     .limits(ReadLimits::untrusted())
+///     .open_file("0001.dcm")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReadOptions<D = StandardDataDictionary, R = TransferSyntaxRegistry> {
+    dict: D,
+    ts_index: R,
+    private_dict: PrivateDictionary,
+    lenient: bool,
+    limits: ReadLimits,
+    detect_format: bool,
+}
+
+impl ReadOptions {
+    /// Create the default options: the standard data dictionary
+    /// and transfer syntax registry, no private dictionary,
+    /// no limits, and the standard file format in strict mode.
+    pub fn new() -> Self {
+        ReadOptions {
+            dict: StandardDataDictionary,
+            ts_index: TransferSyntaxRegistry,
+            private_dict: PrivateDictionary::new(),
+            lenient: false,
+            limits: ReadLimits::default(),
+            detect_format: false,
+        }
+    }
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions::new()
+    }
+}
+
+impl<D, R> ReadOptions<D, R> {
+    /// Use the given data dictionary for the objects read.
+    pub fn dictionary<E>(self, dict: E) -> ReadOptions<E, R> {
+        ReadOptions {
+            dict,
+            ts_index: self.ts_index,
+            private_dict: self.private_dict,
+            lenient: self.lenient,
+            limits: self.limits,
+            detect_format: self.detect_format,
+        }
+    }
+
+    /// Use the given transfer syntax index.
+    /// This is only advised when the built-in transfer syntax registry
+    /// is insufficient.
+    pub fn transfer_syntax_index<T>(self, ts_index: T) -> ReadOptions<D, T> {
+        ReadOptions {
+            dict: self.dict,
+            ts_index,
+            private_dict: self.private_dict,
+            lenient: self.lenient,
+            limits: self.limits,
+            detect_format: self.detect_format,
+        }
+    }
+
+    /// Use the given dictionary of private attributes to resolve
+    /// the value representation of private elements in implicit VR.
+    pub fn private_dictionary(mut self, private_dict: PrivateDictionary) -> Self {
+        self.private_dict = private_dict;
+        self
+    }
+
+    /// Read in lenient mode, which works around known encoding problems
+    /// in the data set instead of failing.
+    /// See `DataSetReader::lenient` for the problems covered.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Enforce the given limits on the data read.
+    /// Use [`ReadLimits::untrusted`] to read data from untrusted sources.
+    ///
+    /// [`ReadLimits::untrusted`]: ../../dicom_parser/parser/struct.ReadLimits.html#method.untrusted
+    pub fn limits(mut self, limits: ReadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Detect the structure and encoding of files from their first bytes,
+    /// as in [`detect_format`], instead of expecting the standard file
+    /// format. If a file does not have a file meta group,
+    /// the data set is read in the detected transfer syntax,
+    /// and a file meta table is created for it.
+    ///
+    /// [`detect_format`]: fn.detect_format.html
+    pub fn detect_format(mut self, detect_format: bool) -> Self {
+        self.detect_format = detect_format;
+        self
+    }
+}
+
+impl<D, R> ReadOptions<D, R>
+where
+    D: DataDictionary,
+    D: Clone,
+    R: TransferSyntaxIndex,
+{
+    /// Read a DICOM object from a file.
+    ///
+    /// Unless the format is detected, this assumes the standard file
+    /// encoding structure: 128-byte preamble, file meta group,
+    /// and the rest of the data set.
+    /// The encoding problems worked around in lenient mode
+    /// are returned alongside the object.
+    pub fn open_file<P>(
+        self,
+        path: P,
+    ) -> Result<(RootDicomObject<InMemDicomObject<D>>, Vec<ReadWarning>)>
+    where
+        P: AsRef<Path>,
+    {
+        self.read_file(BufReader::new(File::open(path)?), true)
+    }
+
+    /// Read a DICOM object from a byte source.
+    ///
+    /// Unless the format is detected, this assumes the standard file
+    /// encoding structure without the preamble: file meta group,
+    /// followed by the rest of the data set.
+    /// The encoding problems worked around in lenient mode
+    /// are returned alongside the object.
+    pub fn from_reader<S>(
+        self,
+        src: S,
+    ) -> Result<(RootDicomObject<InMemDicomObject<D>>, Vec<ReadWarning>)>
+    where
+        S: Read,
+    {
+        self.read_file(BufReader::new(src), false)
+    }
+
+    /// Read a data set with no file meta group from a byte source,
+    /// encoded in the given transfer syntax,
+    /// such as a data set exchanged over the network.
+    /// The encoding problems worked around in lenient mode
+    /// are returned alongside the object.
+    pub fn read_dataset<S>(
+        self,
+        src: S,
+        ts: &TransferSyntax,
+    ) -> Result<(InMemDicomObject<D>, Vec<ReadWarning>)>
+    where
+        S: Read,
+    {
+        self.read_data_set(src, ts, 0)
+    }
+
+    /// Read a DICOM object from a source, which starts with the preamble
+    /// if `preamble` is true and the format is not detected.
+    fn read_file<S>(
+        self,
+        mut src: S,
+        preamble: bool,
+    ) -> Result<(RootDicomObject<InMemDicomObject<D>>, Vec<ReadWarning>)>
+    where
+        S: Read,
+    {
+        let mut head = Vec::with_capacity(DETECTION_LENGTH);
+        let format = if self.detect_format {
+            (&mut src)
+                .take(DETECTION_LENGTH as u64)
+                .read_to_end(&mut head)?;
+            detect_format(&head)?
+        } else {
+            FileFormat::Meta {
+                preamble,
+                magic_code: true,
+                explicit_vr: true,
+            }
+        };
+        let mut src = Cursor::new(head).chain(src);
+
+        match format {
+            FileFormat::Meta {
+                preamble,
+                magic_code,
+                explicit_vr,
+            } => {
+                if preamble {
+                    let mut buf = [0u8; 128];
+                    // skip the preamble
+                    src.read_exact(&mut buf)?;
+                }
+                if magic_code {
+                    let mut buf = [0u8; 4];
+                    src.read_exact(&mut buf)?;
+                    if buf != DICM_MAGIC_CODE {
+                        return Err(Error::InvalidFormat);
+                    }
+                }
+                let meta = FileMetaTable::read_group(&mut src, explicit_vr)?;
+                let ts = self
+                    .ts_index
+                    .get(&meta.transfer_syntax)
+                    .ok_or(Error::UnsupportedTransferSyntax)?;
+                // the position of the data set after the file meta group
+                let offset = if preamble { 128 } else { 0 }
+                    + if magic_code { 4 } else { 0 }
+                    + 12
+                    + u64::from(meta.information_group_length);
+                let (obj, warnings) = self.read_data_set(src, ts, offset)?;
+                Ok((RootDicomObject { meta, obj }, warnings))
+            }
+            FileFormat::DataSet { preamble, .. } => {
+                if preamble {
+                    let mut buf = [0u8; 128];
+                    // skip the preamble
+                    src.read_exact(&mut buf)?;
+                }
+                let ts_uid = format
+                    .transfer_syntax()
+                    .ok_or(Error::UnsupportedTransferSyntax)?;
+                let ts = self
+                    .ts_index
+                    .get(ts_uid)
+                    .ok_or(Error::UnsupportedTransferSyntax)?;
+                let offset = if preamble { 128 } else { 0 };
+                let (obj, warnings) = self.read_data_set(src, ts, offset)?;
+
+                // the SOP class and instance are unknown in ACR-NEMA files
+                let uid_of = |tag| {
+                    obj.element(tag)
+                        .ok()
+                        .and_then(|e| e.value().primitive())
+                        .and_then(|v| v.string())
+                        .map(|uid| uid.trim_end_matches(&['\0', ' '][..]).to_string())
+                        .unwrap_or_default()
+                };
+                let meta = FileMetaTableBuilder::new()
+                    .media_storage_sop_class_uid(uid_of(tags::SOP_CLASS_UID))
+                    .media_storage_sop_instance_uid(uid_of(tags::SOP_INSTANCE_UID))
+                    .transfer_syntax(ts_uid.to_string())
+                    .implementation_class_uid(IMPLEMENTATION_CLASS_UID.to_string())
+                    .implementation_version_name(IMPLEMENTATION_VERSION_NAME.to_string())
+                    .build()?;
+                Ok((RootDicomObject { meta, obj }, warnings))
+            }
+        }
+    }
+
+    /// Read a data set with no file meta group, found at the given
+    /// position in the original source, used to report the position
+    /// of errors and warnings.
+    fn read_data_set<S>(
+        &self,
+        src: S,
+        ts: &TransferSyntax,
+        offset: u64,
+    ) -> Result<(InMemDicomObject<D>, Vec<ReadWarning>)>
+    where
+        S: Read,
+    {
+        let cs = SpecificCharacterSet::Default;
+        let mut dataset = DataSetReader::new_with_dictionary(src, self.dict.clone(), ts, cs)?
+            .with_private_dictionary(self.private_dict.clone())
+            .lenient(self.lenient)
+            .with_limits(self.limits)
+            .with_base_offset(offset);
+        let obj = InMemDicomObject::build_object(
+            &mut dataset,
+            self.dict.clone(),
+            false,
+            Length::UNDEFINED,
+        )?;
+        Ok((obj, dataset.warnings().to_vec()))
+    }
+}
+
 /// Create a DICOM object by reading from a byte source.
 ///
 /// This function assumes the standard file encoding structure without the
@@ -24,3 +500,320 @@ where
 {
     DefaultDicomObject::open_file(path)
 }
+
+/// Read a DICOM file from a byte source and write it to `to`
+/// in the given transfer syntax, one element at a time,
+/// without holding the data set in memory.
+///
+/// Both the transfer syntax of the source and the target one must be
+/// native: implicit VR little endian, explicit VR little endian
+/// or explicit VR big endian. Values of unknown value representation (UN)
+/// are decoded in the representation given by the standard dictionary,
+/// and the transfer syntax in the file meta group is updated.
+///
+/// This function assumes the standard file encoding structure without the
+/// preamble: file meta group, followed by the rest of the data set.
+/// The output has no preamble either.
+pub fn transcode<S, W>(mut from: S, mut to: W, ts: &TransferSyntax) -> Result<()>
+where
+    S: Read,
+    W: Write,
+{
+    let mut meta = FileMetaTable::from_reader(&mut from)?;
+    let source_ts = TransferSyntaxRegistry
+        .get(&meta.transfer_syntax)
+        .ok_or(Error::UnsupportedTransferSyntax)?;
+    if !is_native(source_ts) || !is_native(ts) {
+        return Err(Error::UnsupportedTransferSyntax);
+    }
+    let offset = 4 + 12 + u64::from(meta.information_group_length);
+
+    meta.set_transfer_syntax(ts.uid());
+    meta.write(&mut to)?;
+
+    let tokens = DataSetReader::new_with(from, source_ts, SpecificCharacterSet::Default)?
+        .with_base_offset(offset)
+        .transcode_values(source_ts, StandardDataDictionary)?;
+    write_data_set(to, ts, tokens)
+}
+
+/// Read a DICOM file and write it to another file
+/// in the given transfer syntax, one element at a time,
+/// as in [`transcode`](fn.transcode.html).
+///
+/// This function assumes the standard file encoding structure: 128-byte
+/// preamble, file meta group, and the rest of the data set.
+/// The preamble is copied to the new file.
+pub fn transcode_file<P, Q>(from: P, to: Q, ts: &TransferSyntax) -> Result<()>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    let mut file = BufReader::new(File::open(from)?);
+    let mut preamble = [0u8; 128];
+    file.read_exact(&mut preamble)?;
+
+    let mut out = BufWriter::new(File::create(to)?);
+    out.write_all(&preamble)?;
+    transcode(file, &mut out, ts)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Check whether data sets in the given transfer syntax are read and
+/// written without a codec, so that they can be transcoded.
+pub(crate) fn is_native(ts: &TransferSyntax) -> bool {
+    matches!(ts.codec(), Codec::None) && (ts.explicit_vr() || ts.endianness() == Endianness::Little)
+}
+
+/// Write the tokens of a data set in the given transfer syntax.
+/// Group length elements are left out,
+/// and text values follow the specific character set of the data set.
+pub(crate) fn write_data_set<W, I>(to: W, ts: &TransferSyntax, tokens: I) -> Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = Result<DataToken>>,
+{
+    match (ts.endianness(), ts.explicit_vr()) {
+        (Endianness::Little, false) => {
+            write_data_set_with(to, ImplicitVRLittleEndianEncoder::default(), tokens)
+        }
+        (Endianness::Little, true) => {
+            write_data_set_with(to, ExplicitVRLittleEndianEncoder::default(), tokens)
+        }
+        (Endianness::Big, true) => {
+            write_data_set_with(to, ExplicitVRBigEndianEncoder::default(), tokens)
+        }
+        _ => Err(Error::UnsupportedTransferSyntax),
+    }
+}
+
+fn write_data_set_with<W, E, I>(to: W, encoder: E, tokens: I) -> Result<()>
+where
+    W: Write,
+    E: Encode,
+    I: IntoIterator<Item = Result<DataToken>>,
+{
+    let text: Box<dyn TextCodec> = Box::new(DefaultCharacterSetCodec);
+    let mut writer = DataSetWriter::new(to, encoder, text).with_group_lengths(GroupLengths::Remove);
+    let mut in_charset = false;
+    for token in tokens {
+        let token = token?;
+        let charset = match &token {
+            DataToken::ElementHeader(header) => {
+                in_charset = header.tag == tags::SPECIFIC_CHARACTER_SET;
+                None
+            }
+            DataToken::PrimitiveValue(value) if in_charset => {
+                in_charset = false;
+                SpecificCharacterSet::from_value(value)
+            }
+            _ => None,
+        };
+        writer.write(token)?;
+        if let Some(charset) = charset {
+            writer.set_character_set(charset)?;
+        }
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_format, FileFormat, ReadOptions};
+    use byteordered::Endianness;
+    use dicom_dictionary_std::tags;
+    use dicom_encoding::transfer_syntax::TransferSyntaxIndex;
+    use dicom_parser::error::ReadWarningKind;
+    use dicom_parser::parser::ReadLimits;
+    use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
+
+    #[test]
+    fn detect_file_formats() {
+        let mut file = vec![0u8; 128];
+        file.extend(b"DICM");
+        file.extend(&[0x02, 0x00, 0x00, 0x00, b'U', b'L', 0x04, 0x00]);
+        assert_eq!(
+            detect_format(&file).unwrap(),
+            FileFormat::Meta {
+                preamble: true,
+                magic_code: true,
+                explicit_vr: true,
+            }
+        );
+        assert_eq!(
+            detect_format(&file[128..]).unwrap(),
+            FileFormat::Meta {
+                preamble: false,
+                magic_code: true,
+                explicit_vr: true,
+            }
+        );
+        assert_eq!(
+            detect_format(&file[132..]).unwrap(),
+            FileFormat::Meta {
+                preamble: false,
+                magic_code: false,
+                explicit_vr: true,
+            }
+        );
+
+        // (0008,0005) in implicit VR little endian
+        let implicit_le = detect_format(&[0x08, 0x00, 0x05, 0x00, 0x0A, 0x00, 0x00, 0x00]).unwrap();
+        assert_eq!(
+            implicit_le,
+            FileFormat::DataSet {
+                preamble: false,
+                explicit_vr: false,
+                endianness: Endianness::Little,
+            }
+        );
+        assert_eq!(implicit_le.transfer_syntax(), Some("1.2.840.10008.1.2"));
+
+        // (0008,0016) in explicit VR big endian
+        let explicit_be = detect_format(&[0x00, 0x08, 0x00, 0x16, b'U', b'I', 0x00, 0x1A]).unwrap();
+        assert_eq!(
+            explicit_be,
+            FileFormat::DataSet {
+                preamble: false,
+                explicit_vr: true,
+                endianness: Endianness::Big,
+            }
+        );
+        assert_eq!(explicit_be.transfer_syntax(), Some("1.2.840.10008.1.2.2"));
+
+        // not DICOM
+        assert!(detect_format(b"%PDF-1.4\n").is_err());
+        assert!(detect_format(&[0x08, 0x00]).is_err());
+
+        // a zeroed preamble without the meta group
+        let mut file = vec![0u8; 128];
+        file.extend(&[0x08, 0x00, 0x05, 0x00, 0x0A, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            detect_format(&file).unwrap(),
+            FileFormat::DataSet {
+                preamble: true,
+                explicit_vr: false,
+                endianness: Endianness::Little,
+            }
+        );
+        // but not a zeroed preamble alone
+        assert!(detect_format(&[0u8; 136]).is_err());
+
+        // (0002,0000) in implicit VR little endian, after the magic code
+        let mut file = b"DICM".to_vec();
+        file.extend(&[0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            detect_format(&file).unwrap(),
+            FileFormat::Meta {
+                preamble: false,
+                magic_code: true,
+                explicit_vr: false,
+            }
+        );
+        assert_eq!(
+            detect_format(&file[4..]).unwrap(),
+            FileFormat::Meta {
+                preamble: false,
+                magic_code: false,
+                explicit_vr: false,
+            }
+        );
+    }
+
+    #[test]
+    fn read_files_with_detected_format() {
+        // an element in implicit VR little endian
+        fn element(tag: (u16, u16), value: &[u8]) -> Vec<u8> {
+            let mut out = Vec::new();
+            out.extend(&tag.0.to_le_bytes());
+            out.extend(&tag.1.to_le_bytes());
+            out.extend(&(value.len() as u32).to_le_bytes());
+            out.extend(value);
+            out
+        }
+        let data_set = [
+            element((0x0008, 0x0016), b"1.2.840.10008.5.1.4.1.1.7\0"),
+            element((0x0008, 0x0018), b"1.2.3.4\0"),
+            element((0x0010, 0x0010), b"Doe^"),
+        ]
+        .concat();
+
+        // an old export: a preamble directly followed by the data set
+        let mut file = vec![0u8; 128];
+        file.extend(&data_set);
+        let (obj, _) = ReadOptions::new()
+            .detect_format(true)
+            .from_reader(&file[..])
+            .unwrap();
+        assert_eq!(obj.meta().transfer_syntax, "1.2.840.10008.1.2");
+        assert_eq!(obj.meta().media_storage_sop_instance_uid, "1.2.3.4");
+        assert_eq!(
+            obj.element(tags::PATIENT_NAME).unwrap().to_str().unwrap(),
+            "Doe^"
+        );
+
+        // a file meta group in implicit VR
+        let group = [
+            element((0x0002, 0x0001), &[0x00, 0x01]),
+            element((0x0002, 0x0002), b"1.2.840.10008.5.1.4.1.1.7\0"),
+            element((0x0002, 0x0003), b"1.2.3.4\0"),
+            element((0x0002, 0x0010), b"1.2.840.10008.1.2\0"),
+            element((0x0002, 0x0012), b"1.2.345\0"),
+        ]
+        .concat();
+        let mut file = vec![0u8; 128];
+        file.extend(b"DICM");
+        file.extend(element(
+            (0x0002, 0x0000),
+            &(group.len() as u32).to_le_bytes(),
+        ));
+        file.extend(&group);
+        file.extend(&data_set);
+        let (obj, _) = ReadOptions::new()
+            .detect_format(true)
+            .from_reader(&file[..])
+            .unwrap();
+        assert_eq!(obj.meta().transfer_syntax, "1.2.840.10008.1.2\0");
+        assert_eq!(obj.meta().implementation_class_uid, "1.2.345\0");
+        assert_eq!(
+            obj.element(tags::PATIENT_NAME).unwrap().to_str().unwrap(),
+            "Doe^"
+        );
+    }
+
+    #[test]
+    fn combine_read_options() {
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0010,0010) PatientName, PN, len = 4
+            0x10, 0x00, 0x10, 0x00, b'P', b'N', 0x04, 0x00, b'D', b'o', b'e', b'^',
+            // (0008,0060) Modality, CS, len = 2, out of order
+            0x08, 0x00, 0x60, 0x00, b'C', b'S', 0x02, 0x00, b'O', b'T',
+        ];
+        let ts = TransferSyntaxRegistry.get("1.2.840.10008.1.2.1").unwrap();
+
+        let (obj, warnings) = ReadOptions::new()
+            .lenient(true)
+            .limits(ReadLimits::untrusted())
+            .read_dataset(DATA, ts)
+            .unwrap();
+        assert_eq!(obj.element(tags::MODALITY).unwrap().to_str().unwrap(), "OT");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].kind,
+            ReadWarningKind::OutOfOrder(tags::MODALITY)
+        );
+
+        // limits still apply in lenient mode
+        let limits = ReadLimits {
+            max_element_length: Some(2),
+            ..ReadLimits::default()
+        };
+        assert!(ReadOptions::new()
+            .lenient(true)
+            .limits(limits)
+            .read_dataset(DATA, ts)
+            .is_err());
+    }
+}
@@ -20,12 +20,14 @@
 //! # }
 //! ```
 //!
-//! Elements can also be fetched by tag:
+//! Elements can also be fetched by tag,
+//! which spares a look-up in the data dictionary:
 //!
 //! ```
-//! # use dicom_object::{DicomObject, Result, Tag};
+//! # use dicom_object::{DicomObject, Result};
+//! use dicom_dictionary_std::tags;
 //! # fn something<T: DicomObject>(obj: T) -> Result<()> {
-//! let e = obj.element(Tag(0x0002, 0x0002))?;
+//! let e = obj.element(tags::PATIENT_NAME)?;
 //! # Ok(())
 //! # }
 //! ```
@@ -35,14 +37,16 @@ pub mod loader;
 pub mod mem;
 pub mod meta;
 pub mod pixeldata;
+pub mod validation;
 
 mod util;
 
-pub use crate::file::{from_reader, open_file};
+pub use crate::file::{from_reader, open_file, transcode, transcode_file, ReadOptions};
 pub use crate::meta::FileMetaTable;
 pub use dicom_core::Tag;
 pub use dicom_dictionary_std::StandardDataDictionary;
 pub use dicom_parser::error::{Error, Result};
+pub use dicom_parser::parser::ReadLimits;
 
 /// The default implementation of a root DICOM object.
 pub type DefaultDicomObject = RootDicomObject<mem::InMemDicomObject<StandardDataDictionary>>;
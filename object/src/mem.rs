@@ -4,21 +4,25 @@ use itertools::Itertools;
 use smallvec::SmallVec;
 use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{BufWriter, Read, Write};
 use std::path::Path;
 
+use crate::file::{is_native, write_data_set, ReadOptions};
 use crate::meta::FileMetaTable;
 use crate::{DicomObject, RootDicomObject};
+use dicom_core::dictionary::private::{private_tag, trim_creator, PrivateDataDictionary};
 use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
 use dicom_core::header::Header;
 use dicom_core::value::{DicomValueType, Value, ValueType, C};
 use dicom_core::{DataElement, Length, Tag, VR};
-use dicom_dictionary_std::StandardDataDictionary;
+use dicom_dictionary_std::{tags, StandardDataDictionary};
 use dicom_encoding::text::SpecificCharacterSet;
 use dicom_encoding::transfer_syntax::TransferSyntaxIndex;
-use dicom_parser::dataset::{DataSetReader, DataToken};
+use dicom_encoding::TransferSyntax;
+use dicom_parser::dataset::{
+    resolve_lengths, DataToken, IntoTokens, IntoTokensOptions, TokenTransform,
+};
 use dicom_parser::error::{DataSetSyntaxError, Error, Result};
-use dicom_parser::parser::Parse;
 use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
 
 /// A full in-memory DICOM data element.
@@ -55,6 +59,26 @@ impl<D> DicomValueType for InMemDicomObject<D> {
     }
 }
 
+impl<D> IntoTokens for InMemDicomObject<D> {
+    type Iter = std::vec::IntoIter<DataToken>;
+
+    fn into_tokens_with_options(self, options: IntoTokensOptions) -> Self::Iter {
+        let element_options = IntoTokensOptions {
+            undefined_length: true,
+            ..options
+        };
+        let mut tokens: Vec<_> = self
+            .entries
+            .into_values()
+            .flat_map(|elem| elem.into_tokens_with_options(element_options))
+            .collect();
+        if !options.undefined_length {
+            resolve_lengths(&mut tokens, options.explicit_vr);
+        }
+        tokens.into_iter()
+    }
+}
+
 impl<'s, D: 's> DicomObject for &'s InMemDicomObject<D>
 where
     D: DataDictionary,
@@ -148,34 +172,16 @@ where
     /// is insufficient. Otherwise, please use [`open_file_with_dict`] instead.
     ///
     /// [`open_file_with_dict`]: #method.open_file_with_dict
-    pub fn open_file_with<P: AsRef<Path>, R>(path: P, dict: D, ts_index: R) -> Result<Self>
+    pub fn open_file_with<P, R>(path: P, dict: D, ts_index: R) -> Result<Self>
     where
         P: AsRef<Path>,
         R: TransferSyntaxIndex,
     {
-        let mut file = BufReader::new(File::open(path)?);
-
-        // skip preamble
-        {
-            let mut buf = [0u8; 128];
-            // skip the preamble
-            file.read_exact(&mut buf)?;
-        }
-
-        // read metadata header
-        let meta = FileMetaTable::from_reader(&mut file)?;
-
-        // read rest of data according to metadata, feed it to object
-        let ts = ts_index
-            .get(&meta.transfer_syntax)
-            .ok_or(Error::UnsupportedTransferSyntax)?;
-        let cs = SpecificCharacterSet::Default;
-        let mut dataset = DataSetReader::new_with_dictionary(file, dict.clone(), ts, cs)?;
-
-        Ok(RootDicomObject {
-            meta,
-            obj: InMemDicomObject::build_object(&mut dataset, dict, false, Length::UNDEFINED)?,
-        })
+        ReadOptions::new()
+            .dictionary(dict)
+            .transfer_syntax_index(ts_index)
+            .open_file(path)
+            .map(|(obj, _)| obj)
     }
 
     /// Create a DICOM object by reading from a byte source.
@@ -199,24 +205,81 @@ where
     /// is insufficient. Otherwise, please use [`from_reader_with_dict`] instead.
     ///
     /// [`from_reader_with_dict`]: #method.from_reader_with_dict
-    pub fn from_reader_with<'s, S: 's, R>(src: S, dict: D, ts_index: R) -> Result<Self>
+    pub fn from_reader_with<'s, S, R>(src: S, dict: D, ts_index: R) -> Result<Self>
     where
-        S: Read,
+        S: 's + Read,
         R: TransferSyntaxIndex,
     {
-        let mut file = BufReader::new(src);
+        ReadOptions::new()
+            .dictionary(dict)
+            .transfer_syntax_index(ts_index)
+            .from_reader(src)
+            .map(|(obj, _)| obj)
+    }
+
+    /// Write the object to a file, with a 128-byte preamble of zeros,
+    /// the file meta group, and the data set in the transfer syntax
+    /// given by the file meta table.
+    ///
+    /// Only native transfer syntaxes are supported for writing.
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(&[0; 128])?;
+        self.write_to(&mut file)?;
+        file.flush()?;
+        Ok(())
+    }
 
-        // read metadata header
-        let meta = FileMetaTable::from_reader(&mut file)?;
+    /// Write the object to the given destination, with the file meta group,
+    /// followed by the data set in the transfer syntax
+    /// given by the file meta table. No preamble is written.
+    ///
+    /// Only native transfer syntaxes are supported for writing.
+    pub fn write_to<W: Write>(&self, mut to: W) -> Result<()> {
+        let ts = TransferSyntaxRegistry
+            .get(&self.meta.transfer_syntax)
+            .filter(|ts| is_native(ts))
+            .ok_or(Error::UnsupportedTransferSyntax)?;
+        self.meta.write(&mut to)?;
+        let tokens = self
+            .obj
+            .clone()
+            .into_tokens_with_options(IntoTokensOptions {
+                explicit_vr: ts.explicit_vr(),
+                charset: self.obj.charset(),
+                ..Default::default()
+            })
+            .map(Ok);
+        write_data_set(to, ts, tokens)
+    }
 
-        // read rest of data according to metadata, feed it to object
-        let ts = ts_index
-            .get(&meta.transfer_syntax)
+    /// Prepare the object for writing in another transfer syntax.
+    ///
+    /// Both the current transfer syntax and the target one must be native:
+    /// implicit VR little endian, explicit VR little endian
+    /// or explicit VR big endian. Values of unknown value representation
+    /// (UN) are decoded in the representation given by the dictionary,
+    /// where possible, and the transfer syntax in the file meta table
+    /// is updated. The byte order of values is handled on writing.
+    pub fn transcode_to(&mut self, ts: &TransferSyntax) -> Result<()> {
+        let source_ts = TransferSyntaxRegistry
+            .get(&self.meta.transfer_syntax)
             .ok_or(Error::UnsupportedTransferSyntax)?;
-        let cs = SpecificCharacterSet::Default;
-        let mut dataset = DataSetReader::new_with_dictionary(file, dict.clone(), ts, cs)?;
-        let obj = InMemDicomObject::build_object(&mut dataset, dict, false, Length::UNDEFINED)?;
-        Ok(RootDicomObject { meta, obj })
+        if !is_native(source_ts) || !is_native(ts) {
+            return Err(Error::UnsupportedTransferSyntax);
+        }
+        let dict = self.obj.dict.clone();
+        let obj = std::mem::replace(
+            &mut self.obj,
+            InMemDicomObject::new_empty_with_dict(dict.clone()),
+        );
+        let mut tokens = obj
+            .into_tokens()
+            .map(Ok)
+            .transcode_values(source_ts, dict.clone())?;
+        self.obj = InMemDicomObject::build_object(&mut tokens, dict, false, Length::UNDEFINED)?;
+        self.meta.set_transfer_syntax(ts.uid());
+        Ok(())
     }
 }
 
@@ -270,6 +333,78 @@ where
         self.element(tag)
     }
 
+    /// Retrieve the data dictionary of this object.
+    pub fn dictionary(&self) -> &D {
+        &self.dict
+    }
+
+    /// Retrieve a private DICOM element by its group, private creator and
+    /// element offset within the block reserved by that creator.
+    ///
+    /// The reserved block is resolved from the private creator elements
+    /// `(gggg,0010)`-`(gggg,00FF)` present in this object.
+    pub fn private_element(
+        &self,
+        group: u16,
+        creator: &str,
+        offset: u8,
+    ) -> Result<&InMemElement<D>> {
+        let block = self
+            .private_block(group, creator)
+            .ok_or(Error::NoSuchDataElement)?;
+        self.element(private_tag(group, block, offset))
+    }
+
+    /// Retrieve a private DICOM element by its private creator and name,
+    /// as registered in the given private dictionary.
+    pub fn private_element_by_name<P>(
+        &self,
+        private_dict: &P,
+        creator: &str,
+        name: &str,
+    ) -> Result<&InMemElement<D>>
+    where
+        P: PrivateDataDictionary,
+    {
+        let entry = private_dict
+            .by_name(creator, name)
+            .ok_or(Error::NoSuchAttributeName)?;
+        self.private_element(entry.group, creator, entry.offset)
+    }
+
+    /// Find the block of private elements reserved by the given private
+    /// creator in the given group.
+    pub fn private_block(&self, group: u16, creator: &str) -> Option<u8> {
+        let creator = trim_creator(creator);
+        self.entries
+            .range(Tag(group, 0x0010)..=Tag(group, 0x00FF))
+            .find(|(_, e)| {
+                e.to_str()
+                    .map(|v| trim_creator(&v) == creator)
+                    .unwrap_or(false)
+            })
+            .map(|(tag, _)| tag.1 as u8)
+    }
+
+    /// Write the data set to the given destination
+    /// in the given transfer syntax, with no file meta group.
+    ///
+    /// Only native transfer syntaxes are supported for writing.
+    pub fn write_dataset_with_ts<W: Write>(&self, to: W, ts: &TransferSyntax) -> Result<()> {
+        if !is_native(ts) {
+            return Err(Error::UnsupportedTransferSyntax);
+        }
+        let tokens = self
+            .clone()
+            .into_tokens_with_options(IntoTokensOptions {
+                explicit_vr: ts.explicit_vr(),
+                charset: self.charset(),
+                ..Default::default()
+            })
+            .map(Ok);
+        write_data_set(to, ts, tokens)
+    }
+
     /// Insert a data element to the object, replacing (and returning) any
     /// previous element of the same attribute.
     pub fn put(&mut self, elt: InMemElement<D>) -> Option<InMemElement<D>> {
@@ -278,16 +413,16 @@ where
 
     // private methods
 
-    /// Build an object by consuming a data set parser.
-    fn build_object<'s, S: 's, P>(
-        dataset: &mut DataSetReader<S, P, D>,
+    /// Build an object by consuming a stream of data set tokens,
+    /// such as a data set parser.
+    pub(crate) fn build_object<I>(
+        dataset: &mut I,
         dict: D,
         in_item: bool,
         len: Length,
     ) -> Result<Self>
     where
-        S: Read,
-        P: Parse<dyn Read + 's>,
+        I: Iterator<Item = Result<DataToken>>,
     {
         let mut entries: BTreeMap<Tag, InMemElement<D>> = BTreeMap::new();
         // perform a structured parsing of incoming tokens
@@ -322,16 +457,15 @@ where
         Ok(InMemDicomObject { entries, dict, len })
     }
 
-    /// Build a DICOM sequence by consuming a data set parser.
-    fn build_sequence<'s, S: 's, P>(
+    /// Build a DICOM sequence by consuming a stream of data set tokens.
+    fn build_sequence<I>(
         _tag: Tag,
         _len: Length,
-        dataset: &mut DataSetReader<S, P, D>,
+        dataset: &mut I,
         dict: &D,
     ) -> Result<C<InMemDicomObject<D>>>
     where
-        S: Read,
-        P: Parse<dyn Read + 's>,
+        I: Iterator<Item = Result<DataToken>>,
     {
         let mut items: C<_> = SmallVec::new();
         while let Some(token) = dataset.next() {
@@ -352,6 +486,16 @@ where
         Err(DataSetSyntaxError::PrematureEnd.into())
     }
 
+    /// Obtain the character set declared in the object,
+    /// or the default character set if not declared or not supported.
+    fn charset(&self) -> SpecificCharacterSet {
+        self.element(tags::SPECIFIC_CHARACTER_SET)
+            .ok()
+            .and_then(|e| e.value().primitive())
+            .and_then(SpecificCharacterSet::from_value)
+            .unwrap_or(SpecificCharacterSet::Default)
+    }
+
     fn lookup_name(&self, name: &str) -> Result<Tag> {
         self.dict
             .by_name(name)
@@ -406,15 +550,20 @@ impl<D> Iterator for Iter<D> {
 mod tests {
 
     use super::*;
+    use crate::meta::{FileMetaTableBuilder, IMPLEMENTATION_CLASS_UID};
+    use dicom_core::dictionary::private::PrivateDictionary;
     use dicom_core::value::PrimitiveValue;
     use dicom_core::VR;
+    use dicom_dictionary_std::tags;
+    use dicom_parser::dataset::DataSetReader;
+    use dicom_parser::parser::ReadLimits;
 
     #[test]
     fn inmem_object_write() {
         let mut obj1 = InMemDicomObject::create_empty();
         let mut obj2 = InMemDicomObject::create_empty();
         assert_eq!(obj1, obj2);
-        let empty_patient_name = DataElement::empty(Tag(0x0010, 0x0010), VR::PN);
+        let empty_patient_name = DataElement::empty(tags::PATIENT_NAME, VR::PN);
         obj1.put(empty_patient_name.clone());
         assert_ne!(obj1, obj2);
         obj2.put(empty_patient_name.clone());
@@ -424,26 +573,367 @@ mod tests {
     #[test]
     fn inmem_object_get() {
         let another_patient_name = DataElement::new(
-            Tag(0x0010, 0x0010),
+            tags::PATIENT_NAME,
             VR::PN,
             PrimitiveValue::Str("Doe^John".to_string()).into(),
         );
         let mut obj = InMemDicomObject::create_empty();
         obj.put(another_patient_name.clone());
-        let elem1 = (&obj).element(Tag(0x0010, 0x0010)).unwrap();
+        let elem1 = obj.element(tags::PATIENT_NAME).unwrap();
         assert_eq!(elem1, &another_patient_name);
     }
 
     #[test]
     fn inmem_object_get_by_name() {
         let another_patient_name = DataElement::new(
-            Tag(0x0010, 0x0010),
+            tags::PATIENT_NAME,
             VR::PN,
             PrimitiveValue::Str("Doe^John".to_string()).into(),
         );
         let mut obj = InMemDicomObject::create_empty();
         obj.put(another_patient_name.clone());
-        let elem1 = (&obj).element_by_name("PatientName").unwrap();
+        let elem1 = obj.element_by_name("PatientName").unwrap();
         assert_eq!(elem1, &another_patient_name);
     }
+
+    #[test]
+    fn inmem_object_get_private() {
+        use dicom_core::dictionary::private::PrivateDictionaryEntry;
+
+        let creator = DataElement::new(
+            Tag(0x0029, 0x0011),
+            VR::LO,
+            PrimitiveValue::Strs(["SIEMENS CSA HEADER".to_string()].as_ref().into()).into(),
+        );
+        let header_type = DataElement::new(
+            Tag(0x0029, 0x1108),
+            VR::CS,
+            PrimitiveValue::Strs(["IMAGE NUM 4".to_string()].as_ref().into()).into(),
+        );
+        let mut obj = InMemDicomObject::create_empty();
+        obj.put(creator);
+        obj.put(header_type.clone());
+
+        assert_eq!(obj.private_block(0x0029, "SIEMENS CSA HEADER"), Some(0x11));
+        let elem = obj
+            .private_element(0x0029, "SIEMENS CSA HEADER", 0x08)
+            .unwrap();
+        assert_eq!(elem, &header_type);
+        assert!(obj.private_element(0x0029, "ACME", 0x08).is_err());
+
+        let private_dict: PrivateDictionary = vec![PrivateDictionaryEntry {
+            creator: "SIEMENS CSA HEADER".to_string(),
+            group: 0x0029,
+            offset: 0x08,
+            alias: "CSAImageHeaderType".to_string(),
+            vr: VR::CS,
+        }]
+        .into_iter()
+        .collect();
+        let elem = obj
+            .private_element_by_name(&private_dict, "SIEMENS CSA HEADER", "CSAImageHeaderType")
+            .unwrap();
+        assert_eq!(elem, &header_type);
+    }
+
+    #[test]
+    fn read_data_set_without_meta_group() {
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0008,0016) SOPClassUID; len = 26
+            0x08, 0x00, 0x16, 0x00, 0x1A, 0x00, 0x00, 0x00,
+            b'1', b'.', b'2', b'.', b'8', b'4', b'0', b'.', b'1', b'0', b'0', b'0', b'8', b'.',
+            b'5', b'.', b'1', b'.', b'4', b'.', b'1', b'.', b'1', b'.', b'7', 0x00,
+            // (0008,0018) SOPInstanceUID; len = 8
+            0x08, 0x00, 0x18, 0x00, 0x08, 0x00, 0x00, 0x00,
+            b'1', b'.', b'2', b'.', b'3', b'.', b'4', 0x00,
+            // (0010,0010) PatientName; len = 4
+            0x10, 0x00, 0x10, 0x00, 0x04, 0x00, 0x00, 0x00, b'D', b'o', b'e', b'^',
+        ];
+
+        let (obj, _) = ReadOptions::new()
+            .detect_format(true)
+            .from_reader(DATA)
+            .unwrap();
+        assert_eq!(obj.meta().transfer_syntax, "1.2.840.10008.1.2");
+        assert_eq!(
+            obj.meta().media_storage_sop_class_uid,
+            "1.2.840.10008.5.1.4.1.1.7"
+        );
+        assert_eq!(obj.meta().media_storage_sop_instance_uid, "1.2.3.4");
+        assert_eq!(
+            obj.element(tags::PATIENT_NAME).unwrap().to_str().unwrap(),
+            "Doe^"
+        );
+    }
+
+    #[test]
+    fn inmem_object_into_tokens() {
+        use dicom_core::header::DataElementHeader;
+        use dicom_encoding::text::DefaultCharacterSetCodec;
+        use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianEncoder;
+        use dicom_parser::dataset::DataSetWriter;
+
+        let mut item = InMemDicomObject::create_empty();
+        item.put(DataElement::new(
+            Tag(0x0040, 0xA040),
+            VR::CS,
+            PrimitiveValue::Strs(["TEXT".to_string()].as_ref().into()).into(),
+        ));
+        item.put(DataElement::new(
+            Tag(0x0040, 0xA160),
+            VR::UT,
+            PrimitiveValue::Str("Some text.".to_string()).into(),
+        ));
+        let mut obj = InMemDicomObject::create_empty();
+        obj.put(DataElement::new(
+            tags::PATIENT_NAME,
+            VR::PN,
+            PrimitiveValue::Str("Doe^Jon".to_string()).into(),
+        ));
+        obj.put(DataElement::new(
+            Tag(0x0040, 0xA730),
+            VR::SQ,
+            Value::Sequence {
+                items: SmallVec::from_vec(vec![item]),
+                size: Length::UNDEFINED,
+            },
+        ));
+
+        let tokens: Vec<_> = obj.clone().into_tokens().collect();
+        assert_eq!(
+            tokens,
+            vec![
+                DataToken::ElementHeader(DataElementHeader::new(
+                    tags::PATIENT_NAME,
+                    VR::PN,
+                    Length(8),
+                )),
+                DataToken::PrimitiveValue(PrimitiveValue::Str("Doe^Jon".to_string())),
+                DataToken::SequenceStart {
+                    tag: Tag(0x0040, 0xA730),
+                    len: Length::UNDEFINED,
+                },
+                DataToken::ItemStart {
+                    len: Length::UNDEFINED,
+                },
+                DataToken::ElementHeader(DataElementHeader::new(
+                    Tag(0x0040, 0xA040),
+                    VR::CS,
+                    Length(4),
+                )),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["TEXT".to_string()].as_ref().into(),
+                )),
+                DataToken::ElementHeader(DataElementHeader::new(
+                    Tag(0x0040, 0xA160),
+                    VR::UT,
+                    Length(10),
+                )),
+                DataToken::PrimitiveValue(PrimitiveValue::Str("Some text.".to_string())),
+                DataToken::ItemEnd,
+                DataToken::SequenceEnd,
+            ]
+        );
+
+        // with lengths computed for explicit VR little endian
+        let tokens: Vec<_> = obj
+            .clone()
+            .into_tokens_with_options(IntoTokensOptions {
+                undefined_length: false,
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(
+            tokens[2],
+            DataToken::SequenceStart {
+                tag: Tag(0x0040, 0xA730),
+                len: Length(8 + 12 + 22),
+            }
+        );
+        assert_eq!(
+            tokens[3],
+            DataToken::ItemStart {
+                len: Length(12 + 22)
+            }
+        );
+
+        // the tokens can be written and read back
+        let ts = TransferSyntaxRegistry.get("1.2.840.10008.1.2.1").unwrap();
+        let mut data = Vec::new();
+        DataSetWriter::new(
+            &mut data,
+            ExplicitVRLittleEndianEncoder::default(),
+            DefaultCharacterSetCodec,
+        )
+        .write_sequence(tokens)
+        .unwrap();
+        assert_eq!(data.len(), 8 + 8 + 12 + 8 + 12 + 22);
+        let mut dataset =
+            DataSetReader::new_with(&data[..], ts, SpecificCharacterSet::Default).unwrap();
+        let read = InMemDicomObject::build_object(
+            &mut dataset,
+            StandardDataDictionary,
+            false,
+            Length::UNDEFINED,
+        )
+        .unwrap();
+        assert_eq!(
+            read.element(Tag(0x0040, 0xA730)).unwrap().value().item(),
+            obj.element(Tag(0x0040, 0xA730)).unwrap().value().item()
+        );
+    }
+
+    #[test]
+    fn write_and_read_data_set() {
+        let mut obj = InMemDicomObject::create_empty();
+        obj.put(DataElement::new(
+            tags::QUERY_RETRIEVE_LEVEL,
+            VR::CS,
+            PrimitiveValue::Strs(["STUDY".to_string()].as_ref().into()).into(),
+        ));
+        obj.put(DataElement::new(
+            tags::STUDY_INSTANCE_UID,
+            VR::UI,
+            PrimitiveValue::Strs(["1.2.3".to_string(), "1.2.4".to_string()].as_ref().into()).into(),
+        ));
+
+        for uid in &["1.2.840.10008.1.2", "1.2.840.10008.1.2.1"] {
+            let ts = TransferSyntaxRegistry.get(uid).unwrap();
+            let mut data = Vec::new();
+            obj.write_dataset_with_ts(&mut data, ts).unwrap();
+            // no file meta group and no group length
+            assert_eq!(&data[..4], &[0x08, 0x00, 0x52, 0x00]);
+
+            let (read, _) = ReadOptions::new().read_dataset(&data[..], ts).unwrap();
+            assert_eq!(
+                read.element(tags::QUERY_RETRIEVE_LEVEL)
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+                    .trim(),
+                "STUDY"
+            );
+            let uids = read.element(tags::STUDY_INSTANCE_UID).unwrap();
+            assert_eq!(
+                uids.value().primitive().unwrap().strings().unwrap().len(),
+                2
+            );
+        }
+
+        // the limits of untrusted sources apply to data sets too
+        let ts = TransferSyntaxRegistry.get("1.2.840.10008.1.2.1").unwrap();
+        let mut data = Vec::new();
+        obj.write_dataset_with_ts(&mut data, ts).unwrap();
+        let limits = ReadLimits {
+            max_element_length: Some(8),
+            ..ReadLimits::default()
+        };
+        assert!(ReadOptions::new()
+            .limits(limits)
+            .read_dataset(&data[..], ts)
+            .is_err());
+        assert!(ReadOptions::new()
+            .limits(ReadLimits::untrusted())
+            .read_dataset(&data[..], ts)
+            .is_ok());
+
+        let jpeg_baseline = TransferSyntaxRegistry
+            .get("1.2.840.10008.1.2.4.50")
+            .unwrap();
+        assert!(obj.write_dataset_with_ts(Vec::new(), jpeg_baseline).is_err());
+    }
+
+    #[test]
+    fn transcode_object() {
+        use crate::file::transcode;
+
+        let meta = FileMetaTableBuilder::new()
+            .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.7".to_string())
+            .media_storage_sop_instance_uid("2.25.1234".to_string())
+            .transfer_syntax("1.2.840.10008.1.2".to_string())
+            .implementation_class_uid(IMPLEMENTATION_CLASS_UID.to_string())
+            .build()
+            .unwrap();
+        let mut item = InMemDicomObject::create_empty();
+        item.put(DataElement::new(
+            Tag(0x0040, 0xA040),
+            VR::CS,
+            PrimitiveValue::Strs(["TEXT".to_string()].as_ref().into()).into(),
+        ));
+        let mut obj = RootDicomObject::new_empty_with_dict_and_meta(StandardDataDictionary, meta);
+        obj.put(DataElement::new(
+            tags::MODALITY,
+            VR::UN,
+            PrimitiveValue::U8(b"OT".as_ref().into()).into(),
+        ));
+        obj.put(DataElement::new(
+            tags::PATIENT_NAME,
+            VR::PN,
+            PrimitiveValue::Strs(["Doe^John".to_string()].as_ref().into()).into(),
+        ));
+        obj.put(DataElement::new(
+            tags::SERIES_NUMBER,
+            VR::IS,
+            PrimitiveValue::I32([5].as_ref().into()).into(),
+        ));
+        obj.put(DataElement::new(
+            tags::ROWS,
+            VR::US,
+            PrimitiveValue::U16([2].as_ref().into()).into(),
+        ));
+        obj.put(DataElement::new(
+            Tag(0x0040, 0xA730),
+            VR::SQ,
+            Value::Sequence {
+                items: SmallVec::from_vec(vec![item]),
+                size: Length::UNDEFINED,
+            },
+        ));
+        obj.put(DataElement::new(
+            tags::PIXEL_DATA,
+            VR::OW,
+            PrimitiveValue::U16([0x0102, 0x0304].as_ref().into()).into(),
+        ));
+
+        let mut implicit_vr = Vec::new();
+        obj.write_to(&mut implicit_vr).unwrap();
+
+        let big_endian = TransferSyntaxRegistry.get("1.2.840.10008.1.2.2").unwrap();
+        obj.transcode_to(big_endian).unwrap();
+        assert_eq!(obj.meta().transfer_syntax, "1.2.840.10008.1.2.2\0");
+        let modality = obj.element(tags::MODALITY).unwrap();
+        assert_eq!(modality.vr(), VR::CS);
+        assert_eq!(modality.to_str().unwrap(), "OT");
+
+        let mut explicit_vr = Vec::new();
+        obj.write_to(&mut explicit_vr).unwrap();
+        // values are written in big endian
+        let pixel_data: &[u8] = &[
+            0x7F, 0xE0, 0x00, 0x10, b'O', b'W', 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x01, 0x02,
+            0x03, 0x04,
+        ];
+        assert!(explicit_vr.ends_with(pixel_data));
+
+        let read = RootDicomObject::from_reader(&explicit_vr[..]).unwrap();
+        assert_eq!(read.meta().transfer_syntax, "1.2.840.10008.1.2.2\0");
+        for tag in &[
+            tags::MODALITY,
+            tags::PATIENT_NAME,
+            tags::SERIES_NUMBER,
+            tags::ROWS,
+            tags::PIXEL_DATA,
+        ] {
+            assert_eq!(read.element(*tag).unwrap(), obj.element(*tag).unwrap());
+        }
+        assert_eq!(
+            read.element(Tag(0x0040, 0xA730)).unwrap().value().item(),
+            obj.element(Tag(0x0040, 0xA730)).unwrap().value().item()
+        );
+
+        // the same content is obtained without holding the object in memory
+        let mut streamed = Vec::new();
+        transcode(&implicit_vr[..], &mut streamed, big_endian).unwrap();
+        assert_eq!(streamed, explicit_vr);
+    }
 }
@@ -1,13 +1,25 @@
 //! Module containing data structures and readers of DICOM file meta information tables.
 use byteordered::byteorder::{ByteOrder, LittleEndian};
-use dicom_core::header::Header;
-use dicom_core::{Length, Tag};
-use dicom_encoding::decode::{self, DecodeFrom};
+use dicom_core::header::{DataElementHeader, Header};
+use dicom_core::{Length, Tag, VR};
+use dicom_dictionary_std::tags;
+use dicom_encoding::decode::{self, Decode};
+use dicom_encoding::encode::Encode;
 use dicom_encoding::text::{self, TextCodec};
+use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianEncoder;
+use dicom_encoding::transfer_syntax::implicit_le::ImplicitVRLittleEndianDecoder;
 use dicom_parser::error::{Error, InvalidValueReadError, Result};
-use std::io::Read;
+use std::io::{self, Read, Write};
 
-const DICM_MAGIC_CODE: [u8; 4] = [b'D', b'I', b'C', b'M'];
+pub(crate) const DICM_MAGIC_CODE: [u8; 4] = [b'D', b'I', b'C', b'M'];
+
+/// The implementation class UID of this library,
+/// used in the file meta tables that it creates.
+pub const IMPLEMENTATION_CLASS_UID: &str = "2.25.137038125948464847900039011591283709926";
+
+/// The implementation version name of this library,
+/// used in the file meta tables that it creates.
+pub const IMPLEMENTATION_VERSION_NAME: &str = "DICOM-rs 0.1";
 
 /// DICOM File Meta Information Table.
 ///
@@ -44,20 +56,61 @@ pub struct FileMetaTable {
     pub private_information: Option<Vec<u8>>,
 }
 
+/// Utility function for reading the body of a DICOM element,
+/// which must fit in the rest of the group along with its header.
+/// The length is checked before any memory is allocated for the value.
+fn read_body<S>(
+    source: &mut S,
+    group_length_remaining: &mut u32,
+    header_len: u32,
+    len: u32,
+) -> Result<Vec<u8>>
+where
+    S: Read,
+{
+    let total = header_len
+        .checked_add(len)
+        .filter(|total| *total <= *group_length_remaining)
+        .ok_or(Error::UnexpectedDataValueLength)?;
+    let mut v = Vec::new();
+    source.take(u64::from(len)).read_to_end(&mut v)?;
+    if v.len() != len as usize {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    *group_length_remaining -= total;
+    Ok(v)
+}
+
+/// Utility function for writing a DICOM element of the file meta group,
+/// padding the value to an even length.
+fn write_element<W: Write>(to: &mut W, tag: Tag, vr: VR, value: &[u8]) -> Result<()> {
+    let len = (value.len() as u32 + 1) & !1;
+    ExplicitVRLittleEndianEncoder::default()
+        .encode_element_header(&mut *to, DataElementHeader::new(tag, vr, Length(len)))?;
+    to.write_all(value)?;
+    if value.len() % 2 == 1 {
+        let padding = match vr {
+            VR::UI | VR::OB => 0,
+            _ => b' ',
+        };
+        to.write_all(&[padding])?;
+    }
+    Ok(())
+}
+
 /// Utility function for reading the body of the DICOM element as a UID.
 fn read_str_body<'s, S: 's, T>(
     source: &'s mut S,
     text: &T,
     group_length_remaining: &mut u32,
+    header_len: u32,
     len: u32,
 ) -> Result<String>
 where
     S: Read,
     T: TextCodec,
 {
-    let mut v = vec![0; len as usize];
-    source.read_exact(&mut v)?;
-    *group_length_remaining -= 8 + len;
+    let v = read_body(source, group_length_remaining, header_len, len)?;
     text.decode(&v).map_err(From::from)
 }
 
@@ -66,6 +119,100 @@ impl FileMetaTable {
         FileMetaTable::read_from(file)
     }
 
+    /// Change the transfer syntax of the data set,
+    /// padding the UID to an even length.
+    /// The group length is computed again when the table is written.
+    pub fn set_transfer_syntax(&mut self, uid: &str) {
+        let mut uid = uid.to_string();
+        if uid.len() % 2 == 1 {
+            uid.push('\0');
+        }
+        self.transfer_syntax = uid;
+    }
+
+    /// Write the magic code and the file meta group,
+    /// in explicit VR little endian.
+    pub fn write<W: Write>(&self, mut to: W) -> Result<()> {
+        // the group length is taken from the elements actually written,
+        // so the rest of the group is encoded first
+        let group = self.encode_group()?;
+        to.write_all(&DICM_MAGIC_CODE)?;
+        write_element(
+            &mut to,
+            tags::FILE_META_INFORMATION_GROUP_LENGTH,
+            VR::UL,
+            &(group.len() as u32).to_le_bytes(),
+        )?;
+        to.write_all(&group)?;
+        Ok(())
+    }
+
+    /// Encode the elements of the file meta group
+    /// which follow the group length.
+    fn encode_group(&self) -> Result<Vec<u8>> {
+        let mut group = Vec::new();
+        write_element(
+            &mut group,
+            tags::FILE_META_INFORMATION_VERSION,
+            VR::OB,
+            &self.information_version,
+        )?;
+        let uids = [
+            (
+                tags::MEDIA_STORAGE_SOP_CLASS_UID,
+                &self.media_storage_sop_class_uid,
+            ),
+            (
+                tags::MEDIA_STORAGE_SOP_INSTANCE_UID,
+                &self.media_storage_sop_instance_uid,
+            ),
+            (tags::TRANSFER_SYNTAX_UID, &self.transfer_syntax),
+            (
+                tags::IMPLEMENTATION_CLASS_UID,
+                &self.implementation_class_uid,
+            ),
+        ];
+        for (tag, uid) in &uids {
+            write_element(&mut group, *tag, VR::UI, uid.as_bytes())?;
+        }
+        let optional = [
+            (
+                tags::IMPLEMENTATION_VERSION_NAME,
+                VR::SH,
+                &self.implementation_version_name,
+            ),
+            (
+                tags::SOURCE_APPLICATION_ENTITY_TITLE,
+                VR::AE,
+                &self.source_application_entity_title,
+            ),
+            (
+                tags::SENDING_APPLICATION_ENTITY_TITLE,
+                VR::AE,
+                &self.sending_application_entity_title,
+            ),
+            (
+                tags::RECEIVING_APPLICATION_ENTITY_TITLE,
+                VR::AE,
+                &self.receiving_application_entity_title,
+            ),
+            (
+                tags::PRIVATE_INFORMATION_CREATOR_UID,
+                VR::UI,
+                &self.private_information_creator_uid,
+            ),
+        ];
+        for (tag, vr, value) in &optional {
+            if let Some(value) = value {
+                write_element(&mut group, *tag, *vr, value.as_bytes())?;
+            }
+        }
+        if let Some(value) = &self.private_information {
+            write_element(&mut group, tags::PRIVATE_INFORMATION, VR::OB, value)?;
+        }
+        Ok(group)
+    }
+
     fn read_from<S: Read>(mut file: S) -> Result<Self> {
         let mut buff: [u8; 4] = [0; 4];
         {
@@ -77,14 +224,29 @@ impl FileMetaTable {
             }
         }
 
-        let decoder = decode::file_header_decoder();
+        Self::read_group_with(file, decode::file_header_decoder())
+    }
+
+    /// Read the file meta group from a source with no magic code,
+    /// in explicit or implicit VR little endian.
+    /// The standard demands explicit VR,
+    /// but some old files have their meta group in implicit VR.
+    pub(crate) fn read_group<S: Read>(file: S, explicit_vr: bool) -> Result<Self> {
+        if explicit_vr {
+            Self::read_group_with(file, decode::file_header_decoder())
+        } else {
+            Self::read_group_with(file, ImplicitVRLittleEndianDecoder::default())
+        }
+    }
+
+    fn read_group_with<S: Read, D: Decode>(mut file: S, decoder: D) -> Result<Self> {
         let text = text::DefaultCharacterSetCodec;
 
         let builder = FileMetaTableBuilder::new();
 
         let group_length: u32 = {
             let (elem, _bytes_read) = decoder.decode_header(&mut file)?;
-            if elem.tag() != (0x0002, 0x0000) {
+            if elem.tag() != tags::FILE_META_INFORMATION_GROUP_LENGTH {
                 return Err(Error::UnexpectedTag(elem.tag()));
             }
             if elem.len() != Length(4) {
@@ -101,7 +263,8 @@ impl FileMetaTable {
 
         // Fetch optional data elements
         while group_length_remaining > 0 {
-            let (elem, _bytes_read) = decoder.decode_header(&mut file)?;
+            let (elem, header_len) = decoder.decode_header(&mut file)?;
+            let header_len = header_len as u32;
             let elem_len = match elem.len().get() {
                 None => {
                     return Err(Error::from(InvalidValueReadError::UnresolvedValueLength));
@@ -109,95 +272,95 @@ impl FileMetaTable {
                 Some(len) => len,
             };
             builder = match elem.tag() {
-                Tag(0x0002, 0x0001) => {
+                tags::FILE_META_INFORMATION_VERSION => {
                     // Implementation Version
                     if elem.len() != Length(2) {
                         return Err(Error::UnexpectedDataValueLength);
                     }
-                    let mut hbuf = [0u8; 2];
-                    file.read_exact(&mut hbuf[..])?;
-                    group_length_remaining -= 14;
-
-                    builder.information_version(hbuf)
+                    let v = read_body(&mut file, &mut group_length_remaining, header_len, 2)?;
+                    builder.information_version([v[0], v[1]])
                 }
                 // Media Storage SOP Class UID
-                Tag(0x0002, 0x0002) => builder.media_storage_sop_class_uid(read_str_body(
-                    &mut file,
-                    &text,
-                    &mut group_length_remaining,
-                    elem_len,
-                )?),
+                tags::MEDIA_STORAGE_SOP_CLASS_UID => {
+                    builder.media_storage_sop_class_uid(read_str_body(
+                        &mut file,
+                        &text,
+                        &mut group_length_remaining,
+                        header_len,
+                        elem_len,
+                    )?)
+                }
                 // Media Storage SOP Instance UID
-                Tag(0x0002, 0x0003) => builder.media_storage_sop_instance_uid(read_str_body(
-                    &mut file,
-                    &text,
-                    &mut group_length_remaining,
-                    elem_len,
-                )?),
+                tags::MEDIA_STORAGE_SOP_INSTANCE_UID => {
+                    builder.media_storage_sop_instance_uid(read_str_body(
+                        &mut file,
+                        &text,
+                        &mut group_length_remaining,
+                        header_len,
+                        elem_len,
+                    )?)
+                }
                 // Transfer Syntax
-                Tag(0x0002, 0x0010) => builder.transfer_syntax(read_str_body(
+                tags::TRANSFER_SYNTAX_UID => builder.transfer_syntax(read_str_body(
                     &mut file,
                     &text,
                     &mut group_length_remaining,
+                    header_len,
                     elem_len,
                 )?),
                 // Implementation Class UID
-                Tag(0x0002, 0x0012) => builder.implementation_class_uid(read_str_body(
+                tags::IMPLEMENTATION_CLASS_UID => builder.implementation_class_uid(read_str_body(
                     &mut file,
                     &text,
                     &mut group_length_remaining,
+                    header_len,
                     elem_len,
                 )?),
-                Tag(0x0002, 0x0013) => {
+                tags::IMPLEMENTATION_VERSION_NAME => {
                     // Implementation Version Name
-                    let mut v = vec![0; elem_len as usize];
-                    file.read_exact(&mut v)?;
-                    group_length_remaining -= 8 + elem_len;
+                    let v =
+                        read_body(&mut file, &mut group_length_remaining, header_len, elem_len)?;
                     builder.implementation_version_name(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0016) => {
+                tags::SOURCE_APPLICATION_ENTITY_TITLE => {
                     // Source Application Entity Title
-                    let mut v = vec![0; elem_len as usize];
-                    file.read_exact(&mut v)?;
-                    group_length_remaining -= 8 + elem_len;
+                    let v =
+                        read_body(&mut file, &mut group_length_remaining, header_len, elem_len)?;
                     builder.source_application_entity_title(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0017) => {
+                tags::SENDING_APPLICATION_ENTITY_TITLE => {
                     // Sending Application Entity Title
-                    let mut v = vec![0; elem_len as usize];
-                    file.read_exact(&mut v)?;
-                    group_length_remaining -= 8 + elem_len;
+                    let v =
+                        read_body(&mut file, &mut group_length_remaining, header_len, elem_len)?;
                     builder.sending_application_entity_title(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0018) => {
+                tags::RECEIVING_APPLICATION_ENTITY_TITLE => {
                     // Receiving Application Entity Title
-                    let mut v = vec![0; elem_len as usize];
-                    file.read_exact(&mut v)?;
-                    group_length_remaining -= 8 + elem_len;
+                    let v =
+                        read_body(&mut file, &mut group_length_remaining, header_len, elem_len)?;
                     builder.receiving_application_entity_title(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0100) => {
+                tags::PRIVATE_INFORMATION_CREATOR_UID => {
                     // Private Information Creator UID
-                    let mut v = vec![0; elem_len as usize];
-                    file.read_exact(&mut v)?;
-                    group_length_remaining -= 8 + elem_len;
+                    let v =
+                        read_body(&mut file, &mut group_length_remaining, header_len, elem_len)?;
                     builder.private_information_creator_uid(text.decode(&v)?)
                 }
-                Tag(0x0002, 0x0102) => {
+                tags::PRIVATE_INFORMATION => {
                     // Private Information
-                    let mut v = vec![0; elem_len as usize];
-                    file.read_exact(&mut v)?;
-                    group_length_remaining -= 12 + elem_len;
+                    let v =
+                        read_body(&mut file, &mut group_length_remaining, header_len, elem_len)?;
                     builder.private_information(v)
                 }
                 Tag(0x0002, _) => {
-                    // unknown tag, do nothing
+                    // unknown tag, skip its value
                     // could be an unsupported or non-standard attribute
+                    read_body(&mut file, &mut group_length_remaining, header_len, elem_len)?;
                     builder
                 }
                 _ => {
-                    // unexpected tag from another group! do nothing for now
-                    builder
+                    // unexpected tag from another group
+                    return Err(Error::UnexpectedTag(elem.tag()));
                 }
             }
         }
@@ -334,10 +497,10 @@ impl FileMetaTableBuilder {
     }
 
     /// Build the table.
+    ///
+    /// If the group length was not given,
+    /// it is calculated from the encoded length of the other elements.
     pub fn build(self) -> Result<FileMetaTable> {
-        let information_group_length = self
-            .information_group_length
-            .ok_or_else(|| Error::InvalidFormat)?;
         let information_version = self.information_version.unwrap_or_else(|| {
             // Missing information version, will assume (00H, 01H). See #28
             [0, 1]
@@ -352,8 +515,8 @@ impl FileMetaTableBuilder {
         let implementation_class_uid = self
             .implementation_class_uid
             .ok_or_else(|| Error::InvalidFormat)?;
-        Ok(FileMetaTable {
-            information_group_length,
+        let mut table = FileMetaTable {
+            information_group_length: self.information_group_length.unwrap_or(0),
             information_version,
             media_storage_sop_class_uid,
             media_storage_sop_instance_uid,
@@ -365,13 +528,18 @@ impl FileMetaTableBuilder {
             receiving_application_entity_title: self.receiving_application_entity_title,
             private_information_creator_uid: self.private_information_creator_uid,
             private_information: self.private_information,
-        })
+        };
+        if self.information_group_length.is_none() {
+            // the length of the group as it would be written
+            table.information_group_length = table.encode_group()?.len() as u32;
+        }
+        Ok(table)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::FileMetaTable;
+    use super::{FileMetaTable, FileMetaTableBuilder};
 
     const TEST_META_1: &'static [u8] = &[
         // magic code
@@ -433,4 +601,49 @@ mod tests {
         assert_eq!(table.private_information_creator_uid, None);
         assert_eq!(table.private_information, None);
     }
+
+    #[test]
+    fn build_meta_table_with_group_length() {
+        let table = FileMetaTableBuilder::new()
+            .media_storage_sop_class_uid("1.2.840.10008.5.1.4.1.1.1\0".to_string())
+            .media_storage_sop_instance_uid(
+                "1.2.3.4.5.12345678.1234567890.1234567.123456789.1234567\0".to_string(),
+            )
+            .transfer_syntax("1.2.840.10008.1.2.1\0".to_string())
+            .implementation_class_uid("1.2.345.6.7890.1.234".to_string())
+            .implementation_version_name("RUSTY_DICOM_269 ".to_string())
+            .source_application_entity_title("".to_string())
+            .build()
+            .unwrap();
+
+        let mut source = TEST_META_1;
+        assert_eq!(table, FileMetaTable::from_reader(&mut source).unwrap());
+    }
+
+    #[test]
+    fn write_meta_table() {
+        let mut source = TEST_META_1;
+        let mut table = FileMetaTable::from_reader(&mut source).unwrap();
+
+        let mut out = Vec::new();
+        table.write(&mut out).unwrap();
+        assert_eq!(&out[..], TEST_META_1);
+
+        // changing the transfer syntax changes the group length written
+        table.set_transfer_syntax("1.2.840.10008.1.2");
+        assert_eq!(table.transfer_syntax, "1.2.840.10008.1.2\0");
+        let mut out = Vec::new();
+        table.write(&mut out).unwrap();
+        let read = FileMetaTable::from_reader(&mut &out[..]).unwrap();
+        assert_eq!(read.transfer_syntax, "1.2.840.10008.1.2\0");
+        assert_eq!(read.information_group_length, 198);
+
+        // a stale group length is not written as is
+        table.information_group_length = 1000;
+        let mut out = Vec::new();
+        table.write(&mut out).unwrap();
+        let read = FileMetaTable::from_reader(&mut &out[..]).unwrap();
+        assert_eq!(read.information_group_length, 198);
+        assert_eq!(out.len(), 4 + 12 + 198);
+    }
 }
@@ -0,0 +1,413 @@
+//! Validation of DICOM objects against their information object definition.
+//!
+//! The IOD is identified by the object's SOP Class UID. Mandatory modules
+//! are always checked, whereas conditional and user option modules are only
+//! checked when at least one of their own attributes is present.
+//!
+//! # Limitations
+//!
+//! The conditions of the standard are free text, not machine readable,
+//! so evaluating them is out of scope:
+//!
+//! - a missing Type 1C or 2C attribute is never reported,
+//!   whether its condition holds or not;
+//! - a Type 1C attribute which is present is assumed to meet its condition,
+//!   and is reported if it is empty;
+//! - a Type 1C or 2C attribute present while its condition does not hold
+//!   is not reported either.
+//!
+//! Objects are only validated if the standard IOD registry
+//! (`dicom_dictionary_std::StandardIodDictionary`) has their IOD.
+//! Objects of any other SOP class yield a single
+//! [`IssueKind::UnknownSopClass`](../enum.IssueKind.html) warning.
+
+use super::{AttributePath, Issue, IssueKind, Severity};
+use crate::mem::InMemDicomObject;
+use dicom_core::dictionary::iod::{AttributeSpec, AttributeType, IodSpec, ModuleUsage};
+use dicom_core::dictionary::uid::trim_uid;
+use dicom_core::dictionary::DataDictionary;
+use dicom_core::value::{PrimitiveValue, Value};
+use dicom_core::Tag;
+use dicom_dictionary_std::{tags, StandardIodDictionary};
+
+/// Validate a DICOM object against the IOD of its SOP class,
+/// as in the standard IOD registry.
+pub fn validate_iod<D>(obj: &InMemDicomObject<D>) -> Vec<Issue>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    let sop_class = obj
+        .element(tags::SOP_CLASS_UID)
+        .ok()
+        .and_then(|e| e.value().primitive())
+        .and_then(PrimitiveValue::string)
+        .map(trim_uid)
+        .unwrap_or("");
+    if sop_class.is_empty() {
+        return vec![Issue {
+            path: AttributePath::root(tags::SOP_CLASS_UID),
+            severity: Severity::Error,
+            kind: IssueKind::MissingAttribute(AttributeType::Type1),
+        }];
+    }
+
+    match StandardIodDictionary::by_sop_class(sop_class) {
+        Some(iod) => validate_iod_with(obj, iod),
+        None => vec![Issue {
+            path: AttributePath::root(tags::SOP_CLASS_UID),
+            severity: Severity::Warning,
+            kind: IssueKind::UnknownSopClass(sop_class.to_string()),
+        }],
+    }
+}
+
+/// Validate a DICOM object against the given IOD.
+pub fn validate_iod_with<D>(obj: &InMemDicomObject<D>, iod: &IodSpec) -> Vec<Issue>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    let mandatory: Vec<_> = iod
+        .modules
+        .iter()
+        .filter(|m| m.usage == ModuleUsage::Mandatory)
+        .collect();
+
+    let mut issues = Vec::new();
+    for m in iod.modules {
+        let in_use = m.usage == ModuleUsage::Mandatory
+            || m.module.attributes.iter().any(|a| {
+                // attributes shared with mandatory modules
+                // do not reveal the use of this module
+                obj.element(a.tag).is_ok()
+                    && !mandatory
+                        .iter()
+                        .any(|m| m.module.attributes.iter().any(|b| b.tag == a.tag))
+            });
+        if in_use {
+            check_attributes(obj, m.module.attributes, &[], &mut issues);
+        }
+    }
+    issues
+}
+
+fn check_attributes<D>(
+    obj: &InMemDicomObject<D>,
+    specs: &[AttributeSpec],
+    items: &[(Tag, u32)],
+    issues: &mut Vec<Issue>,
+) where
+    D: DataDictionary,
+    D: Clone,
+{
+    for spec in specs {
+        let value = match obj.element(spec.tag) {
+            Ok(e) => e.value(),
+            Err(_) => {
+                if spec.attribute_type.is_required() {
+                    report(
+                        issues,
+                        items,
+                        spec.tag,
+                        Severity::Error,
+                        IssueKind::MissingAttribute(spec.attribute_type),
+                    );
+                }
+                continue;
+            }
+        };
+
+        if is_empty(value) {
+            if spec.attribute_type.is_non_empty() {
+                report(
+                    issues,
+                    items,
+                    spec.tag,
+                    Severity::Error,
+                    IssueKind::EmptyAttribute(spec.attribute_type),
+                );
+            }
+            continue;
+        }
+
+        match value {
+            Value::Primitive(value) => {
+                for v in value_terms(value) {
+                    if !spec.enumerated_values.is_empty()
+                        && !spec.enumerated_values.iter().any(|t| matches_term(&v, t))
+                    {
+                        report(
+                            issues,
+                            items,
+                            spec.tag,
+                            Severity::Error,
+                            IssueKind::NotEnumeratedValue(v),
+                        );
+                    } else if !spec.defined_terms.is_empty()
+                        && !spec.defined_terms.iter().any(|t| matches_term(&v, t))
+                    {
+                        report(
+                            issues,
+                            items,
+                            spec.tag,
+                            Severity::Warning,
+                            IssueKind::UnknownDefinedTerm(v),
+                        );
+                    }
+                }
+            }
+            Value::Sequence { items: seq, .. } => {
+                let mut path = items.to_vec();
+                for (i, item) in seq.iter().enumerate() {
+                    path.push((spec.tag, i as u32));
+                    check_attributes(item, spec.items, &path, issues);
+                    path.pop();
+                }
+            }
+        }
+    }
+}
+
+fn report(
+    issues: &mut Vec<Issue>,
+    items: &[(Tag, u32)],
+    tag: Tag,
+    severity: Severity,
+    kind: IssueKind,
+) {
+    let issue = Issue {
+        path: AttributePath::new(items, tag),
+        severity,
+        kind,
+    };
+    // the same attribute may be specified by more than one module
+    if !issues.contains(&issue) {
+        issues.push(issue);
+    }
+}
+
+/// Check whether the value is empty,
+/// considering padding-only strings as empty.
+fn is_empty<I>(value: &Value<I>) -> bool {
+    match value {
+        Value::Sequence { items, .. } => items.is_empty(),
+        Value::Primitive(PrimitiveValue::Strs(values)) => {
+            values.iter().all(|v| trim_padding(v).is_empty())
+        }
+        Value::Primitive(PrimitiveValue::Str(v)) => trim_padding(v).is_empty(),
+        Value::Primitive(v) => v.multiplicity() == 0,
+    }
+}
+
+fn trim_padding(value: &str) -> &str {
+    value.trim_matches(|c| c == ' ' || c == '\0')
+}
+
+/// Obtain the individual values to compare against enumerated values
+/// and defined terms. Binary and date/time values are not compared.
+fn value_terms(value: &PrimitiveValue) -> Vec<String> {
+    use PrimitiveValue::*;
+    match value {
+        Strs(values) => values.iter().map(|v| trim_padding(v).to_string()).collect(),
+        Str(v) => vec![trim_padding(v).to_string()],
+        I16(values) => values.iter().map(|v| v.to_string()).collect(),
+        U16(values) if values.len() <= 4 => values.iter().map(|v| v.to_string()).collect(),
+        I32(values) => values.iter().map(|v| v.to_string()).collect(),
+        U32(values) if values.len() <= 4 => values.iter().map(|v| v.to_string()).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Check whether a value matches a term of the standard.
+/// Terms in hexadecimal notation (e.g. "0001H") are matched numerically.
+fn matches_term(value: &str, term: &str) -> bool {
+    if value == term {
+        return true;
+    }
+    if let Some(hex) = term.strip_suffix('H') {
+        let term = i64::from_str_radix(hex, 16);
+        if let (Ok(term), Ok(value)) = (term, value.parse::<i64>()) {
+            return term == value;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::strs;
+    use super::*;
+    use dicom_core::header::Header;
+    use dicom_core::value::C;
+    use dicom_core::{DataElement, Length, VR};
+    use dicom_dictionary_std::StandardDataDictionary;
+
+    fn us(tag: Tag, value: u16) -> DataElement<InMemDicomObject<StandardDataDictionary>> {
+        DataElement::new(
+            tag,
+            VR::US,
+            PrimitiveValue::U16(C::from_elem(value, 1)).into(),
+        )
+    }
+
+    /// A secondary capture object with all required attributes.
+    fn secondary_capture() -> InMemDicomObject<StandardDataDictionary> {
+        let mut obj = InMemDicomObject::create_empty();
+        obj.put(strs(
+            tags::SOP_CLASS_UID,
+            VR::UI,
+            &["1.2.840.10008.5.1.4.1.1.7\0"],
+        ));
+        obj.put(strs(tags::SOP_INSTANCE_UID, VR::UI, &["2.25.1"]));
+        obj.put(DataElement::empty(tags::PATIENT_NAME, VR::PN));
+        obj.put(DataElement::empty(tags::PATIENT_ID, VR::LO));
+        obj.put(DataElement::empty(tags::PATIENT_BIRTH_DATE, VR::DA));
+        obj.put(strs(tags::PATIENT_SEX, VR::CS, &["O "]));
+        obj.put(strs(tags::STUDY_INSTANCE_UID, VR::UI, &["2.25.2"]));
+        obj.put(DataElement::empty(tags::STUDY_DATE, VR::DA));
+        obj.put(DataElement::empty(tags::STUDY_TIME, VR::TM));
+        obj.put(DataElement::empty(tags::REFERRING_PHYSICIAN_NAME, VR::PN));
+        obj.put(DataElement::empty(tags::STUDY_ID, VR::SH));
+        obj.put(DataElement::empty(tags::ACCESSION_NUMBER, VR::SH));
+        obj.put(strs(tags::MODALITY, VR::CS, &["OT"]));
+        obj.put(strs(tags::SERIES_INSTANCE_UID, VR::UI, &["2.25.3"]));
+        obj.put(DataElement::empty(tags::SERIES_NUMBER, VR::IS));
+        obj.put(strs(tags::CONVERSION_TYPE, VR::CS, &["WSD "]));
+        obj.put(DataElement::empty(tags::INSTANCE_NUMBER, VR::IS));
+        obj.put(us(tags::SAMPLES_PER_PIXEL, 1));
+        obj.put(strs(
+            tags::PHOTOMETRIC_INTERPRETATION,
+            VR::CS,
+            &["MONOCHROME2 "],
+        ));
+        obj.put(us(tags::ROWS, 1));
+        obj.put(us(tags::COLUMNS, 1));
+        obj.put(us(tags::BITS_ALLOCATED, 8));
+        obj.put(us(tags::BITS_STORED, 8));
+        obj.put(us(tags::HIGH_BIT, 7));
+        obj.put(us(tags::PIXEL_REPRESENTATION, 0));
+        obj
+    }
+
+    #[test]
+    fn valid_object_has_no_issues() {
+        let obj = secondary_capture();
+        assert_eq!(validate_iod(&obj), vec![]);
+    }
+
+    #[test]
+    fn report_missing_and_empty_attributes() {
+        let obj = secondary_capture()
+            .into_iter()
+            .filter(|e| e.tag() != tags::PATIENT_NAME)
+            .map(Ok);
+        let mut obj = InMemDicomObject::from_element_iter(obj).unwrap();
+        obj.put(DataElement::empty(tags::SERIES_INSTANCE_UID, VR::UI));
+
+        let issues = validate_iod(&obj);
+        assert_eq!(
+            issues,
+            vec![
+                Issue {
+                    path: AttributePath::root(tags::PATIENT_NAME),
+                    severity: Severity::Error,
+                    kind: IssueKind::MissingAttribute(AttributeType::Type2),
+                },
+                Issue {
+                    path: AttributePath::root(tags::SERIES_INSTANCE_UID),
+                    severity: Severity::Error,
+                    kind: IssueKind::EmptyAttribute(AttributeType::Type1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn report_values_out_of_the_standard() {
+        let mut obj = secondary_capture();
+        obj.put(strs(tags::PATIENT_SEX, VR::CS, &["X"]));
+        obj.put(strs(tags::CONVERSION_TYPE, VR::CS, &["SCAN"]));
+        obj.put(us(tags::PIXEL_REPRESENTATION, 2));
+
+        let issues = validate_iod(&obj);
+        assert_eq!(issues.len(), 3);
+        assert!(issues.contains(&Issue {
+            path: AttributePath::root(tags::PATIENT_SEX),
+            severity: Severity::Error,
+            kind: IssueKind::NotEnumeratedValue("X".to_string()),
+        }));
+        assert!(issues.contains(&Issue {
+            path: AttributePath::root(tags::CONVERSION_TYPE),
+            severity: Severity::Warning,
+            kind: IssueKind::UnknownDefinedTerm("SCAN".to_string()),
+        }));
+        assert!(issues.contains(&Issue {
+            path: AttributePath::root(tags::PIXEL_REPRESENTATION),
+            severity: Severity::Error,
+            kind: IssueKind::NotEnumeratedValue("2".to_string()),
+        }));
+    }
+
+    #[test]
+    fn check_sequence_items_and_optional_modules() {
+        let mut obj = secondary_capture();
+        // an item of Source Image Sequence without Referenced SOP Instance UID
+        let mut item = InMemDicomObject::create_empty();
+        item.put(strs(
+            tags::REFERENCED_SOP_CLASS_UID,
+            VR::UI,
+            &["1.2.840.10008.5.1.4.1.1.7"],
+        ));
+        obj.put(DataElement::new(
+            tags::SOURCE_IMAGE_SEQUENCE,
+            VR::SQ,
+            Value::Sequence {
+                items: C::from_elem(item, 1),
+                size: Length::UNDEFINED,
+            },
+        ));
+        // the VOI LUT module is now in use, so its Type 1C attributes
+        // must not be empty
+        obj.put(strs(tags::VOILUT_FUNCTION, VR::CS, &["LINEAR"]));
+        obj.put(DataElement::empty(tags::WINDOW_CENTER, VR::DS));
+
+        let issues = validate_iod(&obj);
+        assert_eq!(
+            issues,
+            vec![
+                Issue {
+                    path: AttributePath::new(
+                        &[(tags::SOURCE_IMAGE_SEQUENCE, 0)],
+                        tags::REFERENCED_SOP_INSTANCE_UID
+                    ),
+                    severity: Severity::Error,
+                    kind: IssueKind::MissingAttribute(AttributeType::Type1),
+                },
+                Issue {
+                    path: AttributePath::root(tags::WINDOW_CENTER),
+                    severity: Severity::Error,
+                    kind: IssueKind::EmptyAttribute(AttributeType::Type1C),
+                },
+            ]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "error: (0008,2112)[0].(0008,1155): missing Type 1 attribute"
+        );
+    }
+
+    #[test]
+    fn unknown_sop_class() {
+        let mut obj = secondary_capture();
+        obj.put(strs(tags::SOP_CLASS_UID, VR::UI, &["1.2.3.4"]));
+        assert_eq!(
+            validate_iod(&obj),
+            vec![Issue {
+                path: AttributePath::root(tags::SOP_CLASS_UID),
+                severity: Severity::Warning,
+                kind: IssueKind::UnknownSopClass("1.2.3.4".to_string()),
+            }]
+        );
+    }
+}
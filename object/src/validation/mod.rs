@@ -0,0 +1,232 @@
+//! This module contains the validation of DICOM objects
+//! against the requirements of the standard.
+//!
+//! Validation does not stop at the first problem found: every violation is
+//! collected as an [`Issue`](struct.Issue.html), which identifies the
+//! offending attribute by its path from the root of the object.
+//!
+//! Objects can be validated against their information object definition
+//! ([`validate_iod`](iod/fn.validate_iod.html)), against the value
+//! representation and multiplicity rules of each element
+//! ([`validate_values`](vr/fn.validate_values.html)), or both
+//! ([`validate`](fn.validate.html)).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use dicom_object::open_file;
+//! use dicom_object::validation::{validate, Severity};
+//! # fn foo() -> dicom_object::Result<()> {
+//! let obj = open_file("0001.dcm")?;
+//! for issue in validate(&obj) {
+//!     if issue.severity == Severity::Error {
+//!         println!("{}", issue);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod iod;
+pub mod vr;
+
+pub use self::iod::{validate_iod, validate_iod_with};
+pub use self::vr::validate_values;
+
+use crate::mem::InMemDicomObject;
+use dicom_core::dictionary::iod::AttributeType;
+use dicom_core::dictionary::DataDictionary;
+use dicom_core::{Tag, VR};
+use std::fmt;
+
+/// Validate a DICOM object against the IOD of its SOP class,
+/// and the values of all of its elements
+/// against their value representation and multiplicity.
+pub fn validate<D>(obj: &InMemDicomObject<D>) -> Vec<Issue>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    let mut issues = validate_iod(obj);
+    issues.extend(validate_values(obj));
+    issues
+}
+
+/// The severity of a validation issue.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The object does not conform to the standard.
+    Error,
+    /// The object is likely to be valid,
+    /// but something unusual was found.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Error => f.write_str("error"),
+            Severity::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// The location of an attribute in a DICOM object,
+/// described by the sequence items leading to it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AttributePath {
+    /// The sequence attributes and respective item indices
+    /// leading to the attribute, from the root of the object.
+    pub items: Vec<(Tag, u32)>,
+    /// The attribute tag
+    pub tag: Tag,
+}
+
+impl AttributePath {
+    /// Create a path to an attribute at the root of the object.
+    pub fn root(tag: Tag) -> Self {
+        AttributePath {
+            items: Vec::new(),
+            tag,
+        }
+    }
+
+    /// Create a path to an attribute in the same object
+    /// as the given items lead to.
+    pub fn new(items: &[(Tag, u32)], tag: Tag) -> Self {
+        AttributePath {
+            items: items.to_vec(),
+            tag,
+        }
+    }
+}
+
+impl fmt::Display for AttributePath {
+    /// Format the path in the form `(0008,1140)[0].(0008,1150)`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (tag, index) in &self.items {
+            write!(f, "{}[{}].", tag, index)?;
+        }
+        write!(f, "{}", self.tag)
+    }
+}
+
+/// The kind of problem found in an attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IssueKind {
+    /// A required attribute is missing.
+    MissingAttribute(AttributeType),
+    /// An attribute which must have a value is empty.
+    EmptyAttribute(AttributeType),
+    /// The value is not one of the attribute's enumerated values.
+    NotEnumeratedValue(String),
+    /// The value is not one of the attribute's defined terms.
+    UnknownDefinedTerm(String),
+    /// The SOP class of the object has no known definition.
+    UnknownSopClass(String),
+    /// A value has characters not allowed in its value representation.
+    InvalidCharacters(VR, String),
+    /// A value is longer than its value representation allows.
+    ValueTooLong {
+        /// The value representation
+        vr: VR,
+        /// The length of the value in bytes
+        length: usize,
+        /// The maximum length of the value representation
+        max: usize,
+    },
+    /// A binary value has an odd number of bytes.
+    OddLength(usize),
+    /// A unique identifier is not well formed.
+    InvalidUid(String),
+    /// The number of values does not match the attribute's multiplicity.
+    InvalidMultiplicity {
+        /// The value multiplicity in the dictionary
+        vm: String,
+        /// The number of values found
+        found: u32,
+    },
+    /// The value representation is not one of those in the dictionary.
+    UnexpectedVr(VR),
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IssueKind::MissingAttribute(t) => write!(f, "missing {} attribute", t),
+            IssueKind::EmptyAttribute(t) => write!(f, "empty {} attribute", t),
+            IssueKind::NotEnumeratedValue(v) => write!(f, "{:?} is not an enumerated value", v),
+            IssueKind::UnknownDefinedTerm(v) => write!(f, "{:?} is not a defined term", v),
+            IssueKind::UnknownSopClass(uid) => write!(f, "unknown SOP class {:?}", uid),
+            IssueKind::InvalidCharacters(vr, v) => {
+                write!(f, "{:?} has characters not allowed in {}", v, vr)
+            }
+            IssueKind::ValueTooLong { vr, length, max } => write!(
+                f,
+                "value of {} bytes is longer than the {} bytes allowed in {}",
+                length, max, vr
+            ),
+            IssueKind::OddLength(len) => write!(f, "odd value length of {} bytes", len),
+            IssueKind::InvalidUid(uid) => write!(f, "{:?} is not a valid UID", uid),
+            IssueKind::InvalidMultiplicity { vm, found } => write!(
+                f,
+                "{} values found, but the multiplicity of the attribute is {}",
+                found, vm
+            ),
+            IssueKind::UnexpectedVr(vr) => {
+                write!(f, "value representation {} is not expected", vr)
+            }
+        }
+    }
+}
+
+/// A violation of the standard found in a DICOM object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Issue {
+    /// The location of the offending attribute
+    pub path: AttributePath,
+    /// The severity of the issue
+    pub severity: Severity,
+    /// The kind of issue
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.path, self.kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_core::value::PrimitiveValue;
+    use dicom_core::DataElement;
+    use dicom_dictionary_std::StandardDataDictionary;
+
+    /// Create an element with the given textual values.
+    pub(super) fn strs(
+        tag: Tag,
+        vr: VR,
+        values: &[&str],
+    ) -> DataElement<InMemDicomObject<StandardDataDictionary>> {
+        DataElement::new(
+            tag,
+            vr,
+            PrimitiveValue::Strs(values.iter().map(|v| v.to_string()).collect()).into(),
+        )
+    }
+
+    #[test]
+    fn issue_display() {
+        let issue = Issue {
+            path: AttributePath::new(&[(Tag(0x0008, 0x1140), 0)], Tag(0x0008, 0x1150)),
+            severity: Severity::Error,
+            kind: IssueKind::MissingAttribute(AttributeType::Type1),
+        };
+        assert_eq!(
+            issue.to_string(),
+            "error: (0008,1140)[0].(0008,1150): missing Type 1 attribute"
+        );
+    }
+}
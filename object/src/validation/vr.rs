@@ -0,0 +1,319 @@
+//! Validation of element values against the rules of their value
+//! representation, and against the value multiplicity and value
+//! representations of the standard data dictionary.
+//!
+//! The following are checked for every element, including those in
+//! sequence items:
+//!
+//! - the character repertoire and maximum length of each textual value;
+//! - the evenness of the length of binary values,
+//!   as textual values are padded when encoded;
+//! - the syntax of unique identifiers;
+//! - the value multiplicity and the value representation of standard
+//!   attributes, as in the dictionary.
+
+use super::{AttributePath, Issue, IssueKind, Severity};
+use crate::mem::InMemDicomObject;
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
+use dicom_core::header::Header;
+use dicom_core::value::{PrimitiveValue, Value};
+use dicom_core::{Tag, VR};
+use dicom_encoding::text::{max_value_length, validate_value, TextValidationOutcome};
+
+/// Validate the values of all elements in a DICOM object.
+pub fn validate_values<D>(obj: &InMemDicomObject<D>) -> Vec<Issue>
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    let mut issues = Vec::new();
+    check_elements(obj, &[], &mut issues);
+    issues
+}
+
+fn check_elements<D>(obj: &InMemDicomObject<D>, items: &[(Tag, u32)], issues: &mut Vec<Issue>)
+where
+    D: DataDictionary,
+    D: Clone,
+{
+    for elem in obj {
+        let tag = elem.tag();
+        let vr = elem.vr();
+        let issue = |severity, kind| Issue {
+            path: AttributePath::new(items, tag),
+            severity,
+            kind,
+        };
+
+        let value = match elem.value() {
+            Value::Primitive(value) => value,
+            Value::Sequence { items: seq, .. } => {
+                let mut path = items.to_vec();
+                for (i, item) in seq.iter().enumerate() {
+                    path.push((tag, i as u32));
+                    check_elements(item, &path, issues);
+                    path.pop();
+                }
+                continue;
+            }
+        };
+
+        // private attributes are not in the data dictionary
+        if tag.0 % 2 == 0 {
+            if let Some(entry) = obj.dictionary().by_tag(tag) {
+                if vr != VR::UN && !entry.vrs().contains(&vr) {
+                    issues.push(issue(Severity::Warning, IssueKind::UnexpectedVr(vr)));
+                }
+                let found = value_multiplicity(vr, value);
+                if let Some(vm) = entry.value_multiplicity() {
+                    if found > 0 && !vm.contains(found) {
+                        issues.push(issue(
+                            Severity::Error,
+                            IssueKind::InvalidMultiplicity {
+                                vm: entry.vm().to_string(),
+                                found,
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+
+        for kind in check_value(vr, value) {
+            issues.push(issue(Severity::Error, kind));
+        }
+    }
+}
+
+/// Obtain the value multiplicity of a primitive value
+/// in the given value representation.
+fn value_multiplicity(vr: VR, value: &PrimitiveValue) -> u32 {
+    match (vr, value) {
+        (_, PrimitiveValue::Empty) => 0,
+        // these are never multi-valued
+        (VR::OB, _)
+        | (VR::OD, _)
+        | (VR::OF, _)
+        | (VR::OL, _)
+        | (VR::OV, _)
+        | (VR::OW, _)
+        | (VR::UN, _)
+        | (VR::LT, _)
+        | (VR::ST, _)
+        | (VR::UT, _)
+        | (VR::UR, _) => 1,
+        (_, value) => value.multiplicity(),
+    }
+}
+
+/// Check a primitive value against the rules of its value representation.
+fn check_value(vr: VR, value: &PrimitiveValue) -> Vec<IssueKind> {
+    let mut kinds = Vec::new();
+    let values: Vec<&str> = match value {
+        PrimitiveValue::Strs(values) => values.iter().map(String::as_str).collect(),
+        PrimitiveValue::Str(value) => vec![value.as_str()],
+        PrimitiveValue::U8(bytes) => {
+            if bytes.len() & 1 == 1 {
+                kinds.push(IssueKind::OddLength(bytes.len()));
+            }
+            return kinds;
+        }
+        // other values are well formed by construction
+        _ => return kinds,
+    };
+    if values.is_empty() {
+        return kinds;
+    }
+
+    for value in values {
+        let trimmed = value.trim_end_matches(&[' ', '\0'][..]);
+        if validate_value(vr, trimmed.as_bytes()) != TextValidationOutcome::Ok {
+            kinds.push(IssueKind::InvalidCharacters(vr, value.to_string()));
+            continue;
+        }
+        if let Some(max) = max_value_length(vr) {
+            // the limit of PN applies to each component group
+            let length = match vr {
+                VR::PN => trimmed.split('=').map(str::len).max().unwrap_or(0),
+                _ => trimmed.len(),
+            };
+            if length > max {
+                kinds.push(IssueKind::ValueTooLong { vr, length, max });
+                continue;
+            }
+        }
+        if vr == VR::UI && !trimmed.is_empty() && !is_valid_uid(trimmed) {
+            kinds.push(IssueKind::InvalidUid(value.to_string()));
+        }
+    }
+    kinds
+}
+
+/// Check the syntax of a UID: a sequence of numeric components separated by
+/// periods, in which components of more than one digit do not start with 0.
+fn is_valid_uid(uid: &str) -> bool {
+    uid.split('.').all(|component| {
+        !component.is_empty()
+            && component.bytes().all(|c| c.is_ascii_digit())
+            && (component == "0" || !component.starts_with('0'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::strs;
+    use super::*;
+    use dicom_core::dictionary::owned::OwnedDataDictionary;
+    use dicom_core::dictionary::{DictionaryEntryBuf, TagRange};
+    use dicom_core::value::C;
+    use dicom_core::{DataElement, Length};
+    use dicom_dictionary_std::tags;
+
+    #[test]
+    fn valid_values_have_no_issues() {
+        let mut obj = InMemDicomObject::create_empty();
+        obj.put(strs(
+            tags::SOP_CLASS_UID,
+            VR::UI,
+            &["1.2.840.10008.5.1.4.1.1.7\0"],
+        ));
+        obj.put(strs(tags::PATIENT_NAME, VR::PN, &["Doe^John"]));
+        obj.put(strs(tags::IMAGE_TYPE, VR::CS, &["ORIGINAL", "PRIMARY"]));
+        obj.put(strs(tags::PIXEL_SPACING, VR::DS, &["0.5", "0.5 "]));
+        obj.put(DataElement::empty(tags::STUDY_DATE, VR::DA));
+        assert_eq!(validate_values(&obj), vec![]);
+    }
+
+    #[test]
+    fn report_value_representation_issues() {
+        let mut obj = InMemDicomObject::create_empty();
+        obj.put(strs(tags::SOP_INSTANCE_UID, VR::UI, &["1.2.03.4"]));
+        obj.put(strs(tags::MODALITY, VR::CS, &["ct"]));
+        obj.put(strs(tags::STUDY_ID, VR::SH, &["AN-IDENTIFIER-TOO-LONG"]));
+        // padded to an even length when encoded
+        obj.put(strs(tags::SERIES_NUMBER, VR::IS, &["1"]));
+        obj.put(DataElement::new(
+            tags::PIXEL_DATA,
+            VR::OB,
+            PrimitiveValue::U8(vec![1, 2, 3].into()).into(),
+        ));
+
+        let issues = validate_values(&obj);
+        let kinds: Vec<_> = issues.iter().map(|i| (i.path.tag, &i.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (
+                    tags::SOP_INSTANCE_UID,
+                    &IssueKind::InvalidUid("1.2.03.4".to_string())
+                ),
+                (
+                    tags::MODALITY,
+                    &IssueKind::InvalidCharacters(VR::CS, "ct".to_string())
+                ),
+                (
+                    tags::STUDY_ID,
+                    &IssueKind::ValueTooLong {
+                        vr: VR::SH,
+                        length: 22,
+                        max: 16
+                    }
+                ),
+                (tags::PIXEL_DATA, &IssueKind::OddLength(3)),
+            ]
+        );
+        assert!(issues.iter().all(|i| i.severity == Severity::Error));
+    }
+
+    #[test]
+    fn report_dictionary_mismatches_in_sequences() {
+        let mut item = InMemDicomObject::create_empty();
+        item.put(strs(
+            tags::REFERENCED_SOP_INSTANCE_UID,
+            VR::UI,
+            &["1.2.3.4", "1.2.3.50"],
+        ));
+        item.put(strs(tags::REFERENCED_SOP_CLASS_UID, VR::LO, &["1.2.3.40"]));
+        let mut obj = InMemDicomObject::create_empty();
+        obj.put(DataElement::new(
+            tags::SOURCE_IMAGE_SEQUENCE,
+            VR::SQ,
+            Value::Sequence {
+                items: C::from_elem(item, 1),
+                size: Length::UNDEFINED,
+            },
+        ));
+
+        let issues = validate_values(&obj);
+        assert_eq!(
+            issues,
+            vec![
+                Issue {
+                    path: AttributePath::new(
+                        &[(tags::SOURCE_IMAGE_SEQUENCE, 0)],
+                        tags::REFERENCED_SOP_CLASS_UID
+                    ),
+                    severity: Severity::Warning,
+                    kind: IssueKind::UnexpectedVr(VR::LO),
+                },
+                Issue {
+                    path: AttributePath::new(
+                        &[(tags::SOURCE_IMAGE_SEQUENCE, 0)],
+                        tags::REFERENCED_SOP_INSTANCE_UID
+                    ),
+                    severity: Severity::Error,
+                    kind: IssueKind::InvalidMultiplicity {
+                        vm: "1".to_string(),
+                        found: 2,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn use_the_dictionary_of_the_object() {
+        // a local dictionary which only admits one patient name, as LO
+        let dict: OwnedDataDictionary = vec![DictionaryEntryBuf {
+            tag: TagRange::Single(tags::PATIENT_NAME),
+            alias: "PatientName".to_string(),
+            name: "Patient's Name".to_string(),
+            vrs: vec![VR::LO],
+            vm: "1".to_string(),
+            retired: false,
+        }]
+        .into_iter()
+        .collect();
+        let mut obj = InMemDicomObject::new_empty_with_dict(dict);
+        obj.put(DataElement::new(
+            tags::PATIENT_NAME,
+            VR::PN,
+            PrimitiveValue::Strs(["Doe^John".to_string(), "Doe^Ann".to_string()][..].into()).into(),
+        ));
+        // not in the local dictionary
+        obj.put(DataElement::new(
+            tags::STUDY_DATE,
+            VR::LO,
+            PrimitiveValue::Strs(["20200101".to_string()][..].into()).into(),
+        ));
+
+        assert_eq!(
+            validate_values(&obj),
+            vec![
+                Issue {
+                    path: AttributePath::root(tags::PATIENT_NAME),
+                    severity: Severity::Warning,
+                    kind: IssueKind::UnexpectedVr(VR::PN),
+                },
+                Issue {
+                    path: AttributePath::root(tags::PATIENT_NAME),
+                    severity: Severity::Error,
+                    kind: IssueKind::InvalidMultiplicity {
+                        vm: "1".to_string(),
+                        found: 2,
+                    },
+                },
+            ]
+        );
+    }
+}
@@ -1,14 +1,21 @@
 //! Interpretation of DICOM data sets as streams of tokens.
-use dicom_core::header::{DataElementHeader, Length};
-use dicom_core::value::{DicomValueType, PrimitiveValue};
-use dicom_core::Tag;
+use crate::printer::numbers_as_text;
+use dicom_core::header::{DataElementHeader, Header, Length};
+use dicom_core::value::{DicomValueType, PrimitiveValue, Value};
+use dicom_core::{DataElement, Tag, VR};
+use dicom_encoding::encode::Encode;
+use dicom_encoding::text::{DefaultCharacterSetCodec, SpecificCharacterSet, TextCodec};
+use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianEncoder;
 use std::fmt;
+use std::io;
 
 pub mod read;
+pub mod transform;
 pub mod write;
 
 pub use self::read::DataSetReader;
-pub use self::write::DataSetWriter;
+pub use self::transform::TokenTransform;
+pub use self::write::{DataSetWriter, GroupLengths, SequenceLengths};
 
 /// A token of a DICOM data set stream. This is part of the interpretation of a
 /// data set as a stream of symbols, which may either represent data headers or
@@ -79,3 +86,201 @@ pub enum SeqTokenType {
     Sequence,
     Item,
 }
+
+/// Options for converting structured DICOM data into data set tokens.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IntoTokensOptions {
+    /// Whether sequences and items are given an undefined length,
+    /// and closed with delimiters. Otherwise, their lengths are computed
+    /// from their contents.
+    pub undefined_length: bool,
+    /// Whether the data set will be encoded in explicit VR,
+    /// which changes the size of element headers.
+    /// Only relevant if sequences and items have a defined length.
+    pub explicit_vr: bool,
+    /// The character set in which text values will be encoded,
+    /// which may change their length.
+    pub charset: SpecificCharacterSet,
+}
+
+impl Default for IntoTokensOptions {
+    /// Obtain the default options, in which sequences and items
+    /// have an undefined length and text is in the default character set.
+    fn default() -> Self {
+        IntoTokensOptions {
+            undefined_length: true,
+            explicit_vr: true,
+            charset: SpecificCharacterSet::Default,
+        }
+    }
+}
+
+/// A trait for converting structured DICOM data into a stream of data set
+/// tokens, such as those consumed by a `DataSetWriter`. The length of each
+/// element is computed from its value.
+pub trait IntoTokens {
+    /// The iterator of tokens.
+    type Iter: Iterator<Item = DataToken>;
+
+    /// Convert the value into tokens, with sequences and items
+    /// of undefined length.
+    fn into_tokens(self) -> Self::Iter
+    where
+        Self: Sized,
+    {
+        self.into_tokens_with_options(IntoTokensOptions::default())
+    }
+
+    /// Convert the value into tokens with the given options.
+    fn into_tokens_with_options(self, options: IntoTokensOptions) -> Self::Iter;
+}
+
+impl<I> IntoTokens for DataElement<I>
+where
+    I: IntoTokens,
+    I: DicomValueType,
+{
+    type Iter = std::vec::IntoIter<DataToken>;
+
+    fn into_tokens_with_options(self, options: IntoTokensOptions) -> Self::Iter {
+        let tag = self.tag();
+        let vr = self.vr();
+        let mut tokens = Vec::new();
+        match self.into_value() {
+            Value::Primitive(value) => {
+                let text = options
+                    .charset
+                    .codec()
+                    .unwrap_or_else(|| Box::new(DefaultCharacterSetCodec));
+                let len = value_length(vr, &value, &*text);
+                tokens.push(DataToken::ElementHeader(DataElementHeader::new(
+                    tag,
+                    vr,
+                    Length(len),
+                )));
+                tokens.push(DataToken::PrimitiveValue(value));
+            }
+            Value::Sequence { items, .. } => {
+                let item_options = IntoTokensOptions {
+                    undefined_length: true,
+                    ..options
+                };
+                tokens.push(DataToken::SequenceStart {
+                    tag,
+                    len: Length::UNDEFINED,
+                });
+                for item in items {
+                    tokens.push(DataToken::ItemStart {
+                        len: Length::UNDEFINED,
+                    });
+                    tokens.extend(item.into_tokens_with_options(item_options));
+                    tokens.push(DataToken::ItemEnd);
+                }
+                tokens.push(DataToken::SequenceEnd);
+                if !options.undefined_length {
+                    resolve_lengths(&mut tokens, options.explicit_vr);
+                }
+            }
+        }
+        tokens.into_iter()
+    }
+}
+
+/// Replace the undefined lengths of the sequences and items in the tokens
+/// with the length of their contents, as encoded in explicit or implicit VR.
+/// The lengths of primitive elements must be defined.
+pub fn resolve_lengths(tokens: &mut [DataToken], explicit_vr: bool) {
+    measure_lengths(tokens, explicit_vr, true);
+}
+
+/// Obtain the number of bytes taken by the given tokens once encoded,
+/// including the delimiters of undefined length sequences and items.
+pub(crate) fn encoded_length(tokens: &mut [DataToken], explicit_vr: bool) -> u32 {
+    measure_lengths(tokens, explicit_vr, false)
+}
+
+/// Compute the encoded length of each sequence and item in the tokens,
+/// replacing their lengths with the length of their contents if `resolve`
+/// is set, and return the encoded length of all tokens.
+fn measure_lengths(tokens: &mut [DataToken], explicit_vr: bool, resolve: bool) -> u32 {
+    // the index of each open sequence or item, and the length of its contents
+    let mut open: Vec<(usize, u32)> = Vec::new();
+    let mut total = 0;
+    for i in 0..tokens.len() {
+        let size = match tokens[i] {
+            DataToken::ElementHeader(header) => {
+                header_size(header.vr, explicit_vr) + header.len.get().unwrap_or(0)
+            }
+            DataToken::SequenceStart { .. } | DataToken::ItemStart { .. } => {
+                open.push((i, 0));
+                continue;
+            }
+            DataToken::SequenceEnd | DataToken::ItemEnd => {
+                let (start, len) = match open.pop() {
+                    Some(open) => open,
+                    None => continue,
+                };
+                let (header_len, given_len) = match &mut tokens[start] {
+                    DataToken::SequenceStart { len: seq_len, .. } => {
+                        (header_size(VR::SQ, explicit_vr), seq_len)
+                    }
+                    DataToken::ItemStart { len: item_len } => (8, item_len),
+                    _ => unreachable!(),
+                };
+                if resolve {
+                    *given_len = Length(len);
+                }
+                // undefined lengths are followed by a delimiter
+                let delimiter_len = if given_len.is_undefined() { 8 } else { 0 };
+                header_len + len + delimiter_len
+            }
+            DataToken::PrimitiveValue(_) => continue,
+        };
+        match open.last_mut() {
+            Some((_, len)) => *len += size,
+            None => total += size,
+        }
+    }
+    total
+}
+
+/// Obtain the size of an element header in the given VR.
+fn header_size(vr: VR, explicit_vr: bool) -> u32 {
+    match vr {
+        VR::OB | VR::OD | VR::OF | VR::OL | VR::OW | VR::SQ | VR::UC | VR::UR | VR::UT | VR::UN
+            if explicit_vr =>
+        {
+            12
+        }
+        _ => 8,
+    }
+}
+
+/// Obtain the length of a primitive value once encoded, as done by the
+/// data set writer. Text is encoded with the given codec, unless the value
+/// representation only allows the default character repertoire.
+pub(crate) fn value_length(vr: VR, value: &PrimitiveValue, text: &dyn TextCodec) -> u32 {
+    let text_len = |s: &str| {
+        match vr {
+            VR::AE | VR::AS | VR::CS | VR::DA | VR::DS | VR::DT | VR::IS | VR::TM | VR::UI => {
+                DefaultCharacterSetCodec.encode(s)
+            }
+            _ => text.encode(s),
+        }
+        .map(|data| data.len())
+        .unwrap_or_else(|_| s.len())
+    };
+    if let Some(texts) = numbers_as_text(vr, value) {
+        let len = texts.iter().map(|s| s.len()).sum::<usize>() + texts.len().max(1) - 1;
+        return ((len + 1) & !1) as u32;
+    }
+    let len = match value {
+        PrimitiveValue::Str(s) => text_len(s),
+        PrimitiveValue::Strs(c) if c.is_empty() => 0,
+        PrimitiveValue::Strs(c) => c.iter().map(|s| text_len(s)).sum::<usize>() + c.len() - 1,
+        value => ExplicitVRLittleEndianEncoder::default()
+            .encode_primitive(io::sink(), value)
+            .unwrap_or(0),
+    };
+    ((len + 1) & !1) as u32
+}
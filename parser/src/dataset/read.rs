@@ -4,15 +4,20 @@
 //! The rest of the crate is used to obtain DICOM element headers and values.
 //! At this level, headers and values are treated as tokens which can be used
 //! to form a syntax tree of a full data set.
-use crate::error::{Error, InvalidValueReadError, Result};
-use crate::parser::{DicomParser, DynamicDicomParser, Parse};
+use crate::error::{Error, InvalidValueReadError, ReadWarning, ReadWarningKind, Result};
+use crate::parser::{DicomParser, DynamicDicomParser, Parse, ReadLimits};
 use crate::util::{ReadSeek, SeekInterval};
+use dicom_core::dictionary::private::{
+    is_private_creator, private_creator_of, PrivateDataDictionary, PrivateDictionary,
+};
 use dicom_core::dictionary::DataDictionary;
 use dicom_core::header::{DataElementHeader, Header, Length, SequenceItemHeader};
+use dicom_core::value::PrimitiveValue;
 use dicom_core::{Tag, VR};
-use dicom_dictionary_std::StandardDataDictionary;
+use dicom_dictionary_std::{tags, StandardDataDictionary};
 use dicom_encoding::text::SpecificCharacterSet;
 use dicom_encoding::transfer_syntax::TransferSyntax;
+use std::collections::BTreeMap;
 use std::io::{Read, Seek, SeekFrom};
 use std::iter::Iterator;
 use std::marker::PhantomData;
@@ -27,6 +32,23 @@ where
 {
 }
 
+/// Check whether the error is due to a read limit being exceeded,
+/// which is never worked around in lenient mode.
+fn is_limit_error(e: &Error) -> bool {
+    matches!(
+        e.inner(),
+        Error::ElementTooLong(..)
+            | Error::DataSetTooLong(_)
+            | Error::NestingTooDeep(_)
+            | Error::TooManyElements(_)
+    )
+}
+
+/// Check whether the error is due to the source ending.
+fn is_eof(e: &Error) -> bool {
+    matches!(e.inner(), Error::Io(e) if e.kind() == ::std::io::ErrorKind::UnexpectedEof)
+}
+
 /// A reader-specific token representing a sequence or item start.
 #[derive(Debug, Copy, Clone, PartialEq)]
 struct SeqToken {
@@ -57,26 +79,45 @@ pub struct DataSetReader<S, P, D> {
     hard_break: bool,
     /// last decoded header
     last_header: Option<DataElementHeader>,
+    /// the dictionary of private attributes
+    private_dict: PrivateDictionary,
+    /// a stack of private creators found so far, one per nested data set
+    private_creators: Vec<BTreeMap<Tag, String>>,
+    /// whether value representations need to be resolved by dictionary
+    implicit_vr: bool,
+    /// whether to work around encoding problems
+    lenient: bool,
+    /// whether reading stopped and open sequences are being closed
+    closing: bool,
+    /// value read ahead of its header token, in lenient mode
+    pending_value: Option<PrimitiveValue>,
+    /// the tag of the last element read at the root of the data set
+    last_tag: Option<Tag>,
+    /// the encoding problems worked around so far
+    warnings: Vec<ReadWarning>,
+    /// the limits on the content read
+    limits: ReadLimits,
+    /// the number of data elements read so far
+    element_count: u32,
+    /// the UID of the transfer syntax, if known
+    transfer_syntax: Option<&'static str>,
+    /// the position of the data set in the source
+    base_offset: u64,
+    /// the sequences and respective item indices leading to the current item
+    path: Vec<(Tag, u32)>,
+    /// the tag of the element being read, if any
+    current_tag: Option<Tag>,
+    /// the position of the value of the last element header read
+    value_offset: u64,
+    /// the position of the last token read
+    token_offset: u64,
 }
 
 impl<'s, S: 's> DataSetReader<S, DynamicDicomParser<'s>, StandardDataDictionary> {
     /// Creates a new iterator with the given random access source,
     /// while considering the given transfer syntax and specific character set.
     pub fn new_with(source: S, ts: &TransferSyntax, cs: SpecificCharacterSet) -> Result<Self> {
-        let parser = DynamicDicomParser::new_with(ts, cs)?;
-
-        is_parse(&parser);
-
-        Ok(DataSetReader {
-            source,
-            parser,
-            dict: StandardDataDictionary,
-            seq_delimiters: Vec::new(),
-            delimiter_check_pending: false,
-            in_sequence: false,
-            hard_break: false,
-            last_header: None,
-        })
+        Self::new_with_dictionary(source, StandardDataDictionary, ts, cs)
     }
 }
 
@@ -93,16 +134,12 @@ impl<'s, S: 's, D> DataSetReader<S, DynamicDicomParser<'s>, D> {
 
         is_parse(&parser);
 
-        Ok(DataSetReader {
+        Ok(DataSetReader::new_with_parts(
             source,
             parser,
             dict,
-            seq_delimiters: Vec::new(),
-            delimiter_check_pending: false,
-            in_sequence: false,
-            hard_break: false,
-            last_header: None,
-        })
+            Some(ts),
+        ))
     }
 }
 
@@ -113,15 +150,39 @@ where
 {
     /// Create a new iterator with the given parser.
     pub fn new(source: S, parser: P) -> Self {
+        DataSetReader::new_with_parts(source, parser, StandardDataDictionary, None)
+    }
+}
+
+impl<S, P, D> DataSetReader<S, P, D> {
+    /// Create a reader at the start of the data set,
+    /// encoded in the given transfer syntax if it is known.
+    fn new_with_parts(source: S, parser: P, dict: D, ts: Option<&TransferSyntax>) -> Self {
         DataSetReader {
             source,
             parser,
-            dict: StandardDataDictionary,
+            dict,
             seq_delimiters: Vec::new(),
             delimiter_check_pending: false,
             in_sequence: false,
             hard_break: false,
             last_header: None,
+            private_dict: PrivateDictionary::new(),
+            private_creators: vec![BTreeMap::new()],
+            implicit_vr: matches!(ts, Some(ts) if !ts.explicit_vr()),
+            lenient: false,
+            closing: false,
+            pending_value: None,
+            last_tag: None,
+            warnings: Vec::new(),
+            limits: ReadLimits::default(),
+            element_count: 0,
+            transfer_syntax: ts.map(|ts| ts.uid()),
+            base_offset: 0,
+            path: Vec::new(),
+            current_tag: None,
+            value_offset: 0,
+            token_offset: 0,
         }
     }
 }
@@ -135,9 +196,122 @@ where
     type Item = Result<DataToken>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        let position = self.parser.bytes_read();
+        let token = self.next_token();
+        self.collect_warnings();
+        match token {
+            Some(Ok(token)) => {
+                self.token_offset = self.base_offset
+                    + match token {
+                        DataToken::PrimitiveValue(_) => self.value_offset,
+                        _ => position,
+                    };
+                self.update_path(&token);
+                Some(Ok(token))
+            }
+            Some(Err(e)) => Some(Err(self.locate_error(e))),
+            None => None,
+        }
+    }
+}
+
+impl<'s, S: 's, P, D> DataSetReader<S, P, D>
+where
+    P: Parse<dyn Read + 's>,
+    S: Read,
+{
+    /// Read the data set in lenient mode, in which known encoding problems
+    /// are worked around instead of failing:
+    ///
+    /// - binary values with an invalid length are read as bytes;
+    /// - numbers, dates and times which cannot be parsed are read as text;
+    /// - invalid value representations in explicit VR are resolved by
+    ///   dictionary, and data sets declared in explicit VR but encoded in
+    ///   implicit VR are read as such;
+    /// - sequences and items with an inconsistent explicit length are closed
+    ///   at the declared length;
+    /// - elements out of order at the root of the data set are kept;
+    /// - data which cannot be read is ignored until the end;
+    /// - sequences and items left open at the end are closed.
+    ///
+    /// Each problem is recorded as a warning, available through
+    /// [`warnings`](#method.warnings).
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self.parser.set_lenient(lenient);
+        self
+    }
+
+    /// Set the position of the data set in the source, in bytes,
+    /// so that the positions reported in errors and token offsets are
+    /// relative to the start of the source, rather than to the start of
+    /// the data set.
+    pub fn with_base_offset(mut self, offset: u64) -> Self {
+        self.base_offset = offset;
+        self
+    }
+
+    /// Obtain an iterator over the tokens of the data set
+    /// along with their positions in the source.
+    pub fn with_offsets(self) -> OffsetTokens<S, P, D> {
+        OffsetTokens { reader: self }
+    }
+
+    /// Retrieve the position in the source, in bytes,
+    /// of the last token read.
+    pub fn token_offset(&self) -> u64 {
+        self.token_offset
+    }
+
+    /// Keep track of the location of the element being read.
+    fn update_path(&mut self, token: &DataToken) {
+        match token {
+            DataToken::ElementHeader(header) => self.current_tag = Some(header.tag),
+            DataToken::PrimitiveValue(_) => self.current_tag = None,
+            DataToken::SequenceStart { tag, .. } => {
+                self.path.push((*tag, 0));
+                self.current_tag = None;
+            }
+            DataToken::ItemStart { .. } => {}
+            DataToken::ItemEnd => {
+                if let Some((_, index)) = self.path.last_mut() {
+                    *index += 1;
+                }
+            }
+            DataToken::SequenceEnd => {
+                self.path.pop();
+            }
+        }
+    }
+
+    /// Attach the position, path and transfer syntax to an error.
+    fn locate_error(&self, e: Error) -> Error {
+        let mut e = e
+            .at(self.parser.bytes_read(), self.transfer_syntax)
+            .with_path(&self.path, self.current_tag);
+        if let Error::Located(_, ref mut context) = e {
+            context.position += self.base_offset;
+        }
+        e
+    }
+
+    /// Enforce the given limits on the data set read, so that untrusted
+    /// data cannot lead to excessive memory use. Reading fails with
+    /// a dedicated error as soon as a limit is exceeded, even in
+    /// lenient mode.
+    pub fn with_limits(mut self, limits: ReadLimits) -> Self {
+        self.limits = limits;
+        self.parser.set_limits(limits);
+        self
+    }
+
+    fn next_token(&mut self) -> Option<Result<DataToken>> {
         if self.hard_break {
             return None;
         }
+        if self.closing {
+            return self.close_open_sequence();
+        }
 
         // item or sequence delimitation logic for explicit lengths
         if self.delimiter_check_pending {
@@ -157,6 +331,7 @@ where
                     SequenceItemHeader::Item { len } => {
                         // entered a new item
                         self.in_sequence = false;
+                        self.private_creators.push(BTreeMap::new());
                         self.seq_delimiters.push(SeqToken {
                             typ: SeqTokenType::Item,
                             len,
@@ -171,6 +346,7 @@ where
                     SequenceItemHeader::ItemDelimiter => {
                         // closed an item
                         self.seq_delimiters.pop();
+                        self.leave_item();
                         self.in_sequence = true;
                         Some(Ok(DataToken::ItemEnd))
                     }
@@ -181,6 +357,10 @@ where
                         Some(Ok(DataToken::SequenceEnd))
                     }
                 },
+                Err(ref e) if self.lenient && is_eof(e) => self.stop_reading(None),
+                Err(e) if self.lenient && !is_limit_error(&e) => {
+                    self.stop_reading(Some(e.inner().to_string()))
+                }
                 Err(e) => {
                     self.hard_break = true;
                     Some(Err(e))
@@ -189,7 +369,12 @@ where
         } else if self.last_header.is_some() {
             // a plain element header was read, so a value is expected
             let header = self.last_header.unwrap();
-            let value = match self.parser.read_value(&mut self.source, &header) {
+            let value = match self
+                .pending_value
+                .take()
+                .map(Ok)
+                .unwrap_or_else(|| self.parser.read_value(&mut self.source, &header))
+            {
                 Ok(v) => v,
                 Err(e) => {
                     self.hard_break = true;
@@ -200,13 +385,31 @@ where
 
             self.last_header = None;
 
+            if is_private_creator(header.tag) {
+                if let Some(creator) = value.string() {
+                    self.private_creators
+                        .last_mut()
+                        .expect("private creator stack should never be empty")
+                        .insert(header.tag, creator.to_string());
+                }
+            }
+
             // sequences can end after this token
             self.delimiter_check_pending = true;
 
             Some(Ok(DataToken::PrimitiveValue(value)))
         } else {
             // a data element header or item delimiter is expected
-            match self.parser.decode_header(&mut self.source) {
+            match self
+                .parser
+                .decode_header(&mut self.source)
+                .map(|header| self.resolve_private_vr(header))
+                .and_then(|header| self.check_limits(&header).map(|_| header))
+                .inspect(|header| {
+                    if self.lenient {
+                        self.check_order(header.tag);
+                    }
+                }) {
                 Ok(DataElementHeader {
                     tag,
                     vr: VR::SQ,
@@ -227,26 +430,54 @@ where
                     Some(Ok(DataToken::SequenceStart { tag, len }))
                 }
                 Ok(DataElementHeader {
-                    tag: Tag(0xFFFE, 0xE00D),
+                    tag: tags::ITEM_DELIMITATION_ITEM,
                     ..
                 }) => {
                     self.in_sequence = true;
+                    self.seq_delimiters.pop();
+                    self.leave_item();
                     Some(Ok(DataToken::ItemEnd))
                 }
                 Ok(header) => {
+                    self.value_offset = self.parser.bytes_read();
+                    if self.lenient {
+                        // read the value right away,
+                        // so that the element is dropped if it is truncated
+                        match self.parser.read_value(&mut self.source, &header) {
+                            Ok(value) => self.pending_value = Some(value),
+                            Err(e) if is_limit_error(&e) => {
+                                self.hard_break = true;
+                                self.current_tag = Some(header.tag);
+                                return Some(Err(e));
+                            }
+                            Err(e) => {
+                                return self.stop_reading(Some(format!(
+                                    "could not read element {}: {}",
+                                    header.tag,
+                                    e.inner()
+                                )));
+                            }
+                        }
+                    }
                     // save it for the next step
                     self.last_header = Some(header);
                     Some(Ok(DataToken::ElementHeader(header)))
                 }
-                Err(Error::Io(ref e)) if e.kind() == ::std::io::ErrorKind::UnexpectedEof => {
+                Err(ref e) if is_eof(e) => {
                     // TODO there might be a more informative way to check
                     // whether the end of a DICOM object was reached gracefully
                     // or with problems. This approach may consume trailing
                     // bytes, and will ignore the possibility of trailing bytes
                     // having already been interpreted as an element header.
+                    if self.lenient {
+                        return self.stop_reading(None);
+                    }
                     self.hard_break = true;
                     None
                 }
+                Err(e) if self.lenient && !is_limit_error(&e) => {
+                    self.stop_reading(Some(e.inner().to_string()))
+                }
                 Err(e) => {
                     self.hard_break = true;
                     Some(Err(e))
@@ -254,6 +485,150 @@ where
             }
         }
     }
+
+    /// Count a newly read data element header,
+    /// and check it against the element count and nesting limits.
+    fn check_limits(&mut self, header: &DataElementHeader) -> Result<()> {
+        if header.tag == tags::ITEM_DELIMITATION_ITEM {
+            return Ok(());
+        }
+        self.element_count += 1;
+        if let Some(max) = self.limits.max_elements {
+            if self.element_count > max {
+                return Err(Error::TooManyElements(max));
+            }
+        }
+        if let (VR::SQ, Some(max)) = (header.vr, self.limits.max_nesting_depth) {
+            let depth = self
+                .seq_delimiters
+                .iter()
+                .filter(|token| token.typ == SeqTokenType::Sequence)
+                .count();
+            if depth >= max as usize {
+                return Err(Error::NestingTooDeep(max));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check whether an element at the root of the data set comes after
+    /// the previous one, as it should, and record a warning if it does not.
+    fn check_order(&mut self, tag: Tag) {
+        if !self.seq_delimiters.is_empty() || tag == tags::ITEM_DELIMITATION_ITEM {
+            return;
+        }
+        if self.last_tag.map(|last| tag <= last).unwrap_or(false) {
+            self.warn(ReadWarningKind::OutOfOrder(tag));
+        }
+        self.last_tag = Some(tag);
+    }
+
+    /// Stop reading the data set, for the given reason if it did not end
+    /// gracefully, and start closing the sequences and items left open.
+    fn stop_reading(&mut self, reason: Option<String>) -> Option<Result<DataToken>> {
+        self.collect_warnings();
+        if let Some(reason) = reason {
+            self.warn(ReadWarningKind::TrailingData(reason));
+        }
+        if !self.seq_delimiters.is_empty() {
+            self.warn(ReadWarningKind::MissingDelimiter);
+        }
+        self.closing = true;
+        self.last_header = None;
+        self.pending_value = None;
+        self.close_open_sequence()
+    }
+
+    /// Close the innermost open sequence or item.
+    fn close_open_sequence(&mut self) -> Option<Result<DataToken>> {
+        match self.seq_delimiters.pop() {
+            Some(SeqToken {
+                typ: SeqTokenType::Sequence,
+                ..
+            }) => {
+                self.in_sequence = false;
+                Some(Ok(DataToken::SequenceEnd))
+            }
+            Some(SeqToken {
+                typ: SeqTokenType::Item,
+                ..
+            }) => {
+                self.in_sequence = true;
+                self.leave_item();
+                Some(Ok(DataToken::ItemEnd))
+            }
+            None => {
+                self.hard_break = true;
+                None
+            }
+        }
+    }
+
+    /// Move the warnings recorded by the parser to the reader.
+    fn collect_warnings(&mut self) {
+        if self.lenient {
+            let warnings = self.parser.take_warnings();
+            self.warnings.extend(warnings);
+        }
+    }
+
+    fn warn(&mut self, kind: ReadWarningKind) {
+        self.warnings.push(ReadWarning {
+            position: self.parser.bytes_read(),
+            kind,
+        });
+    }
+}
+
+impl<S, P, D> DataSetReader<S, P, D> {
+    /// Use the given dictionary of private attributes to resolve the value
+    /// representation of private data elements in implicit VR data sets.
+    ///
+    /// Private attributes are resolved through the private creator elements
+    /// `(gggg,0010)`-`(gggg,00FF)` read so far in the current data set.
+    /// Note that readers created with [`new`] do not know the transfer syntax,
+    /// and so will not perform this resolution.
+    ///
+    /// [`new`]: #method.new
+    pub fn with_private_dictionary(mut self, dict: PrivateDictionary) -> Self {
+        self.private_dict = dict;
+        self
+    }
+
+    /// Retrieve the encoding problems worked around so far in lenient mode.
+    pub fn warnings(&self) -> &[ReadWarning] {
+        &self.warnings
+    }
+
+    /// Resolve the value representation of a private data element header
+    /// obtained from an implicit VR data set.
+    fn resolve_private_vr(&self, mut header: DataElementHeader) -> DataElementHeader {
+        if !self.implicit_vr || header.vr != VR::UN {
+            return header;
+        }
+        if is_private_creator(header.tag) {
+            header.vr = VR::LO;
+        } else if let Some(creator_tag) = private_creator_of(header.tag) {
+            let creator = self
+                .private_creators
+                .last()
+                .and_then(|creators| creators.get(&creator_tag));
+            if let Some(creator) = creator {
+                let offset = (header.tag.1 & 0xFF) as u8;
+                if let Some(entry) = self.private_dict.by_creator(creator, header.tag.0, offset) {
+                    header.vr = entry.vr;
+                }
+            }
+        }
+        header
+    }
+
+    /// Discard the private creators of the item that has just ended.
+    fn leave_item(&mut self) {
+        if self.private_creators.len() > 1 {
+            self.private_creators.pop();
+        }
+    }
 }
 
 impl<'s, S: 's, P, D> DataSetReader<S, P, D>
@@ -262,11 +637,14 @@ where
     S: Read,
 {
     fn update_seq_delimiters(&mut self) -> Result<Option<DataToken>> {
-        if let Some(sd) = self.seq_delimiters.last() {
+        if let Some(sd) = self.seq_delimiters.last().copied() {
             if let Some(len) = sd.len.get() {
                 let eos = sd.base_offset + len as u64;
                 let bytes_read = self.parser.bytes_read();
-                if eos == bytes_read {
+                if eos < bytes_read && self.lenient {
+                    self.warn(ReadWarningKind::InconsistentSequenceEnd(eos));
+                }
+                if eos == bytes_read || (eos < bytes_read && self.lenient) {
                     // end of delimiter, as indicated by the element's length
                     let token;
                     match sd.typ {
@@ -276,6 +654,7 @@ where
                         }
                         SeqTokenType::Item => {
                             self.in_sequence = true;
+                            self.leave_item();
                             token = DataToken::ItemEnd;
                         }
                     }
@@ -292,6 +671,41 @@ where
     }
 }
 
+/// An iterator over the tokens of a data set along with their positions in
+/// the source, created with [`DataSetReader::with_offsets`].
+///
+/// [`DataSetReader::with_offsets`]: struct.DataSetReader.html#method.with_offsets
+#[derive(Debug)]
+pub struct OffsetTokens<S, P, D> {
+    reader: DataSetReader<S, P, D>,
+}
+
+impl<S, P, D> OffsetTokens<S, P, D> {
+    /// Retrieve the underlying data set reader.
+    pub fn reader(&self) -> &DataSetReader<S, P, D> {
+        &self.reader
+    }
+
+    /// Discard the offsets, and retrieve the underlying data set reader.
+    pub fn into_inner(self) -> DataSetReader<S, P, D> {
+        self.reader
+    }
+}
+
+impl<'s, S: 's, P, D> Iterator for OffsetTokens<S, P, D>
+where
+    S: Read,
+    P: Parse<dyn Read + 's>,
+    D: DataDictionary,
+{
+    type Item = Result<(u64, DataToken)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.reader.next()?;
+        Some(token.map(|token| (self.reader.token_offset(), token)))
+    }
+}
+
 /// An iterator for retrieving DICOM object element markers from a random
 /// access data source.
 #[derive(Debug)]
@@ -410,7 +824,7 @@ where
             match self.parser.decode_header(&mut self.source) {
                 Ok(
                     header @ DataElementHeader {
-                        tag: Tag(0x0008, 0x0005),
+                        tag: tags::SPECIFIC_CHARACTER_SET,
                         ..
                     },
                 ) => {
@@ -501,6 +915,7 @@ mod tests {
     use dicom_core::header::{DataElementHeader, Length};
     use dicom_core::value::PrimitiveValue;
     use dicom_core::{Tag, VR};
+    use dicom_dictionary_std::tags;
     use dicom_encoding::decode::basic::LittleEndianBasicDecoder;
     use dicom_encoding::text::DefaultCharacterSetCodec;
     use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianDecoder;
@@ -560,18 +975,18 @@ mod tests {
 
         let ground_truth = vec![
             DataToken::SequenceStart {
-                tag: Tag(0x0018, 0x6011),
+                tag: tags::SEQUENCE_OF_ULTRASOUND_REGIONS,
                 len: Length(46),
             },
             DataToken::ItemStart { len: Length(20) },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
             DataToken::PrimitiveValue(PrimitiveValue::U16([1].as_ref().into())),
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6014),
+                tag: tags::REGION_DATA_TYPE,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -579,7 +994,7 @@ mod tests {
             DataToken::ItemEnd,
             DataToken::ItemStart { len: Length(10) },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -587,7 +1002,7 @@ mod tests {
             DataToken::ItemEnd,
             DataToken::SequenceEnd,
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0020, 0x4000),
+                tag: tags::IMAGE_COMMENTS,
                 vr: VR::LT,
                 len: Length(4),
             }),
@@ -627,12 +1042,12 @@ mod tests {
 
         let ground_truth = vec![
             DataToken::SequenceStart {
-                tag: Tag(0x0008, 0x2218),
+                tag: tags::ANATOMIC_REGION_SEQUENCE,
                 len: Length(54),
             },
             DataToken::ItemStart { len: Length(46) },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0008, 0x0100),
+                tag: tags::CODE_VALUE,
                 vr: VR::SH,
                 len: Length(8),
             }),
@@ -640,13 +1055,13 @@ mod tests {
                 ["T-D1213 ".to_owned()].as_ref().into(),
             )),
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0008, 0x0102),
+                tag: tags::CODING_SCHEME_DESIGNATOR,
                 vr: VR::SH,
                 len: Length(4),
             }),
             DataToken::PrimitiveValue(PrimitiveValue::Strs(["SRT ".to_owned()].as_ref().into())),
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0008, 0x0104),
+                tag: tags::CODE_MEANING,
                 vr: VR::LO,
                 len: Length(10),
             }),
@@ -656,12 +1071,12 @@ mod tests {
             DataToken::ItemEnd,
             DataToken::SequenceEnd,
             DataToken::SequenceStart {
-                tag: Tag(0x0040, 0x0555),
+                tag: tags::ACQUISITION_CONTEXT_SEQUENCE,
                 len: Length(0),
             },
             DataToken::SequenceEnd,
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x2050, 0x0020),
+                tag: tags::PRESENTATION_LUT_SHAPE,
                 vr: VR::CS,
                 len: Length(8),
             }),
@@ -706,20 +1121,20 @@ mod tests {
 
         let ground_truth = vec![
             DataToken::SequenceStart {
-                tag: Tag(0x0018, 0x6011),
+                tag: tags::SEQUENCE_OF_ULTRASOUND_REGIONS,
                 len: Length::UNDEFINED,
             },
             DataToken::ItemStart {
                 len: Length::UNDEFINED,
             },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
             DataToken::PrimitiveValue(PrimitiveValue::U16([1].as_ref().into())),
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6014),
+                tag: tags::REGION_DATA_TYPE,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -729,7 +1144,7 @@ mod tests {
                 len: Length::UNDEFINED,
             },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -737,7 +1152,7 @@ mod tests {
             DataToken::ItemEnd,
             DataToken::SequenceEnd,
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0020, 0x4000),
+                tag: tags::IMAGE_COMMENTS,
                 vr: VR::LT,
                 len: Length(4),
             }),
@@ -746,4 +1161,531 @@ mod tests {
 
         validate_dataset_reader(DATA, ground_truth);
     }
+
+    #[test]
+    fn private_vr_resolution_implicit() {
+        use dicom_core::dictionary::private::{PrivateDictionary, PrivateDictionaryEntry};
+        use dicom_encoding::text::SpecificCharacterSet;
+        use dicom_encoding::transfer_syntax::{Codec, Endianness, TransferSyntax};
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0009,0010) private creator; len = 8
+            0x09, 0x00, 0x10, 0x00, 0x08, 0x00, 0x00, 0x00,
+            b'A', b'C', b'M', b'E', b' ', b'1', b'.', b'0',
+            // (0009,1001) known private attribute; len = 2
+            0x09, 0x00, 0x01, 0x10, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00,
+            // (0009,1002) unknown private attribute; len = 2
+            0x09, 0x00, 0x02, 0x10, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        ];
+
+        let ts: TransferSyntax = TransferSyntax::new(
+            "1.2.840.10008.1.2",
+            "Implicit VR Little Endian",
+            Endianness::Little,
+            false,
+            Codec::None,
+        );
+        let private_dict: PrivateDictionary = vec![PrivateDictionaryEntry {
+            creator: "ACME 1.0".to_string(),
+            group: 0x0009,
+            offset: 0x01,
+            alias: "AcmeScanCount".to_string(),
+            vr: VR::US,
+        }]
+        .into_iter()
+        .collect();
+
+        let dset_reader = DataSetReader::new_with(DATA, &ts, SpecificCharacterSet::Default)
+            .unwrap()
+            .with_private_dictionary(private_dict);
+
+        let tokens: Vec<_> = dset_reader
+            .collect::<Result<_, _>>()
+            .expect("should parse without an error");
+        assert_eq!(
+            tokens,
+            vec![
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: Tag(0x0009, 0x0010),
+                    vr: VR::LO,
+                    len: Length(8),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["ACME 1.0".to_owned()].as_ref().into(),
+                )),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: Tag(0x0009, 0x1001),
+                    vr: VR::US,
+                    len: Length(2),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::U16([1].as_ref().into())),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: Tag(0x0009, 0x1002),
+                    vr: VR::UN,
+                    len: Length(2),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::U8([2, 0].as_ref().into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_uid_values() {
+        use dicom_encoding::text::SpecificCharacterSet;
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0008,0016) SOPClassUID, UI, len = 8, with trailing null padding
+            0x08, 0x00, 0x16, 0x00, b'U', b'I', 0x08, 0x00,
+            b'1', b'.', b'2', b'.', b'8', b'4', b'0', 0x00,
+            // (0008,1150) ReferencedSOPClassUID, UI, len = 12, two values
+            0x08, 0x00, 0x50, 0x11, b'U', b'I', 0x0C, 0x00,
+            b'1', b'.', b'2', b'.', b'3', b'\\', b'1', b'.', b'2', b'.', b'4', 0x00,
+        ];
+
+        let dset_reader =
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap();
+        let tokens: Vec<_> = dset_reader
+            .collect::<Result<_, _>>()
+            .expect("should parse without an error");
+        assert_eq!(
+            tokens,
+            vec![
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::SOP_CLASS_UID,
+                    vr: VR::UI,
+                    len: Length(8),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["1.2.840\0".to_owned()].as_ref().into(),
+                )),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::REFERENCED_SOP_CLASS_UID,
+                    vr: VR::UI,
+                    len: Length(12),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["1.2.3".to_owned(), "1.2.4\0".to_owned()].as_ref().into(),
+                )),
+            ]
+        );
+    }
+
+    fn explicit_vr_le() -> dicom_encoding::transfer_syntax::TransferSyntax {
+        use dicom_encoding::transfer_syntax::{Codec, Endianness, TransferSyntax};
+        TransferSyntax::new(
+            "1.2.840.10008.1.2.1",
+            "Explicit VR Little Endian",
+            Endianness::Little,
+            true,
+            Codec::None,
+        )
+    }
+
+    #[test]
+    fn lenient_reading_explicit() {
+        use crate::error::{ReadWarning, ReadWarningKind};
+        use dicom_encoding::text::SpecificCharacterSet;
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0008,0020) StudyDate, DA, len = 8, not a date
+            0x08, 0x00, 0x20, 0x00, b'D', b'A', 0x08, 0x00,
+            b'2', b'0', b'1', b'9', b'X', b'X', b'0', b'1',
+            // -- 16 --
+            // (0018,6012) RegionSpatialFormat, US, len = 3
+            0x18, 0x00, 0x12, 0x60, b'U', b'S', 0x03, 0x00, 0x01, 0x00, 0x00,
+            // -- 27 --
+            // (0020,0013) InstanceNumber, invalid VR, len = 2
+            0x20, 0x00, 0x13, 0x00, 0x00, 0x00, 0x02, 0x00, b'7', b' ',
+            // -- 37 --
+            // (0040,A730) ContentSequence, SQ, undefined length
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
+            // -- 49 --
+            // item start, undefined length
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // -- 57 --
+            // (0040,A040) ValueType, CS, len = 4
+            0x40, 0x00, 0x40, 0xA0, b'C', b'S', 0x04, 0x00, b'T', b'E', b'X', b'T',
+            // -- 69 --
+            // item and sequence delimiters are missing
+        ];
+
+        let mut dset_reader =
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true);
+        let tokens: Vec<_> = dset_reader
+            .by_ref()
+            .collect::<Result<_, _>>()
+            .expect("should parse without an error");
+        assert_eq!(
+            tokens,
+            vec![
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::STUDY_DATE,
+                    vr: VR::DA,
+                    len: Length(8),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["2019XX01".to_owned()].as_ref().into(),
+                )),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::REGION_SPATIAL_FORMAT,
+                    vr: VR::US,
+                    len: Length(3),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::U8([1, 0, 0].as_ref().into())),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::INSTANCE_NUMBER,
+                    vr: VR::IS,
+                    len: Length(2),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::I32([7].as_ref().into())),
+                DataToken::SequenceStart {
+                    tag: tags::CONTENT_SEQUENCE,
+                    len: Length::UNDEFINED,
+                },
+                DataToken::ItemStart {
+                    len: Length::UNDEFINED,
+                },
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::VALUE_TYPE,
+                    vr: VR::CS,
+                    len: Length(4),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["TEXT".to_owned()].as_ref().into(),
+                )),
+                DataToken::ItemEnd,
+                DataToken::SequenceEnd,
+            ]
+        );
+        assert_eq!(
+            dset_reader.warnings(),
+            &[
+                ReadWarning {
+                    position: 16,
+                    kind: ReadWarningKind::InvalidValue(tags::STUDY_DATE, VR::DA),
+                },
+                ReadWarning {
+                    position: 24,
+                    kind: ReadWarningKind::OddLength(tags::REGION_SPATIAL_FORMAT, VR::US, 3),
+                },
+                ReadWarning {
+                    position: 27,
+                    kind: ReadWarningKind::InvalidVr(tags::INSTANCE_NUMBER, [0, 0], VR::IS),
+                },
+                ReadWarning {
+                    position: 69,
+                    kind: ReadWarningKind::MissingDelimiter,
+                },
+            ][..]
+        );
+    }
+
+    #[test]
+    fn lenient_reading_invalid_numbers_and_out_of_order() {
+        use crate::error::{ReadWarning, ReadWarningKind};
+        use dicom_encoding::text::SpecificCharacterSet;
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0008,0060) Modality, CS, len = 2
+            0x08, 0x00, 0x60, 0x00, b'C', b'S', 0x02, 0x00, b'C', b'T',
+            // (0040,A730) ContentSequence, SQ, len = 42
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0x00, 0x00, 0x2A, 0x00, 0x00, 0x00,
+            // item start, len = 34
+            0xFE, 0xFF, 0x00, 0xE0, 0x22, 0x00, 0x00, 0x00,
+            // -- 30 --
+            // (0018,0050) SliceThickness, DS, len = 4, not a number
+            0x18, 0x00, 0x50, 0x00, b'D', b'S', 0x04, 0x00, b'1', b'.', b'5', b'X',
+            // -- 42 --
+            // (0020,0013) InstanceNumber, IS, len = 2, not a number
+            0x20, 0x00, 0x13, 0x00, b'I', b'S', 0x02, 0x00, b'A', b'1',
+            // -- 52 --
+            // (0040,A040) ValueType, CS, len = 4
+            0x40, 0x00, 0x40, 0xA0, b'C', b'S', 0x04, 0x00, b'T', b'E', b'X', b'T',
+            // -- 64 --
+            // (0010,0010) PatientName, PN, len = 4, out of order
+            0x10, 0x00, 0x10, 0x00, b'P', b'N', 0x04, 0x00, b'D', b'o', b'e', b'^',
+            // (0020,0010) StudyID, SH, len = 2
+            0x20, 0x00, 0x10, 0x00, b'S', b'H', 0x02, 0x00, b'4', b'2',
+        ];
+
+        let mut dset_reader =
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true);
+        let tokens: Vec<_> = dset_reader
+            .by_ref()
+            .collect::<Result<_, _>>()
+            .expect("should parse without an error");
+        assert_eq!(
+            tokens,
+            vec![
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::MODALITY,
+                    vr: VR::CS,
+                    len: Length(2),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(["CT".to_owned()].as_ref().into())),
+                DataToken::SequenceStart {
+                    tag: tags::CONTENT_SEQUENCE,
+                    len: Length(42),
+                },
+                DataToken::ItemStart { len: Length(34) },
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::SLICE_THICKNESS,
+                    vr: VR::DS,
+                    len: Length(4),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["1.5X".to_owned()].as_ref().into(),
+                )),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::INSTANCE_NUMBER,
+                    vr: VR::IS,
+                    len: Length(2),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(["A1".to_owned()].as_ref().into())),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::VALUE_TYPE,
+                    vr: VR::CS,
+                    len: Length(4),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["TEXT".to_owned()].as_ref().into(),
+                )),
+                DataToken::ItemEnd,
+                DataToken::SequenceEnd,
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::PATIENT_NAME,
+                    vr: VR::PN,
+                    len: Length(4),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["Doe^".to_owned()].as_ref().into(),
+                )),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::STUDY_ID,
+                    vr: VR::SH,
+                    len: Length(2),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(["42".to_owned()].as_ref().into())),
+            ]
+        );
+        assert_eq!(
+            dset_reader.warnings(),
+            &[
+                ReadWarning {
+                    position: 42,
+                    kind: ReadWarningKind::InvalidValue(tags::SLICE_THICKNESS, VR::DS),
+                },
+                ReadWarning {
+                    position: 52,
+                    kind: ReadWarningKind::InvalidValue(tags::INSTANCE_NUMBER, VR::IS),
+                },
+                ReadWarning {
+                    position: 72,
+                    kind: ReadWarningKind::OutOfOrder(tags::PATIENT_NAME),
+                },
+            ][..]
+        );
+    }
+
+    #[test]
+    fn lenient_reading_mislabelled_implicit_vr() {
+        use crate::error::ReadWarningKind;
+        use dicom_encoding::text::SpecificCharacterSet;
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0008,0060) Modality, len = 2
+            0x08, 0x00, 0x60, 0x00, 0x02, 0x00, 0x00, 0x00, b'C', b'T',
+            // (0010,0010) PatientName, len = 4
+            0x10, 0x00, 0x10, 0x00, 0x04, 0x00, 0x00, 0x00, b'D', b'o', b'e', b'^',
+            // trailing garbage
+            0x08, 0x00, 0x16, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        // strict reading fails on the first element
+        let strict =
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>();
+        assert!(strict.is_err());
+
+        let mut dset_reader =
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true);
+        let tokens: Vec<_> = dset_reader
+            .by_ref()
+            .collect::<Result<_, _>>()
+            .expect("should parse without an error");
+        assert_eq!(
+            tokens,
+            vec![
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::MODALITY,
+                    vr: VR::CS,
+                    len: Length(2),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(["CT".to_owned()].as_ref().into())),
+                DataToken::ElementHeader(DataElementHeader {
+                    tag: tags::PATIENT_NAME,
+                    vr: VR::PN,
+                    len: Length(4),
+                }),
+                DataToken::PrimitiveValue(PrimitiveValue::Strs(
+                    ["Doe^".to_owned()].as_ref().into(),
+                )),
+            ]
+        );
+        let kinds: Vec<_> = dset_reader.warnings().iter().map(|w| &w.kind).collect();
+        assert_eq!(kinds.len(), 3);
+        assert_eq!(kinds[0], &ReadWarningKind::ImplicitVr);
+        assert_eq!(kinds[1], &ReadWarningKind::OutOfOrder(tags::SOP_CLASS_UID));
+        assert!(matches!(kinds[2], ReadWarningKind::TrailingData(_)));
+    }
+
+    #[test]
+    fn read_limits() {
+        use crate::error::Error;
+        use crate::parser::ReadLimits;
+        use dicom_encoding::text::SpecificCharacterSet;
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0008,0060) Modality, CS, len = 2
+            0x08, 0x00, 0x60, 0x00, b'C', b'S', 0x02, 0x00, b'C', b'T',
+            // (0040,A730) ContentSequence, SQ, undefined length
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
+            // item start, undefined length
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // (0040,A730) ContentSequence, SQ, undefined length
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
+            // sequence end
+            0xFE, 0xFF, 0xDD, 0xE0, 0x00, 0x00, 0x00, 0x00,
+            // item end
+            0xFE, 0xFF, 0x0D, 0xE0, 0x00, 0x00, 0x00, 0x00,
+            // sequence end
+            0xFE, 0xFF, 0xDD, 0xE0, 0x00, 0x00, 0x00, 0x00,
+            // (7FE0,0010) PixelData, OB, len = 0x7FFF_FFF0, truncated
+            0xE0, 0x7F, 0x10, 0x00, b'O', b'B', 0x00, 0x00, 0xF0, 0xFF, 0xFF, 0x7F,
+        ];
+
+        let read_with = |limits: ReadLimits, lenient: bool| {
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(lenient)
+                .with_limits(limits)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(Error::into_inner)
+        };
+
+        let limits = ReadLimits {
+            max_element_length: Some(1024),
+            ..Default::default()
+        };
+        for &lenient in &[false, true] {
+            match read_with(limits, lenient) {
+                Err(Error::ElementTooLong(tags::PIXEL_DATA, 0x7FFF_FFF0, 1024)) => {}
+                other => panic!("unexpected outcome {:?}", other),
+            }
+        }
+
+        let limits = ReadLimits {
+            max_total_bytes: Some(64),
+            ..Default::default()
+        };
+        assert!(matches!(
+            read_with(limits, true),
+            Err(Error::DataSetTooLong(64))
+        ));
+
+        let limits = ReadLimits {
+            max_nesting_depth: Some(1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            read_with(limits, true),
+            Err(Error::NestingTooDeep(1))
+        ));
+
+        let limits = ReadLimits {
+            max_elements: Some(2),
+            ..Default::default()
+        };
+        assert!(matches!(
+            read_with(limits, true),
+            Err(Error::TooManyElements(2))
+        ));
+    }
+
+    #[test]
+    fn error_context_and_offsets() {
+        use crate::error::{Error, ErrorContext};
+        use dicom_encoding::text::SpecificCharacterSet;
+
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // (0040,A730) ContentSequence, SQ, undefined length
+            0x40, 0x00, 0x30, 0xA7, b'S', b'Q', 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
+            // -- 12 -- item start, undefined length
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // -- 20 -- item end
+            0xFE, 0xFF, 0x0D, 0xE0, 0x00, 0x00, 0x00, 0x00,
+            // -- 28 -- item start, undefined length
+            0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF,
+            // -- 36 -- (0040,A040) ValueType, CS, len = 4
+            0x40, 0x00, 0x40, 0xA0, b'C', b'S', 0x04, 0x00, b'T', b'E', b'X', b'T',
+            // -- 48 -- (0040,A124) UID, UI, len = 64, truncated
+            0x40, 0x00, 0x24, 0xA1, b'U', b'I', 0x40, 0x00, b'1', b'.',
+        ];
+
+        let mut tokens =
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap()
+                .with_base_offset(100)
+                .with_offsets();
+        let offsets: Vec<_> = tokens
+            .by_ref()
+            .take(7)
+            .map(|token| token.unwrap().0)
+            .collect();
+        assert_eq!(offsets, vec![100, 112, 120, 128, 136, 144, 148]);
+
+        let err = tokens.next().unwrap().unwrap_err();
+        assert!(matches!(err.inner(), Error::Io(_)));
+        let context = err.context().unwrap();
+        assert_eq!(
+            context,
+            &ErrorContext {
+                position: 156,
+                path: vec![(Tag(0x0040, 0xA730), 1)],
+                tag: Some(Tag(0x0040, 0xA124)),
+                transfer_syntax: Some("1.2.840.10008.1.2.1"),
+            }
+        );
+        assert_eq!(
+            context.to_string(),
+            "@ 156 bytes in (0040,A730)[1].(0040,A124), transfer syntax 1.2.840.10008.1.2.1"
+        );
+
+        // values read ahead in lenient mode keep their own offset
+        let offsets: Vec<_> =
+            DataSetReader::new_with(DATA, &explicit_vr_le(), SpecificCharacterSet::Default)
+                .unwrap()
+                .lenient(true)
+                .with_base_offset(100)
+                .with_offsets()
+                .take(7)
+                .map(|token| token.unwrap().0)
+                .collect();
+        assert_eq!(offsets, vec![100, 112, 120, 128, 136, 144, 148]);
+    }
 }
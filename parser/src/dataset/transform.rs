@@ -0,0 +1,671 @@
+//! This module contains adapters for transforming a stream of data set
+//! tokens, so that DICOM data can be edited while it is read, without
+//! holding the whole data set in memory.
+//!
+//! The adapters are available through the [`TokenTransform`] trait,
+//! for any iterator of fallible data set tokens, such as a `DataSetReader`.
+//! Errors are passed through unchanged.
+//!
+//! Since removing, replacing or inserting elements changes the length of the
+//! sequences and items around them, data sets with sequences of explicit
+//! length should also go through [`undefined_lengths`] before writing.
+//!
+//! [`TokenTransform`]: trait.TokenTransform.html
+//! [`undefined_lengths`]: trait.TokenTransform.html#method.undefined_lengths
+use super::{value_length, DataToken};
+use crate::error::Result;
+use crate::parser::{DynamicDicomParser, Parse};
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
+use dicom_core::header::{DataElementHeader, Length};
+use dicom_core::value::{PrimitiveValue, C};
+use dicom_core::{Tag, VR};
+use dicom_dictionary_std::tags;
+use dicom_encoding::text::{DefaultCharacterSetCodec, SpecificCharacterSet, TextCodec};
+use dicom_encoding::TransferSyntax;
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::io::Cursor;
+
+/// Extension trait for composing transformations over a stream of data set
+/// tokens.
+pub trait TokenTransform: Iterator<Item = Result<DataToken>> + Sized {
+    /// Keep only the elements whose tag satisfies the predicate,
+    /// at any depth. Sequences which are left out are removed along with
+    /// all of their items.
+    fn filter_elements<F>(self, predicate: F) -> FilterElements<Self, F>
+    where
+        F: FnMut(Tag) -> bool,
+    {
+        FilterElements {
+            iter: self,
+            predicate,
+            skip_value: false,
+            skip_depth: 0,
+        }
+    }
+
+    /// Remove all private elements, at any depth.
+    fn drop_private(self) -> FilterElements<Self, fn(Tag) -> bool> {
+        self.filter_elements(|tag| tag.group() & 1 == 0)
+    }
+
+    /// Replace the values of primitive elements. The function is called for
+    /// each element with its header and value, and returns the new value,
+    /// or `None` to keep it. The lengths of replaced values are recomputed,
+    /// following the Specific Character Set of the data set.
+    fn replace_values<F>(self, f: F) -> ReplaceValues<Self, F>
+    where
+        F: FnMut(&DataElementHeader, &PrimitiveValue) -> Option<PrimitiveValue>,
+    {
+        ReplaceValues {
+            iter: self,
+            f,
+            text: Box::new(DefaultCharacterSetCodec),
+            queued: None,
+        }
+    }
+
+    /// Insert the given primitive elements at the root of the data set,
+    /// in tag order. Elements already in the data set with the same tag
+    /// are replaced. The lengths of the inserted values are computed,
+    /// following the Specific Character Set of the data set.
+    fn insert_elements<E>(self, elements: E) -> InsertElements<Self>
+    where
+        E: IntoIterator<Item = (Tag, VR, PrimitiveValue)>,
+    {
+        let mut pending: Vec<_> = elements.into_iter().collect();
+        // sorted in reverse, so that the next one to insert is at the end
+        pending.sort_by_key(|e| Reverse(e.0));
+        InsertElements {
+            iter: self,
+            pending,
+            text: Box::new(DefaultCharacterSetCodec),
+            queued: VecDeque::new(),
+            depth: 0,
+            skip_value: false,
+            skip_depth: 0,
+            charset_value: false,
+        }
+    }
+
+    /// Give all sequences and items an undefined length, so that they are
+    /// written with delimiters, and their length does not need to be known
+    /// in advance.
+    fn undefined_lengths(self) -> UndefinedLengths<Self> {
+        UndefinedLengths { iter: self }
+    }
+
+    /// Prepare the tokens of a data set encoded in the given transfer syntax
+    /// for writing in another one. Values of unknown value representation
+    /// (UN) are decoded in the representation given by the dictionary,
+    /// where possible. The lengths of all values are recomputed,
+    /// and all sequences and items are given an undefined length.
+    ///
+    /// Fails if the transfer syntax is not supported for decoding.
+    fn transcode_values<D>(self, ts: &TransferSyntax, dict: D) -> Result<TranscodeValues<Self, D>>
+    where
+        D: DataDictionary,
+    {
+        Ok(TranscodeValues {
+            iter: self,
+            dict,
+            parser: DynamicDicomParser::new_with(ts, SpecificCharacterSet::Default)?,
+            text: Box::new(DefaultCharacterSetCodec),
+            queued: None,
+        })
+    }
+}
+
+impl<I> TokenTransform for I where I: Iterator<Item = Result<DataToken>> {}
+
+/// Keep track of the tokens of an element which is being removed.
+/// Returns whether the token is part of the element.
+fn skip_token(token: &DataToken, skip_value: &mut bool, skip_depth: &mut u32) -> bool {
+    if *skip_value {
+        *skip_value = false;
+        return matches!(token, DataToken::PrimitiveValue(_));
+    }
+    if *skip_depth > 0 {
+        match token {
+            DataToken::SequenceStart { .. } => *skip_depth += 1,
+            DataToken::SequenceEnd => *skip_depth -= 1,
+            _ => {}
+        }
+        return true;
+    }
+    false
+}
+
+/// Start removing the element of the given token.
+fn start_skipping(token: &DataToken, skip_value: &mut bool, skip_depth: &mut u32) {
+    match token {
+        DataToken::ElementHeader(_) => *skip_value = true,
+        DataToken::SequenceStart { .. } => *skip_depth = 1,
+        _ => {}
+    }
+}
+
+/// The tag of the element started by the token, if any.
+fn element_tag(token: &DataToken) -> Option<Tag> {
+    match token {
+        DataToken::ElementHeader(header) => Some(header.tag),
+        DataToken::SequenceStart { tag, .. } => Some(*tag),
+        _ => None,
+    }
+}
+
+/// An adapter which removes elements from a stream of data set tokens.
+/// See [`TokenTransform::filter_elements`].
+///
+/// [`TokenTransform::filter_elements`]: trait.TokenTransform.html#method.filter_elements
+pub struct FilterElements<I, F> {
+    iter: I,
+    predicate: F,
+    /// whether the next value belongs to a removed element
+    skip_value: bool,
+    /// the nesting depth within a removed sequence
+    skip_depth: u32,
+}
+
+impl<I, F> Iterator for FilterElements<I, F>
+where
+    I: Iterator<Item = Result<DataToken>>,
+    F: FnMut(Tag) -> bool,
+{
+    type Item = Result<DataToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let token = match self.iter.next()? {
+                Ok(token) => token,
+                Err(e) => return Some(Err(e)),
+            };
+            if skip_token(&token, &mut self.skip_value, &mut self.skip_depth) {
+                continue;
+            }
+            match element_tag(&token) {
+                Some(tag) if !(self.predicate)(tag) => {
+                    start_skipping(&token, &mut self.skip_value, &mut self.skip_depth);
+                }
+                _ => return Some(Ok(token)),
+            }
+        }
+    }
+}
+
+/// An adapter which replaces the values of primitive elements
+/// in a stream of data set tokens.
+/// See [`TokenTransform::replace_values`].
+///
+/// [`TokenTransform::replace_values`]: trait.TokenTransform.html#method.replace_values
+pub struct ReplaceValues<I, F> {
+    iter: I,
+    f: F,
+    /// the codec used to compute the length of text values
+    text: Box<dyn TextCodec>,
+    /// the token which follows the last header
+    queued: Option<Result<DataToken>>,
+}
+
+impl<I, F> ReplaceValues<I, F> {
+    /// Set the character set in which text values will be encoded,
+    /// so that their length is computed accordingly.
+    /// It is replaced by the Specific Character Set of the data set, if any.
+    pub fn with_charset(mut self, charset: SpecificCharacterSet) -> Self {
+        if let Some(text) = charset.codec() {
+            self.text = text;
+        }
+        self
+    }
+
+    /// Follow a change of the specific character set in the data set.
+    fn update_charset(&mut self, value: &PrimitiveValue) {
+        if let Some(text) =
+            SpecificCharacterSet::from_value(value).and_then(|charset| charset.codec())
+        {
+            self.text = text;
+        }
+    }
+}
+
+impl<I, F> Iterator for ReplaceValues<I, F>
+where
+    I: Iterator<Item = Result<DataToken>>,
+    F: FnMut(&DataElementHeader, &PrimitiveValue) -> Option<PrimitiveValue>,
+{
+    type Item = Result<DataToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.queued.take() {
+            return Some(token);
+        }
+        let mut header = match self.iter.next()? {
+            Ok(DataToken::ElementHeader(header)) => header,
+            other => return Some(other),
+        };
+        match self.iter.next() {
+            Some(Ok(DataToken::PrimitiveValue(value))) => {
+                let value = match (self.f)(&header, &value) {
+                    Some(new_value) => {
+                        header.len = Length(value_length(header.vr, &new_value, &*self.text));
+                        new_value
+                    }
+                    None => value,
+                };
+                if header.tag == tags::SPECIFIC_CHARACTER_SET {
+                    self.update_charset(&value);
+                }
+                self.queued = Some(Ok(DataToken::PrimitiveValue(value)));
+            }
+            other => self.queued = other,
+        }
+        Some(Ok(DataToken::ElementHeader(header)))
+    }
+}
+
+/// An adapter which inserts primitive elements at the root of a stream of
+/// data set tokens. See [`TokenTransform::insert_elements`].
+///
+/// [`TokenTransform::insert_elements`]: trait.TokenTransform.html#method.insert_elements
+pub struct InsertElements<I> {
+    iter: I,
+    /// the elements left to insert, in reverse tag order
+    pending: Vec<(Tag, VR, PrimitiveValue)>,
+    /// the codec used to compute the length of text values
+    text: Box<dyn TextCodec>,
+    /// the tokens ready to be yielded
+    queued: VecDeque<Result<DataToken>>,
+    /// the sequence nesting depth
+    depth: u32,
+    /// whether the next value belongs to a replaced element
+    skip_value: bool,
+    /// the nesting depth within a replaced sequence
+    skip_depth: u32,
+    /// whether the next value is of the specific character set
+    charset_value: bool,
+}
+
+impl<I> InsertElements<I> {
+    /// Set the character set in which text values will be encoded,
+    /// so that their length is computed accordingly.
+    /// It is replaced by the Specific Character Set of the data set, if any.
+    pub fn with_charset(mut self, charset: SpecificCharacterSet) -> Self {
+        if let Some(text) = charset.codec() {
+            self.text = text;
+        }
+        self
+    }
+
+    /// Follow a change of the specific character set in the data set.
+    fn update_charset(&mut self, value: &PrimitiveValue) {
+        if let Some(text) =
+            SpecificCharacterSet::from_value(value).and_then(|charset| charset.codec())
+        {
+            self.text = text;
+        }
+    }
+
+    /// Queue the tokens of the next element to insert.
+    fn queue_next_pending(&mut self) {
+        if let Some((tag, vr, value)) = self.pending.pop() {
+            if tag == tags::SPECIFIC_CHARACTER_SET {
+                self.update_charset(&value);
+            }
+            let len = value_length(vr, &value, &*self.text);
+            self.queued
+                .push_back(Ok(DataToken::ElementHeader(DataElementHeader::new(
+                    tag,
+                    vr,
+                    Length(len),
+                ))));
+            self.queued.push_back(Ok(DataToken::PrimitiveValue(value)));
+        }
+    }
+}
+
+impl<I> Iterator for InsertElements<I>
+where
+    I: Iterator<Item = Result<DataToken>>,
+{
+    type Item = Result<DataToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.queued.pop_front() {
+                return Some(token);
+            }
+            let token = match self.iter.next() {
+                Some(Ok(token)) => token,
+                Some(Err(e)) => return Some(Err(e)),
+                None if !self.pending.is_empty() => {
+                    self.queue_next_pending();
+                    continue;
+                }
+                None => return None,
+            };
+            if skip_token(&token, &mut self.skip_value, &mut self.skip_depth) {
+                continue;
+            }
+            if self.depth == 0 {
+                if let Some(tag) = element_tag(&token) {
+                    while self.pending.last().map(|e| e.0 < tag).unwrap_or(false) {
+                        self.queue_next_pending();
+                    }
+                    if self.pending.last().map(|e| e.0 == tag).unwrap_or(false) {
+                        // replace the element in the data set
+                        self.queue_next_pending();
+                        start_skipping(&token, &mut self.skip_value, &mut self.skip_depth);
+                        continue;
+                    }
+                }
+            }
+            match &token {
+                DataToken::SequenceStart { .. } => self.depth += 1,
+                DataToken::SequenceEnd => self.depth -= 1,
+                DataToken::ElementHeader(header) => {
+                    self.charset_value = header.tag == tags::SPECIFIC_CHARACTER_SET;
+                }
+                DataToken::PrimitiveValue(value) if self.charset_value => {
+                    self.update_charset(value);
+                }
+                _ => {}
+            }
+            self.queued.push_back(Ok(token));
+        }
+    }
+}
+
+/// An adapter which gives all sequences and items in a stream of data set
+/// tokens an undefined length.
+/// See [`TokenTransform::undefined_lengths`].
+///
+/// [`TokenTransform::undefined_lengths`]: trait.TokenTransform.html#method.undefined_lengths
+#[derive(Debug)]
+pub struct UndefinedLengths<I> {
+    iter: I,
+}
+
+impl<I> Iterator for UndefinedLengths<I>
+where
+    I: Iterator<Item = Result<DataToken>>,
+{
+    type Item = Result<DataToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.iter.next()?;
+        Some(token.map(|token| match token {
+            DataToken::SequenceStart { tag, .. } => DataToken::SequenceStart {
+                tag,
+                len: Length::UNDEFINED,
+            },
+            DataToken::ItemStart { .. } => DataToken::ItemStart {
+                len: Length::UNDEFINED,
+            },
+            token => token,
+        }))
+    }
+}
+
+/// An adapter which prepares a stream of data set tokens for writing in
+/// another transfer syntax.
+/// See [`TokenTransform::transcode_values`].
+///
+/// [`TokenTransform::transcode_values`]: trait.TokenTransform.html#method.transcode_values
+pub struct TranscodeValues<I, D> {
+    iter: I,
+    dict: D,
+    /// the parser for decoding values of unknown VR
+    parser: DynamicDicomParser<'static>,
+    /// the codec used to compute the length of text values
+    text: Box<dyn TextCodec>,
+    /// the token which follows the last header
+    queued: Option<Result<DataToken>>,
+}
+
+impl<I, D> TranscodeValues<I, D>
+where
+    D: DataDictionary,
+{
+    /// Decode a value of unknown VR in the VR of the attribute,
+    /// updating the header. The value is kept as is if the VR is not known
+    /// or the value cannot be decoded.
+    fn decode_unknown(
+        &mut self,
+        header: &mut DataElementHeader,
+        value: PrimitiveValue,
+    ) -> PrimitiveValue {
+        let vr = match self.dict.by_tag(header.tag).map(|entry| entry.vr()) {
+            Some(vr) if vr != VR::UN && vr != VR::SQ => vr,
+            _ => return value,
+        };
+        let bytes = match value {
+            PrimitiveValue::U8(bytes) => bytes,
+            value => return value,
+        };
+        let resolved = DataElementHeader::new(header.tag, vr, Length(bytes.len() as u32));
+        let mut source = Cursor::new(bytes.into_vec());
+        match self.parser.read_value(&mut source, &resolved) {
+            Ok(value) => {
+                header.vr = vr;
+                value
+            }
+            Err(_) => PrimitiveValue::U8(C::from(source.into_inner())),
+        }
+    }
+
+    /// Follow a change of the specific character set in the data set.
+    fn update_charset(&mut self, value: &PrimitiveValue) -> Result<()> {
+        if let Some(charset) = SpecificCharacterSet::from_value(value) {
+            self.parser.set_character_set(charset)?;
+            if let Some(text) = charset.codec() {
+                self.text = text;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I, D> Iterator for TranscodeValues<I, D>
+where
+    I: Iterator<Item = Result<DataToken>>,
+    D: DataDictionary,
+{
+    type Item = Result<DataToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.queued.take() {
+            return Some(token);
+        }
+        let mut header = match self.iter.next()? {
+            Ok(DataToken::ElementHeader(header)) => header,
+            Ok(DataToken::SequenceStart { tag, .. }) => {
+                return Some(Ok(DataToken::SequenceStart {
+                    tag,
+                    len: Length::UNDEFINED,
+                }))
+            }
+            Ok(DataToken::ItemStart { .. }) => {
+                return Some(Ok(DataToken::ItemStart {
+                    len: Length::UNDEFINED,
+                }))
+            }
+            other => return Some(other),
+        };
+        match self.iter.next() {
+            Some(Ok(DataToken::PrimitiveValue(mut value))) => {
+                if header.vr == VR::UN {
+                    value = self.decode_unknown(&mut header, value);
+                }
+                if header.tag == tags::SPECIFIC_CHARACTER_SET {
+                    if let Err(e) = self.update_charset(&value) {
+                        return Some(Err(e));
+                    }
+                }
+                header.len = Length(value_length(header.vr, &value, &*self.text));
+                self.queued = Some(Ok(DataToken::PrimitiveValue(value)));
+            }
+            other => self.queued = other,
+        }
+        Some(Ok(DataToken::ElementHeader(header)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenTransform;
+    use crate::dataset::{DataSetWriter, DataToken};
+    use dicom_core::header::{DataElementHeader, Length};
+    use dicom_core::value::PrimitiveValue;
+    use dicom_core::{Tag, VR};
+    use dicom_dictionary_std::tags;
+    use dicom_encoding::text::DefaultCharacterSetCodec;
+    use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianEncoder;
+
+    fn header(tag: Tag, vr: VR, len: u32) -> DataToken {
+        DataToken::ElementHeader(DataElementHeader::new(tag, vr, Length(len)))
+    }
+
+    fn text(value: &str) -> DataToken {
+        DataToken::PrimitiveValue(PrimitiveValue::Strs([value.to_owned()].as_ref().into()))
+    }
+
+    #[test]
+    fn transform_token_stream() {
+        let tokens = vec![
+            header(tags::MODALITY, VR::CS, 2),
+            text("CT"),
+            header(Tag(0x0009, 0x0010), VR::LO, 4),
+            text("ACME"),
+            header(tags::PATIENT_NAME, VR::PN, 8),
+            text("Doe^John"),
+            DataToken::SequenceStart {
+                tag: Tag(0x0040, 0xA730),
+                len: Length(40),
+            },
+            DataToken::ItemStart { len: Length(32) },
+            header(Tag(0x0029, 0x1001), VR::UN, 8),
+            DataToken::PrimitiveValue(PrimitiveValue::U8([0; 8].as_ref().into())),
+            header(Tag(0x0040, 0xA040), VR::CS, 4),
+            text("TEXT"),
+            DataToken::ItemEnd,
+            DataToken::SequenceEnd,
+        ];
+
+        let transformed: Vec<_> = tokens
+            .into_iter()
+            .map(Ok)
+            .drop_private()
+            .replace_values(|header, _value| {
+                if header.tag == tags::PATIENT_NAME {
+                    Some(PrimitiveValue::Str("Anonymous".to_owned()))
+                } else {
+                    None
+                }
+            })
+            .insert_elements(vec![
+                (
+                    tags::PATIENT_ID,
+                    VR::LO,
+                    PrimitiveValue::Str("ID1".to_owned()),
+                ),
+                (tags::MODALITY, VR::CS, PrimitiveValue::Str("MR".to_owned())),
+            ])
+            .undefined_lengths()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            transformed,
+            vec![
+                header(tags::MODALITY, VR::CS, 2),
+                DataToken::PrimitiveValue(PrimitiveValue::Str("MR".to_owned())),
+                header(tags::PATIENT_NAME, VR::PN, 10),
+                DataToken::PrimitiveValue(PrimitiveValue::Str("Anonymous".to_owned())),
+                header(tags::PATIENT_ID, VR::LO, 4),
+                DataToken::PrimitiveValue(PrimitiveValue::Str("ID1".to_owned())),
+                DataToken::SequenceStart {
+                    tag: Tag(0x0040, 0xA730),
+                    len: Length::UNDEFINED,
+                },
+                DataToken::ItemStart {
+                    len: Length::UNDEFINED,
+                },
+                header(Tag(0x0040, 0xA040), VR::CS, 4),
+                text("TEXT"),
+                DataToken::ItemEnd,
+                DataToken::SequenceEnd,
+            ]
+        );
+
+        // the stream can be fed to a writer
+        let mut out = Vec::new();
+        let mut writer = DataSetWriter::new(
+            &mut out,
+            ExplicitVRLittleEndianEncoder::default(),
+            DefaultCharacterSetCodec,
+        );
+        writer
+            .write_tokens(transformed.into_iter().map(Ok))
+            .unwrap();
+        assert_eq!(out.len(), 10 + 18 + 12 + 12 + 8 + 12 + 8 + 8);
+    }
+
+    #[test]
+    fn follow_specific_character_set() {
+        let tokens = vec![
+            header(tags::SPECIFIC_CHARACTER_SET, VR::CS, 10),
+            text("ISO_IR_192"),
+            header(tags::PATIENT_NAME, VR::PN, 8),
+            text("Doe^John"),
+        ];
+        let name = "Müller^Jörg";
+
+        // the new value is encoded in UTF-8, not in the default character set
+        let replaced: Vec<_> = tokens
+            .clone()
+            .into_iter()
+            .map(Ok)
+            .replace_values(|header, _value| {
+                if header.tag == tags::PATIENT_NAME {
+                    Some(PrimitiveValue::Str(name.to_owned()))
+                } else {
+                    None
+                }
+            })
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(replaced[2], header(tags::PATIENT_NAME, VR::PN, 14));
+
+        let inserted: Vec<_> = tokens
+            .into_iter()
+            .map(Ok)
+            .insert_elements(vec![(
+                tags::PATIENT_NAME,
+                VR::PN,
+                PrimitiveValue::Str(name.to_owned()),
+            )])
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(inserted[2], header(tags::PATIENT_NAME, VR::PN, 14));
+
+        // also when the character set is inserted
+        let inserted: Vec<_> = vec![header(tags::PATIENT_NAME, VR::PN, 8), text("Doe^John")]
+            .into_iter()
+            .map(Ok)
+            .insert_elements(vec![
+                (
+                    tags::SPECIFIC_CHARACTER_SET,
+                    VR::CS,
+                    PrimitiveValue::Str("ISO_IR_192".to_owned()),
+                ),
+                (
+                    tags::PATIENT_NAME,
+                    VR::PN,
+                    PrimitiveValue::Str(name.to_owned()),
+                ),
+            ])
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(inserted[2], header(tags::PATIENT_NAME, VR::PN, 14));
+    }
+}
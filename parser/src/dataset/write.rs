@@ -2,7 +2,7 @@
 use crate::dataset::*;
 use crate::error::{DataSetSyntaxError, Error, Result};
 use crate::printer::Printer;
-use dicom_core::{DataElementHeader, Length, VR};
+use dicom_core::{DataElementHeader, Length, Tag, VR};
 use dicom_encoding::encode::{Encode, EncodeTo};
 use dicom_encoding::text::{SpecificCharacterSet, TextCodec};
 use dicom_encoding::TransferSyntax;
@@ -18,14 +18,63 @@ struct SeqToken {
     len: Length,
 }
 
+/// How the data set writer encodes the lengths of sequences and items.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum SequenceLengths {
+    /// Use the lengths given in the incoming tokens,
+    /// writing delimiters only for undefined lengths.
+    #[default]
+    AsIs,
+    /// Write all sequences and items with undefined length,
+    /// followed by the respective delimiters.
+    Undefined,
+    /// Write all sequences and items with their explicit length.
+    /// The tokens of each sequence are retained until the end of
+    /// the sequence, so that its length can be computed.
+    Explicit,
+}
+
+/// How the data set writer handles group length elements (gggg,0000).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum GroupLengths {
+    /// Write group lengths as given in the incoming tokens.
+    #[default]
+    AsIs,
+    /// Do not write any group length elements.
+    Remove,
+    /// Replace the value of group length elements at the root of the
+    /// data set with the length of the rest of the group. The tokens
+    /// of the group are retained until the end of the group, so a group
+    /// length of the pixel data group keeps all of the pixel data
+    /// in memory.
+    Recompute,
+}
+
 /// A stateful device for printing a DICOM data set in sequential order.
 /// This is analogous to the `DatasetReader` type for converting data
 /// set tokens to bytes.
+///
+/// By default, the writer trusts the lengths in the tokens given.
+/// See `with_sequence_lengths` and `with_group_lengths` for writing
+/// data sets in a normalized form. Tokens retained for computing lengths
+/// are only written on a call to `flush`.
 #[derive(Debug)]
 pub struct DataSetWriter<W, E, T> {
     printer: Printer<W, E, T>,
     seq_tokens: Vec<SeqToken>,
     last_de: Option<DataElementHeader>,
+    sequence_lengths: SequenceLengths,
+    group_lengths: GroupLengths,
+    /// the nesting depth of the incoming tokens
+    depth: u32,
+    /// whether to ignore the next value token
+    skip_value: bool,
+    /// tokens retained until their lengths are known
+    pending: Vec<DataToken>,
+    /// the group of the group length element retained, if any
+    pending_group: Option<u16>,
+    /// whether the encoder writes the value representation of each element
+    explicit_vr: bool,
 }
 
 impl<W> DataSetWriter<W, Box<dyn EncodeTo<W>>, Box<dyn TextCodec>>
@@ -37,7 +86,17 @@ where
             .encoder_for()
             .ok_or_else(|| Error::UnsupportedTransferSyntax)?;
         let text = cs.codec().ok_or_else(|| Error::UnsupportedCharacterSet)?;
-        Ok(DataSetWriter::new(to, encoder, text))
+        Ok(DataSetWriter::new(to, encoder, text).with_explicit_vr(ts.explicit_vr()))
+    }
+}
+
+impl<W, E> DataSetWriter<W, E, Box<dyn TextCodec>> {
+    /// Change the character set in which the following text values
+    /// are encoded.
+    pub fn set_character_set(&mut self, cs: SpecificCharacterSet) -> Result<()> {
+        let text = cs.codec().ok_or_else(|| Error::UnsupportedCharacterSet)?;
+        self.printer.set_text(text);
+        Ok(())
     }
 }
 
@@ -47,8 +106,35 @@ impl<W, E, T> DataSetWriter<W, E, T> {
             printer: Printer::new(to, encoder, text),
             seq_tokens: Vec::new(),
             last_de: None,
+            sequence_lengths: SequenceLengths::default(),
+            group_lengths: GroupLengths::default(),
+            depth: 0,
+            skip_value: false,
+            pending: Vec::new(),
+            pending_group: None,
+            explicit_vr: true,
         }
     }
+
+    /// Set how the lengths of sequences and items are written.
+    pub fn with_sequence_lengths(mut self, mode: SequenceLengths) -> Self {
+        self.sequence_lengths = mode;
+        self
+    }
+
+    /// Set how group length elements are written.
+    pub fn with_group_lengths(mut self, mode: GroupLengths) -> Self {
+        self.group_lengths = mode;
+        self
+    }
+
+    /// Set whether the encoder writes the value representation of each
+    /// element (explicit VR, the default) or not, which the lengths
+    /// computed by the writer depend on.
+    pub fn with_explicit_vr(mut self, explicit_vr: bool) -> Self {
+        self.explicit_vr = explicit_vr;
+        self
+    }
 }
 
 impl<W, E, T> DataSetWriter<W, E, T>
@@ -57,7 +143,8 @@ where
     E: Encode,
     T: TextCodec,
 {
-    /// Feed the given sequence of tokens which are part of the same data set.
+    /// Feed the given sequence of tokens which are part of the same data set,
+    /// then flush any retained tokens.
     pub fn write_sequence<I>(&mut self, tokens: I) -> Result<()>
     where
         I: IntoIterator<Item = DataToken>,
@@ -66,12 +153,119 @@ where
             self.write(token)?;
         }
 
-        Ok(())
+        self.flush()
+    }
+
+    /// Feed the given sequence of fallible tokens which are part of the same
+    /// data set, such as those of a `DataSetReader`, stopping at the first
+    /// error. Any retained tokens are flushed at the end.
+    pub fn write_tokens<I>(&mut self, tokens: I) -> Result<()>
+    where
+        I: IntoIterator<Item = Result<DataToken>>,
+    {
+        for token in tokens {
+            self.write(token?)?;
+        }
+
+        self.flush()
     }
 
     /// Feed the given data set token for writing the data set.
-    #[inline]
+    ///
+    /// Depending on the writer's options, the token may be retained
+    /// until the length of the enclosing sequence or group is known.
     pub fn write(&mut self, token: DataToken) -> Result<()> {
+        let token = match (self.sequence_lengths, token) {
+            (SequenceLengths::Undefined, DataToken::SequenceStart { tag, .. }) => {
+                DataToken::SequenceStart {
+                    tag,
+                    len: Length::UNDEFINED,
+                }
+            }
+            (SequenceLengths::Undefined, DataToken::ItemStart { .. }) => DataToken::ItemStart {
+                len: Length::UNDEFINED,
+            },
+            (_, token) => token,
+        };
+
+        let at_root = self.depth == 0;
+        match token {
+            DataToken::SequenceStart { .. } | DataToken::ItemStart { .. } => self.depth += 1,
+            DataToken::SequenceEnd | DataToken::ItemEnd => {
+                self.depth = self.depth.saturating_sub(1)
+            }
+            _ => {}
+        }
+
+        match token {
+            DataToken::ElementHeader(header)
+                if header.tag.element() == 0 && self.group_lengths == GroupLengths::Remove =>
+            {
+                self.skip_value = true;
+                return Ok(());
+            }
+            DataToken::PrimitiveValue(_) if self.skip_value => {
+                self.skip_value = false;
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        if at_root {
+            if let Some(tag) = element_tag(&token) {
+                if self.pending_group.is_some() && self.pending_group != Some(tag.group()) {
+                    self.flush()?;
+                }
+                if tag.element() == 0 && self.group_lengths == GroupLengths::Recompute {
+                    self.flush()?;
+                    self.pending_group = Some(tag.group());
+                }
+            }
+        }
+
+        if !self.pending.is_empty() || self.pending_group.is_some() {
+            let end_of_sequence = matches!(token, DataToken::SequenceEnd) && self.depth == 0;
+            self.pending.push(token);
+            if end_of_sequence && self.pending_group.is_none() {
+                self.flush()?;
+            }
+            return Ok(());
+        }
+
+        if self.sequence_lengths == SequenceLengths::Explicit {
+            if let DataToken::SequenceStart { .. } = token {
+                self.pending.push(token);
+                return Ok(());
+            }
+        }
+
+        self.write_direct(token)
+    }
+
+    /// Write all tokens retained by the writer,
+    /// computing the lengths of sequences and groups.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.pending.is_empty() {
+            self.pending_group = None;
+            return Ok(());
+        }
+        let mut tokens = std::mem::take(&mut self.pending);
+        if self.sequence_lengths == SequenceLengths::Explicit {
+            resolve_lengths(&mut tokens, self.explicit_vr);
+        }
+        if self.pending_group.take().is_some() && tokens.len() > 1 {
+            let group_len = encoded_length(&mut tokens[2..], self.explicit_vr);
+            if let DataToken::PrimitiveValue(value) = &mut tokens[1] {
+                *value = PrimitiveValue::U32([group_len].as_ref().into());
+            }
+        }
+        for token in tokens {
+            self.write_direct(token)?;
+        }
+        Ok(())
+    }
+
+    fn write_direct(&mut self, token: DataToken) -> Result<()> {
         // adjust the logic of sequence printing:
         // explicit length sequences or items should not print
         // the respective delimiter
@@ -151,17 +345,28 @@ where
     }
 }
 
+/// Obtain the tag of the element started by the given token, if any.
+fn element_tag(token: &DataToken) -> Option<Tag> {
+    match token {
+        DataToken::ElementHeader(header) => Some(header.tag),
+        DataToken::SequenceStart { tag, .. } => Some(*tag),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::DataToken;
-    use super::DataSetWriter;
+    use super::{DataSetWriter, GroupLengths, SequenceLengths};
     use dicom_core::{
         header::{DataElementHeader, Length},
         value::PrimitiveValue,
-        Tag, VR,
+        VR,
     };
+    use dicom_dictionary_std::tags;
     use dicom_encoding::text::DefaultCharacterSetCodec;
     use dicom_encoding::transfer_syntax::explicit_le::ExplicitVRLittleEndianEncoder;
+    use dicom_encoding::transfer_syntax::implicit_le::ImplicitVRLittleEndianEncoder;
 
     fn validate_dataset_writer<I>(tokens: I, ground_truth: &[u8])
     where
@@ -181,18 +386,18 @@ mod tests {
     fn write_sequence_explicit() {
         let tokens = vec![
             DataToken::SequenceStart {
-                tag: Tag(0x0018, 0x6011),
+                tag: tags::SEQUENCE_OF_ULTRASOUND_REGIONS,
                 len: Length(46),
             },
             DataToken::ItemStart { len: Length(20) },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
             DataToken::PrimitiveValue(PrimitiveValue::U16([1].as_ref().into())),
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6014),
+                tag: tags::REGION_DATA_TYPE,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -200,7 +405,7 @@ mod tests {
             DataToken::ItemEnd,
             DataToken::ItemStart { len: Length(10) },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -208,7 +413,7 @@ mod tests {
             DataToken::ItemEnd,
             DataToken::SequenceEnd,
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0020, 0x4000),
+                tag: tags::IMAGE_COMMENTS,
                 vr: VR::LT,
                 len: Length(4),
             }),
@@ -241,24 +446,142 @@ mod tests {
         validate_dataset_writer(tokens, GROUND_TRUTH);
     }
 
+    fn ultrasound_regions(seq_len: Length, item_lens: [Length; 2]) -> Vec<DataToken> {
+        vec![
+            DataToken::ElementHeader(DataElementHeader {
+                tag: dicom_core::Tag(0x0018, 0x0000),
+                vr: VR::UL,
+                len: Length(4),
+            }),
+            DataToken::PrimitiveValue(PrimitiveValue::U32([0].as_ref().into())),
+            DataToken::SequenceStart {
+                tag: tags::SEQUENCE_OF_ULTRASOUND_REGIONS,
+                len: seq_len,
+            },
+            DataToken::ItemStart { len: item_lens[0] },
+            DataToken::ElementHeader(DataElementHeader {
+                tag: tags::REGION_SPATIAL_FORMAT,
+                vr: VR::US,
+                len: Length(2),
+            }),
+            DataToken::PrimitiveValue(PrimitiveValue::U16([1].as_ref().into())),
+            DataToken::ItemEnd,
+            DataToken::ItemStart { len: item_lens[1] },
+            DataToken::ElementHeader(DataElementHeader {
+                tag: tags::REGION_SPATIAL_FORMAT,
+                vr: VR::US,
+                len: Length(2),
+            }),
+            DataToken::PrimitiveValue(PrimitiveValue::U16([4].as_ref().into())),
+            DataToken::ItemEnd,
+            DataToken::SequenceEnd,
+            DataToken::ElementHeader(DataElementHeader {
+                tag: tags::IMAGE_COMMENTS,
+                vr: VR::LT,
+                len: Length(4),
+            }),
+            DataToken::PrimitiveValue(PrimitiveValue::Str("TEST".into())),
+        ]
+    }
+
+    fn write_with(
+        tokens: Vec<DataToken>,
+        sequence_lengths: SequenceLengths,
+        group_lengths: GroupLengths,
+    ) -> Vec<u8> {
+        let mut raw_out: Vec<u8> = vec![];
+        let mut dset_writer = DataSetWriter::new(
+            &mut raw_out,
+            ExplicitVRLittleEndianEncoder::default(),
+            DefaultCharacterSetCodec,
+        )
+        .with_sequence_lengths(sequence_lengths)
+        .with_group_lengths(group_lengths);
+        dset_writer.write_sequence(tokens).unwrap();
+        raw_out
+    }
+
+    #[test]
+    fn write_normalized_lengths() {
+        let undefined = ultrasound_regions(Length::UNDEFINED, [Length::UNDEFINED; 2]);
+        let explicit = ultrasound_regions(Length(36), [Length(10), Length(10)]);
+
+        let undefined_out =
+            write_with(undefined.clone(), SequenceLengths::AsIs, GroupLengths::AsIs);
+        let explicit_out = write_with(explicit.clone(), SequenceLengths::AsIs, GroupLengths::AsIs);
+        assert_eq!(undefined_out.len(), 12 + 12 + 2 * 26 + 8 + 12);
+        assert_eq!(explicit_out.len(), 12 + 12 + 2 * 18 + 12);
+
+        assert_eq!(
+            write_with(
+                undefined.clone(),
+                SequenceLengths::Explicit,
+                GroupLengths::AsIs
+            ),
+            explicit_out
+        );
+        assert_eq!(
+            write_with(
+                explicit.clone(),
+                SequenceLengths::Undefined,
+                GroupLengths::AsIs
+            ),
+            undefined_out
+        );
+
+        // group length removed
+        let out = write_with(
+            explicit.clone(),
+            SequenceLengths::AsIs,
+            GroupLengths::Remove,
+        );
+        assert_eq!(&out[..], &explicit_out[12..]);
+
+        // group length recomputed, in both forms
+        let out = write_with(
+            undefined.clone(),
+            SequenceLengths::AsIs,
+            GroupLengths::Recompute,
+        );
+        assert_eq!(&out[..8], &explicit_out[..8]);
+        assert_eq!(out[8..12], 72_u32.to_le_bytes());
+        assert_eq!(&out[12..], &undefined_out[12..]);
+        let out = write_with(explicit, SequenceLengths::Explicit, GroupLengths::Recompute);
+        assert_eq!(out[8..12], 48_u32.to_le_bytes());
+        assert_eq!(&out[12..], &explicit_out[12..]);
+
+        // in implicit VR, the sequence header is shorter
+        let mut out = Vec::new();
+        DataSetWriter::new(
+            &mut out,
+            ImplicitVRLittleEndianEncoder::default(),
+            DefaultCharacterSetCodec,
+        )
+        .with_explicit_vr(false)
+        .with_group_lengths(GroupLengths::Recompute)
+        .write_sequence(undefined)
+        .unwrap();
+        assert_eq!(out[4..12], [4, 0, 0, 0, 68, 0, 0, 0]);
+    }
+
     #[test]
     fn write_sequence_implicit() {
         let tokens = vec![
             DataToken::SequenceStart {
-                tag: Tag(0x0018, 0x6011),
+                tag: tags::SEQUENCE_OF_ULTRASOUND_REGIONS,
                 len: Length::UNDEFINED,
             },
             DataToken::ItemStart {
                 len: Length::UNDEFINED,
             },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
             DataToken::PrimitiveValue(PrimitiveValue::U16([1].as_ref().into())),
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6014),
+                tag: tags::REGION_DATA_TYPE,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -268,7 +591,7 @@ mod tests {
                 len: Length::UNDEFINED,
             },
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0018, 0x6012),
+                tag: tags::REGION_SPATIAL_FORMAT,
                 vr: VR::US,
                 len: Length(2),
             }),
@@ -276,7 +599,7 @@ mod tests {
             DataToken::ItemEnd,
             DataToken::SequenceEnd,
             DataToken::ElementHeader(DataElementHeader {
-                tag: Tag(0x0020, 0x4000),
+                tag: tags::IMAGE_COMMENTS,
                 vr: VR::LT,
                 len: Length(4),
             }),
@@ -1,7 +1,7 @@
 use crate::dataset::DataToken;
 use dicom_core::error::Error as CoreError;
 pub use dicom_core::error::{CastValueError, InvalidValueReadError};
-use dicom_core::Tag;
+use dicom_core::{Tag, VR};
 use dicom_encoding::error::{Error as EncodingError, TextEncodingError};
 use quick_error::quick_error;
 use std::error::Error as BaseError;
@@ -61,6 +61,28 @@ quick_error! {
         MissingElementValue {
             description("Expected value after data element header, but was missing")
         }
+        /// Raised when the length of a data element value exceeds
+        /// the configured read limit.
+        ElementTooLong(tag: Tag, len: u32, max: u32) {
+            description("Data element value exceeds the maximum length")
+            display("Value of element {} has {} bytes, exceeding the limit of {} bytes", tag, len, max)
+        }
+        /// Raised when the data set exceeds the configured limit
+        /// on the number of bytes read.
+        DataSetTooLong(max: u64) {
+            description("Data set exceeds the maximum length")
+            display("Data set exceeds the limit of {} bytes", max)
+        }
+        /// Raised when sequences are nested deeper than the configured limit.
+        NestingTooDeep(max: u32) {
+            description("Sequence nesting exceeds the maximum depth")
+            display("Sequences are nested deeper than the limit of {} levels", max)
+        }
+        /// Raised when the data set has more elements than the configured limit.
+        TooManyElements(max: u32) {
+            description("Data set exceeds the maximum number of elements")
+            display("Data set has more than the limit of {} elements", max)
+        }
         /// Raised while parsing a DICOM data set and found an unexpected
         /// element header or value.
         DataSetSyntax(err: DataSetSyntaxError) {
@@ -97,6 +119,108 @@ quick_error! {
             cause(err)
             display(self_) -> ("{}: {}", self_.description(), err.description())
         }
+        /// An error raised while reading a data set,
+        /// along with where in the data set it happened.
+        Located(err: Box<Error>, context: ErrorContext) {
+            description("Error while reading data set")
+            cause(&**err)
+            display("{} ({})", err, context)
+        }
+    }
+}
+
+impl Error {
+    /// Retrieve the position and context in which the error was raised,
+    /// if available.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Error::Located(_, context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the error without its context.
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::Located(err, _) => err.inner(),
+            err => err,
+        }
+    }
+
+    /// Discard the context of the error.
+    pub fn into_inner(self) -> Error {
+        match self {
+            Error::Located(err, _) => err.into_inner(),
+            err => err,
+        }
+    }
+
+    /// Attach the position and transfer syntax to the error,
+    /// unless it already has a context.
+    pub(crate) fn at(self, position: u64, transfer_syntax: Option<&'static str>) -> Error {
+        match self {
+            err @ Error::Located(..) => err,
+            err => Error::Located(
+                Box::new(err),
+                ErrorContext {
+                    position,
+                    path: Vec::new(),
+                    tag: None,
+                    transfer_syntax,
+                },
+            ),
+        }
+    }
+
+    /// Attach the location of the element being decoded to the error.
+    pub(crate) fn with_path(mut self, path: &[(Tag, u32)], tag: Option<Tag>) -> Error {
+        if let Error::Located(_, ref mut context) = self {
+            context.path = path.to_vec();
+            context.tag = tag;
+        }
+        self
+    }
+}
+
+/// Where in a data set an error was raised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The position in the source, in bytes, of the element header or value
+    /// being decoded
+    pub position: u64,
+    /// The sequence attributes and respective item indices
+    /// leading to the element, from the root of the data set
+    pub path: Vec<(Tag, u32)>,
+    /// The tag of the element being decoded, if known
+    pub tag: Option<Tag>,
+    /// The UID of the transfer syntax of the data set, if known
+    pub transfer_syntax: Option<&'static str>,
+}
+
+impl fmt::Display for ErrorContext {
+    /// Format the context in the form
+    /// `@ 1024 bytes in (0040,A730)[0].(0040,A040), transfer syntax 1.2.840.10008.1.2.1`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "@ {} bytes", self.position)?;
+        if !self.path.is_empty() || self.tag.is_some() {
+            f.write_str(" in ")?;
+            for (i, (tag, index)) in self.path.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(".")?;
+                }
+                write!(f, "{}[{}]", tag, index)?;
+            }
+            if let Some(tag) = self.tag {
+                if !self.path.is_empty() {
+                    f.write_str(".")?;
+                }
+                write!(f, "{}", tag)?;
+            }
+        }
+        if let Some(uid) = self.transfer_syntax {
+            write!(f, ", transfer syntax {}", uid)?;
+        }
+        Ok(())
     }
 }
 
@@ -149,3 +273,85 @@ impl ::std::error::Error for DataSetSyntaxError {
         }
     }
 }
+
+/// A problem in the encoding of a data set,
+/// which was worked around while reading in lenient mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadWarning {
+    /// The number of bytes read from the data set when the problem was found
+    pub position: u64,
+    /// The kind of problem
+    pub kind: ReadWarningKind,
+}
+
+impl fmt::Display for ReadWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "@ {} bytes: {}", self.position, self.kind)
+    }
+}
+
+/// The kind of encoding problem worked around in lenient mode.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReadWarningKind {
+    /// The data set was declared in explicit VR, but is encoded in implicit
+    /// VR. The value representations were resolved by dictionary.
+    ImplicitVr,
+    /// The value representation in the element header is not valid.
+    /// The element was read with a 2-byte length field,
+    /// and its value representation was resolved by dictionary.
+    InvalidVr(Tag, [u8; 2], VR),
+    /// The value length is not a multiple of the size of each value,
+    /// so the value was read as raw bytes.
+    OddLength(Tag, VR, u32),
+    /// The value is not valid in its value representation,
+    /// so it was kept as text.
+    InvalidValue(Tag, VR),
+    /// A sequence or item with an explicit length ended before its last
+    /// element did, at the given position.
+    InconsistentSequenceEnd(u64),
+    /// The data set ended with sequences or items still open,
+    /// which were closed.
+    MissingDelimiter,
+    /// An element at the root of the data set does not come after
+    /// the previous one in tag order. It was kept.
+    OutOfOrder(Tag),
+    /// The rest of the data set could not be read, and was ignored.
+    TrailingData(String),
+}
+
+impl fmt::Display for ReadWarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadWarningKind::ImplicitVr => {
+                f.write_str("data set is encoded in implicit VR instead of explicit VR")
+            }
+            ReadWarningKind::InvalidVr(tag, vr, resolved) => write!(
+                f,
+                "invalid value representation {:?} in element {}, assumed {}",
+                String::from_utf8_lossy(vr),
+                tag,
+                resolved
+            ),
+            ReadWarningKind::OddLength(tag, vr, len) => write!(
+                f,
+                "length {} of element {} is not valid in {}, read as bytes",
+                len, tag, vr
+            ),
+            ReadWarningKind::InvalidValue(tag, vr) => write!(
+                f,
+                "value of element {} is not valid in {}, read as text",
+                tag, vr
+            ),
+            ReadWarningKind::InconsistentSequenceEnd(eos) => {
+                write!(f, "sequence or item should have ended @ {} bytes", eos)
+            }
+            ReadWarningKind::MissingDelimiter => {
+                f.write_str("data set ended before the end of a sequence")
+            }
+            ReadWarningKind::OutOfOrder(tag) => write!(f, "element {} is out of order", tag),
+            ReadWarningKind::TrailingData(reason) => {
+                write!(f, "ignored the rest of the data set: {}", reason)
+            }
+        }
+    }
+}
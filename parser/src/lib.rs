@@ -7,7 +7,7 @@
 //!
 //! For a more intuitive, object-oriented API, please see the `dicom-object`
 //! crate.
-#![recursion_limit = "80"]
+#![recursion_limit = "110"]
 
 pub mod dataset;
 pub mod error;
@@ -17,4 +17,4 @@ pub mod printer;
 mod util;
 
 pub use dataset::DataSetReader;
-pub use parser::{DicomParser, DynamicDicomParser, Parse};
+pub use parser::{DicomParser, DynamicDicomParser, Parse, ReadLimits};
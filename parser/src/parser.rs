@@ -2,11 +2,13 @@
 //! The structures provided here can translate a byte data source into
 //! an iterator of elements, with either sequential or random access.
 
-use crate::error::{Error, Result};
+use crate::error::{Error, ReadWarning, ReadWarningKind, Result};
 use crate::util::n_times;
 use chrono::FixedOffset;
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
 use dicom_core::header::{DataElementHeader, Header, Length, SequenceItemHeader, Tag, VR};
 use dicom_core::value::{PrimitiveValue, C};
+use dicom_dictionary_std::{tags, StandardDataDictionary};
 use dicom_encoding::decode::basic::{BasicDecoder, LittleEndianBasicDecoder};
 use dicom_encoding::decode::primitive_value::*;
 use dicom_encoding::decode::{BasicDecode, DecodeFrom};
@@ -67,6 +69,55 @@ where
 
     /// Retrieve the exact number of bytes read by the parser.
     fn bytes_read(&self) -> u64;
+
+    /// Enable or disable lenient reading, in which known encoding problems
+    /// in the data are worked around instead of failing. Each problem found
+    /// is recorded as a warning.
+    ///
+    /// Parsers are strict by default,
+    /// and may not support lenient reading at all.
+    fn set_lenient(&mut self, _lenient: bool) {}
+
+    /// Take the warnings recorded in lenient mode since the last call.
+    fn take_warnings(&mut self) -> Vec<ReadWarning> {
+        Vec::new()
+    }
+
+    /// Set the limits to enforce while reading.
+    ///
+    /// Parsers have no limits by default,
+    /// and may not support enforcing them at all.
+    fn set_limits(&mut self, _limits: ReadLimits) {}
+}
+
+/// Limits on the content read, for safely parsing DICOM data
+/// from untrusted sources. A limit of `None` means no limit,
+/// which is the default for all of them.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ReadLimits {
+    /// The maximum length of a single data element value, in bytes.
+    /// Values are only allocated once their length is checked against it.
+    pub max_element_length: Option<u32>,
+    /// The maximum number of bytes read in total.
+    pub max_total_bytes: Option<u64>,
+    /// The maximum depth of nested sequences.
+    pub max_nesting_depth: Option<u32>,
+    /// The maximum number of data elements, including those in sequence items.
+    pub max_elements: Option<u32>,
+}
+
+impl ReadLimits {
+    /// Obtain a set of limits suitable for reading
+    /// data sets of up to 2 GiB from untrusted sources,
+    /// with values of up to 64 MiB each.
+    pub fn untrusted() -> Self {
+        ReadLimits {
+            max_element_length: Some(0x0400_0000),
+            max_total_bytes: Some(0x8000_0000),
+            max_nesting_depth: Some(32),
+            max_elements: Some(1_000_000),
+        }
+    }
 }
 
 /// Alias for a dynamically resolved DICOM parser. Although the data source may be known
@@ -93,6 +144,18 @@ pub struct DicomParser<D, BD, S: ?Sized, TC> {
     dt_utc_offset: FixedOffset,
     buffer: Vec<u8>,
     bytes_read: u64,
+    /// whether the data set is known to be in explicit VR
+    explicit_vr: bool,
+    /// whether to work around encoding problems
+    lenient: bool,
+    /// whether the data set turned out to be in implicit VR
+    implicit_vr_fallback: bool,
+    /// the encoding problems worked around so far
+    warnings: Vec<ReadWarning>,
+    /// the limits on the content read
+    limits: ReadLimits,
+    /// the UID of the transfer syntax, if known
+    transfer_syntax: Option<&'static str>,
 }
 
 impl<S: ?Sized, D, BD, TC> Debug for DicomParser<D, BD, S, TC>
@@ -107,6 +170,9 @@ where
             .field("basic", &self.basic)
             .field("text", &self.text)
             .field("dt_utc_offset", &self.dt_utc_offset)
+            .field("lenient", &self.lenient)
+            .field("limits", &self.limits)
+            .field("transfer_syntax", &self.transfer_syntax)
             .finish()
     }
 }
@@ -131,7 +197,10 @@ impl<'s> DynamicDicomParser<'s> {
             .ok_or_else(|| Error::UnsupportedTransferSyntax)?;
         let text = cs.codec().ok_or_else(|| Error::UnsupportedCharacterSet)?;
 
-        Ok(DynamicDicomParser::new(decoder, basic, text))
+        let mut parser = DynamicDicomParser::new(decoder, basic, text);
+        parser.explicit_vr = ts.explicit_vr();
+        parser.transfer_syntax = Some(ts.uid());
+        Ok(parser)
     }
 }
 
@@ -157,6 +226,12 @@ where
             dt_utc_offset: FixedOffset::east(0),
             buffer: Vec::with_capacity(PARSER_BUFFER_CAPACITY),
             bytes_read: 0,
+            explicit_vr: true,
+            lenient: false,
+            implicit_vr_fallback: false,
+            warnings: Vec::new(),
+            limits: ReadLimits::default(),
+            transfer_syntax: Some("1.2.840.10008.1.2.1"),
         }
     }
 }
@@ -169,6 +244,9 @@ where
     TC: TextCodec,
 {
     /// Create a new DICOM parser from its parts.
+    ///
+    /// Since the parser cannot tell whether the decoder is for explicit VR,
+    /// data element headers are not recovered in lenient mode.
     pub fn new(decoder: D, basic: BD, text: TC) -> DicomParser<D, BD, S, TC> {
         DicomParser {
             phantom: PhantomData,
@@ -178,11 +256,115 @@ where
             dt_utc_offset: FixedOffset::east(0),
             buffer: Vec::with_capacity(PARSER_BUFFER_CAPACITY),
             bytes_read: 0,
+            explicit_vr: false,
+            lenient: false,
+            implicit_vr_fallback: false,
+            warnings: Vec::new(),
+            limits: ReadLimits::default(),
+            transfer_syntax: None,
         }
     }
 
     // ---------------- private methods ---------------------
 
+    /// Check the number of bytes read against the limit.
+    fn check_total_bytes(&self) -> Result<()> {
+        match self.limits.max_total_bytes {
+            Some(max) if self.bytes_read > max => Err(Error::DataSetTooLong(max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the length of a value against the limits
+    /// before it is read.
+    fn check_value_limits(&self, header: &DataElementHeader) -> Result<()> {
+        let len = match header.len().get() {
+            Some(len) => len,
+            None => return Ok(()),
+        };
+        if let Some(max) = self.limits.max_element_length {
+            if len > max {
+                return Err(Error::ElementTooLong(header.tag, len, max));
+            }
+        }
+        match self.limits.max_total_bytes {
+            Some(max) if self.bytes_read + u64::from(len) > max => Err(Error::DataSetTooLong(max)),
+            _ => Ok(()),
+        }
+    }
+
+    fn warn(&mut self, kind: ReadWarningKind) {
+        self.warnings.push(ReadWarning {
+            position: self.bytes_read,
+            kind,
+        });
+    }
+
+    /// Decode an explicit VR data element header, while recovering from
+    /// invalid value representations. If the first header of the data set
+    /// does not have a valid VR, the whole data set is read as implicit VR.
+    fn decode_header_lenient(&mut self, from: &mut S) -> Result<DataElementHeader> {
+        let mut buf = [0u8; 8];
+        from.read_exact(&mut buf)?;
+        let tag = self.basic.decode_tag(&buf[0..4])?;
+        let vr_bytes = [buf[4], buf[5]];
+        let vr = VR::from_binary(vr_bytes);
+
+        if tag.0 != 0xFFFE && !self.implicit_vr_fallback && vr.is_none() && self.bytes_read == 0 {
+            self.implicit_vr_fallback = true;
+            self.warn(ReadWarningKind::ImplicitVr);
+        }
+        if tag.0 != 0xFFFE && self.implicit_vr_fallback {
+            let len = self.basic.decode_ul(&buf[4..8])?;
+            self.bytes_read += 8;
+            return Ok(DataElementHeader::new(
+                tag,
+                implicit_vr_of(tag),
+                Length(len),
+            ));
+        }
+
+        let (vr, len) = match vr {
+            // item delimiters do not have VR or reserved field
+            _ if tag.0 == 0xFFFE => (VR::UN, self.basic.decode_ul(&buf[4..8])?),
+            Some(vr @ VR::OB) | Some(vr @ VR::OD) | Some(vr @ VR::OF) | Some(vr @ VR::OL)
+            | Some(vr @ VR::OW) | Some(vr @ VR::SQ) | Some(vr @ VR::UC) | Some(vr @ VR::UR)
+            | Some(vr @ VR::UT) | Some(vr @ VR::UN) => {
+                // skip the reserved bytes, then read 4 bytes for data length
+                let mut len = [0u8; 4];
+                from.read_exact(&mut len)?;
+                self.bytes_read += 4;
+                (vr, self.basic.decode_ul(&len[..])?)
+            }
+            Some(vr) => (vr, u32::from(self.basic.decode_us(&buf[6..8])?)),
+            None => {
+                let vr = implicit_vr_of(tag);
+                self.warn(ReadWarningKind::InvalidVr(tag, vr_bytes, vr));
+                (vr, u32::from(self.basic.decode_us(&buf[6..8])?))
+            }
+        };
+        self.bytes_read += 8;
+        Ok(DataElementHeader::new(tag, vr, Length(len)))
+    }
+
+    /// Check whether the length of a binary value is a multiple of the size
+    /// of each value. If not, a warning is recorded
+    /// and the length is returned, so that the value is read as bytes.
+    fn check_value_length(&mut self, header: &DataElementHeader) -> Option<Length> {
+        let len = header.len().get()?;
+        let size = match header.vr() {
+            VR::US | VR::SS | VR::OW => 2,
+            VR::AT | VR::UL | VR::SL | VR::FL | VR::OL | VR::OF => 4,
+            VR::FD | VR::OD | VR::UV | VR::SV | VR::OV => 8,
+            _ => return None,
+        };
+        if len % size == 0 {
+            return None;
+        }
+        self.warn(ReadWarningKind::OddLength(header.tag, header.vr, len));
+        Some(Length(len))
+    }
+
     fn read_value_tag(
         &mut self,
         from: &mut S,
@@ -301,6 +483,7 @@ where
 
         self.buffer.resize_with(len, Default::default);
         from.read_exact(&mut self.buffer)?;
+        self.bytes_read += len as u64;
         let buf = trim_trail_empty_bytes(&self.buffer);
         if buf.is_empty() {
             return Ok(PrimitiveValue::Empty);
@@ -320,7 +503,6 @@ where
             .split(|b| *b == b'\\')
             .map(|part| Ok(parse_date(part)?.0))
             .collect();
-        self.bytes_read += len as u64;
         Ok(PrimitiveValue::Date(vec?))
     }
 
@@ -334,6 +516,7 @@ where
 
         self.buffer.resize_with(len, Default::default);
         from.read_exact(&mut self.buffer)?;
+        self.bytes_read += len as u64;
         let buf = trim_trail_empty_bytes(&self.buffer);
         if buf.is_empty() {
             return Ok(PrimitiveValue::Empty);
@@ -349,7 +532,6 @@ where
                     .map_err(|e| Error::from(InvalidValueReadError::from(e)))
             })
             .collect();
-        self.bytes_read += len as u64;
         Ok(PrimitiveValue::F64(parts?))
     }
 
@@ -363,6 +545,7 @@ where
 
         self.buffer.resize_with(len, Default::default);
         from.read_exact(&mut self.buffer)?;
+        self.bytes_read += len as u64;
         let buf = trim_trail_empty_bytes(&self.buffer);
         if buf.is_empty() {
             return Ok(PrimitiveValue::Empty);
@@ -383,7 +566,6 @@ where
             .map(|part| Ok(parse_datetime(part, self.dt_utc_offset)?))
             .collect();
 
-        self.bytes_read += len as u64;
         Ok(PrimitiveValue::DateTime(vec?))
     }
 
@@ -396,6 +578,7 @@ where
         // sequence of signed integers in text form
         self.buffer.resize_with(len, Default::default);
         from.read_exact(&mut self.buffer)?;
+        self.bytes_read += len as u64;
         let buf = trim_trail_empty_bytes(&self.buffer);
         if buf.is_empty() {
             return Ok(PrimitiveValue::Empty);
@@ -411,7 +594,6 @@ where
                     .map_err(|e| Error::from(InvalidValueReadError::from(e)))
             })
             .collect();
-        self.bytes_read += len as u64;
         Ok(PrimitiveValue::I32(parts?))
     }
 
@@ -425,6 +607,7 @@ where
 
         self.buffer.resize_with(len, Default::default);
         from.read_exact(&mut self.buffer)?;
+        self.bytes_read += len as u64;
         let buf = trim_trail_empty_bytes(&self.buffer);
         if buf.is_empty() {
             return Ok(PrimitiveValue::Empty);
@@ -444,7 +627,6 @@ where
             .split(|b| *b == b'\\')
             .map(|part| parse_time(part).map(|t| t.0))
             .collect();
-        self.bytes_read += len as u64;
         Ok(PrimitiveValue::Time(vec?))
     }
 
@@ -550,13 +732,81 @@ where
     BD: BasicDecode,
     S: Read,
 {
-    fn set_character_set(&mut self, charset: SpecificCharacterSet) -> Result<()> {
+    pub(crate) fn set_character_set(&mut self, charset: SpecificCharacterSet) -> Result<()> {
         self.text = charset
             .codec()
             .ok_or_else(|| Error::UnsupportedCharacterSet)?;
         Ok(())
     }
 
+    fn read_value_strict(
+        &mut self,
+        from: &mut S,
+        header: &DataElementHeader,
+    ) -> Result<PrimitiveValue> {
+        match header.vr() {
+            VR::SQ => {
+                // sequence objects should not head over here, they are
+                // handled at a higher level
+                Err(Error::from(InvalidValueReadError::NonPrimitiveType))
+            }
+            VR::AT => self.read_value_tag(from, header),
+            VR::AE | VR::AS | VR::PN | VR::SH | VR::LO | VR::UC | VR::CS => {
+                self.read_value_strs(from, header)
+            }
+            VR::UI => self.read_value_ui(from, header),
+            VR::UT | VR::ST | VR::UR | VR::LT => self.read_value_str(from, header),
+            VR::UN | VR::OB => self.read_value_ob(from, header),
+            VR::US | VR::OW => self.read_value_us(from, header),
+            VR::SS => self.read_value_ss(from, header),
+            VR::DA => self.read_value_da(from, header),
+            VR::DT => self.read_value_dt(from, header),
+            VR::TM => self.read_value_tm(from, header),
+            VR::DS => self.read_value_ds(from, header),
+            VR::FD | VR::OD => self.read_value_od(from, header),
+            VR::FL | VR::OF => self.read_value_fl(from, header),
+            VR::IS => self.read_value_is(from, header),
+            VR::SL => self.read_value_sl(from, header),
+            VR::SV => self.read_value_sv(from, header),
+            VR::OL | VR::UL => self.read_value_ul(from, header),
+            VR::OV | VR::UV => self.read_value_uv(from, header),
+        }
+    }
+
+    /// Read a value while working around encoding problems: binary values
+    /// with an invalid length are read as bytes, and textual numbers, dates
+    /// and times which cannot be parsed are read as text.
+    fn read_value_lenient(
+        &mut self,
+        from: &mut S,
+        header: &DataElementHeader,
+    ) -> Result<PrimitiveValue> {
+        if let Some(len) = self.check_value_length(header) {
+            return self.read_value_ob(from, &DataElementHeader::new(header.tag, header.vr, len));
+        }
+
+        match header.vr() {
+            VR::DA | VR::DT | VR::TM | VR::DS | VR::IS => {
+                match self.read_value_strict(from, header) {
+                    Err(Error::Io(e)) => Err(Error::Io(e)),
+                    Err(_) if header.len().is_defined() => {
+                        // the value was fully read into the buffer,
+                        // but could not be parsed
+                        let parts: EncodingResult<C<_>> = self
+                            .buffer
+                            .split(|v| *v == b'\\')
+                            .map(|slice| DefaultCharacterSetCodec.decode(slice))
+                            .collect();
+                        self.warn(ReadWarningKind::InvalidValue(header.tag, header.vr));
+                        Ok(PrimitiveValue::Strs(parts?))
+                    }
+                    result => result,
+                }
+            }
+            _ => self.read_value_strict(from, header),
+        }
+    }
+
     fn read_value_ui(
         &mut self,
         from: &mut S,
@@ -567,24 +817,18 @@ where
         self.buffer.resize_with(len, Default::default);
         from.read_exact(&mut self.buffer)?;
 
-        let parts: EncodingResult<C<_>> = match header.vr() {
-            VR::AE | VR::CS | VR::AS => self
-                .buffer
-                .split(|v| *v == 0)
-                .map(|slice| DefaultCharacterSetCodec.decode(slice))
-                .collect(),
-            _ => self
-                .buffer
-                .split(|v| *v == 0)
-                .map(|slice| self.text.decode(slice))
-                .collect(),
-        };
+        // values are separated by backslashes, the trailing null padding is kept
+        let parts: EncodingResult<C<_>> = self
+            .buffer
+            .split(|v| *v == b'\\')
+            .map(|slice| DefaultCharacterSetCodec.decode(slice))
+            .collect();
 
         let parts = parts?;
         self.bytes_read += len as u64;
 
         // if it's a Specific Character Set, update the parser immediately.
-        if header.tag == Tag(0x0008, 0x0005) {
+        if header.tag == tags::SPECIFIC_CHARACTER_SET {
             // TODO trigger an error or warning on unsupported specific character sets.
             // Edge case handling strategies should be considered in the future.
             if let Some(charset) = parts
@@ -598,69 +842,46 @@ where
 
         Ok(PrimitiveValue::Strs(parts))
     }
-}
 
-impl<S: ?Sized, D, BD> Parse<S> for DicomParser<D, BD, S, Box<dyn TextCodec>>
-where
-    D: DecodeFrom<S>,
-    BD: BasicDecode,
-    S: Read,
-{
-    fn decode_header(&mut self, from: &mut S) -> Result<DataElementHeader> {
-        self.decoder
-            .decode_header(from)
-            .map(|(header, bytes_read)| {
-                self.bytes_read += bytes_read as u64;
-                header
-            })
-            .map_err(From::from)
+    /// Same as `Parse::decode_header`, without attaching the position to errors.
+    fn decode_header_unlocated(&mut self, from: &mut S) -> Result<DataElementHeader> {
+        let header = if self.lenient && self.explicit_vr {
+            self.decode_header_lenient(from)?
+        } else {
+            let (header, bytes_read) = self.decoder.decode_header(from)?;
+            self.bytes_read += bytes_read as u64;
+            header
+        };
+        self.check_total_bytes()?;
+        Ok(header)
     }
 
-    fn decode_item_header(&mut self, from: &mut S) -> Result<SequenceItemHeader> {
-        self.decoder
-            .decode_item_header(from)
-            .map(|header| {
-                self.bytes_read += 8;
-                header
-            })
-            .map_err(From::from)
+    /// Same as `Parse::decode_item_header`, without attaching the position to errors.
+    fn decode_item_header_unlocated(&mut self, from: &mut S) -> Result<SequenceItemHeader> {
+        let header = self.decoder.decode_item_header(from)?;
+        self.bytes_read += 8;
+        self.check_total_bytes()?;
+        Ok(header)
     }
 
-    fn read_value(&mut self, from: &mut S, header: &DataElementHeader) -> Result<PrimitiveValue> {
+    /// Same as `Parse::read_value`, without attaching the position to errors.
+    fn read_value_unlocated(
+        &mut self,
+        from: &mut S,
+        header: &DataElementHeader,
+    ) -> Result<PrimitiveValue> {
         if header.len() == Length(0) {
             return Ok(PrimitiveValue::Empty);
         }
-
-        match header.vr() {
-            VR::SQ => {
-                // sequence objects should not head over here, they are
-                // handled at a higher level
-                Err(Error::from(InvalidValueReadError::NonPrimitiveType))
-            }
-            VR::AT => self.read_value_tag(from, header),
-            VR::AE | VR::AS | VR::PN | VR::SH | VR::LO | VR::UC | VR::CS => {
-                self.read_value_strs(from, header)
-            }
-            VR::UI => self.read_value_ui(from, header),
-            VR::UT | VR::ST | VR::UR | VR::LT => self.read_value_str(from, header),
-            VR::UN | VR::OB => self.read_value_ob(from, header),
-            VR::US | VR::OW => self.read_value_us(from, header),
-            VR::SS => self.read_value_ss(from, header),
-            VR::DA => self.read_value_da(from, header),
-            VR::DT => self.read_value_dt(from, header),
-            VR::TM => self.read_value_tm(from, header),
-            VR::DS => self.read_value_ds(from, header),
-            VR::FD | VR::OD => self.read_value_od(from, header),
-            VR::FL | VR::OF => self.read_value_fl(from, header),
-            VR::IS => self.read_value_is(from, header),
-            VR::SL => self.read_value_sl(from, header),
-            VR::SV => self.read_value_sv(from, header),
-            VR::OL | VR::UL => self.read_value_ul(from, header),
-            VR::OV | VR::UV => self.read_value_uv(from, header),
+        self.check_value_limits(header)?;
+        if self.lenient {
+            return self.read_value_lenient(from, header);
         }
+        self.read_value_strict(from, header)
     }
 
-    fn read_value_preserved(
+    /// Same as `Parse::read_value_preserved`, without attaching the position to errors.
+    fn read_value_preserved_unlocated(
         &mut self,
         from: &mut S,
         header: &DataElementHeader,
@@ -668,6 +889,13 @@ where
         if header.len() == Length(0) {
             return Ok(PrimitiveValue::Empty);
         }
+        self.check_value_limits(header)?;
+        if self.lenient {
+            if let Some(len) = self.check_value_length(header) {
+                return self
+                    .read_value_ob(from, &DataElementHeader::new(header.tag, header.vr, len));
+            }
+        }
 
         match header.vr() {
             VR::SQ => {
@@ -700,10 +928,70 @@ where
             VR::OV | VR::UV => self.read_value_uv(from, header),
         }
     }
+}
+
+impl<S: ?Sized, D, BD> Parse<S> for DicomParser<D, BD, S, Box<dyn TextCodec>>
+where
+    D: DecodeFrom<S>,
+    BD: BasicDecode,
+    S: Read,
+{
+    fn decode_header(&mut self, from: &mut S) -> Result<DataElementHeader> {
+        let position = self.bytes_read;
+        self.decode_header_unlocated(from)
+            .map_err(|e| e.at(position, self.transfer_syntax))
+    }
+
+    fn decode_item_header(&mut self, from: &mut S) -> Result<SequenceItemHeader> {
+        let position = self.bytes_read;
+        self.decode_item_header_unlocated(from)
+            .map_err(|e| e.at(position, self.transfer_syntax))
+    }
+
+    fn read_value(&mut self, from: &mut S, header: &DataElementHeader) -> Result<PrimitiveValue> {
+        let position = self.bytes_read;
+        self.read_value_unlocated(from, header)
+            .map_err(|e| e.at(position, self.transfer_syntax))
+    }
+
+    fn read_value_preserved(
+        &mut self,
+        from: &mut S,
+        header: &DataElementHeader,
+    ) -> Result<PrimitiveValue> {
+        let position = self.bytes_read;
+        self.read_value_preserved_unlocated(from, header)
+            .map_err(|e| e.at(position, self.transfer_syntax))
+    }
 
     fn bytes_read(&self) -> u64 {
         self.bytes_read
     }
+
+    fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    fn take_warnings(&mut self) -> Vec<ReadWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    fn set_limits(&mut self, limits: ReadLimits) {
+        self.limits = limits;
+    }
+}
+
+/// Resolve the value representation of an attribute by dictionary,
+/// as done in implicit VR.
+fn implicit_vr_of(tag: Tag) -> VR {
+    if tag == tags::PIXEL_DATA {
+        VR::OW
+    } else {
+        StandardDataDictionary
+            .by_tag(tag)
+            .map(|entry| entry.vr())
+            .unwrap_or(VR::UN)
+    }
 }
 
 /// Remove trailing spaces and null characters.
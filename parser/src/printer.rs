@@ -36,6 +36,16 @@ impl<W, E, T> Printer<W, E, T> {
         }
     }
 
+    /// Retrieve the encoder used by this printer.
+    pub fn encoder(&self) -> &E {
+        &self.encoder
+    }
+
+    /// Replace the text codec used by this printer.
+    pub fn set_text(&mut self, text: T) {
+        self.text = text;
+    }
+
     pub fn with_text<U>(self, text: U) -> Printer<W, E, U> {
         Printer {
             to: self.to,
@@ -107,6 +117,12 @@ where
         de: &DataElementHeader,
         value: &PrimitiveValue,
     ) -> Result<()> {
+        // numbers in IS and DS are written as text
+        if let Some(texts) = numbers_as_text(de.vr(), value) {
+            self.encode_texts(&texts[..], de.vr())?;
+            return Ok(());
+        }
+
         // intercept string encoding calls to use the text codec
         match value {
             PrimitiveValue::Str(text) => {
@@ -128,7 +144,7 @@ where
     fn encode_text(&mut self, text: &str, vr: VR) -> Result<()> {
         let bytes = self.encode_text_untrailed(text, vr)?;
         if bytes % 2 == 1 {
-            self.to.write_all(b" ")?;
+            self.to.write_all(padding(vr))?;
             self.bytes_written += 1;
         }
         Ok(())
@@ -148,7 +164,7 @@ where
             }
         }
         if acc % 2 == 1 {
-            self.to.write_all(b" ")?;
+            self.to.write_all(padding(vr))?;
             self.bytes_written += 1;
         }
         Ok(())
@@ -167,3 +183,40 @@ where
         Ok(data.len())
     }
 }
+
+/// The byte used to pad text values to an even length:
+/// a null character for UIDs, a space otherwise.
+fn padding(vr: VR) -> &'static [u8] {
+    if vr == VR::UI {
+        b"\0"
+    } else {
+        b" "
+    }
+}
+
+/// Obtain the textual form of the numbers in an IS or DS value,
+/// if they are held in binary form.
+pub(crate) fn numbers_as_text(vr: VR, value: &PrimitiveValue) -> Option<Vec<String>> {
+    match (vr, value) {
+        (VR::IS, PrimitiveValue::I32(values)) => {
+            Some(values.iter().map(|v| v.to_string()).collect())
+        }
+        (VR::DS, PrimitiveValue::F64(values)) => {
+            Some(values.iter().map(|v| decimal_string(*v)).collect())
+        }
+        _ => None,
+    }
+}
+
+/// Format a number as a decimal string, which is limited to 16 characters.
+fn decimal_string(value: f64) -> String {
+    let text = value.to_string();
+    if text.len() <= 16 {
+        return text;
+    }
+    (0..16)
+        .rev()
+        .map(|precision| format!("{:.*e}", precision, value))
+        .find(|text| text.len() <= 16)
+        .unwrap_or(text)
+}
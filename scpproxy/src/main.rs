@@ -1,4 +1,5 @@
 use clap::{App, Arg};
+use dicom_dictionary_std::StandardUidDictionary;
 use dicom_ul::pdu::reader::{read_pdu, DEFAULT_MAX_PDU};
 use dicom_ul::pdu::writer::write_pdu;
 use dicom_ul::pdu::Pdu;
@@ -53,6 +54,54 @@ pub enum ThreadMessage {
     },
 }
 
+/// Print the names of the UIDs negotiated in an association PDU.
+fn describe_uids(pdu: &Pdu) {
+    match pdu {
+        Pdu::AssociationRQ {
+            application_context_name,
+            presentation_contexts,
+            ..
+        } => {
+            println!(
+                "  application context: {}",
+                StandardUidDictionary::summary(application_context_name)
+            );
+            for pc in presentation_contexts {
+                println!(
+                    "  presentation context {}: {}",
+                    pc.id,
+                    StandardUidDictionary::summary(&pc.abstract_syntax)
+                );
+                for ts in &pc.transfer_syntaxes {
+                    println!(
+                        "    transfer syntax: {}",
+                        StandardUidDictionary::summary(ts)
+                    );
+                }
+            }
+        }
+        Pdu::AssociationAC {
+            application_context_name,
+            presentation_contexts,
+            ..
+        } => {
+            println!(
+                "  application context: {}",
+                StandardUidDictionary::summary(application_context_name)
+            );
+            for pc in presentation_contexts {
+                println!(
+                    "  presentation context {} ({:?}): {}",
+                    pc.id,
+                    pc.reason,
+                    StandardUidDictionary::summary(&pc.transfer_syntax)
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
 fn run(scu_stream: &mut TcpStream, destination_addr: &str) -> Result<()> {
     // Before we do anything, let's also open another connection to the destination
     // SCP.
@@ -133,10 +182,12 @@ fn run(scu_stream: &mut TcpStream, destination_addr: &str) -> Result<()> {
                     ThreadMessage::SendPdu { to, pdu } => match to {
                         ProviderType::SCU => {
                             println!("scu <---- scp: {:?}", &pdu);
+                            describe_uids(&pdu);
                             write_pdu(scu_stream, &pdu).unwrap();
                         }
                         ProviderType::SCP => {
                             println!("scu ----> scp: {:?}", &pdu);
+                            describe_uids(&pdu);
                             write_pdu(scp_stream, &pdu).unwrap();
                         }
                     },
@@ -0,0 +1,163 @@
+//! A simple application that receives DICOM instances over the network
+//! and stores them as files, in the spirit of DCMTK's `storescp`.
+//!
+//! Each association is served on its own thread.
+//! The outcome of every C-STORE request is printed to the standard output.
+use clap::{App, Arg};
+use dicom_ul::services::storage::{StorageScp, StoreOutcome, DEFAULT_PATH_TEMPLATE};
+use std::net::{TcpListener, TcpStream};
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let matches = App::new("storescp")
+        .about("Receive DICOM instances and store them as files")
+        .arg(
+            Arg::with_name("port")
+                .help("The TCP port to listen on")
+                .short("p")
+                .long("port")
+                .default_value("11111")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("out-dir")
+                .help("The directory of the files stored")
+                .short("o")
+                .long("out-dir")
+                .default_value(".")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("ae-title")
+                .help("Only accept associations to this called AE title")
+                .long("ae-title")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("path-template")
+                .help("The path of each file relative to the output directory, with attribute keywords in braces")
+                .long("path-template")
+                .default_value(DEFAULT_PATH_TEMPLATE)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("sop-class")
+                .help("Accept this storage SOP class UID (all standard storage SOP classes by default)")
+                .long("sop-class")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("transfer-syntax")
+                .help("Accept this transfer syntax UID, in order of preference (any supported by default)")
+                .long("transfer-syntax")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("promiscuous")
+                .help("Accept instances of any SOP class")
+                .long("promiscuous"),
+        )
+        .arg(
+            Arg::with_name("timeout")
+                .help("The number of seconds to wait for data from a peer before ending its association")
+                .long("timeout")
+                .default_value("60")
+                .takes_value(true),
+        )
+        .get_matches();
+
+    let port: u16 = match matches.value_of("port").unwrap().parse() {
+        Ok(port) => port,
+        Err(e) => {
+            eprintln!("error: invalid port: {}", e);
+            process::exit(2);
+        }
+    };
+
+    let timeout = match matches.value_of("timeout").unwrap().parse() {
+        Ok(timeout) if timeout > 0 => Duration::from_secs(timeout),
+        _ => {
+            eprintln!("error: the timeout must be a positive number of seconds");
+            process::exit(2);
+        }
+    };
+
+    let mut scp = StorageScp::new(matches.value_of("out-dir").unwrap())
+        .path_template(matches.value_of("path-template").unwrap())
+        .promiscuous(matches.is_present("promiscuous"))
+        .read_timeout(timeout);
+    if let Some(ae_title) = matches.value_of("ae-title") {
+        scp = scp.accept_called_ae_title(ae_title);
+    }
+    for uid in matches.values_of("sop-class").into_iter().flatten() {
+        scp = scp.with_abstract_syntax(uid);
+    }
+    for uid in matches.values_of("transfer-syntax").into_iter().flatten() {
+        scp = scp.with_transfer_syntax(uid);
+    }
+
+    let listener = match TcpListener::bind(("0.0.0.0", port)) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("error: could not listen on port {}: {}", port, e);
+            process::exit(1);
+        }
+    };
+    println!("listening on port {}", port);
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let scp = scp.clone();
+                thread::spawn(move || run(&scp, stream));
+            }
+            Err(e) => eprintln!("error: could not accept connection: {}", e),
+        }
+    }
+}
+
+/// Serve a single association.
+fn run(scp: &StorageScp, stream: TcpStream) {
+    let peer = stream
+        .peer_addr()
+        .map(|addr| addr.to_string())
+        .unwrap_or_else(|_| "unknown peer".to_string());
+    let association = match scp.establish(stream) {
+        Ok(association) => association,
+        Err(e) => {
+            eprintln!("[{}] association not established: {}", peer, e);
+            return;
+        }
+    };
+    println!(
+        "[{}] association established with {}",
+        peer,
+        association.client_ae_title()
+    );
+    match scp.serve(association, |outcome| report(&peer, outcome)) {
+        Ok(()) => println!("[{}] association released", peer),
+        Err(e) => eprintln!("[{}] association ended: {}", peer, e),
+    }
+}
+
+/// Print the outcome of a C-STORE request.
+fn report(peer: &str, outcome: &StoreOutcome) {
+    match &outcome.path {
+        Some(path) => println!(
+            "[{}] stored {} in {}",
+            peer,
+            outcome.sop_instance_uid,
+            path.display()
+        ),
+        None => eprintln!(
+            "[{}] failed to store {} (status {:#06X})",
+            peer, outcome.sop_instance_uid, outcome.status.0
+        ),
+    }
+}
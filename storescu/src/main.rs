@@ -0,0 +1,160 @@
+//! A simple application that sends DICOM files to a DICOM node
+//! through C-STORE requests, in the spirit of DCMTK's `storescu`.
+//!
+//! Directories are searched recursively for files.
+//! The status of each instance is printed to the standard output.
+//! The process exits with a non-zero code
+//! if any instance could not be read or stored.
+use clap::{App, Arg};
+use dicom_ul::services::storage::{Instance, StorageScu, StoreResult};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+fn main() {
+    let matches = App::new("storescu")
+        .about("Send DICOM files to a DICOM node")
+        .arg(
+            Arg::with_name("addr")
+                .help("The address of the SCP (host:port)")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::with_name("files")
+                .help("The DICOM files or directories to send")
+                .required(true)
+                .multiple(true)
+                .index(2),
+        )
+        .arg(
+            Arg::with_name("calling-ae-title")
+                .help("The calling AE title (this node)")
+                .long("calling-ae-title")
+                .default_value("STORE-SCU")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("called-ae-title")
+                .help("The called AE title (the SCP)")
+                .long("called-ae-title")
+                .default_value("ANY-SCP")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("max-pdu-length")
+                .help("The maximum PDU length accepted by this node")
+                .long("max-pdu-length")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("transcode")
+                .help("Transcode native data sets if their transfer syntax is not accepted")
+                .long("transcode"),
+        )
+        .arg(
+            Arg::with_name("verbose")
+                .help("Print the name of each file read")
+                .short("v")
+                .long("verbose"),
+        )
+        .get_matches();
+
+    let addr = matches.value_of("addr").unwrap();
+    let verbose = matches.is_present("verbose");
+
+    let mut scu = StorageScu::new()
+        .calling_ae_title(matches.value_of("calling-ae-title").unwrap())
+        .called_ae_title(matches.value_of("called-ae-title").unwrap())
+        .transcode(matches.is_present("transcode"));
+    if let Some(len) = matches.value_of("max-pdu-length") {
+        match len.parse() {
+            Ok(len) => scu = scu.max_pdu_length(len),
+            Err(e) => {
+                eprintln!("error: invalid max PDU length: {}", e);
+                process::exit(2);
+            }
+        }
+    }
+
+    let mut paths = Vec::new();
+    for path in matches.values_of("files").unwrap() {
+        if let Err(e) = collect_files(Path::new(path), &mut paths) {
+            eprintln!("error: could not read {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+
+    // only the file meta group is read here,
+    // each data set is read when its instance is sent
+    let mut failed = false;
+    let mut instances = Vec::with_capacity(paths.len());
+    for path in &paths {
+        match Instance::open_file(path) {
+            Ok(instance) => {
+                if verbose {
+                    println!("read {}", path.display());
+                }
+                instances.push(instance);
+            }
+            Err(e) => {
+                eprintln!("error: could not read {}: {}", path.display(), e);
+                failed = true;
+            }
+        }
+    }
+    if instances.is_empty() {
+        eprintln!("error: no DICOM files to send");
+        process::exit(1);
+    }
+
+    let results = scu.send(addr, &instances);
+    for result in &results {
+        report(result);
+        failed |= result.is_failure();
+    }
+
+    let stored = results.iter().filter(|r| !r.is_failure()).count();
+    println!("{} of {} instances stored", stored, results.len());
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Collect the files at the given path,
+/// searching directories recursively in name order.
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        for entry in entries {
+            collect_files(&entry, files)?;
+        }
+    } else {
+        files.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Print the result of sending an instance.
+fn report(result: &StoreResult) {
+    match result.status {
+        Some(status) => println!(
+            "{}: {} in {}",
+            result.sop_instance_uid,
+            status,
+            result.transfer_syntax.as_deref().unwrap_or_default()
+        ),
+        None => match &result.error {
+            Some(e) => eprintln!("{}: not sent: {}", result.sop_instance_uid, e),
+            None => eprintln!(
+                "{}: not sent, the file could not be read or no presentation context \
+                 accepted {} in a usable transfer syntax",
+                result.sop_instance_uid, result.sop_class_uid
+            ),
+        },
+    }
+}
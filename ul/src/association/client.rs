@@ -0,0 +1,779 @@
+//! Association requester module.
+//!
+//! The module provides an abstraction for a DICOM association
+//! in which this application entity is the one requesting the association.
+//! See [`ClientAssociationOptions`](./struct.ClientAssociationOptions.html)
+//! for details and examples on how to create an association.
+
+use super::pdata::{self, Message, PDataReader};
+use super::{
+    perform, role_selections_of, PresentationContext, RoleSelection,
+    DICOM_APPLICATION_CONTEXT_NAME, IMPLEMENTATION_CLASS_UID, IMPLEMENTATION_VERSION_NAME,
+    MAXIMUM_PRESENTATION_CONTEXTS,
+};
+use crate::error::{Error, Result};
+use crate::fsm::{Action, Effect, Event, State, StateMachine};
+use crate::pdu::reader::{read_pdu, DEFAULT_MAX_PDU, MAXIMUM_PDU_SIZE, MINIMUM_PDU_SIZE};
+use crate::pdu::{
+    PDataValue, Pdu, PresentationContextProposed, PresentationContextResultReason, UserVariableItem,
+};
+use std::collections::VecDeque;
+use std::io;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// A DICOM association builder for a client node.
+/// The final outcome is a [`ClientAssociation`].
+///
+/// This is the standard way of requesting and establishing
+/// an association with another DICOM node,
+/// that one usually taking the role of a service class provider (SCP).
+///
+/// # Example
+///
+/// ```no_run
+/// # use dicom_ul::association::client::ClientAssociationOptions;
+/// # fn run() -> dicom_ul::error::Result<()> {
+/// let association = ClientAssociationOptions::new()
+///     .calling_ae_title("MY-SCU")
+///     .called_ae_title("ANY-SCP")
+///     .with_presentation_context("1.2.840.10008.1.1", vec!["1.2.840.10008.1.2"])
+///     .establish("129.168.0.5:104")?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`ClientAssociation`]: ./struct.ClientAssociation.html
+#[derive(Debug, Clone)]
+pub struct ClientAssociationOptions {
+    /// the calling AE title
+    calling_ae_title: String,
+    /// the called AE title
+    called_ae_title: String,
+    /// the requested application context name
+    application_context_name: String,
+    /// the list of requested presentation contexts,
+    /// as pairs of abstract syntax and transfer syntaxes
+    presentation_contexts: Vec<(String, Vec<String>)>,
+    /// the SCP/SCU role selections proposed
+    role_selections: Vec<RoleSelection>,
+    /// the maximum PDU length accepted by this node
+    max_pdu_length: u32,
+    /// the implementation class UID announced to the peer
+    implementation_class_uid: String,
+    /// the implementation version name announced to the peer
+    implementation_version_name: String,
+    /// the time allowed for establishing the TCP connection
+    connection_timeout: Option<Duration>,
+    /// the time allowed for each read from the peer
+    read_timeout: Option<Duration>,
+}
+
+impl Default for ClientAssociationOptions {
+    fn default() -> Self {
+        ClientAssociationOptions {
+            calling_ae_title: "THIS-SCU".to_string(),
+            called_ae_title: "ANY-SCP".to_string(),
+            application_context_name: DICOM_APPLICATION_CONTEXT_NAME.to_string(),
+            presentation_contexts: Vec::new(),
+            role_selections: Vec::new(),
+            max_pdu_length: DEFAULT_MAX_PDU,
+            implementation_class_uid: IMPLEMENTATION_CLASS_UID.to_string(),
+            implementation_version_name: IMPLEMENTATION_VERSION_NAME.to_string(),
+            connection_timeout: None,
+            read_timeout: None,
+        }
+    }
+}
+
+impl ClientAssociationOptions {
+    /// Create a new set of options for establishing an association.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define the calling application entity title for the association,
+    /// which refers to this DICOM node.
+    ///
+    /// The default is `THIS-SCU`.
+    pub fn calling_ae_title<T: Into<String>>(mut self, calling_ae_title: T) -> Self {
+        self.calling_ae_title = calling_ae_title.into();
+        self
+    }
+
+    /// Define the called application entity title for the association,
+    /// which refers to the target DICOM node.
+    ///
+    /// The default is `ANY-SCP`.
+    pub fn called_ae_title<T: Into<String>>(mut self, called_ae_title: T) -> Self {
+        self.called_ae_title = called_ae_title.into();
+        self
+    }
+
+    /// Override the application context name,
+    /// which is the DICOM application context by default.
+    pub fn application_context_name<T: Into<String>>(
+        mut self,
+        application_context_name: T,
+    ) -> Self {
+        self.application_context_name = application_context_name.into();
+        self
+    }
+
+    /// Include this presentation context
+    /// in the list of proposed presentation contexts.
+    pub fn with_presentation_context<T>(
+        mut self,
+        abstract_syntax_uid: T,
+        transfer_syntax_uids: Vec<T>,
+    ) -> Self
+    where
+        T: Into<String>,
+    {
+        self.presentation_contexts.push((
+            abstract_syntax_uid.into(),
+            transfer_syntax_uids.into_iter().map(T::into).collect(),
+        ));
+        self
+    }
+
+    /// Propose the given SCP/SCU roles of this node for a SOP class,
+    /// such as the SCP role of a storage SOP class
+    /// for receiving instances through C-GET.
+    pub fn with_role_selection<T: Into<String>>(
+        mut self,
+        sop_class_uid: T,
+        scu_role: bool,
+        scp_role: bool,
+    ) -> Self {
+        self.role_selections.push(RoleSelection {
+            sop_class_uid: sop_class_uid.into(),
+            scu_role,
+            scp_role,
+        });
+        self
+    }
+
+    /// Override the maximum PDU length accepted by this node.
+    pub fn max_pdu_length(mut self, value: u32) -> Self {
+        self.max_pdu_length = value;
+        self
+    }
+
+    /// Override the implementation class UID announced to the peer.
+    pub fn implementation_class_uid<T: Into<String>>(mut self, uid: T) -> Self {
+        self.implementation_class_uid = uid.into();
+        self
+    }
+
+    /// Override the implementation version name announced to the peer.
+    pub fn implementation_version_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.implementation_version_name = name.into();
+        self
+    }
+
+    /// Give up on connecting to the peer after the given time.
+    ///
+    /// By default, the connection attempt waits for as long as
+    /// the operating system allows.
+    pub fn connection_timeout(mut self, timeout: Duration) -> Self {
+        self.connection_timeout = Some(timeout);
+        self
+    }
+
+    /// Give up on reading from the peer after the given time without data,
+    /// both when establishing the association and once it is established.
+    ///
+    /// By default, reads wait indefinitely.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Initiate the TCP connection to the given address
+    /// and request a new DICOM association,
+    /// negotiating the presentation contexts in the process.
+    ///
+    /// Fails with `Error::TooManyPresentationContexts`
+    /// if more than 128 presentation contexts were proposed,
+    /// with `Error::Rejected` if the peer rejects the association,
+    /// and with `Error::NoAcceptedPresentationContexts`
+    /// if none of the proposed presentation contexts were accepted,
+    /// in which case the association is aborted.
+    pub fn establish<A: ToSocketAddrs>(self, address: A) -> Result<ClientAssociation> {
+        let ClientAssociationOptions {
+            calling_ae_title,
+            called_ae_title,
+            application_context_name,
+            presentation_contexts,
+            role_selections,
+            max_pdu_length,
+            implementation_class_uid,
+            implementation_version_name,
+            connection_timeout,
+            read_timeout,
+        } = self;
+
+        if !(MINIMUM_PDU_SIZE..=MAXIMUM_PDU_SIZE).contains(&max_pdu_length) {
+            return Err(Error::InvalidMaxPdu);
+        }
+        if presentation_contexts.is_empty() {
+            return Err(Error::MissingAbstractSyntax);
+        }
+        if presentation_contexts.len() > MAXIMUM_PRESENTATION_CONTEXTS {
+            return Err(Error::TooManyPresentationContexts(
+                presentation_contexts.len(),
+            ));
+        }
+
+        // presentation context identifiers are odd numbers
+        let presentation_contexts: Vec<_> = presentation_contexts
+            .into_iter()
+            .enumerate()
+            .map(
+                |(i, (abstract_syntax, transfer_syntaxes))| PresentationContextProposed {
+                    id: (i * 2 + 1) as u8,
+                    abstract_syntax,
+                    transfer_syntaxes,
+                },
+            )
+            .collect();
+
+        let mut user_variables = vec![
+            UserVariableItem::MaxLength(max_pdu_length),
+            UserVariableItem::ImplementationClassUID(implementation_class_uid),
+        ];
+        user_variables.extend(role_selections.into_iter().map(|role| {
+            UserVariableItem::RoleSelection {
+                sop_class_uid: role.sop_class_uid,
+                scu_role: role.scu_role,
+                scp_role: role.scp_role,
+            }
+        }));
+        user_variables.push(UserVariableItem::ImplementationVersionName(
+            implementation_version_name,
+        ));
+
+        let rq = Pdu::AssociationRQ {
+            protocol_version: 1,
+            calling_ae_title,
+            called_ae_title,
+            application_context_name,
+            presentation_contexts: presentation_contexts.clone(),
+            user_variables,
+        };
+
+        let mut fsm = StateMachine::new();
+        fsm.handle(Event::AssociateRequest(rq))?;
+        let mut socket = match connection_timeout {
+            Some(timeout) => connect_timeout(address, timeout)?,
+            None => TcpStream::connect(address)?,
+        };
+        socket.set_read_timeout(read_timeout)?;
+        let transition = fsm.handle(Event::TransportConnectConfirm)?;
+        perform(&mut socket, transition.effects)?;
+
+        let pdu = read_pdu(&mut socket, max_pdu_length)?;
+        let transition = fsm.receive(pdu)?;
+        let action = transition.action;
+        let confirmation = perform(&mut socket, transition.effects)?.into_iter().next();
+
+        match (action, confirmation) {
+            (
+                Action::AE3,
+                Some(Effect::AssociateConfirmation(Pdu::AssociationAC {
+                    presentation_contexts: results,
+                    user_variables,
+                    ..
+                })),
+            ) => {
+                let acceptor_max_pdu_length = user_variables
+                    .iter()
+                    .filter_map(|item| match item {
+                        UserVariableItem::MaxLength(len) => Some(*len),
+                        _ => None,
+                    })
+                    .next()
+                    .unwrap_or(0);
+                let role_selections = role_selections_of(&user_variables);
+
+                let presentation_contexts: Vec<_> = results
+                    .into_iter()
+                    .filter(|result| result.reason == PresentationContextResultReason::Acceptance)
+                    .filter_map(|result| {
+                        presentation_contexts
+                            .iter()
+                            .find(|pc| pc.id == result.id)
+                            .map(|pc| PresentationContext {
+                                id: result.id,
+                                abstract_syntax: pc.abstract_syntax.clone(),
+                                transfer_syntax: result.transfer_syntax,
+                            })
+                    })
+                    .collect();
+
+                let mut association = ClientAssociation {
+                    presentation_contexts,
+                    role_selections,
+                    requestor_max_pdu_length: max_pdu_length,
+                    acceptor_max_pdu_length,
+                    socket,
+                    fsm,
+                    pending: VecDeque::new(),
+                };
+
+                if association.presentation_contexts.is_empty() {
+                    association.abort_inner()?;
+                    return Err(Error::NoAcceptedPresentationContexts);
+                }
+
+                Ok(association)
+            }
+            (
+                Action::AE4,
+                Some(Effect::AssociateConfirmation(Pdu::AssociationRJ { result, source })),
+            ) => Err(Error::Rejected(result, source)),
+            (Action::AA3, _) => Err(Error::Aborted),
+            _ => Err(Error::UnexpectedPdu),
+        }
+    }
+}
+
+/// Connect to the first of the given addresses
+/// which accepts the connection in time.
+fn connect_timeout<A: ToSocketAddrs>(address: A, timeout: Duration) -> Result<TcpStream> {
+    let mut last_error = None;
+    for address in address.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(socket) => return Ok(socket),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error
+        .unwrap_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no address to connect to"))
+        .into())
+}
+
+/// A DICOM upper level association from the perspective
+/// of a requesting application entity.
+///
+/// The most common operations of an established association are
+/// [`send`](#method.send)
+/// and [`receive`](#method.receive).
+/// DIMSE messages are best exchanged with
+/// [`send_message`](#method.send_message)
+/// and [`receive_message`](#method.receive_message),
+/// which take care of fragmenting and reassembling P-DATA values.
+///
+/// When the value falls out of scope,
+/// the program will abort the association if it is still established.
+/// Call [`release`](#method.release) to end it gracefully.
+#[derive(Debug)]
+pub struct ClientAssociation {
+    /// The presentation contexts accepted by the acceptor
+    presentation_contexts: Vec<PresentationContext>,
+    /// The SCP/SCU role selections accepted by the acceptor
+    role_selections: Vec<RoleSelection>,
+    /// The maximum PDU length that this application entity is expecting to receive
+    requestor_max_pdu_length: u32,
+    /// The maximum PDU length that the remote application entity accepts,
+    /// where 0 means unlimited
+    acceptor_max_pdu_length: u32,
+    /// The TCP stream to the other DICOM node
+    socket: TcpStream,
+    /// The upper layer state machine
+    fsm: StateMachine,
+    /// The presentation data values received but not consumed yet
+    pending: VecDeque<PDataValue>,
+}
+
+impl ClientAssociation {
+    /// Retrieve the list of presentation contexts
+    /// accepted by the association acceptor.
+    pub fn presentation_contexts(&self) -> &[PresentationContext] {
+        &self.presentation_contexts
+    }
+
+    /// Retrieve the list of SCP/SCU role selections
+    /// accepted by the association acceptor.
+    pub fn role_selections(&self) -> &[RoleSelection] {
+        &self.role_selections
+    }
+
+    /// Retrieve the maximum PDU length
+    /// admitted by this application entity.
+    pub fn requestor_max_pdu_length(&self) -> u32 {
+        self.requestor_max_pdu_length
+    }
+
+    /// Retrieve the maximum PDU length
+    /// admitted by the association acceptor,
+    /// where 0 means that there is no limit.
+    pub fn acceptor_max_pdu_length(&self) -> u32 {
+        self.acceptor_max_pdu_length
+    }
+
+    /// Send a P-DATA-TF PDU with the given values to the acceptor.
+    pub fn send(&mut self, data: Vec<PDataValue>) -> Result<()> {
+        let transition = self.fsm.handle(Event::PDataRequest(Pdu::PData { data }))?;
+        perform(&mut self.socket, transition.effects)?;
+        Ok(())
+    }
+
+    /// Read the next PDU from the acceptor.
+    ///
+    /// P-DATA-TF PDUs are the ones expected while the association is established.
+    /// A release request from the acceptor is replied to immediately
+    /// and handed back to the caller, after which the association is over.
+    /// Fails with `Error::Aborted` if the acceptor aborted the association
+    /// or closed the connection,
+    /// and with `Error::UnexpectedPdu` if the acceptor sent a PDU
+    /// not applicable at this point, in which case the association is aborted.
+    pub fn receive(&mut self) -> Result<Pdu> {
+        let pdu = self.read()?;
+        let transition = self.fsm.receive(pdu)?;
+        let action = transition.action;
+        let indication = perform(&mut self.socket, transition.effects)?
+            .into_iter()
+            .next();
+        match (action, indication) {
+            (Action::DT2, Some(Effect::PDataIndication(pdu))) => Ok(pdu),
+            (Action::AR2, _) => {
+                let transition = self.fsm.handle(Event::ReleaseResponse)?;
+                perform(&mut self.socket, transition.effects)?;
+                Ok(Pdu::ReleaseRQ)
+            }
+            (Action::AA3, _) => Err(Error::Aborted),
+            _ => Err(Error::UnexpectedPdu),
+        }
+    }
+
+    /// Send a message made of the given command set and optional data set
+    /// on the given presentation context,
+    /// split into fragments which fit in the maximum PDU length of the acceptor.
+    ///
+    /// Fails with `Error::UnknownPresentationContext`
+    /// if the presentation context was not accepted.
+    pub fn send_message(
+        &mut self,
+        presentation_context_id: u8,
+        command: &[u8],
+        data: Option<&[u8]>,
+    ) -> Result<()> {
+        self.check_presentation_context(presentation_context_id)?;
+        let max_pdu_length = self.acceptor_max_pdu_length;
+        pdata::send_message(
+            |data| self.send(data),
+            max_pdu_length,
+            presentation_context_id,
+            command,
+            data,
+        )
+    }
+
+    /// Receive the next command set from the acceptor,
+    /// returning it along with its presentation context ID.
+    ///
+    /// If the command set announces a data set,
+    /// it should be read next with [`data_reader`](#method.data_reader).
+    pub fn receive_command(&mut self) -> Result<(u8, Vec<u8>)> {
+        pdata::receive_command(|| self.next_value())
+    }
+
+    /// Obtain a reader of the data set which follows the last command set
+    /// received on the given presentation context.
+    pub fn data_reader(&mut self, presentation_context_id: u8) -> PDataReader<'_> {
+        PDataReader::new(move || self.next_value(), presentation_context_id)
+    }
+
+    /// Receive the next complete message from the acceptor,
+    /// with its data set fully read into memory if it has one.
+    ///
+    /// Fails with `Error::Released` if the acceptor released the association instead.
+    pub fn receive_message(&mut self) -> Result<Message> {
+        let (presentation_context_id, command) = self.receive_command()?;
+        let data = if pdata::has_data_set(&command) {
+            Some(pdata::receive_data(
+                || self.next_value(),
+                presentation_context_id,
+            )?)
+        } else {
+            None
+        };
+        Ok(Message {
+            presentation_context_id,
+            command,
+            data,
+        })
+    }
+
+    /// Gracefully terminate the association by exchanging release messages
+    /// and then shutting down the TCP connection.
+    ///
+    /// P-DATA-TF PDUs received while waiting for the release reply are discarded.
+    pub fn release(mut self) -> Result<()> {
+        let transition = self.fsm.handle(Event::ReleaseRequest)?;
+        perform(&mut self.socket, transition.effects)?;
+
+        loop {
+            let pdu = self.read()?;
+            let transition = self.fsm.receive(pdu)?;
+            let action = transition.action;
+            perform(&mut self.socket, transition.effects)?;
+            match action {
+                Action::AR3 => return Ok(()),
+                Action::AR6 => {}
+                Action::AR8 => {
+                    // release collision, which the requestor answers first
+                    let transition = self.fsm.handle(Event::ReleaseResponse)?;
+                    perform(&mut self.socket, transition.effects)?;
+                }
+                Action::AA3 => return Err(Error::Aborted),
+                _ => return Err(Error::UnexpectedPdu),
+            }
+        }
+    }
+
+    /// Abruptly terminate the association
+    /// by sending an abort message and shutting down the TCP connection.
+    pub fn abort(mut self) -> Result<()> {
+        self.abort_inner()
+    }
+
+    fn abort_inner(&mut self) -> Result<()> {
+        let transition = self.fsm.handle(Event::AbortRequest)?;
+        perform(&mut self.socket, transition.effects)?;
+        let _ = self.socket.shutdown(std::net::Shutdown::Both);
+        self.fsm.handle(Event::TransportClosed)?;
+        Ok(())
+    }
+
+    /// Check that the given presentation context was accepted.
+    fn check_presentation_context(&self, presentation_context_id: u8) -> Result<()> {
+        if self
+            .presentation_contexts
+            .iter()
+            .any(|pc| pc.id == presentation_context_id)
+        {
+            Ok(())
+        } else {
+            Err(Error::UnknownPresentationContext(presentation_context_id))
+        }
+    }
+
+    /// Retrieve the next presentation data value from the acceptor,
+    /// receiving a new P-DATA-TF PDU if none are pending.
+    fn next_value(&mut self) -> Result<PDataValue> {
+        loop {
+            if let Some(value) = self.pending.pop_front() {
+                self.check_presentation_context(value.presentation_context_id)?;
+                return Ok(value);
+            }
+            match self.receive()? {
+                Pdu::PData { data } => self.pending.extend(data),
+                Pdu::ReleaseRQ => return Err(Error::Released),
+                _ => return Err(Error::UnexpectedPdu),
+            }
+        }
+    }
+
+    /// Read a PDU from the socket,
+    /// treating the end of the stream as the transport connection being closed.
+    fn read(&mut self) -> Result<Pdu> {
+        match read_pdu(&mut self.socket, self.requestor_max_pdu_length) {
+            Err(Error::NoPduAvailable) => {
+                self.fsm.handle(Event::TransportClosed)?;
+                Err(Error::Aborted)
+            }
+            result => result,
+        }
+    }
+}
+
+impl Drop for ClientAssociation {
+    fn drop(&mut self) {
+        if self.fsm.state() != State::Sta1 {
+            let _ = self.abort_inner();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdu::writer::write_pdu;
+    use crate::pdu::{
+        AssociationRJResult, AssociationRJServiceUserReason, AssociationRJSource, PDataValueType,
+        PresentationContextResult,
+    };
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Spawn a peer which replies to the association request with the
+    /// PDU built by `reply`, then echoes P-DATA back until released.
+    fn spawn_peer<F>(reply: F) -> (std::net::SocketAddr, thread::JoinHandle<Result<Pdu>>)
+    where
+        F: FnOnce(&Pdu) -> Pdu + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || {
+            let (mut socket, _) = listener.accept()?;
+            let rq = read_pdu(&mut socket, DEFAULT_MAX_PDU)?;
+            write_pdu(&mut socket, &reply(&rq))?;
+            loop {
+                match read_pdu(&mut socket, DEFAULT_MAX_PDU) {
+                    Ok(pdu @ Pdu::PData { .. }) => write_pdu(&mut socket, &pdu)?,
+                    Ok(Pdu::ReleaseRQ) => write_pdu(&mut socket, &Pdu::ReleaseRP)?,
+                    Ok(_) | Err(Error::NoPduAvailable) => break,
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(rq)
+        });
+        (addr, handle)
+    }
+
+    #[test]
+    fn establish_exchange_and_release() -> Result<()> {
+        let (addr, peer) = spawn_peer(|rq| match rq {
+            Pdu::AssociationRQ {
+                presentation_contexts,
+                ..
+            } => Pdu::AssociationAC {
+                protocol_version: 1,
+                application_context_name: DICOM_APPLICATION_CONTEXT_NAME.to_string(),
+                presentation_contexts: presentation_contexts
+                    .iter()
+                    .map(|pc| PresentationContextResult {
+                        id: pc.id,
+                        reason: if pc.id == 1 {
+                            PresentationContextResultReason::Acceptance
+                        } else {
+                            PresentationContextResultReason::AbstractSyntaxNotSupported
+                        },
+                        transfer_syntax: pc.transfer_syntaxes[0].clone(),
+                    })
+                    .collect(),
+                user_variables: vec![UserVariableItem::MaxLength(32_768)],
+            },
+            _ => panic!("not an association request"),
+        });
+
+        let mut association = ClientAssociationOptions::new()
+            .calling_ae_title("TEST-SCU")
+            .called_ae_title("TEST-SCP")
+            .with_presentation_context("1.2.840.10008.1.1", vec!["1.2.840.10008.1.2"])
+            .with_presentation_context("1.2.3.4", vec!["1.2.840.10008.1.2.1"])
+            .establish(addr)?;
+
+        assert_eq!(
+            association.presentation_contexts(),
+            &[PresentationContext {
+                id: 1,
+                abstract_syntax: "1.2.840.10008.1.1".to_string(),
+                transfer_syntax: "1.2.840.10008.1.2".to_string(),
+            }]
+        );
+        assert_eq!(association.acceptor_max_pdu_length(), 32_768);
+
+        let data = vec![PDataValue {
+            presentation_context_id: 1,
+            value_type: PDataValueType::Command,
+            is_last: true,
+            data: vec![1, 2, 3, 4],
+        }];
+        association.send(data.clone())?;
+        assert_eq!(association.receive()?, Pdu::PData { data });
+
+        association.release()?;
+
+        match peer.join().unwrap()? {
+            Pdu::AssociationRQ {
+                calling_ae_title,
+                called_ae_title,
+                presentation_contexts,
+                user_variables,
+                ..
+            } => {
+                assert_eq!(calling_ae_title, "TEST-SCU");
+                assert_eq!(called_ae_title, "TEST-SCP");
+                assert_eq!(presentation_contexts.len(), 2);
+                assert_eq!(presentation_contexts[1].id, 3);
+                assert!(user_variables.contains(&UserVariableItem::MaxLength(DEFAULT_MAX_PDU)));
+            }
+            pdu => panic!("unexpected PDU {:?}", pdu),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rejected_association() {
+        let (addr, peer) = spawn_peer(|_| Pdu::AssociationRJ {
+            result: AssociationRJResult::Permanent,
+            source: AssociationRJSource::ServiceUser(
+                AssociationRJServiceUserReason::CalledAETitleNotRecognized,
+            ),
+        });
+
+        let result = ClientAssociationOptions::new()
+            .with_presentation_context("1.2.840.10008.1.1", vec!["1.2.840.10008.1.2"])
+            .establish(addr);
+
+        match result {
+            Err(Error::Rejected(
+                AssociationRJResult::Permanent,
+                AssociationRJSource::ServiceUser(
+                    AssociationRJServiceUserReason::CalledAETitleNotRecognized,
+                ),
+            )) => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+        peer.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn too_many_presentation_contexts() {
+        let options = (0..=MAXIMUM_PRESENTATION_CONTEXTS).fold(
+            ClientAssociationOptions::new(),
+            |options, i| {
+                options.with_presentation_context(
+                    format!("1.2.3.{}", i),
+                    vec!["1.2.840.10008.1.2".to_string()],
+                )
+            },
+        );
+
+        // rejected before connecting to the peer
+        match options.establish("127.0.0.1:1") {
+            Err(Error::TooManyPresentationContexts(129)) => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+    }
+
+    #[test]
+    fn read_timeout_while_establishing() {
+        // a peer which accepts the connection but never replies
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let peer = thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            read_pdu(&mut socket, DEFAULT_MAX_PDU).unwrap();
+            // keep the connection open until the requestor gives up
+            let _ = read_pdu(&mut socket, DEFAULT_MAX_PDU);
+        });
+
+        let result = ClientAssociationOptions::new()
+            .with_presentation_context("1.2.840.10008.1.1", vec!["1.2.840.10008.1.2"])
+            .connection_timeout(Duration::from_secs(5))
+            .read_timeout(Duration::from_millis(100))
+            .establish(addr);
+        match result {
+            Err(Error::Io(e))
+                if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {
+            }
+            r => panic!("unexpected outcome {:?}", r),
+        }
+        peer.join().unwrap();
+    }
+}
@@ -0,0 +1,99 @@
+//! High-level abstractions over DICOM associations.
+//!
+//! An association is established from one of the options builders in this
+//! module, which negotiates the presentation contexts with the peer over TCP
+//! and keeps track of the association state with the upper layer
+//! [state machine](../fsm/index.html).
+
+pub mod client;
+pub mod pdata;
+pub mod server;
+
+pub use self::client::{ClientAssociation, ClientAssociationOptions};
+pub use self::pdata::{Message, PDataReader};
+pub use self::server::{ServerAssociation, ServerAssociationOptions};
+
+use crate::error::Result;
+use crate::fsm::Effect;
+use crate::pdu::writer::write_pdu;
+use crate::pdu::UserVariableItem;
+use std::io::ErrorKind;
+use std::net::{Shutdown, TcpStream};
+
+/// The application context name of DICOM.
+pub const DICOM_APPLICATION_CONTEXT_NAME: &str = "1.2.840.10008.3.1.1.1";
+
+/// The maximum number of presentation contexts in an association request,
+/// as presentation context identifiers are odd numbers from 1 to 255.
+pub const MAXIMUM_PRESENTATION_CONTEXTS: usize = 128;
+
+/// The implementation class UID and version name of this library,
+/// sent to the peer when negotiating an association.
+pub use dicom_object::meta::{IMPLEMENTATION_CLASS_UID, IMPLEMENTATION_VERSION_NAME};
+
+/// A presentation context accepted by both ends of an association.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PresentationContext {
+    /// The presentation context identifier.
+    pub id: u8,
+    /// The abstract syntax UID.
+    pub abstract_syntax: String,
+    /// The transfer syntax UID agreed on.
+    pub transfer_syntax: String,
+}
+
+/// An SCP/SCU role selection negotiated for a SOP class,
+/// from the perspective of the association requestor.
+///
+/// Without a role selection,
+/// the requestor is the service class user and the acceptor the provider.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RoleSelection {
+    /// The SOP class UID.
+    pub sop_class_uid: String,
+    /// Whether the requestor may act as a service class user.
+    pub scu_role: bool,
+    /// Whether the requestor may act as a service class provider.
+    pub scp_role: bool,
+}
+
+/// Carry out the effects of a state machine transition on the given socket,
+/// returning the effects which are meant for the local user.
+pub(crate) fn perform(socket: &mut TcpStream, effects: Vec<Effect>) -> Result<Vec<Effect>> {
+    let mut indications = Vec::new();
+    for effect in effects {
+        match effect {
+            Effect::Send(pdu) => write_pdu(socket, &pdu)?,
+            Effect::Close => {
+                if let Err(e) = socket.shutdown(Shutdown::Both) {
+                    if e.kind() != ErrorKind::NotConnected {
+                        return Err(e.into());
+                    }
+                }
+            }
+            // the transport connection is managed by the caller
+            Effect::Connect | Effect::AcceptConnection => {}
+            effect => indications.push(effect),
+        }
+    }
+    Ok(indications)
+}
+
+/// Collect the SCP/SCU role selection sub-items of an association PDU.
+fn role_selections_of(user_variables: &[UserVariableItem]) -> Vec<RoleSelection> {
+    user_variables
+        .iter()
+        .filter_map(|item| match item {
+            UserVariableItem::RoleSelection {
+                sop_class_uid,
+                scu_role,
+                scp_role,
+            } => Some(RoleSelection {
+                sop_class_uid: sop_class_uid.clone(),
+                scu_role: *scu_role,
+                scp_role: *scp_role,
+            }),
+            _ => None,
+        })
+        .collect()
+}
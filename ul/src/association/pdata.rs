@@ -0,0 +1,368 @@
+//! Message-level P-DATA transfer.
+//!
+//! DIMSE messages are made of a command set and an optional data set,
+//! each of which is split into one or more presentation data values (PDVs)
+//! so that every P-DATA-TF PDU fits in the maximum length admitted by the peer.
+//! This module provides the fragmentation of outgoing messages
+//! and the reassembly of incoming ones,
+//! which the association types build upon in their
+//! `send_message`, `receive_command`, `receive_message`
+//! and `data_reader` methods.
+
+use crate::error::{Error, Result};
+use crate::pdu::reader::MAXIMUM_PDU_SIZE;
+use crate::pdu::{PDataValue, PDataValueType};
+use byteordered::byteorder::{ByteOrder, LittleEndian};
+use std::fmt;
+use std::io::{self, Read};
+
+/// The number of bytes taken by a presentation data value item
+/// besides its fragment: the item length, the presentation context ID
+/// and the message control header.
+const PDV_HEADER_LENGTH: u32 = 6;
+
+/// A complete message received on an association.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Message {
+    /// The presentation context ID of the message.
+    pub presentation_context_id: u8,
+    /// The encoded command set,
+    /// always in Implicit VR Little Endian.
+    pub command: Vec<u8>,
+    /// The encoded data set, if the message has one,
+    /// in the transfer syntax of the presentation context.
+    pub data: Option<Vec<u8>>,
+}
+
+/// Split the given command set or data set into presentation data values,
+/// each of which fits in a P-DATA-TF PDU of at most `max_pdu_length` bytes.
+///
+/// A maximum PDU length of 0 means that the peer imposes no limit.
+/// The last value returned is always marked as the last fragment,
+/// even if `data` is empty.
+pub fn fragment(
+    presentation_context_id: u8,
+    value_type: PDataValueType,
+    data: &[u8],
+    max_pdu_length: u32,
+) -> Vec<PDataValue> {
+    let max_pdu_length = if max_pdu_length == 0 {
+        MAXIMUM_PDU_SIZE
+    } else {
+        max_pdu_length
+    };
+    let fragment_length = (max_pdu_length.saturating_sub(PDV_HEADER_LENGTH) as usize).max(1);
+
+    let mut values: Vec<_> = data
+        .chunks(fragment_length)
+        .map(|chunk| PDataValue {
+            presentation_context_id,
+            value_type: value_type.clone(),
+            is_last: false,
+            data: chunk.to_vec(),
+        })
+        .collect();
+
+    match values.last_mut() {
+        Some(value) => value.is_last = true,
+        None => values.push(PDataValue {
+            presentation_context_id,
+            value_type,
+            is_last: true,
+            data: Vec::new(),
+        }),
+    }
+    values
+}
+
+/// Check whether the given command set announces a data set,
+/// according to its Command Data Set Type (0000,0800) attribute.
+///
+/// The command set is expected to be encoded in Implicit VR Little Endian.
+/// A command set without this attribute is considered to have no data set.
+pub fn has_data_set(command: &[u8]) -> bool {
+    let mut rest = command;
+    while rest.len() >= 8 {
+        let group = LittleEndian::read_u16(&rest[0..2]);
+        let element = LittleEndian::read_u16(&rest[2..4]);
+        let len = LittleEndian::read_u32(&rest[4..8]) as usize;
+        rest = &rest[8..];
+        if len > rest.len() {
+            return false;
+        }
+        if (group, element) == (0x0000, 0x0800) && len >= 2 {
+            return LittleEndian::read_u16(&rest[0..2]) != 0x0101;
+        }
+        rest = &rest[len..];
+    }
+    false
+}
+
+/// Send a message by fragments through the given P-DATA sender,
+/// one presentation data value per PDU.
+pub(crate) fn send_message<F>(
+    mut send: F,
+    max_pdu_length: u32,
+    presentation_context_id: u8,
+    command: &[u8],
+    data: Option<&[u8]>,
+) -> Result<()>
+where
+    F: FnMut(Vec<PDataValue>) -> Result<()>,
+{
+    for value in fragment(
+        presentation_context_id,
+        PDataValueType::Command,
+        command,
+        max_pdu_length,
+    ) {
+        send(vec![value])?;
+    }
+    if let Some(data) = data {
+        for value in fragment(
+            presentation_context_id,
+            PDataValueType::Data,
+            data,
+            max_pdu_length,
+        ) {
+            send(vec![value])?;
+        }
+    }
+    Ok(())
+}
+
+/// Reassemble the next command set from the given source of
+/// presentation data values, returning it with its presentation context ID.
+pub(crate) fn receive_command<F>(mut next: F) -> Result<(u8, Vec<u8>)>
+where
+    F: FnMut() -> Result<PDataValue>,
+{
+    let first = next()?;
+    if first.value_type != PDataValueType::Command {
+        return Err(Error::UnexpectedPDataValue);
+    }
+    let presentation_context_id = first.presentation_context_id;
+    let mut is_last = first.is_last;
+    let mut command = first.data;
+
+    while !is_last {
+        let value = next()?;
+        if value.value_type != PDataValueType::Command
+            || value.presentation_context_id != presentation_context_id
+        {
+            return Err(Error::UnexpectedPDataValue);
+        }
+        command.extend(value.data);
+        is_last = value.is_last;
+    }
+
+    Ok((presentation_context_id, command))
+}
+
+/// Reassemble the data set on the given presentation context
+/// from the given source of presentation data values.
+pub(crate) fn receive_data<F>(mut next: F, presentation_context_id: u8) -> Result<Vec<u8>>
+where
+    F: FnMut() -> Result<PDataValue>,
+{
+    let mut data = Vec::new();
+    loop {
+        let value = next()?;
+        if value.value_type != PDataValueType::Data
+            || value.presentation_context_id != presentation_context_id
+        {
+            return Err(Error::UnexpectedPDataValue);
+        }
+        data.extend(value.data);
+        if value.is_last {
+            return Ok(data);
+        }
+    }
+}
+
+/// A reader of a data set arriving in fragments on an association.
+///
+/// Each fragment is only requested from the peer when the previous one has
+/// been fully read, so that large data sets need not be held in memory as a
+/// whole. The reader reaches the end once the last fragment is consumed.
+/// Fragments of another presentation context or of a command set
+/// result in an error of kind `InvalidData`.
+pub struct PDataReader<'a> {
+    next: Box<dyn FnMut() -> Result<PDataValue> + 'a>,
+    presentation_context_id: u8,
+    buffer: Vec<u8>,
+    position: usize,
+    done: bool,
+}
+
+impl<'a> fmt::Debug for PDataReader<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PDataReader")
+            .field("presentation_context_id", &self.presentation_context_id)
+            .field("buffered", &(self.buffer.len() - self.position))
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<'a> PDataReader<'a> {
+    /// Create a data set reader over the given source of
+    /// presentation data values.
+    pub(crate) fn new<F>(next: F, presentation_context_id: u8) -> Self
+    where
+        F: FnMut() -> Result<PDataValue> + 'a,
+    {
+        PDataReader {
+            next: Box::new(next),
+            presentation_context_id,
+            buffer: Vec::new(),
+            position: 0,
+            done: false,
+        }
+    }
+
+    /// Retrieve the presentation context ID of the data set.
+    pub fn presentation_context_id(&self) -> u8 {
+        self.presentation_context_id
+    }
+}
+
+impl<'a> Read for PDataReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.buffer.len() {
+            if self.done {
+                return Ok(0);
+            }
+            let value = (self.next)().map_err(|e| match e {
+                Error::Io(e) => e,
+                e => io::Error::other(e),
+            })?;
+            if value.value_type != PDataValueType::Data
+                || value.presentation_context_id != self.presentation_context_id
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    Error::UnexpectedPDataValue,
+                ));
+            }
+            self.buffer = value.data;
+            self.position = 0;
+            self.done = value.is_last;
+        }
+
+        let n = buf.len().min(self.buffer.len() - self.position);
+        buf[..n].copy_from_slice(&self.buffer[self.position..self.position + n]);
+        self.position += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::association::{ClientAssociationOptions, ServerAssociationOptions};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// A command set with the given command data set type.
+    fn command(data_set_type: u16) -> Vec<u8> {
+        let mut bytes = vec![
+            // (0000,0100) CommandField
+            0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00,
+            // (0000,0800) CommandDataSetType
+            0x00, 0x00, 0x00, 0x08, 0x02, 0x00, 0x00, 0x00,
+        ];
+        bytes.extend(&data_set_type.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn fragment_within_max_pdu_length() {
+        let data: Vec<u8> = (0..100).collect();
+        let values = fragment(3, PDataValueType::Data, &data, 46);
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0].data, &data[..40]);
+        assert_eq!(values[2].data, &data[80..]);
+        assert!(values.iter().all(|v| v.presentation_context_id == 3));
+        assert_eq!(
+            values.iter().map(|v| v.is_last).collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+
+        let values = fragment(1, PDataValueType::Command, &[], 0);
+        assert_eq!(values.len(), 1);
+        assert!(values[0].is_last);
+    }
+
+    #[test]
+    fn detect_data_set_presence() {
+        assert!(!has_data_set(&command(0x0101)));
+        assert!(has_data_set(&command(0x0000)));
+        assert!(!has_data_set(&command(0x0101)[..10]));
+    }
+
+    #[test]
+    fn read_data_set_by_fragments() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut values = fragment(5, PDataValueType::Data, &data, 106).into_iter();
+        let mut reader = PDataReader::new(move || Ok(values.next().unwrap()), 5);
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, data);
+
+        let mut values = fragment(7, PDataValueType::Data, &data, 106).into_iter();
+        let mut reader = PDataReader::new(move || Ok(values.next().unwrap()), 5);
+        let e = reader.read_to_end(&mut Vec::new()).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn exchange_fragmented_messages() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let data: Vec<u8> = (0..100_000u32).map(|x| x as u8).collect();
+        let expected = data.clone();
+
+        let server = thread::spawn(move || -> Result<()> {
+            let (socket, _) = listener.accept()?;
+            let mut association = ServerAssociationOptions::new()
+                .with_abstract_syntax("1.2.840.10008.5.1.4.1.1.7")
+                .establish(socket)?;
+
+            let (pc_id, command) = association.receive_command()?;
+            assert_eq!(pc_id, 1);
+            assert!(has_data_set(&command));
+            let mut received = Vec::new();
+            association.data_reader(pc_id).read_to_end(&mut received)?;
+            assert_eq!(received, expected);
+
+            let message = association.receive_message()?;
+            assert_eq!(message.command, self::command(0x0101));
+            assert_eq!(message.data, None);
+
+            association.send_message(1, &self::command(0x0101), None)?;
+            match association.receive_message() {
+                Err(Error::Released) => Ok(()),
+                r => panic!("unexpected outcome {:?}", r),
+            }
+        });
+
+        let mut association = ClientAssociationOptions::new()
+            .with_presentation_context("1.2.840.10008.5.1.4.1.1.7", vec!["1.2.840.10008.1.2"])
+            .establish(addr)?;
+
+        match association.send_message(3, &command(0x0101), None) {
+            Err(Error::UnknownPresentationContext(3)) => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+        association.send_message(1, &command(0x0000), Some(&data))?;
+        association.send_message(1, &command(0x0101), None)?;
+
+        let message = association.receive_message()?;
+        assert_eq!(message.presentation_context_id, 1);
+        assert_eq!(message.data, None);
+
+        association.release()?;
+        server.join().unwrap()
+    }
+}
@@ -0,0 +1,894 @@
+//! Association acceptor module.
+//!
+//! The module provides an abstraction for a DICOM association
+//! in which this application entity listens to incoming association requests.
+//! See [`ServerAssociationOptions`](./struct.ServerAssociationOptions.html)
+//! for details and examples on how to accept an association.
+
+use super::pdata::{self, Message, PDataReader};
+use super::{
+    perform, role_selections_of, PresentationContext, RoleSelection,
+    DICOM_APPLICATION_CONTEXT_NAME, IMPLEMENTATION_CLASS_UID, IMPLEMENTATION_VERSION_NAME,
+};
+use crate::error::{Error, Result};
+use crate::fsm::{Action, Effect, Event, State, StateMachine, DEFAULT_ARTIM_TIMEOUT};
+use crate::pdu::reader::{read_pdu, DEFAULT_MAX_PDU, MAXIMUM_PDU_SIZE, MINIMUM_PDU_SIZE};
+use crate::pdu::{
+    AssociationRJResult, AssociationRJServiceProviderASCEReason, AssociationRJServiceUserReason,
+    AssociationRJSource, PDataValue, Pdu, PresentationContextProposed, PresentationContextResult,
+    PresentationContextResultReason, UserVariableItem,
+};
+use dicom_dictionary_std::uid::trim_uid;
+use dicom_encoding::transfer_syntax::TransferSyntaxIndex;
+use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::{Shutdown, TcpStream};
+use std::time::{Duration, Instant};
+
+/// A DICOM association builder for an acceptor DICOM node,
+/// often taking the role of a service class provider (SCP).
+///
+/// The options define the policies applied to incoming association requests:
+/// the called and calling AE titles admitted,
+/// the abstract syntaxes supported,
+/// and the transfer syntaxes accepted, in order of preference.
+/// Transfer syntaxes are only ever accepted
+/// if they can be read with the main [`TransferSyntaxRegistry`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::net::TcpListener;
+/// # use dicom_ul::association::server::ServerAssociationOptions;
+/// # fn run() -> dicom_ul::error::Result<()> {
+/// let listener = TcpListener::bind("0.0.0.0:104")?;
+/// let options = ServerAssociationOptions::new()
+///     .accept_called_ae_title("MY-SCP")
+///     .with_abstract_syntax("1.2.840.10008.1.1");
+/// for stream in listener.incoming() {
+///     let association = options.establish(stream?)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`TransferSyntaxRegistry`]: ../../../dicom_transfer_syntax_registry/struct.TransferSyntaxRegistry.html
+#[derive(Debug, Clone)]
+pub struct ServerAssociationOptions {
+    /// the called AE titles accepted, any title is accepted if empty
+    called_ae_titles: Vec<String>,
+    /// the calling AE titles accepted, any title is accepted if empty
+    calling_ae_titles: Vec<String>,
+    /// the application context name supported
+    application_context_name: String,
+    /// the abstract syntaxes supported
+    abstract_syntaxes: Vec<String>,
+    /// whether to accept any abstract syntax
+    promiscuous: bool,
+    /// the transfer syntaxes accepted in order of preference,
+    /// any supported transfer syntax is accepted if empty
+    transfer_syntaxes: Vec<String>,
+    /// whether to accept the SCP/SCU roles proposed by the requestor
+    accept_role_selection: bool,
+    /// the maximum PDU length accepted by this node
+    max_pdu_length: u32,
+    /// the implementation class UID announced to the peer
+    implementation_class_uid: String,
+    /// the implementation version name announced to the peer
+    implementation_version_name: String,
+    /// the time allowed for each read from an established association
+    read_timeout: Option<Duration>,
+}
+
+impl Default for ServerAssociationOptions {
+    fn default() -> Self {
+        ServerAssociationOptions {
+            called_ae_titles: Vec::new(),
+            calling_ae_titles: Vec::new(),
+            application_context_name: DICOM_APPLICATION_CONTEXT_NAME.to_string(),
+            abstract_syntaxes: Vec::new(),
+            promiscuous: false,
+            transfer_syntaxes: Vec::new(),
+            accept_role_selection: false,
+            max_pdu_length: DEFAULT_MAX_PDU,
+            implementation_class_uid: IMPLEMENTATION_CLASS_UID.to_string(),
+            implementation_version_name: IMPLEMENTATION_VERSION_NAME.to_string(),
+            read_timeout: None,
+        }
+    }
+}
+
+impl ServerAssociationOptions {
+    /// Create a new set of options for accepting associations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Admit the given called AE title.
+    ///
+    /// By default, or if no called AE title is admitted,
+    /// associations are accepted regardless of the called AE title.
+    pub fn accept_called_ae_title<T: Into<String>>(mut self, ae_title: T) -> Self {
+        self.called_ae_titles.push(ae_title.into());
+        self
+    }
+
+    /// Admit the given calling AE title.
+    ///
+    /// By default, or if no calling AE title is admitted,
+    /// associations are accepted regardless of the calling AE title.
+    pub fn accept_calling_ae_title<T: Into<String>>(mut self, ae_title: T) -> Self {
+        self.calling_ae_titles.push(ae_title.into());
+        self
+    }
+
+    /// Override the application context name,
+    /// which is the DICOM application context by default.
+    pub fn application_context_name<T: Into<String>>(
+        mut self,
+        application_context_name: T,
+    ) -> Self {
+        self.application_context_name = application_context_name.into();
+        self
+    }
+
+    /// Include this abstract syntax in the list of supported abstract syntaxes.
+    pub fn with_abstract_syntax<T: Into<String>>(mut self, abstract_syntax_uid: T) -> Self {
+        self.abstract_syntaxes.push(abstract_syntax_uid.into());
+        self
+    }
+
+    /// Define whether presentation contexts of any abstract syntax are accepted.
+    ///
+    /// The default is `false`.
+    pub fn promiscuous(mut self, promiscuous: bool) -> Self {
+        self.promiscuous = promiscuous;
+        self
+    }
+
+    /// Include this transfer syntax in the list of accepted transfer syntaxes,
+    /// with a lower preference than the ones previously included.
+    ///
+    /// By default, or if no transfer syntax is included,
+    /// the first transfer syntax proposed which is supported by this library
+    /// is accepted.
+    pub fn with_transfer_syntax<T: Into<String>>(mut self, transfer_syntax_uid: T) -> Self {
+        self.transfer_syntaxes.push(transfer_syntax_uid.into());
+        self
+    }
+
+    /// Define whether the SCP/SCU roles proposed by the requestor
+    /// for the SOP classes of accepted presentation contexts are accepted.
+    ///
+    /// By default, role selections are ignored,
+    /// so that the requestor may only act as a service class user.
+    pub fn accept_role_selection(mut self, accept: bool) -> Self {
+        self.accept_role_selection = accept;
+        self
+    }
+
+    /// Override the maximum PDU length accepted by this node.
+    pub fn max_pdu_length(mut self, value: u32) -> Self {
+        self.max_pdu_length = value;
+        self
+    }
+
+    /// Override the implementation class UID announced to the peer.
+    pub fn implementation_class_uid<T: Into<String>>(mut self, uid: T) -> Self {
+        self.implementation_class_uid = uid.into();
+        self
+    }
+
+    /// Override the implementation version name announced to the peer.
+    pub fn implementation_version_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.implementation_version_name = name.into();
+        self
+    }
+
+    /// Give up on reading from the requestor after the given time without
+    /// data, once the association is established.
+    ///
+    /// By default, reads wait indefinitely.
+    /// The association request is only awaited for as long as the ARTIM timer.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Negotiate an association with the node on the other end of the given
+    /// TCP stream, which has just been accepted.
+    ///
+    /// The association request is awaited for as long as the ARTIM timer.
+    /// Fails with `Error::Rejected` if the request did not comply with
+    /// the policies of these options, after replying with a rejection.
+    pub fn establish(&self, mut socket: TcpStream) -> Result<ServerAssociation> {
+        if !(MINIMUM_PDU_SIZE..=MAXIMUM_PDU_SIZE).contains(&self.max_pdu_length) {
+            return Err(Error::InvalidMaxPdu);
+        }
+
+        let mut fsm = StateMachine::new();
+        fsm.handle(Event::TransportConnectIndication)?;
+
+        let pdu = read_timed(&mut socket, &mut fsm, self.max_pdu_length)?;
+        let transition = fsm.receive(pdu)?;
+        let action = transition.action;
+        let indication = perform(&mut socket, transition.effects)?.into_iter().next();
+
+        let (calling_ae_title, called_ae_title, application_context_name, proposed, user_variables) =
+            match (action, indication) {
+                (
+                    Action::AE6,
+                    Some(Effect::AssociateIndication(Pdu::AssociationRQ {
+                        calling_ae_title,
+                        called_ae_title,
+                        application_context_name,
+                        presentation_contexts,
+                        user_variables,
+                        ..
+                    })),
+                ) => (
+                    calling_ae_title,
+                    called_ae_title,
+                    application_context_name,
+                    presentation_contexts,
+                    user_variables,
+                ),
+                (Action::AE6, _) => {
+                    // the protocol version was not supported
+                    await_close(&mut socket, &mut fsm)?;
+                    return Err(Error::Rejected(
+                        AssociationRJResult::Permanent,
+                        AssociationRJSource::ServiceProviderASCE(
+                            AssociationRJServiceProviderASCEReason::ProtocolVersionNotSupported,
+                        ),
+                    ));
+                }
+                (Action::AA2, _) => return Err(Error::Aborted),
+                _ => {
+                    await_close(&mut socket, &mut fsm)?;
+                    return Err(Error::UnexpectedPdu);
+                }
+            };
+
+        let rejection = if application_context_name != self.application_context_name {
+            Some(AssociationRJServiceUserReason::ApplicationContextNameNotSupported)
+        } else if !self.called_ae_titles.is_empty()
+            && !self.called_ae_titles.contains(&called_ae_title)
+        {
+            Some(AssociationRJServiceUserReason::CalledAETitleNotRecognized)
+        } else if !self.calling_ae_titles.is_empty()
+            && !self.calling_ae_titles.contains(&calling_ae_title)
+        {
+            Some(AssociationRJServiceUserReason::CallingAETitleNotRecognized)
+        } else {
+            None
+        };
+
+        if let Some(reason) = rejection {
+            let result = AssociationRJResult::Permanent;
+            let source = AssociationRJSource::ServiceUser(reason);
+            let transition = fsm.handle(Event::AssociateReject(Pdu::AssociationRJ {
+                result: result.clone(),
+                source: source.clone(),
+            }))?;
+            perform(&mut socket, transition.effects)?;
+            await_close(&mut socket, &mut fsm)?;
+            return Err(Error::Rejected(result, source));
+        }
+
+        let results: Vec<_> = proposed.iter().map(|pc| self.negotiate(pc)).collect();
+
+        let presentation_contexts: Vec<_> = results
+            .iter()
+            .filter(|result| result.reason == PresentationContextResultReason::Acceptance)
+            .filter_map(|result| {
+                proposed
+                    .iter()
+                    .find(|pc| pc.id == result.id)
+                    .map(|pc| PresentationContext {
+                        id: result.id,
+                        abstract_syntax: pc.abstract_syntax.clone(),
+                        transfer_syntax: result.transfer_syntax.clone(),
+                    })
+            })
+            .collect();
+
+        let requestor_max_pdu_length = user_variables
+            .iter()
+            .filter_map(|item| match item {
+                UserVariableItem::MaxLength(len) => Some(*len),
+                _ => None,
+            })
+            .next()
+            .unwrap_or(0);
+
+        let role_selections: Vec<_> = if self.accept_role_selection {
+            role_selections_of(&user_variables)
+                .into_iter()
+                .filter(|role| {
+                    presentation_contexts
+                        .iter()
+                        .any(|pc| trim_uid(&pc.abstract_syntax) == trim_uid(&role.sop_class_uid))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut user_variables = vec![
+            UserVariableItem::MaxLength(self.max_pdu_length),
+            UserVariableItem::ImplementationClassUID(self.implementation_class_uid.clone()),
+        ];
+        user_variables.extend(
+            role_selections
+                .iter()
+                .map(|role| UserVariableItem::RoleSelection {
+                    sop_class_uid: role.sop_class_uid.clone(),
+                    scu_role: role.scu_role,
+                    scp_role: role.scp_role,
+                }),
+        );
+        user_variables.push(UserVariableItem::ImplementationVersionName(
+            self.implementation_version_name.clone(),
+        ));
+
+        let ac = Pdu::AssociationAC {
+            protocol_version: 1,
+            application_context_name,
+            presentation_contexts: results,
+            user_variables,
+        };
+        let transition = fsm.handle(Event::AssociateAccept(ac))?;
+        perform(&mut socket, transition.effects)?;
+        socket.set_read_timeout(self.read_timeout)?;
+
+        Ok(ServerAssociation {
+            presentation_contexts,
+            role_selections,
+            client_ae_title: calling_ae_title,
+            requestor_max_pdu_length,
+            acceptor_max_pdu_length: self.max_pdu_length,
+            socket,
+            fsm,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Determine the outcome of a proposed presentation context.
+    fn negotiate(&self, pc: &PresentationContextProposed) -> PresentationContextResult {
+        let is_supported = |uid: &str| {
+            TransferSyntaxRegistry
+                .get(uid)
+                .map(|ts| !ts.unsupported())
+                .unwrap_or(false)
+        };
+
+        let (reason, transfer_syntax) =
+            if !self.promiscuous && !self.abstract_syntaxes.contains(&pc.abstract_syntax) {
+                (
+                    PresentationContextResultReason::AbstractSyntaxNotSupported,
+                    None,
+                )
+            } else {
+                let transfer_syntax = if self.transfer_syntaxes.is_empty() {
+                    pc.transfer_syntaxes.iter().find(|ts| is_supported(ts))
+                } else {
+                    self.transfer_syntaxes
+                        .iter()
+                        .find(|ts| pc.transfer_syntaxes.contains(ts) && is_supported(ts))
+                };
+                match transfer_syntax {
+                    Some(ts) => (PresentationContextResultReason::Acceptance, Some(ts)),
+                    None => (
+                        PresentationContextResultReason::TransferSyntaxesNotSupported,
+                        None,
+                    ),
+                }
+            };
+
+        PresentationContextResult {
+            id: pc.id,
+            reason,
+            // the transfer syntax is not significant if the context is rejected,
+            // but the field must still be present
+            transfer_syntax: transfer_syntax
+                .or_else(|| pc.transfer_syntaxes.first())
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// A DICOM upper level association from the perspective
+/// of an accepting application entity.
+///
+/// The most common operations of an established association are
+/// [`send`](#method.send)
+/// and [`receive`](#method.receive).
+/// A release request from the requestor is replied to automatically
+/// by [`receive`](#method.receive).
+///
+/// When the value falls out of scope,
+/// the program will abort the association if it is still established.
+#[derive(Debug)]
+pub struct ServerAssociation {
+    /// The accepted presentation contexts
+    presentation_contexts: Vec<PresentationContext>,
+    /// The SCP/SCU role selections accepted
+    role_selections: Vec<RoleSelection>,
+    /// The calling AE title of the requestor
+    client_ae_title: String,
+    /// The maximum PDU length that the remote application entity accepts,
+    /// where 0 means unlimited
+    requestor_max_pdu_length: u32,
+    /// The maximum PDU length that this application entity is expecting to receive
+    acceptor_max_pdu_length: u32,
+    /// The TCP stream to the other DICOM node
+    socket: TcpStream,
+    /// The upper layer state machine
+    fsm: StateMachine,
+    /// The presentation data values received but not consumed yet
+    pending: VecDeque<PDataValue>,
+}
+
+impl ServerAssociation {
+    /// Retrieve the list of negotiated presentation contexts.
+    pub fn presentation_contexts(&self) -> &[PresentationContext] {
+        &self.presentation_contexts
+    }
+
+    /// Retrieve the list of SCP/SCU role selections accepted,
+    /// from the perspective of the requestor.
+    pub fn role_selections(&self) -> &[RoleSelection] {
+        &self.role_selections
+    }
+
+    /// Retrieve the calling AE title of the association requestor.
+    pub fn client_ae_title(&self) -> &str {
+        &self.client_ae_title
+    }
+
+    /// Retrieve the maximum PDU length
+    /// admitted by the association requestor,
+    /// where 0 means that there is no limit.
+    pub fn requestor_max_pdu_length(&self) -> u32 {
+        self.requestor_max_pdu_length
+    }
+
+    /// Retrieve the maximum PDU length
+    /// admitted by this application entity.
+    pub fn acceptor_max_pdu_length(&self) -> u32 {
+        self.acceptor_max_pdu_length
+    }
+
+    /// Send a P-DATA-TF PDU with the given values to the requestor.
+    pub fn send(&mut self, data: Vec<PDataValue>) -> Result<()> {
+        let transition = self.fsm.handle(Event::PDataRequest(Pdu::PData { data }))?;
+        perform(&mut self.socket, transition.effects)?;
+        Ok(())
+    }
+
+    /// Read the next PDU from the requestor.
+    ///
+    /// P-DATA-TF PDUs are the ones expected while the association is established.
+    /// A release request from the requestor is replied to immediately
+    /// and handed back to the caller, after which the association is over.
+    /// Fails with `Error::Aborted` if the requestor aborted the association
+    /// or closed the connection,
+    /// and with `Error::UnexpectedPdu` if the requestor sent a PDU
+    /// not applicable at this point, in which case the association is aborted.
+    /// The association is also aborted if the PDU could not be read.
+    pub fn receive(&mut self) -> Result<Pdu> {
+        let pdu = match read_pdu(&mut self.socket, self.acceptor_max_pdu_length) {
+            Ok(pdu) => pdu,
+            Err(Error::NoPduAvailable) => {
+                self.fsm.handle(Event::TransportClosed)?;
+                return Err(Error::Aborted);
+            }
+            Err(e) => {
+                // the rest of the stream cannot be trusted,
+                // so the connection is closed right after the abort
+                let transition = self.fsm.handle(Event::InvalidPdu)?;
+                let _ = perform(&mut self.socket, transition.effects);
+                self.close();
+                return Err(e);
+            }
+        };
+        let transition = self.fsm.receive(pdu)?;
+        let action = transition.action;
+        let indication = perform(&mut self.socket, transition.effects)?
+            .into_iter()
+            .next();
+        match (action, indication) {
+            (Action::DT2, Some(Effect::PDataIndication(pdu))) => Ok(pdu),
+            (Action::AR2, _) => {
+                let transition = self.fsm.handle(Event::ReleaseResponse)?;
+                perform(&mut self.socket, transition.effects)?;
+                await_close(&mut self.socket, &mut self.fsm)?;
+                Ok(Pdu::ReleaseRQ)
+            }
+            (Action::AA3, _) => Err(Error::Aborted),
+            _ => {
+                await_close(&mut self.socket, &mut self.fsm)?;
+                Err(Error::UnexpectedPdu)
+            }
+        }
+    }
+
+    /// Send a message made of the given command set and optional data set
+    /// on the given presentation context,
+    /// split into fragments which fit in the maximum PDU length of the requestor.
+    ///
+    /// Fails with `Error::UnknownPresentationContext`
+    /// if the presentation context was not accepted.
+    pub fn send_message(
+        &mut self,
+        presentation_context_id: u8,
+        command: &[u8],
+        data: Option<&[u8]>,
+    ) -> Result<()> {
+        self.check_presentation_context(presentation_context_id)?;
+        let max_pdu_length = self.requestor_max_pdu_length;
+        pdata::send_message(
+            |data| self.send(data),
+            max_pdu_length,
+            presentation_context_id,
+            command,
+            data,
+        )
+    }
+
+    /// Receive the next command set from the requestor,
+    /// returning it along with its presentation context ID.
+    ///
+    /// If the command set announces a data set,
+    /// it should be read next with [`data_reader`](#method.data_reader).
+    pub fn receive_command(&mut self) -> Result<(u8, Vec<u8>)> {
+        pdata::receive_command(|| self.next_value())
+    }
+
+    /// Obtain a reader of the data set which follows the last command set
+    /// received on the given presentation context.
+    pub fn data_reader(&mut self, presentation_context_id: u8) -> PDataReader<'_> {
+        PDataReader::new(move || self.next_value(), presentation_context_id)
+    }
+
+    /// Receive the next complete message from the requestor,
+    /// with its data set fully read into memory if it has one.
+    ///
+    /// Fails with `Error::Released` if the requestor released the association instead.
+    pub fn receive_message(&mut self) -> Result<Message> {
+        let (presentation_context_id, command) = self.receive_command()?;
+        let data = if pdata::has_data_set(&command) {
+            Some(pdata::receive_data(
+                || self.next_value(),
+                presentation_context_id,
+            )?)
+        } else {
+            None
+        };
+        Ok(Message {
+            presentation_context_id,
+            command,
+            data,
+        })
+    }
+
+    /// Check without blocking whether the requestor has sent anything
+    /// which was not received yet,
+    /// such as a C-CANCEL request during a C-FIND operation.
+    pub fn poll(&mut self) -> Result<bool> {
+        if !self.pending.is_empty() {
+            return Ok(true);
+        }
+        self.socket.set_nonblocking(true)?;
+        let result = self.socket.peek(&mut [0; 1]);
+        self.socket.set_nonblocking(false)?;
+        match result {
+            // a closed connection is also reported on the next receive
+            Ok(_) => Ok(true),
+            Err(ref e) if e.kind() == ErrorKind::WouldBlock => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Abruptly terminate the association
+    /// by sending an abort message and shutting down the TCP connection.
+    pub fn abort(mut self) -> Result<()> {
+        self.abort_inner()
+    }
+
+    fn abort_inner(&mut self) -> Result<()> {
+        let transition = self.fsm.handle(Event::AbortRequest)?;
+        perform(&mut self.socket, transition.effects)?;
+        await_close(&mut self.socket, &mut self.fsm)
+    }
+
+    /// Shut down the TCP connection without waiting for the requestor.
+    fn close(&mut self) {
+        let _ = self.socket.shutdown(Shutdown::Both);
+        let _ = self.fsm.handle(Event::TransportClosed);
+    }
+
+    /// Check that the given presentation context was accepted.
+    fn check_presentation_context(&self, presentation_context_id: u8) -> Result<()> {
+        if self
+            .presentation_contexts
+            .iter()
+            .any(|pc| pc.id == presentation_context_id)
+        {
+            Ok(())
+        } else {
+            Err(Error::UnknownPresentationContext(presentation_context_id))
+        }
+    }
+
+    /// Retrieve the next presentation data value from the requestor,
+    /// receiving a new P-DATA-TF PDU if none are pending.
+    fn next_value(&mut self) -> Result<PDataValue> {
+        loop {
+            if let Some(value) = self.pending.pop_front() {
+                self.check_presentation_context(value.presentation_context_id)?;
+                return Ok(value);
+            }
+            match self.receive()? {
+                Pdu::PData { data } => self.pending.extend(data),
+                Pdu::ReleaseRQ => return Err(Error::Released),
+                _ => return Err(Error::UnexpectedPdu),
+            }
+        }
+    }
+}
+
+impl Drop for ServerAssociation {
+    fn drop(&mut self) {
+        if self.fsm.state() != State::Sta1 && self.fsm.state() != State::Sta13 {
+            // send the abort without waiting for the requestor to close
+            if let Ok(transition) = self.fsm.handle(Event::AbortRequest) {
+                let _ = perform(&mut self.socket, transition.effects);
+            }
+            self.close();
+        }
+    }
+}
+
+/// Read a PDU from the socket while the ARTIM timer is running,
+/// feeding the state machine with the expiration of the timer
+/// or the closing of the connection if either happens first.
+fn read_timed(socket: &mut TcpStream, fsm: &mut StateMachine, max_pdu_length: u32) -> Result<Pdu> {
+    let timeout = fsm
+        .artim_deadline()
+        .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+        .unwrap_or(DEFAULT_ARTIM_TIMEOUT);
+    let read_timeout = socket.read_timeout()?;
+    socket.set_read_timeout(Some(timeout.max(Duration::from_millis(1))))?;
+    let result = read_pdu(socket, max_pdu_length);
+    socket.set_read_timeout(read_timeout)?;
+
+    match result {
+        Err(Error::NoPduAvailable) => {
+            fsm.handle(Event::TransportClosed)?;
+            Err(Error::Aborted)
+        }
+        Err(Error::Io(ref e))
+            if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut =>
+        {
+            let transition = fsm.handle(Event::ArtimExpired)?;
+            perform(socket, transition.effects)?;
+            Err(Error::Aborted)
+        }
+        result => result,
+    }
+}
+
+/// Wait for the peer to close the transport connection
+/// after a rejection, release or abort,
+/// closing it if the ARTIM timer expires first.
+fn await_close(socket: &mut TcpStream, fsm: &mut StateMachine) -> Result<()> {
+    while fsm.state() == State::Sta13 {
+        match read_timed(socket, fsm, MAXIMUM_PDU_SIZE) {
+            Ok(pdu) => {
+                let transition = fsm.receive(pdu)?;
+                perform(socket, transition.effects)?;
+            }
+            Err(Error::Aborted) => {}
+            Err(e) => {
+                let _ = socket.shutdown(Shutdown::Both);
+                return Err(e);
+            }
+        }
+    }
+    // the peer may have closed its end only
+    let _ = socket.shutdown(Shutdown::Both);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::association::client::ClientAssociationOptions;
+    use crate::pdu::writer::write_pdu;
+    use crate::pdu::{AbortRQSource, PDataValueType};
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::thread;
+
+    const VERIFICATION: &str = "1.2.840.10008.1.1";
+    const IMPLICIT_VR_LE: &str = "1.2.840.10008.1.2";
+    const EXPLICIT_VR_LE: &str = "1.2.840.10008.1.2.1";
+
+    /// Accept a single association with the given options in another thread.
+    fn spawn_server(
+        options: ServerAssociationOptions,
+    ) -> (
+        std::net::SocketAddr,
+        thread::JoinHandle<Result<ServerAssociation>>,
+    ) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || {
+            let (socket, _) = listener.accept()?;
+            options.establish(socket)
+        });
+        (addr, handle)
+    }
+
+    #[test]
+    fn accept_with_transfer_syntax_preference() -> Result<()> {
+        let (addr, server) = spawn_server(
+            ServerAssociationOptions::new()
+                .accept_called_ae_title("TEST-SCP")
+                .accept_calling_ae_title("TEST-SCU")
+                .with_abstract_syntax(VERIFICATION)
+                .with_transfer_syntax(EXPLICIT_VR_LE)
+                .with_transfer_syntax(IMPLICIT_VR_LE),
+        );
+
+        let mut client = ClientAssociationOptions::new()
+            .calling_ae_title("TEST-SCU")
+            .called_ae_title("TEST-SCP")
+            .with_presentation_context(VERIFICATION, vec![IMPLICIT_VR_LE, EXPLICIT_VR_LE])
+            .with_presentation_context("1.2.3.4", vec![IMPLICIT_VR_LE])
+            // a transfer syntax unknown to the registry
+            .with_presentation_context(VERIFICATION, vec!["1.2.3.4.5.6"])
+            .establish(addr)?;
+        let mut server = server.join().unwrap()?;
+
+        let expected = [PresentationContext {
+            id: 1,
+            abstract_syntax: VERIFICATION.to_string(),
+            transfer_syntax: EXPLICIT_VR_LE.to_string(),
+        }];
+        assert_eq!(client.presentation_contexts(), &expected);
+        assert_eq!(server.presentation_contexts(), &expected);
+        assert_eq!(server.client_ae_title(), "TEST-SCU");
+        assert_eq!(server.requestor_max_pdu_length(), DEFAULT_MAX_PDU);
+
+        let data = vec![PDataValue {
+            presentation_context_id: 1,
+            value_type: PDataValueType::Command,
+            is_last: true,
+            data: vec![0x10, 0x20],
+        }];
+        client.send(data.clone())?;
+        assert_eq!(server.receive()?, Pdu::PData { data });
+
+        let release = thread::spawn(move || client.release());
+        assert_eq!(server.receive()?, Pdu::ReleaseRQ);
+        release.join().unwrap()?;
+        Ok(())
+    }
+
+    #[test]
+    fn reject_unknown_called_ae_title() {
+        let (addr, server) = spawn_server(
+            ServerAssociationOptions::new()
+                .accept_called_ae_title("TEST-SCP")
+                .with_abstract_syntax(VERIFICATION),
+        );
+
+        let client = ClientAssociationOptions::new()
+            .called_ae_title("OTHER-SCP")
+            .with_presentation_context(VERIFICATION, vec![IMPLICIT_VR_LE])
+            .establish(addr);
+
+        let expected_source = AssociationRJSource::ServiceUser(
+            AssociationRJServiceUserReason::CalledAETitleNotRecognized,
+        );
+        match client {
+            Err(Error::Rejected(AssociationRJResult::Permanent, ref source))
+                if source == &expected_source => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+        match server.join().unwrap() {
+            Err(Error::Rejected(AssociationRJResult::Permanent, ref source))
+                if source == &expected_source => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+    }
+
+    #[test]
+    fn reject_unknown_calling_ae_title() {
+        let (addr, server) = spawn_server(
+            ServerAssociationOptions::new()
+                .accept_calling_ae_title("TEST-SCU")
+                .promiscuous(true),
+        );
+
+        let client = ClientAssociationOptions::new()
+            .calling_ae_title("INTRUDER")
+            .with_presentation_context(VERIFICATION, vec![IMPLICIT_VR_LE])
+            .establish(addr);
+
+        match client {
+            Err(Error::Rejected(
+                _,
+                AssociationRJSource::ServiceUser(
+                    AssociationRJServiceUserReason::CallingAETitleNotRecognized,
+                ),
+            )) => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+        assert!(server.join().unwrap().is_err());
+    }
+
+    #[test]
+    fn abort_on_malformed_pdu() -> Result<()> {
+        let (addr, server) =
+            spawn_server(ServerAssociationOptions::new().with_abstract_syntax(VERIFICATION));
+
+        let mut socket = TcpStream::connect(addr)?;
+        write_pdu(
+            &mut socket,
+            &Pdu::AssociationRQ {
+                protocol_version: 1,
+                calling_ae_title: "TEST-SCU".to_string(),
+                called_ae_title: "ANY-SCP".to_string(),
+                application_context_name: DICOM_APPLICATION_CONTEXT_NAME.to_string(),
+                presentation_contexts: vec![PresentationContextProposed {
+                    id: 1,
+                    abstract_syntax: VERIFICATION.to_string(),
+                    transfer_syntaxes: vec![IMPLICIT_VR_LE.to_string()],
+                }],
+                user_variables: vec![UserVariableItem::MaxLength(DEFAULT_MAX_PDU)],
+            },
+        )?;
+        let mut server = server.join().unwrap()?;
+        match read_pdu(&mut socket, DEFAULT_MAX_PDU)? {
+            Pdu::AssociationAC { .. } => {}
+            pdu => panic!("unexpected PDU {:?}", pdu),
+        }
+
+        // a P-DATA-TF PDU longer than the server admits
+        socket.write_all(&[0x04, 0x00, 0xFF, 0xFF, 0xFF, 0xF0])?;
+        assert!(server.receive().is_err());
+        match read_pdu(&mut socket, DEFAULT_MAX_PDU)? {
+            Pdu::AbortRQ {
+                source: AbortRQSource::ServiceProvider(_),
+            } => {}
+            pdu => panic!("unexpected PDU {:?}", pdu),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn drop_without_waiting() -> Result<()> {
+        let (addr, server) =
+            spawn_server(ServerAssociationOptions::new().with_abstract_syntax(VERIFICATION));
+        let mut client = ClientAssociationOptions::new()
+            .with_presentation_context(VERIFICATION, vec![IMPLICIT_VR_LE])
+            .establish(addr)?;
+        let server = server.join().unwrap()?;
+
+        // the requestor does not close its end until it reads the abort
+        let start = Instant::now();
+        drop(server);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        match client.receive() {
+            Err(Error::Aborted) => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,1337 @@
+//! DIMSE command sets.
+//!
+//! Every DIMSE message starts with a command set: a data set made only of
+//! command elements (group 0000), always encoded in Implicit VR Little Endian
+//! and led by the Command Group Length (0000,0000) (PS3.7 Section 6.3).
+//! This module models the DIMSE-C and DIMSE-N commands as typed structures,
+//! all of which are gathered in the [`Command`](enum.Command.html) enum,
+//! and converts them from and into the encoded command set
+//! with the help of the generic [`CommandSet`](struct.CommandSet.html).
+//!
+//! Command sets are transferred over an association with the message-level
+//! methods of the association types, such as `send_message` and
+//! `receive_message`.
+
+use crate::error::{Error, Result};
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
+use dicom_core::header::{DataElementHeader, Header, Length};
+use dicom_core::{PrimitiveValue, Tag, VR};
+use dicom_dictionary_std::{tags, StandardDataDictionary};
+use dicom_encoding::decode::basic::LittleEndianBasicDecoder;
+use dicom_encoding::decode::{BasicDecode, Decode};
+use dicom_encoding::encode::Encode;
+use dicom_encoding::transfer_syntax::implicit_le::{
+    ImplicitVRLittleEndianDecoder, ImplicitVRLittleEndianEncoder,
+};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{Read, Write};
+
+/// The value of Command Data Set Type (0000,0800)
+/// when the message has no data set.
+const DATA_SET_ABSENT: u16 = 0x0101;
+
+/// The value of Command Data Set Type (0000,0800)
+/// written when the message has a data set.
+/// Any value other than `0x0101` is accepted when reading.
+const DATA_SET_PRESENT: u16 = 0x0000;
+
+/// The kind of a DIMSE message, as in Command Field (0000,0100).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum CommandField {
+    CStoreRq,
+    CStoreRsp,
+    CGetRq,
+    CGetRsp,
+    CFindRq,
+    CFindRsp,
+    CMoveRq,
+    CMoveRsp,
+    CEchoRq,
+    CEchoRsp,
+    CCancelRq,
+    NEventReportRq,
+    NEventReportRsp,
+    NGetRq,
+    NGetRsp,
+    NSetRq,
+    NSetRsp,
+    NActionRq,
+    NActionRsp,
+    NCreateRq,
+    NCreateRsp,
+    NDeleteRq,
+    NDeleteRsp,
+}
+
+impl CommandField {
+    /// Obtain the command field of the given code, if it is known.
+    pub fn from_code(code: u16) -> Option<Self> {
+        use self::CommandField::*;
+        match code {
+            0x0001 => Some(CStoreRq),
+            0x8001 => Some(CStoreRsp),
+            0x0010 => Some(CGetRq),
+            0x8010 => Some(CGetRsp),
+            0x0020 => Some(CFindRq),
+            0x8020 => Some(CFindRsp),
+            0x0021 => Some(CMoveRq),
+            0x8021 => Some(CMoveRsp),
+            0x0030 => Some(CEchoRq),
+            0x8030 => Some(CEchoRsp),
+            0x0FFF => Some(CCancelRq),
+            0x0100 => Some(NEventReportRq),
+            0x8100 => Some(NEventReportRsp),
+            0x0110 => Some(NGetRq),
+            0x8110 => Some(NGetRsp),
+            0x0120 => Some(NSetRq),
+            0x8120 => Some(NSetRsp),
+            0x0130 => Some(NActionRq),
+            0x8130 => Some(NActionRsp),
+            0x0140 => Some(NCreateRq),
+            0x8140 => Some(NCreateRsp),
+            0x0150 => Some(NDeleteRq),
+            0x8150 => Some(NDeleteRsp),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the code of this command field.
+    pub fn code(self) -> u16 {
+        use self::CommandField::*;
+        match self {
+            CStoreRq => 0x0001,
+            CStoreRsp => 0x8001,
+            CGetRq => 0x0010,
+            CGetRsp => 0x8010,
+            CFindRq => 0x0020,
+            CFindRsp => 0x8020,
+            CMoveRq => 0x0021,
+            CMoveRsp => 0x8021,
+            CEchoRq => 0x0030,
+            CEchoRsp => 0x8030,
+            CCancelRq => 0x0FFF,
+            NEventReportRq => 0x0100,
+            NEventReportRsp => 0x8100,
+            NGetRq => 0x0110,
+            NGetRsp => 0x8110,
+            NSetRq => 0x0120,
+            NSetRsp => 0x8120,
+            NActionRq => 0x0130,
+            NActionRsp => 0x8130,
+            NCreateRq => 0x0140,
+            NCreateRsp => 0x8140,
+            NDeleteRq => 0x0150,
+            NDeleteRsp => 0x8150,
+        }
+    }
+
+    /// Check whether this command field is of a response message.
+    pub fn is_response(self) -> bool {
+        self.code() & 0x8000 != 0
+    }
+}
+
+/// The priority of a DIMSE-C request, as in Priority (0000,0700).
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Priority {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl Priority {
+    /// Obtain the priority of the given code, if it is valid.
+    pub fn from_code(code: u16) -> Option<Self> {
+        match code {
+            0x0002 => Some(Priority::Low),
+            0x0000 => Some(Priority::Medium),
+            0x0001 => Some(Priority::High),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the code of this priority.
+    pub fn code(self) -> u16 {
+        match self {
+            Priority::Low => 0x0002,
+            Priority::Medium => 0x0000,
+            Priority::High => 0x0001,
+        }
+    }
+}
+
+/// The status of a DIMSE response, as in Status (0000,0900).
+///
+/// The meaning of each code depends on the service,
+/// but the general categories of PS3.7 Annex C are recognized here.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Status(pub u16);
+
+impl Status {
+    /// The operation was successful.
+    pub const SUCCESS: Status = Status(0x0000);
+    /// The operation was terminated after a C-CANCEL request.
+    pub const CANCEL: Status = Status(0xFE00);
+    /// The operation is ongoing and more responses will follow.
+    pub const PENDING: Status = Status(0xFF00);
+    /// The SOP class is not supported by the peer.
+    pub const SOP_CLASS_NOT_SUPPORTED: Status = Status(0x0122);
+    /// The peer could not process the request.
+    pub const PROCESSING_FAILURE: Status = Status(0x0110);
+
+    /// Check whether the status is of success.
+    pub fn is_success(self) -> bool {
+        self.0 == 0x0000
+    }
+
+    /// Check whether the status is of a pending operation.
+    pub fn is_pending(self) -> bool {
+        self.0 == 0xFF00 || self.0 == 0xFF01
+    }
+
+    /// Check whether the status is of a cancelled operation.
+    pub fn is_cancel(self) -> bool {
+        self.0 == 0xFE00
+    }
+
+    /// Check whether the status is a warning.
+    pub fn is_warning(self) -> bool {
+        self.0 == 0x0001 || self.0 & 0xF000 == 0xB000 || self.0 == 0x0107 || self.0 == 0x0116
+    }
+
+    /// Check whether the status is a failure,
+    /// which is any status not falling into the other categories.
+    pub fn is_failure(self) -> bool {
+        !(self.is_success() || self.is_pending() || self.is_cancel() || self.is_warning())
+    }
+
+    /// Obtain the name of the category of the status.
+    pub fn category(self) -> &'static str {
+        if self.is_success() {
+            "success"
+        } else if self.is_warning() {
+            "warning"
+        } else if self.is_pending() {
+            "pending"
+        } else if self.is_cancel() {
+            "cancel"
+        } else {
+            "failure"
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#06X} ({})", self.0, self.category())
+    }
+}
+
+impl Default for Status {
+    fn default() -> Self {
+        Status::SUCCESS
+    }
+}
+
+/// The number of sub-operations reported in C-GET and C-MOVE responses.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SubOperations {
+    /// Number of Remaining Sub-operations (0000,1020)
+    pub remaining: Option<u16>,
+    /// Number of Completed Sub-operations (0000,1021)
+    pub completed: Option<u16>,
+    /// Number of Failed Sub-operations (0000,1022)
+    pub failed: Option<u16>,
+    /// Number of Warning Sub-operations (0000,1023)
+    pub warning: Option<u16>,
+}
+
+/// A C-ECHO request.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CEchoRq {
+    pub message_id: u16,
+    pub affected_sop_class_uid: String,
+}
+
+/// A C-ECHO response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CEchoRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub status: Status,
+}
+
+/// A C-STORE request, always followed by the data set to store.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CStoreRq {
+    pub message_id: u16,
+    pub affected_sop_class_uid: String,
+    pub affected_sop_instance_uid: String,
+    pub priority: Priority,
+    /// The AE title of the C-MOVE requester,
+    /// when storing as a sub-operation of a C-MOVE.
+    pub move_originator_ae_title: Option<String>,
+    /// The message ID of the C-MOVE request,
+    /// when storing as a sub-operation of a C-MOVE.
+    pub move_originator_message_id: Option<u16>,
+}
+
+/// A C-STORE response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CStoreRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub affected_sop_instance_uid: Option<String>,
+    pub status: Status,
+}
+
+/// A C-FIND request, always followed by the identifier data set.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CFindRq {
+    pub message_id: u16,
+    pub affected_sop_class_uid: String,
+    pub priority: Priority,
+}
+
+/// A C-FIND response,
+/// followed by a matching identifier while the status is pending.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CFindRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub status: Status,
+    pub has_data_set: bool,
+}
+
+/// A C-GET request, always followed by the identifier data set.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CGetRq {
+    pub message_id: u16,
+    pub affected_sop_class_uid: String,
+    pub priority: Priority,
+}
+
+/// A C-GET response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CGetRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub status: Status,
+    pub sub_operations: SubOperations,
+    /// Whether the response is followed by an identifier
+    /// with the list of failed SOP instance UIDs.
+    pub has_data_set: bool,
+}
+
+/// A C-MOVE request, always followed by the identifier data set.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CMoveRq {
+    pub message_id: u16,
+    pub affected_sop_class_uid: String,
+    pub priority: Priority,
+    /// The AE title of the destination of the C-STORE sub-operations.
+    pub move_destination: String,
+}
+
+/// A C-MOVE response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CMoveRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub status: Status,
+    pub sub_operations: SubOperations,
+    /// Whether the response is followed by an identifier
+    /// with the list of failed SOP instance UIDs.
+    pub has_data_set: bool,
+}
+
+/// A C-CANCEL request of an ongoing C-FIND, C-GET or C-MOVE operation.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CCancelRq {
+    pub message_id_being_responded_to: u16,
+}
+
+/// An N-EVENT-REPORT request.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NEventReportRq {
+    pub message_id: u16,
+    pub affected_sop_class_uid: String,
+    pub affected_sop_instance_uid: String,
+    pub event_type_id: u16,
+    pub has_data_set: bool,
+}
+
+/// An N-EVENT-REPORT response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NEventReportRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub affected_sop_instance_uid: Option<String>,
+    pub event_type_id: Option<u16>,
+    pub status: Status,
+    pub has_data_set: bool,
+}
+
+/// An N-GET request.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NGetRq {
+    pub message_id: u16,
+    pub requested_sop_class_uid: String,
+    pub requested_sop_instance_uid: String,
+    /// The attributes to retrieve, or all of them if empty.
+    pub attribute_identifier_list: Vec<Tag>,
+}
+
+/// An N-GET response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NGetRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub affected_sop_instance_uid: Option<String>,
+    pub status: Status,
+    pub has_data_set: bool,
+}
+
+/// An N-SET request, always followed by the modification list.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NSetRq {
+    pub message_id: u16,
+    pub requested_sop_class_uid: String,
+    pub requested_sop_instance_uid: String,
+}
+
+/// An N-SET response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NSetRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub affected_sop_instance_uid: Option<String>,
+    pub status: Status,
+    pub has_data_set: bool,
+}
+
+/// An N-ACTION request.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NActionRq {
+    pub message_id: u16,
+    pub requested_sop_class_uid: String,
+    pub requested_sop_instance_uid: String,
+    pub action_type_id: u16,
+    pub has_data_set: bool,
+}
+
+/// An N-ACTION response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NActionRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub affected_sop_instance_uid: Option<String>,
+    pub action_type_id: Option<u16>,
+    pub status: Status,
+    pub has_data_set: bool,
+}
+
+/// An N-CREATE request.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NCreateRq {
+    pub message_id: u16,
+    pub affected_sop_class_uid: String,
+    /// The UID of the instance to create,
+    /// or `None` to let the peer assign one.
+    pub affected_sop_instance_uid: Option<String>,
+    pub has_data_set: bool,
+}
+
+/// An N-CREATE response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NCreateRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub affected_sop_instance_uid: Option<String>,
+    pub status: Status,
+    pub has_data_set: bool,
+}
+
+/// An N-DELETE request.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NDeleteRq {
+    pub message_id: u16,
+    pub requested_sop_class_uid: String,
+    pub requested_sop_instance_uid: String,
+}
+
+/// An N-DELETE response.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NDeleteRsp {
+    pub message_id_being_responded_to: u16,
+    pub affected_sop_class_uid: Option<String>,
+    pub affected_sop_instance_uid: Option<String>,
+    pub status: Status,
+}
+
+/// A DIMSE command of any kind.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Command {
+    CStoreRq(CStoreRq),
+    CStoreRsp(CStoreRsp),
+    CGetRq(CGetRq),
+    CGetRsp(CGetRsp),
+    CFindRq(CFindRq),
+    CFindRsp(CFindRsp),
+    CMoveRq(CMoveRq),
+    CMoveRsp(CMoveRsp),
+    CEchoRq(CEchoRq),
+    CEchoRsp(CEchoRsp),
+    CCancelRq(CCancelRq),
+    NEventReportRq(NEventReportRq),
+    NEventReportRsp(NEventReportRsp),
+    NGetRq(NGetRq),
+    NGetRsp(NGetRsp),
+    NSetRq(NSetRq),
+    NSetRsp(NSetRsp),
+    NActionRq(NActionRq),
+    NActionRsp(NActionRsp),
+    NCreateRq(NCreateRq),
+    NCreateRsp(NCreateRsp),
+    NDeleteRq(NDeleteRq),
+    NDeleteRsp(NDeleteRsp),
+}
+
+impl Command {
+    /// Retrieve the command field of this command.
+    pub fn command_field(&self) -> CommandField {
+        match self {
+            Command::CStoreRq(_) => CommandField::CStoreRq,
+            Command::CStoreRsp(_) => CommandField::CStoreRsp,
+            Command::CGetRq(_) => CommandField::CGetRq,
+            Command::CGetRsp(_) => CommandField::CGetRsp,
+            Command::CFindRq(_) => CommandField::CFindRq,
+            Command::CFindRsp(_) => CommandField::CFindRsp,
+            Command::CMoveRq(_) => CommandField::CMoveRq,
+            Command::CMoveRsp(_) => CommandField::CMoveRsp,
+            Command::CEchoRq(_) => CommandField::CEchoRq,
+            Command::CEchoRsp(_) => CommandField::CEchoRsp,
+            Command::CCancelRq(_) => CommandField::CCancelRq,
+            Command::NEventReportRq(_) => CommandField::NEventReportRq,
+            Command::NEventReportRsp(_) => CommandField::NEventReportRsp,
+            Command::NGetRq(_) => CommandField::NGetRq,
+            Command::NGetRsp(_) => CommandField::NGetRsp,
+            Command::NSetRq(_) => CommandField::NSetRq,
+            Command::NSetRsp(_) => CommandField::NSetRsp,
+            Command::NActionRq(_) => CommandField::NActionRq,
+            Command::NActionRsp(_) => CommandField::NActionRsp,
+            Command::NCreateRq(_) => CommandField::NCreateRq,
+            Command::NCreateRsp(_) => CommandField::NCreateRsp,
+            Command::NDeleteRq(_) => CommandField::NDeleteRq,
+            Command::NDeleteRsp(_) => CommandField::NDeleteRsp,
+        }
+    }
+
+    /// Check whether this command is followed by a data set.
+    pub fn has_data_set(&self) -> bool {
+        match self {
+            Command::CStoreRq(_)
+            | Command::CGetRq(_)
+            | Command::CFindRq(_)
+            | Command::CMoveRq(_)
+            | Command::NSetRq(_) => true,
+            Command::CFindRsp(c) => c.has_data_set,
+            Command::CGetRsp(c) => c.has_data_set,
+            Command::CMoveRsp(c) => c.has_data_set,
+            Command::NEventReportRq(c) => c.has_data_set,
+            Command::NEventReportRsp(c) => c.has_data_set,
+            Command::NGetRsp(c) => c.has_data_set,
+            Command::NSetRsp(c) => c.has_data_set,
+            Command::NActionRq(c) => c.has_data_set,
+            Command::NActionRsp(c) => c.has_data_set,
+            Command::NCreateRq(c) => c.has_data_set,
+            Command::NCreateRsp(c) => c.has_data_set,
+            Command::CStoreRsp(_)
+            | Command::CEchoRq(_)
+            | Command::CEchoRsp(_)
+            | Command::CCancelRq(_)
+            | Command::NGetRq(_)
+            | Command::NDeleteRq(_)
+            | Command::NDeleteRsp(_) => false,
+        }
+    }
+
+    /// Retrieve the status of this command, if it is a response.
+    pub fn status(&self) -> Option<Status> {
+        match self {
+            Command::CStoreRsp(c) => Some(c.status),
+            Command::CGetRsp(c) => Some(c.status),
+            Command::CFindRsp(c) => Some(c.status),
+            Command::CMoveRsp(c) => Some(c.status),
+            Command::CEchoRsp(c) => Some(c.status),
+            Command::NEventReportRsp(c) => Some(c.status),
+            Command::NGetRsp(c) => Some(c.status),
+            Command::NSetRsp(c) => Some(c.status),
+            Command::NActionRsp(c) => Some(c.status),
+            Command::NCreateRsp(c) => Some(c.status),
+            Command::NDeleteRsp(c) => Some(c.status),
+            _ => None,
+        }
+    }
+
+    /// Encode this command into a command set
+    /// in Implicit VR Little Endian.
+    pub fn encode(&self) -> Vec<u8> {
+        self.to_command_set().to_bytes()
+    }
+
+    /// Decode a command from a command set
+    /// in Implicit VR Little Endian.
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        Command::from_command_set(&CommandSet::from_bytes(bytes)?)
+    }
+
+    /// Convert this command into a generic command set.
+    pub fn to_command_set(&self) -> CommandSet {
+        let mut set = CommandSet::new();
+        set.put_us(tags::COMMAND_FIELD, self.command_field().code());
+        set.put_us(
+            tags::COMMAND_DATA_SET_TYPE,
+            if self.has_data_set() {
+                DATA_SET_PRESENT
+            } else {
+                DATA_SET_ABSENT
+            },
+        );
+        if let Some(status) = self.status() {
+            set.put_us(tags::STATUS, status.0);
+        }
+
+        match self {
+            Command::CEchoRq(c) => {
+                set.put_us(tags::MESSAGE_ID, c.message_id);
+                set.put_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+            }
+            Command::CEchoRsp(c) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    c.message_id_being_responded_to,
+                );
+                set.put_opt_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+            }
+            Command::CStoreRq(c) => {
+                set.put_us(tags::MESSAGE_ID, c.message_id);
+                set.put_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+                set.put_str(
+                    tags::AFFECTED_SOP_INSTANCE_UID,
+                    &c.affected_sop_instance_uid,
+                );
+                set.put_us(tags::PRIORITY, c.priority.code());
+                set.put_opt_str(
+                    tags::MOVE_ORIGINATOR_APPLICATION_ENTITY_TITLE,
+                    &c.move_originator_ae_title,
+                );
+                set.put_opt_us(
+                    tags::MOVE_ORIGINATOR_MESSAGE_ID,
+                    c.move_originator_message_id,
+                );
+            }
+            Command::CStoreRsp(c) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    c.message_id_being_responded_to,
+                );
+                set.put_opt_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+                set.put_opt_str(
+                    tags::AFFECTED_SOP_INSTANCE_UID,
+                    &c.affected_sop_instance_uid,
+                );
+            }
+            Command::CFindRq(CFindRq {
+                message_id,
+                affected_sop_class_uid,
+                priority,
+            })
+            | Command::CGetRq(CGetRq {
+                message_id,
+                affected_sop_class_uid,
+                priority,
+            }) => {
+                set.put_us(tags::MESSAGE_ID, *message_id);
+                set.put_str(tags::AFFECTED_SOP_CLASS_UID, affected_sop_class_uid);
+                set.put_us(tags::PRIORITY, priority.code());
+            }
+            Command::CFindRsp(c) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    c.message_id_being_responded_to,
+                );
+                set.put_opt_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+            }
+            Command::CMoveRq(c) => {
+                set.put_us(tags::MESSAGE_ID, c.message_id);
+                set.put_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+                set.put_us(tags::PRIORITY, c.priority.code());
+                set.put_str(tags::MOVE_DESTINATION, &c.move_destination);
+            }
+            Command::CGetRsp(CGetRsp {
+                message_id_being_responded_to,
+                affected_sop_class_uid,
+                sub_operations,
+                ..
+            })
+            | Command::CMoveRsp(CMoveRsp {
+                message_id_being_responded_to,
+                affected_sop_class_uid,
+                sub_operations,
+                ..
+            }) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    *message_id_being_responded_to,
+                );
+                set.put_opt_str(tags::AFFECTED_SOP_CLASS_UID, affected_sop_class_uid);
+                set.put_sub_operations(sub_operations);
+            }
+            Command::CCancelRq(c) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    c.message_id_being_responded_to,
+                );
+            }
+            Command::NEventReportRq(c) => {
+                set.put_us(tags::MESSAGE_ID, c.message_id);
+                set.put_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+                set.put_str(
+                    tags::AFFECTED_SOP_INSTANCE_UID,
+                    &c.affected_sop_instance_uid,
+                );
+                set.put_us(tags::EVENT_TYPE_ID, c.event_type_id);
+            }
+            Command::NEventReportRsp(c) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    c.message_id_being_responded_to,
+                );
+                set.put_opt_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+                set.put_opt_str(
+                    tags::AFFECTED_SOP_INSTANCE_UID,
+                    &c.affected_sop_instance_uid,
+                );
+                set.put_opt_us(tags::EVENT_TYPE_ID, c.event_type_id);
+            }
+            Command::NGetRq(c) => {
+                set.put_us(tags::MESSAGE_ID, c.message_id);
+                set.put_str(tags::REQUESTED_SOP_CLASS_UID, &c.requested_sop_class_uid);
+                set.put_str(
+                    tags::REQUESTED_SOP_INSTANCE_UID,
+                    &c.requested_sop_instance_uid,
+                );
+                if !c.attribute_identifier_list.is_empty() {
+                    set.put(
+                        tags::ATTRIBUTE_IDENTIFIER_LIST,
+                        PrimitiveValue::Tags(c.attribute_identifier_list.clone().into()),
+                    );
+                }
+            }
+            Command::NSetRq(NSetRq {
+                message_id,
+                requested_sop_class_uid,
+                requested_sop_instance_uid,
+            })
+            | Command::NDeleteRq(NDeleteRq {
+                message_id,
+                requested_sop_class_uid,
+                requested_sop_instance_uid,
+            }) => {
+                set.put_us(tags::MESSAGE_ID, *message_id);
+                set.put_str(tags::REQUESTED_SOP_CLASS_UID, requested_sop_class_uid);
+                set.put_str(tags::REQUESTED_SOP_INSTANCE_UID, requested_sop_instance_uid);
+            }
+            Command::NActionRq(c) => {
+                set.put_us(tags::MESSAGE_ID, c.message_id);
+                set.put_str(tags::REQUESTED_SOP_CLASS_UID, &c.requested_sop_class_uid);
+                set.put_str(
+                    tags::REQUESTED_SOP_INSTANCE_UID,
+                    &c.requested_sop_instance_uid,
+                );
+                set.put_us(tags::ACTION_TYPE_ID, c.action_type_id);
+            }
+            Command::NActionRsp(c) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    c.message_id_being_responded_to,
+                );
+                set.put_opt_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+                set.put_opt_str(
+                    tags::AFFECTED_SOP_INSTANCE_UID,
+                    &c.affected_sop_instance_uid,
+                );
+                set.put_opt_us(tags::ACTION_TYPE_ID, c.action_type_id);
+            }
+            Command::NCreateRq(c) => {
+                set.put_us(tags::MESSAGE_ID, c.message_id);
+                set.put_str(tags::AFFECTED_SOP_CLASS_UID, &c.affected_sop_class_uid);
+                set.put_opt_str(
+                    tags::AFFECTED_SOP_INSTANCE_UID,
+                    &c.affected_sop_instance_uid,
+                );
+            }
+            Command::NGetRsp(NGetRsp {
+                message_id_being_responded_to,
+                affected_sop_class_uid,
+                affected_sop_instance_uid,
+                ..
+            })
+            | Command::NSetRsp(NSetRsp {
+                message_id_being_responded_to,
+                affected_sop_class_uid,
+                affected_sop_instance_uid,
+                ..
+            })
+            | Command::NCreateRsp(NCreateRsp {
+                message_id_being_responded_to,
+                affected_sop_class_uid,
+                affected_sop_instance_uid,
+                ..
+            })
+            | Command::NDeleteRsp(NDeleteRsp {
+                message_id_being_responded_to,
+                affected_sop_class_uid,
+                affected_sop_instance_uid,
+                ..
+            }) => {
+                set.put_us(
+                    tags::MESSAGE_ID_BEING_RESPONDED_TO,
+                    *message_id_being_responded_to,
+                );
+                set.put_opt_str(tags::AFFECTED_SOP_CLASS_UID, affected_sop_class_uid);
+                set.put_opt_str(tags::AFFECTED_SOP_INSTANCE_UID, affected_sop_instance_uid);
+            }
+        }
+        set
+    }
+
+    /// Interpret a generic command set as a command.
+    pub fn from_command_set(set: &CommandSet) -> Result<Self> {
+        let code = set.required_us(tags::COMMAND_FIELD)?;
+        let field = CommandField::from_code(code).ok_or(Error::UnknownCommandField(code))?;
+        let has_data_set = set.has_data_set();
+
+        let command = match field {
+            CommandField::CEchoRq => Command::CEchoRq(CEchoRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                affected_sop_class_uid: set.required_str(tags::AFFECTED_SOP_CLASS_UID)?,
+            }),
+            CommandField::CEchoRsp => Command::CEchoRsp(CEchoRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                status: set.status()?,
+            }),
+            CommandField::CStoreRq => Command::CStoreRq(CStoreRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                affected_sop_class_uid: set.required_str(tags::AFFECTED_SOP_CLASS_UID)?,
+                affected_sop_instance_uid: set.required_str(tags::AFFECTED_SOP_INSTANCE_UID)?,
+                priority: set.priority()?,
+                move_originator_ae_title: set
+                    .string(tags::MOVE_ORIGINATOR_APPLICATION_ENTITY_TITLE),
+                move_originator_message_id: set.uint16(tags::MOVE_ORIGINATOR_MESSAGE_ID),
+            }),
+            CommandField::CStoreRsp => Command::CStoreRsp(CStoreRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                status: set.status()?,
+            }),
+            CommandField::CFindRq => Command::CFindRq(CFindRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                affected_sop_class_uid: set.required_str(tags::AFFECTED_SOP_CLASS_UID)?,
+                priority: set.priority()?,
+            }),
+            CommandField::CFindRsp => Command::CFindRsp(CFindRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                status: set.status()?,
+                has_data_set,
+            }),
+            CommandField::CGetRq => Command::CGetRq(CGetRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                affected_sop_class_uid: set.required_str(tags::AFFECTED_SOP_CLASS_UID)?,
+                priority: set.priority()?,
+            }),
+            CommandField::CGetRsp => Command::CGetRsp(CGetRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                status: set.status()?,
+                sub_operations: set.sub_operations(),
+                has_data_set,
+            }),
+            CommandField::CMoveRq => Command::CMoveRq(CMoveRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                affected_sop_class_uid: set.required_str(tags::AFFECTED_SOP_CLASS_UID)?,
+                priority: set.priority()?,
+                move_destination: set.required_str(tags::MOVE_DESTINATION)?,
+            }),
+            CommandField::CMoveRsp => Command::CMoveRsp(CMoveRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                status: set.status()?,
+                sub_operations: set.sub_operations(),
+                has_data_set,
+            }),
+            CommandField::CCancelRq => Command::CCancelRq(CCancelRq {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+            }),
+            CommandField::NEventReportRq => Command::NEventReportRq(NEventReportRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                affected_sop_class_uid: set.required_str(tags::AFFECTED_SOP_CLASS_UID)?,
+                affected_sop_instance_uid: set.required_str(tags::AFFECTED_SOP_INSTANCE_UID)?,
+                event_type_id: set.required_us(tags::EVENT_TYPE_ID)?,
+                has_data_set,
+            }),
+            CommandField::NEventReportRsp => Command::NEventReportRsp(NEventReportRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                event_type_id: set.uint16(tags::EVENT_TYPE_ID),
+                status: set.status()?,
+                has_data_set,
+            }),
+            CommandField::NGetRq => Command::NGetRq(NGetRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                requested_sop_class_uid: set.required_str(tags::REQUESTED_SOP_CLASS_UID)?,
+                requested_sop_instance_uid: set.required_str(tags::REQUESTED_SOP_INSTANCE_UID)?,
+                attribute_identifier_list: match set.get(tags::ATTRIBUTE_IDENTIFIER_LIST) {
+                    Some(PrimitiveValue::Tags(tags)) => tags.to_vec(),
+                    Some(PrimitiveValue::Empty) | None => Vec::new(),
+                    Some(_) => {
+                        return Err(Error::InvalidCommandElement(
+                            tags::ATTRIBUTE_IDENTIFIER_LIST,
+                        ))
+                    }
+                },
+            }),
+            CommandField::NGetRsp => Command::NGetRsp(NGetRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                status: set.status()?,
+                has_data_set,
+            }),
+            CommandField::NSetRq => Command::NSetRq(NSetRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                requested_sop_class_uid: set.required_str(tags::REQUESTED_SOP_CLASS_UID)?,
+                requested_sop_instance_uid: set.required_str(tags::REQUESTED_SOP_INSTANCE_UID)?,
+            }),
+            CommandField::NSetRsp => Command::NSetRsp(NSetRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                status: set.status()?,
+                has_data_set,
+            }),
+            CommandField::NActionRq => Command::NActionRq(NActionRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                requested_sop_class_uid: set.required_str(tags::REQUESTED_SOP_CLASS_UID)?,
+                requested_sop_instance_uid: set.required_str(tags::REQUESTED_SOP_INSTANCE_UID)?,
+                action_type_id: set.required_us(tags::ACTION_TYPE_ID)?,
+                has_data_set,
+            }),
+            CommandField::NActionRsp => Command::NActionRsp(NActionRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                action_type_id: set.uint16(tags::ACTION_TYPE_ID),
+                status: set.status()?,
+                has_data_set,
+            }),
+            CommandField::NCreateRq => Command::NCreateRq(NCreateRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                affected_sop_class_uid: set.required_str(tags::AFFECTED_SOP_CLASS_UID)?,
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                has_data_set,
+            }),
+            CommandField::NCreateRsp => Command::NCreateRsp(NCreateRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                status: set.status()?,
+                has_data_set,
+            }),
+            CommandField::NDeleteRq => Command::NDeleteRq(NDeleteRq {
+                message_id: set.required_us(tags::MESSAGE_ID)?,
+                requested_sop_class_uid: set.required_str(tags::REQUESTED_SOP_CLASS_UID)?,
+                requested_sop_instance_uid: set.required_str(tags::REQUESTED_SOP_INSTANCE_UID)?,
+            }),
+            CommandField::NDeleteRsp => Command::NDeleteRsp(NDeleteRsp {
+                message_id_being_responded_to: set
+                    .required_us(tags::MESSAGE_ID_BEING_RESPONDED_TO)?,
+                affected_sop_class_uid: set.string(tags::AFFECTED_SOP_CLASS_UID),
+                affected_sop_instance_uid: set.string(tags::AFFECTED_SOP_INSTANCE_UID),
+                status: set.status()?,
+            }),
+        };
+        Ok(command)
+    }
+}
+
+/// A generic command set: the command elements of a DIMSE message
+/// in ascending tag order, without the Command Group Length,
+/// which is computed on encoding.
+///
+/// The value representation of each element
+/// is resolved from the standard data dictionary.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CommandSet {
+    elements: BTreeMap<Tag, PrimitiveValue>,
+}
+
+impl CommandSet {
+    /// Create an empty command set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a command element, replacing any previous value.
+    pub fn put(&mut self, tag: Tag, value: PrimitiveValue) {
+        self.elements.insert(tag, value);
+    }
+
+    /// Retrieve the value of a command element.
+    pub fn get(&self, tag: Tag) -> Option<&PrimitiveValue> {
+        self.elements.get(&tag)
+    }
+
+    /// Iterate over the command elements in ascending tag order.
+    pub fn iter(&self) -> impl Iterator<Item = (Tag, &PrimitiveValue)> {
+        self.elements.iter().map(|(tag, value)| (*tag, value))
+    }
+
+    /// Retrieve a command element as a single string.
+    pub fn string(&self, tag: Tag) -> Option<String> {
+        self.get(tag).and_then(|v| v.string()).map(str::to_string)
+    }
+
+    /// Retrieve a command element as a single unsigned short.
+    pub fn uint16(&self, tag: Tag) -> Option<u16> {
+        self.get(tag).and_then(|v| v.uint16())
+    }
+
+    /// Check whether Command Data Set Type (0000,0800)
+    /// announces a data set after this command set.
+    pub fn has_data_set(&self) -> bool {
+        self.uint16(tags::COMMAND_DATA_SET_TYPE)
+            .map(|v| v != DATA_SET_ABSENT)
+            .unwrap_or(false)
+    }
+
+    /// Encode the command set in Implicit VR Little Endian,
+    /// preceded by the Command Group Length.
+    pub fn write<W>(&self, mut to: W) -> Result<()>
+    where
+        W: Write,
+    {
+        let encoder = ImplicitVRLittleEndianEncoder::default();
+        let mut body = Vec::new();
+        for (&tag, value) in &self.elements {
+            let vr = StandardDataDictionary
+                .by_tag(tag)
+                .map(|e| e.vr())
+                .unwrap_or(VR::UN);
+            let mut bytes = Vec::new();
+            encoder.encode_primitive(&mut bytes, value)?;
+            if bytes.len() % 2 != 0 {
+                // UIDs are padded with a null character, other text with a space
+                bytes.push(if vr == VR::UI { 0 } else { b' ' });
+            }
+            encoder.encode_element_header(
+                &mut body,
+                DataElementHeader::new(tag, vr, Length(bytes.len() as u32)),
+            )?;
+            body.extend(bytes);
+        }
+
+        encoder.encode_element_header(
+            &mut to,
+            DataElementHeader::new(tags::COMMAND_GROUP_LENGTH, VR::UL, Length(4)),
+        )?;
+        encoder.encode_primitive(
+            &mut to,
+            &PrimitiveValue::U32(vec![body.len() as u32].into()),
+        )?;
+        to.write_all(&body)?;
+        Ok(())
+    }
+
+    /// Encode the command set into a new byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write(&mut out)
+            .expect("writing a command set to memory should not fail");
+        out
+    }
+
+    /// Decode a command set in Implicit VR Little Endian.
+    /// The Command Group Length, if present, is not kept.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self> {
+        let decoder = ImplicitVRLittleEndianDecoder::with_std_dict();
+        let basic = LittleEndianBasicDecoder;
+        let mut set = CommandSet::new();
+
+        while !bytes.is_empty() {
+            let (header, _) = decoder.decode_header(&mut bytes)?;
+            let tag = header.tag();
+            let len = header
+                .len()
+                .get()
+                .filter(|&len| len as usize <= bytes.len())
+                .ok_or(Error::InvalidCommandElement(tag))? as usize;
+            let (mut data, rest) = bytes.split_at(len);
+            bytes = rest;
+
+            if tag.group() != 0x0000 {
+                return Err(Error::InvalidCommandElement(tag));
+            }
+            if tag == tags::COMMAND_GROUP_LENGTH {
+                continue;
+            }
+
+            let value = match header.vr() {
+                VR::US => {
+                    let mut values = Vec::with_capacity(len / 2);
+                    while data.len() >= 2 {
+                        values.push(basic.decode_us(&mut data)?);
+                    }
+                    PrimitiveValue::U16(values.into())
+                }
+                VR::UL => {
+                    let mut values = Vec::with_capacity(len / 4);
+                    while data.len() >= 4 {
+                        values.push(basic.decode_ul(&mut data)?);
+                    }
+                    PrimitiveValue::U32(values.into())
+                }
+                VR::AT => {
+                    let mut values = Vec::with_capacity(len / 4);
+                    while data.len() >= 4 {
+                        values.push(basic.decode_tag(&mut data)?);
+                    }
+                    PrimitiveValue::Tags(values.into())
+                }
+                VR::UI | VR::AE | VR::LO | VR::SH | VR::CS => {
+                    let mut text = String::new();
+                    data.read_to_string(&mut text)
+                        .map_err(|_| Error::InvalidCommandElement(tag))?;
+                    PrimitiveValue::Strs(
+                        text.trim_end_matches(['\0', ' '])
+                            .split('\\')
+                            .map(|s| s.trim().to_string())
+                            .collect(),
+                    )
+                }
+                _ => PrimitiveValue::U8(data.to_vec().into()),
+            };
+            set.put(tag, value);
+        }
+        Ok(set)
+    }
+
+    fn put_us(&mut self, tag: Tag, value: u16) {
+        self.put(tag, PrimitiveValue::U16(vec![value].into()));
+    }
+
+    fn put_opt_us(&mut self, tag: Tag, value: Option<u16>) {
+        if let Some(value) = value {
+            self.put_us(tag, value);
+        }
+    }
+
+    fn put_str(&mut self, tag: Tag, value: &str) {
+        self.put(tag, PrimitiveValue::Strs(vec![value.to_string()].into()));
+    }
+
+    fn put_opt_str(&mut self, tag: Tag, value: &Option<String>) {
+        if let Some(value) = value {
+            self.put_str(tag, value);
+        }
+    }
+
+    fn put_sub_operations(&mut self, sub_operations: &SubOperations) {
+        self.put_opt_us(
+            tags::NUMBER_OF_REMAINING_SUBOPERATIONS,
+            sub_operations.remaining,
+        );
+        self.put_opt_us(
+            tags::NUMBER_OF_COMPLETED_SUBOPERATIONS,
+            sub_operations.completed,
+        );
+        self.put_opt_us(tags::NUMBER_OF_FAILED_SUBOPERATIONS, sub_operations.failed);
+        self.put_opt_us(
+            tags::NUMBER_OF_WARNING_SUBOPERATIONS,
+            sub_operations.warning,
+        );
+    }
+
+    fn required_us(&self, tag: Tag) -> Result<u16> {
+        match self.get(tag) {
+            None => Err(Error::MissingCommandElement(tag)),
+            Some(v) => v.uint16().ok_or(Error::InvalidCommandElement(tag)),
+        }
+    }
+
+    fn required_str(&self, tag: Tag) -> Result<String> {
+        match self.get(tag) {
+            None => Err(Error::MissingCommandElement(tag)),
+            Some(v) => v
+                .string()
+                .map(str::to_string)
+                .ok_or(Error::InvalidCommandElement(tag)),
+        }
+    }
+
+    fn status(&self) -> Result<Status> {
+        self.required_us(tags::STATUS).map(Status)
+    }
+
+    fn priority(&self) -> Result<Priority> {
+        Priority::from_code(self.required_us(tags::PRIORITY)?)
+            .ok_or(Error::InvalidCommandElement(tags::PRIORITY))
+    }
+
+    fn sub_operations(&self) -> SubOperations {
+        SubOperations {
+            remaining: self.uint16(tags::NUMBER_OF_REMAINING_SUBOPERATIONS),
+            completed: self.uint16(tags::NUMBER_OF_COMPLETED_SUBOPERATIONS),
+            failed: self.uint16(tags::NUMBER_OF_FAILED_SUBOPERATIONS),
+            warning: self.uint16(tags::NUMBER_OF_WARNING_SUBOPERATIONS),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_c_echo_rq() {
+        let command = Command::CEchoRq(CEchoRq {
+            message_id: 1,
+            affected_sop_class_uid: "1.2.840.10008.1.1".to_string(),
+        });
+        let bytes = command.encode();
+
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            // (0000,0000) CommandGroupLength UL 56
+            0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00,
+            // (0000,0002) AffectedSOPClassUID UI "1.2.840.10008.1.1\0"
+            0x00, 0x00, 0x02, 0x00, 0x12, 0x00, 0x00, 0x00,
+            b'1', b'.', b'2', b'.', b'8', b'4', b'0', b'.', b'1', b'0',
+            b'0', b'0', b'8', b'.', b'1', b'.', b'1', 0x00,
+            // (0000,0100) CommandField US 0x0030
+            0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x30, 0x00,
+            // (0000,0110) MessageID US 1
+            0x00, 0x00, 0x10, 0x01, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00,
+            // (0000,0800) CommandDataSetType US 0x0101
+            0x00, 0x00, 0x00, 0x08, 0x02, 0x00, 0x00, 0x00, 0x01, 0x01,
+        ];
+        assert_eq!(bytes, expected);
+        assert_eq!(Command::decode(&bytes).unwrap(), command);
+        assert!(!crate::association::pdata::has_data_set(&bytes));
+    }
+
+    #[test]
+    fn commands_round_trip() {
+        let commands = vec![
+            Command::CStoreRq(CStoreRq {
+                message_id: 7,
+                affected_sop_class_uid: "1.2.840.10008.5.1.4.1.1.7".to_string(),
+                affected_sop_instance_uid: "2.25.1234".to_string(),
+                priority: Priority::High,
+                move_originator_ae_title: Some("MOVE-SCU".to_string()),
+                move_originator_message_id: Some(3),
+            }),
+            Command::CFindRsp(CFindRsp {
+                message_id_being_responded_to: 2,
+                affected_sop_class_uid: Some("1.2.840.10008.5.1.4.1.2.2.1".to_string()),
+                status: Status::PENDING,
+                has_data_set: true,
+            }),
+            Command::CMoveRsp(CMoveRsp {
+                message_id_being_responded_to: 5,
+                affected_sop_class_uid: None,
+                status: Status(0xB000),
+                sub_operations: SubOperations {
+                    remaining: Some(0),
+                    completed: Some(10),
+                    failed: Some(1),
+                    warning: Some(0),
+                },
+                has_data_set: false,
+            }),
+            Command::CCancelRq(CCancelRq {
+                message_id_being_responded_to: 2,
+            }),
+            Command::NGetRq(NGetRq {
+                message_id: 9,
+                requested_sop_class_uid: "1.2.840.10008.5.1.1.16".to_string(),
+                requested_sop_instance_uid: "1.2.840.10008.5.1.1.17".to_string(),
+                attribute_identifier_list: vec![Tag(0x2110, 0x0010), Tag(0x2110, 0x0020)],
+            }),
+            Command::NCreateRq(NCreateRq {
+                message_id: 11,
+                affected_sop_class_uid: "1.2.840.10008.3.1.2.3.3".to_string(),
+                affected_sop_instance_uid: None,
+                has_data_set: true,
+            }),
+        ];
+
+        for command in commands {
+            let bytes = command.encode();
+            assert_eq!(bytes.len() % 2, 0);
+            assert_eq!(
+                crate::association::pdata::has_data_set(&bytes),
+                command.has_data_set()
+            );
+            assert_eq!(Command::decode(&bytes).unwrap(), command);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_command_sets() {
+        let mut set = CommandSet::new();
+        set.put_us(tags::COMMAND_FIELD, 0x1234);
+        match Command::from_command_set(&set) {
+            Err(Error::UnknownCommandField(0x1234)) => {}
+            r => panic!("unexpected outcome {:?}", r),
+        }
+
+        set.put_us(tags::COMMAND_FIELD, CommandField::CStoreRsp.code());
+        set.put_us(tags::MESSAGE_ID_BEING_RESPONDED_TO, 1);
+        match Command::from_command_set(&set) {
+            Err(Error::MissingCommandElement(tag)) => assert_eq!(tag, tags::STATUS),
+            r => panic!("unexpected outcome {:?}", r),
+        }
+    }
+
+    #[test]
+    fn status_categories() {
+        assert!(Status::SUCCESS.is_success());
+        assert!(Status::PENDING.is_pending());
+        assert!(Status(0xFF01).is_pending());
+        assert!(Status::CANCEL.is_cancel());
+        assert!(Status(0xB007).is_warning());
+        assert!(Status(0xA700).is_failure());
+        assert!(Status::SOP_CLASS_NOT_SUPPORTED.is_failure());
+        assert_eq!(Status(0xB007).to_string(), "0xB007 (warning)");
+        assert_eq!(Status::CANCEL.to_string(), "0xFE00 (cancel)");
+    }
+}
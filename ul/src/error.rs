@@ -1,5 +1,8 @@
+use crate::dimse::CommandField;
+use crate::fsm::State;
+use crate::pdu::{AssociationRJResult, AssociationRJSource};
+use dicom_core::Tag;
 use quick_error::quick_error;
-use std::error::Error as BaseError;
 
 /// Type alias for a result from this crate.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -9,64 +12,102 @@ quick_error! {
     pub enum Error {
         Io(err: std::io::Error) {
             from()
-            display(self_) -> ("io error: {}", err)
+            display("io error: {}", err)
         }
         NoPduAvailable {
-            description("no pdu was available")
-            display(self_) -> ("{}", self_.description())
+            display("no pdu was available")
         }
         InvalidMaxPdu {
-            description("invalid max pdu")
-            display(self_) -> ("{}", self_.description())
+            display("invalid max pdu")
         }
         PduTooLarge {
-            description("the incoming pdu was too large")
-            display(self_) -> ("{}", self_.description())
+            display("the incoming pdu was too large")
         }
         InvalidPduVariable {
-            description("the pdu contained an invalid value")
-            display(self_) -> ("{}", self_.description())
+            display("the pdu contained an invalid value")
         }
         MultipleTransferSyntaxesAccepted {
-            description("multiple transfer syntaxes were accepted")
-            display(self_) -> ("{}", self_.description())
+            display("multiple transfer syntaxes were accepted")
         }
         InvalidRejectSourceOrReason {
-            description("the reject source or reason was invalid")
-            display(self_) -> ("{}", self_.description())
+            display("the reject source or reason was invalid")
         }
         InvalidAbortSourceOrReason {
-            description("the abort service provider reason was invalid")
-            display(self_) -> ("{}", self_.description())
+            display("the abort service provider reason was invalid")
         }
         InvalidPresentationContextResultReason {
-            description("the presentation context result reason was invalid")
-            display(self_) -> ("{}", self_.description())
+            display("the presentation context result reason was invalid")
         }
         InvalidTransferSyntaxSubItem {
-            description("invalid transfer syntax sub-item")
-            display(self_) -> ("{}", self_.description())
+            display("invalid transfer syntax sub-item")
         }
         UnknownPresentationContextSubItem {
-            description("unknown presentation context sub-item")
-            display(self_) -> ("{}", self_.description())
+            display("unknown presentation context sub-item")
         }
         EncodingError(err: dicom_encoding::error::Error) {
             from()
-            description("encoding error")
-            display(self_) -> ("{} {}", err, self_.description())
+            display("{} encoding error", err)
+        }
+        UnsupportedTransferSyntax(uid: String) {
+            display("transfer syntax {} is not supported", uid)
+        }
+        DataSet(err: dicom_object::Error) {
+            from()
+            display("could not read or write a data set: {}", err)
         }
         MissingApplicationContextName {
-            description("missing application context name")
-            display(self_) -> ("{}", self_.description())
+            display("missing application context name")
+        }
+        TooManyPresentationContexts(count: usize) {
+            display("{} presentation contexts were proposed, at most 128 are allowed", count)
         }
         MissingAbstractSyntax {
-            description("missing abstract syntax")
-            display(self_) -> ("{}", self_.description())
+            display("missing abstract syntax")
         }
         MissingTransferSyntax {
-            description("missing transfer syntax")
-            display(self_) -> ("{}", self_.description())
+            display("missing transfer syntax")
+        }
+        Rejected(result: AssociationRJResult, source: AssociationRJSource) {
+            display("the association was rejected ({:?}): {:?}", result, source)
+        }
+        Aborted {
+            display("the association was aborted")
+        }
+        UnexpectedPdu {
+            display("an unexpected pdu was received")
+        }
+        NoAcceptedPresentationContexts {
+            display("no presentation contexts were accepted")
+        }
+        Released {
+            display("the association was released")
+        }
+        UnknownPresentationContext(id: u8) {
+            display("presentation context {} was not accepted", id)
+        }
+        UnexpectedPDataValue {
+            display("a presentation data value was out of sequence")
+        }
+        UnknownCommandField(code: u16) {
+            display("unknown DIMSE command field {:#06X}", code)
+        }
+        MissingCommandElement(tag: Tag) {
+            display("missing element {} in DIMSE command set", tag)
+        }
+        InvalidCommandElement(tag: Tag) {
+            display("invalid element {} in DIMSE command set", tag)
+        }
+        NoPresentationContext(abstract_syntax: String) {
+            display("no presentation context was accepted for abstract syntax {}", abstract_syntax)
+        }
+        UnexpectedCommand(field: CommandField) {
+            display("an unexpected DIMSE command was received: {:?}", field)
+        }
+        UnexpectedMessageId(id: u16) {
+            display("a DIMSE response to unknown message {} was received", id)
+        }
+        UnexpectedEvent(event: u8, state: State) {
+            display("event Evt{} not applicable in state {:?}", event, state)
         }
     }
 }
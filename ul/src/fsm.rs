@@ -0,0 +1,885 @@
+//! The DICOM Upper Layer protocol state machine.
+//!
+//! This module implements the state transition table of the DICOM upper layer
+//! (PS3.8, section 9.2) as a transport-agnostic core. The [`StateMachine`] is
+//! fed with [`Event`]s, which are either primitives issued by the local user,
+//! notifications from the transport, or PDUs received from the peer, and
+//! responds with a [`Transition`] describing the action taken and the
+//! [`Effect`]s that the caller must carry out: sending PDUs, opening or
+//! closing the transport connection, and passing indications or
+//! confirmations to the local user.
+//!
+//! The ARTIM timer is kept by the state machine itself as a deadline, which
+//! the caller can inspect with [`StateMachine::artim_deadline`] and check with
+//! [`StateMachine::check_artim`].
+//!
+//! [`StateMachine`]: ./struct.StateMachine.html
+//! [`Event`]: ./enum.Event.html
+//! [`Transition`]: ./struct.Transition.html
+//! [`Effect`]: ./enum.Effect.html
+//! [`StateMachine::artim_deadline`]: ./struct.StateMachine.html#method.artim_deadline
+//! [`StateMachine::check_artim`]: ./struct.StateMachine.html#method.check_artim
+
+use crate::error::{Error, Result};
+use crate::pdu::{
+    AbortRQServiceProviderReason, AbortRQSource, AssociationRJResult,
+    AssociationRJServiceProviderASCEReason, AssociationRJSource, Pdu,
+};
+use std::time::{Duration, Instant};
+
+/// The default duration of the ARTIM timer.
+pub const DEFAULT_ARTIM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A state of the DICOM upper layer protocol machine.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum State {
+    /// Idle.
+    Sta1,
+    /// Transport connection open (awaiting A-ASSOCIATE-RQ PDU).
+    Sta2,
+    /// Awaiting local A-ASSOCIATE response primitive.
+    Sta3,
+    /// Awaiting transport connection opening to complete.
+    Sta4,
+    /// Awaiting A-ASSOCIATE-AC or A-ASSOCIATE-RJ PDU.
+    Sta5,
+    /// Association established and ready for data transfer.
+    Sta6,
+    /// Awaiting A-RELEASE-RP PDU.
+    Sta7,
+    /// Awaiting local A-RELEASE response primitive.
+    Sta8,
+    /// Release collision requestor side; awaiting A-RELEASE response.
+    Sta9,
+    /// Release collision acceptor side; awaiting A-RELEASE-RP PDU.
+    Sta10,
+    /// Release collision requestor side; awaiting A-RELEASE-RP PDU.
+    Sta11,
+    /// Release collision acceptor side; awaiting A-RELEASE response primitive.
+    Sta12,
+    /// Awaiting transport connection close indication.
+    Sta13,
+}
+
+/// An event fed to the state machine.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// Evt1: A-ASSOCIATE request primitive from the local user,
+    /// with the A-ASSOCIATE-RQ PDU to send once connected.
+    AssociateRequest(Pdu),
+    /// Evt2: transport connection confirmation from the local transport service.
+    TransportConnectConfirm,
+    /// Evt3: A-ASSOCIATE-AC PDU received on the transport connection.
+    AssociateAc(Pdu),
+    /// Evt4: A-ASSOCIATE-RJ PDU received on the transport connection.
+    AssociateRj(Pdu),
+    /// Evt5: transport connection indication from the local transport service.
+    TransportConnectIndication,
+    /// Evt6: A-ASSOCIATE-RQ PDU received on the transport connection.
+    AssociateRq(Pdu),
+    /// Evt7: A-ASSOCIATE response primitive (accept),
+    /// with the A-ASSOCIATE-AC PDU to send.
+    AssociateAccept(Pdu),
+    /// Evt8: A-ASSOCIATE response primitive (reject),
+    /// with the A-ASSOCIATE-RJ PDU to send.
+    AssociateReject(Pdu),
+    /// Evt9: P-DATA request primitive, with the P-DATA-TF PDU to send.
+    PDataRequest(Pdu),
+    /// Evt10: P-DATA-TF PDU received on the transport connection.
+    PDataTf(Pdu),
+    /// Evt11: A-RELEASE request primitive.
+    ReleaseRequest,
+    /// Evt12: A-RELEASE-RQ PDU received on the transport connection.
+    ReleaseRq,
+    /// Evt13: A-RELEASE-RP PDU received on the transport connection.
+    ReleaseRp,
+    /// Evt14: A-RELEASE response primitive.
+    ReleaseResponse,
+    /// Evt15: A-ABORT request primitive.
+    AbortRequest,
+    /// Evt16: A-ABORT PDU received on the transport connection.
+    Abort(AbortRQSource),
+    /// Evt17: transport connection closed indication.
+    TransportClosed,
+    /// Evt18: ARTIM timer expired.
+    ArtimExpired,
+    /// Evt19: unrecognized or invalid PDU received.
+    InvalidPdu,
+}
+
+impl Event {
+    /// Retrieve the number of this event in the state transition table
+    /// (e.g. `1` for Evt1).
+    pub fn number(&self) -> u8 {
+        match self {
+            Event::AssociateRequest(_) => 1,
+            Event::TransportConnectConfirm => 2,
+            Event::AssociateAc(_) => 3,
+            Event::AssociateRj(_) => 4,
+            Event::TransportConnectIndication => 5,
+            Event::AssociateRq(_) => 6,
+            Event::AssociateAccept(_) => 7,
+            Event::AssociateReject(_) => 8,
+            Event::PDataRequest(_) => 9,
+            Event::PDataTf(_) => 10,
+            Event::ReleaseRequest => 11,
+            Event::ReleaseRq => 12,
+            Event::ReleaseRp => 13,
+            Event::ReleaseResponse => 14,
+            Event::AbortRequest => 15,
+            Event::Abort(_) => 16,
+            Event::TransportClosed => 17,
+            Event::ArtimExpired => 18,
+            Event::InvalidPdu => 19,
+        }
+    }
+}
+
+impl From<Pdu> for Event {
+    /// Obtain the event of receiving the given PDU from the peer.
+    fn from(pdu: Pdu) -> Self {
+        match pdu {
+            Pdu::AssociationRQ { .. } => Event::AssociateRq(pdu),
+            Pdu::AssociationAC { .. } => Event::AssociateAc(pdu),
+            Pdu::AssociationRJ { .. } => Event::AssociateRj(pdu),
+            Pdu::PData { .. } => Event::PDataTf(pdu),
+            Pdu::ReleaseRQ => Event::ReleaseRq,
+            Pdu::ReleaseRP => Event::ReleaseRp,
+            Pdu::AbortRQ { source } => Event::Abort(source),
+            Pdu::Unknown { .. } => Event::InvalidPdu,
+        }
+    }
+}
+
+/// An action of the state transition table.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Action {
+    /// Issue transport connect request primitive.
+    AE1,
+    /// Send A-ASSOCIATE-RQ PDU.
+    AE2,
+    /// Issue A-ASSOCIATE confirmation (accept) primitive.
+    AE3,
+    /// Issue A-ASSOCIATE confirmation (reject) primitive
+    /// and close transport connection.
+    AE4,
+    /// Issue transport connection response primitive and start ARTIM timer.
+    AE5,
+    /// Stop ARTIM timer and either issue A-ASSOCIATE indication primitive
+    /// or send A-ASSOCIATE-RJ PDU and start ARTIM timer.
+    AE6,
+    /// Send A-ASSOCIATE-AC PDU.
+    AE7,
+    /// Send A-ASSOCIATE-RJ PDU and start ARTIM timer.
+    AE8,
+    /// Send P-DATA-TF PDU.
+    DT1,
+    /// Issue P-DATA indication primitive.
+    DT2,
+    /// Send A-RELEASE-RQ PDU.
+    AR1,
+    /// Issue A-RELEASE indication primitive.
+    AR2,
+    /// Issue A-RELEASE confirmation primitive and close transport connection.
+    AR3,
+    /// Send A-RELEASE-RP PDU and start ARTIM timer.
+    AR4,
+    /// Stop ARTIM timer.
+    AR5,
+    /// Issue P-DATA indication primitive while awaiting A-RELEASE-RP PDU.
+    AR6,
+    /// Send P-DATA-TF PDU while awaiting A-RELEASE response primitive.
+    AR7,
+    /// Issue A-RELEASE indication primitive (release collision).
+    AR8,
+    /// Send A-RELEASE-RP PDU (release collision).
+    AR9,
+    /// Issue A-RELEASE confirmation primitive (release collision).
+    AR10,
+    /// Send A-ABORT PDU and start (or restart) ARTIM timer.
+    AA1,
+    /// Stop ARTIM timer if running and close transport connection.
+    AA2,
+    /// Issue A-ABORT or A-P-ABORT indication primitive
+    /// and close transport connection.
+    AA3,
+    /// Issue A-P-ABORT indication primitive.
+    AA4,
+    /// Stop ARTIM timer.
+    AA5,
+    /// Ignore PDU.
+    AA6,
+    /// Send A-ABORT PDU.
+    AA7,
+    /// Send A-ABORT PDU, issue A-P-ABORT indication primitive
+    /// and start ARTIM timer.
+    AA8,
+}
+
+/// An effect to be carried out by the caller of the state machine.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Effect {
+    /// Open a transport connection to the peer.
+    Connect,
+    /// Accept the incoming transport connection.
+    AcceptConnection,
+    /// Send a PDU to the peer.
+    Send(Pdu),
+    /// Close the transport connection.
+    Close,
+    /// Issue an A-ASSOCIATE indication to the local user,
+    /// with the A-ASSOCIATE-RQ PDU received.
+    AssociateIndication(Pdu),
+    /// Issue an A-ASSOCIATE confirmation to the local user,
+    /// with the A-ASSOCIATE-AC or A-ASSOCIATE-RJ PDU received.
+    AssociateConfirmation(Pdu),
+    /// Issue a P-DATA indication to the local user,
+    /// with the P-DATA-TF PDU received.
+    PDataIndication(Pdu),
+    /// Issue an A-RELEASE indication to the local user.
+    ReleaseIndication,
+    /// Issue an A-RELEASE confirmation to the local user.
+    ReleaseConfirmation,
+    /// Issue an A-ABORT indication to the local user.
+    AbortIndication,
+    /// Issue an A-P-ABORT indication to the local user,
+    /// with the provider reason if one is known.
+    ProviderAbortIndication(Option<AbortRQServiceProviderReason>),
+}
+
+/// The outcome of feeding an event to the state machine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    /// The action taken.
+    pub action: Action,
+    /// The effects to carry out, in order.
+    pub effects: Vec<Effect>,
+}
+
+/// The DICOM upper layer protocol state machine.
+#[derive(Debug, Clone)]
+pub struct StateMachine {
+    state: State,
+    requestor: bool,
+    pending_request: Option<Pdu>,
+    artim_timeout: Duration,
+    artim_deadline: Option<Instant>,
+}
+
+impl Default for StateMachine {
+    fn default() -> Self {
+        StateMachine {
+            state: State::Sta1,
+            requestor: false,
+            pending_request: None,
+            artim_timeout: DEFAULT_ARTIM_TIMEOUT,
+            artim_deadline: None,
+        }
+    }
+}
+
+impl StateMachine {
+    /// Create a new state machine in the idle state (Sta1).
+    pub fn new() -> Self {
+        StateMachine::default()
+    }
+
+    /// Override the duration of the ARTIM timer.
+    pub fn with_artim_timeout(mut self, timeout: Duration) -> Self {
+        self.artim_timeout = timeout;
+        self
+    }
+
+    /// Retrieve the current state.
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Whether the local end is the association requestor.
+    pub fn is_requestor(&self) -> bool {
+        self.requestor
+    }
+
+    /// Retrieve the instant at which the ARTIM timer expires,
+    /// if it is running.
+    pub fn artim_deadline(&self) -> Option<Instant> {
+        self.artim_deadline
+    }
+
+    /// Feed the event of receiving the given PDU from the peer.
+    pub fn receive(&mut self, pdu: Pdu) -> Result<Transition> {
+        self.handle(Event::from(pdu))
+    }
+
+    /// Check the ARTIM timer against the given instant,
+    /// feeding an ARTIM expiration event if the timer has expired.
+    pub fn check_artim(&mut self, now: Instant) -> Result<Option<Transition>> {
+        match self.artim_deadline {
+            Some(deadline) if deadline <= now => self.handle(Event::ArtimExpired).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Feed an event to the state machine, moving it to the next state.
+    ///
+    /// Fails if the event is not applicable in the current state,
+    /// in which case the state machine is left unchanged.
+    pub fn handle(&mut self, event: Event) -> Result<Transition> {
+        use self::State::*;
+
+        let number = event.number();
+        let (action, effects, next) = match (self.state, event) {
+            (Sta1, Event::AssociateRequest(pdu)) => {
+                self.requestor = true;
+                self.pending_request = Some(pdu);
+                (Action::AE1, vec![Effect::Connect], Sta4)
+            }
+            (Sta4, Event::TransportConnectConfirm) => match self.pending_request.take() {
+                Some(pdu) => (Action::AE2, vec![Effect::Send(pdu)], Sta5),
+                None => return Err(Error::UnexpectedEvent(number, self.state)),
+            },
+            (Sta5, Event::AssociateAc(pdu)) => {
+                (Action::AE3, vec![Effect::AssociateConfirmation(pdu)], Sta6)
+            }
+            (Sta5, Event::AssociateRj(pdu)) => (
+                Action::AE4,
+                vec![Effect::AssociateConfirmation(pdu), Effect::Close],
+                Sta1,
+            ),
+            (Sta1, Event::TransportConnectIndication) => {
+                self.requestor = false;
+                self.start_artim();
+                (Action::AE5, vec![Effect::AcceptConnection], Sta2)
+            }
+            (Sta2, Event::AssociateRq(pdu)) => {
+                self.stop_artim();
+                if is_acceptable(&pdu) {
+                    (Action::AE6, vec![Effect::AssociateIndication(pdu)], Sta3)
+                } else {
+                    self.start_artim();
+                    let rj = Pdu::AssociationRJ {
+                        result: AssociationRJResult::Permanent,
+                        source: AssociationRJSource::ServiceProviderASCE(
+                            AssociationRJServiceProviderASCEReason::ProtocolVersionNotSupported,
+                        ),
+                    };
+                    (Action::AE6, vec![Effect::Send(rj)], Sta13)
+                }
+            }
+            (Sta3, Event::AssociateAccept(pdu)) => (Action::AE7, vec![Effect::Send(pdu)], Sta6),
+            (Sta3, Event::AssociateReject(pdu)) => {
+                self.start_artim();
+                (Action::AE8, vec![Effect::Send(pdu)], Sta13)
+            }
+            (Sta6, Event::PDataRequest(pdu)) => (Action::DT1, vec![Effect::Send(pdu)], Sta6),
+            (Sta6, Event::PDataTf(pdu)) => (Action::DT2, vec![Effect::PDataIndication(pdu)], Sta6),
+            (Sta6, Event::ReleaseRequest) => {
+                (Action::AR1, vec![Effect::Send(Pdu::ReleaseRQ)], Sta7)
+            }
+            (Sta6, Event::ReleaseRq) => (Action::AR2, vec![Effect::ReleaseIndication], Sta8),
+            (Sta7, Event::ReleaseRp) | (Sta11, Event::ReleaseRp) => (
+                Action::AR3,
+                vec![Effect::ReleaseConfirmation, Effect::Close],
+                Sta1,
+            ),
+            (Sta8, Event::ReleaseResponse) | (Sta12, Event::ReleaseResponse) => {
+                self.start_artim();
+                (Action::AR4, vec![Effect::Send(Pdu::ReleaseRP)], Sta13)
+            }
+            (Sta13, Event::TransportClosed) => {
+                self.stop_artim();
+                (Action::AR5, vec![], Sta1)
+            }
+            (Sta7, Event::PDataTf(pdu)) => (Action::AR6, vec![Effect::PDataIndication(pdu)], Sta7),
+            (Sta8, Event::PDataRequest(pdu)) => (Action::AR7, vec![Effect::Send(pdu)], Sta8),
+            (Sta7, Event::ReleaseRq) => {
+                let next = if self.requestor { Sta9 } else { Sta10 };
+                (Action::AR8, vec![Effect::ReleaseIndication], next)
+            }
+            (Sta9, Event::ReleaseResponse) => {
+                (Action::AR9, vec![Effect::Send(Pdu::ReleaseRP)], Sta11)
+            }
+            (Sta10, Event::ReleaseRp) => (Action::AR10, vec![Effect::ReleaseConfirmation], Sta12),
+            (Sta2, Event::AssociateAc(_))
+            | (Sta2, Event::AssociateRj(_))
+            | (Sta2, Event::PDataTf(_))
+            | (Sta2, Event::ReleaseRq)
+            | (Sta2, Event::ReleaseRp) => {
+                self.start_artim();
+                let abort = provider_abort(AbortRQServiceProviderReason::UnexpectedPdu);
+                (Action::AA1, vec![Effect::Send(abort)], Sta13)
+            }
+            (Sta2, Event::InvalidPdu) => {
+                self.start_artim();
+                let abort =
+                    provider_abort(AbortRQServiceProviderReason::ReasonNotSpecifiedUnrecognizedPdu);
+                (Action::AA1, vec![Effect::Send(abort)], Sta13)
+            }
+            (Sta3, Event::AbortRequest)
+            | (Sta5, Event::AbortRequest)
+            | (Sta6, Event::AbortRequest)
+            | (Sta7, Event::AbortRequest)
+            | (Sta8, Event::AbortRequest)
+            | (Sta9, Event::AbortRequest)
+            | (Sta10, Event::AbortRequest)
+            | (Sta11, Event::AbortRequest)
+            | (Sta12, Event::AbortRequest) => {
+                self.start_artim();
+                let abort = Pdu::AbortRQ {
+                    source: AbortRQSource::ServiceUser,
+                };
+                (Action::AA1, vec![Effect::Send(abort)], Sta13)
+            }
+            (Sta4, Event::AbortRequest)
+            | (Sta2, Event::Abort(_))
+            | (Sta13, Event::Abort(_))
+            | (Sta2, Event::ArtimExpired)
+            | (Sta13, Event::ArtimExpired) => {
+                self.stop_artim();
+                self.pending_request = None;
+                (Action::AA2, vec![Effect::Close], Sta1)
+            }
+            (state, Event::Abort(source)) if is_association_state(state) || state == Sta3 => {
+                let indication = match source {
+                    AbortRQSource::ServiceUser => Effect::AbortIndication,
+                    AbortRQSource::ServiceProvider(reason) => {
+                        Effect::ProviderAbortIndication(Some(reason))
+                    }
+                    AbortRQSource::Reserved => Effect::ProviderAbortIndication(None),
+                };
+                (Action::AA3, vec![indication, Effect::Close], Sta1)
+            }
+            (state, Event::TransportClosed)
+                if is_association_state(state) || state == Sta3 || state == Sta4 =>
+            {
+                self.pending_request = None;
+                (
+                    Action::AA4,
+                    vec![Effect::ProviderAbortIndication(None)],
+                    Sta1,
+                )
+            }
+            (Sta2, Event::TransportClosed) => {
+                self.stop_artim();
+                (Action::AA5, vec![], Sta1)
+            }
+            (Sta13, Event::AssociateAc(_))
+            | (Sta13, Event::AssociateRj(_))
+            | (Sta13, Event::PDataTf(_))
+            | (Sta13, Event::ReleaseRq)
+            | (Sta13, Event::ReleaseRp) => (Action::AA6, vec![], Sta13),
+            (Sta13, Event::AssociateRq(_)) => {
+                let abort = provider_abort(AbortRQServiceProviderReason::UnexpectedPdu);
+                (Action::AA7, vec![Effect::Send(abort)], Sta13)
+            }
+            (Sta13, Event::InvalidPdu) => {
+                let abort =
+                    provider_abort(AbortRQServiceProviderReason::ReasonNotSpecifiedUnrecognizedPdu);
+                (Action::AA7, vec![Effect::Send(abort)], Sta13)
+            }
+            (state, Event::AssociateAc(_))
+            | (state, Event::AssociateRj(_))
+            | (state, Event::AssociateRq(_))
+            | (state, Event::PDataTf(_))
+            | (state, Event::ReleaseRq)
+            | (state, Event::ReleaseRp)
+                if is_association_state(state) || state == Sta3 =>
+            {
+                self.start_artim();
+                let reason = AbortRQServiceProviderReason::UnexpectedPdu;
+                (
+                    Action::AA8,
+                    vec![
+                        Effect::Send(provider_abort(reason.clone())),
+                        Effect::ProviderAbortIndication(Some(reason)),
+                    ],
+                    Sta13,
+                )
+            }
+            (state, Event::InvalidPdu) if is_association_state(state) || state == Sta3 => {
+                self.start_artim();
+                let reason = AbortRQServiceProviderReason::ReasonNotSpecifiedUnrecognizedPdu;
+                (
+                    Action::AA8,
+                    vec![
+                        Effect::Send(provider_abort(reason.clone())),
+                        Effect::ProviderAbortIndication(Some(reason)),
+                    ],
+                    Sta13,
+                )
+            }
+            (state, _) => return Err(Error::UnexpectedEvent(number, state)),
+        };
+
+        self.state = next;
+        Ok(Transition { action, effects })
+    }
+
+    fn start_artim(&mut self) {
+        self.artim_deadline = Some(Instant::now() + self.artim_timeout);
+    }
+
+    fn stop_artim(&mut self) {
+        self.artim_deadline = None;
+    }
+}
+
+/// Whether the state is one where a transport connection is open and
+/// the association request has already been sent or accepted
+/// (Sta5 to Sta12).
+fn is_association_state(state: State) -> bool {
+    use self::State::*;
+    matches!(
+        state,
+        Sta5 | Sta6 | Sta7 | Sta8 | Sta9 | Sta10 | Sta11 | Sta12
+    )
+}
+
+/// Whether an A-ASSOCIATE-RQ PDU is acceptable by the service provider,
+/// which only requires version 1 of the protocol to be supported.
+fn is_acceptable(pdu: &Pdu) -> bool {
+    match pdu {
+        Pdu::AssociationRQ {
+            protocol_version, ..
+        } => protocol_version & 0x01 != 0,
+        _ => false,
+    }
+}
+
+fn provider_abort(reason: AbortRQServiceProviderReason) -> Pdu {
+    Pdu::AbortRQ {
+        source: AbortRQSource::ServiceProvider(reason),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdu::reader::{read_pdu, DEFAULT_MAX_PDU};
+    use crate::pdu::writer::write_pdu;
+    use crate::pdu::{
+        PDataValue, PDataValueType, PresentationContextProposed, PresentationContextResult,
+        PresentationContextResultReason,
+    };
+    use std::io::Cursor;
+
+    fn association_rq() -> Pdu {
+        Pdu::AssociationRQ {
+            protocol_version: 1,
+            calling_ae_title: "STORE-SCU".to_string(),
+            called_ae_title: "STORE-SCP".to_string(),
+            application_context_name: "1.2.840.10008.3.1.1.1".to_string(),
+            presentation_contexts: vec![PresentationContextProposed {
+                id: 1,
+                abstract_syntax: "1.2.840.10008.1.1".to_string(),
+                transfer_syntaxes: vec!["1.2.840.10008.1.2".to_string()],
+            }],
+            user_variables: vec![],
+        }
+    }
+
+    fn association_ac() -> Pdu {
+        Pdu::AssociationAC {
+            protocol_version: 1,
+            application_context_name: "1.2.840.10008.3.1.1.1".to_string(),
+            presentation_contexts: vec![PresentationContextResult {
+                id: 1,
+                reason: PresentationContextResultReason::Acceptance,
+                transfer_syntax: "1.2.840.10008.1.2".to_string(),
+            }],
+            user_variables: vec![],
+        }
+    }
+
+    fn pdata() -> Pdu {
+        Pdu::PData {
+            data: vec![PDataValue {
+                presentation_context_id: 1,
+                value_type: PDataValueType::Command,
+                is_last: true,
+                data: vec![0x55; 8],
+            }],
+        }
+    }
+
+    /// Carry the PDUs sent in the given effects to the peer through an
+    /// in-memory stream, returning the transitions of the peer.
+    fn deliver(effects: &[Effect], peer: &mut StateMachine) -> Result<Vec<Transition>> {
+        let mut stream = Vec::new();
+        for effect in effects {
+            if let Effect::Send(pdu) = effect {
+                write_pdu(&mut stream, pdu)?;
+            }
+        }
+
+        let mut stream = Cursor::new(stream);
+        let mut transitions = Vec::new();
+        loop {
+            match read_pdu(&mut stream, DEFAULT_MAX_PDU) {
+                Ok(pdu) => transitions.push(peer.receive(pdu)?),
+                Err(Error::NoPduAvailable) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(transitions)
+    }
+
+    /// Bring a requestor and an acceptor to an established association.
+    fn associate() -> Result<(StateMachine, StateMachine)> {
+        let mut requestor = StateMachine::new();
+        let mut acceptor = StateMachine::new();
+
+        let t = requestor.handle(Event::AssociateRequest(association_rq()))?;
+        assert_eq!(t.effects, vec![Effect::Connect]);
+        assert_eq!(requestor.state(), State::Sta4);
+
+        acceptor.handle(Event::TransportConnectIndication)?;
+        assert_eq!(acceptor.state(), State::Sta2);
+        assert!(acceptor.artim_deadline().is_some());
+
+        let t = requestor.handle(Event::TransportConnectConfirm)?;
+        assert_eq!(t.action, Action::AE2);
+        assert_eq!(requestor.state(), State::Sta5);
+
+        let ts = deliver(&t.effects, &mut acceptor)?;
+        assert_eq!(ts.len(), 1);
+        assert_eq!(ts[0].action, Action::AE6);
+        assert_eq!(
+            ts[0].effects,
+            vec![Effect::AssociateIndication(association_rq())]
+        );
+        assert_eq!(acceptor.state(), State::Sta3);
+        assert_eq!(acceptor.artim_deadline(), None);
+
+        let t = acceptor.handle(Event::AssociateAccept(association_ac()))?;
+        assert_eq!(acceptor.state(), State::Sta6);
+
+        let ts = deliver(&t.effects, &mut requestor)?;
+        assert_eq!(ts[0].action, Action::AE3);
+        assert_eq!(
+            ts[0].effects,
+            vec![Effect::AssociateConfirmation(association_ac())]
+        );
+        assert_eq!(requestor.state(), State::Sta6);
+
+        Ok((requestor, acceptor))
+    }
+
+    #[test]
+    fn associate_transfer_and_release() -> Result<()> {
+        let (mut requestor, mut acceptor) = associate()?;
+
+        let t = requestor.handle(Event::PDataRequest(pdata()))?;
+        assert_eq!(t.action, Action::DT1);
+        let ts = deliver(&t.effects, &mut acceptor)?;
+        assert_eq!(ts[0].action, Action::DT2);
+        assert_eq!(ts[0].effects, vec![Effect::PDataIndication(pdata())]);
+
+        let t = requestor.handle(Event::ReleaseRequest)?;
+        assert_eq!(requestor.state(), State::Sta7);
+        let ts = deliver(&t.effects, &mut acceptor)?;
+        assert_eq!(ts[0].action, Action::AR2);
+        assert_eq!(acceptor.state(), State::Sta8);
+
+        // data can still be sent while the release is pending
+        let t = acceptor.handle(Event::PDataRequest(pdata()))?;
+        assert_eq!(t.action, Action::AR7);
+        let ts = deliver(&t.effects, &mut requestor)?;
+        assert_eq!(ts[0].action, Action::AR6);
+        assert_eq!(requestor.state(), State::Sta7);
+
+        let t = acceptor.handle(Event::ReleaseResponse)?;
+        assert_eq!(t.action, Action::AR4);
+        assert_eq!(acceptor.state(), State::Sta13);
+        assert!(acceptor.artim_deadline().is_some());
+
+        let ts = deliver(&t.effects, &mut requestor)?;
+        assert_eq!(ts[0].action, Action::AR3);
+        assert_eq!(
+            ts[0].effects,
+            vec![Effect::ReleaseConfirmation, Effect::Close]
+        );
+        assert_eq!(requestor.state(), State::Sta1);
+
+        let t = acceptor.handle(Event::TransportClosed)?;
+        assert_eq!(t.action, Action::AR5);
+        assert_eq!(acceptor.state(), State::Sta1);
+        assert_eq!(acceptor.artim_deadline(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn release_collision() -> Result<()> {
+        let (mut requestor, mut acceptor) = associate()?;
+
+        let to_acceptor = requestor.handle(Event::ReleaseRequest)?;
+        let to_requestor = acceptor.handle(Event::ReleaseRequest)?;
+
+        let ts = deliver(&to_requestor.effects, &mut requestor)?;
+        assert_eq!(ts[0].action, Action::AR8);
+        assert_eq!(requestor.state(), State::Sta9);
+        let ts = deliver(&to_acceptor.effects, &mut acceptor)?;
+        assert_eq!(ts[0].action, Action::AR8);
+        assert_eq!(acceptor.state(), State::Sta10);
+
+        let t = requestor.handle(Event::ReleaseResponse)?;
+        assert_eq!(t.action, Action::AR9);
+        assert_eq!(requestor.state(), State::Sta11);
+
+        let ts = deliver(&t.effects, &mut acceptor)?;
+        assert_eq!(ts[0].action, Action::AR10);
+        assert_eq!(acceptor.state(), State::Sta12);
+
+        let t = acceptor.handle(Event::ReleaseResponse)?;
+        assert_eq!(t.action, Action::AR4);
+        assert_eq!(acceptor.state(), State::Sta13);
+
+        let ts = deliver(&t.effects, &mut requestor)?;
+        assert_eq!(ts[0].action, Action::AR3);
+        assert_eq!(requestor.state(), State::Sta1);
+
+        acceptor.handle(Event::TransportClosed)?;
+        assert_eq!(acceptor.state(), State::Sta1);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_and_artim_expiry() -> Result<()> {
+        let mut acceptor = StateMachine::new().with_artim_timeout(Duration::from_secs(5));
+        acceptor.handle(Event::TransportConnectIndication)?;
+        acceptor.receive(association_rq())?;
+
+        let rj = Pdu::AssociationRJ {
+            result: AssociationRJResult::Transient,
+            source: AssociationRJSource::ServiceProviderASCE(
+                AssociationRJServiceProviderASCEReason::NoReasonGiven,
+            ),
+        };
+        let t = acceptor.handle(Event::AssociateReject(rj.clone()))?;
+        assert_eq!(t.action, Action::AE8);
+        assert_eq!(t.effects, vec![Effect::Send(rj)]);
+        assert_eq!(acceptor.state(), State::Sta13);
+
+        let deadline = acceptor.artim_deadline().unwrap();
+        assert_eq!(acceptor.check_artim(Instant::now())?, None);
+        let t = acceptor.check_artim(deadline)?.unwrap();
+        assert_eq!(t.action, Action::AA2);
+        assert_eq!(t.effects, vec![Effect::Close]);
+        assert_eq!(acceptor.state(), State::Sta1);
+        assert_eq!(acceptor.check_artim(deadline)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_protocol_version_is_rejected() -> Result<()> {
+        let mut acceptor = StateMachine::new();
+        acceptor.handle(Event::TransportConnectIndication)?;
+
+        let rq = match association_rq() {
+            Pdu::AssociationRQ {
+                calling_ae_title,
+                called_ae_title,
+                application_context_name,
+                presentation_contexts,
+                user_variables,
+                ..
+            } => Pdu::AssociationRQ {
+                protocol_version: 2,
+                calling_ae_title,
+                called_ae_title,
+                application_context_name,
+                presentation_contexts,
+                user_variables,
+            },
+            _ => unreachable!(),
+        };
+        let t = acceptor.receive(rq)?;
+        assert_eq!(t.action, Action::AE6);
+        assert_eq!(
+            t.effects,
+            vec![Effect::Send(Pdu::AssociationRJ {
+                result: AssociationRJResult::Permanent,
+                source: AssociationRJSource::ServiceProviderASCE(
+                    AssociationRJServiceProviderASCEReason::ProtocolVersionNotSupported,
+                ),
+            })]
+        );
+        assert_eq!(acceptor.state(), State::Sta13);
+        assert!(acceptor.artim_deadline().is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn unexpected_pdu_aborts_association() -> Result<()> {
+        let (mut requestor, mut acceptor) = associate()?;
+
+        let t = acceptor.receive(association_rq())?;
+        assert_eq!(t.action, Action::AA8);
+        assert_eq!(
+            t.effects[1],
+            Effect::ProviderAbortIndication(Some(AbortRQServiceProviderReason::UnexpectedPdu))
+        );
+        assert_eq!(acceptor.state(), State::Sta13);
+
+        let ts = deliver(&t.effects, &mut requestor)?;
+        assert_eq!(ts[0].action, Action::AA3);
+        assert_eq!(
+            ts[0].effects,
+            vec![
+                Effect::ProviderAbortIndication(Some(AbortRQServiceProviderReason::UnexpectedPdu)),
+                Effect::Close
+            ]
+        );
+        assert_eq!(requestor.state(), State::Sta1);
+
+        // PDUs arriving while awaiting the transport to close are ignored
+        let t = acceptor.receive(pdata())?;
+        assert_eq!(t.action, Action::AA6);
+        assert_eq!(acceptor.state(), State::Sta13);
+        Ok(())
+    }
+
+    #[test]
+    fn user_abort() -> Result<()> {
+        let (mut requestor, mut acceptor) = associate()?;
+
+        let t = requestor.handle(Event::AbortRequest)?;
+        assert_eq!(t.action, Action::AA1);
+        assert_eq!(requestor.state(), State::Sta13);
+
+        let ts = deliver(&t.effects, &mut acceptor)?;
+        assert_eq!(ts[0].action, Action::AA3);
+        assert_eq!(ts[0].effects, vec![Effect::AbortIndication, Effect::Close]);
+        assert_eq!(acceptor.state(), State::Sta1);
+
+        let t = requestor.handle(Event::TransportClosed)?;
+        assert_eq!(t.action, Action::AR5);
+        assert_eq!(requestor.state(), State::Sta1);
+        Ok(())
+    }
+
+    #[test]
+    fn transport_loss_is_provider_abort() -> Result<()> {
+        let (mut requestor, _) = associate()?;
+        let t = requestor.handle(Event::TransportClosed)?;
+        assert_eq!(t.action, Action::AA4);
+        assert_eq!(t.effects, vec![Effect::ProviderAbortIndication(None)]);
+        assert_eq!(requestor.state(), State::Sta1);
+        Ok(())
+    }
+
+    #[test]
+    fn events_out_of_place_are_refused() {
+        let mut fsm = StateMachine::new();
+        match fsm.handle(Event::ReleaseRequest) {
+            Err(Error::UnexpectedEvent(11, State::Sta1)) => {}
+            e => panic!("unexpected outcome {:?}", e),
+        }
+        assert_eq!(fsm.state(), State::Sta1);
+
+        fsm.handle(Event::AssociateRequest(association_rq()))
+            .unwrap();
+        assert!(fsm.handle(Event::PDataRequest(pdata())).is_err());
+        assert_eq!(fsm.state(), State::Sta4);
+    }
+}
@@ -2,8 +2,23 @@
 //!
 //! It is very low level and not usable as is.
 //!
-//! Eventually, a finite-state-machine and higher-level SCU/SCP helpers will be added that will make
-//! interacting with these types more idiomatic and friendly.
+//! The `fsm` module provides the upper layer protocol state machine, which drives an
+//! association from the PDUs exchanged with the peer and the primitives issued by the local user.
+//!
+//! The `association` module builds on top of it to establish associations over TCP,
+//! with [`ClientAssociationOptions`](association/client/struct.ClientAssociationOptions.html)
+//! for service class users and
+//! [`ServerAssociationOptions`](association/server/struct.ServerAssociationOptions.html)
+//! for service class providers.
+//!
+//! The `dimse` module models the DIMSE command sets
+//! exchanged as the first part of every message on an association,
+//! and the `services` module builds ready-to-use service classes on top of them,
+//! such as [verification](services/verification/index.html).
 
+pub mod association;
+pub mod dimse;
 pub mod error;
+pub mod fsm;
 pub mod pdu;
+pub mod services;
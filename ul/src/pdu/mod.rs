@@ -53,8 +53,8 @@ pub enum AssociationRJResult {
 impl AssociationRJResult {
     fn from(value: u8) -> Option<AssociationRJResult> {
         match value {
-            0 => Some(AssociationRJResult::Permanent),
-            1 => Some(AssociationRJResult::Transient),
+            1 => Some(AssociationRJResult::Permanent),
+            2 => Some(AssociationRJResult::Transient),
             _ => None,
         }
     }
@@ -219,6 +219,11 @@ pub enum UserVariableItem {
     MaxLength(u32),
     ImplementationClassUID(String),
     ImplementationVersionName(String),
+    RoleSelection {
+        sop_class_uid: String,
+        scu_role: bool,
+        scp_role: bool,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, PartialOrd, Hash, Debug)]
@@ -582,6 +582,32 @@ where
                             implementation_class_uid,
                         ));
                     }
+                    0x54 => {
+                        // SCP/SCU Role Selection Sub-Item Structure
+
+                        // 5-6 - UID-length - The number of bytes from the first byte
+                        // of the following field to the last byte of the SOP-class-uid field.
+                        let uid_length = cursor.read_u16::<BigEndian>()?;
+
+                        // 7 - xxx - SOP-class-uid - The SOP class UID
+                        // to which the role selection applies.
+                        let sop_class_uid = codec
+                            .decode(&read_n(&mut cursor, uid_length as usize)?)?
+                            .trim_end_matches(|c: char| c.is_whitespace() || c == '\0')
+                            .to_string();
+
+                        // xxx - SCU-role - 0 for non support of the SCU role, 1 for support.
+                        let scu_role = cursor.read_u8()? != 0;
+
+                        // xxx - SCP-role - 0 for non support of the SCP role, 1 for support.
+                        let scp_role = cursor.read_u8()? != 0;
+
+                        user_variables.push(UserVariableItem::RoleSelection {
+                            sop_class_uid,
+                            scu_role,
+                            scp_role,
+                        });
+                    }
                     0x55 => {
                         // Implementation Version Name Structure
 
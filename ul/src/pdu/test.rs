@@ -115,6 +115,36 @@ fn can_read_write_associate_rq() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn can_read_write_role_selection() -> Result<()> {
+    let role_selection = UserVariableItem::RoleSelection {
+        sop_class_uid: "1.2.840.10008.5.1.4.1.1.7".to_string(),
+        scu_role: false,
+        scp_role: true,
+    };
+    let association_ac = Pdu::AssociationAC {
+        protocol_version: 1,
+        application_context_name: "application context name".to_string(),
+        presentation_contexts: vec![],
+        user_variables: vec![UserVariableItem::MaxLength(23), role_selection.clone()],
+    };
+
+    let mut bytes = vec![0u8; 0];
+    write_pdu(&mut bytes, &association_ac)?;
+
+    match read_pdu(&mut Cursor::new(&bytes), DEFAULT_MAX_PDU)? {
+        Pdu::AssociationAC { user_variables, .. } => {
+            assert_eq!(
+                user_variables,
+                vec![UserVariableItem::MaxLength(23), role_selection]
+            );
+        }
+        pdu => panic!("invalid pdu type {:?}", pdu),
+    }
+
+    Ok(())
+}
+
 #[test]
 fn can_read_write_pdata() -> Result<()> {
     let pdata_rq = Pdu::PData {
@@ -143,3 +173,45 @@ fn can_read_write_pdata() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn can_read_write_abort() -> Result<()> {
+    let sources = vec![
+        AbortRQSource::ServiceUser,
+        AbortRQSource::Reserved,
+        AbortRQSource::ServiceProvider(AbortRQServiceProviderReason::UnexpectedPdu),
+        AbortRQSource::ServiceProvider(AbortRQServiceProviderReason::InvalidPduParameter),
+    ];
+
+    for source in sources {
+        let abort_rq = Pdu::AbortRQ { source };
+
+        let mut bytes = Vec::new();
+        write_pdu(&mut bytes, &abort_rq)?;
+        assert_eq!(bytes.len(), 10);
+
+        let result = read_pdu(&mut Cursor::new(&bytes), DEFAULT_MAX_PDU)?;
+        assert_eq!(result, abort_rq);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn can_read_write_associate_rj() -> Result<()> {
+    let associate_rj = Pdu::AssociationRJ {
+        result: AssociationRJResult::Permanent,
+        source: AssociationRJSource::ServiceUser(
+            AssociationRJServiceUserReason::CalledAETitleNotRecognized,
+        ),
+    };
+
+    let mut bytes = Vec::new();
+    write_pdu(&mut bytes, &associate_rj)?;
+    assert_eq!(bytes[7], 0x01);
+
+    let result = read_pdu(&mut Cursor::new(&bytes), DEFAULT_MAX_PDU)?;
+    assert_eq!(result, associate_rj);
+
+    Ok(())
+}
@@ -402,29 +402,32 @@ where
                         writer.write_u8(0x00)?;
                     }
                     AbortRQSource::Reserved => {
-                        writer.write_u8(0x00)?;
+                        writer.write_u8(0x01)?;
                         writer.write_u8(0x00)?;
                     }
-                    AbortRQSource::ServiceProvider(reason) => match reason {
-                        AbortRQServiceProviderReason::ReasonNotSpecifiedUnrecognizedPdu => {
-                            writer.write_u8(0x00)?;
-                        }
-                        AbortRQServiceProviderReason::UnexpectedPdu => {
-                            writer.write_u8(0x02)?;
-                        }
-                        AbortRQServiceProviderReason::Reserved => {
-                            writer.write_u8(0x03)?;
-                        }
-                        AbortRQServiceProviderReason::UnrecognizedPduParameter => {
-                            writer.write_u8(0x04)?;
-                        }
-                        AbortRQServiceProviderReason::UnexpectedPduParameter => {
-                            writer.write_u8(0x05)?;
-                        }
-                        AbortRQServiceProviderReason::InvalidPduParameter => {
-                            writer.write_u8(0x06)?;
+                    AbortRQSource::ServiceProvider(reason) => {
+                        writer.write_u8(0x02)?;
+                        match reason {
+                            AbortRQServiceProviderReason::ReasonNotSpecifiedUnrecognizedPdu => {
+                                writer.write_u8(0x00)?;
+                            }
+                            AbortRQServiceProviderReason::UnexpectedPdu => {
+                                writer.write_u8(0x02)?;
+                            }
+                            AbortRQServiceProviderReason::Reserved => {
+                                writer.write_u8(0x03)?;
+                            }
+                            AbortRQServiceProviderReason::UnrecognizedPduParameter => {
+                                writer.write_u8(0x04)?;
+                            }
+                            AbortRQServiceProviderReason::UnexpectedPduParameter => {
+                                writer.write_u8(0x05)?;
+                            }
+                            AbortRQServiceProviderReason::InvalidPduParameter => {
+                                writer.write_u8(0x06)?;
+                            }
                         }
-                    },
+                    }
                 }
 
                 Ok(())
@@ -733,6 +736,36 @@ fn write_pdu_variable_user_variables(
                         Ok(())
                     })?;
                 }
+                UserVariableItem::RoleSelection {
+                    sop_class_uid,
+                    scu_role,
+                    scp_role,
+                } => {
+                    // 1 - Item-type - 54H
+                    writer.write_u8(0x54)?;
+
+                    // 2 - Reserved - This reserved field shall be sent with a value 00H but not
+                    // tested to this value when received.
+                    writer.write_u8(0x00)?;
+
+                    write_chunk_u16(writer, |writer| {
+                        // 5-6 - UID-length, followed by
+                        // 7 - xxx - SOP-class-uid - The SOP class UID
+                        // to which the role selection applies.
+                        write_chunk_u16(writer, |writer| {
+                            writer.write_all(&codec.encode(sop_class_uid)?)?;
+                            Ok(())
+                        })?;
+
+                        // xxx - SCU-role - 0 for non support of the SCU role, 1 for support.
+                        writer.write_u8(*scu_role as u8)?;
+
+                        // xxx - SCP-role - 0 for non support of the SCP role, 1 for support.
+                        writer.write_u8(*scp_role as u8)?;
+
+                        Ok(())
+                    })?;
+                }
                 UserVariableItem::Unknown(item_type, data) => {
                     writer.write_u8(*item_type)?;
 
@@ -0,0 +1,63 @@
+//! Ready-to-use DIMSE service classes.
+//!
+//! Each module implements both roles of a service class
+//! on top of the associations of the [`association`](../association/index.html)
+//! module and the command sets of the [`dimse`](../dimse/index.html) module.
+
+pub mod query;
+pub mod retrieve;
+pub mod storage;
+pub mod verification;
+
+#[cfg(test)]
+mod testing;
+
+use crate::association::PresentationContext;
+use crate::error::{Error, Result};
+use dicom_dictionary_std::uid::trim_uid;
+use dicom_dictionary_std::StandardDataDictionary;
+use dicom_encoding::transfer_syntax::TransferSyntaxIndex;
+use dicom_encoding::TransferSyntax;
+use dicom_object::mem::InMemDicomObject;
+use dicom_object::{ReadLimits, ReadOptions};
+use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
+
+/// Find the ID of an accepted presentation context
+/// for the given abstract syntax.
+pub(crate) fn presentation_context_for(
+    presentation_contexts: &[PresentationContext],
+    abstract_syntax: &str,
+) -> Result<u8> {
+    presentation_contexts
+        .iter()
+        .find(|pc| pc.abstract_syntax == abstract_syntax)
+        .map(|pc| pc.id)
+        .ok_or_else(|| Error::NoPresentationContext(abstract_syntax.to_string()))
+}
+
+/// Find the transfer syntax of an accepted presentation context.
+pub(crate) fn transfer_syntax_of(
+    presentation_contexts: &[PresentationContext],
+    presentation_context_id: u8,
+) -> Result<&'static TransferSyntax> {
+    let uid = presentation_contexts
+        .iter()
+        .find(|pc| pc.id == presentation_context_id)
+        .map(|pc| trim_uid(&pc.transfer_syntax))
+        .ok_or(Error::UnknownPresentationContext(presentation_context_id))?;
+    TransferSyntaxRegistry
+        .get(uid)
+        .ok_or_else(|| Error::UnsupportedTransferSyntax(uid.to_string()))
+}
+
+/// Read a data set sent by the peer in the given transfer syntax,
+/// with the limits for untrusted sources.
+pub(crate) fn read_data_set(
+    data: &[u8],
+    ts: &TransferSyntax,
+) -> dicom_object::Result<InMemDicomObject<StandardDataDictionary>> {
+    ReadOptions::new()
+        .limits(ReadLimits::untrusted())
+        .read_dataset(data, ts)
+        .map(|(obj, _)| obj)
+}
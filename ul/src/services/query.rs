@@ -0,0 +1,669 @@
+//! Query/Retrieve service class, FIND operations (PS3.4 Annex C).
+//!
+//! This module implements C-FIND for the Patient Root
+//! and Study Root query/retrieve information models.
+//! The [`find`](fn.find.html) function sends a query as a service class user
+//! and yields the matches as they arrive,
+//! while [`FindScp`](struct.FindScp.html) answers queries
+//! from the matches produced by a [`FindBackend`](trait.FindBackend.html).
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use dicom_core::{DataElement, PrimitiveValue, VR};
+//! # use dicom_dictionary_std::tags;
+//! # use dicom_object::mem::InMemDicomObject;
+//! # use dicom_ul::association::ClientAssociationOptions;
+//! # use dicom_ul::services::query::{self, InformationModel, QueryLevel};
+//! # fn run() -> dicom_ul::error::Result<()> {
+//! let model = InformationModel::StudyRoot;
+//! let mut association = ClientAssociationOptions::new()
+//!     .with_presentation_context(model.find_sop_class(), vec!["1.2.840.10008.1.2"])
+//!     .establish("129.168.0.5:104")?;
+//! let mut identifier = QueryLevel::Study.identifier();
+//! identifier.put(DataElement::new(
+//!     tags::PATIENT_ID,
+//!     VR::LO,
+//!     PrimitiveValue::Strs(["12345".to_string()].as_ref().into()).into(),
+//! ));
+//! identifier.put(DataElement::empty(tags::STUDY_INSTANCE_UID, VR::UI));
+//! for study in query::find(&mut association, model, 1, &identifier)? {
+//!     let study = study?;
+//!     println!("{:?}", study.element(tags::STUDY_INSTANCE_UID));
+//! }
+//! association.release()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::verification::{self, VERIFICATION_SOP_CLASS};
+use super::{presentation_context_for, read_data_set, transfer_syntax_of};
+use crate::association::{ClientAssociation, ServerAssociation, ServerAssociationOptions};
+use crate::dimse::{CCancelRq, CFindRq, CFindRsp, Command, Priority, Status};
+use crate::error::{Error, Result};
+use dicom_core::{DataElement, PrimitiveValue, VR};
+use dicom_dictionary_std::uid::trim_uid;
+use dicom_dictionary_std::{tags, StandardDataDictionary};
+use dicom_encoding::TransferSyntax;
+use dicom_object::mem::InMemDicomObject;
+use std::net::TcpStream;
+
+/// The UID of the Patient Root Query/Retrieve Information Model - FIND SOP class.
+pub const PATIENT_ROOT_FIND_SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.2.1.1";
+
+/// The UID of the Study Root Query/Retrieve Information Model - FIND SOP class.
+pub const STUDY_ROOT_FIND_SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.2.2.1";
+
+/// The UID of the Patient Root Query/Retrieve Information Model - MOVE SOP class.
+pub const PATIENT_ROOT_MOVE_SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.2.1.2";
+
+/// The UID of the Patient Root Query/Retrieve Information Model - GET SOP class.
+pub const PATIENT_ROOT_GET_SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.2.1.3";
+
+/// The UID of the Study Root Query/Retrieve Information Model - MOVE SOP class.
+pub const STUDY_ROOT_MOVE_SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.2.2.2";
+
+/// The UID of the Study Root Query/Retrieve Information Model - GET SOP class.
+pub const STUDY_ROOT_GET_SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.2.2.3";
+
+/// Failed: the identifier does not match the SOP class.
+pub const IDENTIFIER_MISMATCH: Status = Status(0xA900);
+
+/// Failed: the query could not be processed.
+pub const UNABLE_TO_PROCESS: Status = Status(0xC000);
+
+/// A query/retrieve information model.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum InformationModel {
+    /// The Patient Root information model,
+    /// with patient, study, series and image levels.
+    PatientRoot,
+    /// The Study Root information model,
+    /// with study, series and image levels.
+    StudyRoot,
+}
+
+impl InformationModel {
+    /// Obtain the UID of the FIND SOP class of this model.
+    pub fn find_sop_class(self) -> &'static str {
+        match self {
+            InformationModel::PatientRoot => PATIENT_ROOT_FIND_SOP_CLASS,
+            InformationModel::StudyRoot => STUDY_ROOT_FIND_SOP_CLASS,
+        }
+    }
+
+    /// Obtain the model of the given FIND SOP class.
+    pub fn from_find_sop_class(uid: &str) -> Option<Self> {
+        match trim_uid(uid) {
+            PATIENT_ROOT_FIND_SOP_CLASS => Some(InformationModel::PatientRoot),
+            STUDY_ROOT_FIND_SOP_CLASS => Some(InformationModel::StudyRoot),
+            _ => None,
+        }
+    }
+
+    /// Obtain the UID of the MOVE SOP class of this model.
+    pub fn move_sop_class(self) -> &'static str {
+        match self {
+            InformationModel::PatientRoot => PATIENT_ROOT_MOVE_SOP_CLASS,
+            InformationModel::StudyRoot => STUDY_ROOT_MOVE_SOP_CLASS,
+        }
+    }
+
+    /// Obtain the model of the given MOVE SOP class.
+    pub fn from_move_sop_class(uid: &str) -> Option<Self> {
+        match trim_uid(uid) {
+            PATIENT_ROOT_MOVE_SOP_CLASS => Some(InformationModel::PatientRoot),
+            STUDY_ROOT_MOVE_SOP_CLASS => Some(InformationModel::StudyRoot),
+            _ => None,
+        }
+    }
+
+    /// Obtain the UID of the GET SOP class of this model.
+    pub fn get_sop_class(self) -> &'static str {
+        match self {
+            InformationModel::PatientRoot => PATIENT_ROOT_GET_SOP_CLASS,
+            InformationModel::StudyRoot => STUDY_ROOT_GET_SOP_CLASS,
+        }
+    }
+
+    /// Obtain the model of the given GET SOP class.
+    pub fn from_get_sop_class(uid: &str) -> Option<Self> {
+        match trim_uid(uid) {
+            PATIENT_ROOT_GET_SOP_CLASS => Some(InformationModel::PatientRoot),
+            STUDY_ROOT_GET_SOP_CLASS => Some(InformationModel::StudyRoot),
+            _ => None,
+        }
+    }
+
+    /// Check whether the given level exists in this model.
+    pub fn has_level(self, level: QueryLevel) -> bool {
+        self == InformationModel::PatientRoot || level != QueryLevel::Patient
+    }
+}
+
+/// The level of a query, as in Query/Retrieve Level (0008,0052).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum QueryLevel {
+    Patient,
+    Study,
+    Series,
+    Image,
+}
+
+impl QueryLevel {
+    /// Obtain the level from its code string.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim() {
+            "PATIENT" => Some(QueryLevel::Patient),
+            "STUDY" => Some(QueryLevel::Study),
+            "SERIES" => Some(QueryLevel::Series),
+            "IMAGE" => Some(QueryLevel::Image),
+            _ => None,
+        }
+    }
+
+    /// Obtain the code string of the level.
+    pub fn code(self) -> &'static str {
+        match self {
+            QueryLevel::Patient => "PATIENT",
+            QueryLevel::Study => "STUDY",
+            QueryLevel::Series => "SERIES",
+            QueryLevel::Image => "IMAGE",
+        }
+    }
+
+    /// Read the level of an identifier.
+    pub fn of(identifier: &InMemDicomObject<StandardDataDictionary>) -> Option<Self> {
+        identifier
+            .element(tags::QUERY_RETRIEVE_LEVEL)
+            .ok()
+            .and_then(|e| e.to_str().ok())
+            .and_then(|code| QueryLevel::from_code(&code))
+    }
+
+    /// Create an identifier with only the query/retrieve level set to this one.
+    pub fn identifier(self) -> InMemDicomObject<StandardDataDictionary> {
+        let mut identifier = InMemDicomObject::create_empty();
+        identifier.put(self.element());
+        identifier
+    }
+
+    /// Create the Query/Retrieve Level element with this level.
+    pub(crate) fn element(self) -> DataElement<InMemDicomObject<StandardDataDictionary>> {
+        DataElement::new(
+            tags::QUERY_RETRIEVE_LEVEL,
+            VR::CS,
+            PrimitiveValue::Strs([self.code().to_string()].as_ref().into()).into(),
+        )
+    }
+}
+
+/// Send a C-FIND request with the given identifier,
+/// returning an iterator over the matches
+/// in the pending responses of the service class provider.
+///
+/// Fails with `Error::NoPresentationContext`
+/// if the FIND SOP class of the model was not accepted on the association.
+pub fn find<'a>(
+    association: &'a mut ClientAssociation,
+    model: InformationModel,
+    message_id: u16,
+    identifier: &InMemDicomObject<StandardDataDictionary>,
+) -> Result<FindResponses<'a>> {
+    let presentation_context_id =
+        presentation_context_for(association.presentation_contexts(), model.find_sop_class())?;
+    let ts = transfer_syntax_of(association.presentation_contexts(), presentation_context_id)?;
+
+    let mut data = Vec::new();
+    identifier.write_dataset_with_ts(&mut data, ts)?;
+    let request = Command::CFindRq(CFindRq {
+        message_id,
+        affected_sop_class_uid: model.find_sop_class().to_string(),
+        priority: Priority::Medium,
+    });
+    association.send_message(presentation_context_id, &request.encode(), Some(&data))?;
+
+    Ok(FindResponses {
+        association,
+        presentation_context_id,
+        message_id,
+        transfer_syntax: ts,
+        status: None,
+        failed: false,
+    })
+}
+
+/// An iterator over the matches of a C-FIND operation,
+/// created by [`find`](fn.find.html).
+///
+/// The iterator ends on the final response,
+/// whose status is then available through [`status`](#method.status),
+/// or after the first error.
+pub struct FindResponses<'a> {
+    association: &'a mut ClientAssociation,
+    presentation_context_id: u8,
+    message_id: u16,
+    transfer_syntax: &'static TransferSyntax,
+    status: Option<Status>,
+    failed: bool,
+}
+
+impl<'a> FindResponses<'a> {
+    /// Retrieve the status of the final response,
+    /// or `None` if it was not received yet.
+    pub fn status(&self) -> Option<Status> {
+        self.status
+    }
+
+    /// Request the service class provider to cancel the operation.
+    ///
+    /// Matches which were already sent may still be received,
+    /// and the final response should have the cancel status.
+    pub fn cancel(&mut self) -> Result<()> {
+        let request = Command::CCancelRq(CCancelRq {
+            message_id_being_responded_to: self.message_id,
+        });
+        self.association
+            .send_message(self.presentation_context_id, &request.encode(), None)
+    }
+
+    /// Receive the next response, returning its identifier if it is pending.
+    fn next_match(&mut self) -> Result<Option<InMemDicomObject<StandardDataDictionary>>> {
+        loop {
+            let message = self.association.receive_message()?;
+            let response = match Command::decode(&message.command)? {
+                Command::CFindRsp(response) => response,
+                command => return Err(Error::UnexpectedCommand(command.command_field())),
+            };
+            if response.message_id_being_responded_to != self.message_id {
+                return Err(Error::UnexpectedMessageId(
+                    response.message_id_being_responded_to,
+                ));
+            }
+            if !response.status.is_pending() {
+                self.status = Some(response.status);
+                return Ok(None);
+            }
+            if let Some(data) = message.data {
+                let identifier = read_data_set(&data[..], self.transfer_syntax)?;
+                return Ok(Some(identifier));
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for FindResponses<'a> {
+    type Item = Result<InMemDicomObject<StandardDataDictionary>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.status.is_some() || self.failed {
+            return None;
+        }
+        match self.next_match() {
+            Ok(identifier) => identifier.map(Ok),
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// A source of matches for C-FIND queries,
+/// such as the index of an archive.
+pub trait FindBackend {
+    /// Find the entities at the given level which match the identifier,
+    /// returning an identifier for each one
+    /// with the values of the keys requested.
+    ///
+    /// The Query/Retrieve Level is added to each match if it is missing.
+    fn find<'a>(
+        &'a self,
+        level: QueryLevel,
+        identifier: &InMemDicomObject<StandardDataDictionary>,
+    ) -> Box<dyn Iterator<Item = InMemDicomObject<StandardDataDictionary>> + 'a>;
+}
+
+/// A query/retrieve FIND service class provider,
+/// which answers C-FIND requests with the matches of a backend.
+///
+/// Both the Patient Root and the Study Root information models are accepted,
+/// as well as verification requests.
+/// The matches are sent one at a time,
+/// so that a C-CANCEL request from the service class user
+/// stops the operation before the next match.
+#[derive(Debug, Clone)]
+pub struct FindScp<B> {
+    /// the backend producing the matches
+    backend: B,
+    /// the called AE title admitted, any title is admitted if `None`
+    ae_title: Option<String>,
+    /// the transfer syntaxes accepted in order of preference,
+    /// any supported transfer syntax is accepted if empty
+    transfer_syntaxes: Vec<String>,
+}
+
+impl<B> FindScp<B>
+where
+    B: FindBackend,
+{
+    /// Create a FIND SCP answering queries with the given backend.
+    pub fn new(backend: B) -> Self {
+        FindScp {
+            backend,
+            ae_title: None,
+            transfer_syntaxes: Vec::new(),
+        }
+    }
+
+    /// Retrieve the backend of this service class provider.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Only admit associations to the given called AE title.
+    pub fn accept_called_ae_title<T: Into<String>>(mut self, ae_title: T) -> Self {
+        self.ae_title = Some(ae_title.into());
+        self
+    }
+
+    /// Include this transfer syntax in the list of transfer syntaxes accepted,
+    /// in order of preference.
+    ///
+    /// By default, or if no transfer syntax is included,
+    /// any transfer syntax in the main registry is accepted.
+    pub fn with_transfer_syntax<T: Into<String>>(mut self, transfer_syntax_uid: T) -> Self {
+        self.transfer_syntaxes.push(transfer_syntax_uid.into());
+        self
+    }
+
+    /// Build the association options which reflect
+    /// the policies of this service class provider.
+    pub fn association_options(&self) -> ServerAssociationOptions {
+        let mut options = ServerAssociationOptions::new()
+            .with_abstract_syntax(VERIFICATION_SOP_CLASS)
+            .with_abstract_syntax(PATIENT_ROOT_FIND_SOP_CLASS)
+            .with_abstract_syntax(STUDY_ROOT_FIND_SOP_CLASS);
+        if let Some(ae_title) = &self.ae_title {
+            options = options.accept_called_ae_title(ae_title.as_str());
+        }
+        for uid in &self.transfer_syntaxes {
+            options = options.with_transfer_syntax(uid.as_str());
+        }
+        options
+    }
+
+    /// Negotiate an association with a service class user
+    /// on the given TCP stream.
+    pub fn establish(&self, socket: TcpStream) -> Result<ServerAssociation> {
+        self.association_options().establish(socket)
+    }
+
+    /// Answer every query received on the association
+    /// until the requestor releases it.
+    ///
+    /// Fails with `Error::UnexpectedCommand`
+    /// on commands other than C-FIND, C-CANCEL and C-ECHO,
+    /// in which case the association is aborted.
+    pub fn serve(&self, mut association: ServerAssociation) -> Result<()> {
+        loop {
+            match self.serve_next(&mut association) {
+                Ok(()) => {}
+                Err(Error::Released) => return Ok(()),
+                Err(Error::UnexpectedCommand(field)) => {
+                    association.abort()?;
+                    return Err(Error::UnexpectedCommand(field));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Receive and answer the next request.
+    fn serve_next(&self, association: &mut ServerAssociation) -> Result<()> {
+        let message = association.receive_message()?;
+        let pc_id = message.presentation_context_id;
+        match Command::decode(&message.command)? {
+            Command::CEchoRq(request) => verification::respond(association, pc_id, &request),
+            Command::CFindRq(request) => {
+                let data = message.data.unwrap_or_default();
+                self.respond(association, pc_id, &request, &data)
+            }
+            // the operation to cancel is already over
+            Command::CCancelRq(_) => Ok(()),
+            command => Err(Error::UnexpectedCommand(command.command_field())),
+        }
+    }
+
+    /// Answer a C-FIND request,
+    /// sending a pending response for each match of the backend
+    /// and a final response.
+    pub fn respond(
+        &self,
+        association: &mut ServerAssociation,
+        presentation_context_id: u8,
+        request: &CFindRq,
+        data: &[u8],
+    ) -> Result<()> {
+        let ts = transfer_syntax_of(association.presentation_contexts(), presentation_context_id)?;
+        let query = InformationModel::from_find_sop_class(&request.affected_sop_class_uid)
+            .ok_or(Status::SOP_CLASS_NOT_SUPPORTED)
+            .and_then(|model| read_identifier(model, data, ts));
+        let (level, identifier) = match query {
+            Ok(query) => query,
+            Err(status) => {
+                return send_response(association, presentation_context_id, request, status, None)
+            }
+        };
+
+        for mut found in self.backend.find(level, &identifier) {
+            if association.poll()? {
+                let message = association.receive_message()?;
+                match Command::decode(&message.command)? {
+                    Command::CCancelRq(cancel)
+                        if cancel.message_id_being_responded_to == request.message_id =>
+                    {
+                        return send_response(
+                            association,
+                            presentation_context_id,
+                            request,
+                            Status::CANCEL,
+                            None,
+                        );
+                    }
+                    command => return Err(Error::UnexpectedCommand(command.command_field())),
+                }
+            }
+
+            if found.element(tags::QUERY_RETRIEVE_LEVEL).is_err() {
+                found.put(level.element());
+            }
+            let mut data = Vec::new();
+            if found.write_dataset_with_ts(&mut data, ts).is_err() {
+                return send_response(
+                    association,
+                    presentation_context_id,
+                    request,
+                    UNABLE_TO_PROCESS,
+                    None,
+                );
+            }
+            send_response(
+                association,
+                presentation_context_id,
+                request,
+                Status::PENDING,
+                Some(&data),
+            )?;
+        }
+        send_response(
+            association,
+            presentation_context_id,
+            request,
+            Status::SUCCESS,
+            None,
+        )
+    }
+}
+
+/// Decode the identifier of a request in the given information model,
+/// returning its level,
+/// or the status of the failure to send back to the requestor.
+pub(crate) fn read_identifier(
+    model: InformationModel,
+    data: &[u8],
+    ts: &TransferSyntax,
+) -> std::result::Result<(QueryLevel, InMemDicomObject<StandardDataDictionary>), Status> {
+    let identifier = read_data_set(data, ts).map_err(|_| UNABLE_TO_PROCESS)?;
+    match QueryLevel::of(&identifier) {
+        Some(level) if model.has_level(level) => Ok((level, identifier)),
+        _ => Err(IDENTIFIER_MISMATCH),
+    }
+}
+
+/// Send a C-FIND response to the given request.
+fn send_response(
+    association: &mut ServerAssociation,
+    presentation_context_id: u8,
+    request: &CFindRq,
+    status: Status,
+    data: Option<&[u8]>,
+) -> Result<()> {
+    let response = Command::CFindRsp(CFindRsp {
+        message_id_being_responded_to: request.message_id,
+        affected_sop_class_uid: Some(request.affected_sop_class_uid.clone()),
+        status,
+        has_data_set: data.is_some(),
+    });
+    association.send_message(presentation_context_id, &response.encode(), data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::association::ClientAssociationOptions;
+    use crate::services::testing::spawn_scp;
+    use std::net::SocketAddr;
+    use std::thread::JoinHandle;
+
+    /// A backend with one study per patient ID, repeated `count` times.
+    struct Studies {
+        count: usize,
+    }
+
+    impl FindBackend for Studies {
+        fn find<'a>(
+            &'a self,
+            level: QueryLevel,
+            identifier: &InMemDicomObject<StandardDataDictionary>,
+        ) -> Box<dyn Iterator<Item = InMemDicomObject<StandardDataDictionary>> + 'a> {
+            assert_eq!(level, QueryLevel::Study);
+            let patient_id = identifier
+                .element(tags::PATIENT_ID)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .into_owned();
+            Box::new((0..self.count).map(move |i| {
+                let mut study = InMemDicomObject::create_empty();
+                study.put(DataElement::new(
+                    tags::PATIENT_ID,
+                    VR::LO,
+                    PrimitiveValue::Strs([patient_id.clone()].as_ref().into()).into(),
+                ));
+                study.put(DataElement::new(
+                    tags::STUDY_INSTANCE_UID,
+                    VR::UI,
+                    PrimitiveValue::Strs([format!("1.2.3.{}", i)].as_ref().into()).into(),
+                ));
+                study
+            }))
+        }
+    }
+
+    fn spawn_find_scp(count: usize) -> Result<(SocketAddr, JoinHandle<Result<()>>)> {
+        spawn_scp(move |socket| {
+            let scp = FindScp::new(Studies { count });
+            let association = scp.establish(socket)?;
+            scp.serve(association)
+        })
+    }
+
+    fn study_query() -> InMemDicomObject<StandardDataDictionary> {
+        let mut identifier = QueryLevel::Study.identifier();
+        identifier.put(DataElement::new(
+            tags::PATIENT_ID,
+            VR::LO,
+            PrimitiveValue::Strs(["P1".to_string()].as_ref().into()).into(),
+        ));
+        identifier.put(DataElement::empty(tags::STUDY_INSTANCE_UID, VR::UI));
+        identifier
+    }
+
+    #[test]
+    fn find_studies() -> Result<()> {
+        let (addr, server) = spawn_find_scp(3)?;
+
+        let mut association = ClientAssociationOptions::new()
+            .with_presentation_context(STUDY_ROOT_FIND_SOP_CLASS, vec!["1.2.840.10008.1.2"])
+            .with_presentation_context(PATIENT_ROOT_FIND_SOP_CLASS, vec!["1.2.840.10008.1.2.1"])
+            .establish(addr)?;
+
+        let mut responses = find(
+            &mut association,
+            InformationModel::StudyRoot,
+            1,
+            &study_query(),
+        )?;
+        let studies = responses.by_ref().collect::<Result<Vec<_>>>()?;
+        assert_eq!(responses.status(), Some(Status::SUCCESS));
+        assert_eq!(studies.len(), 3);
+        assert_eq!(QueryLevel::of(&studies[2]), Some(QueryLevel::Study));
+        assert_eq!(
+            studies[2]
+                .element(tags::STUDY_INSTANCE_UID)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .trim_end_matches('\0'),
+            "1.2.3.2"
+        );
+
+        // the patient level does not exist in the study root model
+        let mut responses = find(
+            &mut association,
+            InformationModel::StudyRoot,
+            2,
+            &QueryLevel::Patient.identifier(),
+        )?;
+        assert!(responses.next().is_none());
+        assert_eq!(responses.status(), Some(IDENTIFIER_MISMATCH));
+
+        association.release()?;
+        server.join().unwrap()
+    }
+
+    #[test]
+    fn cancel_find() -> Result<()> {
+        let (addr, server) = spawn_find_scp(1_000_000)?;
+
+        let mut association = ClientAssociationOptions::new()
+            .with_presentation_context(STUDY_ROOT_FIND_SOP_CLASS, vec!["1.2.840.10008.1.2"])
+            .establish(addr)?;
+
+        let mut responses = find(
+            &mut association,
+            InformationModel::StudyRoot,
+            1,
+            &study_query(),
+        )?;
+        assert!(responses.next().unwrap().is_ok());
+        responses.cancel()?;
+        let remaining = responses.by_ref().count();
+        assert!(remaining < 1_000_000);
+        assert_eq!(responses.status(), Some(Status::CANCEL));
+
+        association.release()?;
+        server.join().unwrap()
+    }
+}
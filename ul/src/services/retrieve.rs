@@ -0,0 +1,1066 @@
+//! Query/Retrieve service class, MOVE and GET operations (PS3.4 Annex C).
+//!
+//! This module implements C-MOVE and C-GET for the Patient Root
+//! and Study Root query/retrieve information models.
+//! Each matching instance is transferred in a C-STORE sub-operation:
+//! on a new association with the move destination for C-MOVE,
+//! and on the same association for C-GET,
+//! where the requestor takes the SCP role of the storage SOP classes.
+//!
+//! The [`move_to`](fn.move_to.html) and [`get`](fn.get.html) functions
+//! send a request as a service class user
+//! and yield the progress of the sub-operations as it is reported,
+//! while [`RetrieveScp`](struct.RetrieveScp.html) answers both requests
+//! with the instances of a [`RetrieveBackend`](trait.RetrieveBackend.html).
+//!
+//! # Example
+//!
+//! Retrieving a study through C-GET:
+//!
+//! ```no_run
+//! # use dicom_core::{DataElement, PrimitiveValue, VR};
+//! # use dicom_dictionary_std::tags;
+//! # use dicom_ul::association::ClientAssociationOptions;
+//! # use dicom_ul::dimse::Status;
+//! # use dicom_ul::services::query::{InformationModel, QueryLevel};
+//! # use dicom_ul::services::retrieve;
+//! # fn run() -> dicom_ul::error::Result<()> {
+//! const CT_IMAGE_STORAGE: &str = "1.2.840.10008.5.1.4.1.1.2";
+//! let model = InformationModel::StudyRoot;
+//! let mut association = ClientAssociationOptions::new()
+//!     .with_presentation_context(model.get_sop_class(), vec!["1.2.840.10008.1.2"])
+//!     .with_presentation_context(CT_IMAGE_STORAGE, vec!["1.2.840.10008.1.2"])
+//!     .with_role_selection(CT_IMAGE_STORAGE, false, true)
+//!     .establish("129.168.0.5:104")?;
+//! let mut identifier = QueryLevel::Study.identifier();
+//! identifier.put(DataElement::new(
+//!     tags::STUDY_INSTANCE_UID,
+//!     VR::UI,
+//!     PrimitiveValue::Strs(["1.2.3.4".to_string()].as_ref().into()).into(),
+//! ));
+//! let mut responses = retrieve::get(&mut association, model, 1, &identifier, |request, _, data| {
+//!     println!("received {} ({} bytes)", request.affected_sop_instance_uid, data.len());
+//!     Status::SUCCESS
+//! })?;
+//! for progress in &mut responses {
+//!     println!("{:?} remaining", progress?.remaining);
+//! }
+//! println!("final status {:?}", responses.status());
+//! drop(responses);
+//! association.release()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::query::{
+    read_identifier, InformationModel, QueryLevel, PATIENT_ROOT_GET_SOP_CLASS,
+    PATIENT_ROOT_MOVE_SOP_CLASS, STUDY_ROOT_GET_SOP_CLASS, STUDY_ROOT_MOVE_SOP_CLASS,
+};
+use super::storage::{prepare_instance, storage_sop_classes, Instance, StorageScu};
+use super::verification::{self, VERIFICATION_SOP_CLASS};
+use super::{presentation_context_for, read_data_set, transfer_syntax_of};
+use crate::association::{
+    ClientAssociation, PresentationContext, ServerAssociation, ServerAssociationOptions,
+};
+use crate::dimse::{
+    CCancelRq, CGetRq, CGetRsp, CMoveRq, CMoveRsp, CStoreRq, CStoreRsp, Command, Priority, Status,
+    SubOperations,
+};
+use crate::error::{Error, Result};
+use dicom_core::{DataElement, PrimitiveValue, VR};
+use dicom_dictionary_std::uid::trim_uid;
+use dicom_dictionary_std::{tags, StandardDataDictionary};
+use dicom_encoding::TransferSyntax;
+use dicom_object::mem::InMemDicomObject;
+use std::collections::HashMap;
+use std::net::TcpStream;
+
+/// Failed: the sub-operations could not be performed.
+pub const UNABLE_TO_PERFORM_SUB_OPERATIONS: Status = Status(0xA702);
+
+/// Failed: the move destination is unknown.
+pub const MOVE_DESTINATION_UNKNOWN: Status = Status(0xA801);
+
+/// Warning: the sub-operations are complete,
+/// but some of them failed or ended with a warning.
+pub const SUB_OPERATIONS_COMPLETE_WITH_FAILURES: Status = Status(0xB000);
+
+/// The AE title of a retrieve SCP which does not define one,
+/// as the calling AE title of its C-MOVE sub-associations.
+pub const DEFAULT_AE_TITLE: &str = "RETRIEVE-SCP";
+
+/// Send a C-MOVE request with the given identifier,
+/// asking the service class provider to store the matching instances
+/// at the application entity titled `destination`.
+///
+/// The returned iterator yields the progress of the sub-operations
+/// in the pending responses of the service class provider.
+///
+/// Fails with `Error::NoPresentationContext`
+/// if the MOVE SOP class of the model was not accepted on the association.
+pub fn move_to<'a>(
+    association: &'a mut ClientAssociation,
+    model: InformationModel,
+    message_id: u16,
+    destination: &str,
+    identifier: &InMemDicomObject<StandardDataDictionary>,
+) -> Result<RetrieveResponses<'a>> {
+    let request = Command::CMoveRq(CMoveRq {
+        message_id,
+        affected_sop_class_uid: model.move_sop_class().to_string(),
+        priority: Priority::Medium,
+        move_destination: destination.to_string(),
+    });
+    send_request(
+        association,
+        model.move_sop_class(),
+        message_id,
+        &request,
+        identifier,
+        None,
+    )
+}
+
+/// Send a C-GET request with the given identifier,
+/// asking the service class provider to send the matching instances
+/// over the same association.
+///
+/// Each instance arrives in a C-STORE request,
+/// which is passed to `on_store` with the transfer syntax UID
+/// and the encoded data set while iterating over the responses.
+/// The status returned by `on_store` is sent back in the C-STORE response.
+///
+/// The association should have been negotiated with the SCP role
+/// of this node for the storage SOP classes of the instances,
+/// see [`ClientAssociationOptions::with_role_selection`].
+///
+/// Fails with `Error::NoPresentationContext`
+/// if the GET SOP class of the model was not accepted on the association.
+///
+/// [`ClientAssociationOptions::with_role_selection`]: ../../association/client/struct.ClientAssociationOptions.html#method.with_role_selection
+pub fn get<'a, F>(
+    association: &'a mut ClientAssociation,
+    model: InformationModel,
+    message_id: u16,
+    identifier: &InMemDicomObject<StandardDataDictionary>,
+    on_store: F,
+) -> Result<RetrieveResponses<'a>>
+where
+    F: FnMut(&CStoreRq, &str, &[u8]) -> Status + 'a,
+{
+    let request = Command::CGetRq(CGetRq {
+        message_id,
+        affected_sop_class_uid: model.get_sop_class().to_string(),
+        priority: Priority::Medium,
+    });
+    send_request(
+        association,
+        model.get_sop_class(),
+        message_id,
+        &request,
+        identifier,
+        Some(Box::new(on_store)),
+    )
+}
+
+/// Send a C-MOVE or C-GET request followed by its identifier.
+fn send_request<'a>(
+    association: &'a mut ClientAssociation,
+    sop_class_uid: &str,
+    message_id: u16,
+    request: &Command,
+    identifier: &InMemDicomObject<StandardDataDictionary>,
+    on_store: Option<Box<StoreHandler<'a>>>,
+) -> Result<RetrieveResponses<'a>> {
+    let presentation_context_id =
+        presentation_context_for(association.presentation_contexts(), sop_class_uid)?;
+    let ts = transfer_syntax_of(association.presentation_contexts(), presentation_context_id)?;
+
+    let mut data = Vec::new();
+    identifier.write_dataset_with_ts(&mut data, ts)?;
+    association.send_message(presentation_context_id, &request.encode(), Some(&data))?;
+
+    Ok(RetrieveResponses {
+        association,
+        presentation_context_id,
+        message_id,
+        transfer_syntax: ts,
+        on_store,
+        status: None,
+        sub_operations: None,
+        failed_sop_instance_uids: Vec::new(),
+        failed: false,
+    })
+}
+
+/// A receiver of the instances of a C-GET operation.
+type StoreHandler<'a> = dyn FnMut(&CStoreRq, &str, &[u8]) -> Status + 'a;
+
+/// An iterator over the progress of a C-MOVE or C-GET operation,
+/// created by [`move_to`](fn.move_to.html) or [`get`](fn.get.html).
+///
+/// Each item holds the sub-operation counts of a pending response.
+/// The iterator ends on the final response,
+/// whose status is then available through [`status`](#method.status),
+/// or after the first error.
+pub struct RetrieveResponses<'a> {
+    association: &'a mut ClientAssociation,
+    presentation_context_id: u8,
+    message_id: u16,
+    transfer_syntax: &'static TransferSyntax,
+    on_store: Option<Box<StoreHandler<'a>>>,
+    status: Option<Status>,
+    sub_operations: Option<SubOperations>,
+    failed_sop_instance_uids: Vec<String>,
+    failed: bool,
+}
+
+impl<'a> RetrieveResponses<'a> {
+    /// Retrieve the status of the final response,
+    /// or `None` if it was not received yet.
+    pub fn status(&self) -> Option<Status> {
+        self.status
+    }
+
+    /// Retrieve the sub-operation counts of the final response,
+    /// or `None` if it was not received yet.
+    pub fn sub_operations(&self) -> Option<SubOperations> {
+        self.sub_operations
+    }
+
+    /// Retrieve the SOP instance UIDs of the failed sub-operations,
+    /// as listed in the final response.
+    pub fn failed_sop_instance_uids(&self) -> &[String] {
+        &self.failed_sop_instance_uids
+    }
+
+    /// Request the service class provider to cancel the operation.
+    ///
+    /// Sub-operations which were already started may still complete,
+    /// and the final response should have the cancel status.
+    pub fn cancel(&mut self) -> Result<()> {
+        let request = Command::CCancelRq(CCancelRq {
+            message_id_being_responded_to: self.message_id,
+        });
+        self.association
+            .send_message(self.presentation_context_id, &request.encode(), None)
+    }
+
+    /// Receive the next response,
+    /// answering the C-STORE requests of a C-GET operation on the way,
+    /// and return its sub-operation counts if it is pending.
+    fn next_progress(&mut self) -> Result<Option<SubOperations>> {
+        loop {
+            let message = self.association.receive_message()?;
+            let (message_id, status, sub_operations) = match Command::decode(&message.command)? {
+                Command::CMoveRsp(response) => (
+                    response.message_id_being_responded_to,
+                    response.status,
+                    response.sub_operations,
+                ),
+                Command::CGetRsp(response) => (
+                    response.message_id_being_responded_to,
+                    response.status,
+                    response.sub_operations,
+                ),
+                Command::CStoreRq(request) if self.on_store.is_some() => {
+                    let pc_id = message.presentation_context_id;
+                    let ts = self
+                        .association
+                        .presentation_contexts()
+                        .iter()
+                        .find(|pc| pc.id == pc_id)
+                        .map(|pc| pc.transfer_syntax.clone())
+                        .ok_or(Error::UnknownPresentationContext(pc_id))?;
+                    let data = message.data.unwrap_or_default();
+                    let on_store = self.on_store.as_mut().unwrap();
+                    let status = on_store(&request, trim_uid(&ts), &data);
+                    let response = Command::CStoreRsp(CStoreRsp {
+                        message_id_being_responded_to: request.message_id,
+                        affected_sop_class_uid: Some(request.affected_sop_class_uid),
+                        affected_sop_instance_uid: Some(request.affected_sop_instance_uid),
+                        status,
+                    });
+                    self.association
+                        .send_message(pc_id, &response.encode(), None)?;
+                    continue;
+                }
+                command => return Err(Error::UnexpectedCommand(command.command_field())),
+            };
+            if message_id != self.message_id {
+                return Err(Error::UnexpectedMessageId(message_id));
+            }
+            if status.is_pending() {
+                return Ok(Some(sub_operations));
+            }
+
+            self.status = Some(status);
+            self.sub_operations = Some(sub_operations);
+            if let Some(data) = message.data {
+                let identifier = read_data_set(&data[..], self.transfer_syntax)?;
+                if let Ok(list) = identifier.element(tags::FAILED_SOP_INSTANCE_UID_LIST) {
+                    self.failed_sop_instance_uids = list
+                        .to_str()
+                        .map(|uids| {
+                            uids.split('\\')
+                                .map(|uid| trim_uid(uid).to_string())
+                                .filter(|uid| !uid.is_empty())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                }
+            }
+            return Ok(None);
+        }
+    }
+}
+
+impl<'a> Iterator for RetrieveResponses<'a> {
+    type Item = Result<SubOperations>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.status.is_some() || self.failed {
+            return None;
+        }
+        match self.next_progress() {
+            Ok(progress) => progress.map(Ok),
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// A source of instances for C-MOVE and C-GET requests,
+/// such as an archive.
+pub trait RetrieveBackend {
+    /// Find the instances of the entities at the given level
+    /// which match the identifier.
+    ///
+    /// All instances are gathered before the first sub-operation,
+    /// so that the service class provider can report
+    /// the number of remaining sub-operations.
+    /// They should be opened with [`Instance::open_file`],
+    /// which only reads the file meta group,
+    /// so that each data set is only read right before it is sent.
+    ///
+    /// [`Instance::open_file`]: ../storage/struct.Instance.html#method.open_file
+    fn retrieve(
+        &self,
+        level: QueryLevel,
+        identifier: &InMemDicomObject<StandardDataDictionary>,
+    ) -> Vec<Instance>;
+}
+
+/// A query/retrieve MOVE and GET service class provider,
+/// which transfers the instances of a backend.
+///
+/// Both the Patient Root and the Study Root information models are accepted,
+/// as well as verification requests.
+/// Move destinations are resolved from a table of AE titles
+/// and network addresses, see [`with_destination`](#method.with_destination).
+/// For C-GET, the storage SOP classes are accepted
+/// together with the SCP/SCU roles proposed by the requestor,
+/// and instances are only sent for the SOP classes
+/// in which the requestor took the SCP role.
+///
+/// The sub-operations are performed one at a time,
+/// so that a C-CANCEL request from the service class user
+/// stops the operation before the next one.
+#[derive(Debug, Clone)]
+pub struct RetrieveScp<B> {
+    /// the backend producing the instances
+    backend: B,
+    /// the AE title of this node, any called AE title is admitted if `None`
+    ae_title: Option<String>,
+    /// the network address of each known move destination, by AE title
+    destinations: HashMap<String, String>,
+    /// the transfer syntaxes accepted in order of preference,
+    /// any supported transfer syntax is accepted if empty
+    transfer_syntaxes: Vec<String>,
+}
+
+impl<B> RetrieveScp<B>
+where
+    B: RetrieveBackend,
+{
+    /// Create a retrieve SCP transferring the instances of the given backend.
+    pub fn new(backend: B) -> Self {
+        RetrieveScp {
+            backend,
+            ae_title: None,
+            destinations: HashMap::new(),
+            transfer_syntaxes: Vec::new(),
+        }
+    }
+
+    /// Retrieve the backend of this service class provider.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Only admit associations to the given called AE title,
+    /// which is also the calling AE title of the C-MOVE sub-associations.
+    ///
+    /// By default, any called AE title is admitted,
+    /// and sub-associations are requested as `RETRIEVE-SCP`.
+    pub fn accept_called_ae_title<T: Into<String>>(mut self, ae_title: T) -> Self {
+        self.ae_title = Some(ae_title.into());
+        self
+    }
+
+    /// Register a move destination,
+    /// reachable at the given network address (`host:port`).
+    pub fn with_destination<T, A>(mut self, ae_title: T, address: A) -> Self
+    where
+        T: Into<String>,
+        A: Into<String>,
+    {
+        self.destinations.insert(ae_title.into(), address.into());
+        self
+    }
+
+    /// Include this transfer syntax in the list of transfer syntaxes accepted,
+    /// in order of preference.
+    ///
+    /// By default, or if no transfer syntax is included,
+    /// any transfer syntax in the main registry is accepted.
+    pub fn with_transfer_syntax<T: Into<String>>(mut self, transfer_syntax_uid: T) -> Self {
+        self.transfer_syntaxes.push(transfer_syntax_uid.into());
+        self
+    }
+
+    /// Build the association options which reflect
+    /// the policies of this service class provider.
+    pub fn association_options(&self) -> ServerAssociationOptions {
+        let mut options = ServerAssociationOptions::new()
+            .with_abstract_syntax(VERIFICATION_SOP_CLASS)
+            .with_abstract_syntax(PATIENT_ROOT_MOVE_SOP_CLASS)
+            .with_abstract_syntax(STUDY_ROOT_MOVE_SOP_CLASS)
+            .with_abstract_syntax(PATIENT_ROOT_GET_SOP_CLASS)
+            .with_abstract_syntax(STUDY_ROOT_GET_SOP_CLASS)
+            .accept_role_selection(true);
+        for uid in storage_sop_classes() {
+            options = options.with_abstract_syntax(uid);
+        }
+        if let Some(ae_title) = &self.ae_title {
+            options = options.accept_called_ae_title(ae_title.as_str());
+        }
+        for uid in &self.transfer_syntaxes {
+            options = options.with_transfer_syntax(uid.as_str());
+        }
+        options
+    }
+
+    /// Negotiate an association with a service class user
+    /// on the given TCP stream.
+    pub fn establish(&self, socket: TcpStream) -> Result<ServerAssociation> {
+        self.association_options().establish(socket)
+    }
+
+    /// Answer every request received on the association
+    /// until the requestor releases it.
+    ///
+    /// Fails with `Error::UnexpectedCommand`
+    /// on commands other than C-MOVE, C-GET, C-CANCEL and C-ECHO,
+    /// in which case the association is aborted.
+    pub fn serve(&self, mut association: ServerAssociation) -> Result<()> {
+        loop {
+            match self.serve_next(&mut association) {
+                Ok(()) => {}
+                Err(Error::Released) => return Ok(()),
+                Err(Error::UnexpectedCommand(field)) => {
+                    association.abort()?;
+                    return Err(Error::UnexpectedCommand(field));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Receive and answer the next request.
+    fn serve_next(&self, association: &mut ServerAssociation) -> Result<()> {
+        let message = association.receive_message()?;
+        let pc_id = message.presentation_context_id;
+        match Command::decode(&message.command)? {
+            Command::CEchoRq(request) => verification::respond(association, pc_id, &request),
+            Command::CMoveRq(request) => {
+                let data = message.data.unwrap_or_default();
+                self.respond_move(association, pc_id, &request, &data)
+            }
+            Command::CGetRq(request) => {
+                let data = message.data.unwrap_or_default();
+                self.respond_get(association, pc_id, &request, &data)
+            }
+            // the operation to cancel is already over
+            Command::CCancelRq(_) => Ok(()),
+            command => Err(Error::UnexpectedCommand(command.command_field())),
+        }
+    }
+
+    /// Answer a C-MOVE request,
+    /// storing the matching instances at the move destination
+    /// through a new association,
+    /// with a pending response after each sub-operation
+    /// and a final response.
+    pub fn respond_move(
+        &self,
+        association: &mut ServerAssociation,
+        presentation_context_id: u8,
+        request: &CMoveRq,
+        data: &[u8],
+    ) -> Result<()> {
+        let mut operation = Operation {
+            presentation_context_id,
+            request: Request::Move(request),
+            transfer_syntax: transfer_syntax_of(
+                association.presentation_contexts(),
+                presentation_context_id,
+            )?,
+            progress: Progress::default(),
+        };
+        let instances = match self.instances(
+            InformationModel::from_move_sop_class(&request.affected_sop_class_uid),
+            operation.transfer_syntax,
+            data,
+        ) {
+            Ok(instances) => instances,
+            Err(status) => return operation.finish(association, status),
+        };
+        let destination = request.move_destination.trim_end_matches(&['\0', ' '][..]);
+        let address = match self.destinations.get(destination) {
+            Some(address) => address,
+            None => return operation.finish(association, MOVE_DESTINATION_UNKNOWN),
+        };
+        operation.progress.remaining = instances.len();
+        if instances.is_empty() {
+            return operation.finish(association, Status::SUCCESS);
+        }
+
+        let scu = StorageScu::new()
+            .calling_ae_title(self.ae_title.as_deref().unwrap_or(DEFAULT_AE_TITLE))
+            .called_ae_title(destination)
+            .transcode(true)
+            .move_originator(association.client_ae_title(), request.message_id);
+        let mut message_id = 0;
+        for batch in scu.batches(&instances) {
+            // the sub-operations of a batch fail
+            // if the destination cannot be reached
+            let mut sub_association = scu.association_options(batch).establish(address).ok();
+            for instance in batch {
+                if cancel_requested(association, request.message_id)? {
+                    if let Some(sub_association) = sub_association {
+                        // the sub-association is no longer needed either way
+                        let _ = sub_association.release();
+                    }
+                    return operation.finish(association, Status::CANCEL);
+                }
+                message_id = message_id % u16::MAX + 1;
+                let status = match sub_association
+                    .as_mut()
+                    .map(|sub_association| scu.store(sub_association, message_id, instance))
+                {
+                    Some(Ok(result)) => result.status,
+                    Some(Err(_)) => {
+                        // the destination can no longer receive any instance
+                        // on this sub-association
+                        sub_association = None;
+                        None
+                    }
+                    None => None,
+                };
+                operation
+                    .progress
+                    .record(instance.sop_instance_uid(), status);
+                operation.report(association)?;
+            }
+            if let Some(sub_association) = sub_association {
+                // every instance was sent, whatever happens to the sub-association
+                let _ = sub_association.release();
+            }
+        }
+        operation.finish(association, operation.progress.final_status())
+    }
+
+    /// Answer a C-GET request,
+    /// sending the matching instances over the same association,
+    /// with a pending response after each sub-operation
+    /// and a final response.
+    pub fn respond_get(
+        &self,
+        association: &mut ServerAssociation,
+        presentation_context_id: u8,
+        request: &CGetRq,
+        data: &[u8],
+    ) -> Result<()> {
+        let mut operation = Operation {
+            presentation_context_id,
+            request: Request::Get(request),
+            transfer_syntax: transfer_syntax_of(
+                association.presentation_contexts(),
+                presentation_context_id,
+            )?,
+            progress: Progress::default(),
+        };
+        let instances = match self.instances(
+            InformationModel::from_get_sop_class(&request.affected_sop_class_uid),
+            operation.transfer_syntax,
+            data,
+        ) {
+            Ok(instances) => instances,
+            Err(status) => return operation.finish(association, status),
+        };
+        operation.progress.remaining = instances.len();
+
+        // only the SOP classes in which the requestor may act as an SCP
+        let presentation_contexts: Vec<PresentationContext> = association
+            .presentation_contexts()
+            .iter()
+            .filter(|pc| {
+                association.role_selections().iter().any(|role| {
+                    role.scp_role && trim_uid(&role.sop_class_uid) == trim_uid(&pc.abstract_syntax)
+                })
+            })
+            .cloned()
+            .collect();
+
+        let mut cancelled = false;
+        for (i, instance) in instances.iter().enumerate() {
+            if cancelled || cancel_requested(association, request.message_id)? {
+                return operation.finish(association, Status::CANCEL);
+            }
+            let (pc_id, _, data) = match prepare_instance(&presentation_contexts, instance, true) {
+                Some(prepared) => prepared,
+                None => {
+                    operation.progress.record(instance.sop_instance_uid(), None);
+                    operation.report(association)?;
+                    continue;
+                }
+            };
+            let message_id = (i % usize::from(u16::MAX)) as u16 + 1;
+            let store = Command::CStoreRq(CStoreRq {
+                message_id,
+                affected_sop_class_uid: instance.sop_class_uid().to_string(),
+                affected_sop_instance_uid: instance.sop_instance_uid().to_string(),
+                priority: Priority::Medium,
+                move_originator_ae_title: None,
+                move_originator_message_id: None,
+            });
+            association.send_message(pc_id, &store.encode(), Some(&data))?;
+
+            // a C-CANCEL request may arrive before the C-STORE response
+            let status = loop {
+                let message = association.receive_message()?;
+                match Command::decode(&message.command)? {
+                    Command::CStoreRsp(response)
+                        if response.message_id_being_responded_to == message_id =>
+                    {
+                        break response.status;
+                    }
+                    Command::CCancelRq(cancel)
+                        if cancel.message_id_being_responded_to == request.message_id =>
+                    {
+                        cancelled = true;
+                    }
+                    command => return Err(Error::UnexpectedCommand(command.command_field())),
+                }
+            };
+            operation
+                .progress
+                .record(instance.sop_instance_uid(), Some(status));
+            operation.report(association)?;
+        }
+        if cancelled {
+            return operation.finish(association, Status::CANCEL);
+        }
+        operation.finish(association, operation.progress.final_status())
+    }
+
+    /// Decode the identifier of a request in the given information model
+    /// and gather the matching instances of the backend,
+    /// or return the status of the failure.
+    fn instances(
+        &self,
+        model: Option<InformationModel>,
+        ts: &TransferSyntax,
+        data: &[u8],
+    ) -> std::result::Result<Vec<Instance>, Status> {
+        let model = model.ok_or(Status::SOP_CLASS_NOT_SUPPORTED)?;
+        let (level, identifier) = read_identifier(model, data, ts)?;
+        Ok(self.backend.retrieve(level, &identifier))
+    }
+}
+
+/// Check whether the requestor asked to cancel the operation
+/// with the given message ID, without waiting for a message.
+fn cancel_requested(association: &mut ServerAssociation, message_id: u16) -> Result<bool> {
+    if !association.poll()? {
+        return Ok(false);
+    }
+    let message = association.receive_message()?;
+    match Command::decode(&message.command)? {
+        Command::CCancelRq(cancel) => Ok(cancel.message_id_being_responded_to == message_id),
+        command => Err(Error::UnexpectedCommand(command.command_field())),
+    }
+}
+
+/// A C-MOVE or C-GET request being answered.
+#[derive(Debug, Copy, Clone)]
+enum Request<'a> {
+    Move(&'a CMoveRq),
+    Get(&'a CGetRq),
+}
+
+/// The state of a C-MOVE or C-GET operation on the provider side.
+struct Operation<'a> {
+    presentation_context_id: u8,
+    request: Request<'a>,
+    transfer_syntax: &'static TransferSyntax,
+    progress: Progress,
+}
+
+impl<'a> Operation<'a> {
+    /// Send a pending response with the current sub-operation counts,
+    /// unless no sub-operation remains.
+    fn report(&self, association: &mut ServerAssociation) -> Result<()> {
+        if self.progress.remaining == 0 {
+            return Ok(());
+        }
+        self.send_response(association, Status::PENDING, None)
+    }
+
+    /// Send the final response with the given status,
+    /// followed by the list of failed SOP instances if there are any.
+    fn finish(&self, association: &mut ServerAssociation, status: Status) -> Result<()> {
+        if self.progress.failed_sop_instance_uids.is_empty() {
+            return self.send_response(association, status, None);
+        }
+        let mut identifier = InMemDicomObject::<StandardDataDictionary>::create_empty();
+        identifier.put(DataElement::new(
+            tags::FAILED_SOP_INSTANCE_UID_LIST,
+            VR::UI,
+            PrimitiveValue::Strs(self.progress.failed_sop_instance_uids[..].into()).into(),
+        ));
+        let mut data = Vec::new();
+        identifier.write_dataset_with_ts(&mut data, self.transfer_syntax)?;
+        self.send_response(association, status, Some(&data))
+    }
+
+    fn send_response(
+        &self,
+        association: &mut ServerAssociation,
+        status: Status,
+        data: Option<&[u8]>,
+    ) -> Result<()> {
+        let sub_operations = self.progress.sub_operations(status);
+        let response = match self.request {
+            Request::Move(request) => Command::CMoveRsp(CMoveRsp {
+                message_id_being_responded_to: request.message_id,
+                affected_sop_class_uid: Some(request.affected_sop_class_uid.clone()),
+                status,
+                sub_operations,
+                has_data_set: data.is_some(),
+            }),
+            Request::Get(request) => Command::CGetRsp(CGetRsp {
+                message_id_being_responded_to: request.message_id,
+                affected_sop_class_uid: Some(request.affected_sop_class_uid.clone()),
+                status,
+                sub_operations,
+                has_data_set: data.is_some(),
+            }),
+        };
+        association.send_message(self.presentation_context_id, &response.encode(), data)
+    }
+}
+
+/// The outcome of the sub-operations of a C-MOVE or C-GET operation so far.
+#[derive(Debug, Default)]
+struct Progress {
+    remaining: usize,
+    completed: usize,
+    failed: usize,
+    warning: usize,
+    failed_sop_instance_uids: Vec<String>,
+}
+
+impl Progress {
+    /// Record the outcome of a sub-operation,
+    /// which failed if it has no status.
+    fn record(&mut self, sop_instance_uid: &str, status: Option<Status>) {
+        self.remaining -= 1;
+        match status {
+            Some(status) if status.is_success() => self.completed += 1,
+            Some(status) if status.is_warning() => self.warning += 1,
+            _ => {
+                self.failed += 1;
+                self.failed_sop_instance_uids
+                    .push(sop_instance_uid.to_string());
+            }
+        }
+    }
+
+    /// Obtain the status of the final response
+    /// once every sub-operation was performed.
+    fn final_status(&self) -> Status {
+        if self.failed == 0 && self.warning == 0 {
+            Status::SUCCESS
+        } else if self.completed == 0 && self.warning == 0 {
+            UNABLE_TO_PERFORM_SUB_OPERATIONS
+        } else {
+            SUB_OPERATIONS_COMPLETE_WITH_FAILURES
+        }
+    }
+
+    /// Obtain the sub-operation counts to report in a response
+    /// with the given status.
+    fn sub_operations(&self, status: Status) -> SubOperations {
+        let count = |n: usize| Some(n.min(usize::from(u16::MAX)) as u16);
+        SubOperations {
+            // only pending and cancel responses have remaining sub-operations
+            remaining: if status.is_pending() || status.is_cancel() {
+                count(self.remaining)
+            } else {
+                None
+            },
+            completed: count(self.completed),
+            failed: count(self.failed),
+            warning: count(self.warning),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::association::ClientAssociationOptions;
+    use crate::services::storage::{StorageScp, StoreOutcome};
+    use crate::services::testing::{instance, spawn_scp, IMPLICIT_VR_LITTLE_ENDIAN};
+    use dicom_encoding::transfer_syntax::TransferSyntaxIndex;
+    use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
+    use std::fs;
+
+    const SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.1.7";
+    const OTHER_SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.1.2";
+
+    /// A backend with the given instances, all in the same study.
+    struct Archive(Vec<Instance>);
+
+    impl RetrieveBackend for Archive {
+        fn retrieve(
+            &self,
+            level: QueryLevel,
+            identifier: &InMemDicomObject<StandardDataDictionary>,
+        ) -> Vec<Instance> {
+            assert_eq!(level, QueryLevel::Study);
+            assert!(identifier.element(tags::STUDY_INSTANCE_UID).is_ok());
+            self.0.clone()
+        }
+    }
+
+    fn study_identifier() -> InMemDicomObject<StandardDataDictionary> {
+        let mut identifier = QueryLevel::Study.identifier();
+        identifier.put(DataElement::new(
+            tags::STUDY_INSTANCE_UID,
+            VR::UI,
+            PrimitiveValue::Strs(["1.2.3".to_string()].as_ref().into()).into(),
+        ));
+        identifier
+    }
+
+    #[test]
+    fn move_study() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("dicom-ul-movescp-{}", std::process::id()));
+        let storage_scp = StorageScp::new(&dir).with_abstract_syntax(SOP_CLASS);
+        let (destination_addr, store_server) = spawn_scp(move |socket| {
+            let association = storage_scp.establish(socket)?;
+            let calling_ae_title = association.client_ae_title().to_string();
+            let mut outcomes = Vec::<StoreOutcome>::new();
+            storage_scp.serve(association, |outcome| outcomes.push(outcome.clone()))?;
+            Ok((calling_ae_title, outcomes))
+        })?;
+
+        let (addr, server) = spawn_scp(move |socket| {
+            let scp = RetrieveScp::new(Archive(vec![
+                instance(SOP_CLASS, "2.25.1"),
+                instance(SOP_CLASS, "2.25.2"),
+            ]))
+            .accept_called_ae_title("ARCHIVE")
+            .with_destination("DEST", destination_addr.to_string());
+            let association = scp.establish(socket)?;
+            scp.serve(association)
+        })?;
+
+        let model = InformationModel::StudyRoot;
+        let mut association = ClientAssociationOptions::new()
+            .called_ae_title("ARCHIVE")
+            .with_presentation_context(model.move_sop_class(), vec![IMPLICIT_VR_LITTLE_ENDIAN])
+            .establish(addr)?;
+
+        let mut responses = move_to(&mut association, model, 1, "NOWHERE", &study_identifier())?;
+        assert!(responses.next().is_none());
+        assert_eq!(responses.status(), Some(MOVE_DESTINATION_UNKNOWN));
+        drop(responses);
+
+        let mut responses = move_to(&mut association, model, 2, "DEST", &study_identifier())?;
+        let pending = responses.by_ref().collect::<Result<Vec<_>>>()?;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].remaining, Some(1));
+        assert_eq!(pending[0].completed, Some(1));
+        assert_eq!(responses.status(), Some(Status::SUCCESS));
+        let sub_operations = responses.sub_operations().unwrap();
+        assert_eq!(sub_operations.remaining, None);
+        assert_eq!(sub_operations.completed, Some(2));
+        assert_eq!(sub_operations.failed, Some(0));
+        drop(responses);
+        association.release()?;
+        server.join().unwrap()?;
+
+        let (calling_ae_title, outcomes) = store_server.join().unwrap()?;
+        assert_eq!(calling_ae_title, "ARCHIVE");
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|outcome| outcome.path.is_some()));
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn get_study() -> Result<()> {
+        let (addr, server) = spawn_scp(|socket| {
+            let scp = RetrieveScp::new(Archive(vec![
+                instance(SOP_CLASS, "2.25.1"),
+                instance(OTHER_SOP_CLASS, "2.25.2"),
+                instance(SOP_CLASS, "2.25.3"),
+            ]));
+            let association = scp.establish(socket)?;
+            scp.serve(association)
+        })?;
+
+        // the SCP role is only taken for one of the SOP classes
+        let model = InformationModel::StudyRoot;
+        let mut association = ClientAssociationOptions::new()
+            .with_presentation_context(model.get_sop_class(), vec![IMPLICIT_VR_LITTLE_ENDIAN])
+            .with_presentation_context(SOP_CLASS, vec![IMPLICIT_VR_LITTLE_ENDIAN])
+            .with_presentation_context(OTHER_SOP_CLASS, vec![IMPLICIT_VR_LITTLE_ENDIAN])
+            .with_role_selection(SOP_CLASS, false, true)
+            .establish(addr)?;
+        assert_eq!(association.role_selections().len(), 1);
+
+        let mut received = Vec::new();
+        let mut responses = get(
+            &mut association,
+            model,
+            1,
+            &study_identifier(),
+            |request, ts, data| {
+                assert_eq!(ts, IMPLICIT_VR_LITTLE_ENDIAN);
+                assert!(!data.is_empty());
+                received.push(request.affected_sop_instance_uid.clone());
+                Status::SUCCESS
+            },
+        )?;
+        let pending = responses.by_ref().collect::<Result<Vec<_>>>()?;
+        assert_eq!(pending.len(), 2);
+        assert_eq!(
+            responses.status(),
+            Some(SUB_OPERATIONS_COMPLETE_WITH_FAILURES)
+        );
+        let sub_operations = responses.sub_operations().unwrap();
+        assert_eq!(sub_operations.completed, Some(2));
+        assert_eq!(sub_operations.failed, Some(1));
+        assert_eq!(
+            responses.failed_sop_instance_uids(),
+            &["2.25.2".to_string()]
+        );
+        drop(responses);
+        assert_eq!(received, vec!["2.25.1", "2.25.3"]);
+
+        association.release()?;
+        server.join().unwrap()
+    }
+
+    #[test]
+    fn cancel_get_before_last_store_response() -> Result<()> {
+        let (addr, server) = spawn_scp(|socket| {
+            let scp = RetrieveScp::new(Archive(vec![instance(SOP_CLASS, "2.25.1")]));
+            let association = scp.establish(socket)?;
+            scp.serve(association)
+        })?;
+
+        let model = InformationModel::StudyRoot;
+        let mut association = ClientAssociationOptions::new()
+            .with_presentation_context(model.get_sop_class(), vec![IMPLICIT_VR_LITTLE_ENDIAN])
+            .with_presentation_context(SOP_CLASS, vec![IMPLICIT_VR_LITTLE_ENDIAN])
+            .with_role_selection(SOP_CLASS, false, true)
+            .establish(addr)?;
+        let pc_id =
+            presentation_context_for(association.presentation_contexts(), model.get_sop_class())?;
+        let ts = TransferSyntaxRegistry
+            .get(IMPLICIT_VR_LITTLE_ENDIAN)
+            .unwrap();
+        let request = Command::CGetRq(CGetRq {
+            message_id: 1,
+            affected_sop_class_uid: model.get_sop_class().to_string(),
+            priority: Priority::Medium,
+        });
+        let mut data = Vec::new();
+        study_identifier().write_dataset_with_ts(&mut data, ts)?;
+        association.send_message(pc_id, &request.encode(), Some(&data))?;
+
+        // cancel the operation while its only sub-operation is in progress
+        let message = association.receive_message()?;
+        let store = match Command::decode(&message.command)? {
+            Command::CStoreRq(store) => store,
+            command => panic!("unexpected command {:?}", command.command_field()),
+        };
+        let cancel = Command::CCancelRq(CCancelRq {
+            message_id_being_responded_to: 1,
+        });
+        association.send_message(pc_id, &cancel.encode(), None)?;
+        let response = Command::CStoreRsp(CStoreRsp {
+            message_id_being_responded_to: store.message_id,
+            affected_sop_class_uid: Some(store.affected_sop_class_uid),
+            affected_sop_instance_uid: Some(store.affected_sop_instance_uid),
+            status: Status::SUCCESS,
+        });
+        association.send_message(message.presentation_context_id, &response.encode(), None)?;
+
+        let status = loop {
+            let message = association.receive_message()?;
+            match Command::decode(&message.command)? {
+                Command::CGetRsp(response) if !response.status.is_pending() => {
+                    break response.status
+                }
+                Command::CGetRsp(_) => {}
+                command => panic!("unexpected command {:?}", command.command_field()),
+            }
+        };
+        assert_eq!(status, Status::CANCEL);
+
+        association.release()?;
+        server.join().unwrap()
+    }
+
+    #[test]
+    fn final_status_of_sub_operations() {
+        let progress = |completed, failed, warning| Progress {
+            completed,
+            failed,
+            warning,
+            ..Progress::default()
+        };
+        assert_eq!(progress(2, 0, 0).final_status(), Status::SUCCESS);
+        assert_eq!(
+            progress(1, 1, 0).final_status(),
+            SUB_OPERATIONS_COMPLETE_WITH_FAILURES
+        );
+        assert_eq!(
+            progress(0, 1, 1).final_status(),
+            SUB_OPERATIONS_COMPLETE_WITH_FAILURES
+        );
+        assert_eq!(
+            progress(0, 2, 0).final_status(),
+            UNABLE_TO_PERFORM_SUB_OPERATIONS
+        );
+    }
+}
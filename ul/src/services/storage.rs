@@ -0,0 +1,1214 @@
+//! Storage service class (PS3.4 Annex B).
+//!
+//! This module provides a storage service class provider,
+//! [`StorageScp`](struct.StorageScp.html),
+//! which receives instances through C-STORE requests
+//! and writes each of them to disk as a DICOM file,
+//! and a storage service class user,
+//! [`StorageScu`](struct.StorageScu.html),
+//! which sends DICOM files to a storage SCP.
+//!
+//! # Examples
+//!
+//! Sending files:
+//!
+//! ```no_run
+//! # use dicom_ul::services::storage::{Instance, StorageScu};
+//! # fn run() -> dicom_ul::error::Result<()> {
+//! let instances = vec![Instance::open_file("CT1.dcm")?, Instance::open_file("CT2.dcm")?];
+//! let results = StorageScu::new()
+//!     .called_ae_title("STORE-SCP")
+//!     .transcode(true)
+//!     .send("129.168.0.5:104", &instances);
+//! assert!(results.iter().all(|r| r.is_success()));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Receiving files:
+//!
+//! ```no_run
+//! # use std::net::TcpListener;
+//! # use dicom_ul::services::storage::StorageScp;
+//! # fn run() -> dicom_ul::error::Result<()> {
+//! let scp = StorageScp::new("/var/dicom").accept_called_ae_title("STORE-SCP");
+//! let listener = TcpListener::bind("0.0.0.0:104")?;
+//! for stream in listener.incoming() {
+//!     let association = scp.establish(stream?)?;
+//!     scp.serve(association, |outcome| {
+//!         println!("{}: {:?}", outcome.sop_instance_uid, outcome.path);
+//!     })?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::transfer_syntax_of;
+use super::verification::{self, VERIFICATION_SOP_CLASS};
+use crate::association::{
+    pdata, ClientAssociation, ClientAssociationOptions, PresentationContext, ServerAssociation,
+    ServerAssociationOptions, MAXIMUM_PRESENTATION_CONTEXTS,
+};
+use crate::dimse::{CStoreRq, CStoreRsp, Command, Priority, Status};
+use crate::error::{Error, Result};
+use byteordered::Endianness;
+use dicom_core::dictionary::uid::UidType;
+use dicom_core::dictionary::{DataDictionary, DictionaryEntry};
+use dicom_core::{PrimitiveValue, Tag};
+use dicom_dictionary_std::uid::trim_uid;
+use dicom_dictionary_std::{tags, StandardDataDictionary, StandardUidDictionary};
+use dicom_encoding::text::SpecificCharacterSet;
+use dicom_encoding::transfer_syntax::TransferSyntaxIndex;
+use dicom_encoding::{Codec, TransferSyntax};
+use dicom_object::meta::{
+    FileMetaTable, FileMetaTableBuilder, IMPLEMENTATION_CLASS_UID, IMPLEMENTATION_VERSION_NAME,
+};
+use dicom_object::DefaultDicomObject;
+use dicom_parser::dataset::{DataSetReader, DataToken};
+use dicom_parser::parser::ReadLimits;
+use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Refused: out of resources,
+/// such as when the instance could not be written to disk.
+pub const OUT_OF_RESOURCES: Status = Status(0xA700);
+
+/// Error: the data set does not match the SOP class.
+pub const DATA_SET_MISMATCH: Status = Status(0xA900);
+
+/// Error: the data set cannot be understood.
+pub const CANNOT_UNDERSTAND: Status = Status(0xC000);
+
+/// The default template of the path of each stored instance,
+/// relative to the output directory.
+pub const DEFAULT_PATH_TEMPLATE: &str =
+    "{PatientID}/{StudyInstanceUID}/{SeriesInstanceUID}/{SOPInstanceUID}.dcm";
+
+/// The UID of the Explicit VR Little Endian transfer syntax.
+const EXPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2.1";
+
+/// The UID of the Implicit VR Little Endian transfer syntax.
+const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";
+
+/// The number of partial files created by this process,
+/// which makes the name of each of them unique.
+static PARTIAL_FILE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// The text in place of a path template attribute
+/// which is missing from the data set.
+const UNKNOWN: &str = "UNKNOWN";
+
+/// Retrieve the UIDs of the storage SOP classes in the standard UID registry
+/// which are not retired.
+pub fn storage_sop_classes() -> Vec<&'static str> {
+    StandardUidDictionary::iter()
+        .filter(|e| {
+            e.uid_type == UidType::SopClass
+                && !e.retired
+                && e.uid.starts_with("1.2.840.10008.5.1.4.1.1.")
+                && e.keyword.ends_with("Storage")
+        })
+        .map(|e| e.uid)
+        .collect()
+}
+
+/// The outcome of a C-STORE request handled by a storage SCP.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct StoreOutcome {
+    /// The SOP class UID of the instance.
+    pub sop_class_uid: String,
+    /// The SOP instance UID of the instance.
+    pub sop_instance_uid: String,
+    /// The path of the file written, if the instance was stored.
+    pub path: Option<PathBuf>,
+    /// The status sent back to the storage SCU.
+    pub status: Status,
+}
+
+/// A storage service class provider,
+/// which writes every instance received to a DICOM file
+/// in an output directory.
+///
+/// The path of each file is given by a template of attribute keywords
+/// in braces, [`DEFAULT_PATH_TEMPLATE`] by default,
+/// whose values are taken from the data set received.
+/// Attributes which are missing or empty are replaced by `UNKNOWN`,
+/// and characters which are not safe in file names are replaced by `_`.
+/// The file meta group records the calling AE title
+/// as the source application entity title.
+///
+/// Verification requests are answered as well.
+///
+/// [`DEFAULT_PATH_TEMPLATE`]: constant.DEFAULT_PATH_TEMPLATE.html
+#[derive(Debug, Clone)]
+pub struct StorageScp {
+    /// the called AE title admitted, any title is admitted if `None`
+    ae_title: Option<String>,
+    /// the storage SOP classes accepted,
+    /// all standard storage SOP classes if empty
+    abstract_syntaxes: Vec<String>,
+    /// whether to accept any abstract syntax
+    promiscuous: bool,
+    /// the transfer syntaxes accepted in order of preference,
+    /// any supported transfer syntax is accepted if empty
+    transfer_syntaxes: Vec<String>,
+    /// the directory of the files written
+    output_dir: PathBuf,
+    /// the template of the path of each file, relative to `output_dir`
+    path_template: String,
+    /// the time allowed for each read from an established association
+    read_timeout: Option<Duration>,
+}
+
+impl StorageScp {
+    /// Create a storage SCP writing files to the given directory.
+    pub fn new<P: Into<PathBuf>>(output_dir: P) -> Self {
+        StorageScp {
+            ae_title: None,
+            abstract_syntaxes: Vec::new(),
+            promiscuous: false,
+            transfer_syntaxes: Vec::new(),
+            output_dir: output_dir.into(),
+            path_template: DEFAULT_PATH_TEMPLATE.to_string(),
+            read_timeout: None,
+        }
+    }
+
+    /// Only admit associations to the given called AE title.
+    pub fn accept_called_ae_title<T: Into<String>>(mut self, ae_title: T) -> Self {
+        self.ae_title = Some(ae_title.into());
+        self
+    }
+
+    /// Include this SOP class in the list of storage SOP classes accepted.
+    ///
+    /// By default, or if no SOP class is included,
+    /// all storage SOP classes of the standard are accepted.
+    pub fn with_abstract_syntax<T: Into<String>>(mut self, abstract_syntax_uid: T) -> Self {
+        self.abstract_syntaxes.push(abstract_syntax_uid.into());
+        self
+    }
+
+    /// Define whether instances of any SOP class are accepted.
+    pub fn promiscuous(mut self, promiscuous: bool) -> Self {
+        self.promiscuous = promiscuous;
+        self
+    }
+
+    /// Include this transfer syntax in the list of transfer syntaxes accepted,
+    /// in order of preference.
+    ///
+    /// By default, or if no transfer syntax is included,
+    /// any transfer syntax in the main registry is accepted.
+    pub fn with_transfer_syntax<T: Into<String>>(mut self, transfer_syntax_uid: T) -> Self {
+        self.transfer_syntaxes.push(transfer_syntax_uid.into());
+        self
+    }
+
+    /// Override the template of the path of each file,
+    /// relative to the output directory.
+    pub fn path_template<T: Into<String>>(mut self, path_template: T) -> Self {
+        self.path_template = path_template.into();
+        self
+    }
+
+    /// Give up on an association after the given time without data
+    /// from the storage SCU. By default, reads wait indefinitely.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Build the association options which reflect
+    /// the policies of this storage SCP.
+    pub fn association_options(&self) -> ServerAssociationOptions {
+        let mut options = ServerAssociationOptions::new()
+            .with_abstract_syntax(VERIFICATION_SOP_CLASS)
+            .promiscuous(self.promiscuous);
+        if let Some(ae_title) = &self.ae_title {
+            options = options.accept_called_ae_title(ae_title.as_str());
+        }
+        if self.abstract_syntaxes.is_empty() {
+            for uid in storage_sop_classes() {
+                options = options.with_abstract_syntax(uid);
+            }
+        } else {
+            for uid in &self.abstract_syntaxes {
+                options = options.with_abstract_syntax(uid.as_str());
+            }
+        }
+        for uid in &self.transfer_syntaxes {
+            options = options.with_transfer_syntax(uid.as_str());
+        }
+        if let Some(timeout) = self.read_timeout {
+            options = options.read_timeout(timeout);
+        }
+        options
+    }
+
+    /// Negotiate an association with a storage SCU
+    /// on the given TCP stream.
+    pub fn establish(&self, socket: TcpStream) -> Result<ServerAssociation> {
+        self.association_options().establish(socket)
+    }
+
+    /// Store every instance received on the association
+    /// until the requestor releases it,
+    /// calling `on_store` with the outcome of each C-STORE request.
+    ///
+    /// Fails with `Error::UnexpectedCommand`
+    /// on commands other than C-STORE and C-ECHO,
+    /// in which case the association is aborted.
+    pub fn serve<F>(&self, mut association: ServerAssociation, mut on_store: F) -> Result<()>
+    where
+        F: FnMut(&StoreOutcome),
+    {
+        loop {
+            let (pc_id, command) = match association.receive_command() {
+                Ok(received) => received,
+                Err(Error::Released) => return Ok(()),
+                Err(e) => return Err(e),
+            };
+            match Command::decode(&command)? {
+                Command::CEchoRq(request) => {
+                    verification::respond(&mut association, pc_id, &request)?;
+                }
+                Command::CStoreRq(request) => {
+                    let ts = transfer_syntax_of(association.presentation_contexts(), pc_id)?;
+                    let calling_ae_title = association.client_ae_title().to_string();
+                    let outcome = if pdata::has_data_set(&command) {
+                        let mut data = association.data_reader(pc_id);
+                        let outcome = self.store(&calling_ae_title, ts, &request, &mut data);
+                        // skip what was not read, so that the next message is in sync
+                        io::copy(&mut data, &mut io::sink())?;
+                        outcome
+                    } else {
+                        // there is no instance to store
+                        StoreOutcome {
+                            sop_class_uid: request.affected_sop_class_uid.clone(),
+                            sop_instance_uid: request.affected_sop_instance_uid.clone(),
+                            path: None,
+                            status: CANNOT_UNDERSTAND,
+                        }
+                    };
+                    let response = Command::CStoreRsp(CStoreRsp {
+                        message_id_being_responded_to: request.message_id,
+                        affected_sop_class_uid: Some(request.affected_sop_class_uid.clone()),
+                        affected_sop_instance_uid: Some(request.affected_sop_instance_uid.clone()),
+                        status: outcome.status,
+                    });
+                    association.send_message(pc_id, &response.encode(), None)?;
+                    on_store(&outcome);
+                }
+                command => {
+                    association.abort()?;
+                    return Err(Error::UnexpectedCommand(command.command_field()));
+                }
+            }
+        }
+    }
+
+    /// Write the data set of a C-STORE request to a new file,
+    /// returning the outcome of the operation.
+    ///
+    /// The data set is written as it is read from `data`,
+    /// in the given transfer syntax,
+    /// after a file meta group recording `calling_ae_title`
+    /// as the source application entity title.
+    /// It is first written to a partial file in the output directory,
+    /// which is moved to its final path once the attributes in the path
+    /// template are known.
+    pub fn store<R: Read>(
+        &self,
+        calling_ae_title: &str,
+        ts: &TransferSyntax,
+        request: &CStoreRq,
+        data: R,
+    ) -> StoreOutcome {
+        let (path, status) = match self.store_inner(calling_ae_title, ts, request, data) {
+            Ok(path) => (Some(path), Status::SUCCESS),
+            Err(status) => (None, status),
+        };
+        StoreOutcome {
+            sop_class_uid: request.affected_sop_class_uid.clone(),
+            sop_instance_uid: request.affected_sop_instance_uid.clone(),
+            path,
+            status,
+        }
+    }
+
+    fn store_inner<R: Read>(
+        &self,
+        calling_ae_title: &str,
+        ts: &TransferSyntax,
+        request: &CStoreRq,
+        mut data: R,
+    ) -> std::result::Result<PathBuf, Status> {
+        let meta = FileMetaTableBuilder::new()
+            .media_storage_sop_class_uid(request.affected_sop_class_uid.clone())
+            .media_storage_sop_instance_uid(request.affected_sop_instance_uid.clone())
+            .transfer_syntax(ts.uid().to_string())
+            .implementation_class_uid(IMPLEMENTATION_CLASS_UID.to_string())
+            .implementation_version_name(IMPLEMENTATION_VERSION_NAME.to_string())
+            .source_application_entity_title(calling_ae_title.to_string())
+            .build()
+            .map_err(|_| Status::PROCESSING_FAILURE)?;
+
+        let partial = self.output_dir.join(format!(
+            ".{}-{}.part",
+            std::process::id(),
+            PARTIAL_FILE_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = write_partial(&partial, &meta, &mut data)
+            .map_err(|_| OUT_OF_RESOURCES)
+            .and_then(|data_start| self.move_partial(&partial, data_start, ts, request));
+        if result.is_err() {
+            // do not leave a partial file behind
+            let _ = fs::remove_file(&partial);
+        }
+        result
+    }
+
+    /// Check the data set written to a partial file,
+    /// and move the file to the path given by its attributes.
+    fn move_partial(
+        &self,
+        partial: &Path,
+        data_start: u64,
+        ts: &TransferSyntax,
+        request: &CStoreRq,
+    ) -> std::result::Result<PathBuf, Status> {
+        let mut file = BufReader::new(File::open(partial).map_err(|_| OUT_OF_RESOURCES)?);
+        file.seek(SeekFrom::Start(data_start))
+            .map_err(|_| OUT_OF_RESOURCES)?;
+        let attributes = read_attributes(file, ts).ok_or(CANNOT_UNDERSTAND)?;
+        if let Some(sop_class_uid) = attribute_text(&attributes, tags::SOP_CLASS_UID) {
+            if sop_class_uid != request.affected_sop_class_uid {
+                return Err(DATA_SET_MISMATCH);
+            }
+        }
+
+        let path = self
+            .output_dir
+            .join(resolve_path(&self.path_template, &attributes, request));
+        let rename = || -> io::Result<()> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::rename(partial, &path)
+        };
+        rename().map_err(|_| OUT_OF_RESOURCES)?;
+        Ok(path)
+    }
+}
+
+/// Write a DICOM file with the given file meta group
+/// and the data set read from `data`,
+/// returning the position of the data set in the file.
+fn write_partial<R: Read>(path: &Path, meta: &FileMetaTable, data: &mut R) -> io::Result<u64> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(&[0; 128])?;
+    meta.write(&mut file)
+        .map_err(|e| io::Error::other(e.to_string()))?;
+    let data_start = file.stream_position()?;
+    io::copy(data, &mut file)?;
+    file.flush()?;
+    Ok(data_start)
+}
+
+/// A DICOM instance to be sent to a storage SCP:
+/// its file meta group and its data set,
+/// encoded in the transfer syntax of the file meta group.
+///
+/// The data set of an instance opened from a file
+/// is only read when it is about to be sent,
+/// so that many instances can be listed without holding them in memory.
+#[derive(Debug, Clone)]
+pub struct Instance {
+    meta: FileMetaTable,
+    data: InstanceData,
+}
+
+/// The data set of an instance.
+#[derive(Debug, Clone)]
+enum InstanceData {
+    /// the encoded data set
+    Memory(Vec<u8>),
+    /// the path of a DICOM file and the position of the data set in it
+    File(PathBuf, u64),
+}
+
+impl Instance {
+    /// Open an instance from a DICOM file,
+    /// reading its file meta group only.
+    ///
+    /// This function assumes the standard file encoding structure: 128-byte
+    /// preamble, file meta group, and the rest of the data set.
+    /// The data set is not decoded, so that it may be sent
+    /// in any transfer syntax, including encapsulated ones.
+    pub fn open_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut file = BufReader::new(File::open(path)?);
+        let mut preamble = [0u8; 128];
+        file.read_exact(&mut preamble)?;
+        let meta = FileMetaTable::from_reader(&mut file)?;
+        let data_start = file.stream_position()?;
+        Ok(Instance {
+            meta,
+            data: InstanceData::File(path.to_path_buf(), data_start),
+        })
+    }
+
+    /// Read an instance from a source with the file meta group,
+    /// followed by the rest of the data set, without the preamble.
+    pub fn from_reader<R: Read>(mut src: R) -> Result<Self> {
+        let meta = FileMetaTable::from_reader(&mut src)?;
+        let mut data = Vec::new();
+        src.read_to_end(&mut data)?;
+        Ok(Instance {
+            meta,
+            data: InstanceData::Memory(data),
+        })
+    }
+
+    /// Encode a DICOM object in the transfer syntax of its file meta table.
+    ///
+    /// Only native transfer syntaxes are supported.
+    pub fn from_object(obj: &DefaultDicomObject) -> Result<Self> {
+        let mut buf = Vec::new();
+        obj.write_to(&mut buf)?;
+        Instance::from_reader(&buf[..])
+    }
+
+    /// Retrieve the file meta table of the instance.
+    pub fn meta(&self) -> &FileMetaTable {
+        &self.meta
+    }
+
+    /// Retrieve the encoded data set of the instance,
+    /// reading it from its file if the instance was opened from one.
+    pub fn data(&self) -> Result<Cow<'_, [u8]>> {
+        match &self.data {
+            InstanceData::Memory(data) => Ok(Cow::Borrowed(data)),
+            InstanceData::File(path, data_start) => {
+                let mut file = File::open(path)?;
+                file.seek(SeekFrom::Start(*data_start))?;
+                let mut data = Vec::new();
+                file.read_to_end(&mut data)?;
+                Ok(Cow::Owned(data))
+            }
+        }
+    }
+
+    /// Retrieve the SOP class UID of the instance.
+    pub fn sop_class_uid(&self) -> &str {
+        trim_uid(&self.meta.media_storage_sop_class_uid)
+    }
+
+    /// Retrieve the SOP instance UID of the instance.
+    pub fn sop_instance_uid(&self) -> &str {
+        trim_uid(&self.meta.media_storage_sop_instance_uid)
+    }
+
+    /// Retrieve the UID of the transfer syntax of the data set.
+    pub fn transfer_syntax(&self) -> &str {
+        trim_uid(&self.meta.transfer_syntax)
+    }
+
+    /// Encode the data set in another native transfer syntax.
+    fn transcode(&self, ts: &TransferSyntax) -> Result<Vec<u8>> {
+        let mut src = Vec::new();
+        self.meta.write(&mut src)?;
+        src.extend_from_slice(&self.data()?);
+        let mut out = Vec::new();
+        dicom_object::file::transcode(&src[..], &mut out, ts)?;
+        // skip the new file meta group
+        let mut data = &out[..];
+        FileMetaTable::from_reader(&mut data)?;
+        Ok(data.to_vec())
+    }
+}
+
+/// The result of sending an instance to a storage SCP.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct StoreResult {
+    /// The SOP class UID of the instance.
+    pub sop_class_uid: String,
+    /// The SOP instance UID of the instance.
+    pub sop_instance_uid: String,
+    /// The transfer syntax in which the instance was sent,
+    /// or `None` if no accepted presentation context could carry it.
+    pub transfer_syntax: Option<String>,
+    /// The status of the C-STORE response,
+    /// or `None` if the instance was not sent.
+    pub status: Option<Status>,
+    /// The error which interrupted the exchange with the storage SCP
+    /// before a response was received for the instance, if any.
+    pub error: Option<String>,
+}
+
+impl StoreResult {
+    /// Create the result of an instance which was not sent yet.
+    fn new(instance: &Instance) -> Self {
+        StoreResult {
+            sop_class_uid: instance.sop_class_uid().to_string(),
+            sop_instance_uid: instance.sop_instance_uid().to_string(),
+            transfer_syntax: None,
+            status: None,
+            error: None,
+        }
+    }
+
+    /// Check whether the instance was stored successfully.
+    pub fn is_success(&self) -> bool {
+        self.status.map(|s| s.is_success()).unwrap_or(false)
+    }
+
+    /// Check whether the instance was stored with a warning.
+    pub fn is_warning(&self) -> bool {
+        self.status.map(|s| s.is_warning()).unwrap_or(false)
+    }
+
+    /// Check whether the instance was not sent or not stored.
+    pub fn is_failure(&self) -> bool {
+        !self.is_success() && !self.is_warning()
+    }
+}
+
+/// A storage service class user,
+/// which sends instances to a storage SCP through C-STORE requests.
+///
+/// A presentation context is proposed for each SOP class
+/// and transfer syntax of the instances,
+/// along with one for each SOP class
+/// with Explicit VR Little Endian and Implicit VR Little Endian.
+/// When the original transfer syntax of an instance is not accepted,
+/// it may be transcoded to another native transfer syntax
+/// if [`transcode`] is enabled.
+///
+/// [`transcode`]: #method.transcode
+#[derive(Debug, Clone)]
+pub struct StorageScu {
+    /// the calling AE title
+    calling_ae_title: String,
+    /// the called AE title
+    called_ae_title: String,
+    /// the maximum PDU length accepted by this node, the default if `None`
+    max_pdu_length: Option<u32>,
+    /// whether to transcode instances in native transfer syntaxes
+    /// which were not accepted
+    transcode: bool,
+    /// the AE title and message ID of the C-MOVE request
+    /// on behalf of which instances are sent, if any
+    move_originator: Option<(String, u16)>,
+}
+
+impl Default for StorageScu {
+    fn default() -> Self {
+        StorageScu {
+            calling_ae_title: "STORE-SCU".to_string(),
+            called_ae_title: "ANY-SCP".to_string(),
+            max_pdu_length: None,
+            transcode: false,
+            move_originator: None,
+        }
+    }
+}
+
+impl StorageScu {
+    /// Create a storage SCU with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define the calling AE title.
+    pub fn calling_ae_title<T: Into<String>>(mut self, calling_ae_title: T) -> Self {
+        self.calling_ae_title = calling_ae_title.into();
+        self
+    }
+
+    /// Define the called AE title.
+    pub fn called_ae_title<T: Into<String>>(mut self, called_ae_title: T) -> Self {
+        self.called_ae_title = called_ae_title.into();
+        self
+    }
+
+    /// Override the maximum PDU length accepted by this node.
+    pub fn max_pdu_length(mut self, value: u32) -> Self {
+        self.max_pdu_length = Some(value);
+        self
+    }
+
+    /// Define whether instances in a native transfer syntax
+    /// which the peer did not accept are transcoded
+    /// to another native transfer syntax which it did accept.
+    pub fn transcode(mut self, transcode: bool) -> Self {
+        self.transcode = transcode;
+        self
+    }
+
+    /// Send instances as sub-operations of a C-MOVE request,
+    /// identifying its originator AE title and message ID
+    /// in every C-STORE request.
+    pub fn move_originator<T: Into<String>>(mut self, ae_title: T, message_id: u16) -> Self {
+        self.move_originator = Some((ae_title.into(), message_id));
+        self
+    }
+
+    /// Build the association options
+    /// proposing the presentation contexts for the given instances.
+    ///
+    /// An association request carries at most 128 presentation contexts,
+    /// so instances of many SOP classes or transfer syntaxes
+    /// should be split with [`batches`] first.
+    ///
+    /// [`batches`]: #method.batches
+    pub fn association_options(&self, instances: &[Instance]) -> ClientAssociationOptions {
+        let (contexts, sop_classes) = proposed_contexts(instances);
+
+        let mut options = ClientAssociationOptions::new()
+            .calling_ae_title(self.calling_ae_title.as_str())
+            .called_ae_title(self.called_ae_title.as_str());
+        if let Some(max_pdu_length) = self.max_pdu_length {
+            options = options.max_pdu_length(max_pdu_length);
+        }
+        for (sop_class_uid, transfer_syntax) in contexts {
+            options = options.with_presentation_context(sop_class_uid, vec![transfer_syntax]);
+        }
+        for sop_class_uid in sop_classes {
+            options = options.with_presentation_context(
+                sop_class_uid,
+                vec![EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN],
+            );
+        }
+        options
+    }
+
+    /// Split the instances into consecutive batches
+    /// whose presentation contexts fit in a single association request.
+    pub fn batches<'a>(&self, instances: &'a [Instance]) -> Vec<&'a [Instance]> {
+        let mut batches = Vec::new();
+        let mut start = 0;
+        let mut contexts = Vec::new();
+        let mut sop_classes = Vec::new();
+        for (i, instance) in instances.iter().enumerate() {
+            let context = (instance.sop_class_uid(), instance.transfer_syntax());
+            let new_contexts = usize::from(!contexts.contains(&context))
+                + usize::from(!sop_classes.contains(&context.0));
+            if contexts.len() + sop_classes.len() + new_contexts > MAXIMUM_PRESENTATION_CONTEXTS {
+                batches.push(&instances[start..i]);
+                start = i;
+                contexts.clear();
+                sop_classes.clear();
+            }
+            if !contexts.contains(&context) {
+                contexts.push(context);
+            }
+            if !sop_classes.contains(&context.0) {
+                sop_classes.push(context.0);
+            }
+        }
+        if start < instances.len() {
+            batches.push(&instances[start..]);
+        }
+        batches
+    }
+
+    /// Establish an association with the storage SCP at the given address
+    /// and send all instances, one C-STORE request at a time,
+    /// returning the result of each one in order.
+    /// The association is released at the end.
+    ///
+    /// If the instances do not fit in a single association request,
+    /// they are sent in [`batches`], on one association after the other.
+    ///
+    /// If an association cannot be established or used,
+    /// the instances which were not stored yet are not sent,
+    /// and the error is recorded in their results.
+    ///
+    /// [`batches`]: #method.batches
+    pub fn send<A: ToSocketAddrs>(&self, address: A, instances: &[Instance]) -> Vec<StoreResult> {
+        let mut results = Vec::with_capacity(instances.len());
+        if let Err(e) = self.send_batches(&address, instances, &mut results) {
+            let error = e.to_string();
+            results.extend(
+                instances[results.len()..]
+                    .iter()
+                    .map(|instance| StoreResult {
+                        error: Some(error.clone()),
+                        ..StoreResult::new(instance)
+                    }),
+            );
+        }
+        results
+    }
+
+    fn send_batches<A: ToSocketAddrs>(
+        &self,
+        address: &A,
+        instances: &[Instance],
+        results: &mut Vec<StoreResult>,
+    ) -> Result<()> {
+        for batch in self.batches(instances) {
+            let mut association = self.association_options(batch).establish(address)?;
+            for instance in batch {
+                let message_id = (results.len() % usize::from(u16::MAX)) as u16 + 1;
+                results.push(self.store(&mut association, message_id, instance)?);
+            }
+            association.release()?;
+        }
+        Ok(())
+    }
+
+    /// Send an instance with a C-STORE request on an established association
+    /// and wait for the response.
+    ///
+    /// An instance which no accepted presentation context can carry,
+    /// or whose data set could not be read,
+    /// is not sent, and its result has no status.
+    /// Fails only if the association can no longer be used.
+    pub fn store(
+        &self,
+        association: &mut ClientAssociation,
+        message_id: u16,
+        instance: &Instance,
+    ) -> Result<StoreResult> {
+        let mut result = StoreResult::new(instance);
+        let (pc_id, transfer_syntax, data) = match prepare_instance(
+            association.presentation_contexts(),
+            instance,
+            self.transcode,
+        ) {
+            Some(prepared) => prepared,
+            None => return Ok(result),
+        };
+
+        let request = Command::CStoreRq(CStoreRq {
+            message_id,
+            affected_sop_class_uid: result.sop_class_uid.clone(),
+            affected_sop_instance_uid: result.sop_instance_uid.clone(),
+            priority: Priority::Medium,
+            move_originator_ae_title: self.move_originator.as_ref().map(|(ae, _)| ae.clone()),
+            move_originator_message_id: self.move_originator.as_ref().map(|(_, id)| *id),
+        });
+        association.send_message(pc_id, &request.encode(), Some(&data))?;
+
+        let message = association.receive_message()?;
+        match Command::decode(&message.command)? {
+            Command::CStoreRsp(response) => {
+                if response.message_id_being_responded_to != message_id {
+                    return Err(Error::UnexpectedMessageId(
+                        response.message_id_being_responded_to,
+                    ));
+                }
+                result.transfer_syntax = Some(transfer_syntax);
+                result.status = Some(response.status);
+                Ok(result)
+            }
+            command => Err(Error::UnexpectedCommand(command.command_field())),
+        }
+    }
+}
+
+/// Find an accepted presentation context for the instance,
+/// returning its ID, its transfer syntax,
+/// and the data set encoded in that transfer syntax.
+/// If no presentation context carries the instance's own transfer syntax
+/// and `transcode` is set, the data set is transcoded
+/// to the native transfer syntax of another one.
+pub(crate) fn prepare_instance<'a>(
+    presentation_contexts: &[PresentationContext],
+    instance: &'a Instance,
+    transcode: bool,
+) -> Option<(u8, String, Cow<'a, [u8]>)> {
+    let candidates = presentation_contexts
+        .iter()
+        .filter(|pc| trim_uid(&pc.abstract_syntax) == instance.sop_class_uid());
+    let mut fallback = None;
+    for pc in candidates {
+        let transfer_syntax = trim_uid(&pc.transfer_syntax);
+        if transfer_syntax == instance.transfer_syntax() {
+            let data = instance.data().ok()?;
+            return Some((pc.id, transfer_syntax.to_string(), data));
+        }
+        if fallback.is_none() {
+            fallback = TransferSyntaxRegistry
+                .get(transfer_syntax)
+                .filter(|ts| is_native(ts))
+                .map(|ts| (pc.id, ts));
+        }
+    }
+
+    if !transcode {
+        return None;
+    }
+    let (pc_id, ts) = fallback?;
+    let data = instance.transcode(ts).ok()?;
+    Some((pc_id, ts.uid().to_string(), Cow::Owned(data)))
+}
+
+/// Collect the presentation contexts to propose for the given instances:
+/// one for each pair of SOP class and transfer syntax,
+/// followed by one for each SOP class in the native transfer syntaxes.
+fn proposed_contexts(instances: &[Instance]) -> (Vec<(&str, &str)>, Vec<&str>) {
+    let mut contexts = Vec::new();
+    let mut sop_classes = Vec::new();
+    for instance in instances {
+        let context = (instance.sop_class_uid(), instance.transfer_syntax());
+        if !contexts.contains(&context) {
+            contexts.push(context);
+        }
+        if !sop_classes.contains(&context.0) {
+            sop_classes.push(context.0);
+        }
+    }
+    (contexts, sop_classes)
+}
+
+/// Check whether data sets in the given transfer syntax
+/// can be transcoded without a codec.
+fn is_native(ts: &TransferSyntax) -> bool {
+    matches!(ts.codec(), Codec::None) && (ts.explicit_vr() || ts.endianness() == Endianness::Little)
+}
+
+/// Read the primitive attributes at the root of a data set,
+/// up to the pixel data,
+/// which may be encapsulated and is not needed for naming the file.
+///
+/// Returns `None` if the data set could not be read up to that point,
+/// or if it exceeds the limits for untrusted sources.
+fn read_attributes<R: Read>(data: R, ts: &TransferSyntax) -> Option<BTreeMap<Tag, PrimitiveValue>> {
+    let reader = DataSetReader::new_with_dictionary(
+        data,
+        StandardDataDictionary,
+        ts,
+        SpecificCharacterSet::Default,
+    )
+    .ok()?
+    .with_limits(ReadLimits::untrusted());
+
+    let mut attributes = BTreeMap::new();
+    let mut depth = 0;
+    let mut tag = None;
+    for token in reader {
+        match token.ok()? {
+            DataToken::SequenceStart { .. } => depth += 1,
+            DataToken::SequenceEnd => depth -= 1,
+            DataToken::ElementHeader(header) if depth == 0 => {
+                if header.tag >= tags::PIXEL_DATA {
+                    break;
+                }
+                tag = Some(header.tag);
+            }
+            DataToken::PrimitiveValue(value) => {
+                if let Some(tag) = tag.take() {
+                    attributes.insert(tag, value);
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(attributes)
+}
+
+/// Retrieve the text of an attribute, without padding.
+fn attribute_text(attributes: &BTreeMap<Tag, PrimitiveValue>, tag: Tag) -> Option<String> {
+    attributes
+        .get(&tag)
+        .and_then(|v| v.string())
+        .map(|s| s.trim_end_matches(&['\0', ' '][..]).trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Resolve a path template against the attributes of a data set,
+/// falling back to the SOP class and instance UIDs of the request.
+fn resolve_path(
+    template: &str,
+    attributes: &BTreeMap<Tag, PrimitiveValue>,
+    request: &CStoreRq,
+) -> PathBuf {
+    let mut path = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+        path.push_str(&rest[..start]);
+        let keyword = &rest[start + 1..end];
+        let value = StandardDataDictionary
+            .by_name(keyword)
+            .and_then(|e| attribute_text(attributes, e.tag()))
+            .or_else(|| match keyword {
+                "SOPClassUID" => Some(request.affected_sop_class_uid.clone()),
+                "SOPInstanceUID" => Some(request.affected_sop_instance_uid.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| UNKNOWN.to_string());
+        path.push_str(&sanitize(&value));
+        rest = &rest[end + 1..];
+    }
+    path.push_str(rest);
+    Path::new(&path).to_path_buf()
+}
+
+/// Make an attribute value safe to use as a path component.
+fn sanitize(value: &str) -> String {
+    let value: String = value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '_' || c == '^' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if value.starts_with('.') {
+        // never refer to the current or parent directory
+        value.replacen('.', "_", 1)
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::testing::{instance, spawn_scp};
+    use std::net::{SocketAddr, TcpListener};
+    use std::thread::JoinHandle;
+
+    const SOP_CLASS: &str = "1.2.840.10008.5.1.4.1.1.7";
+    const SOP_INSTANCE: &str = "2.25.42";
+
+    /// A data set in Implicit VR Little Endian with the given attributes.
+    fn data_set(patient_id: &str, sop_class_uid: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut put = |group: u16, element: u16, value: &str, padding: u8| {
+            let mut value = value.as_bytes().to_vec();
+            if value.len() % 2 == 1 {
+                value.push(padding);
+            }
+            out.extend(&group.to_le_bytes());
+            out.extend(&element.to_le_bytes());
+            out.extend(&(value.len() as u32).to_le_bytes());
+            out.extend(value);
+        };
+        put(0x0008, 0x0016, sop_class_uid, 0);
+        put(0x0008, 0x0018, SOP_INSTANCE, 0);
+        put(0x0010, 0x0020, patient_id, b' ');
+        put(0x0020, 0x000D, "1.2.3", 0);
+        out
+    }
+
+    fn request() -> CStoreRq {
+        CStoreRq {
+            message_id: 1,
+            affected_sop_class_uid: SOP_CLASS.to_string(),
+            affected_sop_instance_uid: SOP_INSTANCE.to_string(),
+            priority: Priority::Medium,
+            move_originator_ae_title: None,
+            move_originator_message_id: None,
+        }
+    }
+
+    /// Serve one association with the given storage SCP on another thread,
+    /// collecting the outcomes of the C-STORE requests.
+    fn spawn_storage_scp(
+        scp: StorageScp,
+    ) -> Result<(SocketAddr, JoinHandle<Result<Vec<StoreOutcome>>>)> {
+        spawn_scp(move |socket| {
+            let association = scp.establish(socket)?;
+            let mut outcomes = Vec::new();
+            scp.serve(association, |outcome| outcomes.push(outcome.clone()))?;
+            Ok(outcomes)
+        })
+    }
+
+    #[test]
+    fn resolve_path_template() {
+        let ts = TransferSyntaxRegistry.get("1.2.840.10008.1.2").unwrap();
+        let attributes = read_attributes(&data_set("../Doe 1", SOP_CLASS)[..], ts).unwrap();
+        assert_eq!(
+            resolve_path(DEFAULT_PATH_TEMPLATE, &attributes, &request()),
+            Path::new("_._Doe_1/1.2.3/UNKNOWN/2.25.42.dcm")
+        );
+        assert_eq!(
+            resolve_path("{Modality}/{SOPClassUID}", &BTreeMap::new(), &request()),
+            Path::new("UNKNOWN/1.2.840.10008.5.1.4.1.1.7")
+        );
+    }
+
+    #[test]
+    fn split_instances_in_batches() {
+        // two presentation contexts are proposed for each SOP class
+        let instances: Vec<_> = (0..65)
+            .map(|i| instance(&format!("1.2.3.{}", i), SOP_INSTANCE))
+            .chain(vec![instance("1.2.3.64", SOP_INSTANCE)])
+            .collect();
+        let scu = StorageScu::new();
+        let batches = scu.batches(&instances);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), 64);
+        assert_eq!(batches[1].len(), 2);
+        assert_eq!(batches[1][0].sop_class_uid(), "1.2.3.64");
+
+        let instances = vec![instance(SOP_CLASS, SOP_INSTANCE); 200];
+        assert_eq!(scu.batches(&instances).len(), 1);
+        assert!(scu.batches(&[]).is_empty());
+    }
+
+    #[test]
+    fn open_instance_file() -> Result<()> {
+        let path =
+            std::env::temp_dir().join(format!("dicom-ul-instance-{}.dcm", std::process::id()));
+        let mut file = File::create(&path)?;
+        file.write_all(&[0; 128])?;
+        let expected = instance(SOP_CLASS, SOP_INSTANCE);
+        expected.meta().write(&mut file)?;
+        file.write_all(&expected.data()?)?;
+        drop(file);
+
+        // the data set is read when requested
+        let instance = Instance::open_file(&path)?;
+        assert_eq!(instance.sop_class_uid(), SOP_CLASS);
+        assert_eq!(instance.data()?, expected.data()?);
+        fs::remove_file(&path)?;
+        assert!(instance.data().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn store_instances() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("dicom-ul-storescp-{}", std::process::id()));
+        let (addr, server) =
+            spawn_storage_scp(StorageScp::new(&dir).with_abstract_syntax(SOP_CLASS))?;
+
+        let mut association = ClientAssociationOptions::new()
+            .calling_ae_title("STORE-SCU")
+            .with_presentation_context(SOP_CLASS, vec!["1.2.840.10008.1.2"])
+            .establish(addr)?;
+        let command = Command::CStoreRq(request()).encode();
+        association.send_message(1, &command, Some(&data_set("P1", SOP_CLASS)))?;
+        let response = Command::decode(&association.receive_message()?.command)?;
+        assert_eq!(response.status(), Some(Status::SUCCESS));
+        association.send_message(1, &command, Some(&data_set("P1", "1.2.3.4")))?;
+        let response = Command::decode(&association.receive_message()?.command)?;
+        assert_eq!(response.status(), Some(DATA_SET_MISMATCH));
+
+        // a request which announces no data set
+        let mut no_data_set = command.clone();
+        let at = no_data_set
+            .windows(4)
+            .position(|w| w == [0x00, 0x00, 0x00, 0x08])
+            .unwrap();
+        no_data_set[at + 8..at + 10].copy_from_slice(&[0x01, 0x01]);
+        association.send_message(1, &no_data_set, None)?;
+        let response = Command::decode(&association.receive_message()?.command)?;
+        assert_eq!(response.status(), Some(CANNOT_UNDERSTAND));
+        association.release()?;
+
+        let outcomes = server.join().unwrap()?;
+        assert_eq!(outcomes.len(), 3);
+        let path = outcomes[0].path.clone().unwrap();
+        assert_eq!(path, dir.join("P1/1.2.3/UNKNOWN/2.25.42.dcm"));
+        assert_eq!(outcomes[1].path, None);
+        assert_eq!(outcomes[2].path, None);
+        // only the stored instance is left in the output directory
+        assert_eq!(fs::read_dir(&dir)?.count(), 1);
+
+        let obj = dicom_object::open_file(&path).unwrap();
+        assert_eq!(
+            obj.meta()
+                .media_storage_sop_instance_uid
+                .trim_end_matches('\0'),
+            SOP_INSTANCE
+        );
+        assert_eq!(
+            obj.meta().transfer_syntax.trim_end_matches('\0'),
+            "1.2.840.10008.1.2"
+        );
+        assert_eq!(
+            obj.meta()
+                .source_application_entity_title
+                .as_ref()
+                .map(|s| s.trim_end()),
+            Some("STORE-SCU")
+        );
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn send_instances_with_transcoding() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("dicom-ul-storescu-{}", std::process::id()));
+        let scp = StorageScp::new(&dir)
+            .with_abstract_syntax(SOP_CLASS)
+            .with_transfer_syntax(EXPLICIT_VR_LITTLE_ENDIAN);
+
+        let instances = vec![instance(SOP_CLASS, SOP_INSTANCE)];
+        assert_eq!(instances[0].transfer_syntax(), IMPLICIT_VR_LITTLE_ENDIAN);
+
+        // the original transfer syntax is not accepted
+        let (addr, server) = spawn_storage_scp(scp.clone())?;
+        let results = StorageScu::new().send(addr, &instances);
+        assert_eq!(results[0].status, None);
+        assert!(results[0].is_failure());
+        assert!(server.join().unwrap()?.is_empty());
+
+        let (addr, server) = spawn_storage_scp(scp)?;
+        let results = StorageScu::new().transcode(true).send(addr, &instances);
+        assert!(results[0].is_success());
+        assert_eq!(
+            results[0].transfer_syntax.as_deref(),
+            Some(EXPLICIT_VR_LITTLE_ENDIAN)
+        );
+
+        let outcomes = server.join().unwrap()?;
+        assert_eq!(outcomes.len(), 1);
+        let obj = dicom_object::open_file(outcomes[0].path.as_ref().unwrap()).unwrap();
+        assert_eq!(
+            obj.meta().transfer_syntax.trim_end_matches('\0'),
+            EXPLICIT_VR_LITTLE_ENDIAN
+        );
+        assert_eq!(
+            obj.element(tags::PATIENT_ID)
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .trim(),
+            "P1"
+        );
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn record_errors_of_unsent_instances() {
+        // nothing listens on this address anymore
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let instances = vec![
+            instance(SOP_CLASS, SOP_INSTANCE),
+            instance(SOP_CLASS, SOP_INSTANCE),
+        ];
+        let results = StorageScu::new().send(addr, &instances);
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|r| r.is_failure() && r.status.is_none() && r.error.is_some()));
+    }
+}
@@ -0,0 +1,64 @@
+//! Fixtures shared by the tests of the service classes.
+
+use super::storage::Instance;
+use crate::error::Result;
+use dicom_core::{DataElement, PrimitiveValue, VR};
+use dicom_dictionary_std::{tags, StandardDataDictionary};
+use dicom_encoding::transfer_syntax::TransferSyntaxIndex;
+use dicom_object::mem::InMemDicomObject;
+use dicom_object::meta::{FileMetaTableBuilder, IMPLEMENTATION_CLASS_UID};
+use dicom_transfer_syntax_registry::TransferSyntaxRegistry;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread::{self, JoinHandle};
+
+pub(crate) const IMPLICIT_VR_LITTLE_ENDIAN: &str = "1.2.840.10008.1.2";
+
+/// Listen on a local port and serve the first connection to it
+/// on another thread.
+pub(crate) fn spawn_scp<T, F>(serve: F) -> Result<(SocketAddr, JoinHandle<Result<T>>)>
+where
+    T: Send + 'static,
+    F: FnOnce(TcpStream) -> Result<T> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    let server = thread::spawn(move || {
+        let (socket, _) = listener.accept()?;
+        serve(socket)
+    });
+    Ok((addr, server))
+}
+
+/// An instance in Implicit VR Little Endian of patient `P1`,
+/// in study `1.2.3`.
+pub(crate) fn instance(sop_class_uid: &str, sop_instance_uid: &str) -> Instance {
+    let mut obj = InMemDicomObject::<StandardDataDictionary>::create_empty();
+    for &(tag, vr, value) in &[
+        (tags::SOP_CLASS_UID, VR::UI, sop_class_uid),
+        (tags::SOP_INSTANCE_UID, VR::UI, sop_instance_uid),
+        (tags::PATIENT_ID, VR::LO, "P1"),
+        (tags::STUDY_INSTANCE_UID, VR::UI, "1.2.3"),
+    ] {
+        obj.put(DataElement::new(
+            tag,
+            vr,
+            PrimitiveValue::Strs([value.to_string()].as_ref().into()).into(),
+        ));
+    }
+    let ts = TransferSyntaxRegistry
+        .get(IMPLICIT_VR_LITTLE_ENDIAN)
+        .unwrap();
+    let mut data = Vec::new();
+    obj.write_dataset_with_ts(&mut data, ts).unwrap();
+    let meta = FileMetaTableBuilder::new()
+        .media_storage_sop_class_uid(sop_class_uid.to_string())
+        .media_storage_sop_instance_uid(sop_instance_uid.to_string())
+        .transfer_syntax(IMPLICIT_VR_LITTLE_ENDIAN.to_string())
+        .implementation_class_uid(IMPLEMENTATION_CLASS_UID.to_string())
+        .build()
+        .unwrap();
+    let mut src = Vec::new();
+    meta.write(&mut src).unwrap();
+    src.extend(data);
+    Instance::from_reader(&src[..]).unwrap()
+}
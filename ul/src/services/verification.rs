@@ -0,0 +1,149 @@
+//! Verification service class (PS3.4 Annex A).
+//!
+//! The verification SOP class lets an application entity check
+//! that a peer is reachable and speaks DICOM,
+//! by exchanging a C-ECHO request and response.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use dicom_ul::association::ClientAssociationOptions;
+//! # use dicom_ul::services::verification::{self, VERIFICATION_SOP_CLASS};
+//! # fn run() -> dicom_ul::error::Result<()> {
+//! let mut association = ClientAssociationOptions::new()
+//!     .with_presentation_context(VERIFICATION_SOP_CLASS, vec!["1.2.840.10008.1.2"])
+//!     .establish("129.168.0.5:104")?;
+//! let status = verification::echo(&mut association, 1)?;
+//! assert!(status.is_success());
+//! association.release()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::presentation_context_for;
+use crate::association::{ClientAssociation, ServerAssociation};
+use crate::dimse::{CEchoRq, CEchoRsp, Command, Status};
+use crate::error::{Error, Result};
+
+/// The UID of the verification SOP class.
+pub const VERIFICATION_SOP_CLASS: &str = "1.2.840.10008.1.1";
+
+/// Send a C-ECHO request with the given message ID
+/// and wait for the response, returning its status.
+///
+/// Fails with `Error::NoPresentationContext`
+/// if the verification SOP class was not accepted on the association.
+pub fn echo(association: &mut ClientAssociation, message_id: u16) -> Result<Status> {
+    let presentation_context_id =
+        presentation_context_for(association.presentation_contexts(), VERIFICATION_SOP_CLASS)?;
+    let request = Command::CEchoRq(CEchoRq {
+        message_id,
+        affected_sop_class_uid: VERIFICATION_SOP_CLASS.to_string(),
+    });
+    association.send_message(presentation_context_id, &request.encode(), None)?;
+
+    let message = association.receive_message()?;
+    match Command::decode(&message.command)? {
+        Command::CEchoRsp(response) => {
+            if response.message_id_being_responded_to != message_id {
+                return Err(Error::UnexpectedMessageId(
+                    response.message_id_being_responded_to,
+                ));
+            }
+            Ok(response.status)
+        }
+        command => Err(Error::UnexpectedCommand(command.command_field())),
+    }
+}
+
+/// Reply with success to a C-ECHO request
+/// received on the given presentation context.
+pub fn respond(
+    association: &mut ServerAssociation,
+    presentation_context_id: u8,
+    request: &CEchoRq,
+) -> Result<()> {
+    let response = Command::CEchoRsp(CEchoRsp {
+        message_id_being_responded_to: request.message_id,
+        affected_sop_class_uid: Some(request.affected_sop_class_uid.clone()),
+        status: Status::SUCCESS,
+    });
+    association.send_message(presentation_context_id, &response.encode(), None)
+}
+
+/// Reply to every C-ECHO request on the association
+/// until the requestor releases it,
+/// returning the number of requests served.
+///
+/// Fails with `Error::UnexpectedCommand` on any other command,
+/// in which case the association is aborted.
+pub fn serve(mut association: ServerAssociation) -> Result<usize> {
+    let mut count = 0;
+    loop {
+        let message = match association.receive_message() {
+            Ok(message) => message,
+            Err(Error::Released) => return Ok(count),
+            Err(e) => return Err(e),
+        };
+        match Command::decode(&message.command)? {
+            Command::CEchoRq(request) => {
+                respond(&mut association, message.presentation_context_id, &request)?;
+                count += 1;
+            }
+            command => {
+                association.abort()?;
+                return Err(Error::UnexpectedCommand(command.command_field()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::association::{ClientAssociationOptions, ServerAssociationOptions};
+    use crate::services::testing::spawn_scp;
+
+    #[test]
+    fn echo_round_trip() -> Result<()> {
+        let (addr, server) = spawn_scp(|socket| {
+            let association = ServerAssociationOptions::new()
+                .with_abstract_syntax(VERIFICATION_SOP_CLASS)
+                .establish(socket)?;
+            serve(association)
+        })?;
+
+        let mut association = ClientAssociationOptions::new()
+            .with_presentation_context(VERIFICATION_SOP_CLASS, vec!["1.2.840.10008.1.2"])
+            .establish(addr)?;
+        assert_eq!(echo(&mut association, 1)?, Status::SUCCESS);
+        assert_eq!(echo(&mut association, 2)?, Status::SUCCESS);
+        association.release()?;
+
+        assert_eq!(server.join().unwrap()?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn echo_without_verification_context() -> Result<()> {
+        let (addr, server) = spawn_scp(|socket| {
+            let mut association = ServerAssociationOptions::new()
+                .with_abstract_syntax("1.2.840.10008.5.1.4.1.1.7")
+                .establish(socket)?;
+            match association.receive_message() {
+                Err(Error::Released) => Ok(()),
+                r => panic!("unexpected outcome {:?}", r),
+            }
+        })?;
+
+        let mut association = ClientAssociationOptions::new()
+            .with_presentation_context("1.2.840.10008.5.1.4.1.1.7", vec!["1.2.840.10008.1.2"])
+            .establish(addr)?;
+        match echo(&mut association, 1) {
+            Err(Error::NoPresentationContext(uid)) => assert_eq!(uid, VERIFICATION_SOP_CLASS),
+            r => panic!("unexpected outcome {:?}", r),
+        }
+        association.release()?;
+        server.join().unwrap()
+    }
+}